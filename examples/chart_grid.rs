@@ -0,0 +1,35 @@
+use charton::prelude::*;
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let x: Vec<f64> = (0..300).map(|i| i as f64 / 20.0).collect();
+    let y: Vec<f64> = x.iter().map(|v| (v * 1.5).sin() * v.sqrt()).collect();
+    let region = vec!["North", "South", "East", "West"];
+    let sales = vec![42.0, 31.0, 55.0, 27.0];
+
+    let trend = chart!(x, y)?
+        .mark_line()?
+        .configure_line(|l| l.with_color("steelblue"))
+        .encode((alt::x("x"), alt::y("y")))?
+        .with_title("Trend");
+    let scatter = chart!(x, y)?
+        .mark_point()?
+        .encode((alt::x("x"), alt::y("y")))?
+        .with_title("Samples");
+    let bars = chart!(region, sales)?
+        .mark_bar()?
+        .encode((alt::x("region"), alt::y("sales")))?
+        .with_title("Sales by Region");
+
+    // The trend spans the whole first row; the detail views share the second.
+    ChartGrid::new(2, 2)
+        .place_span(trend, 0, 0, 1, 2)
+        .place(scatter, 1, 0)
+        .place(bars, 1, 1)
+        .with_gap(12.0)
+        .with_size(900, 650)
+        .with_title("Dashboard")
+        .save("docs/src/images/chart_grid.svg")?;
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use charton::prelude::*;
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let month: Vec<f64> = (0..48).map(|i| i as f64 / 2.0).collect();
+    let revenue: Vec<f64> = month
+        .iter()
+        .map(|m| 100.0 + 25.0 * (m / 3.0).sin() + m)
+        .collect();
+    let cost: Vec<f64> = month
+        .iter()
+        .map(|m| 105.0 + 15.0 * (m / 4.0 + 1.0).cos() + 0.8 * m)
+        .collect();
+
+    // The shaded gap is drawn first so that both lines sit on top of it.
+    let gap = chart!(month, revenue, cost)?
+        .mark_area()?
+        .configure_area(|a| {
+            a.with_positive_color("#2ca02c")
+                .with_negative_color("#d62728")
+                .with_opacity(0.35)
+        })
+        .encode((alt::x("month"), alt::y("revenue"), alt::y2("cost")))?;
+    let revenue_line = chart!(month, revenue)?
+        .mark_line()?
+        .configure_line(|l| l.with_color("#2ca02c"))
+        .encode((alt::x("month"), alt::y("revenue")))?;
+    let cost_line = chart!(month, cost)?
+        .mark_line()?
+        .configure_line(|l| l.with_color("#d62728"))
+        .encode((alt::x("month"), alt::y("cost")))?;
+
+    gap.and(revenue_line)
+        .and(cost_line)
+        .with_y_label("amount")
+        .with_title("Revenue vs Cost")
+        .save("docs/src/images/difference_area.svg")?;
+
+    Ok(())
+}
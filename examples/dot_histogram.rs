@@ -0,0 +1,20 @@
+use charton::prelude::*;
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let ds = load_dataset("iris")?;
+
+    // Wilkinson dot histogram: one dot per flower, stacked within each bin.
+    chart!(ds)?
+        .mark_dots()?
+        .encode((
+            alt::x("sepal_length").with_bins(20),
+            alt::y("count"),
+            alt::color("species"),
+        ))?
+        .with_x_label("Sepal Length")
+        .with_y_label("Count")
+        .save("docs/src/images/dot_histogram.svg")?;
+
+    Ok(())
+}
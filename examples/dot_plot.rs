@@ -0,0 +1,24 @@
+use charton::prelude::*;
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let country = ["Norway", "Japan", "Chile", "Kenya", "Spain", "Canada"];
+    let score = [7.3, 6.1, 6.4, 4.5, 6.5, 6.9];
+
+    // Cleveland dot plot: categories on the vertical axis, sorted by value.
+    chart!(country, score)?
+        .mark_dotplot()?
+        .configure_dotplot(|m| {
+            m.with_color("steelblue")
+                .with_size(5.0)
+                .with_line_dash(vec![3.0, 2.0])
+                .with_sort("ascending")
+        })
+        .encode((alt::x("country"), alt::y("score")))?
+        .coord_flip()
+        .with_x_label("")
+        .with_y_label("Happiness Score")
+        .save("docs/src/images/dot_plot.svg")?;
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use charton::prelude::*;
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let x: Vec<f64> = (0..400).map(|i| i as f64 / 20.0).collect();
+    let y: Vec<f64> = x.iter().map(|v| (v * 3.0).sin() * 0.3 + v.sqrt()).collect();
+
+    let line = chart!(x, y)?
+        .mark_line()?
+        .configure_line(|l| l.with_color("steelblue"))
+        .encode((alt::x("x"), alt::y("y")))?;
+
+    // Zoom into a small window of the series and place it in the lower right corner.
+    line.clone()
+        .with_inset(
+            line,
+            InsetSpec::new()
+                .with_anchor("bottom-right")
+                .with_size(0.4, 0.4)
+                .with_x_domain(4.0, 6.0)
+                .with_y_domain(1.6, 2.8),
+        )
+        .with_size(600, 400)
+        .with_title("Zoomed Inset")
+        .save("docs/src/images/inset.svg")?;
+
+    Ok(())
+}
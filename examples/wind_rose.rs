@@ -0,0 +1,22 @@
+use charton::prelude::*;
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let ds = load_dataset("mtcars")?;
+
+    // Each carburetor count becomes a "direction" of the rose, and the
+    // horsepower of its cars is stacked outward by number of gears.
+    chart!(ds)?
+        .mark_bar()?
+        .encode((
+            alt::x("carb").with_scale(Scale::Discrete),
+            alt::y("hp").with_stack("stacked"),
+            alt::color("gear").with_scale(Scale::Discrete),
+        ))?
+        .with_title("Horsepower by carburetors and gears")
+        .with_coord_polar()
+        .with_grid(true)
+        .save("docs/src/images/wind_rose.svg")?;
+
+    Ok(())
+}
@@ -1,6 +1,9 @@
+#[cfg(feature = "geo")]
 use charton::prelude::*;
+#[cfg(feature = "geo")]
 use std::error::Error;
 
+#[cfg(feature = "geo")]
 fn main() -> Result<(), Box<dyn Error>> {
     // World map geojson was downloaed from "https://raw.githubusercontent.com/nvkelso/natural-earth-vector/master/geojson/ne_110m_admin_0_countries.geojson";
     let world_map = std::path::Path::new("assets/ne_110m_admin_0_countries.geojson");
@@ -39,3 +42,8 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     Ok(())
 }
+
+#[cfg(not(feature = "geo"))]
+fn main() {
+    println!("This example requires --features \"geo\" to run.");
+}
@@ -157,6 +157,7 @@ pub mod base {
     /// Implementors of this trait represent specific visualization libraries such as:
     /// - `Altair`: For creating statistical visualizations using the Altair library
     /// - `Matplotlib`: For creating plots using the Matplotlib library
+    /// - `Plotly`: For creating interactive HTML charts using the Plotly library
     ///
     /// This trait is used in conjunction with the `Plot` struct to enable generic
     /// programming over different visualization tools.
@@ -198,6 +199,23 @@ pub mod base {
     pub struct Matplotlib {}
     impl Renderer for Matplotlib {}
 
+    /// A marker struct representing the Plotly visualization library.
+    ///
+    /// This struct implements the `Renderer` trait and serves as a marker type
+    /// to indicate that Plotly should be used as the visualization tool.
+    /// Plotly produces interactive, zoomable charts that are saved as standalone
+    /// HTML pages or shown inline in Jupyter notebooks.
+    ///
+    /// The plotting code either defines a Plotly figure named `fig` in Python or
+    /// is a Plotly figure in JSON form.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let plot = Plot::<Plotly>::build(&data)?;
+    /// ```
+    pub struct Plotly {}
+    impl Renderer for Plotly {}
+
     /// A trait that defines the core functionality for visualization libraries.
     ///
     /// This trait specifies the essential methods that any visualization tool
@@ -269,7 +287,8 @@ pub mod base {
         ///
         /// This method runs the visualization code and saves the resulting plot
         /// to the specified file path. The format is typically inferred from
-        /// the file extension. Currently, only SVG and PNG format are supported.
+        /// the file extension. Supported formats depend on the renderer, e.g. SVG for
+        /// Altair, PNG for Matplotlib and HTML for Plotly.
         ///
         /// # Parameters
         /// * `path` - A path-like object specifying where to save the visualization
@@ -1,2 +1,3 @@
 pub mod altair;
 pub mod matplotlib;
+pub mod plotly;
@@ -11,9 +11,14 @@ use std::process::{Command, Stdio};
 
 impl Plot<Matplotlib> {
     fn to_png(&self) -> Result<String, ChartonError> {
-        let full_plotting_code = self.generate_full_plotting_code("png")?;
-        let png_content = self.execute_plotting_code(&full_plotting_code)?;
-        Ok(png_content)
+        self.to_figure("png")
+    }
+
+    // Render the figure in the given matplotlib format and return it as a base64 string
+    fn to_figure(&self, format: &str) -> Result<String, ChartonError> {
+        let full_plotting_code = self.generate_full_plotting_code(format)?;
+        let figure_content = self.execute_plotting_code(&full_plotting_code)?;
+        Ok(figure_content)
     }
 }
 
@@ -35,20 +40,22 @@ __charton_temp_df_name_fm_n9jh3 = pl.read_ipc(BytesIO(ipc_data)).to_pandas()
 "#;
 
         let output = match output_format {
-            "png" => {
+            // Matplotlib writes PDF as vector output with its own embedded fonts.
+            "png" | "pdf" => format!(
                 r#"
 # Transfer base64 encoded figure to rust
 import matplotlib
 import sys
 
 __charton_temp_buf_fm_n9jh3 = BytesIO()
-matplotlib.pyplot.savefig(__charton_temp_buf_fm_n9jh3, format="png")
+matplotlib.pyplot.savefig(__charton_temp_buf_fm_n9jh3, format="{}")
 matplotlib.pyplot.close()
 # Encode buffered raw binary bytes to base64 bytes and decode to a base64 string
 # An "\n" will be appeneded to the end if we use print
 print(base64.b64encode(__charton_temp_buf_fm_n9jh3.getvalue()).decode("utf-8"))
-"#
-            }
+"#,
+                output_format
+            ),
             _ => {
                 return Err(ChartonError::Unimplemented(format!(
                     "Output format '{}' is not supported",
@@ -213,8 +220,6 @@ impl Visualization for Plot<Matplotlib> {
     }
 
     fn save<P: AsRef<std::path::Path>>(&self, path: P) -> Result<(), ChartonError> {
-        let png_base64 = self.to_png()?;
-
         // Convert to Path for file operations
         let path_obj = path.as_ref();
 
@@ -234,12 +239,13 @@ impl Visualization for Plot<Matplotlib> {
             .map(|s| s.to_lowercase());
 
         match ext.as_deref() {
-            Some("png") => {
+            Some(format @ ("png" | "pdf")) => {
+                let figure_base64 = self.to_figure(format)?;
                 // Decode the base64 string to raw binary bytes
                 let figure = general_purpose::STANDARD
-                    .decode(png_base64.trim()) // Remove "\n" at the end
+                    .decode(figure_base64.trim()) // Remove "\n" at the end
                     .map_err(|e| ChartonError::Render(format!("Failed to decode Base64: {}", e)))?;
-                // Write the raw binary bytes to PNG
+                // Write the raw binary bytes to the figure file
                 std::fs::write(path_obj, figure).map_err(ChartonError::Io)?;
             }
             Some(format) => {
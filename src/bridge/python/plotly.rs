@@ -0,0 +1,390 @@
+use crate::bridge::base::{
+    ExternalRendererExecutor, InputData, Plot, Plotly, SerializedData, Visualization,
+};
+use crate::error::ChartonError;
+use base64::Engine;
+use polars::prelude::*;
+use std::io::{Cursor, Seek, SeekFrom, Write};
+use std::marker::PhantomData;
+use std::process::{Command, Stdio};
+
+impl Plot<Plotly> {
+    /// Generates and returns a standalone HTML page with the interactive chart.
+    ///
+    /// The page loads plotly.js from its CDN, so it needs network access when
+    /// opened but stays small enough to commit or mail around.
+    ///
+    /// # Returns
+    /// A Result containing either:
+    /// - Ok(String) with the complete HTML document
+    /// - Err(ChartonError) if there was an error during execution
+    pub fn to_html(&self) -> Result<String, ChartonError> {
+        let full_plotting_code = self.generate_full_plotting_code("html")?;
+        self.execute_plotting_code(&full_plotting_code)
+    }
+
+    /// Generates and returns the Plotly figure as JSON.
+    ///
+    /// The JSON holds the figure's `data` and `layout` and can be passed to
+    /// `Plotly.newPlot` in a web page or read back with `plotly.io.from_json`.
+    pub fn to_json(&self) -> Result<String, ChartonError> {
+        let full_plotting_code = self.generate_full_plotting_code("json")?;
+        self.execute_plotting_code(&full_plotting_code)
+    }
+
+    // The figure as an HTML <div>, for embedding in a notebook cell
+    fn to_html_fragment(&self) -> Result<String, ChartonError> {
+        let full_plotting_code = self.generate_full_plotting_code("div")?;
+        self.execute_plotting_code(&full_plotting_code)
+    }
+
+    // The user code, or a figure loaded from it when the code is a Plotly JSON figure
+    fn figure_code(&self) -> Result<String, ChartonError> {
+        if !self.raw_plotting_code.trim_start().starts_with('{') {
+            return Ok(self.raw_plotting_code.clone());
+        }
+        // A JSON string literal is also a valid Python string literal
+        let literal = serde_json::to_string(&self.raw_plotting_code)
+            .map_err(|_| ChartonError::Data("Failed to serialize plotting code".to_string()))?;
+        Ok(format!(
+            r#"
+import plotly.io as pio
+
+fig = pio.from_json({})
+"#,
+            literal
+        ))
+    }
+}
+
+impl ExternalRendererExecutor for Plot<Plotly> {
+    fn generate_full_plotting_code(&self, output_format: &str) -> Result<String, ChartonError> {
+        let ipc_to_df = r#"
+import json
+import sys
+import base64
+import polars as pl
+from io import BytesIO
+
+data = json.loads(sys.stdin.read())
+ipc_data = base64.b64decode(data["value"])
+__charton_temp_df_name_fm_n9jh3 = pl.read_ipc(BytesIO(ipc_data))
+"#;
+
+        let output = match output_format {
+            "html" => {
+                r#"
+print(fig.to_html(full_html=True, include_plotlyjs="cdn"))
+"#
+            }
+            "div" => {
+                r#"
+print(fig.to_html(full_html=False, include_plotlyjs="cdn"))
+"#
+            }
+            "json" => {
+                r#"
+print(fig.to_json())
+"#
+            }
+            _ => {
+                return Err(ChartonError::Unimplemented(format!(
+                    "Output format '{}' is not supported",
+                    output_format
+                )));
+            }
+        };
+
+        let full_plotting_code = format!("{}{}{}", ipc_to_df, self.figure_code()?, output);
+        // Replace the dataframe name with the actual dataframe name
+        let full_plotting_code = full_plotting_code.replace(
+            "__charton_temp_df_name_fm_n9jh3 = pl.read_ipc(BytesIO(ipc_data))",
+            &format!("{} = pl.read_ipc(BytesIO(ipc_data))", self.data.name),
+        );
+
+        Ok(full_plotting_code)
+    }
+
+    fn execute_plotting_code(&self, code: &str) -> Result<String, ChartonError> {
+        let mut child = Command::new(&self.exe_path)
+            .arg("-c")
+            .arg(code)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .map_err(ChartonError::Io)?;
+
+        if let Some(mut stdin) = child.stdin.take() {
+            let json_data = serde_json::to_string(&self.data)
+                .map_err(|_| ChartonError::Data("Failed to serialize data".to_string()))?;
+            stdin
+                .write_all(json_data.as_bytes())
+                .map_err(ChartonError::Io)?;
+        }
+
+        let output = child.wait_with_output().map_err(ChartonError::Io)?;
+
+        if !output.status.success() {
+            return Err(ChartonError::Render(format!(
+                "Python script execution failed with status: {:?}",
+                output.status
+            )));
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    }
+}
+
+impl Visualization for Plot<Plotly> {
+    fn build(data: InputData) -> Result<Self, ChartonError> {
+        // Convert Polars DataFrame to Base64 encoded Arrow IPC format string
+        // Create an in-memory buffer
+        let mut buf = Cursor::new(Vec::new());
+        // Create IPC writer and write data
+        IpcWriter::new(&mut buf).finish(&mut data.df.clone())?;
+        // Reset cursor position for reading
+        buf.seek(SeekFrom::Start(0))?;
+        // Get raw byte data
+        let ipc_data = buf.into_inner();
+        // Encode binary data using base64
+        let base64_ipc = base64::engine::general_purpose::STANDARD.encode(ipc_data);
+
+        let data = SerializedData::new(&data.name, base64_ipc);
+
+        Ok(Plot {
+            data,
+            exe_path: String::new(),
+            raw_plotting_code: String::new(),
+            _renderer: PhantomData,
+        })
+    }
+
+    fn with_exe_path<P: AsRef<std::path::Path>>(
+        mut self,
+        exe_path: P,
+    ) -> Result<Self, ChartonError> {
+        let path = exe_path.as_ref();
+
+        // Check if the path exists
+        if !path.exists() {
+            return Err(ChartonError::ExecutablePath(format!(
+                "Python executable not found at path: {}",
+                path.display()
+            )));
+        }
+
+        // Check if the path is a file (not a directory)
+        if !path.is_file() {
+            return Err(ChartonError::ExecutablePath(format!(
+                "Provided path is not a file: {}",
+                path.display()
+            )));
+        }
+
+        // On Unix systems, we can also check if the file is executable
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::MetadataExt;
+            let metadata = path.metadata().map_err(ChartonError::Io)?;
+
+            if metadata.mode() & 0o111 == 0 {
+                return Err(ChartonError::ExecutablePath(format!(
+                    "Python executable is not executable: {}",
+                    path.display()
+                )));
+            }
+        }
+
+        // Convert path to string for process execution
+        let exe_path_str = path.to_str().ok_or_else(|| {
+            ChartonError::ExecutablePath(
+                "Python executable path contains invalid characters".to_string(),
+            )
+        })?;
+
+        // Verify that this is actually a Python interpreter by checking its version
+        let output = std::process::Command::new(exe_path_str)
+            .arg("--version")
+            .output()
+            .map_err(ChartonError::Io)?;
+
+        if !output.status.success() {
+            return Err(ChartonError::ExecutablePath(format!(
+                "File at {} is not a valid Python interpreter",
+                path.display()
+            )));
+        }
+
+        let version_output = String::from_utf8_lossy(&output.stdout);
+        let version_stderr = String::from_utf8_lossy(&output.stderr);
+
+        // Python version output is typically in format "Python X.Y.Z"
+        // It can be in either stdout or stderr depending on the Python version
+        if !(version_output.starts_with("Python ") || version_stderr.starts_with("Python ")) {
+            return Err(ChartonError::ExecutablePath(format!(
+                "File at {} is not a Python interpreter",
+                path.display()
+            )));
+        }
+
+        self.exe_path = exe_path_str.to_string();
+        Ok(self)
+    }
+
+    fn with_plotting_code(mut self, code: &str) -> Self {
+        self.raw_plotting_code = code.to_string();
+        self
+    }
+
+    fn show(&self) -> Result<(), ChartonError> {
+        let html = self.to_html_fragment()?;
+
+        // Check if we're in EVCXR Jupyter environment
+        if std::env::var("EVCXR_IS_RUNTIME").is_ok() {
+            println!("EVCXR_BEGIN_CONTENT text/html\n{}\nEVCXR_END_CONTENT", html);
+        }
+
+        Ok(())
+    }
+
+    fn save<P: AsRef<std::path::Path>>(&self, path: P) -> Result<(), ChartonError> {
+        let path_obj = path.as_ref();
+
+        // Create parent directory if it doesn't exist
+        if let Some(parent) = path_obj.parent().filter(|p| !p.exists()) {
+            std::fs::create_dir_all(parent).map_err(|e| {
+                ChartonError::Io(std::io::Error::other(format!(
+                    "Failed to create directory: {}",
+                    e
+                )))
+            })?;
+        }
+
+        let ext = path_obj
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(|s| s.to_lowercase());
+
+        match ext.as_deref() {
+            Some("html" | "htm") => {
+                let html_content = self.to_html()?;
+                std::fs::write(path_obj, html_content).map_err(ChartonError::Io)?;
+            }
+            Some("json") => {
+                let json_content = self.to_json()?;
+                std::fs::write(path_obj, json_content).map_err(ChartonError::Io)?;
+            }
+            Some(format) => {
+                return Err(ChartonError::Unimplemented(format!(
+                    "Output format '{}' is not supported",
+                    format
+                )));
+            }
+            None => {
+                return Err(ChartonError::Unimplemented(
+                    "Output format could not be determined from file extension".to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data;
+
+    #[test]
+    fn generate_full_plotting_code_works() -> Result<(), ChartonError> {
+        let df1 = df![
+            "a" => [1, 2],
+            "b" => [4, 5]
+        ]?;
+        // Python code as string
+        let raw_plotting_code = r#"
+import plotly.express as px
+
+fig = px.scatter(df1, x="a", y="b")
+"#;
+
+        let expected = r#"
+import json
+import sys
+import base64
+import polars as pl
+from io import BytesIO
+
+data = json.loads(sys.stdin.read())
+ipc_data = base64.b64decode(data["value"])
+df1 = pl.read_ipc(BytesIO(ipc_data))
+
+import plotly.express as px
+
+fig = px.scatter(df1, x="a", y="b")
+
+print(fig.to_html(full_html=True, include_plotlyjs="cdn"))
+"#;
+
+        let plotly = Plot::<Plotly>::build(data!(&df1)?)?.with_plotting_code(raw_plotting_code);
+        let full_plotting_code = plotly.generate_full_plotting_code("html")?;
+        assert_eq!(&full_plotting_code, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn json_figure_is_loaded_with_plotly_io() -> Result<(), ChartonError> {
+        let df1 = df![
+            "a" => [1, 2],
+            "b" => [4, 5]
+        ]?;
+        let figure = r#"{"data": [{"type": "bar", "x": ["it's"], "y": [1]}]}"#;
+
+        let plotly = Plot::<Plotly>::build(data!(&df1)?)?.with_plotting_code(figure);
+        let full_plotting_code = plotly.generate_full_plotting_code("json")?;
+        assert!(full_plotting_code.contains(
+            r#"fig = pio.from_json("{\"data\": [{\"type\": \"bar\", \"x\": [\"it's\"], \"y\": [1]}]}")"#
+        ));
+        assert!(full_plotting_code.ends_with("print(fig.to_json())\n"));
+        Ok(())
+    }
+
+    #[test]
+    fn unsupported_format_is_rejected() -> Result<(), ChartonError> {
+        let df1 = df!["a" => [1, 2]]?;
+        let plotly = Plot::<Plotly>::build(data!(&df1)?)?;
+        assert!(matches!(
+            plotly.generate_full_plotting_code("png"),
+            Err(ChartonError::Unimplemented(_))
+        ));
+        assert!(matches!(
+            plotly.save("chart.svg"),
+            Err(ChartonError::Unimplemented(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    #[ignore = "Requires Python environment with plotly"]
+    fn save_html_works() -> Result<(), ChartonError> {
+        let exe_path = r"D:\Programs\miniconda3\envs\cellpy\python.exe";
+        let df1 = df![
+            "Model" => ["S1", "M1", "R2", "P8", "M4", "T5", "V1"],
+            "Price" => [2430, 3550, 5700, 8750, 2315, 3560, 980],
+            "Discount" => [Some(0.65), Some(0.73), Some(0.82), None, Some(0.51), None, Some(0.26)],
+        ]?;
+
+        let raw_plotting_code = r#"
+import plotly.express as px
+
+fig = px.scatter(df1, x="Price", y="Discount", color="Model")
+"#;
+
+        Plot::<Plotly>::build(data!(&df1)?)?
+            .with_exe_path(exe_path)?
+            .with_plotting_code(raw_plotting_code)
+            .save("./tests/plotly.html")?;
+
+        Ok(())
+    }
+}
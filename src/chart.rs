@@ -1,6 +1,11 @@
+#![deny(clippy::unwrap_used, clippy::expect_used)]
+
 pub mod area_chart;
 pub mod bar_chart;
 pub mod box_chart;
+pub mod candlestick_chart;
+pub mod dotplot_chart;
+pub mod dots_chart;
 pub mod errorbar_chart;
 pub mod geo_chart;
 pub mod hist_chart;
@@ -10,20 +15,30 @@ pub mod rect_chart;
 pub mod rule_chart;
 pub mod text_chart;
 pub mod tick_chart;
+pub mod violin_chart;
 
 use crate::TEMP_SUFFIX;
 use crate::coordinate::CoordinateTrait;
 use crate::core::aesthetics::GlobalAesthetics;
-use crate::core::data::{Dataset, SemanticType, ToDataset};
-use crate::core::layer::{Layer, MarkRenderer};
-use crate::encode::{Channel, Encoding, IntoEncoding, y::StackMode};
+use crate::core::context::PanelContext;
+use crate::core::data::{AnyValue, ColumnVector, Dataset, SemanticType, ToDataset};
+use crate::core::format::FormatRegistry;
+use crate::core::guide::LegendGlyph;
+use crate::core::layer::{FacetPartition, Layer, MarkRenderer};
+use crate::encode::{
+    Channel, Encoding, IntoEncoding,
+    y::{StackMode, Y, YAxisSide},
+};
 use crate::error::ChartonError;
 use crate::mark::{
-    Mark, area::MarkArea, bar::MarkBar, boxplot::MarkBoxplot, errorbar::MarkErrorBar,
-    geo_path::MarkGeoPath, histogram::MarkHist, line::MarkLine, no_mark::NoMark, point::MarkPoint,
-    rect::MarkRect, rule::MarkRule, text::MarkText, tick::MarkTick,
+    Mark, area::MarkArea, bar::MarkBar, boxplot::MarkBoxplot, candlestick::MarkCandlestick,
+    dotplot::MarkDotPlot, dots::MarkDots, errorbar::MarkErrorBar, geo_path::MarkGeoPath,
+    histogram::MarkHist, line::MarkLine, no_mark::NoMark, point::MarkPoint, rect::MarkRect,
+    rule::MarkRule, text::MarkText, tick::MarkTick, violin::MarkViolin,
 };
+use crate::scale::inverse::TickFormatter;
 use crate::scale::{Expansion, Scale, ScaleDomain};
+use crate::visual::color::ColorMap;
 use ahash::AHashMap;
 use std::sync::Arc;
 
@@ -43,11 +58,19 @@ use std::sync::Arc;
 /// * `data` - The underlying data source.
 /// * `encoding` - Mapping between data fields and visual channels (x, y, color, etc.).
 /// * `mark` - The specific visual mark configuration. Is `None` when `T` is [NoMark].
+/// * `numeric_coercion` - Whether String columns holding numbers are cast to
+///   numbers when a channel prefers a continuous scale.
+/// * `y_axis_side` - The y axis this layer is scaled against in a layered chart.
+/// * `source` - The data and encoding the mark transform started from, so that
+///   facets can split the raw rows and transform each panel on its own.
 #[derive(Clone)]
 pub struct Chart<T: Mark = NoMark> {
     pub(crate) data: Dataset,
     pub(crate) encoding: Encoding,
     pub(crate) mark: Option<T>,
+    pub(crate) numeric_coercion: bool,
+    pub(crate) y_axis_side: YAxisSide,
+    pub(crate) source: Option<(Dataset, Encoding)>,
 }
 
 impl Chart<NoMark> {
@@ -71,6 +94,9 @@ impl Chart<NoMark> {
             data: dataset,
             encoding: Encoding::new(),
             mark: None,
+            numeric_coercion: true,
+            y_axis_side: YAxisSide::Left,
+            source: None,
         })
     }
 
@@ -81,6 +107,9 @@ impl Chart<NoMark> {
         let chart = Chart::<MarkPoint> {
             data: self.data,
             encoding: self.encoding,
+            numeric_coercion: self.numeric_coercion,
+            y_axis_side: self.y_axis_side,
+            source: None,
             mark: Some(MarkPoint::default()),
         };
 
@@ -98,6 +127,9 @@ impl Chart<NoMark> {
         let chart = Chart::<MarkLine> {
             data: self.data,
             encoding: self.encoding,
+            numeric_coercion: self.numeric_coercion,
+            y_axis_side: self.y_axis_side,
+            source: None,
             mark: Some(MarkLine::default()),
         };
 
@@ -113,6 +145,9 @@ impl Chart<NoMark> {
         let chart = Chart::<MarkBar> {
             data: self.data,
             encoding: self.encoding,
+            numeric_coercion: self.numeric_coercion,
+            y_axis_side: self.y_axis_side,
+            source: None,
             mark: Some(MarkBar::default()),
         };
 
@@ -128,6 +163,9 @@ impl Chart<NoMark> {
         let chart = Chart::<MarkArea> {
             data: self.data,
             encoding: self.encoding,
+            numeric_coercion: self.numeric_coercion,
+            y_axis_side: self.y_axis_side,
+            source: None,
             mark: Some(MarkArea::default()),
         };
 
@@ -143,6 +181,9 @@ impl Chart<NoMark> {
         let chart = Chart::<MarkText> {
             data: self.data,
             encoding: self.encoding,
+            numeric_coercion: self.numeric_coercion,
+            y_axis_side: self.y_axis_side,
+            source: None,
             mark: Some(MarkText::default()),
         };
 
@@ -158,6 +199,9 @@ impl Chart<NoMark> {
         let chart = Chart::<MarkRule> {
             data: self.data,
             encoding: self.encoding,
+            numeric_coercion: self.numeric_coercion,
+            y_axis_side: self.y_axis_side,
+            source: None,
             mark: Some(MarkRule::default()),
         };
 
@@ -173,6 +217,9 @@ impl Chart<NoMark> {
         let chart = Chart::<MarkBoxplot> {
             data: self.data,
             encoding: self.encoding,
+            numeric_coercion: self.numeric_coercion,
+            y_axis_side: self.y_axis_side,
+            source: None,
             mark: Some(MarkBoxplot::default()),
         };
 
@@ -183,11 +230,52 @@ impl Chart<NoMark> {
         Ok(chart)
     }
 
+    /// Transitions the base chart into a Candlestick (OHLC) chart.
+    ///
+    /// Requires an `x` encoding plus the `open`, `high`, `low` and `close` channels.
+    pub fn mark_candlestick(self) -> Result<Chart<MarkCandlestick>, ChartonError> {
+        let chart = Chart::<MarkCandlestick> {
+            data: self.data,
+            encoding: self.encoding,
+            numeric_coercion: self.numeric_coercion,
+            y_axis_side: self.y_axis_side,
+            source: None,
+            mark: Some(MarkCandlestick::default()),
+        };
+
+        if !chart.encoding.is_empty() {
+            return chart.validate_and_transform();
+        }
+
+        Ok(chart)
+    }
+
+    /// Transitions the base chart into a Violin chart.
+    pub fn mark_violin(self) -> Result<Chart<MarkViolin>, ChartonError> {
+        let chart = Chart::<MarkViolin> {
+            data: self.data,
+            encoding: self.encoding,
+            numeric_coercion: self.numeric_coercion,
+            y_axis_side: self.y_axis_side,
+            source: None,
+            mark: Some(MarkViolin::default()),
+        };
+
+        if !chart.encoding.is_empty() {
+            return chart.validate_and_transform();
+        }
+
+        Ok(chart)
+    }
+
     /// Transitions the base chart into a Histogram chart.
     pub fn mark_hist(self) -> Result<Chart<MarkHist>, ChartonError> {
         let chart = Chart::<MarkHist> {
             data: self.data,
             encoding: self.encoding,
+            numeric_coercion: self.numeric_coercion,
+            y_axis_side: self.y_axis_side,
+            source: None,
             mark: Some(MarkHist::default()),
         };
 
@@ -203,6 +291,9 @@ impl Chart<NoMark> {
         let chart = Chart::<MarkRect> {
             data: self.data,
             encoding: self.encoding,
+            numeric_coercion: self.numeric_coercion,
+            y_axis_side: self.y_axis_side,
+            source: None,
             mark: Some(MarkRect::default()),
         };
 
@@ -218,6 +309,9 @@ impl Chart<NoMark> {
         let chart = Chart::<MarkErrorBar> {
             data: self.data,
             encoding: self.encoding,
+            numeric_coercion: self.numeric_coercion,
+            y_axis_side: self.y_axis_side,
+            source: None,
             mark: Some(MarkErrorBar::default()),
         };
 
@@ -233,6 +327,9 @@ impl Chart<NoMark> {
         let chart = Chart::<MarkGeoPath> {
             data: self.data,
             encoding: self.encoding,
+            numeric_coercion: self.numeric_coercion,
+            y_axis_side: self.y_axis_side,
+            source: None,
             mark: Some(MarkGeoPath::default()),
         };
 
@@ -248,6 +345,9 @@ impl Chart<NoMark> {
         let chart = Chart::<MarkTick> {
             data: self.data,
             encoding: self.encoding,
+            numeric_coercion: self.numeric_coercion,
+            y_axis_side: self.y_axis_side,
+            source: None,
             mark: Some(MarkTick::default()),
         };
 
@@ -258,6 +358,48 @@ impl Chart<NoMark> {
         Ok(chart)
     }
 
+    /// Transitions the base chart into a Cleveland dot plot.
+    ///
+    /// Categories go on X and values on Y; add `y2` for range dots and use
+    /// `coord_flip()` to list the categories vertically.
+    pub fn mark_dotplot(self) -> Result<Chart<MarkDotPlot>, ChartonError> {
+        let chart = Chart::<MarkDotPlot> {
+            data: self.data,
+            encoding: self.encoding,
+            numeric_coercion: self.numeric_coercion,
+            y_axis_side: self.y_axis_side,
+            source: None,
+            mark: Some(MarkDotPlot::default()),
+        };
+
+        if !chart.encoding.is_empty() {
+            return chart.validate_and_transform();
+        }
+
+        Ok(chart)
+    }
+
+    /// Transitions the base chart into a Wilkinson dot histogram.
+    ///
+    /// Like `mark_hist`, X is binned and Y names the generated count column,
+    /// but every observation is drawn as its own dot.
+    pub fn mark_dots(self) -> Result<Chart<MarkDots>, ChartonError> {
+        let chart = Chart::<MarkDots> {
+            data: self.data,
+            encoding: self.encoding,
+            numeric_coercion: self.numeric_coercion,
+            y_axis_side: self.y_axis_side,
+            source: None,
+            mark: Some(MarkDots::default()),
+        };
+
+        if !chart.encoding.is_empty() {
+            return chart.validate_and_transform();
+        }
+
+        Ok(chart)
+    }
+
     // Creates a faceted view of the chart based on a specific data field.
     //
     // Faceting (also known as small multiples) splits the data into multiple subsets
@@ -286,7 +428,44 @@ impl Chart<NoMark> {
     //}
 }
 
+/// Share of the non-null values of a String column that must parse as numbers
+/// for the column to be treated as numeric.
+const NUMERIC_STRING_THRESHOLD: f64 = 0.99;
+
 impl<T: Mark> Chart<T> {
+    /// Controls whether String columns that hold numbers are cast to numbers.
+    ///
+    /// When a channel prefers a continuous scale (the position axes of point,
+    /// line and area charts, any axis whose mark needs a quantity, size, or a
+    /// channel given an explicit continuous scale) and its String column parses
+    /// as numbers for at least 99% of its values, the column is cast to
+    /// `Float64` with a diagnostic; values that do not parse become null. When
+    /// disabled, only a warning suggesting the cast is printed and the column
+    /// stays categorical. Enabled by default. Set it before `encode`.
+    pub const fn with_auto_numeric_coercion(mut self, enabled: bool) -> Self {
+        self.numeric_coercion = enabled;
+        self
+    }
+
+    /// Scales this layer against the y axis on the given side of the panel.
+    ///
+    /// `YAxisSide::Right` puts the layer on the secondary y axis, like
+    /// `y(..).axis_secondary()`: in a layered chart it gets its own y domain,
+    /// ticks and label (see `with_secondary_y_label`), independent of the
+    /// layers on the left axis.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// let rain = chart!(month, mm)?
+    ///     .mark_bar()?
+    ///     .encode((x("month"), y("mm")))?
+    ///     .with_y_axis_side(YAxisSide::Right);
+    /// temperature.and(rain).with_secondary_y_label("precipitation (mm)")
+    /// ```
+    pub const fn with_y_axis_side(mut self, side: YAxisSide) -> Self {
+        self.y_axis_side = side;
+        self
+    }
     /// Apply encoding mappings to the chart.
     ///
     /// This method defines how data fields map to visual properties (channels).
@@ -311,8 +490,11 @@ impl<T: Mark> Chart<T> {
     where
         U: IntoEncoding,
     {
-        // 1. Update the internal encoding mappings
-        enc.apply(&mut self.encoding);
+        // 1. Update the internal encoding mappings. The new specification is applied
+        // on its own first, so that a channel given twice in one call is reported.
+        let mut incoming = Encoding::new();
+        enc.apply(&mut incoming)?;
+        self.encoding.merge(incoming);
 
         // 2. Check if we are in the base (NoMark) state.
         // If so, we defer validation until a specific mark is assigned via .mark_xxx().
@@ -336,6 +518,8 @@ impl<T: Mark> Chart<T> {
     /// 5. **Final Resolution**: Resolves types for newly generated/transformed columns.
     /// 6. **Visual Refinement**: Applies final aesthetic defaults (zero-baselines, padding).
     pub(crate) fn validate_and_transform(mut self) -> Result<Self, ChartonError> {
+        let source = (self.data.clone(), self.encoding.clone());
+
         // --- Step 1: Mark Identification ---
         let mark_type = self
             .mark
@@ -345,6 +529,158 @@ impl<T: Mark> Chart<T> {
 
         // --- Step 2: Mandatory Encoding Validation ---
         self.validate_mandatory_encodings(&mark_type)?;
+        if self.encoding.href.is_some()
+            && matches!(
+                mark_type.as_str(),
+                "line" | "area" | "boxplot" | "violin" | "geo_path"
+            )
+        {
+            return Err(ChartonError::Encoding(format!(
+                "The href encoding is not supported by {} marks",
+                mark_type
+            )));
+        }
+        if self.encoding.tooltip.is_some()
+            && matches!(
+                mark_type.as_str(),
+                "line" | "area" | "boxplot" | "violin" | "geo_path"
+            )
+        {
+            return Err(ChartonError::Encoding(format!(
+                "The tooltip encoding is not supported by {} marks",
+                mark_type
+            )));
+        }
+        if let Some(tooltip) = &self.encoding.tooltip
+            && let Some(field) = tooltip
+                .fields
+                .iter()
+                .find(|f| !self.data.schema.contains_key(*f))
+        {
+            return Err(ChartonError::Data(format!(
+                "Column '{}' used by the tooltip encoding of the {} chart was not found",
+                field, mark_type
+            )));
+        }
+        if let Some(href) = &self.encoding.href {
+            let column = self.data.column(&href.field).map_err(|_| {
+                ChartonError::Data(format!(
+                    "Column '{}' used by the href encoding of the {} chart was not found",
+                    href.field, mark_type
+                ))
+            })?;
+            if !matches!(
+                column,
+                ColumnVector::String { .. } | ColumnVector::Categorical { .. }
+            ) {
+                return Err(ChartonError::Data(format!(
+                    "The href encoding needs a string column, but '{}' is {}",
+                    href.field,
+                    column.dtype_name()
+                )));
+            }
+        }
+        if let Some(detail) = &self.encoding.detail {
+            if mark_type != "line" {
+                return Err(ChartonError::Encoding(format!(
+                    "The detail encoding is only supported by line marks, not {}",
+                    mark_type
+                )));
+            }
+            if !self.data.schema.contains_key(&detail.field) {
+                return Err(ChartonError::Data(format!(
+                    "Column '{}' used by the detail encoding of the {} chart was not found",
+                    detail.field, mark_type
+                )));
+            }
+        }
+        if let Some(angle) = &self.encoding.angle {
+            if mark_type != "point" && mark_type != "text" {
+                return Err(ChartonError::Encoding(format!(
+                    "The angle encoding is only supported by point and text marks, not {}",
+                    mark_type
+                )));
+            }
+            let column = self.data.column(&angle.field).map_err(|_| {
+                ChartonError::Data(format!(
+                    "Column '{}' used by the angle encoding of the {} chart was not found",
+                    angle.field, mark_type
+                ))
+            })?;
+            if column.semantic_type() != SemanticType::Continuous {
+                return Err(ChartonError::Encoding(format!(
+                    "The angle encoding needs a numeric column of degrees, but '{}' is not numeric",
+                    angle.field
+                )));
+            }
+        }
+        for (name, offset) in [
+            (
+                "x_offset",
+                self.encoding.x_offset.as_ref().map(|o| &o.field),
+            ),
+            (
+                "y_offset",
+                self.encoding.y_offset.as_ref().map(|o| &o.field),
+            ),
+        ] {
+            let Some(field) = offset else { continue };
+            if mark_type != "point" {
+                return Err(ChartonError::Encoding(format!(
+                    "The {} encoding is only supported by point marks, not {}",
+                    name, mark_type
+                )));
+            }
+            if !self.data.schema.contains_key(field) {
+                return Err(ChartonError::Data(format!(
+                    "Column '{}' used by the {} encoding of the {} chart was not found",
+                    field, name, mark_type
+                )));
+            }
+        }
+        for (name, field) in [
+            ("opacity", self.encoding.opacity.as_ref().map(|o| &o.field)),
+            (
+                "fill_opacity",
+                self.encoding.fill_opacity.as_ref().map(|o| &o.field),
+            ),
+            (
+                "stroke_opacity",
+                self.encoding.stroke_opacity.as_ref().map(|o| &o.field),
+            ),
+        ] {
+            let Some(field) = field else { continue };
+            // A mapped opacity also applies to whole lines; the raw ones only to points.
+            let supported = mark_type == "point" || (name == "opacity" && mark_type == "line");
+            if !supported {
+                return Err(ChartonError::Encoding(format!(
+                    "The {} encoding is not supported by {} marks",
+                    name, mark_type
+                )));
+            }
+            let column = self.data.column(field).map_err(|_| {
+                ChartonError::Data(format!(
+                    "Column '{}' used by the {} encoding of the {} chart was not found",
+                    field, name, mark_type
+                ))
+            })?;
+            if column.semantic_type() != SemanticType::Continuous {
+                return Err(ChartonError::Encoding(format!(
+                    "The {} encoding needs a numeric column of opacities, but '{}' is not numeric",
+                    name, field
+                )));
+            }
+        }
+        self.coerce_numeric_strings(&mark_type)?;
+
+        // Candlesticks have no y channel of their own: the price axis is trained on the
+        // OHLC fields and titled after the close field unless a y encoding is given.
+        if mark_type == "candlestick"
+            && self.encoding.y.is_none()
+            && let Some(close) = self.encoding.close.as_ref()
+        {
+            self.encoding.y = Some(Y::new(&close.field));
+        }
 
         // --- Step 3: First Pass Semantic Resolution ---
         // Injects inferred or user-defined Scales into self.encoding
@@ -354,6 +690,7 @@ impl<T: Mark> Chart<T> {
         // Replace the old field-based check with Scale-based check.
         // This validates if the Mark (e.g., "bar") can work with the Scale (e.g., "Discrete").
         self.validate_scale_compatibility(&mark_type)?;
+        self.resolve_size_values()?;
 
         // --- Step 5: Statistical Transformations ---
         self.resolve_pre_transform_encodings()?;
@@ -361,13 +698,17 @@ impl<T: Mark> Chart<T> {
         match mark_type.as_str() {
             "point" => self = self.transform_point_data()?,
             "boxplot" => self = self.transform_boxplot_data()?,
+            "violin" => self = self.transform_violin_data()?,
             "errorbar" if self.encoding.y2.is_none() => {
                 self = self.transform_errorbar_data()?;
             }
             "rect" => self = self.transform_rect_data()?,
+            "dotplot" => self = self.transform_dotplot_data()?,
             "bar" => self = self.transform_bar_data()?,
             "hist" => self = self.transform_histogram_data()?,
-            "area" => self = self.transform_area_data()?,
+            "dots" => self = self.transform_dots_data()?,
+            // With y2 the area fills the gap between two series and is not stacked.
+            "area" if self.encoding.y2.is_none() => self = self.transform_area_data()?,
             _ => {}
         }
 
@@ -384,9 +725,27 @@ impl<T: Mark> Chart<T> {
         // invalidate the numerical meaning of the X-coordinate.
         self.resolve_semantic_types()?;
 
+        // Any positional field still absent at this point is neither user data nor
+        // a generated column: report it instead of failing later during layout.
+        for (channel, enc_field) in [
+            ("x", self.encoding.x.as_ref().map(|e| &e.field)),
+            ("y", self.encoding.y.as_ref().map(|e| &e.field)),
+        ] {
+            if let Some(field) = enc_field
+                && !field.is_empty()
+                && !self.data.schema.contains_key(field)
+            {
+                return Err(ChartonError::Data(format!(
+                    "Column '{}' used by the {} encoding of the {} chart was not found",
+                    field, channel, mark_type
+                )));
+            }
+        }
+
         // --- Step 7: Visual Refinement ---
         self.apply_visual_defaults()?;
 
+        self.source = Some(source);
         Ok(self)
     }
 
@@ -394,7 +753,7 @@ impl<T: Mark> Chart<T> {
     fn validate_mandatory_encodings(&self, mark_type: &str) -> Result<(), ChartonError> {
         match mark_type {
             "errorbar" | "bar" | "hist" | "line" | "point" | "area" | "boxplot" | "text"
-            | "rule" | "tick" | "geo_path" => {
+            | "rule" | "tick" | "geo_path" | "dotplot" | "dots" | "violin" => {
                 if self.encoding.x.is_none() || self.encoding.y.is_none() {
                     return Err(ChartonError::Encoding(format!(
                         "{} chart requires both x and y encodings",
@@ -412,6 +771,24 @@ impl<T: Mark> Chart<T> {
                     ));
                 }
             }
+            "candlestick" => {
+                let missing: Vec<&str> = [
+                    ("x", self.encoding.x.is_none()),
+                    ("open", self.encoding.open.is_none()),
+                    ("high", self.encoding.high.is_none()),
+                    ("low", self.encoding.low.is_none()),
+                    ("close", self.encoding.close.is_none()),
+                ]
+                .into_iter()
+                .filter_map(|(name, absent)| absent.then_some(name))
+                .collect();
+                if !missing.is_empty() {
+                    return Err(ChartonError::Encoding(format!(
+                        "Candlestick chart requires x, open, high, low and close encodings (missing: {})",
+                        missing.join(", ")
+                    )));
+                }
+            }
             "none" => {}
             _ => {
                 return Err(ChartonError::Mark(format!(
@@ -496,6 +873,10 @@ impl<T: Mark> Chart<T> {
             size.scale_type = resolve_channel_scale(&size.field, size.scale_type)?;
         }
 
+        if let Some(ref mut opacity) = self.encoding.opacity {
+            opacity.scale_type = resolve_channel_scale(&opacity.field, opacity.scale_type)?;
+        }
+
         if let Some(ref mut shape) = self.encoding.shape {
             shape.scale_type = resolve_channel_scale(&shape.field, shape.scale_type)?;
         }
@@ -507,6 +888,153 @@ impl<T: Mark> Chart<T> {
         Ok(())
     }
 
+    /// Finds String columns holding numbers on channels that prefer a continuous
+    /// scale, and casts them (or warns about them, see `with_auto_numeric_coercion`).
+    ///
+    /// Columns that are mostly, but not reliably, numeric are left alone with a
+    /// warning naming a few of the values that did not parse.
+    fn coerce_numeric_strings(&mut self, mark_type: &str) -> Result<(), ChartonError> {
+        let expectations = self.get_expected_scale_types(mark_type);
+        let numeric_axes = ["point", "line", "area"].contains(&mark_type);
+
+        for channel in [Channel::X, Channel::Y, Channel::Color, Channel::Size] {
+            let Some(field) = self.encoding.get_field_by_channel(channel) else {
+                continue;
+            };
+            let requested = self.encoding.get_scale_by_channel(channel);
+            let prefers_continuous = match requested {
+                Some(scale) => scale != Scale::Discrete,
+                None => {
+                    expectations
+                        .get(&channel)
+                        .is_some_and(|allowed| !allowed.contains(&Scale::Discrete))
+                        || (numeric_axes && matches!(channel, Channel::X | Channel::Y))
+                }
+            };
+            if !prefers_continuous {
+                continue;
+            }
+
+            let Ok(ColumnVector::String { data, validity }) = self.data.column(field) else {
+                continue;
+            };
+
+            let mut values: Vec<Option<f64>> = Vec::with_capacity(data.len());
+            let (mut present, mut unparsed) = (0usize, 0usize);
+            let mut offending: Vec<&str> = Vec::new();
+            for (i, raw) in data.iter().enumerate() {
+                let parsed = if ColumnVector::is_valid_in_mask(validity, i) {
+                    present += 1;
+                    let parsed = raw.trim().parse::<f64>().ok();
+                    if parsed.is_none() {
+                        unparsed += 1;
+                        if !offending.contains(&raw.as_str()) {
+                            offending.push(raw);
+                        }
+                    }
+                    parsed
+                } else {
+                    None
+                };
+                values.push(parsed);
+            }
+
+            if present == unparsed {
+                continue;
+            }
+            let share = (present - unparsed) as f64 / present as f64;
+            let examples = offending
+                .iter()
+                .take(5)
+                .map(|v| format!("\"{}\"", v))
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            if share < NUMERIC_STRING_THRESHOLD {
+                if share >= 0.5 {
+                    eprintln!(
+                        "Encoding: String column '{}' ({:?}) is {:.0}% numeric, but values such as {} do not parse as numbers; it stays categorical.",
+                        field,
+                        channel,
+                        share * 100.0,
+                        examples
+                    );
+                }
+                continue;
+            }
+
+            if !self.numeric_coercion {
+                eprintln!(
+                    "Encoding: String column '{}' ({:?}) holds numbers but is drawn as categories; cast it to a numeric column or enable with_auto_numeric_coercion.",
+                    field, channel
+                );
+                continue;
+            }
+
+            let nulls = if unparsed > 0 {
+                format!(" ({} became null)", examples)
+            } else {
+                String::new()
+            };
+            eprintln!(
+                "Encoding: String column '{}' ({:?}) holds numbers and was converted to Float64{}.",
+                field, channel, nulls
+            );
+
+            let field = field.to_string();
+            self.data.add_column(field, ColumnVector::from(values))?;
+        }
+
+        Ok(())
+    }
+
+    /// Rejects negative size values and drops zero-size rows when the size encoding asks for it.
+    fn resolve_size_values(&mut self) -> Result<(), ChartonError> {
+        let Some(size) = self.encoding.size.as_ref() else {
+            return Ok(());
+        };
+        let values = self.data.column(&size.field)?.to_f64_options();
+
+        let negative: Vec<(usize, f64)> = values
+            .iter()
+            .enumerate()
+            .filter_map(|(i, v)| v.filter(|v| *v < 0.0).map(|v| (i, v)))
+            .collect();
+        if !negative.is_empty() {
+            let min = negative
+                .iter()
+                .map(|(_, v)| *v)
+                .fold(f64::INFINITY, f64::min);
+            let rows: Vec<String> = negative
+                .iter()
+                .take(10)
+                .map(|(i, _)| i.to_string())
+                .collect();
+            let more = if negative.len() > 10 { ", ..." } else { "" };
+            return Err(ChartonError::Data(format!(
+                "Size values in '{}' must be non-negative; found {} negative value(s) (min {}) at rows [{}{}]",
+                size.field,
+                negative.len(),
+                min,
+                rows.join(", "),
+                more
+            )));
+        }
+
+        if size.drop_zero {
+            let keep: Vec<usize> = (0..values.len())
+                .filter(|&i| values[i] != Some(0.0))
+                .collect();
+            let dropped = values.len() - keep.len();
+            if dropped > 0 {
+                eprintln!("Size: Dropped {} rows with zero '{}'.", dropped, size.field);
+                self.data = self.data.take_rows(&keep)?;
+            }
+        }
+
+        Ok(())
+    }
+
     /// Helper method to perform the actual validation based on get_expected_scale_types
     fn validate_scale_compatibility(&self, mark_type: &str) -> Result<(), ChartonError> {
         let expectations = self.get_expected_scale_types(mark_type);
@@ -547,13 +1075,23 @@ impl<T: Mark> Chart<T> {
 
         // --- MARK-SPECIFIC AXIS CONSTRAINTS ---
         match mark_type {
-            "bar" | "boxplot" => {
-                // Standard Bar/Box: One axis must be discrete (categories),
+            "bar" => {
+                // Bars stand on categories, or on a time axis (e.g. resampled periods).
+                expected.insert(Channel::X, vec![Scale::Discrete, Scale::Temporal]);
+                expected.insert(Channel::Y, vec![Scale::Linear, Scale::Log, Scale::Temporal]);
+            }
+            "boxplot" | "violin" => {
+                // Standard Box: One axis must be discrete (categories),
                 // the other must be quantitative (height/value).
                 expected.insert(Channel::X, vec![Scale::Discrete]);
                 expected.insert(Channel::Y, vec![Scale::Linear, Scale::Log, Scale::Temporal]);
             }
-            "hist" => {
+            "dotplot" => {
+                // Dot plots place one dot per category along a quantitative axis.
+                expected.insert(Channel::X, vec![Scale::Discrete]);
+                expected.insert(Channel::Y, vec![Scale::Linear, Scale::Log, Scale::Temporal]);
+            }
+            "hist" | "dots" => {
                 // Histograms require a quantitative X-axis to perform binning.
                 expected.insert(Channel::X, vec![Scale::Linear, Scale::Log, Scale::Temporal]);
                 // Y is usually the generated 'count' (Linear).
@@ -575,6 +1113,14 @@ impl<T: Mark> Chart<T> {
                 );
                 expected.insert(Channel::Y, vec![Scale::Linear, Scale::Log]);
             }
+            "candlestick" => {
+                // Candles sit on a time, index or category axis and span a price range.
+                expected.insert(
+                    Channel::X,
+                    vec![Scale::Temporal, Scale::Linear, Scale::Discrete],
+                );
+                expected.insert(Channel::Y, vec![Scale::Linear, Scale::Log]);
+            }
             "errorbar" | "rule" => {
                 // Rules and Error bars are geometric intervals.
                 expected.insert(Channel::Y, vec![Scale::Linear, Scale::Log, Scale::Temporal]);
@@ -614,10 +1160,14 @@ impl<T: Mark> Chart<T> {
     /// provided by the user.
     fn resolve_pre_transform_encodings(&mut self) -> Result<(), ChartonError> {
         // Access the mark type to determine if binning is applicable.
-        let mt = self.mark.as_ref().unwrap().mark_type();
+        let mt = self
+            .mark
+            .as_ref()
+            .map(|m| m.mark_type())
+            .ok_or_else(|| ChartonError::Mark("A mark is required for binning".into()))?;
 
-        // Only "rect" (heatmaps) and "hist" (histograms) require pre-transform binning.
-        if !["rect", "hist"].contains(&mt) {
+        // Only "rect" (heatmaps), "hist" and "dots" (histograms) require pre-transform binning.
+        if !["rect", "hist", "dots"].contains(&mt) {
             return Ok(());
         }
 
@@ -669,15 +1219,35 @@ impl<T: Mark> Chart<T> {
     /// Refines visual properties like axis baselines and padding after data
     /// transformations are complete.
     fn apply_visual_defaults(&mut self) -> Result<(), ChartonError> {
-        let mt = self.mark.as_ref().unwrap().mark_type();
-
-        // We ensure x and y exist; unwrap is safe due to Mandatory Encoding Validation step.
-        let x_enc = self.encoding.x.as_mut().unwrap();
-        let y_enc = self.encoding.y.as_mut().unwrap();
+        let mt =
+            self.mark.as_ref().map(|m| m.mark_type()).ok_or_else(|| {
+                ChartonError::Mark("A mark is required for visual defaults".into())
+            })?;
+
+        // X and Y are guaranteed by the Mandatory Encoding Validation step for every
+        // mark reaching this point, but a missing channel is still reported, not assumed.
+        let x_enc = self.encoding.x.as_mut().ok_or_else(|| {
+            ChartonError::Encoding(format!("{} chart is missing its x encoding", mt))
+        })?;
+        let y_enc = self.encoding.y.as_mut().ok_or_else(|| {
+            ChartonError::Encoding(format!("{} chart is missing its y encoding", mt))
+        })?;
 
         // --- 1. STATISTICAL INTEGRITY & MAGNITUDE BASELINES ---
         // Marks representing magnitude (Bar, Area, Hist) should generally start at zero.
-        if y_enc.scale_type == Some(Scale::Linear) && ["area", "bar", "hist"].contains(&mt) {
+        // Dot plots and areas only grow from zero when no explicit range (y2) is given.
+        let is_zero_based_range = ["dotplot", "area"].contains(&mt) && self.encoding.y2.is_none();
+
+        // Stacks normalized to 100% fill the panel exactly, from 0 to 1.
+        if y_enc.stack == StackMode::Normalize && y_enc.expansion.is_none() {
+            y_enc.expansion = Some(Expansion {
+                mult: (0.0, 0.0),
+                add: (0.0, 0.0),
+            });
+        }
+        if y_enc.scale_type == Some(Scale::Linear)
+            && (["bar", "hist", "dots"].contains(&mt) || is_zero_based_range)
+        {
             // Force zero baseline unless the user explicitly disabled it.
             if y_enc.zero.is_none() {
                 y_enc.zero = Some(true);
@@ -685,6 +1255,12 @@ impl<T: Mark> Chart<T> {
 
             // PIE MODE DETECTION: An empty X field implies a radial projection of the Y axis.
             let is_pie_mode = x_enc.field.is_empty();
+            // Dodged bars are named beyond the end of each stack, which needs more room.
+            let pad = if mt == "bar" && x_enc.dodge_by.is_some() {
+                0.12
+            } else {
+                0.05
+            };
 
             // Calculate directional expansion based on data bounds.
             if let Ok(y_col) = self.data.column(&y_enc.field) {
@@ -700,18 +1276,21 @@ impl<T: Mark> Chart<T> {
                     } else if y_min >= 0.0 {
                         // Buffer at the top for positive distributions (5% mult).
                         Expansion {
-                            mult: (0.0, 0.05),
+                            mult: (0.0, pad),
                             add: (0.0, 0.0),
                         }
                     } else if y_max <= 0.0 {
                         // Buffer at the bottom for negative distributions (5% mult).
                         Expansion {
-                            mult: (0.05, 0.0),
+                            mult: (pad, 0.0),
                             add: (0.0, 0.0),
                         }
                     } else {
                         // Default padding for data crossing zero (usually 5% on both ends).
-                        Expansion::default()
+                        Expansion {
+                            mult: (pad, pad),
+                            ..Expansion::default()
+                        }
                     });
                 }
             }
@@ -720,7 +1299,8 @@ impl<T: Mark> Chart<T> {
         // --- 2. HALF-STEP PADDING FOR DISCRETE AXES ---
         // Categorical marks with thickness (Bar, Boxplot, Rect) need 0.5 units of padding
         // to center the marks and prevent them from clipping against axis lines.
-        let needs_discrete_padding = ["bar", "boxplot", "rect"].contains(&mt);
+        let needs_discrete_padding =
+            ["bar", "boxplot", "violin", "rect", "dotplot", "candlestick"].contains(&mt);
         if needs_discrete_padding {
             if x_enc.scale_type == Some(Scale::Discrete) && x_enc.expansion.is_none() {
                 x_enc.expansion = Some(Expansion {
@@ -736,6 +1316,27 @@ impl<T: Mark> Chart<T> {
             }
         }
 
+        // Bars on a time axis are one step (the closest pair of timestamps) wide,
+        // so they get half of that step as padding (temporal expansions are in seconds).
+        if mt == "bar" && x_enc.scale_type == Some(Scale::Temporal) && x_enc.expansion.is_none() {
+            let mut positions: Vec<f64> = self
+                .data
+                .column(&x_enc.field)?
+                .to_f64_options()
+                .into_iter()
+                .flatten()
+                .collect();
+            positions.sort_unstable_by(f64::total_cmp);
+            positions.dedup();
+            if let Some(step) = positions.windows(2).map(|w| w[1] - w[0]).reduce(f64::min) {
+                let half_step_secs = step / 2.0 / 1e9;
+                x_enc.expansion = Some(Expansion {
+                    mult: (0.0, 0.0),
+                    add: (half_step_secs, half_step_secs),
+                });
+            }
+        }
+
         // --- 3. FLUSH CONTINUOUS RECTANGLES (HEATMAPS) ---
         // Heatmaps on continuous scales should touch the edges of the plotting area.
         if mt == "rect" {
@@ -755,13 +1356,158 @@ impl<T: Mark> Chart<T> {
 
         Ok(())
     }
+
+    /// Builds the tooltip of a data row: one "field: value" line per tooltip field.
+    ///
+    /// Returns `None` without a tooltip encoding. Fields the mark's transform
+    /// did not keep are skipped. Numbers use the tooltip's format, then the
+    /// field's format in `formats`; floats default to up to four decimals.
+    pub(crate) fn tooltip_text(&self, row: usize, formats: &FormatRegistry) -> Option<String> {
+        let tooltip = self.encoding.tooltip.as_ref()?;
+        self.format_tooltip(&tooltip.fields, tooltip.format.as_ref(), row, formats)
+    }
+
+    /// Builds a tooltip of a data row from the fields of its encoded channels.
+    ///
+    /// Used by marks with an automatic tooltip when no tooltip encoding is set.
+    /// Lists the x, y, color, shape, size and text fields in that order, each
+    /// field once.
+    pub(crate) fn encoded_tooltip_text(
+        &self,
+        row: usize,
+        formats: &FormatRegistry,
+    ) -> Option<String> {
+        let mut fields: Vec<String> = Vec::new();
+        for channel in [
+            Channel::X,
+            Channel::Y,
+            Channel::Color,
+            Channel::Shape,
+            Channel::Size,
+            Channel::Text,
+        ] {
+            if let Some(field) = self.encoding.get_field_by_channel(channel)
+                && !field.is_empty()
+                && !fields.iter().any(|f| f == field)
+            {
+                fields.push(field.to_string());
+            }
+        }
+        self.format_tooltip(&fields, None, row, formats)
+    }
+
+    /// Formats one "field: value" line per field of a data row.
+    fn format_tooltip(
+        &self,
+        fields: &[String],
+        format: Option<&TickFormatter>,
+        row: usize,
+        formats: &FormatRegistry,
+    ) -> Option<String> {
+        let lines: Vec<String> = fields
+            .iter()
+            .filter(|field| self.data.schema.contains_key(*field))
+            .map(|field| {
+                let value = self.data.get(field, row);
+                let number = match value {
+                    AnyValue::Float64(v) => Some(v),
+                    AnyValue::Float32(v) => Some(v as f64),
+                    AnyValue::Int8(v) => Some(v as f64),
+                    AnyValue::Int16(v) => Some(v as f64),
+                    AnyValue::Int32(v) => Some(v as f64),
+                    AnyValue::Int64(v) => Some(v as f64),
+                    AnyValue::UInt32(v) => Some(v as f64),
+                    AnyValue::UInt64(v) => Some(v as f64),
+                    _ => None,
+                };
+                let label = number.and_then(|v| {
+                    format
+                        .and_then(|f| f.format_value(v))
+                        .or_else(|| formats.format(field, v))
+                });
+                let value = match (number, label) {
+                    (Some(v), _) if v.is_nan() => "null".into(),
+                    (_, Some(label)) => label,
+                    (Some(v), None)
+                        if matches!(value, AnyValue::Float64(_) | AnyValue::Float32(_)) =>
+                    {
+                        format_tooltip_number(v)
+                    }
+                    _ => value.to_string().unwrap_or_else(|| "null".into()),
+                };
+                format!("{}: {}", field, value)
+            })
+            .collect();
+        (!lines.is_empty()).then(|| lines.join("\n"))
+    }
+
+    /// Returns the rotation of a data row's mark in degrees, clockwise.
+    ///
+    /// Returns 0 without an angle encoding and for null angles.
+    pub(crate) fn angle_degrees(&self, row: usize) -> f64 {
+        self.encoding
+            .angle
+            .as_ref()
+            .and_then(|angle| self.data.get(&angle.field, row).to_f64())
+            .unwrap_or(0.0)
+    }
+
+    /// Returns the fill and stroke opacities of a data row, clamped to [0, 1].
+    ///
+    /// Each side takes its own encoding, then the opacity encoding mapped
+    /// through the chart's opacity scale, then `fallback` (the mark's
+    /// configured opacity) for nulls or no encoding.
+    pub(crate) fn row_opacities(
+        &self,
+        row: usize,
+        fallback: f64,
+        context: &PanelContext,
+    ) -> (f64, f64) {
+        let value = |field: Option<&String>| {
+            field
+                .and_then(|f| self.data.get(f, row).to_f64())
+                .filter(|v| !v.is_nan())
+                .map(|v| v.clamp(0.0, 1.0))
+        };
+        let shared = self.mapped_opacity(row, context).unwrap_or(fallback);
+        let fill = value(self.encoding.fill_opacity.as_ref().map(|o| &o.field)).unwrap_or(shared);
+        let stroke =
+            value(self.encoding.stroke_opacity.as_ref().map(|o| &o.field)).unwrap_or(shared);
+        (fill, stroke)
+    }
+
+    /// Maps a data row's opacity value through the chart's opacity scale.
+    ///
+    /// Returns `None` without an opacity encoding and for null values.
+    pub(crate) fn mapped_opacity(&self, row: usize, context: &PanelContext) -> Option<f64> {
+        let mapping = context.spec.aesthetics.opacity.as_ref()?;
+        let field = &self.encoding.opacity.as_ref()?.field;
+        let value = self.data.get(field, row).to_f64().filter(|v| !v.is_nan())?;
+        let scale = mapping.scale_impl.as_ref();
+        Some(scale.mapper()?.map_to_opacity(scale.normalize(value)))
+    }
+
+    /// Returns the link target of a data row.
+    ///
+    /// Returns `None` without an href encoding, for null or empty URLs, and
+    /// when the mark's transform did not keep the URL column.
+    pub(crate) fn href_url(&self, row: usize) -> Option<String> {
+        let href = self.encoding.href.as_ref()?;
+        if !self.data.schema.contains_key(&href.field) {
+            return None;
+        }
+        self.data
+            .get(&href.field, row)
+            .to_string()
+            .filter(|url| !url.is_empty())
+    }
 }
 
 // Implementation of Layer trait for Chart<T> allowing any chart to be used as a layer.
 // This follows the "Composition over Inheritance" principle.
 impl<T> Layer for Chart<T>
 where
-    T: crate::mark::Mark + Send + Sync,
+    T: crate::mark::Mark + Send + Sync + 'static,
     Chart<T>: MarkRenderer + Clone,
 {
     /// Determines if this specific layer needs coordinate axes.
@@ -770,6 +1516,11 @@ where
         self.mark.as_ref().map(|m| m.mark_type()) != Some("arc")
     }
 
+    #[cfg(feature = "vega")]
+    fn vega_layer(&self) -> Result<serde_json::Value, ChartonError> {
+        crate::core::vega::chart_layer(self)
+    }
+
     /// Retrieves the field name for a specific channel.
     /// Redirects to the central Encoding container.
     fn get_field(&self, channel: Channel) -> Option<String> {
@@ -788,6 +1539,64 @@ where
         self.encoding.get_expand_by_channel(channel)
     }
 
+    /// Retrieves the logarithm base for log-scaled channels.
+    fn get_log_base(&self, channel: Channel) -> Option<f64> {
+        self.encoding.get_log_base_by_channel(channel)
+    }
+
+    /// Retrieves the color map of an ordinal color encoding.
+    fn get_color_scheme(&self) -> Option<ColorMap> {
+        self.encoding
+            .color
+            .as_ref()
+            .filter(|c| c.order.is_some())
+            .map(|c| c.scheme.unwrap_or(ColorMap::Blues))
+    }
+
+    /// Retrieves the size floor configured on the size encoding.
+    fn get_size_floor(&self) -> Option<f64> {
+        self.encoding.size.as_ref().and_then(|s| s.floor)
+    }
+
+    /// Retrieves the opacity range configured on the opacity encoding.
+    fn get_opacity_range(&self) -> Option<(f64, f64)> {
+        self.encoding.opacity.as_ref().map(|o| o.range)
+    }
+
+    /// Reports whether the layer or its y encoding opted into the secondary axis.
+    fn uses_secondary_y(&self) -> bool {
+        self.y_axis_side == YAxisSide::Right
+            || self.encoding.y.as_ref().is_some_and(|y| y.secondary)
+    }
+
+    /// Reports whether the y encoding stacks to 100%.
+    fn stacks_normalized(&self) -> bool {
+        self.encoding
+            .y
+            .as_ref()
+            .is_some_and(|y| y.stack == StackMode::Normalize)
+    }
+
+    /// Picks the legend key matching the mark: line samples for strokes,
+    /// squares for filled marks and symbols for everything else.
+    fn legend_glyph(&self) -> LegendGlyph {
+        let Some(mark) = self.mark.as_ref() else {
+            return LegendGlyph::Symbol;
+        };
+        match mark.mark_type() {
+            "line" => LegendGlyph::Line {
+                dash: (mark as &dyn std::any::Any)
+                    .downcast_ref::<MarkLine>()
+                    .map(|l| l.dash.clone())
+                    .unwrap_or_default(),
+            },
+            "rule" | "tick" | "errorbar" => LegendGlyph::Line { dash: Vec::new() },
+            "bar" | "hist" | "area" | "rect" | "boxplot" | "violin" | "candlestick"
+            | "geo_path" => LegendGlyph::Swatch,
+            _ => LegendGlyph::Symbol,
+        }
+    }
+
     /// Calculates the raw data boundaries for any visual channel.
     ///
     /// This unified implementation supports:
@@ -817,7 +1626,7 @@ where
             // --- DISCRETE DOMAIN ---
             // Triggered if Scale is Discrete (even if data is numeric).
             Scale::Discrete => {
-                let mut labels = primary_series.unique_values();
+                let mut labels = primary_series.category_values();
                 // Define the exact internal tags used during data transformation for boxplot.
                 let boundary_tag = format!("{}_boundary", TEMP_SUFFIX);
                 let default_tag = format!("{}_default", TEMP_SUFFIX);
@@ -826,6 +1635,26 @@ where
                 // This ensures that system-injected categories remain invisible to the
                 // user-facing components like Legends and Axis labels.
                 labels.retain(|l| l != &boundary_tag && l != &default_tag);
+
+                // Ordinal colors follow the declared order rather than data appearance.
+                if channel == Channel::Color
+                    && let Some(color) = self.encoding.color.as_ref()
+                    && let Some(order) = color.order.as_ref()
+                {
+                    let unlisted: Vec<String> =
+                        labels.into_iter().filter(|l| !order.contains(l)).collect();
+                    if !unlisted.is_empty() && !color.append_unlisted {
+                        return Err(ChartonError::Encoding(format!(
+                            "Ordinal color '{}': categories [{}] are not in the declared order \
+                             (use with_append_unlisted(true) to keep them)",
+                            field_name,
+                            unlisted.join(", ")
+                        )));
+                    }
+                    labels = order.clone();
+                    labels.extend(unlisted);
+                }
+
                 Ok(ScaleDomain::Discrete(labels))
             }
 
@@ -847,9 +1676,33 @@ where
                 let is_errorbar = matches!(mark_type, Some("errorbar"));
                 let is_boxplot = matches!(mark_type, Some("boxplot"));
 
+                // Candlesticks span every OHLC field, so the price axis covers low to high.
+                if matches!(mark_type, Some("candlestick")) && channel == Channel::Y {
+                    let ohlc = [
+                        self.encoding.open.as_ref().map(|e| &e.field),
+                        self.encoding.high.as_ref().map(|e| &e.field),
+                        self.encoding.low.as_ref().map(|e| &e.field),
+                        self.encoding.close.as_ref().map(|e| &e.field),
+                    ];
+                    for col_name in ohlc.into_iter().flatten() {
+                        let (m_min, m_max) = self.data.column(col_name)?.min_max();
+                        if !m_min.is_nan() {
+                            global_min = global_min.min(m_min);
+                            found_data = true;
+                        }
+                        if !m_max.is_nan() {
+                            global_max = global_max.max(m_max);
+                            found_data = true;
+                        }
+                    }
+                }
+
                 // --- STEP 1: Priority Check for Pre-computed Columns (Area & ErrorBar & Boxplot) ---
-                if (is_area || is_errorbar || is_boxplot) && channel == Channel::Y {
-                    let y_field = &self.encoding.y.as_ref().unwrap().field;
+                if (is_area || is_errorbar || is_boxplot)
+                    && channel == Channel::Y
+                    && let Some(y_enc) = self.encoding.y.as_ref()
+                {
+                    let y_field = &y_enc.field;
                     let temp_min_col = format!("{}_{}_min", TEMP_SUFFIX, y_field);
                     let temp_max_col = format!("{}_{}_max", TEMP_SUFFIX, y_field);
 
@@ -878,19 +1731,28 @@ where
                             .is_some_and(|e| e.stack != StackMode::None)
                         && self.encoding.color.is_some();
 
-                    if is_y_stacked {
-                        let x_field = &self.encoding.x.as_ref().unwrap().field;
-                        let y_field = &self.encoding.y.as_ref().unwrap().field;
+                    if is_y_stacked
+                        && let (Some(x_enc), Some(y_enc)) =
+                            (self.encoding.x.as_ref(), self.encoding.y.as_ref())
+                    {
+                        let x_field = &x_enc.field;
+                        let y_field = &y_enc.field;
 
                         let x_series = self.data.column(x_field)?;
                         let y_series = self.data.column(y_field)?;
+                        // Dodged bars stack separately for every (x, dodge) pair.
+                        let dodge_series = x_enc
+                            .dodge_by
+                            .as_ref()
+                            .and_then(|df| self.data.column(df).ok());
 
-                        let mut stacks: AHashMap<String, f64> = AHashMap::new();
+                        let mut stacks: AHashMap<(String, Option<String>), f64> = AHashMap::new();
                         for i in 0..x_series.len() {
                             if let (Some(x_val), Some(y_val)) =
                                 (x_series.get(i).to_string(), y_series.get(i).to_f64())
                             {
-                                let entry = stacks.entry(x_val).or_insert(0.0);
+                                let d_val = dodge_series.and_then(|d| d.get(i).to_string());
+                                let entry = stacks.entry((x_val, d_val)).or_insert(0.0);
                                 *entry += y_val;
                             }
                         }
@@ -1012,4 +1874,71 @@ where
             *guard = Some(map.scale_impl.clone());
         }
     }
+
+    /// Lists the values of the facet column, including unused categories of a
+    /// categorical column so that their panels are still reserved.
+    fn facet_values(&self, field: &str) -> Option<Vec<String>> {
+        self.data
+            .column(field)
+            .or_else(|e| match &self.source {
+                Some((data, _)) => data.column(field),
+                None => Err(e),
+            })
+            .ok()
+            .map(|col| col.category_values())
+    }
+
+    /// Splits the rows by the facet column, keeping the order of first appearance.
+    /// Rows with a null facet value belong to no panel.
+    ///
+    /// Aggregating marks (bars, boxplots, …) drop columns they do not encode.
+    /// For those, the rows the mark transform started from are split instead
+    /// and each panel is transformed on its own.
+    fn facet_partition(&self, field: &str) -> Option<FacetPartition> {
+        let (data, source_encoding) = match (&self.source, self.data.column(field)) {
+            (_, Ok(_)) | (None, Err(_)) => (&self.data, None),
+            (Some((data, encoding)), Err(_)) => (data, Some(encoding)),
+        };
+        let col = data.column(field).ok()?;
+
+        let mut groups: Vec<(String, Vec<usize>)> = Vec::new();
+        let mut lookup: AHashMap<String, usize> = AHashMap::new();
+        for i in 0..data.height() {
+            let Some(key) = col.get(i).to_string() else {
+                continue;
+            };
+            let slot = *lookup.entry(key.clone()).or_insert_with(|| {
+                groups.push((key, Vec::new()));
+                groups.len() - 1
+            });
+            groups[slot].1.push(i);
+        }
+
+        groups
+            .into_iter()
+            .map(|(key, rows)| {
+                let mut part = self.clone();
+                part.data = data.take_rows(&rows).ok()?;
+                if let Some(encoding) = source_encoding {
+                    part.encoding = encoding.clone();
+                    part = part.validate_and_transform().ok()?;
+                }
+                Some((key, Arc::new(part) as Arc<dyn Layer>))
+            })
+            .collect()
+    }
+}
+
+/// Writes a tooltip number with up to four decimals and no trailing zeros.
+fn format_tooltip_number(value: f64) -> String {
+    if value.is_nan() {
+        return "null".into();
+    }
+    let label = format!("{:.4}", value);
+    let label = label.trim_end_matches('0').trim_end_matches('.');
+    if label == "-0" {
+        "0".into()
+    } else {
+        label.into()
+    }
 }
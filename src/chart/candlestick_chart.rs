@@ -0,0 +1,15 @@
+use crate::chart::Chart;
+use crate::mark::candlestick::MarkCandlestick;
+
+/// Extension implementation for `Chart` to support Candlestick Charts (MarkCandlestick).
+impl Chart<MarkCandlestick> {
+    /// Configures candlestick properties like the bull/bear colors and body width.
+    pub fn configure_candlestick<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(MarkCandlestick) -> MarkCandlestick,
+    {
+        let mark = self.mark.take().unwrap_or_default();
+        self.mark = Some(f(mark));
+        self
+    }
+}
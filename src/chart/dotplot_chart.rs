@@ -0,0 +1,18 @@
+use crate::chart::Chart;
+use crate::mark::dotplot::MarkDotPlot;
+
+/// Extension implementation for `Chart` to support Cleveland dot plots (MarkDotPlot).
+impl Chart<MarkDotPlot> {
+    /// Configures the visual properties of the dots and their guide lines.
+    ///
+    /// Sorting options are applied when the encoding is validated, so call
+    /// `configure_dotplot` before `encode` if you rely on `with_sort`.
+    pub fn configure_dotplot<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(MarkDotPlot) -> MarkDotPlot,
+    {
+        let mark = self.mark.take().unwrap_or_default();
+        self.mark = Some(f(mark));
+        self
+    }
+}
@@ -0,0 +1,51 @@
+use crate::chart::Chart;
+use crate::mark::dots::MarkDots;
+use crate::mark::histogram::HistBinning;
+
+/// Extension implementation for `Chart` to support dot histograms (MarkDots).
+///
+/// Binning is applied by `encode`, so the `with_bin_*` options must be set before it.
+impl Chart<MarkDots> {
+    /// Configures the visual properties of the dot histogram.
+    ///
+    /// The layout (stack vs. dodge) is applied while binning, so call this
+    /// before `encode` when changing it.
+    pub fn configure_dots<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(MarkDots) -> MarkDots,
+    {
+        let mark = self.mark.take().unwrap_or_default();
+        self.mark = Some(f(mark));
+        self
+    }
+
+    /// Splits the binning range into `count` equally wide bins.
+    ///
+    /// Same as the histogram's `with_bin_count`; replaces an earlier `with_bin_width`.
+    pub fn with_bin_count(self, count: usize) -> Self {
+        self.configure_dots(|mut d| {
+            d.binning = Some(HistBinning::Count(count));
+            d
+        })
+    }
+
+    /// Uses bins of a fixed `width`, counted from the lower end of the binning range.
+    ///
+    /// Same as the histogram's `with_bin_width`; replaces an earlier `with_bin_count`.
+    pub fn with_bin_width(self, width: f64) -> Self {
+        self.configure_dots(|mut d| {
+            d.binning = Some(HistBinning::Width(width));
+            d
+        })
+    }
+
+    /// Pins the extent of the bins to `[min, max]` instead of the data range.
+    ///
+    /// Values outside the range get no dot.
+    pub fn with_bin_range(self, min: f64, max: f64) -> Self {
+        self.configure_dots(|mut d| {
+            d.bin_range = Some((min, max));
+            d
+        })
+    }
+}
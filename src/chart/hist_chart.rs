@@ -1,7 +1,9 @@
 use crate::chart::Chart;
-use crate::mark::histogram::MarkHist;
+use crate::mark::histogram::{CumulativeDirection, HistBinning, MarkHist};
 
 /// Extension implementation for `Chart` to support Histograms (MarkHist).
+///
+/// Binning is applied by `encode`, so the `with_bin_*` options must be set before it.
 impl Chart<MarkHist> {
     /// Configures the visual properties of the histogram bars using a closure.
     pub fn configure_hist<F>(mut self, f: F) -> Self
@@ -12,4 +14,59 @@ impl Chart<MarkHist> {
         self.mark = Some(f(mark));
         self
     }
+
+    /// Splits the binning range into `count` equally wide bins, at most 10,000.
+    ///
+    /// Replaces an earlier `with_bin_width`.
+    pub fn with_bin_count(self, count: usize) -> Self {
+        self.configure_hist(|mut h| {
+            h.binning = Some(HistBinning::Count(count));
+            h
+        })
+    }
+
+    /// Uses bins of a fixed `width`, counted from the lower end of the binning range.
+    ///
+    /// The last bin is widened to a full bin if the range is not a multiple of
+    /// `width`, and `width` itself is widened if it would need over 10,000 bins.
+    /// Replaces an earlier `with_bin_count`.
+    pub fn with_bin_width(self, width: f64) -> Self {
+        self.configure_hist(|mut h| {
+            h.binning = Some(HistBinning::Width(width));
+            h
+        })
+    }
+
+    /// Pins the extent of the bins to `[min, max]` instead of the data range.
+    ///
+    /// Values outside the range are left out of the counts, and the x axis
+    /// spans exactly the bin edges.
+    pub fn with_bin_range(self, min: f64, max: f64) -> Self {
+        self.configure_hist(|mut h| {
+            h.bin_range = Some((min, max));
+            h
+        })
+    }
+
+    /// Accumulates the bin counts from left to right, per color group.
+    ///
+    /// Combined with `alt::y(..).with_normalize(true)` this draws a binned
+    /// empirical CDF that ends at 1.
+    pub fn with_cumulative(self, cumulative: bool) -> Self {
+        self.configure_hist(|mut h| {
+            h.cumulative = cumulative.then(|| h.cumulative.unwrap_or_default());
+            h
+        })
+    }
+
+    /// Sets the direction of a cumulative histogram, enabling accumulation.
+    ///
+    /// `CumulativeDirection::Desc` counts each bin and everything above it,
+    /// giving a survival-style curve.
+    pub fn with_cumulative_direction(self, direction: CumulativeDirection) -> Self {
+        self.configure_hist(|mut h| {
+            h.cumulative = Some(direction);
+            h
+        })
+    }
 }
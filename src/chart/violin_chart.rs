@@ -0,0 +1,15 @@
+use crate::chart::Chart;
+use crate::mark::violin::MarkViolin;
+
+/// Extension implementation for `Chart` to support Violin Plots (MarkViolin).
+impl Chart<MarkViolin> {
+    /// Configures violin properties like width, color and the median line.
+    pub fn configure_violin<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(MarkViolin) -> MarkViolin,
+    {
+        let mark = self.mark.take().unwrap_or_default();
+        self.mark = Some(f(mark));
+        self
+    }
+}
@@ -30,6 +30,14 @@ impl Rect {
     }
 }
 
+/// Which grid lines to draw, named by data axis: `x` follows the x scale even
+/// when `coord_flip()` turns it into the vertical axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GridLines {
+    pub x: bool,
+    pub y: bool,
+}
+
 /// Describes the layout preferences and geometric behaviors of a coordinate system.
 ///
 /// Different coordinate systems (Cartesian, Polar, Geo) require different
@@ -81,12 +89,14 @@ pub trait CoordinateTrait: Send + Sync {
         y_explicit: Option<&[ExplicitTick]>,
     ) -> Result<(), ChartonError>;
 
-    /// Renders the coordinate system's background grid.
+    /// Renders the coordinate system's background grid at the tick positions
+    /// of the axes selected by `lines`.
     fn render_grid_lines(
         &self,
         backend: &mut dyn RenderBackend,
         theme: &Theme,
         panel: &Rect,
+        lines: GridLines,
         x_explicit: Option<&[ExplicitTick]>,
         y_explicit: Option<&[ExplicitTick]>,
     ) -> Result<(), ChartonError>;
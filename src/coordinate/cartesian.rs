@@ -1,4 +1,4 @@
-use super::{CoordLayout, CoordinateTrait, Rect};
+use super::{CoordLayout, CoordinateTrait, GridLines, Rect};
 use crate::core::layer::RenderBackend;
 use crate::error::ChartonError;
 use crate::scale::{ExplicitTick, ScaleTrait};
@@ -64,11 +64,12 @@ impl CoordinateTrait for Cartesian2D {
         backend: &mut dyn RenderBackend,
         theme: &Theme,
         panel: &Rect,
+        lines: GridLines,
         x_explicit: Option<&[ExplicitTick]>,
         y_explicit: Option<&[ExplicitTick]>,
     ) -> Result<(), ChartonError> {
         crate::render::cartesian2d_axis_renderer::render_cartesian_grid(
-            backend, theme, panel, self, x_explicit, y_explicit,
+            backend, theme, panel, self, lines, x_explicit, y_explicit,
         )
     }
 
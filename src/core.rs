@@ -1,9 +1,17 @@
 pub mod aesthetics;
+pub mod annotation;
+pub mod chart_grid;
 pub mod composite;
 pub mod context;
 pub mod conversion;
 pub mod data;
+pub mod format;
 pub mod guide;
+pub mod inset;
 pub mod layer;
 pub mod layout;
+pub mod reference_line;
+pub mod shared_scales;
 pub mod utils;
+#[cfg(feature = "vega")]
+pub mod vega;
@@ -28,6 +28,9 @@ pub struct GlobalAesthetics {
 
     /// Mapping for the size channel (typically binned for legends).
     pub size: Option<AestheticMapping>,
+
+    /// Mapping for the opacity channel (continuous, binned for legends).
+    pub opacity: Option<AestheticMapping>,
 }
 
 impl GlobalAesthetics {
@@ -39,8 +42,14 @@ impl GlobalAesthetics {
         color: Option<AestheticMapping>,
         shape: Option<AestheticMapping>,
         size: Option<AestheticMapping>,
+        opacity: Option<AestheticMapping>,
     ) -> Self {
-        Self { color, shape, size }
+        Self {
+            color,
+            shape,
+            size,
+            opacity,
+        }
     }
 
     /// Helper to identify if a specific field is used across multiple aesthetics.
@@ -62,6 +71,11 @@ impl GlobalAesthetics {
         {
             found.push(("size", m));
         }
+        if let Some(ref m) = self.opacity
+            && m.field == field_name
+        {
+            found.push(("opacity", m));
+        }
         found
     }
 }
@@ -0,0 +1,80 @@
+use crate::mark::text::TextAnchor;
+use crate::visual::color::SingleColor;
+
+/// A text label placed at a data position of a chart.
+///
+/// The position goes through the same scales and coordinate system as the
+/// marks, so the label stays on its data point when domains, margins or the
+/// chart size change. The label can be moved away from the point by a pixel
+/// offset, optionally with a leader arrow pointing back at it.
+#[derive(Debug, Clone)]
+pub struct Annotation {
+    pub(crate) x: f64,
+    pub(crate) y: f64,
+    pub(crate) text: String,
+
+    /// Horizontal alignment of the text relative to its position.
+    pub(crate) anchor: TextAnchor,
+    /// Pixel offset of the text from the data point (positive y is downwards).
+    pub(crate) offset: (f64, f64),
+    /// Draws a leader line with an arrow head from the text to the data point.
+    pub(crate) arrow: bool,
+    /// Text and arrow color. `None` uses the theme's label color.
+    pub(crate) color: Option<SingleColor>,
+    /// Font size in pixels. `None` uses the theme's tick label size.
+    pub(crate) font_size: Option<f64>,
+}
+
+impl Annotation {
+    /// Creates a label at the data position (`x`, `y`).
+    ///
+    /// For a discrete axis, the position is the index of the category.
+    pub fn new(x: f64, y: f64, text: impl Into<String>) -> Self {
+        Self {
+            x,
+            y,
+            text: text.into(),
+            anchor: TextAnchor::Middle,
+            offset: (0.0, 0.0),
+            arrow: false,
+            color: None,
+            font_size: None,
+        }
+    }
+
+    // --- Fluent Configuration Methods (Builder Pattern) ---
+
+    /// Sets the horizontal alignment of the text ("start", "middle" or "end").
+    pub fn with_anchor(mut self, anchor: impl Into<TextAnchor>) -> Self {
+        self.anchor = anchor.into();
+        self
+    }
+
+    /// Moves the text by (`dx`, `dy`) pixels away from the data point.
+    ///
+    /// Positive `dy` moves the text down, as in screen coordinates.
+    pub const fn with_offset(mut self, dx: f64, dy: f64) -> Self {
+        self.offset = (dx, dy);
+        self
+    }
+
+    /// Draws an arrow from the text to the data point.
+    ///
+    /// Only visible together with an offset that moves the text off the point.
+    pub const fn with_arrow(mut self, arrow: bool) -> Self {
+        self.arrow = arrow;
+        self
+    }
+
+    /// Sets the color of the text and the arrow.
+    pub fn with_color(mut self, color: impl Into<SingleColor>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the font size of the text in pixels.
+    pub const fn with_font_size(mut self, size: f64) -> Self {
+        self.font_size = Some(size);
+        self
+    }
+}
@@ -0,0 +1,366 @@
+use crate::Precision;
+use crate::coordinate::Rect;
+use crate::core::composite::LayeredChart;
+use crate::core::context::{ChartSpec, PanelContext};
+use crate::core::guide::LegendPosition;
+use crate::core::layer::{RectConfig, RenderBackend, TextConfig};
+use crate::error::ChartonError;
+use crate::render::backend::offset::OffsetBackend;
+use crate::theme::Theme;
+
+/// A chart placed in a `ChartGrid`, covering one or more cells.
+#[derive(Clone)]
+struct GridCell {
+    chart: LayeredChart,
+    row: usize,
+    col: usize,
+    rowspan: usize,
+    colspan: usize,
+}
+
+/// A figure that arranges independent charts on a rows x columns grid.
+///
+/// Each child is laid out at the pixel size of the cells it covers and drawn at
+/// that position, so a chart can span several rows or columns (e.g. a wide
+/// overview above two detail views). Children keep their own scales and
+/// themes; only the figure title, the background and the optional shared
+/// legend belong to the grid.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// ChartGrid::new(2, 2)
+///     .place_span(overview, 0, 0, 1, 2)
+///     .place(left, 1, 0)
+///     .place(right, 1, 1)
+///     .with_gap(12.0)
+///     .with_title("Dashboard")
+///     .save("dashboard.svg")?;
+/// ```
+#[derive(Clone)]
+pub struct ChartGrid {
+    rows: usize,
+    cols: usize,
+    cells: Vec<GridCell>,
+
+    width: u32,
+    height: u32,
+    /// Space in pixels between neighbouring cells.
+    gap: f64,
+    title: Option<String>,
+    /// Figure-level styling: background, title font and shared legend.
+    theme: Theme,
+    /// Draws the legend of the first child once, to the right of the grid,
+    /// instead of a legend inside every child.
+    shared_legend: bool,
+}
+
+impl ChartGrid {
+    /// Creates an empty grid with the given number of rows and columns (at least one each).
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Self {
+            rows: if rows == 0 { 1 } else { rows },
+            cols: if cols == 0 { 1 } else { cols },
+            cells: Vec::new(),
+            width: 800,
+            height: 600,
+            gap: 10.0,
+            title: None,
+            theme: Theme::default(),
+            shared_legend: false,
+        }
+    }
+
+    // --- Fluent Configuration Methods (Builder Pattern) ---
+
+    /// Places a chart in a single cell.
+    pub fn place<C: Into<LayeredChart>>(self, chart: C, row: usize, col: usize) -> Self {
+        self.place_span(chart, row, col, 1, 1)
+    }
+
+    /// Places a chart whose top-left cell is (`row`, `col`) and which covers
+    /// `rowspan` rows and `colspan` columns.
+    pub fn place_span<C: Into<LayeredChart>>(
+        mut self,
+        chart: C,
+        row: usize,
+        col: usize,
+        rowspan: usize,
+        colspan: usize,
+    ) -> Self {
+        self.cells.push(GridCell {
+            chart: chart.into(),
+            row,
+            col,
+            rowspan: rowspan.max(1),
+            colspan: colspan.max(1),
+        });
+        self
+    }
+
+    /// Sets the space in pixels between neighbouring cells.
+    pub const fn with_gap(mut self, px: f64) -> Self {
+        self.gap = px;
+        self
+    }
+
+    /// Sets the overall figure size in pixels.
+    pub const fn with_size(mut self, width: u32, height: u32) -> Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    /// Sets the figure title, drawn once above all cells.
+    pub fn with_title<S: Into<String>>(mut self, title: S) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets the figure theme (background, title and shared legend styling).
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Replaces the per-chart legends with a single legend to the right of the grid,
+    /// taken from the first placed chart.
+    pub const fn with_shared_legend(mut self, shared: bool) -> Self {
+        self.shared_legend = shared;
+        self
+    }
+
+    // --- Layout ---
+
+    /// Height reserved above the cells for the figure title.
+    fn title_height(&self) -> f64 {
+        if self.title.is_some() {
+            self.theme.title_size * 2.5
+        } else {
+            0.0
+        }
+    }
+
+    /// Computes the pixel rect of every placed chart inside `area`.
+    fn resolve_cells(&self, area: &Rect) -> Result<Vec<Rect>, ChartonError> {
+        let gap = self.gap.max(0.0);
+        let cell_w = ((area.width - gap * (self.cols - 1) as f64) / self.cols as f64).max(1.0);
+        let cell_h = ((area.height - gap * (self.rows - 1) as f64) / self.rows as f64).max(1.0);
+
+        self.cells
+            .iter()
+            .map(|c| {
+                if c.row + c.rowspan > self.rows || c.col + c.colspan > self.cols {
+                    return Err(ChartonError::Render(format!(
+                        "ChartGrid: chart at ({}, {}) spanning {}x{} does not fit in a {}x{} grid",
+                        c.row, c.col, c.rowspan, c.colspan, self.rows, self.cols
+                    )));
+                }
+                Ok(Rect::new(
+                    area.x + c.col as f64 * (cell_w + gap),
+                    area.y + c.row as f64 * (cell_h + gap),
+                    cell_w * c.colspan as f64 + gap * (c.colspan - 1) as f64,
+                    cell_h * c.rowspan as f64 + gap * (c.rowspan - 1) as f64,
+                ))
+            })
+            .collect()
+    }
+
+    // --- Rendering ---
+
+    /// Renders the title, every placed chart and the optional shared legend.
+    pub fn render<B: RenderBackend>(&self, backend: &mut B) -> Result<(), ChartonError> {
+        let w = self.width as f64;
+        let h = self.height as f64;
+        let top = self.title_height();
+
+        // The shared legend is resolved from the first chart that has one, laid out
+        // in a column to the right of the cells.
+        let mut legend = None;
+        if self.shared_legend {
+            for cell in &self.cells {
+                let scene = cell.chart.resolve_scene()?;
+                if !scene.3.is_empty() {
+                    legend = Some((scene, &cell.chart.formats));
+                    break;
+                }
+            }
+        }
+        let mut legend_theme = self.theme.clone();
+        legend_theme.legend_position = LegendPosition::Right;
+        let legend_w = legend.as_ref().map_or(0.0, |((_, _, _, specs, _), _)| {
+            let blocks = specs
+                .iter()
+                .map(|s| s.estimate_size(&legend_theme, h - top).width)
+                .fold(0.0, f64::max);
+            blocks + legend_theme.legend_margin * 2.0
+        });
+
+        let area = Rect::new(0.0, top, (w - legend_w).max(1.0), (h - top).max(1.0));
+        let rects = self.resolve_cells(&area)?;
+
+        if let Some(title) = &self.title {
+            backend.draw_text(TextConfig {
+                x: (w / 2.0) as Precision,
+                y: (top / 2.0) as Precision,
+                text: title.clone(),
+                font_size: self.theme.title_size as Precision,
+                font_family: self.theme.title_family.clone(),
+                color: self.theme.title_color,
+                text_anchor: "middle".to_string(),
+                dominant_baseline: "middle".into(),
+                font_weight: self.theme.title_font_weight.to_string(),
+                font_style: self.theme.title_font_style.to_string(),
+                opacity: 1.0,
+                angle: 0.0,
+                direction: self.theme.text_direction.as_str().to_string(),
+                writing_mode: "horizontal-tb".to_string(),
+            });
+        }
+
+        for (cell, rect) in self.cells.iter().zip(&rects) {
+            let mut chart = cell.chart.clone();
+            chart.width = rect.width.round().max(1.0) as u32;
+            chart.height = rect.height.round().max(1.0) as u32;
+            if self.shared_legend {
+                // Also frees the space the child would otherwise reserve for its legend.
+                chart.theme.show_legend = false;
+                chart.theme.legend_position = LegendPosition::None;
+            }
+
+            let mut offset = OffsetBackend::new(backend, rect.x, rect.y);
+            chart.render(&mut offset)?;
+        }
+
+        if let Some(((coord, _, aesthetics, specs, _), formats)) = &legend {
+            // The legend keeps the number formats of the chart it comes from.
+            let spec = ChartSpec::new(aesthetics, &legend_theme).with_formats(formats);
+            let ctx = PanelContext::new(&spec, coord.clone(), area);
+            crate::render::legend_renderer::LegendRenderer::render_legend(
+                backend,
+                specs,
+                &legend_theme,
+                &ctx,
+                (area.x + area.width + legend_theme.legend_margin, area.y),
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Generates and returns the SVG representation of the whole grid.
+    pub fn to_svg(&self) -> Result<String, ChartonError> {
+        let mut svg_content = String::new();
+        svg_content.push_str(&format!(
+            r#"<svg width="{}" height="{}" viewBox="0 0 {} {}" xmlns="http://www.w3.org/2000/svg">"#,
+            self.width, self.height, self.width, self.height
+        ));
+
+        {
+            let mut backend = crate::render::backend::svg::SvgBackend::new(&mut svg_content);
+            backend.draw_rect(self.background());
+            self.render(&mut backend)?;
+        }
+
+        svg_content.push_str("</svg>");
+        Ok(svg_content)
+    }
+
+    /// Generates and returns a single-page PDF of the whole grid as a byte vector.
+    #[cfg(feature = "pdf")]
+    pub fn to_pdf(&self) -> Result<Vec<u8>, ChartonError> {
+        crate::core::utils::svg_to_pdf(&self.to_svg()?)
+    }
+
+    /// Generates and returns a PNG representation of the whole grid as a byte vector.
+    #[cfg(feature = "raster")]
+    pub fn to_png(&self) -> Result<Vec<u8>, ChartonError> {
+        // Children are rendered at the grid's resolution, so the first chart's
+        // scale factor applies to the whole figure.
+        let scale_factor = self.cells.first().map_or(2.0, |c| c.chart.scale_factor);
+
+        let mut pixmap =
+            crate::render::backend::raster::allocate_pixmap(self.width, self.height, scale_factor)?;
+
+        {
+            let mut backend =
+                crate::render::backend::raster::RasterBackend::new(&mut pixmap, scale_factor);
+            backend.draw_rect(self.background());
+            self.render(&mut backend)?;
+        }
+
+        crate::render::backend::raster::encode_png(&pixmap, scale_factor)
+    }
+
+    /// Saves the grid to a file; the format is taken from the extension (svg, png or pdf).
+    pub fn save<P: AsRef<std::path::Path>>(&self, path: P) -> Result<(), ChartonError> {
+        let path_obj = path.as_ref();
+
+        if let Some(parent) = path_obj.parent().filter(|p| !p.exists()) {
+            std::fs::create_dir_all(parent).map_err(ChartonError::Io)?;
+        }
+
+        let ext = path_obj
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(|s| s.to_lowercase());
+
+        match ext.as_deref() {
+            Some("svg") => {
+                std::fs::write(path_obj, self.to_svg()?).map_err(ChartonError::Io)?;
+            }
+            Some("png") => {
+                #[cfg(feature = "raster")]
+                {
+                    std::fs::write(path_obj, self.to_png()?).map_err(ChartonError::Io)?;
+                }
+                #[cfg(not(feature = "raster"))]
+                {
+                    return Err(ChartonError::Unimplemented(
+                        "PNG export is disabled. Please enable the 'raster' feature (or its alias 'png')"
+                            .to_string(),
+                    ));
+                }
+            }
+            Some("pdf") => {
+                #[cfg(feature = "pdf")]
+                {
+                    std::fs::write(path_obj, self.to_pdf()?).map_err(ChartonError::Io)?;
+                }
+                #[cfg(not(feature = "pdf"))]
+                {
+                    return Err(ChartonError::Unimplemented(
+                        "PDF support is disabled. Please enable the 'pdf' feature".to_string(),
+                    ));
+                }
+            }
+            Some(format) => {
+                return Err(ChartonError::Unimplemented(format!(
+                    "Output format '{}' is not supported",
+                    format
+                )));
+            }
+            None => {
+                return Err(ChartonError::Unimplemented(
+                    "Output format could not be determined from file extension".to_string(),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Full-canvas background rect in the figure theme's color.
+    fn background(&self) -> RectConfig {
+        RectConfig {
+            x: 0.0,
+            y: 0.0,
+            width: self.width as Precision,
+            height: self.height as Precision,
+            fill: self.theme.background_color,
+            stroke: "none".into(),
+            stroke_width: 0.0,
+            opacity: 1.0,
+        }
+    }
+}
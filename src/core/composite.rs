@@ -1,17 +1,35 @@
+#![deny(clippy::unwrap_used, clippy::expect_used)]
+
 use crate::Precision;
 use crate::chart::Chart;
-use crate::coordinate::{CoordSystem, CoordinateTrait, Rect};
+use crate::coordinate::{CoordSystem, CoordinateTrait, GridLines, Rect};
 use crate::core::aesthetics::AestheticMapping;
 use crate::core::aesthetics::GlobalAesthetics;
+use crate::core::annotation::Annotation;
 use crate::core::context::{ChartSpec, PanelContext};
-use crate::core::guide::GuideSpec;
-use crate::core::layer::{Layer, RectConfig, RenderBackend, TextConfig};
+use crate::core::format::FormatRegistry;
+use crate::core::guide::{GuideSpec, LegendPosition};
+use crate::core::inset::InsetSpec;
+use crate::core::layer::{
+    Layer, LineConfig, PathConfig, PathTopology, PolygonConfig, RectConfig, RenderBackend,
+    TextConfig,
+};
+use crate::core::layout::{AxisLayoutConstraints, LegendLayoutConstraints};
+use crate::core::reference_line::{ReferenceAxis, ReferenceLine};
+use crate::core::shared_scales::{SharedScale, SharedScales, report_overflow};
 use crate::encode::Channel;
 use crate::error::ChartonError;
+use crate::facets::{Facet, FacetStrip};
+use crate::mark::text::TextAnchor;
 use crate::scale::{
-    Expansion, ExplicitTick, Scale, ScaleDomain, create_scale, mapper::VisualMapper,
+    Expansion, ExplicitTick, Scale, ScaleDomain, ScaleTrait, create_scale,
+    inverse::{InverseLabelScale, InverseTransform, TickFormatter},
+    mapper::VisualMapper,
+    reversed::ReversedScale,
+    temporal::check_date_ticks,
 };
 use crate::theme::Theme;
+use crate::visual::color::{ColorPalette, PaletteOverflow, SingleColor};
 use std::sync::Arc;
 
 /// A complete specification for a visual channel before the final Scale object is created.
@@ -20,6 +38,8 @@ pub struct ResolvedSpec {
     pub scale_type: Scale,
     pub domain: ScaleDomain,
     pub expand: Expansion,
+    /// Logarithm base, only meaningful for `Scale::Log`.
+    pub log_base: f64,
 }
 
 /// `LayeredChart` is the central orchestrator of the visualization.
@@ -44,6 +64,10 @@ pub struct LayeredChart {
     // --- Content ---
     /// The text content of the chart title. Styled by `theme.title_size/color`.
     pub(crate) title: Option<String>,
+    /// The URL the title links to in SVG output.
+    pub(crate) title_href: Option<String>,
+    /// Extra attributes of the root `<svg>` element, in insertion order.
+    pub(crate) metadata: Vec<(String, String)>,
     /// The collection of plot layers (points, lines, bars, etc.).
     pub(crate) layers: Vec<Arc<dyn Layer>>,
     /// The logical coordinate system (e.g., Cartesian, Polar).
@@ -52,6 +76,9 @@ pub struct LayeredChart {
     /// User-defined override to show/hide grid lines.
     /// If `None`, `theme.show_grid_lines` takes precedence.
     pub(crate) show_grid: Option<bool>,
+    /// Per-axis overrides of `show_grid` for the x and y grid lines.
+    pub(crate) x_grid: Option<bool>,
+    pub(crate) y_grid: Option<bool>,
 
     // --- Layout Overrides (The "Override" Pattern) ---
     /// Manual override for chart margins [top, right, bottom, left].
@@ -71,6 +98,8 @@ pub struct LayeredChart {
     pub(crate) x_expand: Option<Expansion>,
     /// Explicit ticks for the X-axis.
     pub(crate) x_ticks: Option<Vec<ExplicitTick>>,
+    /// strftime-style label format for a temporal X-axis (e.g., "%Y-%m").
+    pub(crate) x_time_format: Option<String>,
 
     /// User-defined range for the Y-axis.
     pub(crate) y_domain: Option<ScaleDomain>,
@@ -80,12 +109,33 @@ pub struct LayeredChart {
     pub(crate) y_expand: Option<Expansion>,
     /// Explicit ticks for the Y-axis.
     pub(crate) y_ticks: Option<Vec<ExplicitTick>>,
+    /// strftime-style label format for a temporal Y-axis.
+    pub(crate) y_time_format: Option<String>,
+    /// Maps pre-transformed X and Y values back to original units in tick labels.
+    pub(crate) x_tick_inverse: Option<InverseTransform>,
+    pub(crate) y_tick_inverse: Option<InverseTransform>,
+    /// Whether the X and Y axes run from their maximum to their minimum.
+    pub(crate) x_reversed: bool,
+    pub(crate) y_reversed: bool,
+    /// Number formats of the axes, the colorbar and data fields, applied
+    /// wherever values are written as text.
+    pub(crate) formats: FormatRegistry,
+
+    /// User-defined range for the secondary (right-hand) Y-axis.
+    pub(crate) y2_domain: Option<ScaleDomain>,
+    /// Explicit title for the secondary Y-axis.
+    pub(crate) y2_label: Option<String>,
 
     /// User-defined domain for the Color channel (legend).
     pub(crate) color_domain: Option<ScaleDomain>,
+    /// Explicit colors for the categories of the color domain, by position.
+    pub(crate) color_range: Option<Vec<SingleColor>>,
     /// Explicit title for the Color legend.
     pub(crate) color_label: Option<String>,
     pub(crate) color_expand: Option<Expansion>,
+    /// Policy for discrete colors beyond the end of the palette. If `None`,
+    /// the palette's own default is used.
+    pub(crate) palette_overflow: Option<PaletteOverflow>,
 
     pub(crate) shape_domain: Option<ScaleDomain>,
     pub(crate) shape_label: Option<String>,
@@ -104,9 +154,31 @@ pub struct LayeredChart {
     pub(crate) polar_start_angle: Option<f64>,
     pub(crate) polar_end_angle: Option<f64>,
     pub(crate) polar_inner_radius: Option<f64>,
+    /// Text drawn at the center of a polar panel, e.g. the total of a donut.
+    pub(crate) center_text: Option<String>,
 
     // The device pixel ratio for raster rendering. Defaults to 2.0.
     pub(crate) scale_factor: f32,
+
+    /// The JPEG compression quality, from 1 to 100. Defaults to 90.
+    pub(crate) jpeg_quality: u8,
+
+    /// Text labels anchored at data positions, drawn above the marks.
+    pub(crate) annotations: Vec<Annotation>,
+
+    /// Lines at fixed data values, drawn over the marks and clipped to the panel.
+    pub(crate) reference_lines: Vec<ReferenceLine>,
+
+    /// Charts drawn inside the main panel, each with its own placement and scales.
+    pub(crate) insets: Vec<(LayeredChart, InsetSpec)>,
+
+    /// Splits the panel into small multiples, one per value of a data column
+    /// (`FacetWrap`) or per combination of two columns (`FacetGrid`).
+    pub(crate) facet: Option<Arc<dyn Facet + Send + Sync>>,
+
+    /// Scales captured from another chart. Covered channels use them as-is
+    /// instead of consolidating this chart's own data.
+    pub(crate) shared_scales: Option<SharedScales>,
 }
 
 impl Default for LayeredChart {
@@ -123,11 +195,15 @@ impl LayeredChart {
 
             theme: Theme::default(),
             title: None,
+            title_href: None,
+            metadata: Vec::new(),
 
             layers: Vec::new(),
             coord_system: CoordSystem::default(),
 
             show_grid: None,
+            x_grid: None,
+            y_grid: None,
 
             top_margin: None,
             right_margin: None,
@@ -139,15 +215,26 @@ impl LayeredChart {
             x_label: None,
             x_expand: None,
             x_ticks: None,
+            x_time_format: None,
 
             y_domain: None,
             y_label: None,
             y_expand: None,
             y_ticks: None,
+            y_time_format: None,
+            x_tick_inverse: None,
+            y_tick_inverse: None,
+            x_reversed: false,
+            y_reversed: false,
+            formats: FormatRegistry::default(),
+            y2_domain: None,
+            y2_label: None,
 
             color_domain: None,
+            color_range: None,
             color_label: None,
             color_expand: None,
+            palette_overflow: None,
 
             shape_domain: None,
             shape_label: None,
@@ -162,8 +249,19 @@ impl LayeredChart {
             polar_start_angle: None,
             polar_end_angle: None,
             polar_inner_radius: None,
+            center_text: None,
 
             scale_factor: 2.0,
+            jpeg_quality: 90,
+
+            annotations: Vec::new(),
+            reference_lines: Vec::new(),
+
+            insets: Vec::new(),
+
+            facet: None,
+
+            shared_scales: None,
         }
     }
 
@@ -178,9 +276,38 @@ impl LayeredChart {
     /// 3. **Consolidation**: Merges domains (min/max or unique labels) and expands requirements.
     /// 4. **Override**: Applies explicit user settings (highest priority).
     /// 5. **Finalization**: Applies smart defaults for edge cases (e.g., zero-range data).
+    ///
+    /// For the Y channel, only the layers on the primary axis take part; see
+    /// [`LayeredChart::resolve_secondary_y_spec`] for the others.
     pub fn resolve_scale_spec(
         &self,
         channel: Channel,
+    ) -> Result<Option<ResolvedSpec>, ChartonError> {
+        // Shared scales replace the whole pipeline for the channels they cover.
+        if let Some(shared) = self.shared_scales.as_ref().and_then(|s| s.get(channel)) {
+            return self.resolve_shared_spec(channel, shared).map(Some);
+        }
+        self.resolve_layer_spec(channel, false)
+    }
+
+    /// Resolves the secondary Y-axis from the layers whose y encoding opted into it.
+    ///
+    /// Its domain is consolidated separately from the primary axis and only
+    /// honors the `y2` overrides. Returns `None` if no layer uses it.
+    pub fn resolve_secondary_y_spec(&self) -> Result<Option<ResolvedSpec>, ChartonError> {
+        if !self.layers.iter().any(|l| l.uses_secondary_y()) {
+            return Ok(None);
+        }
+        self.resolve_layer_spec(Channel::Y, true)
+    }
+
+    /// Consolidates a channel from the layers' data and the user overrides.
+    ///
+    /// `secondary` selects the layers (and overrides) of the secondary Y-axis.
+    fn resolve_layer_spec(
+        &self,
+        channel: Channel,
+        secondary: bool,
     ) -> Result<Option<ResolvedSpec>, ChartonError> {
         // --- Accumulators for Data Inference ---
         let mut inferred_field: Option<String> = None;
@@ -197,9 +324,13 @@ impl LayeredChart {
         let mut max_mult = (0.0f64, 0.0f64);
         let mut max_add = (0.0f64, 0.0f64);
         let mut has_expansion_info = false;
+        let mut log_base: Option<f64> = None;
 
         // --- Step 1: Scan Layers ---
         for (i, layer) in self.layers.iter().enumerate() {
+            if channel == Channel::Y && layer.uses_secondary_y() != secondary {
+                continue; // The layer belongs to the other Y-axis
+            }
             let (field, current_type) = match (layer.get_field(channel), layer.get_scale(channel)) {
                 (Some(f), Some(t)) => (f, t),
                 _ => continue, // Layer does not participate in this channel
@@ -219,7 +350,7 @@ impl LayeredChart {
             }
 
             // Consolidate Domain Data
-            match layer.get_data_bounds(channel)? {
+            match self.position_bounds(layer, channel)? {
                 ScaleDomain::Continuous(min, max) => {
                     cont_min = cont_min.min(min);
                     cont_max = cont_max.max(max);
@@ -237,6 +368,11 @@ impl LayeredChart {
                 }
             }
 
+            // The first layer requesting a log base decides it for the shared scale.
+            if log_base.is_none() {
+                log_base = layer.get_log_base(channel);
+            }
+
             // Consolidate Expansion Requirements
             if let Some(layer_expand) = layer.get_expand(channel) {
                 max_mult.0 = max_mult.0.max(layer_expand.mult.0);
@@ -249,6 +385,7 @@ impl LayeredChart {
 
         // --- Step 2: Retrieve User Overrides ---
         let (manual_domain, manual_label, manual_expand) = match channel {
+            Channel::Y if secondary => (self.y2_domain.clone(), self.y2_label.clone(), None),
             Channel::X => (self.x_domain.clone(), self.x_label.clone(), self.x_expand),
             Channel::Y => (self.y_domain.clone(), self.y_label.clone(), self.y_expand),
             Channel::Color => (
@@ -258,7 +395,7 @@ impl LayeredChart {
             ),
             Channel::Shape => (self.shape_domain.clone(), None, self.shape_expand),
             Channel::Size => (self.size_domain.clone(), None, self.size_expand),
-            Channel::Text | Channel::PathGroup => (None, None, None),
+            Channel::Text | Channel::PathGroup | Channel::Opacity => (None, None, None),
         };
 
         // --- Step 3: Final Reconciliation ---
@@ -279,9 +416,36 @@ impl LayeredChart {
             .or(inferred_field)
             .unwrap_or_else(|| format!("{:?}", channel));
 
+        let log_base = log_base.unwrap_or(10.0);
+
         // C. Resolve Domain (Priority: Manual > Consolidated)
         let domain = if let Some(d) = manual_domain {
-            d
+            match d {
+                // Categories missing from a custom order are kept after it.
+                ScaleDomain::Discrete(mut labels) => {
+                    if scale_type != Scale::Discrete {
+                        return Err(ChartonError::Scale(format!(
+                            "{:?}: a category order needs a discrete scale, but '{}' is {:?}",
+                            channel, field, scale_type
+                        )));
+                    }
+                    let unlisted: Vec<String> = all_labels
+                        .into_iter()
+                        .filter(|l| !labels.contains(l))
+                        .collect();
+                    if channel == Channel::Color && !unlisted.is_empty() {
+                        eprintln!(
+                            "Color domain: categories [{}] of '{}' are not in the domain; \
+                             they are colored from the palette.",
+                            unlisted.join(", "),
+                            field
+                        );
+                    }
+                    labels.extend(unlisted);
+                    ScaleDomain::Discrete(labels)
+                }
+                d => d,
+            }
         } else {
             match scale_type {
                 Scale::Discrete => {
@@ -294,17 +458,32 @@ impl LayeredChart {
                     if temp_min == i64::MAX || temp_max == i64::MIN {
                         return Ok(None);
                     }
-                    ScaleDomain::Temporal(temp_min, temp_max)
+                    if temp_min == temp_max {
+                        // A single timestamp gets one day on each side.
+                        let day_ns = 86_400_000_000_000i64;
+                        ScaleDomain::Temporal(
+                            temp_min.saturating_sub(day_ns),
+                            temp_max.saturating_add(day_ns),
+                        )
+                    } else {
+                        ScaleDomain::Temporal(temp_min, temp_max)
+                    }
                 }
                 _ => {
                     if cont_min.is_infinite() {
                         ScaleDomain::Continuous(0.0, 1.0)
                     } else {
-                        // Zero-range Protection
+                        // Zero-range Protection, applied to the consolidated domain so a
+                        // layer of identical values never pads a layer with a real range.
                         let (mut min, mut max) = (cont_min, cont_max);
-                        if (max - min).abs() < 1e-12 {
-                            min -= 0.5;
-                            max += 0.5;
+                        if is_degenerate_range(min, max) {
+                            if scale_type == Scale::Log {
+                                // Half a power of the base on each side keeps the domain positive.
+                                min /= log_base.sqrt();
+                                max *= log_base.sqrt();
+                            } else {
+                                (min, max) = pad_degenerate_range(min, max);
+                            }
                         }
                         ScaleDomain::Continuous(min, max)
                     }
@@ -326,7 +505,7 @@ impl LayeredChart {
                 // Non-positional channels (Color, Size, Shape) map data points directly
                 // to visual identities and typically require zero padding to maintain
                 // mathematical limits (e.g., full color scale range).
-                Channel::Color | Channel::Size | Channel::Shape => Expansion {
+                Channel::Color | Channel::Size | Channel::Shape | Channel::Opacity => Expansion {
                     mult: (0.0, 0.0),
                     add: (0.0, 0.0),
                 },
@@ -351,9 +530,212 @@ impl LayeredChart {
             scale_type,
             domain,
             expand,
+            log_base,
         }))
     }
 
+    /// The data bounds of a layer along `channel`.
+    ///
+    /// In a faceted chart the positional bounds come from the facet panels, as a
+    /// layer transformed per panel (e.g. bars aggregated within each panel) can
+    /// reach values its unfaceted data does not. Discrete labels keep the order
+    /// of the whole layer.
+    fn position_bounds(
+        &self,
+        layer: &Arc<dyn Layer>,
+        channel: Channel,
+    ) -> Result<ScaleDomain, ChartonError> {
+        let whole = layer.get_data_bounds(channel)?;
+        let Some(facet) = self.facet.as_ref() else {
+            return Ok(whole);
+        };
+        if !matches!(channel, Channel::X | Channel::Y) {
+            return Ok(whole);
+        }
+
+        let mut merged: Option<ScaleDomain> = None;
+        for (_, part) in split_facets(layer.clone(), &facet.fields()) {
+            let bounds = part.get_data_bounds(channel)?;
+            merged = Some(match (merged, bounds) {
+                (None, b) => b,
+                (Some(ScaleDomain::Continuous(a, b)), ScaleDomain::Continuous(c, d)) => {
+                    ScaleDomain::Continuous(a.min(c), b.max(d))
+                }
+                (Some(ScaleDomain::Temporal(a, b)), ScaleDomain::Temporal(c, d)) => {
+                    ScaleDomain::Temporal(a.min(c), b.max(d))
+                }
+                (Some(m), _) => m,
+            });
+        }
+
+        Ok(match (whole, merged) {
+            (ScaleDomain::Discrete(mut labels), Some(ScaleDomain::Discrete(extra))) => {
+                for label in extra {
+                    if !labels.contains(&label) {
+                        labels.push(label);
+                    }
+                }
+                ScaleDomain::Discrete(labels)
+            }
+            (ScaleDomain::Discrete(labels), _) => ScaleDomain::Discrete(labels),
+            (whole, merged) => merged.unwrap_or(whole),
+        })
+    }
+
+    /// Builds the spec of a channel covered by a shared scale.
+    ///
+    /// The layers only have to agree with the shared scale type; their data is
+    /// checked against the shared domain but never widens it.
+    fn resolve_shared_spec(
+        &self,
+        channel: Channel,
+        shared: &SharedScale,
+    ) -> Result<ResolvedSpec, ChartonError> {
+        let mut inferred_field: Option<String> = None;
+
+        for (i, layer) in self.layers.iter().enumerate() {
+            if channel == Channel::Y && layer.uses_secondary_y() {
+                continue;
+            }
+            let (field, current_type) = match (layer.get_field(channel), layer.get_scale(channel)) {
+                (Some(f), Some(t)) => (f, t),
+                _ => continue,
+            };
+
+            if current_type != shared.scale_type {
+                return Err(ChartonError::Scale(format!(
+                    "{:?} scale conflict: the shared scale is {:?}, but layer {} is {:?}",
+                    channel, shared.scale_type, i, current_type
+                )));
+            }
+
+            report_overflow(channel, i, &layer.get_data_bounds(channel)?, &shared.domain);
+            inferred_field.get_or_insert(field);
+        }
+
+        let manual_label = match channel {
+            Channel::X => self.x_label.clone(),
+            Channel::Y => self.y_label.clone(),
+            Channel::Color => self.color_label.clone(),
+            _ => None,
+        };
+
+        Ok(ResolvedSpec {
+            field: manual_label
+                .or(inferred_field)
+                .unwrap_or_else(|| shared.field.clone()),
+            scale_type: shared.scale_type,
+            domain: shared.domain.clone(),
+            expand: shared.expand,
+            log_base: shared.log_base,
+        })
+    }
+
+    /// Resolves which grid lines to draw.
+    ///
+    /// Per-axis settings win over `with_grid`, which wins over the theme.
+    fn grid_lines(&self) -> GridLines {
+        let all = self.show_grid.unwrap_or(self.theme.show_grid);
+        GridLines {
+            x: self.x_grid.unwrap_or(all),
+            y: self.y_grid.unwrap_or(all),
+        }
+    }
+
+    /// Chooses the mapper that turns normalized color values into colors.
+    ///
+    /// A shared color mapper wins; otherwise an ordinal scheme requested by a
+    /// layer, and finally the theme's palette or color map.
+    pub(crate) fn resolve_color_mapper(
+        &self,
+        spec: &ResolvedSpec,
+    ) -> Result<VisualMapper, ChartonError> {
+        if let Some(mapper) = self
+            .shared_scales
+            .as_ref()
+            .and_then(|s| s.color_mapper.as_ref())
+        {
+            return Ok(mapper.clone());
+        }
+
+        if let (Some(range), ScaleDomain::Discrete(labels)) = (&self.color_range, &spec.domain)
+            && spec.scale_type == Scale::Discrete
+        {
+            return Ok(self.custom_color_mapper(&spec.field, labels, range));
+        }
+
+        let scheme = self.layers.iter().find_map(|l| l.get_color_scheme());
+        Ok(match scheme {
+            Some(map) if spec.scale_type == Scale::Discrete => VisualMapper::OrdinalColor { map },
+            _ => {
+                if let ScaleDomain::Discrete(labels) = &spec.domain {
+                    self.check_palette_overflow(&spec.field, labels)?;
+                }
+                VisualMapper::new_color_default(&spec.scale_type, &self.theme)
+                    .with_palette_overflow(self.palette_overflow)
+            }
+        })
+    }
+
+    /// Builds a discrete color mapper that gives each category its color from
+    /// `with_color_range`.
+    ///
+    /// Range colors pair up with the custom color domain, or with the resolved
+    /// categories when no domain is set. Categories left without a color take
+    /// the palette colors in turn.
+    fn custom_color_mapper(
+        &self,
+        field: &str,
+        labels: &[String],
+        range: &[SingleColor],
+    ) -> VisualMapper {
+        let keys = match &self.color_domain {
+            Some(ScaleDomain::Discrete(domain)) => domain.as_slice(),
+            _ => labels,
+        };
+        let range_color = |label: &String| {
+            keys.iter()
+                .position(|k| k == label)
+                .and_then(|i| range.get(i))
+                .copied()
+        };
+
+        let fallbacks: Vec<&String> = labels.iter().filter(|l| range_color(l).is_none()).collect();
+        // Categories outside the domain were already reported while resolving it.
+        let uncolored: Vec<&str> = fallbacks
+            .iter()
+            .filter(|l| keys.contains(l))
+            .map(|l| l.as_str())
+            .collect();
+        if !uncolored.is_empty() {
+            eprintln!(
+                "Color range: categories [{}] of '{}' have no color in the range; \
+                 they are colored from the palette.",
+                uncolored.join(", "),
+                field
+            );
+        }
+
+        let palette = &self.theme.palette;
+        let overflow = self
+            .palette_overflow
+            .unwrap_or_else(|| palette.default_overflow());
+        let colors = labels
+            .iter()
+            .map(|label| {
+                range_color(label).unwrap_or_else(|| {
+                    let k = fallbacks.iter().position(|f| *f == label).unwrap_or(0);
+                    palette.get_color_with_overflow(k, fallbacks.len(), overflow)
+                })
+            })
+            .collect();
+
+        VisualMapper::DiscreteColor {
+            palette: ColorPalette::Custom(colors),
+            overflow: PaletteOverflow::Cycle,
+        }
+    }
+
     /// Add a layer to the chart
     ///
     /// Adds a new chart layer to create a multi-layered visualization. Each layer can represent
@@ -422,20 +804,28 @@ impl LayeredChart {
             Rect,
             GlobalAesthetics,
             Vec<GuideSpec>,
+            (f64, f64),
         ),
         ChartonError,
     > {
         // --- STEP 1: RESOLVE GLOBAL AESTHETIC MAPPINGS ---
-        // We resolve non-positional encodings (Color, Shape, Size) across all layers.
+        // We resolve non-positional encodings (Color, Shape, Size, Opacity) across all layers.
 
         let color_mapping = if let Some(spec) = self.resolve_scale_spec(Channel::Color)? {
-            let mapper = VisualMapper::new_color_default(&spec.scale_type, &self.theme);
+            let mapper = self.resolve_color_mapper(&spec)?;
             let scale_impl = create_scale(
                 &spec.scale_type,
                 spec.domain,
                 spec.expand,
+                spec.log_base,
+                None,
                 Some(mapper.clone()),
             )?;
+            let formatter = self.formats.resolve(Channel::Color, &spec.field);
+            let scale_impl = match formatter {
+                Some(formatter) => Self::format_ticks(scale_impl, None, formatter, "color"),
+                None => scale_impl,
+            };
             Some(AestheticMapping {
                 field: spec.field,
                 scale_impl,
@@ -450,6 +840,8 @@ impl LayeredChart {
                 &spec.scale_type,
                 spec.domain,
                 spec.expand,
+                spec.log_base,
+                None,
                 Some(mapper.clone()),
             )?;
             Some(AestheticMapping {
@@ -461,11 +853,43 @@ impl LayeredChart {
         };
 
         let size_mapping = if let Some(spec) = self.resolve_scale_spec(Channel::Size)? {
-            let mapper = VisualMapper::new_size_default(2.0, 9.0);
+            // Layers sharing the size scale use the largest floor any of them requests.
+            let floor = self
+                .layers
+                .iter()
+                .filter_map(|l| l.get_size_floor())
+                .reduce(f64::max);
+            let mapper = VisualMapper::new_size_default(2.0, 9.0).with_size_floor(floor);
+            let scale_impl = create_scale(
+                &spec.scale_type,
+                spec.domain,
+                spec.expand,
+                spec.log_base,
+                None,
+                Some(mapper.clone()),
+            )?;
+            Some(AestheticMapping {
+                field: spec.field,
+                scale_impl,
+            })
+        } else {
+            None
+        };
+
+        let opacity_mapping = if let Some(spec) = self.resolve_scale_spec(Channel::Opacity)? {
+            // Layers sharing the opacity scale use the range of the first one setting it.
+            let (min, max) = self
+                .layers
+                .iter()
+                .find_map(|l| l.get_opacity_range())
+                .unwrap_or((0.2, 1.0));
+            let mapper = VisualMapper::new_opacity_default(min, max);
             let scale_impl = create_scale(
                 &spec.scale_type,
                 spec.domain,
                 spec.expand,
+                spec.log_base,
+                None,
                 Some(mapper.clone()),
             )?;
             Some(AestheticMapping {
@@ -476,20 +900,79 @@ impl LayeredChart {
             None
         };
 
-        let aesthetics = GlobalAesthetics::new(color_mapping, shape_mapping, size_mapping);
+        let aesthetics =
+            GlobalAesthetics::new(color_mapping, shape_mapping, size_mapping, opacity_mapping);
 
         // Create the global ChartSpec (Blueprint) early so it can be used for measurement.
         let chart_spec = ChartSpec {
             aesthetics: &aesthetics,
             theme: &self.theme,
+            formats: &self.formats,
         };
 
         // --- STEP 2: RESOLVE COORDINATE SCALES (X & Y) ---
-        let x_spec = self.resolve_scale_spec(Channel::X)?.unwrap();
-        let y_spec = self.resolve_scale_spec(Channel::Y)?.unwrap();
+        let x_spec = self.resolve_scale_spec(Channel::X)?.ok_or_else(|| {
+            ChartonError::Encoding("No layer provides an x encoding to build the axis".into())
+        })?;
+        let y_spec = self.resolve_scale_spec(Channel::Y)?.ok_or_else(|| {
+            ChartonError::Encoding("No layer provides a y encoding to build the axis".into())
+        })?;
 
-        let x_scale = create_scale(&x_spec.scale_type, x_spec.domain, x_spec.expand, None)?;
-        let y_scale = create_scale(&y_spec.scale_type, y_spec.domain, y_spec.expand, None)?;
+        // Date strings given as ticks must parse on a temporal axis.
+        for (spec, ticks, axis) in [(&x_spec, &self.x_ticks, "X"), (&y_spec, &self.y_ticks, "Y")] {
+            if let (Scale::Temporal, Some(ticks)) = (spec.scale_type, ticks) {
+                check_date_ticks(ticks, axis)?;
+            }
+        }
+
+        let mut x_scale = create_scale(
+            &x_spec.scale_type,
+            x_spec.domain,
+            x_spec.expand,
+            x_spec.log_base,
+            self.x_time_format.as_deref(),
+            None,
+        )?;
+        let mut y_scale = create_scale(
+            &y_spec.scale_type,
+            y_spec.domain,
+            y_spec.expand,
+            y_spec.log_base,
+            self.y_time_format.as_deref(),
+            None,
+        )?;
+
+        // Only the tick labels of pre-transformed axes change; positions stay put.
+        // A field format applies unless the axis has its own formatter; normalized
+        // stacks show fractions, not the field's values.
+        let normalized = self
+            .layers
+            .iter()
+            .any(|l| l.stacks_normalized() && !l.uses_secondary_y());
+        let x_formatter = self.formats.resolve(Channel::X, &x_spec.field);
+        let y_formatter = if normalized {
+            self.formats.channel(Channel::Y)
+        } else {
+            self.formats.resolve(Channel::Y, &y_spec.field)
+        };
+        if let Some(formatter) = x_formatter {
+            x_scale = Self::format_ticks(x_scale, self.x_tick_inverse.clone(), formatter, "X");
+        } else if let Some(inverse) = &self.x_tick_inverse {
+            x_scale = InverseLabelScale::wrap(x_scale, inverse.clone());
+        }
+        if let Some(formatter) = y_formatter {
+            y_scale = Self::format_ticks(y_scale, self.y_tick_inverse.clone(), formatter, "Y");
+        } else if let Some(inverse) = &self.y_tick_inverse {
+            y_scale = InverseLabelScale::wrap(y_scale, inverse.clone());
+        } else if normalized {
+            y_scale = InverseLabelScale::wrap_percent(y_scale);
+        }
+        if self.x_reversed {
+            x_scale = ReversedScale::wrap(x_scale);
+        }
+        if self.y_reversed {
+            y_scale = ReversedScale::wrap(y_scale);
+        }
 
         let final_coord: Arc<dyn CoordinateTrait> = match self.coord_system {
             CoordSystem::Cartesian2D => Arc::new(crate::coordinate::cartesian::Cartesian2D::new(
@@ -510,6 +993,12 @@ impl LayeredChart {
                 let inner_radius = self
                     .polar_inner_radius
                     .unwrap_or(self.theme.polar_inner_radius);
+                if !(0.0..1.0).contains(&inner_radius) {
+                    return Err(ChartonError::Encoding(format!(
+                        "Inner radius ratio must be in [0, 1), got {}",
+                        inner_radius
+                    )));
+                }
 
                 // 2. Initialize the Polar coordinate system with resolved scales and data fields.
                 let mut polar = crate::coordinate::polar::Polar::new(
@@ -535,7 +1024,8 @@ impl LayeredChart {
         };
 
         // --- STEP 3: GUIDE GENERATION ---
-        let guide_specs = crate::core::guide::GuideManager::collect_guides(&aesthetics);
+        let guide_specs =
+            crate::core::guide::GuideManager::collect_guides(&aesthetics, &self.layers);
 
         // --- STEP 4: PHYSICAL MEASUREMENT (LAYOUT ENGINE) ---
         let w = self.width as f64;
@@ -550,56 +1040,234 @@ impl LayeredChart {
                 - self.top_margin.unwrap_or(self.theme.top_margin)
                 - self.bottom_margin.unwrap_or(self.theme.bottom_margin));
 
-        // A. Measure Legend Constraints.
+        // The layout takes two passes, since the legend and the axes compete
+        // for the same margins: the axes are measured first, the legend is fitted
+        // next to them, and the axes are measured again on the resulting panel.
+        let margins = (
+            self.left_margin.unwrap_or(self.theme.left_margin) * w,
+            self.right_margin.unwrap_or(self.theme.right_margin) * w,
+            self.top_margin.unwrap_or(self.theme.top_margin) * h,
+            self.bottom_margin.unwrap_or(self.theme.bottom_margin) * h,
+        );
+        let secondary_coord = self.resolve_secondary_coord(&final_coord)?;
+        let measure_axes = |panel: &Rect| {
+            // A temporary context lets the engine generate the tick labels the
+            // panel will show and measure their rotated extents.
+            let temp_ctx = PanelContext::new(&chart_spec, final_coord.clone(), *panel);
+            let mut axes = crate::core::layout::LayoutEngine::calculate_axis_constraints(
+                &temp_ctx,
+                &self.theme,
+                panel.width,
+                panel.height,
+            );
+            // Reserve room on the right for a secondary Y-axis.
+            if let Some(secondary) = &secondary_coord {
+                axes.right = self.secondary_axis_width(secondary.as_ref(), panel.height);
+            }
+            axes
+        };
+        let resolve_panel = |legend: &LegendLayoutConstraints, axes: &AxisLayoutConstraints| {
+            let left = margins.0 + legend.left + axes.left;
+            let right = margins.1 + legend.right + axes.right;
+            let top = margins.2 + legend.top;
+            let bottom = margins.3 + legend.bottom + axes.bottom;
+            // Apply final dimensions with a safety floor (min_panel_size).
+            Rect::new(
+                left,
+                top,
+                (w - left - right).max(self.theme.min_panel_size),
+                (h - top - bottom).max(self.theme.min_panel_size),
+            )
+        };
+
+        // A. First pass: measure the axes on the panel without any legend.
+        let mut axis_box = measure_axes(&resolve_panel(
+            &LegendLayoutConstraints::default(),
+            &AxisLayoutConstraints::default(),
+        ));
+
+        // B. Fit the legend into the space the axes leave free. A hidden legend
+        // reserves nothing, so the panel takes the full width.
+        let shown_specs: &[GuideSpec] = if self.theme.show_legend {
+            &guide_specs
+        } else {
+            &[]
+        };
         let legend_box = crate::core::layout::LayoutEngine::calculate_legend_constraints(
-            &guide_specs,
+            shown_specs,
             self.theme.legend_position,
             w,
             h,
-            initial_plot_w,
-            initial_plot_h,
+            (initial_plot_w - axis_box.left - axis_box.right).max(10.0),
+            (initial_plot_h - axis_box.bottom).max(10.0),
             self.theme.legend_margin,
+            &axis_box,
             &self.theme,
         );
 
-        // B. Measure Axis Constraints using a temporary PanelContext.
-        // We calculate a 'rough' panel area first to allow the engine to estimate
-        // tick density and label overlap.
-        let temp_panel = Rect::new(
-            (self.left_margin.unwrap_or(self.theme.left_margin) * w) + legend_box.left,
-            (self.top_margin.unwrap_or(self.theme.top_margin) * h) + legend_box.top,
-            (initial_plot_w - legend_box.left - legend_box.right).max(10.0),
-            (initial_plot_h - legend_box.top - legend_box.bottom).max(10.0),
+        // C. Second pass: the smaller panel may show fewer ticks, with labels of a
+        // different extent. Only then is the panel resolved once more.
+        let mut final_panel_rect = resolve_panel(&legend_box, &axis_box);
+        let remeasured = measure_axes(&final_panel_rect);
+        if remeasured != axis_box {
+            axis_box = remeasured;
+            final_panel_rect = resolve_panel(&legend_box, &axis_box);
+        }
+
+        let legend_origin = crate::core::layout::LayoutEngine::legend_origin(
+            self.theme.legend_position,
+            &final_panel_rect,
+            &axis_box,
+            &legend_box,
+            &self.theme,
         );
 
-        // Create the temporary context required for layout measurement.
-        let temp_ctx = PanelContext::new(&chart_spec, final_coord.clone(), temp_panel);
+        Ok((
+            final_coord,
+            final_panel_rect,
+            aesthetics,
+            guide_specs,
+            legend_origin,
+        ))
+    }
+
+    /// Builds the coordinate system of the layers on the secondary Y-axis.
+    ///
+    /// It shares the x scale of `coord` and holds the secondary y scale. Returns
+    /// `None` if no layer uses the secondary axis.
+    ///
+    /// # Errors
+    /// Returns `ChartonError::Unimplemented` outside unflipped, unfaceted
+    /// Cartesian charts, and `ChartonError::Encoding` if only secondary layers
+    /// provide a y encoding.
+    pub(crate) fn resolve_secondary_coord(
+        &self,
+        coord: &Arc<dyn CoordinateTrait>,
+    ) -> Result<Option<Arc<dyn CoordinateTrait>>, ChartonError> {
+        let Some(spec) = self.resolve_secondary_y_spec()? else {
+            return Ok(None);
+        };
+        if self.coord_system != CoordSystem::Cartesian2D || self.flipped || self.facet.is_some() {
+            return Err(ChartonError::Unimplemented(
+                "A secondary y axis is only supported on unflipped, unfaceted Cartesian charts"
+                    .into(),
+            ));
+        }
+
+        let y2_scale = create_scale(
+            &spec.scale_type,
+            spec.domain,
+            spec.expand,
+            spec.log_base,
+            None,
+            None,
+        )?;
+        Ok(Some(Arc::new(
+            crate::coordinate::cartesian::Cartesian2D::new(
+                coord.get_x_arc(),
+                y2_scale,
+                coord.get_x_label().to_string(),
+                spec.field,
+                false,
+            ),
+        )))
+    }
 
-        let axis_box = crate::core::layout::LayoutEngine::calculate_axis_constraints(
-            &temp_ctx,
+    /// The width of the secondary Y-axis for a panel of the given height.
+    fn secondary_axis_width(&self, secondary: &dyn CoordinateTrait, panel_height: f64) -> f64 {
+        if !self.theme.show_axes {
+            return 0.0;
+        }
+        crate::core::layout::LayoutEngine::calculate_secondary_axis_width(
+            secondary.get_y_scale(),
+            secondary.get_y_label(),
             &self.theme,
-            temp_panel.width,
-            temp_panel.height,
-        );
+            panel_height,
+        )
+    }
+
+    /// Reports a discrete color field with more categories than the palette has
+    /// colors, and fails if the overflow policy is `Error`.
+    ///
+    /// Under `Cycle`, the diagnostic lists the categories that end up sharing a color.
+    fn check_palette_overflow(&self, field: &str, labels: &[String]) -> Result<(), ChartonError> {
+        let palette = &self.theme.palette;
+        let len = palette.color_count();
+        if len == 0 || labels.len() <= len {
+            return Ok(());
+        }
 
-        // --- STEP 5: FINAL PANEL RESOLUTION ---
-        let final_left = (self.left_margin.unwrap_or(self.theme.left_margin) * w)
-            + legend_box.left
-            + axis_box.left;
-        let final_right =
-            (self.right_margin.unwrap_or(self.theme.right_margin) * w) + legend_box.right;
-        let final_top = (self.top_margin.unwrap_or(self.theme.top_margin) * h) + legend_box.top;
-        let final_bottom = (self.bottom_margin.unwrap_or(self.theme.bottom_margin) * h)
-            + legend_box.bottom
-            + axis_box.bottom;
+        let policy = self
+            .palette_overflow
+            .unwrap_or_else(|| palette.default_overflow());
+        let name = match palette {
+            ColorPalette::Custom(_) => "custom".to_string(),
+            p => format!("{:?}", p),
+        };
+        let summary = format!(
+            "'{}' has {} categories but the {} palette provides {} colors",
+            field,
+            labels.len(),
+            name,
+            len
+        );
 
-        // Apply final dimensions with a safety floor (min_panel_size).
-        let plot_w = (w - final_left - final_right).max(self.theme.min_panel_size);
-        let plot_h = (h - final_top - final_bottom).max(self.theme.min_panel_size);
+        match policy {
+            PaletteOverflow::Error => {
+                return Err(ChartonError::Scale(format!(
+                    "{}; use a larger palette or another PaletteOverflow policy",
+                    summary
+                )));
+            }
+            PaletteOverflow::Interpolate => {
+                eprintln!(
+                    "ColorPalette: {}; {} extra colors were interpolated.",
+                    summary,
+                    labels.len() - len
+                );
+            }
+            PaletteOverflow::Cycle => {
+                let shared: Vec<String> = (0..len)
+                    .map(|slot| {
+                        labels
+                            .iter()
+                            .skip(slot)
+                            .step_by(len)
+                            .map(|l| l.as_str())
+                            .collect::<Vec<_>>()
+                    })
+                    .filter(|group| group.len() > 1)
+                    .map(|group| group.join(" = "))
+                    .collect();
+                eprintln!(
+                    "ColorPalette: {}; colors are reused by: {}.",
+                    summary,
+                    shared.join(", ")
+                );
+            }
+        }
 
-        let final_panel_rect = Rect::new(final_left, final_top, plot_w, plot_h);
+        Ok(())
+    }
 
-        Ok((final_coord, final_panel_rect, aesthetics, guide_specs))
+    /// Wraps a continuous scale so its tick labels are written by `formatter`.
+    ///
+    /// Discrete and temporal scales keep their labels, with a warning naming `channel`.
+    fn format_ticks(
+        scale: Arc<dyn ScaleTrait>,
+        inverse: Option<InverseTransform>,
+        formatter: &TickFormatter,
+        channel: &str,
+    ) -> Arc<dyn ScaleTrait> {
+        if matches!(scale.scale_type(), Scale::Discrete | Scale::Temporal) {
+            eprintln!(
+                "Tick formatter: the {} scale is {:?}, not continuous; its labels are kept.",
+                channel,
+                scale.scale_type()
+            );
+            return scale;
+        }
+        InverseLabelScale::wrap_formatter(scale, inverse, formatter.clone())
     }
 
     /// Renders the chart title at the top-center of the SVG canvas.
@@ -611,7 +1279,7 @@ impl LayeredChart {
     fn render_title<B: RenderBackend>(
         &self,
         backend: &mut B,
-        panel: &Rect,
+        content_top: f64,
     ) -> Result<(), ChartonError> {
         // 1. Guard: Check if a title exists.
         let title_text = match &self.title {
@@ -624,9 +1292,10 @@ impl LayeredChart {
         let center_x = self.width as f64 / 2.0;
 
         // 3. Vertical Positioning Logic:
-        // We calculate the available vertical space above the plot panel (panel.y).
+        // We calculate the available vertical space above the content, i.e. the
+        // plot panel or a legend on top of it.
         // We place the text's baseline in the middle of this area.
-        let title_area_height = panel.y;
+        let title_area_height = content_top;
         let font_size = self.theme.title_size;
 
         // Calculate the vertical midpoint.
@@ -647,12 +1316,17 @@ impl LayeredChart {
             color: *font_color,
             text_anchor: "middle".to_string(),
             dominant_baseline: "middle".into(),
-            font_weight: "bold".to_string(),
+            font_weight: self.theme.title_font_weight.to_string(),
+            font_style: self.theme.title_font_style.to_string(),
             opacity: 1.0,
             angle: 0.0,
+            direction: self.theme.text_direction.as_str().to_string(),
+            writing_mode: "horizontal-tb".to_string(),
         };
 
+        backend.set_href(self.title_href.clone());
         backend.draw_text(config);
+        backend.set_href(None);
 
         Ok(())
     }
@@ -668,88 +1342,611 @@ impl LayeredChart {
         }
 
         // --- STEP 1: SCENE RESOLUTION ---
-        let (coord, panel, aesthetics, guide_specs) = self.resolve_scene()?;
+        let (coord, panel, aesthetics, guide_specs, legend_origin) = self.resolve_scene()?;
 
         // --- STEP 2: GLOBAL SPECIFICATION SETUP ---
         let spec = ChartSpec {
             aesthetics: &aesthetics,
             theme: &self.theme,
+            formats: &self.formats,
         };
 
         // --- STEP 3: LAYER SYNCHRONIZATION (The "Back-fill") ---
+        // Layers on the secondary Y-axis receive its coordinate system instead.
+        let secondary_coord = self.resolve_secondary_coord(&coord)?;
         for layer in self.layers.iter() {
-            layer.inject_resolved_scales(coord.clone(), &aesthetics);
+            let layer_coord = match &secondary_coord {
+                Some(secondary) if layer.uses_secondary_y() => secondary.clone(),
+                _ => coord.clone(),
+            };
+            layer.inject_resolved_scales(layer_coord, &aesthetics);
         }
 
+        // The title sits above both the panel and a legend placed on top of it.
+        let content_top = if self.theme.show_legend && !guide_specs.is_empty() {
+            panel.y.min(legend_origin.1)
+        } else {
+            panel.y
+        };
+
         // --- STEP 4: ORCHESTRATED DRAWING ---
         // 4a. Initialize the Primary Panel Context.
         let primary_panel_ctx = PanelContext::new(&spec, coord.clone(), panel);
+        let secondary_panel_ctx = secondary_coord
+            .as_ref()
+            .map(|secondary| PanelContext::new(&spec, secondary.clone(), panel));
+
+        // A faceted chart draws grid, marks and axes once per panel instead.
+        if let Some(facet) = &self.facet {
+            self.render_title(backend, content_top)?;
+            self.render_facets(backend, &primary_panel_ctx, facet.as_ref())?;
+        } else {
+            // 4b. Render Grid Lines (BOTTOM LAYER)
+            let grid_lines = self.grid_lines();
+            if grid_lines.x || grid_lines.y {
+                let x_explicit = self.x_ticks.as_deref();
+                let y_explicit = self.y_ticks.as_deref();
+
+                // Polymorphic dispatch: The specific coordinate system handles its own grid drawing.
+                primary_panel_ctx.coord.render_grid_lines(
+                    backend,
+                    &self.theme,
+                    &primary_panel_ctx.panel,
+                    grid_lines,
+                    x_explicit,
+                    y_explicit,
+                )?;
+            }
+
+            // 4c. Render Chart Title.
+            self.render_title(backend, content_top)?;
 
-        // 4b. Render Grid Lines (BOTTOM LAYER)
-        // Check user override first, fallback to theme default.
-        let should_show_grid = self.show_grid.unwrap_or(self.theme.show_grid);
-        if should_show_grid {
-            let x_explicit = self.x_ticks.as_deref();
-            let y_explicit = self.y_ticks.as_deref();
+            // 4d. Render Marks (MIDDLE LAYER - Data Geometries)
+            // We activate clipping to lock chart marks strictly inside the data viewport.
+            // Since grid lines are drawn before this, they safely sit underneath the data.
+            backend.begin_clip_scope(&primary_panel_ctx.panel);
 
-            // Polymorphic dispatch: The specific coordinate system handles its own grid drawing.
-            primary_panel_ctx.coord.render_grid_lines(
+            for layer in &self.layers {
+                match &secondary_panel_ctx {
+                    Some(ctx) if layer.uses_secondary_y() => layer.render_marks(backend, ctx)?,
+                    _ => layer.render_marks(backend, &primary_panel_ctx)?,
+                }
+            }
+            self.render_reference_lines(backend, &primary_panel_ctx);
+
+            backend.end_clip_scope();
+
+            // 4e. Render Axes (TOP LAYER)
+            // Drawn after the marks and outside the clipping scope to ensure labels aren't clipped
+            // and axis spines sit crisply on top of data lines that touch the edges.
+            if self.theme.show_axes && self.layers.iter().any(|l| l.requires_axes()) {
+                let x_label = coord.get_x_label();
+                let y_label = coord.get_y_label();
+                let x_explicit = self.x_ticks.as_deref();
+                let y_explicit = self.y_ticks.as_deref();
+
+                // Polymorphic dispatch for axes
+                primary_panel_ctx.coord.render_axes(
+                    backend,
+                    &self.theme,
+                    &primary_panel_ctx.panel,
+                    x_label,
+                    x_explicit,
+                    y_label,
+                    y_explicit,
+                )?;
+
+                // The secondary Y-axis sits on the right edge with its own ticks.
+                if let Some(ctx) = &secondary_panel_ctx {
+                    crate::render::cartesian2d_axis_renderer::render_secondary_y_axis(
+                        backend,
+                        &self.theme,
+                        &ctx.panel,
+                        ctx.coord.as_ref(),
+                        ctx.coord.get_y_label(),
+                    )?;
+                }
+            }
+
+            // 4f. Render Annotations, anchored to data positions above everything else.
+            self.render_annotations(backend, &primary_panel_ctx);
+            self.render_center_text(backend, &primary_panel_ctx);
+        }
+
+        // 4g. Render Unified Legends & Guides (FOREGROUND LAYER)
+        if self.theme.show_legend {
+            crate::render::legend_renderer::LegendRenderer::render_legend(
                 backend,
+                &guide_specs,
                 &self.theme,
-                &primary_panel_ctx.panel,
-                x_explicit,
-                y_explicit,
-            )?;
+                &primary_panel_ctx,
+                legend_origin,
+            );
         }
 
-        // 4c. Render Chart Title.
-        self.render_title(backend, &primary_panel_ctx.panel)?;
+        // 4h. Render Insets (OVERLAY LAYER)
+        for (inner, spec) in &self.insets {
+            self.render_inset(backend, &primary_panel_ctx, inner, spec)?;
+        }
+
+        Ok(())
+    }
 
-        // 4d. Render Marks (MIDDLE LAYER - Data Geometries)
-        // We activate clipping to lock chart marks strictly inside the data viewport.
-        // Since grid lines are drawn before this, they safely sit underneath the data.
-        backend.begin_clip_scope(&primary_panel_ctx.panel);
+    /// Draws one small multiple per value of the facet field, or per
+    /// combination of values for a two-field grid.
+    ///
+    /// The facet panels share the scales resolved for the whole chart, so the
+    /// x scale is the same down every column and the y scale across every
+    /// row. A layer whose mark transform drops the facet column is transformed
+    /// again per panel from its source rows. They are laid out by the [`Facet`] inside the main panel. Each panel
+    /// gets its own grid, marks and axes; gutters between panels leave room
+    /// for the tick labels, while axis titles are only drawn along the outer
+    /// edges. Layers without a facet column are repeated along it, and
+    /// combinations without data are left as empty panels.
+    fn render_facets<B: RenderBackend>(
+        &self,
+        backend: &mut B,
+        ctx: &PanelContext,
+        facet: &(dyn Facet + Send + Sync),
+    ) -> Result<(), ChartonError> {
+        // 1. Collect the values of every facet field across layers, keeping
+        // first appearance order.
+        let fields = facet.fields();
+        let mut factors: Vec<Vec<String>> = Vec::with_capacity(fields.len());
+        for field in &fields {
+            let mut values: Vec<String> = Vec::new();
+            for layer in &self.layers {
+                for v in layer.facet_values(field).unwrap_or_default() {
+                    if !values.contains(&v) {
+                        values.push(v);
+                    }
+                }
+            }
+            if values.is_empty() {
+                return Err(ChartonError::Encoding(format!(
+                    "Facet field '{}' is not present in any layer",
+                    field
+                )));
+            }
+            factors.push(values);
+        }
 
-        for layer in &self.layers {
-            layer.render_marks(backend, &primary_panel_ctx)?;
+        // 2. Partition every layer and back-fill the shared scales into the parts.
+        let parts: Vec<FacetPart> = self
+            .layers
+            .iter()
+            .flat_map(|l| split_facets(l.clone(), &fields))
+            .collect();
+        for (key, part) in &parts {
+            if key.iter().any(Option::is_some) {
+                part.inject_resolved_scales(ctx.coord.clone(), ctx.spec.aesthetics);
+            }
         }
 
-        backend.end_clip_scope();
+        // 3. Lay out the panels, widening the gaps so tick labels fit between them.
+        let gutter = crate::core::layout::LayoutEngine::calculate_tick_constraints(
+            ctx,
+            &self.theme,
+            ctx.panel.width,
+            ctx.panel.height,
+        );
+        let layout_theme = Theme {
+            facet_spacing: self.theme.facet_spacing + gutter.left.max(gutter.bottom),
+            ..self.theme.clone()
+        };
+        let layout = facet.compute_layout(&factors, &ctx.panel, &layout_theme);
 
-        // 4e. Render Axes (TOP LAYER)
-        // Drawn after the marks and outside the clipping scope to ensure labels aren't clipped
-        // and axis spines sit crisply on top of data lines that touch the edges.
-        if self.theme.show_axes && self.layers.iter().any(|l| l.requires_axes()) {
-            let x_label = coord.get_x_label();
-            let y_label = coord.get_y_label();
-            let x_explicit = self.x_ticks.as_deref();
-            let y_explicit = self.y_ticks.as_deref();
+        let grid_lines = self.grid_lines();
+        let show_axes = self.theme.show_axes && self.layers.iter().any(|l| l.requires_axes());
+        let x_explicit = self.x_ticks.as_deref();
+        let y_explicit = self.y_ticks.as_deref();
 
-            // Polymorphic dispatch for axes
-            primary_panel_ctx.coord.render_axes(
-                backend,
-                &self.theme,
-                &primary_panel_ctx.panel,
-                x_label,
-                x_explicit,
-                y_label,
-                y_explicit,
-            )?;
+        for strip in &layout.strips {
+            self.render_facet_strip(backend, strip);
         }
 
-        // 4f. Render Unified Legends & Guides (FOREGROUND LAYER)
-        if self.theme.show_legend {
-            crate::render::legend_renderer::LegendRenderer::render_legend(
-                backend,
-                &guide_specs,
-                &self.theme,
-                &primary_panel_ctx,
-            );
+        for cell in &layout.cells {
+            let cell_ctx = PanelContext::new(ctx.spec, ctx.coord.clone(), cell.rect);
+
+            if grid_lines.x || grid_lines.y {
+                cell_ctx.coord.render_grid_lines(
+                    backend,
+                    &self.theme,
+                    &cell_ctx.panel,
+                    grid_lines,
+                    x_explicit,
+                    y_explicit,
+                )?;
+            }
+
+            backend.begin_clip_scope(&cell_ctx.panel);
+            for (key, part) in &parts {
+                let in_cell = key
+                    .iter()
+                    .zip(&cell.info.values)
+                    .all(|(k, v)| k.as_ref().is_none_or(|k| k == v));
+                if in_cell {
+                    part.render_marks(backend, &cell_ctx)?;
+                }
+            }
+            self.render_reference_lines(backend, &cell_ctx);
+            backend.end_clip_scope();
+
+            if show_axes {
+                let info = &cell.info;
+                let x_label = if info.row + 1 == info.total_rows {
+                    ctx.coord.get_x_label()
+                } else {
+                    ""
+                };
+                let y_label = if info.col == 0 {
+                    ctx.coord.get_y_label()
+                } else {
+                    ""
+                };
+                cell_ctx.coord.render_axes(
+                    backend,
+                    &self.theme,
+                    &cell_ctx.panel,
+                    x_label,
+                    x_explicit,
+                    y_label,
+                    y_explicit,
+                )?;
+            }
+
+            self.render_annotations(backend, &cell_ctx);
+            self.render_center_text(backend, &cell_ctx);
         }
 
         Ok(())
     }
 
+    /// Draws a facet header strip, labelled with its value.
+    fn render_facet_strip<B: RenderBackend>(&self, backend: &mut B, strip: &FacetStrip) {
+        let rect = &strip.rect;
+        backend.draw_rect(RectConfig {
+            x: rect.x as Precision,
+            y: rect.y as Precision,
+            width: rect.width as Precision,
+            height: rect.height as Precision,
+            fill: self.theme.facet_strip_fill,
+            stroke: "none".into(),
+            stroke_width: 0.0,
+            opacity: 1.0,
+        });
+
+        backend.draw_text(TextConfig {
+            x: (rect.x + rect.width / 2.0) as Precision,
+            y: (rect.y + rect.height / 2.0) as Precision,
+            text: strip.label.clone(),
+            font_size: self.theme.facet_label_size as Precision,
+            font_family: self.theme.label_family.clone(),
+            color: self.theme.facet_label_color,
+            text_anchor: "middle".to_string(),
+            dominant_baseline: "middle".into(),
+            font_weight: "normal".to_string(),
+            font_style: "normal".to_string(),
+            opacity: 1.0,
+            angle: if strip.vertical { 90.0 } else { 0.0 },
+            direction: self.theme.text_direction.as_str().to_string(),
+            writing_mode: "horizontal-tb".to_string(),
+        });
+    }
+
+    /// Draws a nested chart inside the main panel.
+    ///
+    /// The inner chart is laid out on a canvas the size of the inset frame, with
+    /// its own scales, and then drawn through an `OffsetBackend` so that every
+    /// output format positions it identically.
+    fn render_inset<B: RenderBackend>(
+        &self,
+        backend: &mut B,
+        ctx: &PanelContext,
+        inner: &LayeredChart,
+        spec: &InsetSpec,
+    ) -> Result<(), ChartonError> {
+        let frame = spec.resolve_frame(&ctx.panel);
+
+        let mut inset = inner.clone();
+        inset.width = frame.width.round().max(1.0) as u32;
+        inset.height = frame.height.round().max(1.0) as u32;
+        inset.theme = spec.apply_to_theme(inset.theme);
+
+        // A zoomed window is shown exactly, without the usual padding around the data.
+        let no_expand = Expansion {
+            mult: (0.0, 0.0),
+            add: (0.0, 0.0),
+        };
+        if let Some((min, max)) = spec.x_domain {
+            inset.x_domain = Some(ScaleDomain::Continuous(min, max));
+            inset.x_expand = Some(no_expand);
+        }
+        if let Some((min, max)) = spec.y_domain {
+            inset.y_domain = Some(ScaleDomain::Continuous(min, max));
+            inset.y_expand = Some(no_expand);
+        }
+
+        if spec.show_indicator {
+            self.render_inset_indicator(backend, ctx, spec, &frame);
+        }
+
+        // Opaque backdrop and frame so the main chart does not show through.
+        backend.draw_rect(RectConfig {
+            x: frame.x as Precision,
+            y: frame.y as Precision,
+            width: frame.width as Precision,
+            height: frame.height as Precision,
+            fill: inset.theme.background_color,
+            stroke: spec.indicator_color,
+            stroke_width: 1.0,
+            opacity: 1.0,
+        });
+
+        let mut offset =
+            crate::render::backend::offset::OffsetBackend::new(backend, frame.x, frame.y);
+        inset.render(&mut offset)
+    }
+
+    /// Marks the zoomed window on the main chart and links it to the inset frame.
+    ///
+    /// Only Cartesian charts with continuous axes can locate a data window, so
+    /// other configurations simply skip the indicator.
+    fn render_inset_indicator<B: RenderBackend>(
+        &self,
+        backend: &mut B,
+        ctx: &PanelContext,
+        spec: &InsetSpec,
+        frame: &Rect,
+    ) {
+        if spec.x_domain.is_none() && spec.y_domain.is_none() {
+            return;
+        }
+        if !matches!(self.coord_system, CoordSystem::Cartesian2D) {
+            return;
+        }
+
+        let x_scale = ctx.coord.get_x_scale();
+        let y_scale = ctx.coord.get_y_scale();
+        if matches!(x_scale.scale_type(), Scale::Discrete)
+            || matches!(y_scale.scale_type(), Scale::Discrete)
+        {
+            return;
+        }
+
+        // Missing windows span the whole axis; the result is kept inside the panel.
+        let (nx0, nx1) = spec.x_domain.map_or((0.0, 1.0), |(a, b)| {
+            (x_scale.normalize(a), x_scale.normalize(b))
+        });
+        let (ny0, ny1) = spec.y_domain.map_or((0.0, 1.0), |(a, b)| {
+            (y_scale.normalize(a), y_scale.normalize(b))
+        });
+        let clamp = |v: f64| v.clamp(0.0, 1.0);
+
+        let (ax, ay) = ctx.coord.transform(clamp(nx0), clamp(ny0), &ctx.panel);
+        let (bx, by) = ctx.coord.transform(clamp(nx1), clamp(ny1), &ctx.panel);
+        let zoom = Rect::new(ax.min(bx), ay.min(by), (ax - bx).abs(), (ay - by).abs());
+
+        backend.draw_rect(RectConfig {
+            x: zoom.x as Precision,
+            y: zoom.y as Precision,
+            width: zoom.width as Precision,
+            height: zoom.height as Precision,
+            fill: "none".into(),
+            stroke: spec.indicator_color,
+            stroke_width: 1.0,
+            opacity: 1.0,
+        });
+
+        // Connect matching corners; the two shortest links never cross either box.
+        let corners = |r: &Rect| {
+            [
+                (r.x, r.y),
+                (r.x + r.width, r.y),
+                (r.x, r.y + r.height),
+                (r.x + r.width, r.y + r.height),
+            ]
+        };
+        let mut links: Vec<((f64, f64), (f64, f64))> =
+            corners(&zoom).into_iter().zip(corners(frame)).collect();
+        links.sort_by(|a, b| {
+            let len = |((x1, y1), (x2, y2)): &((f64, f64), (f64, f64))| (x2 - x1).hypot(y2 - y1);
+            len(a).total_cmp(&len(b))
+        });
+
+        for ((x1, y1), (x2, y2)) in links.into_iter().take(2) {
+            backend.draw_line(LineConfig {
+                x1: x1 as Precision,
+                y1: y1 as Precision,
+                x2: x2 as Precision,
+                y2: y2 as Precision,
+                color: spec.indicator_color,
+                width: 0.8,
+                opacity: 1.0,
+                dash: vec![],
+            });
+        }
+    }
+
+    /// Draws the reference lines across the given panel.
+    ///
+    /// The line runs from one end of the other axis to the other in normalized
+    /// space, so the coordinate system decides its shape (a circle in polar
+    /// coordinates). Lines at values the scale cannot place are skipped.
+    fn render_reference_lines<B: RenderBackend>(&self, backend: &mut B, ctx: &PanelContext) {
+        for line in &self.reference_lines {
+            let points = match line.axis {
+                ReferenceAxis::X => {
+                    let nx = ctx.coord.get_x_scale().normalize(line.value);
+                    [(nx, 0.0), (nx, 1.0)]
+                }
+                ReferenceAxis::Y => {
+                    let ny = ctx.coord.get_y_scale().normalize(line.value);
+                    [(0.0, ny), (1.0, ny)]
+                }
+            };
+            if points.iter().any(|(x, y)| !x.is_finite() || !y.is_finite()) {
+                continue;
+            }
+
+            backend.draw_path(PathConfig {
+                points: ctx
+                    .transform_path(&points, false)
+                    .into_iter()
+                    .map(|(px, py)| (px as Precision, py as Precision))
+                    .collect(),
+                fill: SingleColor::none(),
+                stroke: line.color.unwrap_or(self.theme.axes_color),
+                stroke_width: line.width as Precision,
+                opacity: 1.0,
+                dash: line.dash.iter().map(|&d| d as Precision).collect(),
+                topology: PathTopology::Simple,
+            });
+        }
+    }
+
+    /// Draws the annotations at their data positions in the given panel.
+    ///
+    /// Positions are normalized by the resolved scales and mapped by the
+    /// coordinate system, exactly like mark positions. Annotations whose point
+    /// falls outside the scale domains are skipped.
+    fn render_annotations<B: RenderBackend>(&self, backend: &mut B, ctx: &PanelContext) {
+        let x_scale = ctx.coord.get_x_scale();
+        let y_scale = ctx.coord.get_y_scale();
+        let inside = |v: f64| v.is_finite() && (-1e-9..=1.0 + 1e-9).contains(&v);
+
+        for annotation in &self.annotations {
+            let nx = x_scale.normalize(annotation.x);
+            let ny = y_scale.normalize(annotation.y);
+            if !inside(nx) || !inside(ny) {
+                continue;
+            }
+
+            let (px, py) = ctx.coord.transform(nx, ny, &ctx.panel);
+            let (tx, ty) = (px + annotation.offset.0, py + annotation.offset.1);
+            let color = annotation.color.unwrap_or(self.theme.label_color);
+            let font_size = annotation.font_size.unwrap_or(self.theme.tick_label_size);
+
+            if annotation.arrow {
+                let (text_pos, point) = ((tx, ty), (px, py));
+                Self::render_annotation_arrow(
+                    backend, annotation, font_size, text_pos, point, color,
+                );
+            }
+
+            backend.draw_text(TextConfig {
+                x: tx as Precision,
+                y: ty as Precision,
+                text: annotation.text.clone(),
+                font_size: font_size as Precision,
+                font_family: self.theme.tick_label_family.clone(),
+                color,
+                text_anchor: annotation.anchor.to_string(),
+                dominant_baseline: "central".into(),
+                font_weight: self.theme.tick_label_font_weight.to_string(),
+                font_style: self.theme.tick_label_font_style.to_string(),
+                opacity: 1.0,
+                angle: 0.0,
+                direction: self.theme.text_direction.as_str().to_string(),
+                writing_mode: "horizontal-tb".to_string(),
+            });
+        }
+    }
+
+    /// Draws the center text in the middle of a polar panel, i.e. in the hole
+    /// of a donut chart. Cartesian and geographic panels have no center.
+    fn render_center_text<B: RenderBackend>(&self, backend: &mut B, ctx: &PanelContext) {
+        let Some(text) = &self.center_text else {
+            return;
+        };
+        if self.coord_system != CoordSystem::Polar {
+            return;
+        }
+
+        backend.draw_text(TextConfig {
+            x: (ctx.panel.x + ctx.panel.width / 2.0) as Precision,
+            y: (ctx.panel.y + ctx.panel.height / 2.0) as Precision,
+            text: text.clone(),
+            font_size: self.theme.title_size as Precision,
+            font_family: self.theme.label_family.clone(),
+            color: self.theme.label_color,
+            text_anchor: "middle".into(),
+            dominant_baseline: "central".into(),
+            font_weight: self.theme.label_font_weight.to_string(),
+            font_style: self.theme.label_font_style.to_string(),
+            opacity: 1.0,
+            angle: 0.0,
+            direction: self.theme.text_direction.as_str().to_string(),
+            writing_mode: "horizontal-tb".to_string(),
+        });
+    }
+
+    /// Draws a leader line with an arrow head from an annotation's text to its point.
+    ///
+    /// The line starts at the edge of the (estimated) text box, so nothing is
+    /// drawn when the point lies under the text itself.
+    fn render_annotation_arrow<B: RenderBackend>(
+        backend: &mut B,
+        annotation: &Annotation,
+        font_size: f64,
+        text_pos: (f64, f64),
+        point: (f64, f64),
+        color: SingleColor,
+    ) {
+        const GAP: f64 = 2.0;
+        const HEAD_LENGTH: f64 = 6.0;
+        const HEAD_HALF_WIDTH: f64 = 3.0;
+
+        let width = crate::core::utils::estimate_text_width(&annotation.text, font_size);
+        let center_x = match annotation.anchor {
+            TextAnchor::Start => text_pos.0 + width / 2.0,
+            TextAnchor::Middle => text_pos.0,
+            TextAnchor::End => text_pos.0 - width / 2.0,
+        };
+        let (half_w, half_h) = (width / 2.0 + GAP, font_size / 2.0 + GAP);
+
+        let (dx, dy) = (point.0 - center_x, point.1 - text_pos.1);
+        let length = dx.hypot(dy);
+        // Fraction of the way to the point at which the ray leaves the text box.
+        let exit = (half_w / dx.abs()).min(half_h / dy.abs());
+        if length <= f64::EPSILON || exit * length + GAP + HEAD_LENGTH >= length {
+            return;
+        }
+
+        let (ux, uy) = (dx / length, dy / length);
+        let (x1, y1) = (center_x + dx * exit, text_pos.1 + dy * exit);
+        let (tip_x, tip_y) = (point.0 - ux * GAP, point.1 - uy * GAP);
+        let (base_x, base_y) = (tip_x - ux * HEAD_LENGTH, tip_y - uy * HEAD_LENGTH);
+
+        backend.draw_line(LineConfig {
+            x1: x1 as Precision,
+            y1: y1 as Precision,
+            x2: base_x as Precision,
+            y2: base_y as Precision,
+            color,
+            width: 1.0,
+            opacity: 1.0,
+            dash: vec![],
+        });
+        backend.draw_polygon(PolygonConfig {
+            points: vec![
+                (tip_x as Precision, tip_y as Precision),
+                (
+                    (base_x - uy * HEAD_HALF_WIDTH) as Precision,
+                    (base_y + ux * HEAD_HALF_WIDTH) as Precision,
+                ),
+                (
+                    (base_x + uy * HEAD_HALF_WIDTH) as Precision,
+                    (base_y - ux * HEAD_HALF_WIDTH) as Precision,
+                ),
+            ],
+            fill: color,
+            stroke: color,
+            stroke_width: 0.5,
+            opacity: 1.0,
+        });
+    }
+
     /// Generates and returns the SVG representation of the chart.
     ///
     /// This method renders the entire chart as an SVG string. It creates a mutable
@@ -765,8 +1962,12 @@ impl LayeredChart {
         // 1. SVG Header & ViewBox Setup
         // Define dimensions and coordinate system to ensure proper scaling.
         svg_content.push_str(&format!(
-            r#"<svg width="{}" height="{}" viewBox="0 0 {} {}" xmlns="http://www.w3.org/2000/svg">"#,
-            self.width, self.height, self.width, self.height
+            r#"<svg width="{}" height="{}" viewBox="0 0 {} {}" xmlns="http://www.w3.org/2000/svg"{}>"#,
+            self.width,
+            self.height,
+            self.width,
+            self.height,
+            self.metadata_attributes()?
         ));
 
         // 2. Localized Backend Scope
@@ -797,6 +1998,124 @@ impl LayeredChart {
         Ok(svg_content)
     }
 
+    /// Formats the metadata as attributes of the root `<svg>` element, each with
+    /// a leading space.
+    ///
+    /// # Errors
+    /// Returns `ChartonError::Render` for a name that is not an XML attribute
+    /// name or is one of the root's own attributes, and for a value holding
+    /// characters XML does not allow.
+    fn metadata_attributes(&self) -> Result<String, ChartonError> {
+        const RESERVED: [&str; 4] = ["width", "height", "viewBox", "xmlns"];
+
+        let mut attributes = String::new();
+        for (name, value) in &self.metadata {
+            if !is_xml_attribute_name(name) || RESERVED.contains(&name.as_str()) {
+                return Err(ChartonError::Render(format!(
+                    "'{}' cannot be used as an SVG metadata attribute",
+                    name
+                )));
+            }
+            if value
+                .chars()
+                .any(|c| c.is_control() && !matches!(c, '\t' | '\n' | '\r'))
+            {
+                return Err(ChartonError::Render(format!(
+                    "The value of SVG metadata attribute '{}' contains control characters",
+                    name
+                )));
+            }
+            attributes.push_str(&format!(
+                r#" {}="{}""#,
+                name,
+                html_escape::encode_double_quoted_attribute(value)
+            ));
+        }
+        Ok(attributes)
+    }
+
+    /// Renders only the legend of the chart into a standalone SVG string.
+    ///
+    /// The legend goes through the same guide collection and consolidation as
+    /// in [`LayeredChart::to_svg`], so its colors, shapes and labels match the
+    /// chart exactly. The document is sized to the legend blocks plus the
+    /// theme's legend margin on every side. Blocks are arranged as for the
+    /// theme's legend position; a chart with `LegendPosition::None` gets a
+    /// vertical legend. Pair it with `with_legend(false)` to place the legend
+    /// elsewhere, e.g. below a grid of charts.
+    ///
+    /// # Errors
+    /// Returns `ChartonError::Render` if the chart has no legend to draw.
+    pub fn legend_svg(&self) -> Result<String, ChartonError> {
+        let mut chart_instance = self.clone();
+        // The legend is laid out as if shown next to the chart, so long lists
+        // wrap into the same columns.
+        chart_instance.theme.show_legend = true;
+        if chart_instance.theme.legend_position == LegendPosition::None {
+            chart_instance.theme.legend_position = LegendPosition::Right;
+        }
+        if chart_instance.layers.is_empty() {
+            return Err(ChartonError::Render("The chart has no legend".into()));
+        }
+
+        let (coord, panel, aesthetics, guide_specs, _) = chart_instance.resolve_scene()?;
+        if guide_specs.is_empty() {
+            return Err(ChartonError::Render("The chart has no legend".into()));
+        }
+        let theme = &chart_instance.theme;
+        let spec = ChartSpec {
+            aesthetics: &aesthetics,
+            theme,
+            formats: &chart_instance.formats,
+        };
+        let ctx = PanelContext::new(&spec, coord, panel);
+
+        // Draw first, since the extent of the legend sizes the document.
+        let pad = theme.legend_margin;
+        let mut body = String::new();
+        let size = {
+            let mut backend = crate::render::backend::svg::SvgBackend::new(&mut body);
+            crate::render::legend_renderer::LegendRenderer::render_legend(
+                &mut backend,
+                &guide_specs,
+                theme,
+                &ctx,
+                (pad, pad),
+            )
+        };
+        let width = (size.width + 2.0 * pad).ceil();
+        let height = (size.height + 2.0 * pad).ceil();
+
+        let mut svg_content = format!(
+            r#"<svg width="{}" height="{}" viewBox="0 0 {} {}" xmlns="http://www.w3.org/2000/svg">"#,
+            width, height, width, height
+        );
+        crate::render::backend::svg::SvgBackend::new(&mut svg_content).draw_rect(RectConfig {
+            x: 0.0,
+            y: 0.0,
+            width: width as Precision,
+            height: height as Precision,
+            fill: theme.background_color,
+            stroke: "none".into(),
+            stroke_width: 0.0,
+            opacity: 1.0,
+        });
+        svg_content.push_str(&body);
+        svg_content.push_str("</svg>");
+
+        Ok(svg_content)
+    }
+
+    /// Generates and returns a PDF representation of the chart as a byte vector.
+    ///
+    /// The page is `width` × `height` points with the fonts embedded, so the
+    /// vector output suits print submission; `save("*.pdf")` writes the same
+    /// buffer.
+    #[cfg(feature = "pdf")]
+    pub fn to_pdf(&self) -> Result<Vec<u8>, ChartonError> {
+        crate::core::utils::svg_to_pdf(&self.to_svg()?)
+    }
+
     /// Generates and returns a PNG representation of the chart as a byte vector.
     ///
     /// This method renders the entire chart into a pixel buffer using the `tiny-skia`
@@ -804,30 +2123,74 @@ impl LayeredChart {
     /// "Training Phase" (synchronizing scales and aesthetics) while preserving
     /// the original chart as an immutable recipe.
     ///
+    /// The bytes are produced in memory, so they can be streamed (e.g. in an
+    /// HTTP response) without touching the filesystem; `save("*.png")` writes
+    /// the same buffer.
+    ///
     /// # Returns
     /// A Result containing the PNG encoded bytes or a ChartonError.
-    #[cfg(feature = "png")]
+    #[cfg(feature = "raster")]
     pub fn to_png(&self) -> Result<Vec<u8>, ChartonError> {
+        self.to_png_with_scale(f64::from(self.scale_factor))
+    }
+
+    /// Generates a PNG at the given pixel density instead of the chart's scale factor.
+    ///
+    /// A scale of 1.0 gives one pixel per chart unit; the image is
+    /// `width * scale` by `height * scale` pixels.
+    ///
+    /// The PNG records a resolution of `scale * 96` DPI, so print software
+    /// sizes it at the chart's width and height in CSS pixels.
+    ///
+    /// # Returns
+    /// A Result containing the PNG encoded bytes, or a `ChartonError::Render`
+    /// if `scale` is not a positive finite number or the image does not fit
+    /// in memory.
+    #[cfg(feature = "raster")]
+    pub fn to_png_with_scale(&self, scale: f64) -> Result<Vec<u8>, ChartonError> {
+        let scale = scale as f32;
+        let pixmap = self.render_pixmap(scale)?;
+        crate::render::backend::raster::encode_png(&pixmap, scale)
+    }
+
+    /// Generates a JPEG of the chart at its scale factor, for small web thumbnails.
+    ///
+    /// The compression follows [`with_jpeg_quality`](IntoLayered::with_jpeg_quality)
+    /// (90 by default). JPEG has no transparency, so a transparent background
+    /// comes out white; `save("*.jpg")` writes the same buffer.
+    #[cfg(feature = "jpeg")]
+    pub fn to_jpeg(&self) -> Result<Vec<u8>, ChartonError> {
+        let pixmap = self.render_pixmap(self.scale_factor)?;
+        crate::render::backend::raster::encode_jpeg(&pixmap, self.scale_factor, self.jpeg_quality)
+    }
+
+    /// Generates a lossless WebP of the chart at its scale factor.
+    ///
+    /// Transparency is kept; `save("*.webp")` writes the same buffer.
+    #[cfg(feature = "webp")]
+    pub fn to_webp(&self) -> Result<Vec<u8>, ChartonError> {
+        let pixmap = self.render_pixmap(self.scale_factor)?;
+        crate::render::backend::raster::encode_webp(&pixmap)
+    }
+
+    /// Renders the chart into a pixel buffer at `scale`, shared by the raster formats.
+    #[cfg(feature = "raster")]
+    fn render_pixmap(&self, scale: f32) -> Result<tiny_skia::Pixmap, ChartonError> {
         // 1. Create a mutable clone for the stateful rendering phase.
         // This ensures the training phase doesn't mutate the original chart instance.
         let mut chart_instance = self.clone();
 
         // 2. Initialize the Pixmap (pixel buffer).
         // If dimensions are invalid or memory allocation fails, we return a descriptive Render error.
-        let mut pixmap = tiny_skia::Pixmap::new(
-            (self.width as f32 * self.scale_factor) as u32,
-            (self.height as f32 * self.scale_factor) as u32,
-        )
-        .ok_or_else(|| {
-            ChartonError::Render("Invalid chart dimensions or out of memory for Pixmap".to_string())
-        })?;
+        let mut pixmap =
+            crate::render::backend::raster::allocate_pixmap(self.width, self.height, scale)?;
 
         // 3. Localized Backend Scope.
         {
-            // Renders the chart using the configured scale_factor (defaulting to 2.0x for High-DPI quality).
+            // Renders the chart at the requested scale (to_png defaults to 2.0x for High-DPI quality).
             // The backend automatically retrieves the globally cached 'static font from utils.
             let mut backend =
-                crate::render::backend::raster::RasterBackend::new(&mut pixmap, self.scale_factor);
+                crate::render::backend::raster::RasterBackend::new(&mut pixmap, scale);
 
             // Render Background.
             // We use the backend's draw_rect to ensure the background is the first
@@ -848,13 +2211,7 @@ impl LayeredChart {
             chart_instance.render(&mut backend)?;
         }
 
-        // 4. Finalize PNG Document.
-        // Encode the raw pixel buffer into a standard PNG byte stream.
-        let png_bytes = pixmap
-            .encode_png()
-            .map_err(|e| ChartonError::Render(format!("Failed to encode PNG: {}", e)))?;
-
-        Ok(png_bytes)
+        Ok(pixmap)
     }
 
     /// Generate the chart and display in Jupyter
@@ -901,7 +2258,9 @@ impl LayeredChart {
     /// Generate the chart and save to file
     ///
     /// Renders the chart and saves it to the specified file path. The format is determined
-    /// by the file extension in the path. Currently, only SVG and PNG format are supported.
+    /// by the file extension in the path: SVG, PNG (`raster` feature), PDF (`pdf` feature),
+    /// JPEG (`.jpg`/`.jpeg`, `jpeg` feature) or WebP (`webp` feature).
+    /// A PDF holds a single page of `width` × `height` points, with the fonts embedded.
     ///
     /// # Arguments
     ///
@@ -909,7 +2268,7 @@ impl LayeredChart {
     ///
     /// # Returns
     ///
-    /// Returns a Result indicating success or a ChartonError if SVG/PNG generation or file writing fails
+    /// Returns a Result indicating success or a ChartonError if the image generation or file writing fails
     ///
     /// # Example
     ///
@@ -947,23 +2306,7 @@ impl LayeredChart {
             Some("pdf") => {
                 #[cfg(feature = "pdf")]
                 {
-                    let svg_content = self.to_svg()?;
-                    let mut opts = svg2pdf::usvg::Options::default();
-                    opts.fontdb = crate::core::utils::get_font_db();
-
-                    // Parse the raw SVG string into a usvg render tree
-                    let tree = svg2pdf::usvg::Tree::from_str(&svg_content, &opts)
-                        .map_err(|e| ChartonError::Render(format!("SVG parsing error: {:?}", e)))?;
-
-                    // Compile the tree into standard binary PDF bytes
-                    let pdf_data = svg2pdf::to_pdf(
-                        &tree,
-                        svg2pdf::ConversionOptions::default(),
-                        svg2pdf::PageOptions::default(),
-                    )
-                    .map_err(|e| ChartonError::Render(format!("PDF generation error: {:?}", e)))?;
-
-                    std::fs::write(path_obj, pdf_data).map_err(ChartonError::Io)?;
+                    std::fs::write(path_obj, self.to_pdf()?).map_err(ChartonError::Io)?;
                 }
                 #[cfg(not(feature = "pdf"))]
                 {
@@ -974,25 +2317,49 @@ impl LayeredChart {
             }
             Some("png") => {
                 // Branch 1: High-performance GPU-accelerated rendering via wgpu
-                #[cfg(all(feature = "wgpu", feature = "png"))]
+                #[cfg(all(feature = "wgpu", feature = "raster"))]
                 {
                     // Block on the async GPU pipeline to execute synchronously within this thread context
                     pollster::block_on(self.save_wgpu_png(path_obj))?;
                 }
 
                 // Branch 2: Standard CPU-bound fallback rendering via tiny-skia
-                #[cfg(all(feature = "png", not(feature = "wgpu")))]
+                #[cfg(all(feature = "raster", not(feature = "wgpu")))]
                 {
                     let png_data = self.to_png()?;
                     std::fs::write(path_obj, png_data).map_err(ChartonError::Io)?;
                 }
 
                 // Branch 3: Guard rail triggered if no raster backends are active
-                #[cfg(not(feature = "png"))]
+                #[cfg(not(feature = "raster"))]
+                {
+                    return Err(ChartonError::Unimplemented(
+                        "PNG export is disabled. Please enable the 'raster' feature (or its alias 'png')"
+                            .to_string(),
+                    ));
+                }
+            }
+            Some("jpg" | "jpeg") => {
+                #[cfg(feature = "jpeg")]
+                {
+                    std::fs::write(path_obj, self.to_jpeg()?).map_err(ChartonError::Io)?;
+                }
+                #[cfg(not(feature = "jpeg"))]
                 {
                     return Err(ChartonError::Unimplemented(
-                        "To save PNG images onto the local file system, you must also enable the 'png' feature."
-                        .to_string()
+                        "JPEG export is disabled. Please enable the 'jpeg' feature".to_string(),
+                    ));
+                }
+            }
+            Some("webp") => {
+                #[cfg(feature = "webp")]
+                {
+                    std::fs::write(path_obj, self.to_webp()?).map_err(ChartonError::Io)?;
+                }
+                #[cfg(not(feature = "webp"))]
+                {
+                    return Err(ChartonError::Unimplemented(
+                        "WebP export is disabled. Please enable the 'webp' feature".to_string(),
                     ));
                 }
             }
@@ -1066,7 +2433,7 @@ impl LayeredChart {
     }
 
     /// Desktop/Headless: Render via WGPU and save to PNG with tiny-skia text compositing.
-    #[cfg(all(feature = "wgpu", feature = "png"))]
+    #[cfg(all(feature = "wgpu", feature = "raster"))]
     pub async fn save_wgpu_png<P: AsRef<std::path::Path>>(
         &self,
         path: P,
@@ -1089,6 +2456,18 @@ impl LayeredChart {
         let scaled_width = (self.width as f32 * self.scale_factor) as u32;
         let scaled_height = (self.height as f32 * self.scale_factor) as u32;
 
+        // Textures beyond the device limit would fail validation with a panic.
+        let max_dim = device.limits().max_texture_dimension_2d;
+        if !(self.scale_factor.is_finite() && self.scale_factor > 0.0)
+            || !(1..=max_dim).contains(&scaled_width)
+            || !(1..=max_dim).contains(&scaled_height)
+        {
+            return Err(ChartonError::Render(format!(
+                "Cannot render a {}x{} PNG at scale {} (GPU limit {}px)",
+                scaled_width, scaled_height, self.scale_factor, max_dim
+            )));
+        }
+
         let texture_size = wgpu::Extent3d {
             width: scaled_width,
             height: scaled_height,
@@ -1170,7 +2549,7 @@ impl LayeredChart {
         });
 
         rx.recv()
-            .unwrap()
+            .map_err(|e| ChartonError::Render(format!("Buffer mapping callback lost: {}", e)))?
             .map_err(|e| ChartonError::Render(format!("Buffer mapping failed: {:?}", e)))?;
 
         // 6. Convert raw texture pixels to tiny_skia compatible layout (BGRA Premultiplied)
@@ -1233,9 +2612,7 @@ impl LayeredChart {
         }
 
         // 9. Encode image and serialize to filesystem target
-        let png_bytes = pixmap
-            .encode_png()
-            .map_err(|e| ChartonError::Render(format!("PNG encoding failed: {}", e)))?;
+        let png_bytes = crate::render::backend::raster::encode_png(&pixmap, self.scale_factor)?;
         std::fs::write(path, png_bytes).map_err(ChartonError::Io)?;
 
         Ok(())
@@ -1327,25 +2704,28 @@ impl LayeredChart {
                     .ok_or_else(|| ChartonError::Render("Failed to cast to HtmlElement".into()))?;
 
                 // Style configurations for matching overlay alignment and blending
-                html_element
-                    .style()
-                    .set_property("position", "absolute")
-                    .unwrap();
-                html_element.style().set_property("top", "0").unwrap();
-                html_element.style().set_property("left", "0").unwrap();
-                html_element.style().set_property("width", "100%").unwrap();
-                html_element.style().set_property("height", "100%").unwrap();
-                html_element
-                    .style()
-                    .set_property("pointer-events", "none")
-                    .unwrap();
-                html_element
-                    .style()
-                    .set_property("background", "transparent")
-                    .unwrap();
+                let style = html_element.style();
+                for (property, value) in [
+                    ("position", "absolute"),
+                    ("top", "0"),
+                    ("left", "0"),
+                    ("width", "100%"),
+                    ("height", "100%"),
+                    ("pointer-events", "none"),
+                    ("background", "transparent"),
+                ] {
+                    style.set_property(property, value).map_err(|_| {
+                        ChartonError::Render(format!(
+                            "Failed to set text layer style '{}'",
+                            property
+                        ))
+                    })?;
+                }
 
                 if let Some(parent) = host_canvas.parent_node() {
-                    parent.append_child(&text_canvas).unwrap();
+                    parent.append_child(&text_canvas).map_err(|_| {
+                        ChartonError::Render("Failed to attach the text layer".into())
+                    })?;
                 }
 
                 let instance = wgpu::Instance::default();
@@ -1463,8 +2843,8 @@ impl LayeredChart {
             // Execute the native high-performance Canvas2D HTML text rendering pass
             for config in text_ledger {
                 let font = format!(
-                    "{} {}px {}",
-                    config.font_weight, config.font_size, config.font_family
+                    "{} {} {}px {}",
+                    config.font_style, config.font_weight, config.font_size, config.font_family
                 );
                 ctx.set_font(&font);
 
@@ -1502,3 +2882,63 @@ impl LayeredChart {
         }
     }
 }
+
+/// A layer part drawn in the facet panels matching its key: one value per
+/// facet field, or `None` along fields the layer does not have.
+type FacetPart = (Vec<Option<String>>, Arc<dyn Layer>);
+
+/// Splits a layer by each facet field in turn, keyed by the field values.
+fn split_facets(layer: Arc<dyn Layer>, fields: &[String]) -> Vec<FacetPart> {
+    let Some((field, rest)) = fields.split_first() else {
+        return vec![(Vec::new(), layer)];
+    };
+    let keyed = |value: Option<String>, part: Arc<dyn Layer>| {
+        split_facets(part, rest)
+            .into_iter()
+            .map(move |(mut key, leaf)| {
+                key.insert(0, value.clone());
+                (key, leaf)
+            })
+    };
+    match layer.facet_partition(field) {
+        Some(parts) => parts
+            .into_iter()
+            .flat_map(|(value, part)| keyed(Some(value), part))
+            .collect(),
+        None => keyed(None, layer).collect(),
+    }
+}
+
+/// Whether a consolidated continuous domain is too narrow to scale.
+///
+/// The test is relative to the magnitude of the bounds, so data living entirely
+/// at `1e-15` still counts as a real range.
+fn is_degenerate_range(min: f64, max: f64) -> bool {
+    let magnitude = min.abs().max(max.abs());
+    max - min <= magnitude * 1e-12
+}
+
+/// Widens a degenerate domain by half its magnitude on each side.
+///
+/// A domain at zero has no magnitude to scale by and falls back to ±0.5.
+fn pad_degenerate_range(min: f64, max: f64) -> (f64, f64) {
+    let magnitude = min.abs().max(max.abs());
+    let pad = if magnitude >= f64::MIN_POSITIVE {
+        0.5 * magnitude
+    } else {
+        0.5
+    };
+    (min - pad, max + pad)
+}
+
+/// Whether `name` is an XML attribute name outside the reserved `xml` prefix.
+///
+/// Colons are rejected as well, since a namespace prefix would need a
+/// declaration on the root element.
+fn is_xml_attribute_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    let starts_well = chars.next().is_some_and(|c| c.is_alphabetic() || c == '_');
+    starts_well
+        && chars.all(|c| c.is_alphanumeric() || matches!(c, '-' | '.' | '_'))
+        && !name.to_ascii_lowercase().starts_with("xml")
+}
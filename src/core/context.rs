@@ -1,7 +1,10 @@
 use crate::coordinate::{CoordinateTrait, Rect};
 use crate::core::aesthetics::GlobalAesthetics;
+use crate::core::format::FormatRegistry;
+use crate::encode::Channel;
+use crate::scale::ScaleTrait;
 use crate::theme::Theme;
-use std::sync::Arc;
+use std::sync::{Arc, LazyLock};
 
 /// `ChartSpec` (Chart Specification) represents the global blueprint of a chart.
 ///
@@ -14,6 +17,27 @@ pub struct ChartSpec<'a> {
 
     /// The visual theme defining the look and feel (fonts, grid lines, margins).
     pub theme: &'a Theme,
+
+    /// Number formats of data fields, shared by ticks, labels and tooltips.
+    pub(crate) formats: &'a FormatRegistry,
+}
+
+impl<'a> ChartSpec<'a> {
+    /// Creates a spec without any field or channel number formats.
+    pub fn new(aesthetics: &'a GlobalAesthetics, theme: &'a Theme) -> Self {
+        static NO_FORMATS: LazyLock<FormatRegistry> = LazyLock::new(FormatRegistry::default);
+        Self {
+            aesthetics,
+            theme,
+            formats: &NO_FORMATS,
+        }
+    }
+
+    /// Uses the number formats of a chart.
+    pub(crate) const fn with_formats(mut self, formats: &'a FormatRegistry) -> Self {
+        self.formats = formats;
+        self
+    }
 }
 
 /// `PanelContext` provides the localized rendering environment for a specific 2D area.
@@ -84,6 +108,78 @@ impl<'a> PanelContext<'a> {
         self.transform(0.0, y_norm).1
     }
 
+    // --- Categorical Bands ---
+    //
+    // A discrete axis divides its length into equal bands, one per category, with
+    // marks centered in their band. The helpers below describe those bands in
+    // pixels so that custom layers can size their glyphs like the built-in
+    // bar, box and violin marks. They assume a Cartesian panel.
+
+    /// Returns the scale of a positional channel (`Channel::X` or `Channel::Y`).
+    fn axis_scale(&self, axis: Channel) -> Option<&dyn ScaleTrait> {
+        match axis {
+            Channel::X => Some(self.coord.get_x_scale()),
+            Channel::Y => Some(self.coord.get_y_scale()),
+            _ => None,
+        }
+    }
+
+    /// Whether the channel is drawn horizontally, taking `coord_flip` into account.
+    fn is_horizontal(&self, axis: Channel) -> bool {
+        (axis == Channel::X) != self.coord.is_flipped()
+    }
+
+    /// Returns the ordered categories of a discrete positional axis.
+    ///
+    /// Returns `None` for continuous or temporal axes.
+    pub fn categories(&self, axis: Channel) -> Option<&[String]> {
+        self.axis_scale(axis)?.categories()
+    }
+
+    /// Returns the pixel width of one category band on a discrete positional axis.
+    ///
+    /// Returns `None` for continuous or temporal axes.
+    pub fn band_width(&self, axis: Channel) -> Option<f64> {
+        let scale = self.axis_scale(axis)?;
+        scale.categories()?;
+        let step = (scale.normalize(1.0) - scale.normalize(0.0)).abs();
+        let length = if self.is_horizontal(axis) {
+            self.panel.width
+        } else {
+            self.panel.height
+        };
+        Some(step * length)
+    }
+
+    /// Returns the `(start, end)` pixel positions of every category band, relative
+    /// to the panel origin and in category order (see [`Self::categories`]).
+    ///
+    /// Horizontal bands are measured from the left edge of the panel, vertical ones
+    /// from the top edge, so `start < end` always holds. Returns `None` for
+    /// continuous or temporal axes.
+    pub fn band_edges(&self, axis: Channel) -> Option<Vec<(f64, f64)>> {
+        let scale = self.axis_scale(axis)?;
+        let count = scale.categories()?.len();
+        let half_step = (scale.normalize(1.0) - scale.normalize(0.0)).abs() / 2.0;
+        let horizontal = self.is_horizontal(axis);
+
+        let edges = (0..count)
+            .map(|i| {
+                let center = scale.normalize(i as f64);
+                let (lo, hi) = (center - half_step, center + half_step);
+                if horizontal {
+                    (lo * self.panel.width, hi * self.panel.width)
+                } else {
+                    (
+                        (1.0 - hi) * self.panel.height,
+                        (1.0 - lo) * self.panel.height,
+                    )
+                }
+            })
+            .collect();
+        Some(edges)
+    }
+
     /// Provides direct access to the global theme.
     pub const fn theme(&self) -> &Theme {
         self.spec.theme
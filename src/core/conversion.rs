@@ -1,11 +1,22 @@
 use crate::chart::Chart;
 use crate::coordinate::CoordSystem;
+use crate::core::annotation::Annotation;
 use crate::core::composite::LayeredChart;
+use crate::core::inset::InsetSpec;
 use crate::core::layer::Layer;
+use crate::core::reference_line::ReferenceLine;
+use crate::core::shared_scales::SharedScales;
+use crate::encode::Channel;
 use crate::error::ChartonError;
+use crate::facets::{FacetGrid, FacetStrategy, FacetWrap};
 use crate::mark::Mark;
-use crate::scale::{Expansion, IntoExplicitTicks, ScaleDomain};
+use crate::scale::{
+    Expansion, IntoExplicitTicks, ScaleDomain,
+    inverse::{InverseTransform, TickFormatter},
+};
 use crate::theme::Theme;
+use crate::visual::color::{ColorPalette, PaletteOverflow, SingleColor};
+use std::sync::Arc;
 
 /// A unified interface for configuring and rendering visualizations and API.
 ///
@@ -25,19 +36,121 @@ pub trait IntoLayered: Into<LayeredChart> + Clone {
 
         // 1. Merge layers
         lc.layers.append(&mut other_lc.layers);
+        lc.insets.append(&mut other_lc.insets);
+        lc.annotations.append(&mut other_lc.annotations);
+        lc.reference_lines.append(&mut other_lc.reference_lines);
 
         // 2. Resolve metadata (Optional: Left-side priority)
         // If the left side doesn't have a title/label, take it from the right side.
         if lc.title.is_none() {
             lc.title = other_lc.title;
         }
+        if lc.title_href.is_none() {
+            lc.title_href = other_lc.title_href;
+        }
+        if lc.color_domain.is_none() {
+            lc.color_domain = other_lc.color_domain;
+        }
+        if lc.color_range.is_none() {
+            lc.color_range = other_lc.color_range;
+        }
+        if lc.center_text.is_none() {
+            lc.center_text = other_lc.center_text;
+        }
+        for (name, value) in other_lc.metadata {
+            if !lc.metadata.iter().any(|(n, _)| *n == name) {
+                lc.metadata.push((name, value));
+            }
+        }
         if lc.x_label.is_none() {
             lc.x_label = other_lc.x_label;
         }
         if lc.y_label.is_none() {
             lc.y_label = other_lc.y_label;
         }
+        if lc.x_time_format.is_none() {
+            lc.x_time_format = other_lc.x_time_format;
+        }
+        if lc.y_time_format.is_none() {
+            lc.y_time_format = other_lc.y_time_format;
+        }
+        if lc.x_tick_inverse.is_none() {
+            lc.x_tick_inverse = other_lc.x_tick_inverse;
+        }
+        if lc.y_tick_inverse.is_none() {
+            lc.y_tick_inverse = other_lc.y_tick_inverse;
+        }
+        lc.formats.merge(other_lc.formats);
+        lc.x_reversed |= other_lc.x_reversed;
+        lc.y_reversed |= other_lc.y_reversed;
+        if lc.y2_label.is_none() {
+            lc.y2_label = other_lc.y2_label;
+        }
+        if lc.y2_domain.is_none() {
+            lc.y2_domain = other_lc.y2_domain;
+        }
+        if lc.facet.is_none() {
+            lc.facet = other_lc.facet;
+        }
+        if lc.shared_scales.is_none() {
+            lc.shared_scales = other_lc.shared_scales;
+        }
+
+        lc
+    }
+
+    /// Draws another chart inside this chart's panel.
+    ///
+    /// The inset keeps its own scales; `spec` controls where it is placed, which
+    /// data window it zooms into and whether that window is marked on this chart.
+    fn with_inset<L: Into<LayeredChart>>(self, inner: L, spec: InsetSpec) -> LayeredChart {
+        let mut lc: LayeredChart = self.into();
+        lc.insets.push((inner.into(), spec));
+        lc
+    }
+
+    /// Adds a text label at the data position (`x`, `y`).
+    ///
+    /// The label is mapped through the chart's scales like a mark, so it stays
+    /// on its data point. Use [`IntoLayered::annotate`] for offsets, arrows and
+    /// alignment.
+    fn with_annotation(self, x: f64, y: f64, text: &str) -> LayeredChart {
+        self.annotate(Annotation::new(x, y, text))
+    }
+
+    /// Adds a fully configured [`Annotation`].
+    fn annotate(self, annotation: Annotation) -> LayeredChart {
+        let mut lc: LayeredChart = self.into();
+        lc.annotations.push(annotation);
+        lc
+    }
+
+    /// Adds a horizontal line across the panel at the data value `y`.
+    ///
+    /// No data column is needed; use [`IntoLayered::with_reference_line`] to
+    /// set the color, width or dash pattern.
+    fn with_hline(self, y: f64) -> LayeredChart {
+        self.with_reference_line(ReferenceLine::horizontal(y))
+    }
+
+    /// Adds a vertical line across the panel at the data value `x`.
+    fn with_vline(self, x: f64) -> LayeredChart {
+        self.with_reference_line(ReferenceLine::vertical(x))
+    }
 
+    /// Adds a fully configured [`ReferenceLine`].
+    fn with_reference_line(self, line: ReferenceLine) -> LayeredChart {
+        let mut lc: LayeredChart = self.into();
+        lc.reference_lines.push(line);
+        lc
+    }
+
+    /// Adds a custom layer, such as a user-defined mark implementing [`Layer`].
+    ///
+    /// The layer takes part in scale training and is drawn after the existing layers.
+    fn with_layer<L: Layer + 'static>(self, layer: L) -> LayeredChart {
+        let mut lc: LayeredChart = self.into();
+        lc.layers.push(Arc::new(layer));
         lc
     }
 
@@ -87,12 +200,69 @@ pub trait IntoLayered: Into<LayeredChart> + Clone {
         lc
     }
 
+    /// Shows or hides the grid lines of both axes, overriding the theme.
+    ///
+    /// Style them with `with_grid_line_color`, `with_grid_line_width`,
+    /// `with_grid_line_dash` and `with_grid_opacity`.
     fn with_grid(self, show: bool) -> LayeredChart {
         let mut lc: LayeredChart = self.into();
         lc.show_grid = Some(show);
         lc
     }
 
+    /// Shows or hides the grid lines at the x-axis ticks, overriding `with_grid`.
+    ///
+    /// The lines follow the x scale, so they run horizontally after `coord_flip()`.
+    fn with_x_grid(self, show: bool) -> LayeredChart {
+        let mut lc: LayeredChart = self.into();
+        lc.x_grid = Some(show);
+        lc
+    }
+
+    /// Shows or hides the grid lines at the y-axis ticks, overriding `with_grid`.
+    fn with_y_grid(self, show: bool) -> LayeredChart {
+        let mut lc: LayeredChart = self.into();
+        lc.y_grid = Some(show);
+        lc
+    }
+
+    /// Sets the color of grid lines, overriding the theme.
+    fn with_grid_line_color(self, color: &str) -> LayeredChart {
+        let mut lc: LayeredChart = self.into();
+        lc.theme.grid_color = SingleColor::new(color);
+        lc
+    }
+
+    /// Sets the stroke width of grid lines, overriding the theme.
+    fn with_grid_line_width(self, width: f64) -> LayeredChart {
+        let mut lc: LayeredChart = self.into();
+        lc.theme.grid_width = width;
+        lc
+    }
+
+    /// Sets the dash pattern of grid lines in SVG `stroke-dasharray` syntax,
+    /// e.g. `"4 2"` or `"4,2"`. An empty pattern or `"none"` draws solid lines.
+    ///
+    /// A pattern with negative or non-numeric lengths is reported and ignored.
+    fn with_grid_line_dash(self, pattern: &str) -> LayeredChart {
+        let mut lc: LayeredChart = self.into();
+        match parse_dash_pattern(pattern) {
+            Some(dash) => lc.theme.grid_dash = dash,
+            None => eprintln!(
+                "Grid line dash: '{}' is not a list of non-negative lengths; ignored.",
+                pattern
+            ),
+        }
+        lc
+    }
+
+    /// Sets the opacity of grid lines, from 0.0 to 1.0, overriding the theme.
+    fn with_grid_opacity(self, opacity: f64) -> LayeredChart {
+        let mut lc: LayeredChart = self.into();
+        lc.theme = lc.theme.with_grid_opacity(opacity);
+        lc
+    }
+
     // --- Aesthetic Styling ---
 
     fn with_theme(self, theme: Theme) -> LayeredChart {
@@ -111,6 +281,15 @@ pub trait IntoLayered: Into<LayeredChart> + Clone {
         lc
     }
 
+    /// Shows or hides the legend. A hidden legend reserves no space, so the
+    /// panel takes the full width; `LayeredChart::legend_svg` still renders it
+    /// on its own.
+    fn with_legend(self, show: bool) -> LayeredChart {
+        let mut lc: LayeredChart = self.into();
+        lc.theme.show_legend = show;
+        lc
+    }
+
     /// Sets the global chart title.
     fn with_title<S: Into<String>>(self, title: S) -> LayeredChart {
         let mut lc: LayeredChart = self.into();
@@ -118,6 +297,39 @@ pub trait IntoLayered: Into<LayeredChart> + Clone {
         lc
     }
 
+    /// Links the chart title to a URL, e.g. the canonical page of the figure.
+    ///
+    /// In SVG output the title text is wrapped in an `<a>` element; raster
+    /// and PDF output draw the title without the link.
+    fn with_title_href<S: Into<String>>(self, url: S) -> LayeredChart {
+        let mut lc: LayeredChart = self.into();
+        lc.title_href = Some(url.into());
+        lc
+    }
+
+    /// Adds attributes to the root `<svg>` element, e.g. `data-figure-id`.
+    ///
+    /// Each name may be given once; a later call replaces the value of a name
+    /// already set. Names must be XML attribute names other than the root's
+    /// own `width`, `height`, `viewBox` and `xmlns`, and values must be valid
+    /// XML text; `to_svg` reports anything else as a `ChartonError::Render`.
+    /// Raster and PDF output ignore the metadata.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// chart.with_metadata(&[("data-figure-id", "fig-12"), ("data-source", "warehouse.sales")])
+    /// ```
+    fn with_metadata(self, attributes: &[(&str, &str)]) -> LayeredChart {
+        let mut lc: LayeredChart = self.into();
+        for &(name, value) in attributes {
+            match lc.metadata.iter_mut().find(|(n, _)| n == name) {
+                Some(existing) => existing.1 = value.to_string(),
+                None => lc.metadata.push((name.to_string(), value.to_string())),
+            }
+        }
+        lc
+    }
+
     // --- Axis Data & Scale Configuration ---
 
     /// Set the global X-axis domain, overriding automatic data range calculation.
@@ -127,6 +339,20 @@ pub trait IntoLayered: Into<LayeredChart> + Clone {
         lc
     }
 
+    /// Orders the categories of a discrete x-axis.
+    ///
+    /// Sets both the tick order and where the marks land. Categories found in
+    /// the data but missing from `order` follow it in order of appearance, and
+    /// listed categories without data keep an empty slot, so layers and
+    /// charts with partial data line up.
+    fn with_x_category_order<S: Into<String>>(self, order: Vec<S>) -> LayeredChart {
+        let mut lc: LayeredChart = self.into();
+        lc.x_domain = Some(ScaleDomain::Discrete(
+            order.into_iter().map(Into::into).collect(),
+        ));
+        lc
+    }
+
     /// Set the X-axis expansion (padding).
     fn with_x_expand(self, expand: Expansion) -> LayeredChart {
         let mut lc: LayeredChart = self.into();
@@ -146,6 +372,43 @@ pub trait IntoLayered: Into<LayeredChart> + Clone {
         lc
     }
 
+    /// Sets the tick label format of a temporal X-axis using strftime-style
+    /// specifiers, e.g. `"%Y-%m"` or `"%b %d"`.
+    ///
+    /// Without a format, labels follow the tick interval chosen for the data span.
+    fn with_x_time_format(self, format: &str) -> LayeredChart {
+        let mut lc: LayeredChart = self.into();
+        lc.x_time_format = Some(format.to_string());
+        lc
+    }
+
+    /// Labels the X-axis ticks of pre-transformed data in original units.
+    ///
+    /// See `with_y_tick_inverse_transform`.
+    fn with_x_tick_inverse_transform<I: Into<InverseTransform>>(self, inverse: I) -> LayeredChart {
+        let mut lc: LayeredChart = self.into();
+        lc.x_tick_inverse = Some(inverse.into());
+        lc
+    }
+
+    /// Writes the tick labels of a continuous X-axis with `formatter`.
+    ///
+    /// See `with_y_tick_formatter`.
+    fn with_x_tick_formatter<F: Into<TickFormatter>>(self, formatter: F) -> LayeredChart {
+        let mut lc: LayeredChart = self.into();
+        lc.formats.insert_channel(Channel::X, formatter.into());
+        lc
+    }
+
+    /// Asks for about `n` ticks on a continuous X-axis.
+    ///
+    /// See `with_y_tick_count`.
+    fn with_x_tick_count(self, n: usize) -> LayeredChart {
+        let mut lc: LayeredChart = self.into();
+        lc.theme.x_tick_count = Some(n);
+        lc
+    }
+
     /// Set the global Y-axis domain.
     fn with_y_domain(self, min: f64, max: f64) -> LayeredChart {
         let mut lc: LayeredChart = self.into();
@@ -153,6 +416,17 @@ pub trait IntoLayered: Into<LayeredChart> + Clone {
         lc
     }
 
+    /// Orders the categories of a discrete y-axis.
+    ///
+    /// See [`IntoLayered::with_x_category_order`].
+    fn with_y_category_order<S: Into<String>>(self, order: Vec<S>) -> LayeredChart {
+        let mut lc: LayeredChart = self.into();
+        lc.y_domain = Some(ScaleDomain::Discrete(
+            order.into_iter().map(Into::into).collect(),
+        ));
+        lc
+    }
+
     /// Set the Y-axis expansion (padding).
     fn with_y_expand(self, expand: Expansion) -> LayeredChart {
         let mut lc: LayeredChart = self.into();
@@ -172,6 +446,183 @@ pub trait IntoLayered: Into<LayeredChart> + Clone {
         lc
     }
 
+    /// Sets the tick label format of a temporal Y-axis (see `with_x_time_format`).
+    fn with_y_time_format(self, format: &str) -> LayeredChart {
+        let mut lc: LayeredChart = self.into();
+        lc.y_time_format = Some(format.to_string());
+        lc
+    }
+
+    /// Labels the Y-axis ticks of pre-transformed data in original units.
+    ///
+    /// Tick positions and the domain stay in transformed units; only the label
+    /// text is mapped through `inverse` and written with thousands separators.
+    /// Accepts the `InverseTransform` presets or any `Fn(f64) -> f64`.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// // A log2(CPM) axis with ticks labelled in CPM: 10.2 reads "1,176".
+    /// chart.with_y_tick_inverse_transform(InverseTransform::Exp2)
+    /// ```
+    fn with_y_tick_inverse_transform<I: Into<InverseTransform>>(self, inverse: I) -> LayeredChart {
+        let mut lc: LayeredChart = self.into();
+        lc.y_tick_inverse = Some(inverse.into());
+        lc
+    }
+
+    /// Writes the tick labels of a continuous Y-axis with `formatter`.
+    ///
+    /// Accepts a built-in `TickFormat` such as `Percent`, `SiSuffix`,
+    /// `Scientific` or `Precision(2)`, or any `Fn(f64) -> String`. Tick positions are unchanged,
+    /// and with an inverse transform the formatter receives original units.
+    /// Discrete and temporal axes keep their labels.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// chart.with_y_tick_formatter(|v| format!("${:.0}", v))
+    /// ```
+    fn with_y_tick_formatter<F: Into<TickFormatter>>(self, formatter: F) -> LayeredChart {
+        let mut lc: LayeredChart = self.into();
+        lc.formats.insert_channel(Channel::Y, formatter.into());
+        lc
+    }
+
+    /// Asks for about `n` ticks on a continuous Y-axis.
+    ///
+    /// The step is the "nice" number (1, 2, 2.5 or 5 times a power of ten) at or
+    /// above `range / n`, so the axis shows roughly `n` round values. Ticks set
+    /// with `with_y_ticks` take precedence, and `n = 0` hides the ticks and
+    /// their labels. Discrete, log and temporal axes treat `n` as a hint.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// chart.with_y_tick_count(5)
+    /// ```
+    fn with_y_tick_count(self, n: usize) -> LayeredChart {
+        let mut lc: LayeredChart = self.into();
+        lc.theme.y_tick_count = Some(n);
+        lc
+    }
+
+    /// Sets the number format of a data field wherever its values are written.
+    ///
+    /// Axis ticks and the colorbar of the field, stack labels of a bar's value
+    /// field, text marks and tooltips all use it, so one setting keeps them
+    /// consistent. Channel-specific options such as `with_y_tick_formatter` or
+    /// a tooltip's `with_format` take precedence.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// chart.with_field_format("revenue", TickFormat::SiSuffix)
+    /// ```
+    fn with_field_format<F: Into<TickFormatter>>(self, field: &str, format: F) -> LayeredChart {
+        let mut lc: LayeredChart = self.into();
+        lc.formats.insert(field, format.into());
+        lc
+    }
+
+    /// Writes the tick labels of a continuous colorbar with `formatter`
+    /// (see `with_y_tick_formatter`).
+    fn with_color_tick_formatter<F: Into<TickFormatter>>(self, formatter: F) -> LayeredChart {
+        let mut lc: LayeredChart = self.into();
+        lc.formats.insert_channel(Channel::Color, formatter.into());
+        lc
+    }
+
+    /// Runs the X-axis from its maximum on the left to its minimum on the right.
+    ///
+    /// Marks, ticks and grid lines all follow the mirrored axis; categories of
+    /// a discrete axis are listed right to left. With `coord_flip` this is the
+    /// vertical axis.
+    fn with_x_reversed(self, reversed: bool) -> LayeredChart {
+        let mut lc: LayeredChart = self.into();
+        lc.x_reversed = reversed;
+        lc
+    }
+
+    /// Runs the Y-axis from its maximum at the bottom to its minimum at the top,
+    /// e.g. for depth or rank. Bars then hang from their baseline at the top.
+    /// The secondary Y-axis is not affected.
+    fn with_y_reversed(self, reversed: bool) -> LayeredChart {
+        let mut lc: LayeredChart = self.into();
+        lc.y_reversed = reversed;
+        lc
+    }
+
+    /// Set the domain of the secondary Y-axis used by layers with `y(..).axis_secondary()`
+    /// or `with_y_axis_side(YAxisSide::Right)`.
+    fn with_y2_domain(self, min: f64, max: f64) -> LayeredChart {
+        let mut lc: LayeredChart = self.into();
+        lc.y2_domain = Some(ScaleDomain::Continuous(min, max));
+        lc
+    }
+
+    /// Set the title of the secondary Y-axis.
+    fn with_y2_label<S: Into<String>>(self, label: S) -> LayeredChart {
+        let mut lc: LayeredChart = self.into();
+        lc.y2_label = Some(label.into());
+        lc
+    }
+
+    /// Set the title of the secondary Y-axis. Same as [`IntoLayered::with_y2_label`].
+    fn with_secondary_y_label<S: Into<String>>(self, label: S) -> LayeredChart {
+        self.with_y2_label(label)
+    }
+
+    /// Sets how a discrete color scale handles more categories than palette colors.
+    ///
+    /// Accepts `PaletteOverflow` variants or "cycle", "interpolate" and "error".
+    fn with_palette_overflow<P: Into<PaletteOverflow>>(self, policy: P) -> LayeredChart {
+        let mut lc: LayeredChart = self.into();
+        lc.palette_overflow = Some(policy.into());
+        lc
+    }
+
+    /// Fixes the categories of a discrete color scale and their order.
+    ///
+    /// The legend lists the categories in this order, including those absent
+    /// from the data, so colors stay put when the data is filtered. Categories
+    /// found in the data but missing here are appended with a warning.
+    fn with_color_domain<S: Into<String>>(self, domain: Vec<S>) -> LayeredChart {
+        let mut lc: LayeredChart = self.into();
+        lc.color_domain = Some(ScaleDomain::Discrete(
+            domain.into_iter().map(Into::into).collect(),
+        ));
+        lc
+    }
+
+    /// Assigns explicit colors to the categories of a discrete color scale.
+    ///
+    /// The colors pair up by position with [`IntoLayered::with_color_domain`],
+    /// or with the categories in order of appearance when no domain is set.
+    /// Categories without a color fall back to the palette with a warning.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// chart
+    ///     .with_color_domain(vec!["setosa", "versicolor", "virginica"])
+    ///     .with_color_range(vec!["#1f77b4", "#2ca02c", "red"])
+    /// ```
+    fn with_color_range<S: Into<String>>(self, range: Vec<S>) -> LayeredChart {
+        let mut lc: LayeredChart = self.into();
+        lc.color_range = Some(
+            range
+                .into_iter()
+                .map(|c| SingleColor::new(&c.into()))
+                .collect(),
+        );
+        lc
+    }
+
+    /// Sets the palette used by discrete color scales (e.g. grouped violins or bars).
+    ///
+    /// Shorthand for replacing the palette of the current theme.
+    fn with_color_palette<P: Into<ColorPalette>>(self, palette: P) -> LayeredChart {
+        let mut lc: LayeredChart = self.into();
+        lc.theme.palette = palette.into();
+        lc
+    }
+
     fn with_shape_label<S: Into<String>>(self, label: S) -> LayeredChart {
         let mut lc: LayeredChart = self.into();
         lc.shape_label = Some(label.into());
@@ -200,6 +651,14 @@ pub trait IntoLayered: Into<LayeredChart> + Clone {
         lc
     }
 
+    /// Switches to polar coordinates: x maps to the angle and y to the radius.
+    ///
+    /// Shorthand for `with_coord(CoordSystem::Polar)`, used for rose, radial bar
+    /// and pie charts.
+    fn with_coord_polar(self) -> LayeredChart {
+        self.with_coord(CoordSystem::Polar)
+    }
+
     // --- Polar Context Overrides ---
 
     /// Sets the starting angle for polar coordinates (in radians).
@@ -223,6 +682,84 @@ pub trait IntoLayered: Into<LayeredChart> + Clone {
         lc
     }
 
+    /// Sets the hole of a donut chart as a fraction of the outer radius.
+    ///
+    /// A ratio of 0.0 draws a filled pie; larger ratios turn every slice into
+    /// an annular sector. The ratio must lie in `[0.0, 1.0)`, otherwise
+    /// rendering fails with [`ChartonError::Encoding`](crate::error::ChartonError::Encoding).
+    fn with_inner_radius_ratio(self, ratio: f64) -> LayeredChart {
+        self.with_inner_radius(ratio)
+    }
+
+    /// Writes `text` at the center of a polar chart, e.g. a total in the hole
+    /// of a donut. Ignored by non-polar charts.
+    fn with_center_text(self, text: &str) -> LayeredChart {
+        let mut lc: LayeredChart = self.into();
+        lc.center_text = Some(text.to_string());
+        lc
+    }
+
+    // --- Faceting ---
+
+    /// Splits the chart into small multiples, one panel per value of `field`.
+    ///
+    /// Panels wrap into a near-square grid inside the chart, each headed by a
+    /// strip with its value, and share the x and y scales of the whole chart.
+    /// Unused categories of a categorical column still get an (empty) panel.
+    fn facet(self, field: &str) -> LayeredChart {
+        let mut lc: LayeredChart = self.into();
+        lc.facet = Some(Arc::new(FacetWrap {
+            field: field.to_string(),
+            strategy: FacetStrategy::Fixed,
+            rows: None,
+            cols: None,
+        }));
+        lc
+    }
+
+    /// Splits the chart into small multiples laid out in `columns` columns.
+    ///
+    /// Like [`IntoLayered::facet`], but the panels fill rows of the given
+    /// width, e.g. 1 to stack them vertically. A width of 0 is treated as 1.
+    fn facet_wrap(self, field: &str, columns: usize) -> LayeredChart {
+        let mut lc: LayeredChart = self.into();
+        lc.facet = Some(Arc::new(FacetWrap {
+            field: field.to_string(),
+            strategy: FacetStrategy::Fixed,
+            rows: None,
+            cols: Some(columns.max(1)),
+        }));
+        lc
+    }
+
+    /// Splits the chart into a matrix of panels, one row per value of
+    /// `row_field` and one column per value of `col_field`.
+    ///
+    /// Column values head the top row and row values label the right edge.
+    /// All panels share the x and y scales of the whole chart, so columns are
+    /// directly comparable along x and rows along y. Combinations without
+    /// data are left as empty panels.
+    fn facet_grid(self, row_field: &str, col_field: &str) -> LayeredChart {
+        let mut lc: LayeredChart = self.into();
+        lc.facet = Some(Arc::new(FacetGrid {
+            row_field: row_field.to_string(),
+            col_field: col_field.to_string(),
+            strategy: FacetStrategy::Fixed,
+        }));
+        lc
+    }
+
+    /// Uses scales captured from another chart for x, y and color.
+    ///
+    /// Covered channels take the shared domain, expansion and color mapper
+    /// instead of fitting this chart's data, which keeps separate figures
+    /// directly comparable. Data beyond a shared domain is clipped and reported.
+    fn with_shared_scales(self, scales: &SharedScales) -> LayeredChart {
+        let mut lc: LayeredChart = self.into();
+        lc.shared_scales = Some(scales.clone());
+        lc
+    }
+
     /// Sets the scale factor for rendering.
     ///
     /// # Note
@@ -240,6 +777,26 @@ pub trait IntoLayered: Into<LayeredChart> + Clone {
         lc
     }
 
+    /// Sets the raster resolution in dots per inch, e.g. 300 for print.
+    ///
+    /// Chart sizes are in CSS pixels at 96 DPI, so this is a scale factor of
+    /// `dpi / 96`: an 800 x 600 chart at 300 DPI becomes a 2500 x 1875 PNG.
+    /// The resolution is also recorded in the PNG, so it prints at the
+    /// chart's physical size.
+    fn with_dpi(self, dpi: f32) -> LayeredChart {
+        self.with_scale_factor(dpi / 96.0)
+    }
+
+    /// Sets the JPEG compression quality, from 1 (smallest) to 100 (best).
+    ///
+    /// Only `to_jpeg()` and `save("*.jpg")` use it; the default is 90. The
+    /// pixel size of the JPEG still follows the scale factor.
+    fn with_jpeg_quality(self, quality: u8) -> LayeredChart {
+        let mut lc: LayeredChart = self.into();
+        lc.jpeg_quality = quality;
+        lc
+    }
+
     // --- Terminal Actions ---
 
     /// Generates and returns the SVG representation of the chart.
@@ -254,6 +811,34 @@ pub trait IntoLayered: Into<LayeredChart> + Clone {
         lc.to_svg()
     }
 
+    /// Generates the chart as single-page PDF bytes in memory.
+    #[cfg(feature = "pdf")]
+    fn to_pdf(&self) -> Result<Vec<u8>, ChartonError> {
+        let lc: LayeredChart = self.clone().into();
+        lc.to_pdf()
+    }
+
+    /// Generates the chart as PNG bytes in memory, at the chart's scale factor.
+    #[cfg(feature = "raster")]
+    fn to_png(&self) -> Result<Vec<u8>, ChartonError> {
+        let lc: LayeredChart = self.clone().into();
+        lc.to_png()
+    }
+
+    /// Generates the chart as PNG bytes in memory, at the given pixel density.
+    #[cfg(feature = "raster")]
+    fn to_png_with_scale(&self, scale: f64) -> Result<Vec<u8>, ChartonError> {
+        let lc: LayeredChart = self.clone().into();
+        lc.to_png_with_scale(scale)
+    }
+
+    /// Translates the chart into a Vega-Lite JSON specification, without Python.
+    #[cfg(feature = "vega")]
+    fn to_vega_json(&self) -> Result<String, ChartonError> {
+        let lc: LayeredChart = self.clone().into();
+        lc.to_vega_json()
+    }
+
     fn show(&self) -> Result<(), ChartonError> {
         let lc: LayeredChart = self.clone().into();
         lc.show()
@@ -293,3 +878,19 @@ where
         LayeredChart::new().add_layer(val)
     }
 }
+
+/// Parses an SVG `stroke-dasharray` value into dash and gap lengths.
+///
+/// Returns an empty pattern for `""` or `"none"`, and `None` if any length is
+/// negative or not a number.
+fn parse_dash_pattern(pattern: &str) -> Option<Vec<f64>> {
+    let pattern = pattern.trim();
+    if pattern.eq_ignore_ascii_case("none") {
+        return Some(Vec::new());
+    }
+    pattern
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|t| !t.is_empty())
+        .map(|t| t.parse::<f64>().ok().filter(|v| v.is_finite() && *v >= 0.0))
+        .collect()
+}
@@ -137,6 +137,10 @@ pub enum SemanticType {
     Temporal,
 }
 
+/// Nanoseconds in one day, used to place `Date` values on the same nanosecond
+/// timeline as `Datetime` values when they are read numerically.
+pub(crate) const NANOS_PER_DAY: f64 = 86_400_000_000_000.0;
+
 /// Represents a dynamically-typed scalar value borrowed safely from a ColumnVector.
 /// This acts as the single unified interface for extracting row-level data.
 #[derive(Debug, Clone, PartialEq)]
@@ -186,7 +190,7 @@ impl<'a> AnyValue<'a> {
                     Some(*v)
                 }
             }
-            AnyValue::Date(v) => Some(*v as f64),
+            AnyValue::Date(v) => Some(*v as f64 * NANOS_PER_DAY),
             AnyValue::Datetime(v, _) => Some(*v as f64),
             AnyValue::Duration(v) => Some(*v as f64),
             AnyValue::Time(v) => Some(*v as f64),
@@ -539,9 +543,8 @@ impl ColumnVector {
                 }
             }
 
-            // --- 32-bit Integer & Date Types ---
-            // Date is physically stored as an i32 representing days since the Epoch.
-            ColumnVector::Int32 { data, validity } | ColumnVector::Date { data, validity } => {
+            // --- 32-bit Integer Types ---
+            ColumnVector::Int32 { data, validity } => {
                 for (i, &v) in data.iter().enumerate().take(n) {
                     out.push(if Self::is_valid_in_mask(validity, i) {
                         v as f64
@@ -551,6 +554,19 @@ impl ColumnVector {
                 }
             }
 
+            // --- Date Types ---
+            // Date is physically stored as an i32 representing days since the Epoch;
+            // like Datetime, it is projected onto nanoseconds.
+            ColumnVector::Date { data, validity } => {
+                for (i, &v) in data.iter().enumerate().take(n) {
+                    out.push(if Self::is_valid_in_mask(validity, i) {
+                        v as f64 * NANOS_PER_DAY
+                    } else {
+                        0.0
+                    });
+                }
+            }
+
             // --- Unsigned Integer Types ---
             // Handled separately to accommodate different underlying data types in the enum variants.
             ColumnVector::UInt32 { data, validity } => {
@@ -711,6 +727,27 @@ impl ColumnVector {
         }
     }
 
+    /// Like [`ColumnVector::take`], but a `None` index yields a null row.
+    ///
+    /// Used by joins, where a row without a match keeps its place in the
+    /// output with a null value. The column must not be empty.
+    pub(crate) fn take_or_null(&self, indices: &[Option<usize>]) -> Self {
+        let placeholder: Vec<usize> = indices.iter().map(|i| i.unwrap_or(0)).collect();
+        let mut out = self.take(&placeholder);
+        if indices.iter().all(Option::is_some) {
+            return out;
+        }
+
+        let mut mask = vec![0u8; indices.len().div_ceil(8)];
+        for (new_idx, old_idx) in indices.iter().enumerate() {
+            if old_idx.is_some_and(|i| Self::is_valid_in_mask(self.get_validity_mask(), i)) {
+                mask[new_idx / 8] |= 1 << (new_idx % 8);
+            }
+        }
+        *out.validity_mut() = Some(mask);
+        out
+    }
+
     /// Re-indexes the packed bitmask (validity map) based on the provided row indices.
     ///
     /// This function creates a new bitmask where each bit represents the validity
@@ -782,6 +819,27 @@ impl ColumnVector {
         }
     }
 
+    /// Mutable counterpart of [`ColumnVector::get_validity_mask`].
+    const fn validity_mut(&mut self) -> &mut Option<Vec<u8>> {
+        match self {
+            ColumnVector::Float64 { validity, .. } => validity,
+            ColumnVector::Float32 { validity, .. } => validity,
+            ColumnVector::Int8 { validity, .. } => validity,
+            ColumnVector::Int16 { validity, .. } => validity,
+            ColumnVector::Int32 { validity, .. } => validity,
+            ColumnVector::Int64 { validity, .. } => validity,
+            ColumnVector::UInt32 { validity, .. } => validity,
+            ColumnVector::UInt64 { validity, .. } => validity,
+            ColumnVector::Boolean { validity, .. } => validity,
+            ColumnVector::String { validity, .. } => validity,
+            ColumnVector::Categorical { validity, .. } => validity,
+            ColumnVector::Date { validity, .. } => validity,
+            ColumnVector::Datetime { validity, .. } => validity,
+            ColumnVector::Time { validity, .. } => validity,
+            ColumnVector::Duration { validity, .. } => validity,
+        }
+    }
+
     /// Returns the number of unique non-null values in the column.
     ///
     /// The 'Null' state is determined strictly by the validity bitmask.
@@ -1000,6 +1058,23 @@ impl ColumnVector {
         result
     }
 
+    /// Returns the unique values in the order used for discrete axes and legends.
+    ///
+    /// Numeric columns treated as categories (e.g. cluster ids) are ordered by
+    /// value, so `2` comes before `10`. All other columns keep the ordering of
+    /// [`ColumnVector::unique_values`].
+    pub fn category_values(&self) -> Vec<String> {
+        let mut values = self.unique_values();
+        if matches!(self.semantic_type(), SemanticType::Continuous) {
+            values.sort_by(|a, b| {
+                let a = a.parse::<f64>().unwrap_or(f64::NAN);
+                let b = b.parse::<f64>().unwrap_or(f64::NAN);
+                a.total_cmp(&b)
+            });
+        }
+        values
+    }
+
     /// Internal helper that uses i128 casting to deduplicate various integer and
     /// temporal types into a single stable String vector.
     fn collect_unique_primitives_as_strings(&self, result: &mut Vec<String>) {
@@ -1077,7 +1152,7 @@ impl ColumnVector {
 
                 // --- TEMPORAL PATHS ---
                 ColumnVector::Date { data, validity } => {
-                    self.parallel_scan_with_mask(data, validity, |&v| v as f64)
+                    self.parallel_scan_with_mask(data, validity, |&v| v as f64 * NANOS_PER_DAY)
                 }
                 ColumnVector::Datetime { data, validity, .. } => {
                     self.parallel_scan_with_mask(data, validity, |&v| v as f64)
@@ -1163,7 +1238,7 @@ impl ColumnVector {
                 self.serial_scan_with_mask(data, validity, |&v| v as f64)
             }
             ColumnVector::Date { data, validity } => {
-                self.serial_scan_with_mask(data, validity, |&v| v as f64)
+                self.serial_scan_with_mask(data, validity, |&v| v as f64 * NANOS_PER_DAY)
             }
             ColumnVector::Datetime { data, validity, .. } => {
                 self.serial_scan_with_mask(data, validity, |&v| v as f64)
@@ -2083,6 +2158,32 @@ pub struct GroupedIndices {
     pub groups: Vec<(Option<String>, Vec<usize>)>,
 }
 
+impl GroupedIndices {
+    /// Splits every group further by the values of `col_name`.
+    ///
+    /// Sub-groups keep the label of their parent group and follow the first
+    /// appearance of each value within it; null values form one sub-group.
+    pub(crate) fn split_by(self, ds: &Dataset, col_name: &str) -> Result<Self, ChartonError> {
+        let column = ds.column(col_name)?;
+        let mut groups = Vec::with_capacity(self.groups.len());
+        for (label, rows) in self.groups {
+            let mut positions: AHashMap<Option<String>, usize> = AHashMap::new();
+            let mut parts: Vec<Vec<usize>> = Vec::new();
+            for idx in rows {
+                let slot = *positions
+                    .entry(column.get(idx).to_string())
+                    .or_insert_with(|| {
+                        parts.push(Vec::new());
+                        parts.len() - 1
+                    });
+                parts[slot].push(idx);
+            }
+            groups.extend(parts.into_iter().map(|rows| (label.clone(), rows)));
+        }
+        Ok(Self { groups })
+    }
+}
+
 #[derive(Hash, PartialEq, Eq)]
 enum InternalKey {
     /// Handles i64 (Time/Datetime/Duration), i32 (Date), and raw bits of floats.
@@ -2978,6 +3079,10 @@ pub enum AggregateOp {
     Max,
     /// The total count of records (including or excluding nulls, based on implementation).
     Count,
+    /// Sample standard deviation (n - 1 denominator). NaN for fewer than two values.
+    Std,
+    /// Sample variance (n - 1 denominator). NaN for fewer than two values.
+    Var,
 }
 
 impl From<&str> for AggregateOp {
@@ -2989,6 +3094,8 @@ impl From<&str> for AggregateOp {
             "max" => Self::Max,
             "count" | "n" => Self::Count,
             "median" => Self::Median,
+            "std" | "stdev" | "stddev" => Self::Std,
+            "var" | "variance" => Self::Var,
             _ => Self::Sum,
         }
     }
@@ -3055,6 +3162,25 @@ impl AggregateOp {
                 let vals = self.extract_and_sort(col, indices);
                 get_quantile(&vals, 0.5)
             }
+
+            AggregateOp::Std | AggregateOp::Var => {
+                let vals: Vec<f64> = indices
+                    .iter()
+                    .filter_map(|&i| col.get(i).to_f64())
+                    .filter(|v| !v.is_nan())
+                    .collect();
+                if vals.len() < 2 {
+                    return f64::NAN;
+                }
+                let mean = vals.iter().sum::<f64>() / vals.len() as f64;
+                let var =
+                    vals.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (vals.len() - 1) as f64;
+                if *self == AggregateOp::Std {
+                    var.sqrt()
+                } else {
+                    var
+                }
+            }
         }
     }
 
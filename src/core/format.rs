@@ -0,0 +1,64 @@
+use crate::encode::Channel;
+use crate::scale::inverse::TickFormatter;
+use std::collections::HashMap;
+
+/// The number formats of a chart, by channel and by data field.
+///
+/// Every component that writes values as text looks its format up here: axis
+/// ticks, the colorbar, stack labels, text marks and tooltips. A field format
+/// set once with `with_field_format` therefore reads the same everywhere; a
+/// channel format set with `with_x_tick_formatter`, `with_y_tick_formatter`
+/// or `with_color_tick_formatter` takes precedence on that channel. A
+/// tooltip's own `with_format` is the only format stored elsewhere, on its
+/// encoding.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct FormatRegistry {
+    fields: HashMap<String, TickFormatter>,
+    channels: HashMap<Channel, TickFormatter>,
+}
+
+impl FormatRegistry {
+    /// Sets the format of `field`, replacing any earlier one.
+    pub(crate) fn insert(&mut self, field: &str, formatter: TickFormatter) {
+        self.fields.insert(field.to_string(), formatter);
+    }
+
+    /// Sets the format of the ticks along `channel`, replacing any earlier one.
+    pub(crate) fn insert_channel(&mut self, channel: Channel, formatter: TickFormatter) {
+        self.channels.insert(channel, formatter);
+    }
+
+    /// The format of `field`, if one was set.
+    pub(crate) fn get(&self, field: &str) -> Option<&TickFormatter> {
+        self.fields.get(field)
+    }
+
+    /// The format of the ticks along `channel` showing `field`: the channel's
+    /// own format, else the field's.
+    pub(crate) fn resolve(&self, channel: Channel, field: &str) -> Option<&TickFormatter> {
+        self.channels.get(&channel).or_else(|| self.get(field))
+    }
+
+    /// The format set for `channel` itself, ignoring field formats.
+    pub(crate) fn channel(&self, channel: Channel) -> Option<&TickFormatter> {
+        self.channels.get(&channel)
+    }
+
+    /// Writes `value` in the format of `field`.
+    ///
+    /// Returns `None` when the field has no format, or one that depends on a
+    /// scale (the log notations), so callers keep their default text.
+    pub(crate) fn format(&self, field: &str, value: f64) -> Option<String> {
+        self.get(field)?.format_value(value)
+    }
+
+    /// Adds the formats of `other` for fields and channels that have none here.
+    pub(crate) fn merge(&mut self, other: FormatRegistry) {
+        for (field, formatter) in other.fields {
+            self.fields.entry(field).or_insert(formatter);
+        }
+        for (channel, formatter) in other.channels {
+            self.channels.entry(channel).or_insert(formatter);
+        }
+    }
+}
@@ -1,10 +1,22 @@
 use crate::core::aesthetics::{AestheticMapping, GlobalAesthetics};
+use crate::core::layer::Layer;
 use crate::core::utils::estimate_text_width;
+use crate::encode::Channel;
 use crate::scale::ScaleDomain;
 use crate::scale::Tick;
 use crate::scale::mapper::VisualMapper;
 use crate::theme::Theme;
 use std::collections::BTreeMap;
+use std::sync::Arc;
+
+/// Length of the gradient strip of a ColorBar, in pixels.
+pub(crate) const COLORBAR_LENGTH: f64 = 150.0;
+
+/// Thickness of the gradient strip of a ColorBar, in pixels.
+pub(crate) const COLORBAR_THICKNESS: f64 = 15.0;
+
+/// Height available to each legend block in a Top or Bottom legend row.
+pub(crate) const HORIZONTAL_LEGEND_MAX_HEIGHT: f64 = 150.0;
 
 /// Represents the physical rectangular area required by a Guide (Legend or ColorBar).
 /// Used by the LayoutEngine to reserve space and calculate the final Plot Panel.
@@ -24,6 +36,20 @@ pub enum GuideKind {
     ColorBar,
 }
 
+/// The key drawn in a discrete legend for one kind of mark.
+///
+/// A legend shared by several layers overlays the glyphs of all of them,
+/// e.g. a line with a marker for a line layer drawn over a point layer.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LegendGlyph {
+    /// A marker in the mapped shape and size, for point-like marks.
+    Symbol,
+    /// A short line sample with the layer's dash pattern, for line-like marks.
+    Line { dash: Vec<f64> },
+    /// A filled square, for marks that fill an area (bars, areas, rects).
+    Swatch,
+}
+
 /// Specification for a Guide (Legend or ColorBar), acting as the bridge
 /// between abstract data scales and visual rendering instructions.
 ///
@@ -40,11 +66,14 @@ pub struct GuideSpec {
     pub domain: ScaleDomain,
     /// The collection of visual mappings tied to this specific field.
     pub mappings: Vec<AestheticMapping>,
+    /// The glyphs of the layers using this field, in layer order.
+    /// Empty means a plain symbol.
+    pub glyphs: Vec<LegendGlyph>,
 }
 
 impl GuideSpec {
     /// Constructs a GuideSpec and performs **Semantic Inference**:
-    /// 1. If any mapping involves Size or Shape, it is forced to be a `Legend`.
+    /// 1. If any mapping involves Size, Shape or Opacity, it is forced to be a `Legend`.
     /// 2. Only if it is strictly a continuous Color mapping does it become a `ColorBar`.
     pub fn new(field: String, domain: ScaleDomain, mappings: Vec<AestheticMapping>) -> Self {
         let mut has_complex_geometry = false;
@@ -54,7 +83,9 @@ impl GuideSpec {
             if let Some(mapper) = m.scale_impl.mapper() {
                 match mapper {
                     // Size and Shape require discrete symbol keys
-                    VisualMapper::Size { .. } | VisualMapper::Shape { .. } => {
+                    VisualMapper::Size { .. }
+                    | VisualMapper::Shape { .. }
+                    | VisualMapper::Opacity { .. } => {
                         has_complex_geometry = true;
                     }
                     // Continuous color can potentially use a gradient bar
@@ -79,38 +110,82 @@ impl GuideSpec {
             kind,
             domain,
             mappings,
+            glyphs: Vec::new(),
         }
     }
 
     /// Entry point for the LayoutEngine to calculate required pixels.
     pub fn estimate_size(&self, theme: &Theme, max_h: f64) -> GuideSize {
         match self.kind {
-            GuideKind::ColorBar => self.estimate_colorbar_size(theme, max_h),
+            GuideKind::ColorBar => self.estimate_colorbar_size(theme),
             GuideKind::Legend => self.estimate_legend_size(theme, max_h),
         }
     }
 
     /// Estimates dimensions for a gradient ColorBar.
-    fn estimate_colorbar_size(&self, theme: &Theme, max_h: f64) -> GuideSize {
+    ///
+    /// The strip stands upright beside the plot and lies flat in a Top or
+    /// Bottom legend, with its tick labels below it.
+    fn estimate_colorbar_size(&self, theme: &Theme) -> GuideSize {
         let font_size = theme.legend_label_size;
         let title_font_size = font_size * 1.1;
 
         let title_w = estimate_text_width(&self.title, title_font_size);
-        let bar_w = 15.0; // Standard thickness of the color strip
+        let title_h = title_font_size + theme.legend_title_gap;
+
+        if matches!(
+            theme.legend_position,
+            LegendPosition::Top | LegendPosition::Bottom
+        ) {
+            let (lead, trail) = self.colorbar_label_overhang(font_size);
+            return GuideSize {
+                width: f64::max(title_w, lead + COLORBAR_LENGTH + trail),
+                height: title_h + COLORBAR_THICKNESS + theme.legend_marker_text_gap + font_size,
+            };
+        }
 
-        let labels = self.get_sampling_labels();
-        let max_lbl_w = labels
+        let max_lbl_w = self
+            .colorbar_ticks()
             .iter()
-            .map(|l| estimate_text_width(l, font_size))
+            .map(|t| estimate_text_width(&t.label, font_size))
             .fold(0.0, f64::max);
 
         GuideSize {
-            width: f64::max(title_w, bar_w + theme.legend_marker_text_gap + max_lbl_w),
-            // Height is usually 70% of plot height or capped at a reasonable max (200px)
-            height: title_font_size + theme.legend_title_gap + f64::min(200.0, max_h * 0.7),
+            width: f64::max(
+                title_w,
+                COLORBAR_THICKNESS + theme.legend_marker_text_gap + max_lbl_w,
+            ),
+            // The strip has a fixed length, whatever the space around the plot.
+            height: title_h + COLORBAR_LENGTH,
         }
     }
 
+    /// The labeled ticks along a ColorBar, from the consolidated color scale.
+    pub(crate) fn colorbar_ticks(&self) -> Vec<Tick> {
+        self.mappings
+            .first()
+            .map(|m| m.scale_impl.suggest_ticks(5))
+            .unwrap_or_default()
+    }
+
+    /// How far the tick labels of a horizontal ColorBar reach past the left
+    /// and right ends of the strip, in pixels.
+    pub(crate) fn colorbar_label_overhang(&self, font_size: f64) -> (f64, f64) {
+        let Some(mapping) = self.mappings.first() else {
+            return (0.0, 0.0);
+        };
+        self.colorbar_ticks()
+            .iter()
+            .fold((0.0, 0.0), |(lead, trail), tick| {
+                let pos = COLORBAR_LENGTH * mapping.scale_impl.normalize(tick.value);
+                let half_w = estimate_text_width(&tick.label, font_size) / 2.0;
+                (
+                    f64::max(lead, half_w - pos),
+                    f64::max(trail, pos + half_w - COLORBAR_LENGTH),
+                )
+            })
+    }
+
     /// Estimates dimensions for a discrete Legend, supporting multi-column wrapping.
     fn estimate_legend_size(&self, theme: &Theme, max_h: f64) -> GuideSize {
         let font_size = theme.legend_label_size;
@@ -119,7 +194,12 @@ impl GuideSpec {
         let title_w = estimate_text_width(&self.title, title_font_size);
         let title_h = title_font_size;
 
-        let labels = self.get_sampling_labels();
+        let mut labels = self.get_sampling_labels();
+        if self.floors_smallest_entry()
+            && let Some(first) = labels.first_mut()
+        {
+            *first = format!("≤ {}", first);
+        }
         let max_lbl_w = labels
             .iter()
             .map(|l| estimate_text_width(l, font_size))
@@ -193,7 +273,10 @@ impl GuideSpec {
             // 3. --- Uniform Precision Logic ---
 
             // Check if we are dealing with a numeric (non-categorical) scale
-            if !matches!(self.domain, ScaleDomain::Discrete(_)) {
+            // whose labels are not written by a tick formatter
+            if !matches!(self.domain, ScaleDomain::Discrete(_))
+                && !first_mapping.scale_impl.has_custom_labels()
+            {
                 // Determine the maximum precision needed across all sampled points.
                 // We look for the most specific decimal place to ensure no data is lost.
                 let mut max_precision = 0;
@@ -244,6 +327,11 @@ impl GuideSpec {
                 ticks = first_mapping.scale_impl.sample_n(count);
             }
 
+            // Labels written by a tick formatter are kept as they are
+            if first_mapping.scale_impl.has_custom_labels() {
+                return ticks;
+            }
+
             // Apply the precision alignment we discussed earlier
             let mut max_p = 0;
             let has_fractions = ticks
@@ -268,6 +356,24 @@ impl GuideSpec {
             Vec::new()
         }
     }
+
+    /// Returns true when a size floor raises the smallest legend entry, which is
+    /// then drawn at the floored size and labeled "≤ value".
+    pub(crate) fn floors_smallest_entry(&self) -> bool {
+        if matches!(self.domain, ScaleDomain::Discrete(_)) {
+            return false;
+        }
+        let Some(first) = self.get_sampling_ticks().into_iter().next() else {
+            return false;
+        };
+        self.mappings.iter().any(|m| {
+            m.scale_impl.mapper().is_some_and(|mapper| {
+                mapper.size_floor().is_some_and(|floor| {
+                    mapper.map_to_size(m.scale_impl.normalize(first.value)) <= floor
+                })
+            })
+        })
+    }
 }
 
 /// Core manager responsible for grouping aesthetics and generating GuideSpecs.
@@ -286,7 +392,12 @@ impl GuideManager {
     /// 2. Use a `BTreeMap` to ensure that guides are generated in a stable, alphabetical order.
     /// 3. Pass the consolidated mappings to `GuideSpec::new`, which infers the visual
     ///    type (Legend vs. ColorBar) based on the combined mapping properties.
-    pub fn collect_guides(aesthetics: &GlobalAesthetics) -> Vec<GuideSpec> {
+    /// 4. Record the legend glyph of every layer mapping the field, so the legend
+    ///    keys look like the marks they stand for.
+    pub fn collect_guides(
+        aesthetics: &GlobalAesthetics,
+        layers: &[Arc<dyn Layer>],
+    ) -> Vec<GuideSpec> {
         // We group mappings by field name. The tuple contains the inferred ScaleDomain
         // and the list of mappings associated with that field.
         let mut field_map: BTreeMap<String, (ScaleDomain, Vec<AestheticMapping>)> = BTreeMap::new();
@@ -309,6 +420,7 @@ impl GuideManager {
         collect(&aesthetics.color);
         collect(&aesthetics.shape);
         collect(&aesthetics.size);
+        collect(&aesthetics.opacity);
 
         // --- Phase 2: Specification ---
         // Convert each field group into a high-level GuideSpec.
@@ -319,7 +431,22 @@ impl GuideManager {
             .map(|(field, (domain, mappings))| {
                 // GuideSpec::new performs semantic inference to decide if this
                 // should be rendered as a discrete Legend or a continuous ColorBar.
-                GuideSpec::new(field, domain, mappings)
+                let mut spec = GuideSpec::new(field, domain, mappings);
+                for layer in layers {
+                    let uses_field = [
+                        Channel::Color,
+                        Channel::Shape,
+                        Channel::Size,
+                        Channel::Opacity,
+                    ]
+                    .into_iter()
+                    .any(|ch| layer.get_field(ch).as_deref() == Some(spec.field.as_str()));
+                    let glyph = layer.legend_glyph();
+                    if uses_field && !spec.glyphs.contains(&glyph) {
+                        spec.glyphs.push(glyph);
+                    }
+                }
+                spec
             })
             .collect()
     }
@@ -0,0 +1,180 @@
+use crate::coordinate::Rect;
+use crate::theme::Theme;
+use crate::visual::color::SingleColor;
+
+/// Corner of the main panel an inset is attached to.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum InsetAnchor {
+    TopLeft,
+    #[default]
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+/// Implements conversion from string slices for a more ergonomic Fluent API.
+impl From<&str> for InsetAnchor {
+    fn from(s: &str) -> Self {
+        match s.to_lowercase().replace(['-', '_', ' '], "").as_str() {
+            "topleft" => InsetAnchor::TopLeft,
+            "bottomleft" => InsetAnchor::BottomLeft,
+            "bottomright" => InsetAnchor::BottomRight,
+            _ => InsetAnchor::TopRight,
+        }
+    }
+}
+
+/// Placement and zoom settings for a chart drawn inside another chart's panel.
+///
+/// The inset is sized as a fraction of the main panel and anchored to one of its
+/// corners. Its scales are resolved independently of the main chart; setting
+/// `x_domain`/`y_domain` turns it into a zoomed view of that window, and the
+/// zoomed region can be marked on the main chart with a rectangle and connector
+/// lines leading to the inset frame.
+#[derive(Debug, Clone)]
+pub struct InsetSpec {
+    pub(crate) anchor: InsetAnchor,
+    pub(crate) width_frac: f64,
+    pub(crate) height_frac: f64,
+    pub(crate) x_domain: Option<(f64, f64)>,
+    pub(crate) y_domain: Option<(f64, f64)>,
+
+    /// Gap in pixels between the inset frame and the panel edges.
+    pub(crate) padding: f64,
+    /// Draws the zoom rectangle and connector lines on the main chart.
+    pub(crate) show_indicator: bool,
+    pub(crate) indicator_color: SingleColor,
+    /// Whether the inset draws its own axes.
+    pub(crate) show_axes: bool,
+    /// Factor applied to every font size of the inset's theme.
+    pub(crate) font_scale: f64,
+}
+
+impl InsetSpec {
+    pub fn new() -> Self {
+        Self {
+            anchor: InsetAnchor::TopRight,
+            width_frac: 0.4,
+            height_frac: 0.4,
+            x_domain: None,
+            y_domain: None,
+            padding: 10.0,
+            show_indicator: true,
+            indicator_color: SingleColor::new("#555555"),
+            show_axes: true,
+            font_scale: 0.7,
+        }
+    }
+
+    // --- Fluent Configuration Methods (Builder Pattern) ---
+
+    /// Sets the panel corner the inset is attached to.
+    ///
+    /// Accepts `InsetAnchor` variants or string literals like "bottom-left".
+    pub fn with_anchor(mut self, anchor: impl Into<InsetAnchor>) -> Self {
+        self.anchor = anchor.into();
+        self
+    }
+
+    /// Sets the inset size as fractions (0.0 to 1.0) of the main panel.
+    pub const fn with_size(mut self, width_frac: f64, height_frac: f64) -> Self {
+        self.width_frac = width_frac.clamp(0.05, 1.0);
+        self.height_frac = height_frac.clamp(0.05, 1.0);
+        self
+    }
+
+    /// Restricts the inset's X-axis to the given data window.
+    pub const fn with_x_domain(mut self, min: f64, max: f64) -> Self {
+        self.x_domain = Some((min, max));
+        self
+    }
+
+    /// Restricts the inset's Y-axis to the given data window.
+    pub const fn with_y_domain(mut self, min: f64, max: f64) -> Self {
+        self.y_domain = Some((min, max));
+        self
+    }
+
+    /// Sets the gap in pixels between the inset frame and the panel edges.
+    pub const fn with_padding(mut self, padding: f64) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Toggles the zoom rectangle and connector lines drawn on the main chart.
+    pub const fn with_indicator(mut self, show: bool) -> Self {
+        self.show_indicator = show;
+        self
+    }
+
+    /// Sets the color of the inset frame, the zoom rectangle and the connectors.
+    pub fn with_indicator_color(mut self, color: impl Into<SingleColor>) -> Self {
+        self.indicator_color = color.into();
+        self
+    }
+
+    /// Toggles the axes of the inset chart.
+    pub const fn with_axes(mut self, show: bool) -> Self {
+        self.show_axes = show;
+        self
+    }
+
+    /// Sets the factor applied to the inset's font sizes (default 0.7).
+    pub const fn with_font_scale(mut self, scale: f64) -> Self {
+        self.font_scale = scale;
+        self
+    }
+
+    // --- Layout Helpers ---
+
+    /// Resolves the inset frame inside the main panel.
+    pub(crate) fn resolve_frame(&self, panel: &Rect) -> Rect {
+        let w = panel.width * self.width_frac;
+        let h = panel.height * self.height_frac;
+
+        let left = panel.x + self.padding;
+        let right = panel.x + panel.width - self.padding - w;
+        let top = panel.y + self.padding;
+        let bottom = panel.y + panel.height - self.padding - h;
+
+        let (x, y) = match self.anchor {
+            InsetAnchor::TopLeft => (left, top),
+            InsetAnchor::TopRight => (right, top),
+            InsetAnchor::BottomLeft => (left, bottom),
+            InsetAnchor::BottomRight => (right, bottom),
+        };
+
+        Rect::new(x, y, w, h)
+    }
+
+    /// Derives the inset theme: reduced fonts, tight margins, optional axes and
+    /// no legend, since the inset shares its meaning with the main chart's legend.
+    pub(crate) fn apply_to_theme(&self, mut theme: Theme) -> Theme {
+        let k = self.font_scale;
+        theme.title_size *= k;
+        theme.label_size *= k;
+        theme.tick_label_size *= k;
+        theme.legend_title_size *= k;
+        theme.legend_label_size *= k;
+        theme.tick_length *= k;
+        theme.label_padding *= k;
+        theme.tick_label_padding *= k;
+
+        // The frame is only a fraction of the panel, so the regular margins and
+        // minimum panel size would push the inset's axes outside of it.
+        theme.top_margin = 0.06;
+        theme.right_margin = 0.04;
+        theme.bottom_margin = 0.04;
+        theme.left_margin = 0.04;
+        theme.min_panel_size = 10.0;
+        theme.show_axes = self.show_axes;
+        theme.show_legend = false;
+        theme
+    }
+}
+
+impl Default for InsetSpec {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -2,10 +2,11 @@ use super::aesthetics::GlobalAesthetics;
 use crate::Precision;
 use crate::coordinate::{CoordinateTrait, Rect};
 use crate::core::context::PanelContext;
+use crate::core::guide::LegendGlyph;
 use crate::encode::Channel;
 use crate::error::ChartonError;
 use crate::scale::{Expansion, Scale, ScaleDomain};
-use crate::visual::color::SingleColor;
+use crate::visual::color::{ColorMap, SingleColor};
 use crate::visual::shape::PointShape;
 use std::sync::Arc;
 
@@ -25,7 +26,10 @@ pub enum PathTopology {
 /// PointElementConfig is a high-level representation of a point-based mark.
 /// It acts as a bridge between the Layer logic and the specific geometric
 /// draw calls of the RenderBackend.
+///
+/// Build it with [`PointElementConfig::new`]; further fields may be added.
 #[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
 pub struct PointElementConfig {
     pub x: f64,
     pub y: f64,
@@ -34,7 +38,49 @@ pub struct PointElementConfig {
     pub fill: SingleColor,
     pub stroke: SingleColor,
     pub stroke_width: f64,
-    pub opacity: f64,
+    pub opacity: f64,        // Fill opacity
+    pub stroke_opacity: f64, // Outline opacity, usually equal to `opacity`
+    pub angle: f64,          // Rotation about the center in degrees, clockwise
+}
+
+impl PointElementConfig {
+    /// Creates an unrotated point whose outline shares the fill opacity.
+    #[allow(clippy::too_many_arguments)]
+    pub const fn new(
+        x: f64,
+        y: f64,
+        shape: PointShape,
+        size: f64,
+        fill: SingleColor,
+        stroke: SingleColor,
+        stroke_width: f64,
+        opacity: f64,
+    ) -> Self {
+        Self {
+            x,
+            y,
+            shape,
+            size,
+            fill,
+            stroke,
+            stroke_width,
+            opacity,
+            stroke_opacity: opacity,
+            angle: 0.0,
+        }
+    }
+
+    /// Sets the outline opacity separately from the fill opacity.
+    pub const fn with_stroke_opacity(mut self, stroke_opacity: f64) -> Self {
+        self.stroke_opacity = stroke_opacity;
+        self
+    }
+
+    /// Sets the clockwise rotation about the center, in degrees.
+    pub const fn with_angle(mut self, angle: f64) -> Self {
+        self.angle = angle;
+        self
+    }
 }
 
 pub struct CircleConfig {
@@ -100,8 +146,11 @@ pub struct TextConfig {
     pub text_anchor: String,       // "start", "middle", "end"
     pub dominant_baseline: String, // "alphabetic", "hanging", "central", etc.
     pub font_weight: String,       // "normal", "bold", or numeric "400"
+    pub font_style: String,        // "normal", "italic", "oblique"
     pub opacity: Precision,
-    pub angle: Precision, // Rotation angle in degrees
+    pub angle: Precision,     // Rotation angle in degrees
+    pub direction: String,    // "ltr", "rtl"
+    pub writing_mode: String, // "horizontal-tb", or "vertical-rl" with upright glyphs
 }
 
 ///
@@ -137,6 +186,15 @@ pub trait RenderBackend {
     /// Renders text with specific alignment and weight.
     fn draw_text(&mut self, config: TextConfig);
 
+    /// Renders text whose trailing Unicode superscript characters, as in the
+    /// tick label "10³", form an exponent.
+    ///
+    /// The default draws the characters as they are; markup backends may set
+    /// the exponent as raised, smaller text instead.
+    fn draw_superscript_text(&mut self, config: TextConfig) {
+        self.draw_text(config);
+    }
+
     /// Draws a simple straight line between two points.
     ///
     /// Commonly used for rendering axis ticks or custom markers within guides.
@@ -154,6 +212,16 @@ pub trait RenderBackend {
     fn end_clip_scope(&mut self) {
         // Default no-op.
     }
+
+    /// Attaches a hover tooltip to every element drawn until it is cleared with `None`.
+    fn set_tooltip(&mut self, _tooltip: Option<String>) {
+        // Default no-op for backends without interactive output.
+    }
+
+    /// Links every element drawn until it is cleared with `None` to a URL.
+    fn set_href(&mut self, _href: Option<String>) {
+        // Default no-op for backends without interactive output.
+    }
 }
 
 /// `MarkRenderer` defines the contract for drawing geometric primitives.
@@ -179,6 +247,9 @@ pub trait MarkRenderer {
     ) -> Result<(), ChartonError>;
 }
 
+/// The parts of a layer split by a facet field, keyed by the field value.
+pub type FacetPartition = Vec<(String, Arc<dyn Layer>)>;
+
 /// `Layer` is the core trait for the layered grammar of graphics in Charton.
 ///
 /// A layer represents a single component of a chart (e.g., a Scatter plot, a
@@ -190,6 +261,31 @@ pub trait MarkRenderer {
 /// 2. **Training**: The engine aggregates bounds from all layers to build global scales.
 /// 3. **Injection**: The engine calls `inject_resolved_scales` to "back-fill" the final scales into the layer.
 /// 4. **Rendering**: The engine calls `render_marks` to produce the final geometry.
+///
+/// # Custom Marks
+/// `Layer` is also the extension point for marks that Charton does not ship.
+/// Implement `MarkRenderer` and `Layer` for your own type and add it with
+/// `IntoLayered::with_layer`. On a discrete axis, `PanelContext::categories`,
+/// `band_width` and `band_edges` give the per-category geometry that built-in
+/// marks such as bars and boxplots use:
+///
+/// ```rust,ignore
+/// impl MarkRenderer for MyMark {
+///     fn render_marks(
+///         &self,
+///         backend: &mut dyn RenderBackend,
+///         context: &PanelContext,
+///     ) -> Result<(), ChartonError> {
+///         let edges = context.band_edges(Channel::X).unwrap_or_default();
+///         for (start, end) in edges {
+///             // Draw something between `context.panel.x + start` and `context.panel.x + end`.
+///         }
+///         Ok(())
+///     }
+/// }
+///
+/// let chart = LayeredChart::new().with_layer(MyMark { /* ... */ });
+/// ```
 pub trait Layer: MarkRenderer + Send + Sync {
     // --- Metadata Discovery Phase ---
 
@@ -206,6 +302,53 @@ pub trait Layer: MarkRenderer + Send + Sync {
     /// Returns the expansion rules (padding/margins) requested by this layer for a channel.
     fn get_expand(&self, channel: Channel) -> Option<Expansion>;
 
+    /// Returns the logarithm base requested by this layer for a channel using `Scale::Log`.
+    fn get_log_base(&self, _channel: Channel) -> Option<f64> {
+        None
+    }
+
+    /// Returns the color map requested by an ordinal color encoding, if any.
+    fn get_color_scheme(&self) -> Option<ColorMap> {
+        None
+    }
+
+    /// Returns the minimum mapped size (in pixels) requested by this layer's size encoding.
+    fn get_size_floor(&self) -> Option<f64> {
+        None
+    }
+
+    /// Returns the (min, max) opacities requested by this layer's opacity encoding.
+    fn get_opacity_range(&self) -> Option<(f64, f64)> {
+        None
+    }
+
+    /// Returns true if this layer's y values use the secondary (right-hand) y axis.
+    fn uses_secondary_y(&self) -> bool {
+        false
+    }
+
+    /// Returns true if this layer stacks its y values to fractions of 1.
+    fn stacks_normalized(&self) -> bool {
+        false
+    }
+
+    /// Returns the key drawn for this layer in a discrete legend.
+    ///
+    /// Defaults to a symbol in the mapped color, shape and size.
+    fn legend_glyph(&self) -> LegendGlyph {
+        LegendGlyph::Symbol
+    }
+
+    /// Translates this layer into a Vega-Lite layer (data, mark and encoding).
+    ///
+    /// Only layers that Vega-Lite export supports override this.
+    #[cfg(feature = "vega")]
+    fn vega_layer(&self) -> Result<serde_json::Value, ChartonError> {
+        Err(ChartonError::Unimplemented(
+            "This layer cannot be exported to Vega-Lite".into(),
+        ))
+    }
+
     /// Calculates the raw data boundaries (Min/Max for continuous, unique labels for discrete)
     /// contained within this specific layer's dataset.
     ///
@@ -228,4 +371,22 @@ pub trait Layer: MarkRenderer + Send + Sync {
         coord: Arc<dyn CoordinateTrait>,
         aesthetics: &GlobalAesthetics,
     );
+
+    // --- Faceting ---
+
+    /// Returns the distinct values of `field` in this layer's data, in panel order.
+    ///
+    /// Returns `None` if the layer has no such column; the default implementation
+    /// always does, so custom layers are repeated in every facet panel.
+    fn facet_values(&self, _field: &str) -> Option<Vec<String>> {
+        None
+    }
+
+    /// Splits the layer into one copy per value of `field`, each holding only the
+    /// rows with that value.
+    ///
+    /// Layers that return `None` are drawn unchanged in every facet panel.
+    fn facet_partition(&self, _field: &str) -> Option<FacetPartition> {
+        None
+    }
 }
@@ -1,13 +1,16 @@
 use super::context::PanelContext;
-use super::guide::{GuideSpec, LegendPosition};
+use super::guide::{GuideSpec, HORIZONTAL_LEGEND_MAX_HEIGHT, LegendPosition};
 use super::utils::estimate_text_width;
+use crate::coordinate::Rect;
 use crate::theme::Theme;
 
 /// Physical constraints calculated for axis areas.
-#[derive(Default, Debug, Clone, Copy)]
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
 pub struct AxisLayoutConstraints {
     pub bottom: f64,
     pub left: f64,
+    /// Width of a secondary Y-axis on the right; 0 without one.
+    pub right: f64,
 }
 
 /// Margin reserved on each side of the plot for legend placement.
@@ -29,6 +32,9 @@ impl LayoutEngine {
     ///    If a legend exceeds `initial_plot_h`, a new column is started to the side.
     /// 2. **Horizontal Stacking (Top/Bottom)**: Legends are laid out in a row.
     ///    If a legend exceeds `initial_plot_w`, a new row is started below/above.
+    ///
+    /// `axes` holds the measured axis depths; the legend is capped so that the
+    /// panel keeps its minimum size next to them.
     #[allow(clippy::too_many_arguments)]
     pub fn calculate_legend_constraints(
         specs: &[GuideSpec],
@@ -38,6 +44,7 @@ impl LayoutEngine {
         initial_plot_w: f64,
         initial_plot_h: f64,
         margin_gap: f64, // Space between plot panel and the whole legend block
+        axes: &AxisLayoutConstraints,
         theme: &Theme,
     ) -> LegendLayoutConstraints {
         let mut constraints = LegendLayoutConstraints::default();
@@ -82,8 +89,8 @@ impl LayoutEngine {
                 // We ensure the plot panel has a "Defense Floor".
                 let min_panel_w =
                     f64::max(theme.min_panel_size, canvas_w * theme.panel_defense_ratio);
-                let max_allowed_legend_w =
-                    (canvas_w - min_panel_w - theme.axis_reserve_buffer).max(0.0);
+                let axis_reserve = f64::max(theme.axis_reserve_buffer, axes.left + axes.right);
+                let max_allowed_legend_w = (canvas_w - min_panel_w - axis_reserve).max(0.0);
 
                 let final_w = f64::min(total_width, max_allowed_legend_w);
                 let reserve = if final_w > 0.0 {
@@ -108,8 +115,9 @@ impl LayoutEngine {
 
                 for (i, spec) in specs.iter().enumerate() {
                     // Capping the height of individual legend items for horizontal layout
-                    // to prevent "squashing" the plot panel vertically.
-                    let size = spec.estimate_size(theme, canvas_h * 0.25);
+                    // to prevent "squashing" the plot panel vertically. The renderer
+                    // wraps the blocks with the same cap.
+                    let size = spec.estimate_size(theme, HORIZONTAL_LEGEND_MAX_HEIGHT);
 
                     if current_row_w + size.width > max_w && current_row_w > 0.0 {
                         total_height += current_row_h + block_gap;
@@ -128,8 +136,8 @@ impl LayoutEngine {
 
                 let min_panel_h =
                     f64::max(theme.min_panel_size, canvas_h * theme.panel_defense_ratio);
-                let max_allowed_legend_h =
-                    (canvas_h - min_panel_h - theme.axis_reserve_buffer).max(0.0);
+                let axis_reserve = f64::max(theme.axis_reserve_buffer, axes.bottom);
+                let max_allowed_legend_h = (canvas_h - min_panel_h - axis_reserve).max(0.0);
 
                 let final_h = f64::min(total_height, max_allowed_legend_h);
                 let reserve = if final_h > 0.0 {
@@ -151,6 +159,28 @@ impl LayoutEngine {
         constraints
     }
 
+    /// Returns the top-left corner of the legend block.
+    ///
+    /// The legend sits outside the axes: below the bottom axis title, left of
+    /// the left axis title, right of a secondary axis, or above the panel in
+    /// the room reserved by `legend`.
+    pub fn legend_origin(
+        position: LegendPosition,
+        panel: &Rect,
+        axes: &AxisLayoutConstraints,
+        legend: &LegendLayoutConstraints,
+        theme: &Theme,
+    ) -> (f64, f64) {
+        let margin = theme.legend_margin;
+        match position {
+            LegendPosition::Right => (panel.x + panel.width + axes.right + margin, panel.y),
+            LegendPosition::Left => ((panel.x - axes.left - legend.left).max(0.0), panel.y),
+            LegendPosition::Top => (panel.x, (panel.y - legend.top).max(0.0)),
+            LegendPosition::Bottom => (panel.x, panel.y + panel.height + axes.bottom + margin),
+            LegendPosition::None => (panel.x, panel.y),
+        }
+    }
+
     /// Calculates layout constraints based on predicted axis dimensions.
     ///
     /// This method uses the chart's reference dimensions to estimate the "worst-case"
@@ -160,6 +190,48 @@ impl LayoutEngine {
         theme: &Theme,
         reference_width: f64,
         reference_height: f64,
+    ) -> AxisLayoutConstraints {
+        Self::measure_axes(ctx, theme, reference_width, reference_height, true)
+    }
+
+    /// Like [`LayoutEngine::calculate_axis_constraints`], but leaves out the axis titles.
+    ///
+    /// Faceted charts use this to size the gutters between panels, where only
+    /// ticks and tick labels are drawn.
+    pub fn calculate_tick_constraints(
+        ctx: &PanelContext,
+        theme: &Theme,
+        reference_width: f64,
+        reference_height: f64,
+    ) -> AxisLayoutConstraints {
+        Self::measure_axes(ctx, theme, reference_width, reference_height, false)
+    }
+
+    /// Estimates the width taken by a secondary Y-axis on the right of the panel,
+    /// from the axis line to the outer edge of its title.
+    pub fn calculate_secondary_axis_width(
+        scale: &dyn crate::scale::ScaleTrait,
+        title: &str,
+        theme: &Theme,
+        reference_height: f64,
+    ) -> f64 {
+        Self::estimate_axis_dimension(
+            scale,
+            false,
+            title,
+            theme.label_padding,
+            theme,
+            false,
+            reference_height,
+        )
+    }
+
+    fn measure_axes(
+        ctx: &PanelContext,
+        theme: &Theme,
+        reference_width: f64,
+        reference_height: f64,
+        with_titles: bool,
     ) -> AxisLayoutConstraints {
         let mut constraints = AxisLayoutConstraints::default();
         let coord = ctx.coord.clone();
@@ -167,25 +239,23 @@ impl LayoutEngine {
 
         // 1. Resolve Bottom Axis:
         // Uses X-scale by default; uses Y-scale if the coordinate system is flipped.
-        let (b_scale, b_angle, b_title, b_pad) = if is_flipped {
+        let (b_scale, b_title, b_pad) = if is_flipped {
             (
                 coord.get_y_scale(),
-                theme.y_tick_label_angle,
                 coord.get_y_label(),
                 theme.label_padding,
             )
         } else {
             (
                 coord.get_x_scale(),
-                theme.x_tick_label_angle,
                 coord.get_x_label(),
                 theme.label_padding,
             )
         };
         constraints.bottom = Self::estimate_axis_dimension(
             b_scale,
-            b_angle,
-            b_title,
+            !is_flipped,
+            if with_titles { b_title } else { "" },
             b_pad,
             theme,
             true,
@@ -194,25 +264,23 @@ impl LayoutEngine {
 
         // 2. Resolve Left Axis:
         // Uses Y-axis by default, or X-axis if flipped.
-        let (l_scale, l_angle, l_title, l_pad) = if is_flipped {
+        let (l_scale, l_title, l_pad) = if is_flipped {
             (
                 coord.get_x_scale(),
-                theme.x_tick_label_angle,
                 coord.get_x_label(),
                 theme.label_padding,
             )
         } else {
             (
                 coord.get_y_scale(),
-                theme.y_tick_label_angle,
                 coord.get_y_label(),
                 theme.label_padding,
             )
         };
         constraints.left = Self::estimate_axis_dimension(
             l_scale,
-            l_angle,
-            l_title,
+            is_flipped,
+            if with_titles { l_title } else { "" },
             l_pad,
             theme,
             false,
@@ -234,9 +302,11 @@ impl LayoutEngine {
     /// 4. Padding between labels and the axis title.
     /// 5. The height of the title text itself.
     /// 6. A final safety buffer for the SVG edge.
+    ///
+    /// `x_axis` names the logical axis whose tick label angle and format apply.
     fn estimate_axis_dimension(
         scale: &dyn crate::scale::ScaleTrait,
-        angle_deg: f64,
+        x_axis: bool,
         title: &str,
         label_padding: f64, // The padding between labels and title (theme.label_padding)
         theme: &Theme,
@@ -247,13 +317,18 @@ impl LayoutEngine {
         let tick_line_len = 6.0;
         let title_gap = 5.0; // Distance between labels and the title text
         let edge_buffer = 10.0; // Prevents the title from touching the very edge of the SVG
+        let (angle_deg, tick_count) = if x_axis {
+            (theme.x_tick_label_angle, theme.x_tick_count)
+        } else {
+            (theme.y_tick_label_angle, theme.y_tick_count)
+        };
         let angle_rad = angle_deg.to_radians();
 
         // 1. Predictive Tick Generation
         // We must generate the same number of ticks as the renderer to ensure we
         // measure the actual strings (like "1.0000E7") that will be displayed.
         let final_count = theme.suggest_tick_count(available_space);
-        let ticks = scale.suggest_ticks(final_count);
+        let ticks = crate::scale::nice_ticks::axis_ticks(scale, tick_count, final_count);
 
         // 2. Compute the physical footprint of the labels
         // Rotated text creates a bounding box. We need the projection of this box
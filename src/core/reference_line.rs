@@ -0,0 +1,71 @@
+use crate::visual::color::SingleColor;
+
+/// The data axis a reference line marks a value on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ReferenceAxis {
+    X,
+    Y,
+}
+
+/// A straight line across the panel at a fixed data value, such as a threshold.
+///
+/// Unlike `mark_rule`, the position is a constant rather than a data column.
+/// It goes through the same scales and coordinate system as the marks: a
+/// horizontal line marks a value of the y scale, so it turns vertical when the
+/// coordinates are flipped, and becomes a circle in polar coordinates.
+#[derive(Debug, Clone)]
+pub struct ReferenceLine {
+    pub(crate) axis: ReferenceAxis,
+    pub(crate) value: f64,
+
+    /// Line color. `None` uses the theme's axis color.
+    pub(crate) color: Option<SingleColor>,
+    /// Line width in pixels.
+    pub(crate) width: f64,
+    /// Dash pattern in the SVG `stroke-dasharray` format; empty is solid.
+    pub(crate) dash: Vec<f64>,
+}
+
+impl ReferenceLine {
+    const fn new(axis: ReferenceAxis, value: f64) -> Self {
+        Self {
+            axis,
+            value,
+            color: None,
+            width: 1.0,
+            dash: Vec::new(),
+        }
+    }
+
+    /// Creates a line at the value `y` of the y scale.
+    pub const fn horizontal(y: f64) -> Self {
+        Self::new(ReferenceAxis::Y, y)
+    }
+
+    /// Creates a line at the value `x` of the x scale.
+    ///
+    /// For a discrete axis, the value is the index of the category.
+    pub const fn vertical(x: f64) -> Self {
+        Self::new(ReferenceAxis::X, x)
+    }
+
+    // --- Fluent Configuration Methods (Builder Pattern) ---
+
+    /// Sets the color of the line.
+    pub fn with_color(mut self, color: impl Into<SingleColor>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the width of the line in pixels.
+    pub const fn with_width(mut self, width: f64) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the dash pattern, e.g. `vec![4.0, 4.0]`; `vec![]` draws a solid line.
+    pub fn with_dash(mut self, dash: impl Into<Vec<f64>>) -> Self {
+        self.dash = dash.into();
+        self
+    }
+}
@@ -0,0 +1,125 @@
+#![deny(clippy::unwrap_used, clippy::expect_used)]
+
+use crate::core::composite::{LayeredChart, ResolvedSpec};
+use crate::encode::Channel;
+use crate::error::ChartonError;
+use crate::scale::{Expansion, Scale, ScaleDomain, mapper::VisualMapper};
+
+/// One resolved scale captured from a chart: everything needed to rebuild it elsewhere.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SharedScale {
+    /// The field the scale was resolved for; used as the axis title when the
+    /// receiving chart has no field of its own for the channel.
+    pub field: String,
+    pub scale_type: Scale,
+    pub domain: ScaleDomain,
+    pub expand: Expansion,
+    /// Logarithm base, only meaningful for `Scale::Log`.
+    pub log_base: f64,
+}
+
+impl From<ResolvedSpec> for SharedScale {
+    fn from(spec: ResolvedSpec) -> Self {
+        Self {
+            field: spec.field,
+            scale_type: spec.scale_type,
+            domain: spec.domain,
+            expand: spec.expand,
+            log_base: spec.log_base,
+        }
+    }
+}
+
+/// The x, y and color scales of one chart, reusable by other charts.
+///
+/// Independent figures that must be read side by side (e.g. two panels of a
+/// report) can share the exact same axes and colors: capture the scales from
+/// one chart with [`SharedScales::from_chart`] and hand them to the others with
+/// `with_shared_scales`. The receiving charts skip scale consolidation for the
+/// covered channels, so their own data no longer widens the domains; marks
+/// outside a shared domain are clipped and a diagnostic is printed.
+///
+/// With the `serde` feature the scales can be serialized, so a pipeline can
+/// persist them and reuse them on a later run.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let scales = SharedScales::from_chart(&first)?;
+/// second.with_shared_scales(&scales).save("second.svg")?;
+/// ```
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SharedScales {
+    pub x: Option<SharedScale>,
+    pub y: Option<SharedScale>,
+    pub color: Option<SharedScale>,
+    /// The mapper turning normalized color values into colors, so that the
+    /// same category or value gets the same color in every chart.
+    pub color_mapper: Option<VisualMapper>,
+}
+
+impl SharedScales {
+    /// Resolves the x, y and color scales of `chart` exactly as rendering would.
+    ///
+    /// Channels the chart does not use are left as `None` and stay automatic in
+    /// the charts the scales are applied to.
+    pub fn from_chart(chart: &LayeredChart) -> Result<Self, ChartonError> {
+        let color = chart.resolve_scale_spec(Channel::Color)?;
+        let color_mapper = match &color {
+            Some(spec) => Some(chart.resolve_color_mapper(spec)?),
+            None => None,
+        };
+
+        Ok(Self {
+            x: chart.resolve_scale_spec(Channel::X)?.map(Into::into),
+            y: chart.resolve_scale_spec(Channel::Y)?.map(Into::into),
+            color: color.map(Into::into),
+            color_mapper,
+        })
+    }
+
+    /// Returns the shared scale of a channel, if any.
+    pub const fn get(&self, channel: Channel) -> Option<&SharedScale> {
+        match channel {
+            Channel::X => self.x.as_ref(),
+            Channel::Y => self.y.as_ref(),
+            Channel::Color => self.color.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+/// Prints a diagnostic if `bounds` reaches outside the shared `domain`.
+pub(crate) fn report_overflow(
+    channel: Channel,
+    layer: usize,
+    bounds: &ScaleDomain,
+    domain: &ScaleDomain,
+) {
+    let outside = match (bounds, domain) {
+        (ScaleDomain::Continuous(min, max), ScaleDomain::Continuous(lo, hi)) => {
+            (min < lo || max > hi).then(|| format!("[{}, {}]", min, max))
+        }
+        (ScaleDomain::Temporal(min, max), ScaleDomain::Temporal(lo, hi)) => {
+            (min < lo || max > hi).then(|| format!("[{}, {}] ns", min, max))
+        }
+        (ScaleDomain::Discrete(labels), ScaleDomain::Discrete(shared)) => {
+            let missing: Vec<&str> = labels
+                .iter()
+                .filter(|l| !shared.contains(l))
+                .map(String::as_str)
+                .collect();
+            (!missing.is_empty()).then(|| format!("categories {}", missing.join(", ")))
+        }
+        _ => None,
+    };
+
+    if let Some(range) = outside {
+        eprintln!(
+            "SharedScales: {:?} data of layer {} ({}) exceeds the shared domain {:?}; marks outside it are clipped.",
+            channel, layer, range, domain
+        );
+    }
+}
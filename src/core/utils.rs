@@ -24,7 +24,7 @@ pub(crate) fn estimate_text_width(text: &str, font_size: f64) -> f64 {
 }
 
 // =============================== Font Database Utilities =====================================
-#[cfg(any(feature = "png", feature = "pdf"))]
+#[cfg(any(feature = "raster", feature = "pdf"))]
 use std::sync::OnceLock;
 
 #[cfg(feature = "pdf")]
@@ -57,35 +57,89 @@ pub(crate) fn get_font_db() -> Arc<svg2pdf::usvg::fontdb::Database> {
             let default_font_data = include_bytes!("../../assets/fonts/Inter-Regular.ttf");
             fontdb.load_font_data(default_font_data.to_vec());
 
-            // 3. Define the default fallback family for 'sans-serif' requests.
-            // When an SVG specifies "sans-serif" but no system mapping exists,
-            // the renderer will use the "Inter" font we just loaded.
+            // 3. Define the default fallback family for generic requests.
+            // When an SVG specifies "sans-serif", "serif" or "monospace", the
+            // renderer uses the "Inter" font we just loaded, so text never
+            // depends on how the system maps those families.
             fontdb.set_sans_serif_family("Inter");
+            fontdb.set_serif_family("Inter");
+            fontdb.set_monospace_family("Inter");
 
             Arc::new(fontdb)
         })
         .clone()
 }
 
+/// Converts a rendered SVG document into a single-page PDF.
+///
+/// Text is laid out with the shared font database and embedded in the PDF.
+/// At the default 72 DPI, one SVG pixel becomes one point on the page.
+#[cfg(feature = "pdf")]
+pub(crate) fn svg_to_pdf(svg: &str) -> Result<Vec<u8>, crate::error::ChartonError> {
+    use crate::error::ChartonError;
+
+    let opts = svg2pdf::usvg::Options {
+        fontdb: get_font_db(),
+        ..Default::default()
+    };
+
+    // Parse the raw SVG string into a usvg render tree
+    let tree = svg2pdf::usvg::Tree::from_str(svg, &opts)
+        .map_err(|e| ChartonError::Render(format!("SVG parsing error: {:?}", e)))?;
+
+    svg2pdf::to_pdf(
+        &tree,
+        svg2pdf::ConversionOptions::default(),
+        svg2pdf::PageOptions::default(),
+    )
+    .map_err(|e| ChartonError::Render(format!("PDF generation error: {:?}", e)))
+}
+
 // =============================== Raster Font Utilities (PNG) =====================================
-#[cfg(feature = "png")]
+#[cfg(feature = "raster")]
 use ab_glyph::FontArc;
-#[cfg(feature = "png")]
+#[cfg(feature = "raster")]
 use std::sync::RwLock;
 
+/// A raster font together with the styling its outlines already carry.
+///
+/// The raster backend synthesizes whatever is requested but missing here,
+/// e.g. a bold title drawn with the embedded regular Inter face.
+#[cfg(feature = "raster")]
+#[derive(Clone)]
+pub(crate) struct RasterFace {
+    pub(crate) font: FontArc,
+    /// The face is a genuine bold (weight 600 or above).
+    pub(crate) bold: bool,
+    /// The face is a genuine italic or oblique.
+    pub(crate) italic: bool,
+}
+
+#[cfg(feature = "raster")]
+impl RasterFace {
+    const fn regular(font: FontArc) -> Self {
+        Self {
+            font,
+            bold: false,
+            italic: false,
+        }
+    }
+}
+
 /// Global cache for raster fonts.
-/// Maps lowercase font family names to FontArc instances.
-#[cfg(feature = "png")]
-static RASTER_FONT_REGISTRY: OnceLock<RwLock<AHashMap<String, FontArc>>> = OnceLock::new();
+/// Maps lowercase font family names (plus a style suffix for bold/italic
+/// variants) to loaded faces.
+#[cfg(feature = "raster")]
+static RASTER_FONT_REGISTRY: OnceLock<RwLock<AHashMap<String, RasterFace>>> = OnceLock::new();
 
 /// Global cache for the system font database.
 /// This allows us to search for system fonts by name without rescanning the OS directories every time.
-#[cfg(feature = "png")]
+#[cfg(feature = "raster")]
 static SYSTEM_FONT_DB: OnceLock<fontdb::Database> = OnceLock::new();
 
 /// Retrieves or initializes the global system font database.
 /// This performs an expensive I/O operation (scanning OS font dirs) only once.
-#[cfg(feature = "png")]
+#[cfg(feature = "raster")]
 fn get_system_font_db() -> &'static fontdb::Database {
     SYSTEM_FONT_DB.get_or_init(|| {
         let mut db = fontdb::Database::new();
@@ -96,8 +150,11 @@ fn get_system_font_db() -> &'static fontdb::Database {
 }
 
 /// Initializes the font registry with the default embedded font (Inter).
-#[cfg(feature = "png")]
-fn get_raster_registry() -> &'static RwLock<AHashMap<String, FontArc>> {
+///
+/// Inter also stands in for the generic "sans-serif", "serif" and
+/// "monospace" families, matching the vector output.
+#[cfg(feature = "raster")]
+fn get_raster_registry() -> &'static RwLock<AHashMap<String, RasterFace>> {
     RASTER_FONT_REGISTRY.get_or_init(|| {
         let mut map = AHashMap::new();
 
@@ -105,9 +162,11 @@ fn get_raster_registry() -> &'static RwLock<AHashMap<String, FontArc>> {
         let default_font_data = include_bytes!("../../assets/fonts/Inter-Regular.ttf");
         if let Ok(font) = FontArc::try_from_slice(default_font_data) {
             // Register as "inter" for explicit requests
-            map.insert("inter".to_string(), font.clone());
-            // Register as "sans-serif" for generic fallbacks
-            map.insert("sans-serif".to_string(), font);
+            map.insert("inter".to_string(), RasterFace::regular(font.clone()));
+            // Register the generic families as fallbacks
+            for generic in ["sans-serif", "serif", "monospace"] {
+                map.insert(generic.to_string(), RasterFace::regular(font.clone()));
+            }
         } else {
             eprintln!("Warning: Failed to load default Inter font for raster rendering.");
         }
@@ -116,25 +175,36 @@ fn get_raster_registry() -> &'static RwLock<AHashMap<String, FontArc>> {
     })
 }
 
-/// Retrieves a raster font by family name.
+/// Retrieves a raster font by family name, weight and slant.
 ///
 /// Logic:
-/// 1. Check if the font is already loaded in our local cache.
-/// 2. If not, search the System Font Database for the family name.
+/// 1. Check if the face is already loaded in our local cache.
+/// 2. If not, search the System Font Database for the family in the requested style.
 /// 3. If found in system, load it into memory, cache it, and return it.
-/// 4. If not found in system, fallback to "sans-serif" (Inter).
+/// 4. A bold or italic request that finds nothing falls back to the regular face
+///    of the same family; the caller synthesizes the missing styling.
+/// 5. If not found in system, fallback to "sans-serif" (Inter).
 ///
 /// # Arguments
 /// * `family` - The font family name (e.g., "Arial", "Times New Roman", "Inter"). Case-insensitive.
-#[cfg(feature = "png")]
-pub(crate) fn get_raster_font(family: &str) -> FontArc {
+/// * `bold` - Whether a bold face is wanted.
+/// * `italic` - Whether an italic (or oblique) face is wanted.
+#[cfg(feature = "raster")]
+pub(crate) fn get_raster_font(family: &str, bold: bool, italic: bool) -> RasterFace {
     let registry = get_raster_registry();
+    let family_key = family.to_lowercase();
+    let key = match (bold, italic) {
+        (false, false) => family_key.clone(),
+        (true, false) => format!("{}:bold", family_key),
+        (false, true) => format!("{}:italic", family_key),
+        (true, true) => format!("{}:bold-italic", family_key),
+    };
 
     // 1. Fast path: Check if already loaded in our local cache
     {
         let map = registry.read().expect("Failed to read font registry");
-        if let Some(font) = map.get(&family.to_lowercase()) {
-            return font.clone();
+        if let Some(face) = map.get(&key) {
+            return face.clone();
         }
     }
 
@@ -143,8 +213,8 @@ pub(crate) fn get_raster_font(family: &str) -> FontArc {
     let mut map = registry.write().expect("Failed to write to font registry");
 
     // Double-check after acquiring write lock (another thread might have loaded it)
-    if let Some(font) = map.get(&family.to_lowercase()) {
-        return font.clone();
+    if let Some(face) = map.get(&key) {
+        return face.clone();
     }
 
     // Search in the global system font database
@@ -152,26 +222,46 @@ pub(crate) fn get_raster_font(family: &str) -> FontArc {
     let families = [fontdb::Family::Name(family)];
     let query = fontdb::Query {
         families: &families,
-        weight: fontdb::Weight::NORMAL,
+        weight: if bold {
+            fontdb::Weight::BOLD
+        } else {
+            fontdb::Weight::NORMAL
+        },
         stretch: fontdb::Stretch::Normal,
-        style: fontdb::Style::Normal,
+        style: if italic {
+            fontdb::Style::Italic
+        } else {
+            fontdb::Style::Normal
+        },
     };
 
-    // Try to find the font in the system database and load it
+    // Try to find the font in the system database and load it.
+    // The closest match may still be a regular face, so its real styling is recorded.
     if let Some(id) = sys_db.query(&query)
         && let Some(face_info) = sys_db.face(id)
         && let fontdb::Source::File(ref path) = face_info.source
         && let Ok(font_data) = std::fs::read(path)
         && let Ok(font) = FontArc::try_from_vec(font_data)
     {
+        let face = RasterFace {
+            font,
+            bold: face_info.weight.0 >= 600,
+            italic: face_info.style != fontdb::Style::Normal,
+        };
         // Cache it for future requests
-        map.insert(family.to_lowercase(), font.clone());
-        return font;
+        map.insert(key, face.clone());
+        return face;
+    }
+
+    // Styled request without a matching face: reuse the regular face of the family.
+    if bold || italic {
+        drop(map);
+        return get_raster_font(family, false, false);
     }
 
     // Fallback: If system font not found or failed to load, use sans-serif (Inter)
-    if let Some(font) = map.get("sans-serif") {
-        return font.clone();
+    if let Some(face) = map.get("sans-serif") {
+        return face.clone();
     }
 
     // Ultimate fallback: Panic if no fonts are available (should not happen if Inter loads correctly)
@@ -196,14 +286,14 @@ pub(crate) fn get_raster_font(family: &str) -> FontArc {
 /// let font_data = std::fs::read("path/to/font.ttf")?;
 /// register_raster_font("MyFont", font_data)?;
 /// ```
-#[cfg(feature = "png")]
+#[cfg(feature = "raster")]
 pub fn register_raster_font(name: &str, data: Vec<u8>) -> Result<(), Box<dyn std::error::Error>> {
     // try_from_vec takes ownership of the Vec, ensuring the data lives as long as the FontArc
     let font = FontArc::try_from_vec(data)?;
 
     let registry = get_raster_registry();
     let mut map = registry.write().expect("Failed to write to font registry");
-    map.insert(name.to_lowercase(), font);
+    map.insert(name.to_lowercase(), RasterFace::regular(font));
     Ok(())
 }
 
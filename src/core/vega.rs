@@ -0,0 +1,440 @@
+//! Vega-Lite export: translates charts into Vega-Lite specifications in pure Rust.
+//!
+//! Only the common cases are covered: point, line and bar marks with x, y and
+//! color encodings. The exported data is the chart's data after its mark
+//! transforms (e.g. aggregated bars), so Vega-Lite draws it as is.
+
+use crate::TEMP_SUFFIX;
+use crate::chart::Chart;
+use crate::core::composite::LayeredChart;
+use crate::core::data::{AnyValue, Dataset};
+use crate::encode::Channel;
+use crate::encode::y::StackMode;
+use crate::error::ChartonError;
+use crate::mark::Mark;
+use crate::mark::bar::MarkBar;
+use crate::mark::line::MarkLine;
+use crate::mark::point::MarkPoint;
+use crate::scale::mapper::VisualMapper;
+use crate::scale::{Scale, ScaleDomain};
+use crate::visual::color::{ColorGradient, ColorMap, ColorPalette, SingleColor};
+use serde_json::{Map, Value, json};
+use std::any::Any;
+
+const SCHEMA: &str = "https://vega.github.io/schema/vega-lite/v5.json";
+
+/// Nanoseconds per millisecond, the time unit of Vega-Lite.
+const NS_PER_MS: i64 = 1_000_000;
+/// Milliseconds in one day, for `Date` columns stored as days.
+const MS_PER_DAY: i64 = 86_400_000;
+
+/// Builds the Vega-Lite layer of a single chart: its data, mark and encoding.
+pub(crate) fn chart_layer<T: Mark>(chart: &Chart<T>) -> Result<Value, ChartonError> {
+    let mark = chart
+        .mark
+        .as_ref()
+        .ok_or_else(|| ChartonError::Mark("A mark is required for Vega-Lite export".into()))?;
+    let any = mark as &dyn Any;
+
+    let mark_spec = if let Some(m) = any.downcast_ref::<MarkPoint>() {
+        // Charton sizes points by radius, Vega-Lite by area.
+        json!({
+            "type": "circle",
+            "color": vega_color(&m.color),
+            "opacity": m.opacity,
+            "size": std::f64::consts::PI * m.size * m.size,
+        })
+    } else if let Some(m) = any.downcast_ref::<MarkLine>() {
+        let mut spec = json!({
+            "type": "line",
+            "color": vega_color(&m.color),
+            "opacity": m.opacity,
+            "strokeWidth": m.stroke_width,
+        });
+        if !m.dash.is_empty() {
+            spec["strokeDash"] = json!(m.dash);
+        }
+        spec
+    } else if let Some(m) = any.downcast_ref::<MarkBar>() {
+        json!({
+            "type": "bar",
+            "color": vega_color(&m.color),
+            "opacity": m.opacity,
+        })
+    } else {
+        return Err(ChartonError::Unimplemented(format!(
+            "Vega-Lite export supports point, line and bar marks, not '{}'",
+            mark.mark_type()
+        )));
+    };
+
+    let mut encoding = Map::new();
+    for (channel, name) in [
+        (Channel::X, "x"),
+        (Channel::Y, "y"),
+        (Channel::Color, "color"),
+    ] {
+        let (Some(field), Some(scale)) = (
+            chart.encoding.get_field_by_channel(channel),
+            chart.encoding.get_scale_by_channel(channel),
+        ) else {
+            continue;
+        };
+        if field.is_empty() {
+            return Err(ChartonError::Unimplemented(format!(
+                "Vega-Lite export needs a field on the {} channel (pie charts are not supported)",
+                name
+            )));
+        }
+        let mut def = json!({ "field": escape_field(field), "type": vega_type(scale) });
+        if scale == Scale::Log {
+            let base = chart.encoding.get_log_base_by_channel(channel);
+            def["scale"] = json!({ "type": "log", "base": base.unwrap_or(10.0) });
+        }
+        encoding.insert(name.to_string(), def);
+    }
+
+    if let Some(detail) = &chart.encoding.detail {
+        encoding.insert(
+            "detail".to_string(),
+            json!({ "field": escape_field(&detail.field), "type": "nominal" }),
+        );
+    }
+
+    // Colored bars are stacked or set side by side, like in Charton.
+    if let (true, Some(y), Some(color)) = (
+        any.is::<MarkBar>(),
+        chart.encoding.y.as_ref(),
+        encoding.get("color").cloned(),
+    ) {
+        let stack = match y.stack {
+            StackMode::None => Value::Null,
+            StackMode::Stacked => json!("zero"),
+            StackMode::Normalize => json!("normalize"),
+            StackMode::Center => json!("center"),
+        };
+        if stack.is_null() {
+            encoding.insert(
+                "xOffset".to_string(),
+                json!({ "field": color["field"], "type": "nominal" }),
+            );
+        }
+        if let Some(y_def) = encoding.get_mut("y") {
+            y_def["stack"] = stack;
+        }
+    }
+
+    Ok(json!({
+        "data": { "values": data_values(&chart.data) },
+        "mark": mark_spec,
+        "encoding": encoding,
+    }))
+}
+
+impl LayeredChart {
+    /// Translates the chart into a Vega-Lite specification, without Python.
+    ///
+    /// Covers point, line and bar layers with x, y and color encodings. Scales
+    /// map to Vega-Lite field types (log scales keep their base), discrete axes
+    /// keep Charton's category order, and the color scale reproduces the
+    /// palette or color map of the theme, as a named Vega scheme where one
+    /// exists. The width and height are those of the whole chart. Other marks
+    /// return [`ChartonError::Unimplemented`].
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let spec = chart.to_vega_json()?;
+    /// std::fs::write("chart.vl.json", spec)?;
+    /// ```
+    pub fn to_vega_json(&self) -> Result<String, ChartonError> {
+        let mut layers = self
+            .layers
+            .iter()
+            .map(|l| l.vega_layer())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let x_order = self.category_order(Channel::X)?;
+        let y_order = self.category_order(Channel::Y)?;
+        let color_scale = self.vega_color_scale()?;
+
+        for layer in &mut layers {
+            let Some(encoding) = layer.get_mut("encoding").and_then(Value::as_object_mut) else {
+                continue;
+            };
+            let axes = [
+                (
+                    "x",
+                    &self.x_label,
+                    &self.x_domain,
+                    &x_order,
+                    self.x_reversed,
+                ),
+                (
+                    "y",
+                    &self.y_label,
+                    &self.y_domain,
+                    &y_order,
+                    self.y_reversed,
+                ),
+            ];
+            for (name, label, domain, order, reversed) in axes {
+                let Some(def) = encoding.get_mut(name) else {
+                    continue;
+                };
+                if let Some(label) = label {
+                    def["title"] = json!(label);
+                }
+                if let Some(order) = order {
+                    def["sort"] = json!(order);
+                }
+                if let Some(ScaleDomain::Continuous(min, max)) = domain {
+                    scale_entry(def)["domain"] = json!([min, max]);
+                }
+                if reversed {
+                    scale_entry(def)["reverse"] = json!(true);
+                }
+            }
+            if let Some(def) = encoding.get_mut("color") {
+                if let Some(label) = &self.color_label {
+                    def["title"] = json!(label);
+                }
+                if let Some(scale) = &color_scale {
+                    def["scale"] = scale.clone();
+                }
+            }
+            if self.flipped {
+                let x = encoding.remove("x");
+                let y = encoding.remove("y");
+                if let Some(y) = y {
+                    encoding.insert("x".to_string(), y);
+                }
+                if let Some(x) = x {
+                    encoding.insert("y".to_string(), x);
+                }
+                if let Some(offset) = encoding.remove("xOffset") {
+                    encoding.insert("yOffset".to_string(), offset);
+                }
+            }
+        }
+
+        let mut spec = json!({
+            "$schema": SCHEMA,
+            "width": self.width,
+            "height": self.height,
+            "autosize": { "type": "fit", "contains": "padding" },
+            "background": vega_color(&self.theme.background_color),
+        });
+        if let Some(title) = &self.title {
+            spec["title"] = json!(title);
+        }
+        match layers.len() {
+            0 => {}
+            1 => {
+                if let (Some(spec), Value::Object(layer)) = (spec.as_object_mut(), layers.remove(0))
+                {
+                    spec.extend(layer);
+                }
+            }
+            _ => spec["layer"] = Value::Array(layers),
+        }
+
+        serde_json::to_string_pretty(&spec)
+            .map_err(|e| ChartonError::Render(format!("Failed to serialize Vega-Lite spec: {}", e)))
+    }
+
+    /// The resolved categories of a discrete axis, in Charton's order.
+    ///
+    /// Vega-Lite sorts categories alphabetically by default, so the order is
+    /// passed along explicitly.
+    fn category_order(&self, channel: Channel) -> Result<Option<Vec<String>>, ChartonError> {
+        Ok(match self.resolve_scale_spec(channel)? {
+            Some(spec) if spec.scale_type == Scale::Discrete => match spec.domain {
+                ScaleDomain::Discrete(labels) => Some(labels),
+                _ => None,
+            },
+            _ => None,
+        })
+    }
+
+    /// The Vega-Lite color scale matching the colors Charton would draw.
+    fn vega_color_scale(&self) -> Result<Option<Value>, ChartonError> {
+        let Some(spec) = self.resolve_scale_spec(Channel::Color)? else {
+            return Ok(None);
+        };
+        let mapper = self.resolve_color_mapper(&spec)?;
+
+        Ok(Some(match (&spec.domain, &mapper) {
+            (ScaleDomain::Discrete(labels), VisualMapper::DiscreteColor { palette, .. })
+                if labels.len() <= palette.color_count() && palette_scheme(palette).is_some() =>
+            {
+                json!({ "domain": labels, "scheme": palette_scheme(palette) })
+            }
+            (ScaleDomain::Discrete(labels), _) => {
+                let max = labels.len().saturating_sub(1) as f64;
+                let range: Vec<String> = (0..labels.len())
+                    .map(|i| {
+                        let norm = if max > 0.0 { i as f64 / max } else { 0.0 };
+                        vega_color(&mapper.map_to_color(norm, max))
+                    })
+                    .collect();
+                json!({ "domain": labels, "range": range })
+            }
+            (_, VisualMapper::ContinuousColor { map }) => color_map_scale(map),
+            _ => return Ok(None),
+        }))
+    }
+}
+
+/// Returns the `scale` object of a field definition, creating it if needed.
+fn scale_entry(def: &mut Value) -> &mut Value {
+    if def.get("scale").is_none() {
+        def["scale"] = json!({});
+    }
+    &mut def["scale"]
+}
+
+/// The Vega-Lite scale of a continuous color map.
+///
+/// Maps without a Vega scheme are sampled into an explicit color range.
+fn color_map_scale(map: &ColorGradient) -> Value {
+    match map {
+        ColorGradient::Map(inner) => match color_map_scheme(inner) {
+            Some(name) => json!({ "scheme": name }),
+            None => sampled_range(map),
+        },
+        ColorGradient::Reversed(inner) => match inner.as_ref() {
+            ColorGradient::Map(base) => match color_map_scheme(base) {
+                Some(name) => json!({ "scheme": name, "reverse": true }),
+                None => sampled_range(map),
+            },
+            _ => sampled_range(map),
+        },
+        ColorGradient::Custom(_) => sampled_range(map),
+    }
+}
+
+fn sampled_range(map: &ColorGradient) -> Value {
+    let range: Vec<String> = (0..9)
+        .map(|i| vega_color(&map.get_color(i as f64 / 8.0)))
+        .collect();
+    json!({ "range": range })
+}
+
+/// The Vega scheme name of a color map, if Vega has it.
+const fn color_map_scheme(map: &ColorMap) -> Option<&'static str> {
+    Some(match map {
+        ColorMap::Viridis => "viridis",
+        ColorMap::Inferno => "inferno",
+        ColorMap::Magma => "magma",
+        ColorMap::Plasma => "plasma",
+        ColorMap::Cividis => "cividis",
+        ColorMap::Blues => "blues",
+        ColorMap::Greens => "greens",
+        ColorMap::Greys => "greys",
+        ColorMap::Oranges => "oranges",
+        ColorMap::Purples => "purples",
+        ColorMap::Reds => "reds",
+        ColorMap::BuGn => "bluegreen",
+        ColorMap::BuPu => "bluepurple",
+        ColorMap::GnBu => "greenblue",
+        ColorMap::OrRd => "orangered",
+        ColorMap::PuBuGn => "purplebluegreen",
+        ColorMap::PuBu => "purpleblue",
+        ColorMap::PuRd => "purplered",
+        ColorMap::RdPu => "redpurple",
+        ColorMap::YlGnBu => "yellowgreenblue",
+        ColorMap::YlGn => "yellowgreen",
+        ColorMap::YlOrBr => "yelloworangebrown",
+        ColorMap::YlOrRd => "yelloworangered",
+        ColorMap::Rainbow => "rainbow",
+        _ => return None,
+    })
+}
+
+/// The Vega scheme name of a palette, if Vega has it.
+const fn palette_scheme(palette: &ColorPalette) -> Option<&'static str> {
+    Some(match palette {
+        ColorPalette::Tab10 => "tableau10",
+        ColorPalette::Tab20 => "tableau20",
+        ColorPalette::Set1 => "set1",
+        ColorPalette::Set2 => "set2",
+        ColorPalette::Set3 => "set3",
+        ColorPalette::Pastel1 => "pastel1",
+        ColorPalette::Pastel2 => "pastel2",
+        ColorPalette::Dark2 => "dark2",
+        ColorPalette::Accent => "accent",
+        _ => return None,
+    })
+}
+
+/// The Vega-Lite measurement type of a scale.
+const fn vega_type(scale: Scale) -> &'static str {
+    match scale {
+        Scale::Linear | Scale::Log => "quantitative",
+        Scale::Discrete => "nominal",
+        Scale::Temporal => "temporal",
+    }
+}
+
+/// Writes a color as `#rrggbb`, or as CSS `rgba()` when it is translucent.
+fn vega_color(color: &SingleColor) -> String {
+    let [r, g, b, a] = color.rgba();
+    if a < 1.0 {
+        return color.to_css_string();
+    }
+    let byte = |c: f32| (c * 255.0).round() as u8;
+    format!("#{:02x}{:02x}{:02x}", byte(r), byte(g), byte(b))
+}
+
+/// Escapes the characters Vega-Lite reads as nested field access.
+fn escape_field(field: &str) -> String {
+    let mut escaped = String::with_capacity(field.len());
+    for c in field.chars() {
+        if matches!(c, '.' | '[' | ']' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// The rows of a dataset as Vega-Lite inline values, without internal columns.
+fn data_values(ds: &Dataset) -> Vec<Value> {
+    let names: Vec<String> = ds
+        .get_column_names()
+        .into_iter()
+        .filter(|n| !n.contains(TEMP_SUFFIX))
+        .collect();
+
+    (0..ds.height())
+        .map(|row| {
+            let record: Map<String, Value> = names
+                .iter()
+                .map(|name| (name.clone(), json_value(ds.get(name, row))))
+                .collect();
+            Value::Object(record)
+        })
+        .collect()
+}
+
+/// Converts a cell to JSON; temporal values become Vega-Lite millisecond timestamps.
+fn json_value(value: AnyValue<'_>) -> Value {
+    match value {
+        AnyValue::Null => Value::Null,
+        AnyValue::Boolean(b) => json!(b),
+        AnyValue::Int8(v) => json!(v),
+        AnyValue::Int16(v) => json!(v),
+        AnyValue::Int32(v) => json!(v),
+        AnyValue::Int64(v) => json!(v),
+        AnyValue::UInt32(v) => json!(v),
+        AnyValue::UInt64(v) => json!(v),
+        AnyValue::Float32(v) => {
+            serde_json::Number::from_f64(v as f64).map_or(Value::Null, Value::Number)
+        }
+        AnyValue::Float64(v) => serde_json::Number::from_f64(v).map_or(Value::Null, Value::Number),
+        AnyValue::String(s) => json!(s),
+        AnyValue::Date(days) => json!(days as i64 * MS_PER_DAY),
+        AnyValue::Datetime(ns, _) => json!(ns.div_euclid(NS_PER_MS)),
+        AnyValue::Duration(ns) | AnyValue::Time(ns) => json!(ns.div_euclid(NS_PER_MS)),
+    }
+}
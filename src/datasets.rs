@@ -5,12 +5,181 @@ pub mod nightingale;
 pub mod penguins;
 pub mod unemployment;
 
-use crate::core::data::Dataset;
+use crate::core::data::{ColumnVector, Dataset};
 use crate::error::ChartonError;
+use std::path::PathBuf;
+use std::sync::{Arc, OnceLock, PoisonError, RwLock};
+
+/// The built-in datasets: name, description and loader.
+type BuiltinDataset = (
+    &'static str,
+    &'static str,
+    fn() -> Result<Dataset, ChartonError>,
+);
+
+const BUILTIN_DATASETS: [BuiltinDataset; 6] = [
+    (
+        "mtcars",
+        "Motor trend car road tests (32 rows × 12 columns)",
+        mtcars::get_data,
+    ),
+    (
+        "iris",
+        "Edgar Anderson's Iris data (150 rows × 5 columns)",
+        iris::get_data,
+    ),
+    (
+        "mpg",
+        "First 10 rows of the UCI Auto MPG data",
+        mpg::get_data,
+    ),
+    (
+        "penguins",
+        "Palmer penguins (344 rows × 7 columns)",
+        penguins::get_data,
+    ),
+    (
+        "nightingale",
+        "Florence Nightingale's causes of mortality in the Crimean War",
+        nightingale::get_data,
+    ),
+    (
+        "unemployment",
+        "Unemployment rates of countries over 31 years",
+        unemployment::get_data,
+    ),
+];
+
+/// The file formats a registered dataset can be read from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DataFormat {
+    /// Comma-separated values with a header row. Columns whose values all
+    /// parse as numbers become numeric; empty fields are null.
+    Csv,
+}
+
+/// Where a registered dataset comes from. It is read on every `load_dataset`
+/// call, so a file picks up later edits.
+#[derive(Debug, Clone)]
+pub enum DatasetSource {
+    /// A file; the format is taken from its extension.
+    Path(PathBuf),
+    /// File contents held in memory.
+    Bytes(Vec<u8>, DataFormat),
+    /// A function building the dataset.
+    Loader(fn() -> Result<Dataset, ChartonError>),
+}
+
+impl DatasetSource {
+    fn load(&self) -> Result<Dataset, ChartonError> {
+        match self {
+            DatasetSource::Path(path) => {
+                let format = match path.extension().and_then(|e| e.to_str()) {
+                    Some(ext) if ext.eq_ignore_ascii_case("csv") => DataFormat::Csv,
+                    _ => {
+                        return Err(ChartonError::Unimplemented(format!(
+                            "Cannot infer the format of '{}'; supported extensions: csv",
+                            path.display()
+                        )));
+                    }
+                };
+                parse(&std::fs::read(path)?, format)
+            }
+            DatasetSource::Bytes(bytes, format) => parse(bytes, *format),
+            DatasetSource::Loader(loader) => loader(),
+        }
+    }
+}
+
+struct RegisteredDataset {
+    name: String,
+    description: String,
+    source: Arc<DatasetSource>,
+}
+
+/// Process-wide registry of user datasets, in registration order.
+static DATASET_REGISTRY: OnceLock<RwLock<Vec<RegisteredDataset>>> = OnceLock::new();
+
+fn registry() -> &'static RwLock<Vec<RegisteredDataset>> {
+    DATASET_REGISTRY.get_or_init(|| RwLock::new(Vec::new()))
+}
+
+/// Makes a dataset available to [`load_dataset`] under `name` for the rest of
+/// the process.
+///
+/// # Arguments
+///
+/// - `name`: The name passed to `load_dataset`.
+/// - `description`: A short description shown by [`list_datasets`].
+/// - `source`: Where the data is read from.
+/// - `overwrite`: Whether to replace a built-in or previously registered
+///   dataset of the same name.
+///
+/// # Errors
+///
+/// Returns `ChartonError::Data` if the name is taken and `overwrite` is false.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// register_dataset("sales", "Monthly sales", DatasetSource::Path("sales.csv".into()), false)?;
+/// let df = load_dataset("sales")?;
+/// ```
+pub fn register_dataset(
+    name: &str,
+    description: &str,
+    source: DatasetSource,
+    overwrite: bool,
+) -> Result<(), ChartonError> {
+    let mut datasets = registry().write().unwrap_or_else(PoisonError::into_inner);
+    let existing = datasets.iter().position(|d| d.name == name);
+    let is_builtin = BUILTIN_DATASETS.iter().any(|(n, _, _)| *n == name);
+    if !overwrite && (existing.is_some() || is_builtin) {
+        return Err(ChartonError::Data(format!(
+            "Dataset '{}' already exists; pass overwrite = true to replace it",
+            name
+        )));
+    }
+
+    let entry = RegisteredDataset {
+        name: name.to_string(),
+        description: description.to_string(),
+        source: Arc::new(source),
+    };
+    match existing {
+        Some(i) => datasets[i] = entry,
+        None => datasets.push(entry),
+    }
+    Ok(())
+}
+
+/// Lists the names and descriptions of all datasets `load_dataset` accepts:
+/// the built-ins first, then the registered ones in registration order.
+pub fn list_datasets() -> Vec<(String, String)> {
+    let datasets = registry().read().unwrap_or_else(PoisonError::into_inner);
+    let builtins = BUILTIN_DATASETS
+        .iter()
+        .map(|(name, description, _)| {
+            // A registered dataset that replaces a built-in keeps its place.
+            let description = datasets
+                .iter()
+                .find(|d| d.name == *name)
+                .map_or(*description, |d| d.description.as_str());
+            (name.to_string(), description.to_string())
+        })
+        .collect::<Vec<_>>();
+    let registered = datasets
+        .iter()
+        .filter(|d| !BUILTIN_DATASETS.iter().any(|(n, _, _)| *n == d.name))
+        .map(|d| (d.name.clone(), d.description.clone()));
+    builtins.into_iter().chain(registered).collect()
+}
 
 /// Load built-in datasets.
 ///
 /// Based on the passed dataset name `dataset`, returns the corresponding `Dataset` or an error.
+/// Datasets added with [`register_dataset`] are looked up first.
 ///
 /// # Arguments
 ///
@@ -20,6 +189,8 @@ use crate::error::ChartonError;
 ///   - `"mpg"`: Subset of first 10 rows from UCI Auto MPG dataset
 ///   - `"nightingale"`: Dataset for Florence Nightingale's famous polar area diagram.
 ///   - `"penguins"`: Detaset that includes data points across a sample size of 344 penguins (344 rows × 7 columns).
+///   - `"unemployment"`: Unemployment rates of several countries over 31 years.
+///   - any name registered with [`register_dataset`].
 ///
 /// # Returns
 ///
@@ -32,13 +203,111 @@ use crate::error::ChartonError;
 /// let df = load_dataset("mtcars")?;
 /// ```
 pub fn load_dataset(dataset: &str) -> Result<Dataset, ChartonError> {
-    match dataset {
-        "mtcars" => mtcars::get_data(),
-        "iris" => iris::get_data(),
-        "mpg" => mpg::get_data(),
-        "penguins" => penguins::get_data(),
-        "nightingale" => nightingale::get_data(),
-        "unemployment" => unemployment::get_data(),
-        _ => Err(ChartonError::Data("Dataset not found".into())),
+    // The lock is released before loading, so a loader may load other datasets.
+    let registered = registry()
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .iter()
+        .find(|d| d.name == dataset)
+        .map(|d| Arc::clone(&d.source));
+    if let Some(source) = registered {
+        return source.load();
+    }
+
+    match BUILTIN_DATASETS
+        .iter()
+        .find(|(name, _, _)| *name == dataset)
+    {
+        Some((_, _, loader)) => loader(),
+        None => Err(ChartonError::Data(format!(
+            "Dataset '{}' not found",
+            dataset
+        ))),
+    }
+}
+
+fn parse(bytes: &[u8], format: DataFormat) -> Result<Dataset, ChartonError> {
+    match format {
+        DataFormat::Csv => parse_csv(bytes),
+    }
+}
+
+/// Reads CSV with a header row. Fields may be quoted, with `""` for a quote.
+fn parse_csv(bytes: &[u8]) -> Result<Dataset, ChartonError> {
+    let text = std::str::from_utf8(bytes)
+        .map_err(|e| ChartonError::Data(format!("CSV is not valid UTF-8: {}", e)))?;
+    let mut records = split_csv_records(text.strip_prefix('\u{feff}').unwrap_or(text))?;
+    if records.is_empty() {
+        return Err(ChartonError::Data("CSV has no header row".into()));
+    }
+    let header = records.remove(0);
+
+    let mut columns: Vec<Vec<Option<String>>> =
+        vec![Vec::with_capacity(records.len()); header.len()];
+    for (line, record) in records.into_iter().enumerate() {
+        if record.len() != header.len() {
+            return Err(ChartonError::Data(format!(
+                "CSV row {} has {} fields, expected {}",
+                line + 1,
+                record.len(),
+                header.len()
+            )));
+        }
+        for (column, field) in columns.iter_mut().zip(record) {
+            column.push((!field.is_empty()).then_some(field));
+        }
+    }
+
+    let mut ds = Dataset::new();
+    for (name, values) in header.into_iter().zip(columns) {
+        let numbers: Option<Vec<Option<f64>>> = values
+            .iter()
+            .map(|v| match v {
+                Some(s) => s.trim().parse::<f64>().ok().map(Some),
+                None => Some(None),
+            })
+            .collect();
+        let column: ColumnVector = match numbers {
+            Some(numbers) => numbers.into(),
+            None => values.into(),
+        };
+        ds.add_column(name, column)?;
+    }
+    Ok(ds)
+}
+
+fn split_csv_records(text: &str) -> Result<Vec<Vec<String>>, ChartonError> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (c, in_quotes) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            ('"', true) => in_quotes = false,
+            ('"', false) if field.is_empty() => in_quotes = true,
+            (',', false) => record.push(std::mem::take(&mut field)),
+            ('\r', false) if chars.peek() == Some(&'\n') => {}
+            ('\n', false) => {
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+            }
+            _ => field.push(c),
+        }
+    }
+    if in_quotes {
+        return Err(ChartonError::Data("CSV has an unterminated quote".into()));
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
     }
+    // Blank lines carry no data.
+    records.retain(|r| !(r.len() == 1 && r[0].is_empty()));
+    Ok(records)
 }
@@ -1,15 +1,32 @@
+pub mod angle;
+pub mod close;
 pub mod color;
+pub mod detail;
+pub mod fill_opacity;
+pub mod high;
+pub mod href;
+pub mod low;
+pub mod opacity;
+pub mod open;
 pub mod path_group;
 pub mod shape;
 pub mod size;
+pub mod stroke_opacity;
 pub mod text;
+pub mod tooltip;
 pub mod x;
+pub mod x_offset;
 pub mod y;
 pub mod y2;
+pub mod y_offset;
 
 use self::{
-    color::Color, path_group::PathGroup, shape::Shape, size::Size, text::Text, x::X, y::Y, y2::Y2,
+    angle::Angle, close::Close, color::Color, detail::Detail, fill_opacity::FillOpacity,
+    high::High, href::Href, low::Low, opacity::Opacity, open::Open, path_group::PathGroup,
+    shape::Shape, size::Size, stroke_opacity::StrokeOpacity, text::Text, tooltip::Tooltip, x::X,
+    x_offset::XOffset, y::Y, y_offset::YOffset, y2::Y2,
 };
+use crate::error::ChartonError;
 use crate::scale::{Expansion, Scale};
 
 /// Represents the various visual aesthetics that can be mapped to data.
@@ -26,6 +43,7 @@ pub enum Channel {
     Size,
     Text,
     PathGroup,
+    Opacity,
 }
 
 /// Unified application interface for encoding specifications.
@@ -34,7 +52,11 @@ pub enum Channel {
 /// added to the global `Encoding` container.
 pub trait IntoEncoding {
     /// Consumes the specification and applies it to the provided `Encoding` container.
-    fn apply(self, enc: &mut Encoding);
+    ///
+    /// # Errors
+    /// Returns `ChartonError::Encoding` if a tuple assigns the same channel more
+    /// than once, naming both fields and their tuple positions.
+    fn apply(self, enc: &mut Encoding) -> Result<(), ChartonError>;
 }
 
 /// Global encoding container.
@@ -55,6 +77,21 @@ pub struct Encoding {
     pub(crate) size: Option<Size>,
     pub(crate) text: Option<Text>,
     pub(crate) path_group: Option<PathGroup>,
+    pub(crate) tooltip: Option<Tooltip>,
+    pub(crate) href: Option<Href>,
+    pub(crate) detail: Option<Detail>,
+    pub(crate) angle: Option<Angle>,
+    pub(crate) x_offset: Option<XOffset>,
+    pub(crate) y_offset: Option<YOffset>,
+    pub(crate) opacity: Option<Opacity>,
+    pub(crate) fill_opacity: Option<FillOpacity>,
+    pub(crate) stroke_opacity: Option<StrokeOpacity>,
+
+    // --- OHLC Channels (candlestick charts) ---
+    pub(crate) open: Option<Open>,
+    pub(crate) high: Option<High>,
+    pub(crate) low: Option<Low>,
+    pub(crate) close: Option<Close>,
 }
 
 impl Encoding {
@@ -75,6 +112,19 @@ impl Encoding {
             && self.size.is_none()
             && self.text.is_none()
             && self.path_group.is_none()
+            && self.tooltip.is_none()
+            && self.href.is_none()
+            && self.detail.is_none()
+            && self.angle.is_none()
+            && self.x_offset.is_none()
+            && self.y_offset.is_none()
+            && self.opacity.is_none()
+            && self.fill_opacity.is_none()
+            && self.stroke_opacity.is_none()
+            && self.open.is_none()
+            && self.high.is_none()
+            && self.low.is_none()
+            && self.close.is_none()
     }
 
     /// Returns the data field name associated with a specific visual channel.
@@ -90,6 +140,7 @@ impl Encoding {
             Channel::Size => self.size.as_ref().map(|v| v.field.as_str()),
             Channel::Text => self.text.as_ref().map(|v| v.field.as_str()),
             Channel::PathGroup => self.path_group.as_ref().map(|v| v.field.as_str()),
+            Channel::Opacity => self.opacity.as_ref().map(|v| v.field.as_str()),
         }
     }
 
@@ -103,6 +154,7 @@ impl Encoding {
             Channel::Size => self.size.as_ref().and_then(|v| v.scale_type),
             Channel::Text => None,
             Channel::PathGroup => self.path_group.as_ref().and_then(|v| v.scale_type),
+            Channel::Opacity => self.opacity.as_ref().and_then(|v| v.scale_type),
         }
     }
 
@@ -114,11 +166,114 @@ impl Encoding {
             Channel::Color => self.color.as_ref().and_then(|v| v.expansion),
             Channel::Shape => self.shape.as_ref().and_then(|v| v.expansion),
             Channel::Size => self.size.as_ref().and_then(|v| v.expansion),
-            Channel::Text => None,
+            Channel::Text | Channel::Opacity => None,
             Channel::PathGroup => self.path_group.as_ref().and_then(|v| v.expansion),
         }
     }
 
+    /// Retrieves the logarithm base configured for a positional channel.
+    pub fn get_log_base_by_channel(&self, channel: Channel) -> Option<f64> {
+        match channel {
+            Channel::X => self.x.as_ref().and_then(|v| v.log_base),
+            Channel::Y => self.y.as_ref().and_then(|v| v.log_base),
+            _ => None,
+        }
+    }
+
+    /// Moves every channel set in `other` into this container, replacing existing ones.
+    ///
+    /// Repeated `encode` calls therefore refine a chart, while duplicates within a
+    /// single call are rejected by [`IntoEncoding::apply`].
+    pub(crate) fn merge(&mut self, other: Encoding) {
+        macro_rules! take {
+            ($($field:ident),+) => {
+                $( if other.$field.is_some() { self.$field = other.$field; } )+
+            };
+        }
+        take!(
+            x,
+            y,
+            y2,
+            color,
+            shape,
+            size,
+            text,
+            path_group,
+            tooltip,
+            href,
+            detail,
+            angle,
+            x_offset,
+            y_offset,
+            opacity,
+            fill_opacity,
+            stroke_opacity,
+            open,
+            high,
+            low,
+            close
+        );
+    }
+
+    /// Lists the channels set in this container with the field(s) each one encodes.
+    fn assigned(&self) -> Vec<(&'static str, String)> {
+        let mut assigned = Vec::new();
+        macro_rules! list {
+            ($($field:ident),+) => {
+                $( if let Some(v) = &self.$field {
+                    assigned.push((stringify!($field), v.field.clone()));
+                } )+
+            };
+        }
+        list!(
+            x,
+            y,
+            y2,
+            color,
+            shape,
+            size,
+            text,
+            path_group,
+            href,
+            detail,
+            angle,
+            x_offset,
+            y_offset,
+            opacity,
+            fill_opacity,
+            stroke_opacity,
+            open,
+            high,
+            low,
+            close
+        );
+        if let Some(tooltip) = &self.tooltip {
+            assigned.push(("tooltip", tooltip.fields.join(", ")));
+        }
+        assigned
+    }
+
+    /// Merges the channels applied by the specification at tuple `position`,
+    /// rejecting any channel already `claimed` by an earlier position.
+    fn merge_claimed(
+        &mut self,
+        part: Encoding,
+        position: usize,
+        claimed: &mut Vec<(&'static str, String, usize)>,
+    ) -> Result<(), ChartonError> {
+        for (channel, field) in part.assigned() {
+            if let Some((_, previous, first_pos)) = claimed.iter().find(|(c, ..)| *c == channel) {
+                return Err(ChartonError::Encoding(format!(
+                    "Channel '{}' is encoded more than once: '{}' (position {}) and '{}' (position {})",
+                    channel, previous, first_pos, field, position
+                )));
+            }
+            claimed.push((channel, field, position));
+        }
+        self.merge(part);
+        Ok(())
+    }
+
     /// Checks if the channel is explicitly configured to include zero in its axis range.
     pub fn get_zero_by_channel(&self, channel: Channel) -> bool {
         match channel {
@@ -133,50 +288,149 @@ impl Encoding {
 /* ---------- IntoEncoding Implementations ---------- */
 
 impl IntoEncoding for X {
-    fn apply(self, enc: &mut Encoding) {
+    fn apply(self, enc: &mut Encoding) -> Result<(), ChartonError> {
         enc.x = Some(self);
+        Ok(())
     }
 }
 
 impl IntoEncoding for Y {
-    fn apply(self, enc: &mut Encoding) {
+    fn apply(self, enc: &mut Encoding) -> Result<(), ChartonError> {
         enc.y = Some(self);
+        Ok(())
     }
 }
 
 impl IntoEncoding for Y2 {
-    fn apply(self, enc: &mut Encoding) {
+    fn apply(self, enc: &mut Encoding) -> Result<(), ChartonError> {
         enc.y2 = Some(self);
+        Ok(())
     }
 }
 
 impl IntoEncoding for Color {
-    fn apply(self, enc: &mut Encoding) {
+    fn apply(self, enc: &mut Encoding) -> Result<(), ChartonError> {
         enc.color = Some(self);
+        Ok(())
     }
 }
 
 impl IntoEncoding for Shape {
-    fn apply(self, enc: &mut Encoding) {
+    fn apply(self, enc: &mut Encoding) -> Result<(), ChartonError> {
         enc.shape = Some(self);
+        Ok(())
     }
 }
 
 impl IntoEncoding for Size {
-    fn apply(self, enc: &mut Encoding) {
+    fn apply(self, enc: &mut Encoding) -> Result<(), ChartonError> {
         enc.size = Some(self);
+        Ok(())
     }
 }
 
 impl IntoEncoding for Text {
-    fn apply(self, enc: &mut Encoding) {
+    fn apply(self, enc: &mut Encoding) -> Result<(), ChartonError> {
         enc.text = Some(self);
+        Ok(())
     }
 }
 
 impl IntoEncoding for PathGroup {
-    fn apply(self, enc: &mut Encoding) {
+    fn apply(self, enc: &mut Encoding) -> Result<(), ChartonError> {
         enc.path_group = Some(self);
+        Ok(())
+    }
+}
+
+impl IntoEncoding for Tooltip {
+    fn apply(self, enc: &mut Encoding) -> Result<(), ChartonError> {
+        enc.tooltip = Some(self);
+        Ok(())
+    }
+}
+
+impl IntoEncoding for Href {
+    fn apply(self, enc: &mut Encoding) -> Result<(), ChartonError> {
+        enc.href = Some(self);
+        Ok(())
+    }
+}
+
+impl IntoEncoding for Detail {
+    fn apply(self, enc: &mut Encoding) -> Result<(), ChartonError> {
+        enc.detail = Some(self);
+        Ok(())
+    }
+}
+
+impl IntoEncoding for Angle {
+    fn apply(self, enc: &mut Encoding) -> Result<(), ChartonError> {
+        enc.angle = Some(self);
+        Ok(())
+    }
+}
+
+impl IntoEncoding for XOffset {
+    fn apply(self, enc: &mut Encoding) -> Result<(), ChartonError> {
+        enc.x_offset = Some(self);
+        Ok(())
+    }
+}
+
+impl IntoEncoding for YOffset {
+    fn apply(self, enc: &mut Encoding) -> Result<(), ChartonError> {
+        enc.y_offset = Some(self);
+        Ok(())
+    }
+}
+
+impl IntoEncoding for Opacity {
+    fn apply(self, enc: &mut Encoding) -> Result<(), ChartonError> {
+        enc.opacity = Some(self);
+        Ok(())
+    }
+}
+
+impl IntoEncoding for FillOpacity {
+    fn apply(self, enc: &mut Encoding) -> Result<(), ChartonError> {
+        enc.fill_opacity = Some(self);
+        Ok(())
+    }
+}
+
+impl IntoEncoding for StrokeOpacity {
+    fn apply(self, enc: &mut Encoding) -> Result<(), ChartonError> {
+        enc.stroke_opacity = Some(self);
+        Ok(())
+    }
+}
+
+impl IntoEncoding for Open {
+    fn apply(self, enc: &mut Encoding) -> Result<(), ChartonError> {
+        enc.open = Some(self);
+        Ok(())
+    }
+}
+
+impl IntoEncoding for High {
+    fn apply(self, enc: &mut Encoding) -> Result<(), ChartonError> {
+        enc.high = Some(self);
+        Ok(())
+    }
+}
+
+impl IntoEncoding for Low {
+    fn apply(self, enc: &mut Encoding) -> Result<(), ChartonError> {
+        enc.low = Some(self);
+        Ok(())
+    }
+}
+
+impl IntoEncoding for Close {
+    fn apply(self, enc: &mut Encoding) -> Result<(), ChartonError> {
+        enc.close = Some(self);
+        Ok(())
     }
 }
 
@@ -185,8 +439,15 @@ macro_rules! impl_tuple_encoding {
     ($($idx:tt $T:ident),+) => {
         impl<$($T: IntoEncoding),+> IntoEncoding for ($($T,)+) {
             #[inline]
-            fn apply(self, enc: &mut Encoding) {
-                $( self.$idx.apply(enc); )+
+            fn apply(self, enc: &mut Encoding) -> Result<(), ChartonError> {
+                // Channels assigned so far, with the tuple position that assigned them.
+                let mut claimed = Vec::new();
+                $(
+                    let mut part = Encoding::new();
+                    self.$idx.apply(&mut part)?;
+                    enc.merge_claimed(part, $idx, &mut claimed)?;
+                )+
+                Ok(())
             }
         }
     };
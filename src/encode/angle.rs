@@ -0,0 +1,40 @@
+/// Represents a rotation encoding specification.
+///
+/// The `Angle` struct names a numeric data column holding rotations in
+/// degrees, clockwise on screen from the positive x-axis: 0 keeps text level
+/// and shapes in their usual orientation (a triangle points up). Point marks
+/// turn their shape about its center, which makes triangles and stars usable
+/// as direction markers (e.g. wind direction); text marks turn about their
+/// anchor.
+///
+/// ### Architecture Note:
+/// Like `Tooltip`, a rotation maps data values straight to the mark and has no
+/// scale or legend. Rows with a null angle are drawn unrotated, and circles
+/// look the same at any angle.
+#[derive(Clone, Debug)]
+pub struct Angle {
+    // --- User Configuration (Intent/Inputs) ---
+    /// The name of the numeric data column holding the angles in degrees.
+    pub(crate) field: String,
+}
+
+impl Angle {
+    /// Creates a new rotation encoding for a specific data field.
+    pub fn new(field: &str) -> Self {
+        Self {
+            field: field.to_string(),
+        }
+    }
+}
+
+/// Convenience builder function to create a new Angle encoding.
+///
+/// ### Example
+/// ```rust,ignore
+/// chart.mark_point()?
+///     .configure_point(|m| m.with_shape("triangle"))
+///     .encode((alt::x("lon"), alt::y("lat"), alt::angle("wind_dir")))?
+/// ```
+pub fn angle(field: &str) -> Angle {
+    Angle::new(field)
+}
@@ -0,0 +1,24 @@
+/// Represents the close price encoding of a candlestick (OHLC) chart.
+///
+/// Like `Y2`, this channel has no scale of its own: its values are drawn on the
+/// same vertical axis as the other OHLC fields.
+#[derive(Debug, Clone)]
+pub struct Close {
+    /// The name of the data column holding the closing price of each period.
+    pub(crate) field: String,
+}
+
+impl Close {
+    /// Creates a new Close encoding for the specified data field.
+    pub fn new(field: &str) -> Self {
+        Self {
+            field: field.to_string(),
+        }
+    }
+}
+
+/// Convenience builder function to create a new Close encoding.
+///
+pub fn close(field: &str) -> Close {
+    Close::new(field)
+}
@@ -1,5 +1,6 @@
 use crate::core::data::AggregateOp;
 use crate::scale::{Expansion, ResolvedScale, Scale, ScaleDomain};
+use crate::visual::color::ColorMap;
 
 /// Represents a color encoding specification for chart elements.
 ///
@@ -30,6 +31,15 @@ pub struct Color {
     /// Rules for adding padding or buffer to the ends of the color scale domain.
     pub(crate) expansion: Option<Expansion>,
 
+    /// Declared category order for ordinal color scales (e.g. Likert responses).
+    pub(crate) order: Option<Vec<String>>,
+
+    /// Whether categories missing from `order` are appended instead of rejected.
+    pub(crate) append_unlisted: bool,
+
+    /// Continuous color map sampled for ordinal categories.
+    pub(crate) scheme: Option<ColorMap>,
+
     // --- System Resolution (Result/Outputs) ---
     /// Stores the resolved scale instance. Using RwLock to support
     /// back-filling updates across multiple render calls.
@@ -45,6 +55,9 @@ impl Color {
             scale_type: None,
             domain: None,
             expansion: None,
+            order: None,
+            append_unlisted: false,
+            scheme: None,
             resolved_scale: ResolvedScale::none(),
         }
     }
@@ -66,6 +79,38 @@ impl Color {
         self
     }
 
+    /// Treats the field as ordered categories, listed from lowest to highest.
+    ///
+    /// The categories are colored with evenly spaced samples of a continuous
+    /// color map (see [`Color::with_scheme`], default `ColorMap::Blues`) and the
+    /// legend lists them in the declared order. Categories found in the data but
+    /// missing from `order` are an error unless [`Color::with_append_unlisted`] is set.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// alt::color("response")
+    ///     .ordinal(&["Disagree", "Neutral", "Agree"])
+    ///     .with_scheme(ColorMap::Blues)
+    /// ```
+    pub fn ordinal(mut self, order: &[&str]) -> Self {
+        self.scale_type = Some(Scale::Discrete);
+        self.order = Some(order.iter().map(|s| s.to_string()).collect());
+        self
+    }
+
+    /// Sets the color map sampled for ordinal categories.
+    pub const fn with_scheme(mut self, scheme: ColorMap) -> Self {
+        self.scheme = Some(scheme);
+        self
+    }
+
+    /// Appends categories missing from the ordinal order (in order of appearance)
+    /// instead of returning an error.
+    pub const fn with_append_unlisted(mut self, append: bool) -> Self {
+        self.append_unlisted = append;
+        self
+    }
+
     /// Configures the expansion padding for the color scale.
     pub const fn with_expandsion(mut self, expansion: Expansion) -> Self {
         self.expansion = Some(expansion);
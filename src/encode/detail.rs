@@ -0,0 +1,39 @@
+/// Represents a detail (grouping) encoding specification.
+///
+/// The `Detail` struct names a data column whose distinct values split the
+/// rows of a line mark into separate paths, without giving them their own
+/// colors or legend entries. Without it, every row of an uncolored line is
+/// joined into a single path, zig-zagging between categories.
+///
+/// ### Architecture Note:
+/// Like `Href`, a detail field has no scale. With a color encoding as well,
+/// each color group is split further by the detail values, so several paths
+/// can share a color. Rows with a null detail value form a path of their own.
+#[derive(Clone, Debug)]
+pub struct Detail {
+    // --- User Configuration (Intent/Inputs) ---
+    /// The name of the data column identifying each path.
+    pub(crate) field: String,
+}
+
+impl Detail {
+    /// Creates a new detail encoding for a specific data field.
+    pub fn new(field: &str) -> Self {
+        Self {
+            field: field.to_string(),
+        }
+    }
+}
+
+/// Convenience builder function to create a new Detail encoding.
+///
+/// ### Example
+/// ```rust,ignore
+/// // One gray line per country, none of them in the legend.
+/// chart
+///     .mark_line()?
+///     .encode((alt::x("year"), alt::y("gdp"), alt::detail("country")))?
+/// ```
+pub fn detail(field: &str) -> Detail {
+    Detail::new(field)
+}
@@ -0,0 +1,38 @@
+/// Represents a fill opacity encoding specification.
+///
+/// The `FillOpacity` struct names a numeric data column holding opacities
+/// from 0 to 1 for the inside of each point mark only; the outline keeps the
+/// opacity given by `StrokeOpacity`, `Opacity` or the mark configuration.
+///
+/// ### Architecture Note:
+/// Like `Opacity`, the values are used as they are, with no scale or legend.
+/// Values outside [0, 1] are clamped and null rows fall back to the shared
+/// opacity.
+#[derive(Clone, Debug)]
+pub struct FillOpacity {
+    // --- User Configuration (Intent/Inputs) ---
+    /// The name of the numeric data column holding the fill opacities.
+    pub(crate) field: String,
+}
+
+impl FillOpacity {
+    /// Creates a new fill opacity encoding for a specific data field.
+    pub fn new(field: &str) -> Self {
+        Self {
+            field: field.to_string(),
+        }
+    }
+}
+
+/// Convenience builder function to create a new FillOpacity encoding.
+///
+/// ### Example
+/// ```rust,ignore
+/// // Translucent bubbles with solid outlines.
+/// chart.mark_point()?
+///     .configure_point(|m| m.with_stroke("black"))
+///     .encode((alt::x("gdp"), alt::y("life"), alt::fill_opacity("share")))?
+/// ```
+pub fn fill_opacity(field: &str) -> FillOpacity {
+    FillOpacity::new(field)
+}
@@ -0,0 +1,24 @@
+/// Represents the high price encoding of a candlestick (OHLC) chart.
+///
+/// Like `Y2`, this channel has no scale of its own: its values are drawn on the
+/// same vertical axis as the other OHLC fields.
+#[derive(Debug, Clone)]
+pub struct High {
+    /// The name of the data column holding the highest price of each period.
+    pub(crate) field: String,
+}
+
+impl High {
+    /// Creates a new High encoding for the specified data field.
+    pub fn new(field: &str) -> Self {
+        Self {
+            field: field.to_string(),
+        }
+    }
+}
+
+/// Convenience builder function to create a new High encoding.
+///
+pub fn high(field: &str) -> High {
+    High::new(field)
+}
@@ -0,0 +1,37 @@
+/// Represents a hyperlink encoding specification.
+///
+/// The `Href` struct names a string column holding URLs. In SVG output, the
+/// elements drawn for a row are wrapped in an `<a>` element that opens the
+/// row's URL in a new tab; other backends ignore it.
+///
+/// ### Architecture Note:
+/// Like `Tooltip`, a link maps data values straight to strings and has no
+/// scale. Rows with a null URL are drawn without a link. An aggregated bar
+/// links to the URL of its first row; marks whose statistical transform does
+/// not keep the column are not linked. Marks drawing one shape from many rows
+/// (line, area, boxplot, violin and geo_path) reject the encoding.
+#[derive(Clone, Debug)]
+pub struct Href {
+    // --- User Configuration (Intent/Inputs) ---
+    /// The name of the data column holding the URLs.
+    pub(crate) field: String,
+}
+
+impl Href {
+    /// Creates a new hyperlink encoding for a specific data field.
+    pub fn new(field: &str) -> Self {
+        Self {
+            field: field.to_string(),
+        }
+    }
+}
+
+/// Convenience builder function to create a new Href encoding.
+///
+/// ### Example
+/// ```rust,ignore
+/// chart.encode((alt::x("name"), alt::y("stars"), alt::href("url")))?
+/// ```
+pub fn href(field: &str) -> Href {
+    Href::new(field)
+}
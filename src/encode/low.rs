@@ -0,0 +1,24 @@
+/// Represents the low price encoding of a candlestick (OHLC) chart.
+///
+/// Like `Y2`, this channel has no scale of its own: its values are drawn on the
+/// same vertical axis as the other OHLC fields.
+#[derive(Debug, Clone)]
+pub struct Low {
+    /// The name of the data column holding the lowest price of each period.
+    pub(crate) field: String,
+}
+
+impl Low {
+    /// Creates a new Low encoding for the specified data field.
+    pub fn new(field: &str) -> Self {
+        Self {
+            field: field.to_string(),
+        }
+    }
+}
+
+/// Convenience builder function to create a new Low encoding.
+///
+pub fn low(field: &str) -> Low {
+    Low::new(field)
+}
@@ -0,0 +1,76 @@
+use crate::error::ChartonError;
+use crate::scale::Scale;
+
+/// Represents an opacity encoding specification.
+///
+/// The `Opacity` struct maps a continuous data column to the opacity of the
+/// marks, the way `Size` maps one to their radius: the data domain is scaled
+/// onto an alpha range, [0.2, 1.0] by default, and shown in a legend of
+/// increasingly opaque keys. Point marks take the mapped value for their fill
+/// and stroke; line marks for the whole line, from its first row.
+///
+/// ### Architecture Note:
+/// The domain is consolidated across all layers mapping opacity, like size
+/// and shape. Rows with a null value keep the mark's configured opacity.
+/// `FillOpacity` and `StrokeOpacity` stay unscaled (they read opacities
+/// straight from the data) and take precedence over this channel for their
+/// own part of the mark.
+#[derive(Clone, Debug)]
+pub struct Opacity {
+    // --- User Configuration (Intent/Inputs) ---
+    /// The name of the data column used for opacity mapping.
+    pub(crate) field: String,
+
+    /// The scale type for opacity mapping (Linear or Log).
+    pub(crate) scale_type: Option<Scale>,
+
+    /// The (min, max) opacities the data domain is mapped onto.
+    pub(crate) range: (f64, f64),
+}
+
+impl Opacity {
+    /// Creates a new opacity encoding for a specific data field.
+    pub fn new(field: &str) -> Self {
+        Self {
+            field: field.to_string(),
+            scale_type: Some(Scale::Linear),
+            range: (0.2, 1.0),
+        }
+    }
+
+    /// Sets the scale type for the opacity encoding (e.g., Linear, Log).
+    ///
+    /// # Errors
+    /// Returns `ChartonError::Scale` if `Scale::Discrete` is provided, as opacity
+    /// maps continuous data.
+    pub fn with_scale(mut self, scale_type: Scale) -> Result<Self, ChartonError> {
+        if matches!(scale_type, Scale::Discrete) {
+            return Err(ChartonError::Scale(
+                "Opacity encoding cannot use Scale::Discrete as opacity requires continuous data"
+                    .to_string(),
+            ));
+        }
+        self.scale_type = Some(scale_type);
+        Ok(self)
+    }
+
+    /// Sets the opacities the smallest and largest data values map to.
+    ///
+    /// Both ends are clamped to [0, 1]; `min` may exceed `max` to make larger
+    /// values more transparent.
+    pub const fn with_range(mut self, min: f64, max: f64) -> Self {
+        self.range = (min.clamp(0.0, 1.0), max.clamp(0.0, 1.0));
+        self
+    }
+}
+
+/// Convenience builder function to create a new Opacity encoding.
+///
+/// ### Example
+/// ```rust,ignore
+/// chart.mark_point()?
+///     .encode((alt::x("gdp"), alt::y("life"), alt::opacity("confidence")))?
+/// ```
+pub fn opacity(field: &str) -> Opacity {
+    Opacity::new(field)
+}
@@ -0,0 +1,24 @@
+/// Represents the open price encoding of a candlestick (OHLC) chart.
+///
+/// Like `Y2`, this channel has no scale of its own: its values are drawn on the
+/// same vertical axis as the other OHLC fields.
+#[derive(Debug, Clone)]
+pub struct Open {
+    /// The name of the data column holding the opening price of each period.
+    pub(crate) field: String,
+}
+
+impl Open {
+    /// Creates a new Open encoding for the specified data field.
+    pub fn new(field: &str) -> Self {
+        Self {
+            field: field.to_string(),
+        }
+    }
+}
+
+/// Convenience builder function to create a new Open encoding.
+///
+pub fn open(field: &str) -> Open {
+    Open::new(field)
+}
@@ -27,6 +27,12 @@ pub struct Size {
     /// Rules for adding padding or buffer to the ends of the size domain.
     pub(crate) expansion: Option<Expansion>,
 
+    /// Minimum mapped size in pixels; smaller results are raised to this value.
+    pub(crate) floor: Option<f64>,
+
+    /// Drops rows whose size value is exactly zero instead of drawing them.
+    pub(crate) drop_zero: bool,
+
     // --- System Resolution (Result/Outputs) ---
     /// Stores the resolved scale instance. Using RwLock to support
     /// back-filling updates across multiple render calls.
@@ -41,6 +47,8 @@ impl Size {
             scale_type: Some(Scale::Linear),
             domain: None,
             expansion: None,
+            floor: None,
+            drop_zero: false,
             resolved_scale: ResolvedScale::none(),
         }
     }
@@ -72,6 +80,21 @@ impl Size {
         self.expansion = Some(expansion);
         self
     }
+
+    /// Sets a minimum mapped size in pixels, so that tiny values stay visible.
+    ///
+    /// The size legend reflects the floor: its smallest entry is drawn at the
+    /// floored size and labeled with "≤".
+    pub const fn with_floor(mut self, px: f64) -> Self {
+        self.floor = Some(px);
+        self
+    }
+
+    /// Drops rows whose size value is zero instead of drawing them at the minimum size.
+    pub const fn with_drop_zero(mut self, drop: bool) -> Self {
+        self.drop_zero = drop;
+        self
+    }
 }
 
 /// Convenience builder function to create a new Size encoding.
@@ -0,0 +1,36 @@
+/// Represents a stroke opacity encoding specification.
+///
+/// The `StrokeOpacity` struct names a numeric data column holding opacities
+/// from 0 to 1 for the outline of each point mark only; the inside keeps the
+/// opacity given by `FillOpacity`, `Opacity` or the mark configuration.
+///
+/// ### Architecture Note:
+/// Like `Opacity`, the values are used as they are, with no scale or legend.
+/// Values outside [0, 1] are clamped and null rows fall back to the shared
+/// opacity.
+#[derive(Clone, Debug)]
+pub struct StrokeOpacity {
+    // --- User Configuration (Intent/Inputs) ---
+    /// The name of the numeric data column holding the stroke opacities.
+    pub(crate) field: String,
+}
+
+impl StrokeOpacity {
+    /// Creates a new stroke opacity encoding for a specific data field.
+    pub fn new(field: &str) -> Self {
+        Self {
+            field: field.to_string(),
+        }
+    }
+}
+
+/// Convenience builder function to create a new StrokeOpacity encoding.
+///
+/// ### Example
+/// ```rust,ignore
+/// chart.mark_point()?
+///     .encode((alt::x("x"), alt::y("y"), alt::stroke_opacity("certainty")))?
+/// ```
+pub fn stroke_opacity(field: &str) -> StrokeOpacity {
+    StrokeOpacity::new(field)
+}
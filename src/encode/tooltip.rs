@@ -0,0 +1,107 @@
+use crate::scale::inverse::TickFormatter;
+
+/// Represents a tooltip encoding specification.
+///
+/// The `Tooltip` struct lists the data columns shown when hovering a mark.
+/// In SVG output, every mark drawn for a row gets a `<title>` child with one
+/// "field: value" line per column; other backends ignore it.
+///
+/// ### Architecture Note:
+/// Like `Text`, a tooltip maps data values straight to strings and has no
+/// scale. Columns that a mark's statistical transform does not keep (e.g.
+/// the raw rows behind an aggregated bar) are left out of its tooltip.
+/// Marks drawing one shape from many rows (line, area, boxplot, violin and
+/// geo_path) reject the encoding.
+#[derive(Clone, Debug)]
+pub struct Tooltip {
+    // --- User Configuration (Intent/Inputs) ---
+    /// The names of the data columns listed in the tooltip, in order.
+    pub(crate) fields: Vec<String>,
+
+    /// The format of numeric values.
+    pub(crate) format: Option<TickFormatter>,
+}
+
+impl Tooltip {
+    /// Creates a new tooltip encoding for one or more data fields.
+    pub fn new<F: IntoTooltipFields>(fields: F) -> Self {
+        Self {
+            fields: fields.into_tooltip_fields(),
+            format: None,
+        }
+    }
+
+    /// Formats the numeric values of the tooltip.
+    ///
+    /// Accepts a built-in [`TickFormat`](crate::theme::TickFormat) or any `Fn(f64) -> String`, like the
+    /// axis tick formatters: `TickFormat::Precision(2)` gives two decimals and
+    /// `TickFormat::Percent` a percentage. It takes precedence over the
+    /// fields' `with_field_format`. Text, dates and nulls are shown unchanged.
+    pub fn with_format(mut self, format: impl Into<TickFormatter>) -> Self {
+        self.format = Some(format.into());
+        self
+    }
+}
+
+/// Field lists accepted by [`tooltip`]: a single name or a collection of names.
+pub trait IntoTooltipFields {
+    fn into_tooltip_fields(self) -> Vec<String>;
+}
+
+impl IntoTooltipFields for &str {
+    fn into_tooltip_fields(self) -> Vec<String> {
+        vec![self.to_string()]
+    }
+}
+
+impl IntoTooltipFields for String {
+    fn into_tooltip_fields(self) -> Vec<String> {
+        vec![self]
+    }
+}
+
+impl IntoTooltipFields for &[&str] {
+    fn into_tooltip_fields(self) -> Vec<String> {
+        self.iter().map(|f| f.to_string()).collect()
+    }
+}
+
+impl<const N: usize> IntoTooltipFields for [&str; N] {
+    fn into_tooltip_fields(self) -> Vec<String> {
+        self.iter().map(|f| f.to_string()).collect()
+    }
+}
+
+impl IntoTooltipFields for Vec<&str> {
+    fn into_tooltip_fields(self) -> Vec<String> {
+        self.into_iter().map(|f| f.to_string()).collect()
+    }
+}
+
+impl IntoTooltipFields for Vec<String> {
+    fn into_tooltip_fields(self) -> Vec<String> {
+        self
+    }
+}
+
+/// Convenience builder function to create a new Tooltip encoding.
+///
+/// ### Example
+/// ```rust,ignore
+/// chart.encode((alt::x("wt"), alt::y("mpg"), alt::tooltip(["model", "mpg", "hp"])))?
+/// ```
+pub fn tooltip<F: IntoTooltipFields>(fields: F) -> Tooltip {
+    Tooltip::new(fields)
+}
+
+/// Creates a tooltip listing several fields, one "field: value" line each.
+///
+/// Same as [`tooltip`] with a list of names.
+///
+/// ### Example
+/// ```rust,ignore
+/// chart.encode((alt::x("wt"), alt::y("mpg"), alt::tooltip_multi(vec!["model", "mpg"])))?
+/// ```
+pub fn tooltip_multi(fields: Vec<&str>) -> Tooltip {
+    Tooltip::new(fields)
+}
@@ -23,6 +23,9 @@ pub struct X {
     /// This acts as the highest priority override during the training phase.
     pub(crate) domain: Option<ScaleDomain>,
 
+    /// The logarithm base used when the scale is `Scale::Log`. Defaults to 10.
+    pub(crate) log_base: Option<f64>,
+
     /// Rules for adding padding/buffer to the ends of the axis domain.
     pub(crate) expansion: Option<Expansion>,
 
@@ -32,6 +35,10 @@ pub struct X {
 
     pub(crate) bins: Option<usize>, // bins for continuous encoding value in marks like barchart and histogram
 
+    /// A field that splits each x category into side-by-side sub-bars (bar charts only).
+    /// Independent of the color field, which then stacks inside every sub-bar.
+    pub(crate) dodge_by: Option<String>,
+
     // --- System Resolution (Result/Outputs) ---
     /// Stores the resolved scale instance. Using RwLock to support
     /// back-filling updates across multiple render calls.
@@ -45,9 +52,11 @@ impl X {
             field: field.to_string(),
             scale_type: None,
             domain: None,
+            log_base: None,
             expansion: None,
             zero: None,
             bins: None,
+            dodge_by: None,
             resolved_scale: ResolvedScale::none(),
         }
     }
@@ -67,6 +76,15 @@ impl X {
         self
     }
 
+    /// Uses a logarithmic scale with the given base (e.g. 2.0 or `std::f64::consts::E`).
+    ///
+    /// Ticks are placed at integer powers of the base. The base must be greater than 1.
+    pub const fn with_log_base(mut self, base: f64) -> Self {
+        self.scale_type = Some(Scale::Log);
+        self.log_base = Some(base);
+        self
+    }
+
     /// Configures the expansion padding for the axis.
     pub const fn with_expansion(mut self, expansion: Expansion) -> Self {
         self.expansion = Some(expansion);
@@ -94,6 +112,25 @@ impl X {
         self.bins = Some(bins);
         self
     }
+
+    /// Dodges bars side by side within each x category by the given field.
+    ///
+    /// Combined with a stacked y and a color encoding, this draws one stack per
+    /// value of `field` in every category, stacking the color field inside it.
+    /// Each sub-bar is labelled with its value above the stack.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// chart.mark_bar()?.encode((
+    ///     x("month").with_dodge_by("region"),
+    ///     y("sales").with_stack("stacked"),
+    ///     color("product"),
+    /// ))?
+    /// ```
+    pub fn with_dodge_by(mut self, field: &str) -> Self {
+        self.dodge_by = Some(field.to_string());
+        self
+    }
 }
 
 /// Convenience builder function to create a new X encoding.
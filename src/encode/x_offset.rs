@@ -0,0 +1,40 @@
+/// Represents a horizontal dodge encoding specification.
+///
+/// The `XOffset` struct names a data column whose values split every X
+/// position into side-by-side lanes: each unique value gets its own lane,
+/// in the column's category order, and lanes are centered on the position.
+/// Unlike jitter, the offset is deterministic, so the same group always sits
+/// at the same place in every category.
+///
+/// ### Architecture Note:
+/// Like `Angle`, an offset has no scale or legend of its own. It replaces the
+/// lanes that a color encoding would otherwise create on a discrete X-axis;
+/// offsetting by the color field keeps lanes and colors in the same order.
+/// Lane spacing is set with `with_dodge_width` on the mark. Only point marks
+/// support it.
+#[derive(Clone, Debug)]
+pub struct XOffset {
+    // --- User Configuration (Intent/Inputs) ---
+    /// The name of the data column holding the groups.
+    pub(crate) field: String,
+}
+
+impl XOffset {
+    /// Creates a new horizontal dodge encoding for a specific data field.
+    pub fn new(field: &str) -> Self {
+        Self {
+            field: field.to_string(),
+        }
+    }
+}
+
+/// Convenience builder function to create a new XOffset encoding.
+///
+/// ### Example
+/// ```rust,ignore
+/// chart.mark_point()?
+///     .encode((alt::x("dose"), alt::y("response"), alt::x_offset("trial"), alt::color("trial")))?
+/// ```
+pub fn x_offset(field: &str) -> XOffset {
+    XOffset::new(field)
+}
@@ -26,6 +26,16 @@ impl From<&str> for StackMode {
     }
 }
 
+/// The side of the panel whose y axis a layer is scaled against.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum YAxisSide {
+    /// The primary y axis on the left (the default).
+    #[default]
+    Left,
+    /// The secondary y axis on the right, with its own domain and ticks.
+    Right,
+}
+
 /// Represents a Y-axis encoding specification for chart elements.
 ///
 /// Following the Grammar of Graphics, the `Y` struct separates the
@@ -55,6 +65,9 @@ pub struct Y {
     /// If set, this takes absolute priority over automatic data inference.
     pub(crate) domain: Option<ScaleDomain>,
 
+    /// The logarithm base used when the scale is `Scale::Log`. Defaults to 10.
+    pub(crate) log_base: Option<f64>,
+
     /// Rules for adding padding or buffer to the top and bottom of the axis.
     pub(crate) expansion: Option<Expansion>,
 
@@ -70,6 +83,9 @@ pub struct Y {
     // Per X-position (each vertical slice sums to 1.0)
     pub(crate) stack: StackMode,
 
+    /// Whether this layer is scaled against the secondary (right-hand) y axis.
+    pub(crate) secondary: bool,
+
     // --- System Resolution (Result/Outputs) ---
     /// Stores the resolved scale instance. Using RwLock to support
     /// back-filling updates across multiple render calls.
@@ -84,11 +100,13 @@ impl Y {
             aggregate: AggregateOp::default(), // Defaults to Sum
             scale_type: None,
             domain: None,
+            log_base: None,
             expansion: None,
             zero: None,
             bins: None,
             normalize: false, // Default to false (raw counts)
             stack: StackMode::None,
+            secondary: false,
             resolved_scale: ResolvedScale::none(),
         }
     }
@@ -121,6 +139,15 @@ impl Y {
         self
     }
 
+    /// Uses a logarithmic scale with the given base (e.g. 2.0 or `std::f64::consts::E`).
+    ///
+    /// Ticks are placed at integer powers of the base. The base must be greater than 1.
+    pub const fn with_log_base(mut self, base: f64) -> Self {
+        self.scale_type = Some(Scale::Log);
+        self.log_base = Some(base);
+        self
+    }
+
     /// Configures the expansion padding for the axis.
     pub const fn with_expansion(mut self, expansion: Expansion) -> Self {
         self.expansion = Some(expansion);
@@ -182,6 +209,33 @@ impl Y {
         self.stack = stack.into();
         self
     }
+
+    /// Stacks the layers to 100%: every x position sums to 1 and the axis
+    /// reads 0% to 100%.
+    ///
+    /// Shorthand for `with_stack(StackMode::Normalize)`, e.g. for a
+    /// percent-stacked area chart colored by series.
+    pub const fn stack_normalize(mut self) -> Self {
+        self.stack = StackMode::Normalize;
+        self
+    }
+
+    /// Scales this layer against a secondary y axis on the right of the panel.
+    ///
+    /// In a layered chart, the layers using the secondary axis get their own y
+    /// domain and ticks, independent of the other layers. Use
+    /// `with_y2_label` and `with_y2_domain` on the chart to configure the axis.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// let bars = chart!(month, revenue)?.mark_bar()?.encode((x("month"), y("revenue")))?;
+    /// let line = chart!(month, rate)?.mark_line()?.encode((x("month"), y("rate").axis_secondary()))?;
+    /// bars.and(line).with_y2_label("conversion rate")
+    /// ```
+    pub const fn axis_secondary(mut self) -> Self {
+        self.secondary = true;
+        self
+    }
 }
 
 /// Convenience builder function to create a new Y encoding.
@@ -0,0 +1,33 @@
+/// Represents a vertical dodge encoding specification.
+///
+/// The vertical counterpart of `XOffset`: each unique value of the column
+/// gets its own lane around the Y position, which spreads groups of points
+/// sharing a category on a discrete Y-axis. Lanes follow the column's
+/// category order and are spaced by the mark's `with_dodge_width`. Only point
+/// marks support it.
+#[derive(Clone, Debug)]
+pub struct YOffset {
+    // --- User Configuration (Intent/Inputs) ---
+    /// The name of the data column holding the groups.
+    pub(crate) field: String,
+}
+
+impl YOffset {
+    /// Creates a new vertical dodge encoding for a specific data field.
+    pub fn new(field: &str) -> Self {
+        Self {
+            field: field.to_string(),
+        }
+    }
+}
+
+/// Convenience builder function to create a new YOffset encoding.
+///
+/// ### Example
+/// ```rust,ignore
+/// chart.mark_point()?
+///     .encode((alt::x("score"), alt::y("team"), alt::y_offset("season")))?
+/// ```
+pub fn y_offset(field: &str) -> YOffset {
+    YOffset::new(field)
+}
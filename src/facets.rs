@@ -2,7 +2,7 @@ pub mod engine;
 pub mod facet_grid;
 pub mod facet_wrap;
 
-pub use engine::{FacetCell, FacetInfo, FacetLayout, FacetStrategy};
+pub use engine::{FacetCell, FacetInfo, FacetLayout, FacetStrategy, FacetStrip};
 pub use facet_grid::FacetGrid;
 pub use facet_wrap::FacetWrap;
 
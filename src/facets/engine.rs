@@ -18,6 +18,8 @@ pub struct FacetInfo {
     pub total_cols: usize,
     /// The display label (e.g., "Year: 2023").
     pub label: String,
+    /// The values of the facet fields shown in the panel, in field order.
+    pub values: Vec<String>,
 }
 
 /// A resolved facet cell containing its physical bounds.
@@ -29,8 +31,17 @@ pub struct FacetCell {
     pub info: FacetInfo,
 }
 
+/// A header strip labelling a panel, or a whole row or column of panels.
+pub struct FacetStrip {
+    pub rect: Rect,
+    pub label: String,
+    /// Row strips of a grid stand on the side and read top to bottom.
+    pub vertical: bool,
+}
+
 /// The physical layout result of any faceting operation.
 /// This is what the Renderer consumes.
 pub struct FacetLayout {
     pub cells: Vec<FacetCell>,
+    pub strips: Vec<FacetStrip>,
 }
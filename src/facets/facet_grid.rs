@@ -1,8 +1,12 @@
 use crate::coordinate::Rect;
-use crate::facets::{Facet, FacetCell, FacetInfo, FacetLayout, FacetStrategy};
+use crate::facets::{Facet, FacetCell, FacetInfo, FacetLayout, FacetStrategy, FacetStrip};
 use crate::theme::Theme;
 
 /// FacetGrid partitions data by two variables into a strict Row x Column matrix.
+///
+/// Column values head the top row of panels; row values label the right edge
+/// of every row, reading top to bottom.
+#[derive(Debug, Clone)]
 pub struct FacetGrid {
     pub row_field: String,
     pub col_field: String,
@@ -32,29 +36,50 @@ impl Facet for FacetGrid {
         let header_h = theme.facet_label_size * 1.5;
         let gap = theme.facet_spacing;
 
-        let panel_w = (container.width - (n_cols - 1) as f64 * gap) / n_cols as f64;
-        let panel_h = (container.height - (n_rows - 1) as f64 * gap - n_rows as f64 * header_h)
-            / n_rows as f64;
+        // One strip of headers runs along the top, another down the right edge.
+        let panel_w = (container.width - (n_cols - 1) as f64 * gap - header_h) / n_cols as f64;
+        let panel_h = (container.height - (n_rows - 1) as f64 * gap - header_h) / n_rows as f64;
+        let strip_x = container.x + n_cols as f64 * panel_w + (n_cols - 1) as f64 * gap;
 
         let mut cells = Vec::new();
+        let mut strips = Vec::new();
+        for (c_idx, c_val) in cols_vals.iter().enumerate() {
+            strips.push(FacetStrip {
+                rect: Rect::new(
+                    container.x + c_idx as f64 * (panel_w + gap),
+                    container.y,
+                    panel_w,
+                    header_h,
+                ),
+                label: c_val.clone(),
+                vertical: false,
+            });
+        }
         for (r_idx, r_val) in rows_vals.iter().enumerate() {
+            let y = container.y + header_h + r_idx as f64 * (panel_h + gap);
+            strips.push(FacetStrip {
+                rect: Rect::new(strip_x, y, header_h, panel_h),
+                label: r_val.clone(),
+                vertical: true,
+            });
+
             for (c_idx, c_val) in cols_vals.iter().enumerate() {
                 let x = container.x + c_idx as f64 * (panel_w + gap);
-                let header_y = container.y + r_idx as f64 * (panel_h + header_h + gap);
 
                 cells.push(FacetCell {
-                    rect: Rect::new(x, header_y + header_h, panel_w, panel_h),
-                    header_rect: Rect::new(x, header_y, panel_w, header_h),
+                    rect: Rect::new(x, y, panel_w, panel_h),
+                    header_rect: Rect::new(x, container.y, panel_w, header_h),
                     info: FacetInfo {
                         row: r_idx,
                         col: c_idx,
                         total_rows: n_rows,
                         total_cols: n_cols,
-                        label: format!("{} | {}", r_val, c_val), // Combined label for now
+                        label: format!("{} | {}", r_val, c_val),
+                        values: vec![r_val.clone(), c_val.clone()],
                     },
                 });
             }
         }
-        FacetLayout { cells }
+        FacetLayout { cells, strips }
     }
 }
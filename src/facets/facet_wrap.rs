@@ -1,8 +1,9 @@
 use crate::coordinate::Rect;
-use crate::facets::{Facet, FacetCell, FacetInfo, FacetLayout, FacetStrategy};
+use crate::facets::{Facet, FacetCell, FacetInfo, FacetLayout, FacetStrategy, FacetStrip};
 use crate::theme::Theme;
 
 /// FacetWrap partitions data by a single variable and wraps panels into a 2D grid.
+#[derive(Debug, Clone)]
 pub struct FacetWrap {
     pub field: String,
     pub strategy: FacetStrategy,
@@ -47,6 +48,7 @@ impl Facet for FacetWrap {
             (container.height - (rows - 1) as f64 * gap - rows as f64 * header_h) / rows as f64;
 
         let mut cells = Vec::new();
+        let mut strips = Vec::new();
         for (idx, val) in values.iter().enumerate() {
             let r = idx / cols;
             let c = idx % cols;
@@ -55,6 +57,11 @@ impl Facet for FacetWrap {
             let header_y = container.y + r as f64 * (panel_h + header_h + gap);
             let plot_y = header_y + header_h;
 
+            strips.push(FacetStrip {
+                rect: Rect::new(x, header_y, panel_w, header_h),
+                label: val.clone(),
+                vertical: false,
+            });
             cells.push(FacetCell {
                 rect: Rect::new(x, plot_y, panel_w, panel_h),
                 header_rect: Rect::new(x, header_y, panel_w, header_h),
@@ -64,9 +71,10 @@ impl Facet for FacetWrap {
                     total_rows: rows,
                     total_cols: cols,
                     label: val.clone(),
+                    values: vec![val.clone()],
                 },
             });
         }
-        FacetLayout { cells }
+        FacetLayout { cells, strips }
     }
 }
@@ -38,13 +38,26 @@ pub use arrow;
 pub mod macros;
 
 pub mod alt {
+    pub use crate::encode::angle::angle;
+    pub use crate::encode::close::close;
     pub use crate::encode::color::color;
+    pub use crate::encode::detail::detail;
+    pub use crate::encode::fill_opacity::fill_opacity;
+    pub use crate::encode::high::high;
+    pub use crate::encode::href::href;
+    pub use crate::encode::low::low;
+    pub use crate::encode::opacity::opacity;
+    pub use crate::encode::open::open;
     pub use crate::encode::path_group::path_group;
     pub use crate::encode::shape::shape;
     pub use crate::encode::size::size;
+    pub use crate::encode::stroke_opacity::stroke_opacity;
     pub use crate::encode::text::text;
+    pub use crate::encode::tooltip::{tooltip, tooltip_multi};
     pub use crate::encode::x::x;
+    pub use crate::encode::x_offset::x_offset;
     pub use crate::encode::y::y;
+    pub use crate::encode::y_offset::y_offset;
     pub use crate::encode::y2::y2;
 }
 
@@ -53,29 +66,65 @@ pub mod prelude {
     pub use crate::chart::Chart;
     pub use crate::coordinate::CoordSystem;
     pub use crate::coordinate::geo::GeoProjection;
+    pub use crate::core::annotation::Annotation;
+    pub use crate::core::chart_grid::ChartGrid;
     pub use crate::core::composite::LayeredChart;
     pub use crate::core::conversion::IntoLayered;
-    pub use crate::core::data::{ColumnVector, Dataset, IntoColumn, ToDataset};
-    pub use crate::datasets::load_dataset;
+    pub use crate::core::data::{AggregateOp, ColumnVector, Dataset, IntoColumn, ToDataset};
+    pub use crate::core::inset::{InsetAnchor, InsetSpec};
+    pub use crate::core::reference_line::ReferenceLine;
+    pub use crate::core::shared_scales::{SharedScale, SharedScales};
+    pub use crate::datasets::{
+        DataFormat, DatasetSource, list_datasets, load_dataset, register_dataset,
+    };
+    pub use crate::encode::y::YAxisSide;
     pub use crate::mark::{
-        area::MarkArea, bar::MarkBar, boxplot::MarkBoxplot, errorbar::MarkErrorBar,
-        geo_path::MarkGeoPath, line::MarkLine, point::MarkPoint, rect::MarkRect, rule::MarkRule,
-        text::MarkText, tick::MarkTick,
+        area::MarkArea,
+        bar::MarkBar,
+        boxplot::MarkBoxplot,
+        candlestick::MarkCandlestick,
+        dotplot::{MarkDotPlot, SortOrder},
+        dots::{DotsLayout, MarkDots},
+        errorbar::MarkErrorBar,
+        geo_path::MarkGeoPath,
+        histogram::CumulativeDirection,
+        line::MarkLine,
+        point::MarkPoint,
+        rect::MarkRect,
+        rule::MarkRule,
+        stack_label::StackLabelPart,
+        text::{FontStyle, FontWeight, MarkText},
+        tick::MarkTick,
+        violin::MarkViolin,
+        zero_line::ZeroLinePosition,
     };
     pub use crate::render::line_renderer::PathInterpolation;
-    pub use crate::scale::{Expansion, Scale};
-    pub use crate::theme::Theme;
+    pub use crate::scale::{
+        Expansion, Scale,
+        inverse::{InverseTransform, TickFormatter},
+    };
+    pub use crate::theme::{TextDirection, TextOrientation, Theme, TickFormat};
     pub use crate::transform::{
+        aggregate_transform::{AggregateFieldDef, AggregateTransform},
+        bin_transform::BinTransform,
         density_transform::{BandwidthType, DensityTransform, KernelType},
+        index_transform::{IndexBase, IndexTransform},
+        loess_transform::LoessTransform,
+        lookup_transform::LookupTransform,
+        quantile_transform::QuantileTransform,
+        regression_transform::{R_SQUARED_FIELD, RegressionMethod, RegressionTransform},
+        resample_transform::{Period, ResampleTransform},
         window_transform::{WindowFieldDef, WindowOnlyOp, WindowTransform},
     };
-    pub use crate::visual::color::{ColorMap, ColorPalette, SingleColor};
+    pub use crate::visual::color::{
+        ColorGradient, ColorMap, ColorPalette, PaletteOverflow, SingleColor,
+    };
     pub use crate::visual::shape::PointShape;
     pub use crate::{chart, load_polars_df, load_polars_v44_52};
     pub use time as ctime;
 
     #[cfg(all(feature = "bridge", not(target_arch = "wasm32")))]
-    pub use crate::bridge::base::{Altair, Matplotlib, Plot, Visualization};
+    pub use crate::bridge::base::{Altair, Matplotlib, Plot, Plotly, Visualization};
     #[cfg(feature = "geo")]
     pub use crate::core::utils::geojson_to_dataset;
     #[cfg(all(feature = "bridge", not(target_arch = "wasm32")))]
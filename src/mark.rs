@@ -1,6 +1,9 @@
 pub(crate) mod area;
 pub(crate) mod bar;
 pub(crate) mod boxplot;
+pub(crate) mod candlestick;
+pub(crate) mod dotplot;
+pub(crate) mod dots;
 pub(crate) mod errorbar;
 pub(crate) mod geo_path;
 pub(crate) mod histogram;
@@ -9,8 +12,11 @@ pub(crate) mod no_mark;
 pub(crate) mod point;
 pub(crate) mod rect;
 pub(crate) mod rule;
+pub(crate) mod stack_label;
 pub(crate) mod text;
 pub(crate) mod tick;
+pub(crate) mod violin;
+pub(crate) mod zero_line;
 
 use crate::prelude::SingleColor;
 /// A trait representing a visual mark in a plot.
@@ -1,4 +1,6 @@
 use crate::mark::Mark;
+use crate::mark::stack_label::StackLabelPart;
+use crate::mark::zero_line::{ZeroLine, ZeroLinePosition};
 use crate::visual::color::SingleColor;
 
 /// Mark type for area charts.
@@ -6,6 +8,10 @@ use crate::visual::color::SingleColor;
 /// The `MarkArea` struct defines the visual properties of filled area elements.
 /// It supports a fluent interface for configuring fill color, opacity, and
 /// stroke properties of the area boundary.
+///
+/// When a `y2` encoding is present the area becomes a difference chart: the gap
+/// between the `y` and `y2` series is filled with `positive_color` where `y` is
+/// above `y2` and with `negative_color` where it is below.
 #[derive(Clone, Debug)]
 pub struct MarkArea {
     pub(crate) color: SingleColor,
@@ -13,6 +19,18 @@ pub struct MarkArea {
     pub(crate) stroke: SingleColor,
     pub(crate) stroke_width: f64,
     pub(crate) dash: Vec<f64>,
+
+    /// Fill of the regions where `y` is above `y2` (difference areas only).
+    pub(crate) positive_color: SingleColor,
+    /// Fill of the regions where `y` is below `y2` (difference areas only).
+    pub(crate) negative_color: SingleColor,
+
+    /// Reference line at zero on the value axis (unstacked areas only).
+    pub(crate) zero_line: ZeroLine,
+
+    /// Parts of the label drawn inside each band of a stacked area.
+    /// Empty means no labels.
+    pub(crate) stack_label_parts: Vec<StackLabelPart>,
 }
 
 impl MarkArea {
@@ -24,6 +42,10 @@ impl MarkArea {
             stroke: SingleColor::new("none"),
             stroke_width: 1.0,
             dash: vec![],
+            positive_color: SingleColor::new("#2ca02c"),
+            negative_color: SingleColor::new("#d62728"),
+            zero_line: ZeroLine::new(true, ZeroLinePosition::Under),
+            stack_label_parts: Vec::new(),
         }
     }
 
@@ -69,6 +91,62 @@ impl MarkArea {
         self.dash = dash.into();
         self
     }
+
+    /// Sets the fill used where `y` is above `y2` when the area is drawn between
+    /// two series (i.e. a `y2` encoding is present).
+    pub fn with_positive_color(mut self, color: impl Into<SingleColor>) -> Self {
+        self.positive_color = color.into();
+        self
+    }
+
+    /// Sets the fill used where `y` is below `y2` when the area is drawn between
+    /// two series (i.e. a `y2` encoding is present).
+    pub fn with_negative_color(mut self, color: impl Into<SingleColor>) -> Self {
+        self.negative_color = color.into();
+        self
+    }
+
+    /// Labels every band of a stacked area with the given parts, in order.
+    ///
+    /// Each band is labeled once, where it is thickest, with the values at
+    /// that x. Numbers follow the value axis formatter or the value field's
+    /// format, or else the notation of the value axis ticks. Bands too thin
+    /// to hold a line of text are left unlabeled. Has no effect on unstacked
+    /// areas.
+    pub fn with_stack_label_parts(mut self, parts: &[StackLabelPart]) -> Self {
+        self.stack_label_parts = parts.to_vec();
+        self
+    }
+
+    /// Shows or hides the reference line at zero on the value axis (shown by default).
+    ///
+    /// This only affects the line; use `with_zero` on the encoding to control
+    /// whether the value domain includes zero.
+    pub const fn with_zero_line(mut self, show: bool) -> Self {
+        self.zero_line.visible = show;
+        self
+    }
+
+    /// Sets the color, width and dash pattern (`vec![]` for solid) of the zero line.
+    ///
+    /// The line is drawn in the theme's axis color unless a color is set here.
+    pub fn with_zero_line_style(
+        mut self,
+        color: impl Into<SingleColor>,
+        width: f64,
+        dash: impl Into<Vec<f64>>,
+    ) -> Self {
+        self.zero_line.color = Some(color.into());
+        self.zero_line.width = width;
+        self.zero_line.dash = dash.into();
+        self
+    }
+
+    /// Draws the zero line beneath or on top of the area.
+    pub const fn with_zero_line_position(mut self, position: ZeroLinePosition) -> Self {
+        self.zero_line.position = position;
+        self
+    }
 }
 
 impl Default for MarkArea {
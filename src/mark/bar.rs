@@ -1,4 +1,7 @@
+use crate::core::data::AggregateOp;
 use crate::mark::Mark;
+use crate::mark::stack_label::StackLabelPart;
+use crate::mark::zero_line::{ZeroLine, ZeroLinePosition};
 use crate::visual::color::SingleColor;
 
 /// Mark type for bar charts.
@@ -24,6 +27,17 @@ pub struct MarkBar {
     /// Dimensional override for the total span of a bar group.
     /// If None, the coordinate system's `default_bar_span` is used.
     pub(crate) span: Option<f64>,
+
+    /// Parts of the label drawn inside each segment of a stacked bar.
+    /// Empty means no labels.
+    pub(crate) stack_label_parts: Vec<StackLabelPart>,
+
+    /// Reference line at zero on the value axis.
+    pub(crate) zero_line: ZeroLine,
+
+    /// How a pie merges rows sharing a category. `None` (unset) defers to the
+    /// y encoding's aggregate; `Some(None)` keeps one slice per row.
+    pub(crate) arc_aggregate: Option<Option<AggregateOp>>,
 }
 
 impl MarkBar {
@@ -40,6 +54,9 @@ impl MarkBar {
             width: None, // The maximal percentage of a bar's width relative to the tick interval. Defer to CoordLayout
             spacing: None, // The percentage of the space between bars within a group reltative to the bar width. Defer to CoordLayout
             span: None,    // The (width+spacing) of all bars in a group. Defer to CoordLayout
+            stack_label_parts: Vec::new(),
+            zero_line: ZeroLine::new(false, ZeroLinePosition::Over),
+            arc_aggregate: None,
         }
     }
 
@@ -88,6 +105,70 @@ impl MarkBar {
         self.span = Some(span.clamp(0.0, 1.0));
         self
     }
+
+    /// Labels every segment of a stacked bar with the given parts, in order.
+    ///
+    /// Numbers follow the value axis formatter or the value field's format
+    /// (see `with_field_format`), or else the notation of the value axis ticks.
+    /// Segments too thin to hold a line of text are left unlabeled. The parts
+    /// are also listed in the tooltips of stacked bars with a tooltip
+    /// encoding, which otherwise show the segment, cumulative and total
+    /// values. Has no effect on unstacked bars.
+    ///
+    /// ```rust,ignore
+    /// mark.with_stack_label_parts(&[StackLabelPart::Segment, StackLabelPart::Percent])
+    /// ```
+    pub fn with_stack_label_parts(mut self, parts: &[StackLabelPart]) -> Self {
+        self.stack_label_parts = parts.to_vec();
+        self
+    }
+
+    /// Sets how a pie chart merges rows that share a category.
+    ///
+    /// By default repeated categories are merged into one slice with the y
+    /// encoding's aggregate (sum unless set with `with_aggregate`). `Some(op)`
+    /// merges them with `op` instead, while `None` draws every row as its own
+    /// slice, in data order, e.g. for sequential ring segments. Has no effect
+    /// outside of pie charts.
+    ///
+    /// ```rust,ignore
+    /// mark.with_arc_aggregate(None)
+    /// mark.with_arc_aggregate(Some(AggregateOp::Mean))
+    /// ```
+    pub const fn with_arc_aggregate(mut self, op: Option<AggregateOp>) -> Self {
+        self.arc_aggregate = Some(op);
+        self
+    }
+
+    /// Shows or hides the reference line at zero on the value axis (hidden by default).
+    ///
+    /// This only affects the line; use `with_zero` on the encoding to control
+    /// whether the value domain includes zero.
+    pub const fn with_zero_line(mut self, show: bool) -> Self {
+        self.zero_line.visible = show;
+        self
+    }
+
+    /// Sets the color, width and dash pattern (`vec![]` for solid) of the zero line.
+    ///
+    /// The line is drawn in the theme's axis color unless a color is set here.
+    pub fn with_zero_line_style(
+        mut self,
+        color: impl Into<SingleColor>,
+        width: f64,
+        dash: impl Into<Vec<f64>>,
+    ) -> Self {
+        self.zero_line.color = Some(color.into());
+        self.zero_line.width = width;
+        self.zero_line.dash = dash.into();
+        self
+    }
+
+    /// Draws the zero line beneath or on top of the bars.
+    pub const fn with_zero_line_position(mut self, position: ZeroLinePosition) -> Self {
+        self.zero_line.position = position;
+        self
+    }
 }
 
 impl Default for MarkBar {
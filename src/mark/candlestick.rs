@@ -0,0 +1,77 @@
+use crate::mark::Mark;
+use crate::visual::color::SingleColor;
+
+/// Mark type for candlestick (OHLC) charts.
+///
+/// Each row is drawn as a body spanning the open and close prices and a wick
+/// spanning the low and high prices. Rising periods (close above open) use the
+/// bull color, falling ones the bear color.
+#[derive(Debug, Clone)]
+pub struct MarkCandlestick {
+    pub(crate) bull_color: SingleColor,
+    pub(crate) bear_color: SingleColor,
+    pub(crate) opacity: f64,
+    pub(crate) stroke_width: f64,
+    /// Body width relative to the distance between neighbouring candles.
+    pub(crate) width: f64,
+}
+
+impl MarkCandlestick {
+    pub(crate) fn new() -> Self {
+        Self {
+            bull_color: SingleColor::new("#26a69a"),
+            bear_color: SingleColor::new("#ef5350"),
+            opacity: 1.0,
+            stroke_width: 1.0,
+            width: 0.7,
+        }
+    }
+
+    // --- Fluent Configuration Methods (Builder Pattern) ---
+
+    /// Sets the color of candles that close above their open price.
+    pub fn with_bull_color(mut self, color: impl Into<SingleColor>) -> Self {
+        self.bull_color = color.into();
+        self
+    }
+
+    /// Sets the color of candles that close below their open price.
+    pub fn with_bear_color(mut self, color: impl Into<SingleColor>) -> Self {
+        self.bear_color = color.into();
+        self
+    }
+
+    /// Sets the opacity of the candle bodies and wicks.
+    ///
+    /// Value should be between 0.0 (transparent) and 1.0 (opaque).
+    pub const fn with_opacity(mut self, opacity: f64) -> Self {
+        self.opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the thickness of the wick and body outline.
+    pub const fn with_stroke_width(mut self, width: f64) -> Self {
+        self.stroke_width = width;
+        self
+    }
+
+    /// Sets the body width relative to the distance between neighbouring candles.
+    ///
+    /// Value is clamped between 0.0 and 1.0.
+    pub const fn with_candle_width(mut self, width: f64) -> Self {
+        self.width = width.clamp(0.0, 1.0);
+        self
+    }
+}
+
+impl Default for MarkCandlestick {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Mark for MarkCandlestick {
+    fn mark_type(&self) -> &'static str {
+        "candlestick"
+    }
+}
@@ -0,0 +1,143 @@
+use crate::mark::Mark;
+use crate::visual::color::SingleColor;
+
+/// Ordering applied to the categories of a dot plot based on their values.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum SortOrder {
+    /// Categories keep their order of first appearance in the data.
+    #[default]
+    None,
+    /// Categories are ordered from the smallest to the largest value.
+    Ascending,
+    /// Categories are ordered from the largest to the smallest value.
+    Descending,
+}
+
+/// Implements conversion from string slices for a more ergonomic Fluent API.
+impl From<&str> for SortOrder {
+    fn from(s: &str) -> Self {
+        match s.to_lowercase().as_str() {
+            "ascending" | "asc" => SortOrder::Ascending,
+            "descending" | "desc" => SortOrder::Descending,
+            _ => SortOrder::None,
+        }
+    }
+}
+
+/// Mark type for Cleveland dot plots.
+///
+/// A dot plot draws one dot per category on a continuous axis, connected to the
+/// baseline by a light guide line. If a `y2` encoding is present, the guide line
+/// spans from `y2` to `y` and a dot is drawn at both ends (range dot plot).
+///
+/// Categories are placed on the X axis; use `coord_flip()` to lay them out
+/// vertically, which is the classic Cleveland orientation.
+#[derive(Debug, Clone)]
+pub struct MarkDotPlot {
+    pub(crate) color: SingleColor,
+    pub(crate) size: f64,
+    pub(crate) opacity: f64,
+    pub(crate) stroke: SingleColor,
+    pub(crate) stroke_width: f64,
+
+    // --- Guide line ---
+    pub(crate) line_color: SingleColor,
+    pub(crate) line_width: f64,
+    pub(crate) line_dash: Vec<f64>,
+
+    // --- Category ordering ---
+    pub(crate) sort: SortOrder,
+}
+
+impl MarkDotPlot {
+    pub(crate) fn new() -> Self {
+        Self {
+            color: SingleColor::new("black"),
+            size: 4.0,
+            opacity: 1.0,
+            stroke: SingleColor::new("none"),
+            stroke_width: 0.0,
+            line_color: SingleColor::new("#BBBBBB"),
+            line_width: 1.0,
+            line_dash: vec![],
+            sort: SortOrder::None,
+        }
+    }
+
+    // --- Fluent Configuration Methods (Builder Pattern) ---
+
+    /// Sets the fill color of the dots. Accepts "red", "#hex", etc.
+    pub fn with_color(mut self, color: impl Into<SingleColor>) -> Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Sets the radius of the dots in pixels.
+    pub const fn with_size(mut self, size: f64) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the opacity of the dots.
+    ///
+    /// Value should be between 0.0 (transparent) and 1.0 (opaque).
+    pub const fn with_opacity(mut self, opacity: f64) -> Self {
+        self.opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the outline color of the dots. Use "none" to disable.
+    pub fn with_stroke(mut self, stroke: impl Into<SingleColor>) -> Self {
+        self.stroke = stroke.into();
+        self
+    }
+
+    /// Sets the thickness of the dot outline.
+    pub const fn with_stroke_width(mut self, width: f64) -> Self {
+        self.stroke_width = width;
+        self
+    }
+
+    /// Sets the color of the guide lines. Use "none" to hide them.
+    pub fn with_line_color(mut self, color: impl Into<SingleColor>) -> Self {
+        self.line_color = color.into();
+        self
+    }
+
+    /// Sets the thickness of the guide lines.
+    pub const fn with_line_width(mut self, width: f64) -> Self {
+        self.line_width = width;
+        self
+    }
+
+    /// Sets the dash pattern of the guide lines, e.g. `vec![4.0, 2.0]`.
+    /// An empty vector draws solid lines.
+    pub fn with_line_dash(mut self, dash: Vec<f64>) -> Self {
+        self.line_dash = dash;
+        self
+    }
+
+    /// Orders the categories by their value.
+    ///
+    /// Categories are laid out along the axis direction, so under `coord_flip()`
+    /// the first category sits at the bottom: use `Ascending` to put the largest
+    /// value on top.
+    ///
+    /// Accepts `SortOrder` variants or string literals like "descending".
+    pub fn with_sort(mut self, sort: impl Into<SortOrder>) -> Self {
+        self.sort = sort.into();
+        self
+    }
+}
+
+impl Default for MarkDotPlot {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Mark for MarkDotPlot {
+    fn mark_type(&self) -> &'static str {
+        "dotplot"
+    }
+}
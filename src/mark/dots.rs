@@ -0,0 +1,115 @@
+use crate::mark::Mark;
+use crate::mark::histogram::HistBinning;
+use crate::visual::color::SingleColor;
+
+/// Arrangement of color groups that share a bin in a dot histogram.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum DotsLayout {
+    /// Groups are stacked on top of each other in a single column per bin.
+    #[default]
+    Stack,
+    /// Each group gets its own column, placed side by side within the bin.
+    Dodge,
+}
+
+/// Implements conversion from string slices for a more ergonomic Fluent API.
+impl From<&str> for DotsLayout {
+    fn from(s: &str) -> Self {
+        match s.to_lowercase().as_str() {
+            "dodge" | "side" => DotsLayout::Dodge,
+            _ => DotsLayout::Stack,
+        }
+    }
+}
+
+/// Mark type for Wilkinson dot histograms.
+///
+/// Each observation is drawn as one dot, stacked vertically within its bin.
+/// Binning follows the histogram options (`alt::x(..).with_bins(n)` or the
+/// chart's `with_bin_count`, `with_bin_width` and `with_bin_range`), and the
+/// Y encoding names the generated count column, exactly like `mark_hist`.
+///
+/// Unless a fixed size is given, the dot radius is derived from the panel so
+/// that the tallest stack fits and neighbouring bins don't overlap.
+#[derive(Debug, Clone)]
+pub struct MarkDots {
+    pub(crate) color: SingleColor,
+    pub(crate) size: Option<f64>,
+    pub(crate) opacity: f64,
+    pub(crate) stroke: SingleColor,
+    pub(crate) stroke_width: f64,
+    pub(crate) layout: DotsLayout,
+    /// Explicit bin count or width. If `None`, the count is chosen from the data.
+    pub(crate) binning: Option<HistBinning>,
+    /// Explicit `(min, max)` extent of the bins. If `None`, the data range is used.
+    pub(crate) bin_range: Option<(f64, f64)>,
+}
+
+impl MarkDots {
+    pub(crate) fn new() -> Self {
+        Self {
+            color: SingleColor::new("black"),
+            size: None,
+            opacity: 1.0,
+            stroke: SingleColor::new("white"),
+            stroke_width: 0.5,
+            layout: DotsLayout::Stack,
+            binning: None,
+            bin_range: None,
+        }
+    }
+
+    // --- Fluent Configuration Methods (Builder Pattern) ---
+
+    /// Sets the fill color of the dots. Accepts "red", "#hex", etc.
+    pub fn with_color(mut self, color: impl Into<SingleColor>) -> Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Fixes the dot radius in pixels, disabling automatic sizing.
+    pub const fn with_size(mut self, size: f64) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the opacity of the dots.
+    ///
+    /// Value should be between 0.0 (transparent) and 1.0 (opaque).
+    pub const fn with_opacity(mut self, opacity: f64) -> Self {
+        self.opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the outline color of the dots. Use "none" to disable.
+    pub fn with_stroke(mut self, stroke: impl Into<SingleColor>) -> Self {
+        self.stroke = stroke.into();
+        self
+    }
+
+    /// Sets the thickness of the dot outline.
+    pub const fn with_stroke_width(mut self, width: f64) -> Self {
+        self.stroke_width = width;
+        self
+    }
+
+    /// Sets how color groups share a bin.
+    ///
+    /// Accepts `DotsLayout` variants or string literals like "dodge".
+    pub fn with_layout(mut self, layout: impl Into<DotsLayout>) -> Self {
+        self.layout = layout.into();
+        self
+    }
+}
+
+impl Default for MarkDots {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Mark for MarkDots {
+    fn mark_type(&self) -> &'static str {
+        "dots"
+    }
+}
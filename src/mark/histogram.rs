@@ -1,6 +1,25 @@
 use crate::mark::Mark;
 use crate::visual::color::SingleColor;
 
+/// How the bins of a histogram are chosen when the user sets them explicitly.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum HistBinning {
+    /// A fixed number of equally wide bins.
+    Count(usize),
+    /// Bins of a fixed width, starting at the lower end of the binning range.
+    Width(f64),
+}
+
+/// The direction in which a cumulative histogram accumulates its counts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CumulativeDirection {
+    /// Each bin holds the count of values up to and including it.
+    #[default]
+    Asc,
+    /// Each bin holds the count of values from it upwards, as in survival plots.
+    Desc,
+}
+
 /// Mark type for histogram charts.
 ///
 /// The `MarkHist` struct defines the visual properties of bars representing binned data.
@@ -12,6 +31,12 @@ pub struct MarkHist {
     pub(crate) opacity: f64,
     pub(crate) stroke: SingleColor,
     pub(crate) stroke_width: f64,
+    /// Explicit bin count or width. If `None`, the count is chosen from the data.
+    pub(crate) binning: Option<HistBinning>,
+    /// Explicit `(min, max)` extent of the bins. If `None`, the data range is used.
+    pub(crate) bin_range: Option<(f64, f64)>,
+    /// Accumulates the bin counts in the given direction. If `None`, bins are counted independently.
+    pub(crate) cumulative: Option<CumulativeDirection>,
 }
 
 impl MarkHist {
@@ -22,6 +47,9 @@ impl MarkHist {
             opacity: 1.0,
             stroke: SingleColor::new("black"),
             stroke_width: 0.0,
+            binning: None,
+            bin_range: None,
+            cumulative: None,
         }
     }
 
@@ -39,6 +39,12 @@ pub struct MarkPoint {
     pub(crate) stroke: SingleColor,
     pub(crate) stroke_width: f64,
 
+    // --- Automatic transparency ---
+    /// Derives the opacity from how densely the points overlap on the panel.
+    pub(crate) auto_alpha: bool,
+    /// Lower and upper bounds for the automatically chosen opacity.
+    pub(crate) auto_alpha_range: (f64, f64),
+
     // --- Layout strategy ---
     /// The physical arrangement strategy (Standard, Jitter, or Beeswarm).
     pub(crate) layout: PointLayout,
@@ -51,6 +57,14 @@ pub struct MarkPoint {
     pub(crate) spacing: f64,
     /// Total width of all groups combined at the axis position.
     pub(crate) span: f64,
+    /// Distance between the centers of neighboring lanes, overriding the
+    /// width/spacing/span layout.
+    pub(crate) dodge_width: Option<f64>,
+    /// Fraction of the category band (or dodge lane) jittered points spread across.
+    pub(crate) jitter_width: Option<f64>,
+
+    /// Whether each point carries a hover tooltip of its encoded fields.
+    pub(crate) tooltip: bool,
 }
 
 impl MarkPoint {
@@ -62,10 +76,15 @@ impl MarkPoint {
             opacity: 1.0,
             stroke: SingleColor::new("none"),
             stroke_width: 0.0,
+            auto_alpha: false,
+            auto_alpha_range: (0.05, 1.0),
             layout: PointLayout::Standard,
             width: 0.5,
             spacing: 0.2,
             span: 0.7,
+            dodge_width: None,
+            jitter_width: None,
+            tooltip: false,
         }
     }
 
@@ -112,6 +131,24 @@ impl MarkPoint {
         self
     }
 
+    /// Enables density-aware transparency.
+    ///
+    /// The opacity is chosen at render time from the number of points and how much
+    /// they overlap on the panel, so that heavily overplotted regions stay readable.
+    /// The result multiplies the configured opacity instead of replacing it, and
+    /// the chosen value is reported on stderr.
+    pub const fn with_auto_alpha(mut self, auto: bool) -> Self {
+        self.auto_alpha = auto;
+        self
+    }
+
+    /// Sets the range the automatic opacity is clamped to (defaults to 0.05..1.0).
+    pub const fn with_auto_alpha_range(mut self, min: f64, max: f64) -> Self {
+        let min = min.clamp(0.0, 1.0);
+        self.auto_alpha_range = (min, max.clamp(min, 1.0));
+        self
+    }
+
     /// Sets the layout strategy for point marks.
     ///
     /// Accepts `PointLayout` variants or string literals like "jitter".
@@ -137,6 +174,47 @@ impl MarkPoint {
         self.span = span.clamp(0.0, 1.0);
         self
     }
+
+    /// Sets the distance between neighboring dodge lanes, as a fraction of
+    /// the category step (one data unit on continuous axes).
+    ///
+    /// Lanes come from `x_offset`/`y_offset` encodings, or from color on a
+    /// discrete axis. A point in lane `i` of `n` moves by
+    /// `(i - (n - 1) / 2) * dodge_width`, so the lanes stay centered on the
+    /// category: `(i - n / 2) * dodge_width` would shift every lane half a
+    /// step to the left (two lanes at -1 and 0 instead of -0.5 and 0.5),
+    /// unlike dodged bars and box plots. Without it, the lanes share the
+    /// mark's span.
+    pub const fn with_dodge_width(mut self, width: f64) -> Self {
+        self.dodge_width = Some(width.max(0.0));
+        self
+    }
+
+    /// Jitters the points across the given fraction of their category band.
+    ///
+    /// Switches to `PointLayout::Jitter`, e.g. for a strip plot of the raw
+    /// observations over a boxplot. Each point moves along the categorical
+    /// axis by a pseudo-random amount derived from its row, so the output is
+    /// reproducible. The spread is measured in pixels from the band width and
+    /// follows the chart size; when points are dodged by color, it is a
+    /// fraction of their lane instead. Without this setting, jittered points
+    /// spread across the mark width (half the band) or their full lane.
+    pub const fn with_jitter(mut self, width: f64) -> Self {
+        self.layout = PointLayout::Jitter;
+        self.jitter_width = Some(width.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Adds a hover tooltip to every point, listing the row's encoded fields.
+    ///
+    /// In SVG output each point gets a `<title>` child, which browsers show on
+    /// hover without any script. The tooltip lists the x, y, color, shape, size
+    /// and text fields; use a `tooltip` encoding instead to choose the fields
+    /// or their number format. Off by default to keep the output small.
+    pub const fn with_tooltip(mut self, tooltip: bool) -> Self {
+        self.tooltip = tooltip;
+        self
+    }
 }
 
 impl Default for MarkPoint {
@@ -12,6 +12,8 @@ pub struct MarkRect {
     pub(crate) opacity: f64,
     pub(crate) stroke: SingleColor,
     pub(crate) stroke_width: f64,
+    /// Gap in pixels left between neighbouring cells.
+    pub(crate) cell_border: f64,
 }
 
 impl MarkRect {
@@ -22,6 +24,7 @@ impl MarkRect {
             opacity: 1.0,
             stroke: SingleColor::new("white"),
             stroke_width: 0.0,
+            cell_border: 0.0,
         }
     }
 
@@ -52,6 +55,15 @@ impl MarkRect {
         self.stroke_width = width;
         self
     }
+
+    /// Separates neighbouring cells by a border of `width` pixels.
+    ///
+    /// Each cell is shrunk so the background shows through between cells,
+    /// which keeps the grid readable in any theme without the cells overlapping.
+    pub const fn with_cell_border(mut self, width: f64) -> Self {
+        self.cell_border = width.max(0.0);
+        self
+    }
 }
 
 impl Default for MarkRect {
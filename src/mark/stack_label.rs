@@ -0,0 +1,31 @@
+/// A piece of information shown for each segment of a stacked bar or area.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StackLabelPart {
+    /// The value of the segment itself.
+    Segment,
+    /// The running total at the top of the segment (prefixed with "Σ").
+    Cumulative,
+    /// The total of the whole stack (prefixed with "of").
+    Total,
+    /// The share of the segment in its stack, in percent.
+    Percent,
+}
+
+impl StackLabelPart {
+    /// The parts listed in the tooltips of stacked marks unless others are set.
+    pub(crate) const TOOLTIP_DEFAULT: [StackLabelPart; 3] = [
+        StackLabelPart::Segment,
+        StackLabelPart::Cumulative,
+        StackLabelPart::Total,
+    ];
+
+    /// The name of the part in tooltips.
+    pub(crate) const fn name(self) -> &'static str {
+        match self {
+            StackLabelPart::Segment => "Segment",
+            StackLabelPart::Cumulative => "Cumulative",
+            StackLabelPart::Total => "Total",
+            StackLabelPart::Percent => "Percent",
+        }
+    }
+}
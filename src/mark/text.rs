@@ -18,7 +18,7 @@ pub struct MarkText {
 }
 
 /// Font weight options for text elements.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum FontWeight {
     /// Normal font weight (equivalent to 400).
     #[default]
@@ -55,6 +55,39 @@ impl std::fmt::Display for FontWeight {
     }
 }
 
+/// Font style (slant) options for text elements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FontStyle {
+    /// Upright glyphs.
+    #[default]
+    Normal,
+    /// The italic face of the font family.
+    Italic,
+    /// A slanted version of the regular face.
+    Oblique,
+}
+
+impl From<&str> for FontStyle {
+    fn from(s: &str) -> Self {
+        match s.to_lowercase().as_str() {
+            "italic" => FontStyle::Italic,
+            "oblique" => FontStyle::Oblique,
+            _ => FontStyle::Normal,
+        }
+    }
+}
+
+// Helper for backend conversion.
+impl std::fmt::Display for FontStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FontStyle::Normal => write!(f, "normal"),
+            FontStyle::Italic => write!(f, "italic"),
+            FontStyle::Oblique => write!(f, "oblique"),
+        }
+    }
+}
+
 /// Horizontal alignment options for text elements.
 #[derive(Debug, Clone, Default)]
 pub enum TextAnchor {
@@ -0,0 +1,142 @@
+use crate::mark::Mark;
+use crate::transform::density_transform::{BandwidthType, KernelType};
+use crate::visual::color::SingleColor;
+
+/// Mark type for violin charts.
+///
+/// The `MarkViolin` struct defines the visual properties of violin elements: a kernel
+/// density estimate of each category's values, mirrored around the category position.
+/// Grouping and dodging follow the same width, spacing and span rules as boxplots.
+/// With a color encoding each group takes its color from the theme palette
+/// (see `with_color_palette`); otherwise every violin uses the mark color.
+#[derive(Debug, Clone)]
+pub struct MarkViolin {
+    /// Fill for violins without a color encoding. `None` uses the default steelblue.
+    pub(crate) color: Option<SingleColor>,
+    pub(crate) opacity: f64,
+    pub(crate) stroke: SingleColor,
+    pub(crate) stroke_width: f64,
+    /// Draws a line across each violin at the median.
+    pub(crate) show_median: bool,
+    /// Draws a slim box plot (quartiles and range) inside each violin.
+    pub(crate) inner_box: bool,
+    pub(crate) width: f64,
+    pub(crate) spacing: f64,
+    pub(crate) span: f64,
+
+    // --- Density estimation ---
+    pub(crate) bandwidth: BandwidthType,
+    pub(crate) kernel: KernelType,
+}
+
+impl MarkViolin {
+    pub(crate) fn new() -> Self {
+        Self {
+            color: None,
+            opacity: 1.0,
+            stroke: SingleColor::new("black"),
+            stroke_width: 1.0,
+            show_median: true,
+            inner_box: false,
+            // span = width + spacing, as for boxplots
+            width: 0.5,
+            spacing: 0.2,
+            span: 0.7,
+            bandwidth: BandwidthType::Scott,
+            kernel: KernelType::Normal,
+        }
+    }
+
+    // --- Fluent Configuration Methods (Builder Pattern) ---
+
+    /// Sets the fill color of the violins, or `None` for the default.
+    ///
+    /// Ignored for groups colored through a color encoding.
+    pub const fn with_violin_color(mut self, color: Option<SingleColor>) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Sets the maximal width of a violin, relative to the category step.
+    ///
+    /// The widest point of the densest violin reaches this width; the others are
+    /// scaled by the same factor so that their areas remain comparable.
+    pub const fn with_violin_width(mut self, width: f64) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the opacity of the violin fill.
+    ///
+    /// Value should be between 0.0 (transparent) and 1.0 (opaque).
+    pub const fn with_opacity(mut self, opacity: f64) -> Self {
+        self.opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the outline color of the violins. Use "none" to disable.
+    pub fn with_stroke(mut self, stroke: impl Into<SingleColor>) -> Self {
+        self.stroke = stroke.into();
+        self
+    }
+
+    /// Sets the thickness of the violin outline and median line.
+    pub const fn with_stroke_width(mut self, width: f64) -> Self {
+        self.stroke_width = width;
+        self
+    }
+
+    /// Enables or disables the median line drawn across each violin.
+    pub const fn with_median(mut self, show: bool) -> Self {
+        self.show_median = show;
+        self
+    }
+
+    /// Enables or disables the inner box plot showing quartiles and the data range.
+    pub const fn with_inner_box(mut self, show: bool) -> Self {
+        self.inner_box = show;
+        self
+    }
+
+    /// Sets the bandwidth used to estimate each violin's density.
+    ///
+    /// Larger bandwidths give smoother outlines. Defaults to Scott's rule.
+    pub const fn with_violin_bandwidth(mut self, bandwidth: BandwidthType) -> Self {
+        self.bandwidth = bandwidth;
+        self
+    }
+
+    /// Sets the kernel used to estimate each violin's density. Defaults to `Normal`.
+    pub const fn with_violin_kernel(mut self, kernel: KernelType) -> Self {
+        self.kernel = kernel;
+        self
+    }
+
+    /// Sets the relative spacing between violins in a group.
+    ///
+    /// Value is clamped between 0.0 and 1.0.
+    pub const fn with_spacing(mut self, spacing: f64) -> Self {
+        self.spacing = spacing.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the total span allocated for a violin group.
+    ///
+    /// Value is clamped between 0.0 and 1.0.
+    pub const fn with_span(mut self, span: f64) -> Self {
+        self.span = span.clamp(0.0, 1.0);
+        self
+    }
+}
+
+impl Default for MarkViolin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Mark for MarkViolin {
+    fn mark_type(&self) -> &'static str {
+        "violin"
+    }
+}
@@ -0,0 +1,38 @@
+use crate::visual::color::SingleColor;
+
+/// Whether the zero reference line is drawn beneath or on top of the marks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZeroLinePosition {
+    /// Drawn before the marks, so it only shows between and beyond them.
+    Under,
+    /// Drawn after the marks, across them.
+    Over,
+}
+
+/// The reference line drawn at zero on the value axis of bar and area marks.
+///
+/// The line is purely visual: whether the value domain includes zero is
+/// controlled by `with_zero` on the encoding, and the line is only drawn when
+/// zero falls inside the domain.
+#[derive(Debug, Clone)]
+pub(crate) struct ZeroLine {
+    pub(crate) visible: bool,
+    /// Line color. `None` uses the theme's axis color.
+    pub(crate) color: Option<SingleColor>,
+    pub(crate) width: f64,
+    pub(crate) dash: Vec<f64>,
+    pub(crate) position: ZeroLinePosition,
+}
+
+impl ZeroLine {
+    /// Creates a dashed line in the theme's axis color.
+    pub(crate) fn new(visible: bool, position: ZeroLinePosition) -> Self {
+        Self {
+            visible,
+            color: None,
+            width: 1.0,
+            dash: vec![4.0, 4.0],
+            position,
+        }
+    }
+}
@@ -1,8 +1,13 @@
+#![deny(clippy::unwrap_used, clippy::expect_used)]
+
 pub(crate) mod area_renderer;
 pub(crate) mod backend;
 pub(crate) mod bar_renderer;
 pub(crate) mod box_renderer;
+pub(crate) mod candlestick_renderer;
 pub(crate) mod cartesian2d_axis_renderer;
+pub(crate) mod dotplot_renderer;
+pub(crate) mod dots_renderer;
 pub(crate) mod errorbar_renderer;
 pub(crate) mod geo_axis_renderer;
 pub(crate) mod geo_renderer;
@@ -13,16 +18,19 @@ pub(crate) mod point_renderer;
 pub(crate) mod polar_axis_renderer;
 pub(crate) mod rect_renderer;
 pub(crate) mod rule_renderer;
+pub(crate) mod stack_label_renderer;
 pub(crate) mod text_renderer;
 pub(crate) mod tick_renderer;
+pub(crate) mod violin_renderer;
 pub mod wgpu_renderer;
+pub(crate) mod zero_line_renderer;
 
 // Re-export the wgpubackend and rasterbackend so `render_to_surface` can be used from extern
 #[cfg(feature = "wgpu")]
 pub use backend::wgpu::WgpuBackend;
 
-#[cfg(all(feature = "wgpu", feature = "png"))]
+#[cfg(all(feature = "wgpu", feature = "raster"))]
 pub use wgpu_renderer::WgpuRenderer;
 
-#[cfg(feature = "png")]
+#[cfg(feature = "raster")]
 pub use backend::raster::RasterBackend;
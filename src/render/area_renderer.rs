@@ -7,7 +7,11 @@ use crate::core::utils::Parallelizable;
 use crate::encode::y::StackMode;
 use crate::error::ChartonError;
 use crate::mark::area::MarkArea;
+use crate::mark::zero_line::ZeroLinePosition;
+use crate::render::stack_label_renderer::{StackFormat, StackSegment, render_stack_label};
+use crate::render::zero_line_renderer::render_zero_line;
 use crate::visual::color::SingleColor;
+use ahash::AHashMap;
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
@@ -42,6 +46,10 @@ impl MarkRenderer for Chart<MarkArea> {
         let x_scale = context.coord.get_x_scale();
         let y_scale = context.coord.get_y_scale();
 
+        if let Some(y2_enc) = self.encoding.y2.as_ref() {
+            return self.render_difference(backend, context, mark_config, &y2_enc.field);
+        }
+
         // Identify temporary column names for stacked/stream modes
         let y_field = y_enc.field.as_str();
         let y0_field = format!("{}_{}_min", TEMP_SUFFIX, y_field);
@@ -52,10 +60,15 @@ impl MarkRenderer for Chart<MarkArea> {
             StackMode::Stacked | StackMode::Normalize | StackMode::Center
         );
 
-        // --- STEP 2: Render Zero Baseline ---
+        // --- STEP 2: Render Zero Baseline (beneath the areas) ---
         // Only rendered for unstacked modes to provide a visual reference for 0.0
         if !use_stacked {
-            self.draw_zero_baseline(backend, context);
+            render_zero_line(
+                &mark_config.zero_line,
+                ZeroLinePosition::Under,
+                backend,
+                context,
+            );
         }
 
         // --- STEP 3: Vectorized Column Extraction (Normalized Space) ---
@@ -210,6 +223,68 @@ impl MarkRenderer for Chart<MarkArea> {
             }
         }
 
+        // --- STEP 6: Labels for stacked bands, above all the fills ---
+        if use_stacked && !mark_config.stack_label_parts.is_empty() {
+            let y0_vals = ds.column(&y0_field)?.to_f64_vec();
+            let y1_vals = ds.column(&y1_field)?.to_f64_vec();
+
+            // The bottom and top of the whole stack at each x.
+            let mut extents: AHashMap<u64, (f64, f64)> = AHashMap::new();
+            for idx in 0..ds.row_count {
+                if let Some(xn) = x_norms[idx] {
+                    let e = extents
+                        .entry(xn.to_bits())
+                        .or_insert((f64::INFINITY, f64::NEG_INFINITY));
+                    *e = (e.0.min(y0_vals[idx]), e.1.max(y1_vals[idx]));
+                }
+            }
+
+            let format = StackFormat::new(
+                context,
+                y_field,
+                y_enc.normalize || y_enc.stack == StackMode::Normalize,
+            );
+            for (_name, row_indices) in &grouped_data.groups {
+                // Each band is labeled once, where it is thickest.
+                let Some((idx, xn)) = row_indices
+                    .iter()
+                    .filter_map(|&idx| Some((idx, x_norms[idx]?)))
+                    .max_by(|a, b| {
+                        (y1_vals[a.0] - y0_vals[a.0]).total_cmp(&(y1_vals[b.0] - y0_vals[b.0]))
+                    })
+                else {
+                    continue;
+                };
+                let (bottom, top) = extents[&xn.to_bits()];
+                let segment = StackSegment {
+                    value: y1_vals[idx] - y0_vals[idx],
+                    cumulative: y1_vals[idx] - bottom,
+                    total: top - bottom,
+                };
+                render_stack_label(
+                    format.label(&mark_config.stack_label_parts, segment),
+                    (
+                        xn,
+                        y_scale.normalize(y0_vals[idx]),
+                        y_scale.normalize(y1_vals[idx]),
+                    ),
+                    context,
+                    backend,
+                    mark_config.opacity as Precision,
+                );
+            }
+        }
+
+        // --- STEP 7: Render Zero Baseline (over the areas) ---
+        if !use_stacked {
+            render_zero_line(
+                &mark_config.zero_line,
+                ZeroLinePosition::Over,
+                backend,
+                context,
+            );
+        }
+
         Ok(())
     }
 }
@@ -222,30 +297,139 @@ struct AreaInternalPoint {
     y0n: f64, // Normalized Y0 (Baseline/Bottom Boundary)
 }
 
+/// A stretch of the difference chart where one series stays on the same side of the other.
+struct DifferenceRun {
+    /// Vertices as (x, y, y2) in normalized space.
+    points: Vec<(f64, f64, f64)>,
+    /// `Some(true)` where y > y2, `Some(false)` where y < y2, `None` until known.
+    positive: Option<bool>,
+}
+
+impl DifferenceRun {
+    const fn new() -> Self {
+        Self {
+            points: Vec::new(),
+            positive: None,
+        }
+    }
+}
+
+/// Splits a series of (x, y, y2) points, sorted by x, into runs of constant sign.
+///
+/// Where the two series cross between samples, the crossing is found by linear
+/// interpolation and shared by both neighbouring runs. A sample where the series
+/// are exactly equal ends the current run and starts the next one.
+fn split_difference_runs(points: &[(f64, f64, f64)]) -> Vec<DifferenceRun> {
+    let mut runs = Vec::new();
+    let mut current = DifferenceRun::new();
+
+    for &(x, a, b) in points {
+        let d = a - b;
+        if d == 0.0 {
+            current.points.push((x, a, b));
+            runs.push(std::mem::replace(&mut current, DifferenceRun::new()));
+            current.points.push((x, a, b));
+            continue;
+        }
+
+        let positive = d > 0.0;
+        match (current.positive, current.points.last()) {
+            (Some(sign), Some(&(px, pa, pb))) if sign != positive => {
+                let pd = pa - pb;
+                let t = pd / (pd - d);
+                let cx = px + t * (x - px);
+                let cy = pa + t * (a - pa);
+                current.points.push((cx, cy, cy));
+                runs.push(std::mem::replace(&mut current, DifferenceRun::new()));
+                current.points.push((cx, cy, cy));
+            }
+            _ => {}
+        }
+        current.positive = Some(positive);
+        current.points.push((x, a, b));
+    }
+    runs.push(current);
+
+    runs.retain(|r| r.positive.is_some() && r.points.len() > 1);
+    runs
+}
+
 impl Chart<MarkArea> {
-    /// Renders a dashed reference line at y=0 if it falls within the current axis domain
-    fn draw_zero_baseline(&self, backend: &mut dyn RenderBackend, context: &PanelContext) {
+    /// Fills the gap between the `y` and `y2` series, one polygon per run of
+    /// constant sign, colored by whether `y` is above or below `y2`.
+    fn render_difference(
+        &self,
+        backend: &mut dyn RenderBackend,
+        context: &PanelContext,
+        mark_config: &MarkArea,
+        y2_field: &str,
+    ) -> Result<(), ChartonError> {
+        let ds = &self.data;
+        let (Some(x_enc), Some(y_enc)) = (self.encoding.x.as_ref(), self.encoding.y.as_ref())
+        else {
+            return Err(ChartonError::Encoding(
+                "Difference area requires x and y encodings".into(),
+            ));
+        };
+        let x_scale = context.coord.get_x_scale();
         let y_scale = context.coord.get_y_scale();
-        let (y_min, y_max) = y_scale.domain();
 
-        if y_min <= 0.0 && y_max >= 0.0 {
-            let b_norm = y_scale.normalize(0.0);
-            let (px1, py1) = context.coord.transform(0.0, b_norm, &context.panel);
-            let (px2, py2) = context.coord.transform(1.0, b_norm, &context.panel);
+        let x_norms = x_scale
+            .scale_type()
+            .normalize_column(x_scale, ds.column(&x_enc.field)?);
+        let a_norms = y_scale
+            .scale_type()
+            .normalize_column(y_scale, ds.column(&y_enc.field)?);
+        let b_norms = y_scale
+            .scale_type()
+            .normalize_column(y_scale, ds.column(y2_field)?);
 
-            backend.draw_path(PathConfig {
-                points: vec![
-                    (px1 as Precision, py1 as Precision),
-                    (px2 as Precision, py2 as Precision),
-                ],
-                fill: SingleColor::none(),
-                stroke: SingleColor::from("#888888"),
-                stroke_width: 1.0,
-                opacity: 0.5,
-                dash: vec![4.0, 4.0],
-                topology: PathTopology::Simple,
-            });
+        let color_field = context
+            .spec
+            .aesthetics
+            .color
+            .as_ref()
+            .map(|c| c.field.as_str());
+
+        for (_name, row_indices) in ds.group_by(color_field).groups {
+            let mut points: Vec<(f64, f64, f64)> = row_indices
+                .iter()
+                .filter_map(|&i| Some((x_norms[i]?, a_norms[i]?, b_norms[i]?)))
+                .collect();
+            // Stable sort so that rows sharing an x keep their input order.
+            points.sort_by(|p, q| p.0.total_cmp(&q.0));
+
+            for run in split_difference_runs(&points) {
+                let mut fill_pts: Vec<(Precision, Precision)> =
+                    Vec::with_capacity(run.points.len() * 2);
+                for &(x, a, _) in &run.points {
+                    let (px, py) = context.coord.transform(x, a, &context.panel);
+                    fill_pts.push((px as Precision, py as Precision));
+                }
+                for &(x, _, b) in run.points.iter().rev() {
+                    let (px, py) = context.coord.transform(x, b, &context.panel);
+                    fill_pts.push((px as Precision, py as Precision));
+                }
+
+                let fill = if run.positive == Some(true) {
+                    mark_config.positive_color
+                } else {
+                    mark_config.negative_color
+                };
+
+                backend.draw_path(PathConfig {
+                    points: fill_pts,
+                    fill,
+                    stroke: SingleColor::none(),
+                    stroke_width: 0.0,
+                    opacity: mark_config.opacity as Precision,
+                    dash: vec![],
+                    topology: PathTopology::Complex,
+                });
+            }
         }
+
+        Ok(())
     }
 
     /// Optimized color resolution that maps a normalized value directly to a color.
@@ -1,6 +1,7 @@
+pub(crate) mod offset;
 pub mod svg;
 
-#[cfg(feature = "png")]
+#[cfg(feature = "raster")]
 pub mod raster;
 
 #[cfg(feature = "wgpu")]
@@ -0,0 +1,110 @@
+use crate::Precision;
+use crate::coordinate::Rect;
+use crate::core::layer::{
+    CircleConfig, GradientRectConfig, LineConfig, PathConfig, PolygonConfig, RectConfig,
+    RenderBackend, TextConfig,
+};
+
+/// `OffsetBackend` forwards every draw call to another backend, shifted by a fixed offset.
+///
+/// It lets a complete chart, laid out on its own (0, 0)-based canvas, be drawn at an
+/// arbitrary position of a parent canvas (e.g. an inset inside the main panel). Because
+/// the translation happens before the calls reach the wrapped backend, SVG, raster and
+/// GPU outputs all place the nested chart identically.
+pub(crate) struct OffsetBackend<'a> {
+    inner: &'a mut dyn RenderBackend,
+    dx: Precision,
+    dy: Precision,
+}
+
+impl<'a> OffsetBackend<'a> {
+    pub(crate) fn new(inner: &'a mut dyn RenderBackend, dx: f64, dy: f64) -> Self {
+        Self {
+            inner,
+            dx: dx as Precision,
+            dy: dy as Precision,
+        }
+    }
+
+    fn shift_points(&self, points: Vec<(Precision, Precision)>) -> Vec<(Precision, Precision)> {
+        points
+            .into_iter()
+            .map(|(x, y)| (x + self.dx, y + self.dy))
+            .collect()
+    }
+}
+
+impl<'a> RenderBackend for OffsetBackend<'a> {
+    fn begin_clip_scope(&mut self, rect: &Rect) {
+        let shifted = Rect::new(
+            rect.x + self.dx as f64,
+            rect.y + self.dy as f64,
+            rect.width,
+            rect.height,
+        );
+        self.inner.begin_clip_scope(&shifted);
+    }
+
+    fn end_clip_scope(&mut self) {
+        self.inner.end_clip_scope();
+    }
+
+    fn set_tooltip(&mut self, tooltip: Option<String>) {
+        self.inner.set_tooltip(tooltip);
+    }
+
+    fn set_href(&mut self, href: Option<String>) {
+        self.inner.set_href(href);
+    }
+
+    fn draw_circle(&mut self, mut config: CircleConfig) {
+        config.x += self.dx;
+        config.y += self.dy;
+        self.inner.draw_circle(config);
+    }
+
+    fn draw_rect(&mut self, mut config: RectConfig) {
+        config.x += self.dx;
+        config.y += self.dy;
+        self.inner.draw_rect(config);
+    }
+
+    fn draw_path(&mut self, mut config: PathConfig) {
+        config.points = self.shift_points(config.points);
+        self.inner.draw_path(config);
+    }
+
+    fn draw_polygon(&mut self, mut config: PolygonConfig) {
+        config.points = self.shift_points(config.points);
+        self.inner.draw_polygon(config);
+    }
+
+    fn draw_text(&mut self, mut config: TextConfig) {
+        config.x += self.dx;
+        config.y += self.dy;
+        self.inner.draw_text(config);
+    }
+
+    fn draw_superscript_text(&mut self, mut config: TextConfig) {
+        config.x += self.dx;
+        config.y += self.dy;
+        self.inner.draw_superscript_text(config);
+    }
+
+    fn draw_line(&mut self, mut config: LineConfig) {
+        config.x1 += self.dx;
+        config.y1 += self.dy;
+        config.x2 += self.dx;
+        config.y2 += self.dy;
+        self.inner.draw_line(config);
+    }
+
+    fn draw_gradient_rect(&mut self, mut config: GradientRectConfig) {
+        config.x += self.dx;
+        config.y += self.dy;
+        // Keep gradient definitions of the nested chart distinct from the parent's.
+        config.id_suffix =
+            format!("{}_offset_{}_{}", config.id_suffix, self.dx, self.dy).replace(['.', '-'], "_");
+        self.inner.draw_gradient_rect(config);
+    }
+}
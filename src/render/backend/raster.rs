@@ -3,6 +3,7 @@ use crate::core::layer::{
     CircleConfig, GradientRectConfig, LineConfig, PathConfig, PathTopology, PolygonConfig,
     RectConfig, RenderBackend, TextConfig,
 };
+use crate::error::ChartonError;
 use crate::visual::color::SingleColor;
 use ab_glyph::{Font, FontArc, PxScale, ScaleFont};
 use tiny_skia::{
@@ -66,6 +67,33 @@ impl<'a> RasterBackend<'a> {
         }
         width
     }
+
+    /// Fills one glyph outline, thickening it by `embolden` pixels when the
+    /// face has no genuine bold variant.
+    fn fill_glyph(
+        &mut self,
+        path: &tiny_skia::Path,
+        paint: &Paint,
+        transform: Transform,
+        embolden: Precision,
+    ) {
+        self.pixmap.fill_path(
+            path,
+            paint,
+            tiny_skia::FillRule::Winding,
+            transform,
+            self.clip_mask.as_ref(),
+        );
+        if embolden > 0.0 {
+            let stroke = Stroke {
+                width: embolden,
+                line_join: LineJoin::Round,
+                ..Default::default()
+            };
+            self.pixmap
+                .stroke_path(path, paint, &stroke, transform, self.clip_mask.as_ref());
+        }
+    }
 }
 
 impl<'a> RenderBackend for RasterBackend<'a> {
@@ -389,18 +417,84 @@ impl<'a> RenderBackend for RasterBackend<'a> {
             return;
         }
 
-        let font = crate::core::utils::get_raster_font(&config.font_family);
+        let wants_bold = match config.font_weight.as_str() {
+            "bold" | "bolder" => true,
+            weight => weight.parse::<u16>().is_ok_and(|w| w >= 600),
+        };
+        let wants_italic = config.font_style != "normal";
+        let face =
+            crate::core::utils::get_raster_font(&config.font_family, wants_bold, wants_italic);
+        let font = face.font.clone();
+
+        // Styling the face lacks is synthesized: a thin outline stroke for bold
+        // and a horizontal shear for italic.
+        let embolden = if wants_bold && !face.bold {
+            config.font_size * 0.04
+        } else {
+            0.0
+        };
+        let slant = if wants_italic && !face.italic {
+            Transform::from_row(1.0, 0.0, -0.2, 1.0, 0.0, 0.0)
+        } else {
+            Transform::identity()
+        };
+
         let scale = PxScale::from(config.font_size);
         let scaled_font = font.as_scaled(scale);
 
+        // Out-of-range channels (e.g. opacity above 1.0) yield no valid color.
+        let Some(base_color) = self.to_skia_color(&config.color, config.opacity) else {
+            return;
+        };
+        let mut paint = tiny_skia::Paint::default();
+        paint.set_color(base_color);
+        paint.anti_alias = true;
+
+        let units_per_em = font.units_per_em().unwrap_or(1000.0);
+        let font_to_px = config.font_size / units_per_em;
+
         let anchor_x = config.x;
         let anchor_y = config.y;
 
+        let ascent = scaled_font.ascent();
+        let descent = scaled_font.descent();
+
+        // 0. Vertical upright text: one em-square cell per character, stacked top to
+        // bottom and centered on x. The anchor applies along the vertical axis.
+        if config.writing_mode == "vertical-rl" {
+            let count = config.text.chars().count() as Precision;
+            let total_height = count * config.font_size;
+            let mut cell_top = match config.text_anchor.as_str() {
+                "middle" => anchor_y - total_height / 2.0,
+                "end" => anchor_y - total_height,
+                _ => anchor_y,
+            };
+
+            for c in config.text.chars() {
+                let glyph_id = font.glyph_id(c);
+                let glyph_x = anchor_x - scaled_font.h_advance(glyph_id) / 2.0;
+                let baseline = cell_top + config.font_size / 2.0 + (ascent + descent) / 2.0;
+                if let Some(path) = glyph_path(&font, glyph_id, font_to_px) {
+                    let transform = self
+                        .transform
+                        .pre_translate(glyph_x, baseline)
+                        .pre_concat(slant);
+                    self.fill_glyph(&path, &paint, transform, embolden);
+                }
+                cell_top += config.font_size;
+            }
+            return;
+        }
+
+        // Glyphs are laid out left to right, so bidirectional text is reordered first.
+        let text = bidi_visual_order(&config.text, config.direction == "rtl");
+
         // 1. Strictly calculate horizontal width (used for text-anchor)
-        let width = self.get_precise_width(&config.text, scale, &font);
+        let width = self.get_precise_width(&text, scale, &font)
+            + text.chars().count() as Precision * embolden;
         const TRACKING: f32 = 0.3;
-        let total_tracking = if config.text.len() > 1 {
-            (config.text.len() - 1) as f32 * TRACKING
+        let total_tracking = if text.len() > 1 {
+            (text.len() - 1) as f32 * TRACKING
         } else {
             0.0
         };
@@ -416,8 +510,6 @@ impl<'a> RenderBackend for RasterBackend<'a> {
         // 2. Strictly align with SVG dominant-baseline
         // In SVG, the default y is the baseline, so dy = 0
         let mut dy = 0.0;
-        let ascent = scaled_font.ascent();
-        let descent = scaled_font.descent();
 
         match config.dominant_baseline.as_str() {
             "hanging" => dy += ascent,
@@ -442,74 +534,23 @@ impl<'a> RenderBackend for RasterBackend<'a> {
         let mut current_x = anchor_x + dx;
         let draw_y = anchor_y + dy;
 
-        let base_color = self.to_skia_color(&config.color, config.opacity).unwrap();
-        let mut paint = tiny_skia::Paint::default();
-        paint.set_color(base_color);
-        paint.anti_alias = true;
-
-        let units_per_em = font.units_per_em().unwrap_or(1000.0);
-        let font_to_px = config.font_size / units_per_em;
-
         let mut last_glyph_id = None;
 
-        for c in config.text.chars() {
+        for c in text.chars() {
             let glyph_id = font.glyph_id(c);
             if let Some(last_id) = last_glyph_id {
                 current_x += scaled_font.kern(last_id, glyph_id);
             }
 
-            if let Some(outline) = font.outline(glyph_id) {
-                let mut pb = tiny_skia::PathBuilder::new();
-                // Note: p.y is negated because the ab_glyph coordinate system grows upwards
-                let map_p = |p: ab_glyph::Point| (p.x * font_to_px, -p.y * font_to_px);
-
-                let mut current_pen: Option<ab_glyph::Point> = None;
-                for curve in outline.curves {
-                    let (start, end) = match curve {
-                        ab_glyph::OutlineCurve::Line(p1, p2) => (p1, p2),
-                        ab_glyph::OutlineCurve::Quad(p1, _, p3) => (p1, p3),
-                        ab_glyph::OutlineCurve::Cubic(p1, _, _, p4) => (p1, p4),
-                    };
-
-                    if current_pen != Some(start) {
-                        let (sx, sy) = map_p(start);
-                        pb.move_to(sx, sy);
-                    }
-
-                    match curve {
-                        ab_glyph::OutlineCurve::Line(_, p2) => {
-                            let (px, py) = map_p(p2);
-                            pb.line_to(px, py);
-                        }
-                        ab_glyph::OutlineCurve::Quad(_, p2, p3) => {
-                            let (p2x, p2y) = map_p(p2);
-                            let (p3x, p3y) = map_p(p3);
-                            pb.quad_to(p2x, p2y, p3x, p3y);
-                        }
-                        ab_glyph::OutlineCurve::Cubic(_, p2, p3, p4) => {
-                            let (p2x, p2y) = map_p(p2);
-                            let (p3x, p3y) = map_p(p3);
-                            let (p4x, p4y) = map_p(p4);
-                            pb.cubic_to(p2x, p2y, p3x, p3y, p4x, p4y);
-                        }
-                    }
-                    current_pen = Some(end);
-                }
-
-                if let Some(path) = pb.finish() {
-                    // Apply rotation first, then translate to the current character's baseline position
-                    let glyph_transform = global_transform.pre_translate(current_x, draw_y);
-                    self.pixmap.fill_path(
-                        &path,
-                        &paint,
-                        tiny_skia::FillRule::Winding,
-                        glyph_transform,
-                        self.clip_mask.as_ref(),
-                    );
-                }
+            if let Some(path) = glyph_path(&font, glyph_id, font_to_px) {
+                // Apply rotation first, then translate to the current character's baseline position
+                let glyph_transform = global_transform
+                    .pre_translate(current_x, draw_y)
+                    .pre_concat(slant);
+                self.fill_glyph(&path, &paint, glyph_transform, embolden);
             }
 
-            current_x += scaled_font.h_advance(glyph_id) + TRACKING;
+            current_x += scaled_font.h_advance(glyph_id) + TRACKING + embolden;
             last_glyph_id = Some(glyph_id);
         }
     }
@@ -577,3 +618,367 @@ impl<'a> RenderBackend for RasterBackend<'a> {
         }
     }
 }
+
+/// Chart units are CSS pixels, so a scale of 1.0 corresponds to 96 DPI.
+pub(crate) const BASE_DPI: f32 = 96.0;
+
+/// Allocates a blank pixmap for a `width` x `height` chart rendered at `scale`.
+///
+/// The buffer is reserved fallibly, so a scale too large for the available
+/// memory is reported as a `ChartonError::Render` instead of aborting.
+pub(crate) fn allocate_pixmap(width: u32, height: u32, scale: f32) -> Result<Pixmap, ChartonError> {
+    if !(scale.is_finite() && scale > 0.0) {
+        return Err(ChartonError::Render(format!(
+            "PNG scale must be a positive number, got {}",
+            scale
+        )));
+    }
+
+    let (w, h) = (
+        (width as f32 * scale).trunc(),
+        (height as f32 * scale).trunc(),
+    );
+    let size = (w < u32::MAX as f32 && h < u32::MAX as f32)
+        .then(|| tiny_skia::IntSize::from_wh(w as u32, h as u32))
+        .flatten()
+        .ok_or_else(|| {
+            ChartonError::Render(format!("Invalid PNG size {}x{} at scale {}", w, h, scale))
+        })?;
+
+    let too_large = || {
+        ChartonError::Render(format!(
+            "Not enough memory for a {}x{} PNG at scale {}",
+            size.width(),
+            size.height(),
+            scale
+        ))
+    };
+    let len = (size.width() as usize)
+        .checked_mul(size.height() as usize)
+        .and_then(|n| n.checked_mul(4))
+        .ok_or_else(too_large)?;
+    let mut data = Vec::new();
+    data.try_reserve_exact(len).map_err(|_| too_large())?;
+    data.resize(len, 0);
+
+    Pixmap::from_vec(data, size).ok_or_else(too_large)
+}
+
+/// Encodes a pixmap as PNG and records its resolution in a `pHYs` chunk.
+///
+/// The resolution is `scale * 96` DPI, so a figure saved at scale 3.125
+/// prints at its chart size on a 300 DPI device.
+pub(crate) fn encode_png(pixmap: &Pixmap, scale: f32) -> Result<Vec<u8>, ChartonError> {
+    let png = pixmap
+        .encode_png()
+        .map_err(|e| ChartonError::Render(format!("Failed to encode PNG: {}", e)))?;
+
+    // The pHYs chunk must precede the image data; it goes right after the
+    // 8-byte signature and the 25-byte IHDR chunk.
+    const IHDR_END: usize = 8 + 25;
+    if png.len() < IHDR_END || &png[12..16] != b"IHDR" {
+        return Err(ChartonError::Render("Unexpected PNG layout".to_string()));
+    }
+
+    // Pixels per metre, with unit specifier 1 (metre).
+    let ppm = (scale as f64 * BASE_DPI as f64 / 0.0254).round() as u32;
+    let mut chunk = Vec::with_capacity(21);
+    chunk.extend_from_slice(&9u32.to_be_bytes());
+    chunk.extend_from_slice(b"pHYs");
+    chunk.extend_from_slice(&ppm.to_be_bytes());
+    chunk.extend_from_slice(&ppm.to_be_bytes());
+    chunk.push(1);
+    let crc = crc32(&chunk[4..]);
+    chunk.extend_from_slice(&crc.to_be_bytes());
+
+    let mut out = Vec::with_capacity(png.len() + chunk.len());
+    out.extend_from_slice(&png[..IHDR_END]);
+    out.extend_from_slice(&chunk);
+    out.extend_from_slice(&png[IHDR_END..]);
+    Ok(out)
+}
+
+/// The CRC-32 used by PNG chunks (polynomial 0xEDB88320).
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = u32::MAX;
+    for &byte in bytes {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+/// Encodes a pixmap as JPEG at `quality` (1 to 100).
+///
+/// JPEG has no alpha channel, so translucent pixels are flattened onto white.
+/// Like the PNG, the file records a resolution of `scale * 96` DPI.
+#[cfg(feature = "jpeg")]
+pub(crate) fn encode_jpeg(
+    pixmap: &Pixmap,
+    scale: f32,
+    quality: u8,
+) -> Result<Vec<u8>, ChartonError> {
+    use image::codecs::jpeg::{JpegEncoder, PixelDensity};
+
+    if !(1..=100).contains(&quality) {
+        return Err(ChartonError::Render(format!(
+            "JPEG quality must be between 1 and 100, got {}",
+            quality
+        )));
+    }
+
+    // Premultiplied channels composite onto white as `c + (255 - a)`.
+    let rgb: Vec<u8> = pixmap
+        .pixels()
+        .iter()
+        .flat_map(|p| {
+            let back = 255 - p.alpha();
+            [p.red() + back, p.green() + back, p.blue() + back]
+        })
+        .collect();
+
+    let mut out = Vec::new();
+    let mut encoder = JpegEncoder::new_with_quality(&mut out, quality);
+    let dpi = (scale * BASE_DPI).round().clamp(1.0, u16::MAX as f32) as u16;
+    encoder.set_pixel_density(PixelDensity::dpi(dpi));
+    encoder
+        .encode(
+            &rgb,
+            pixmap.width(),
+            pixmap.height(),
+            image::ExtendedColorType::Rgb8,
+        )
+        .map_err(|e| ChartonError::Render(format!("Failed to encode JPEG: {}", e)))?;
+    Ok(out)
+}
+
+/// Encodes a pixmap as WebP, keeping transparency.
+///
+/// The image crate only writes lossless WebP, which is still usually smaller
+/// than the PNG of a chart.
+#[cfg(feature = "webp")]
+pub(crate) fn encode_webp(pixmap: &Pixmap) -> Result<Vec<u8>, ChartonError> {
+    use image::codecs::webp::WebPEncoder;
+
+    let rgba: Vec<u8> = pixmap
+        .pixels()
+        .iter()
+        .flat_map(|p| {
+            let c = p.demultiply();
+            [c.red(), c.green(), c.blue(), c.alpha()]
+        })
+        .collect();
+
+    let mut out = Vec::new();
+    WebPEncoder::new_lossless(&mut out)
+        .encode(
+            &rgba,
+            pixmap.width(),
+            pixmap.height(),
+            image::ExtendedColorType::Rgba8,
+        )
+        .map_err(|e| ChartonError::Render(format!("Failed to encode WebP: {}", e)))?;
+    Ok(out)
+}
+
+/// Builds the outline of a glyph in pixels, with its origin on the baseline.
+fn glyph_path(
+    font: &FontArc,
+    glyph_id: ab_glyph::GlyphId,
+    font_to_px: f32,
+) -> Option<tiny_skia::Path> {
+    let outline = font.outline(glyph_id)?;
+    let mut pb = tiny_skia::PathBuilder::new();
+    // Note: p.y is negated because the ab_glyph coordinate system grows upwards
+    let map_p = |p: ab_glyph::Point| (p.x * font_to_px, -p.y * font_to_px);
+
+    let mut current_pen: Option<ab_glyph::Point> = None;
+    for curve in outline.curves {
+        let (start, end) = match curve {
+            ab_glyph::OutlineCurve::Line(p1, p2) => (p1, p2),
+            ab_glyph::OutlineCurve::Quad(p1, _, p3) => (p1, p3),
+            ab_glyph::OutlineCurve::Cubic(p1, _, _, p4) => (p1, p4),
+        };
+
+        if current_pen != Some(start) {
+            let (sx, sy) = map_p(start);
+            pb.move_to(sx, sy);
+        }
+
+        match curve {
+            ab_glyph::OutlineCurve::Line(_, p2) => {
+                let (px, py) = map_p(p2);
+                pb.line_to(px, py);
+            }
+            ab_glyph::OutlineCurve::Quad(_, p2, p3) => {
+                let (p2x, p2y) = map_p(p2);
+                let (p3x, p3y) = map_p(p3);
+                pb.quad_to(p2x, p2y, p3x, p3y);
+            }
+            ab_glyph::OutlineCurve::Cubic(_, p2, p3, p4) => {
+                let (p2x, p2y) = map_p(p2);
+                let (p3x, p3y) = map_p(p3);
+                let (p4x, p4y) = map_p(p4);
+                pb.cubic_to(p2x, p2y, p3x, p3y, p4x, p4y);
+            }
+        }
+        current_pen = Some(end);
+    }
+
+    pb.finish()
+}
+
+/// Reorders a logical string into left-to-right display order.
+///
+/// This is a simplified bidi pass for short labels: Hebrew and Arabic letters form
+/// right-to-left runs, other letters and digits form left-to-right runs, and neutral
+/// characters (spaces, punctuation) join their neighbours when both sides agree and
+/// otherwise follow the base direction. Brackets in right-to-left runs are mirrored.
+/// Arabic contextual shaping is not applied, so Arabic renders in isolated letter
+/// forms in raster output.
+fn bidi_visual_order(text: &str, rtl: bool) -> String {
+    let is_rtl = |c: char| matches!(c as u32, 0x0590..=0x08FF | 0xFB1D..=0xFDFF | 0xFE70..=0xFEFF);
+
+    if !rtl && !text.chars().any(is_rtl) {
+        return text.to_string();
+    }
+
+    let chars: Vec<char> = text.chars().collect();
+    let strong: Vec<Option<bool>> = chars
+        .iter()
+        .map(|&c| {
+            if is_rtl(c) {
+                Some(true)
+            } else if c.is_alphanumeric() {
+                Some(false)
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    // Nearest strong direction on each side of every position.
+    let mut prev = vec![None; chars.len()];
+    let mut last = None;
+    for i in 0..chars.len() {
+        prev[i] = last;
+        last = strong[i].or(last);
+    }
+    let mut next = vec![None; chars.len()];
+    last = None;
+    for i in (0..chars.len()).rev() {
+        next[i] = last;
+        last = strong[i].or(last);
+    }
+
+    let levels: Vec<bool> = (0..chars.len())
+        .map(|i| match (strong[i], prev[i], next[i]) {
+            (Some(level), _, _) => level,
+            (None, Some(p), Some(n)) if p == n => p,
+            _ => rtl,
+        })
+        .collect();
+
+    // Split into runs of equal direction.
+    let mut runs: Vec<(bool, Vec<char>)> = Vec::new();
+    for (&c, &level) in chars.iter().zip(&levels) {
+        match runs.last_mut() {
+            Some((l, run)) if *l == level => run.push(c),
+            _ => runs.push((level, vec![c])),
+        }
+    }
+
+    if rtl {
+        runs.reverse();
+    }
+
+    runs.into_iter()
+        .flat_map(|(level, mut run)| {
+            if level {
+                run.reverse();
+                for c in run.iter_mut() {
+                    *c = match *c {
+                        '(' => ')',
+                        ')' => '(',
+                        '[' => ']',
+                        ']' => '[',
+                        '{' => '}',
+                        '}' => '{',
+                        '<' => '>',
+                        '>' => '<',
+                        other => other,
+                    };
+                }
+            }
+            run
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{bidi_visual_order, crc32};
+
+    #[test]
+    fn test_crc32_matches_png_chunks() {
+        // The CRC of every IEND chunk, as found at the end of any PNG file.
+        assert_eq!(crc32(b"IEND"), 0xAE42_6082);
+    }
+
+    #[test]
+    fn test_bidi_visual_order() {
+        // Pure left-to-right text is untouched.
+        assert_eq!(bidi_visual_order("Price (USD)", false), "Price (USD)");
+        // Hebrew is reversed into display order.
+        assert_eq!(bidi_visual_order("שלום", false), "םולש");
+        // Numbers keep their digit order inside right-to-left text, brackets are mirrored.
+        assert_eq!(bidi_visual_order("מחיר (2024)", true), "(2024) ריחמ");
+        // A right-to-left run inside left-to-right text is reversed in place.
+        assert_eq!(bidi_visual_order("Tel Aviv תא", false), "Tel Aviv את");
+    }
+
+    #[test]
+    fn test_generic_font_families_draw_text() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::prelude::*;
+
+        // Counts the non-white pixels in the band above the plot, where only the title is drawn.
+        let title_ink =
+            |title: Option<&str>, family: &str| -> Result<usize, Box<dyn std::error::Error>> {
+                let ds = Dataset::new()
+                    .with_column("x", vec![1.0, 2.0, 3.0])?
+                    .with_column("y", vec![4.0, 5.0, 6.0])?;
+                let chart = Chart::build(ds)?
+                    .mark_point()?
+                    .encode((alt::x("x"), alt::y("y")))?
+                    .with_theme(Theme::default().with_title_family(family))
+                    .with_size(400, 300);
+                let chart = match title {
+                    Some(title) => chart.with_title(title),
+                    None => chart,
+                };
+                let pixmap = tiny_skia::Pixmap::decode_png(&chart.to_png_with_scale(1.0)?)?;
+                let band = (pixmap.width() * 30) as usize;
+                Ok(pixmap.pixels()[..band]
+                    .iter()
+                    .filter(|p| p.red() < 200 || p.green() < 200 || p.blue() < 200)
+                    .count())
+            };
+
+        // Every generic family resolves to the bundled Inter, whatever the system has.
+        let inter = title_ink(Some("Fonts"), "Inter")?;
+        for family in ["sans-serif", "serif", "monospace"] {
+            let blank = title_ink(None, family)?;
+            let titled = title_ink(Some("Fonts"), family)?;
+            assert!(titled > blank + 50, "no title pixels with {}", family);
+            assert_eq!(titled, inter, "{} is not drawn with Inter", family);
+        }
+
+        Ok(())
+    }
+}
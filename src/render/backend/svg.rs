@@ -2,6 +2,7 @@ use crate::core::layer::{
     CircleConfig, GradientRectConfig, LineConfig, PathConfig, PathTopology, PolygonConfig,
     RectConfig, RenderBackend, TextConfig,
 };
+use crate::scale::split_superscript;
 use crate::visual::color::SingleColor;
 use std::fmt::Write;
 
@@ -16,12 +17,42 @@ use std::fmt::Write;
 pub struct SvgBackend<'a> {
     /// Target buffer where SVG XML content is appended.
     pub buffer: &'a mut String,
+    /// Number of clip regions emitted so far, used to keep their ids unique
+    /// when several panels (e.g. insets) share one document.
+    clip_count: usize,
+    /// Text of the `<title>` child given to the elements currently being drawn.
+    tooltip: Option<String>,
+    /// URL of the open `<a>` element wrapping the elements currently being drawn.
+    href: Option<String>,
 }
 
 impl<'a> SvgBackend<'a> {
     /// Creates a new `SvgBackend` wrapped around an external string stream.
     pub const fn new(buffer: &'a mut String) -> Self {
-        Self { buffer }
+        Self {
+            buffer,
+            clip_count: 0,
+            tooltip: None,
+            href: None,
+        }
+    }
+
+    /// Closes an element whose attributes have been written, nesting the
+    /// current tooltip as a `<title>` child when one is set.
+    fn close_element(&mut self, tag: &str) {
+        match &self.tooltip {
+            Some(tooltip) => {
+                let _ = writeln!(
+                    self.buffer,
+                    "><title>{}</title></{}>",
+                    html_escape::encode_safe(tooltip),
+                    tag
+                );
+            }
+            None => {
+                let _ = self.buffer.write_str(" />\n");
+            }
+        }
     }
 
     /// Directly formats a `SingleColor` into the SVG buffer as an `rgba()` string.
@@ -43,6 +74,90 @@ impl<'a> SvgBackend<'a> {
             );
         }
     }
+
+    /// Writes a `<text>` element; with `superscript`, trailing superscript
+    /// characters are set as a raised `<tspan>`.
+    fn write_text(&mut self, config: TextConfig, superscript: bool) {
+        let TextConfig {
+            x,
+            y,
+            text,
+            font_size,
+            font_family,
+            color,
+            text_anchor,
+            font_weight,
+            font_style,
+            dominant_baseline,
+            opacity,
+            angle,
+            direction,
+            writing_mode,
+        } = config;
+
+        // With direction="rtl" an SVG "start" anchor refers to the right edge, so the
+        // anchor is mirrored to keep every label at the position the layout computed.
+        let rtl = direction == "rtl";
+        let text_anchor = match text_anchor.as_str() {
+            "start" if rtl => "end".to_string(),
+            "end" if rtl => "start".to_string(),
+            _ => text_anchor,
+        };
+
+        let _ = write!(
+            self.buffer,
+            r#"<text x="{:.3}" y="{:.3}" font-size="{:.1}" font-family="{}" fill=""#,
+            x, y, font_size, font_family
+        );
+        self.write_color(&color);
+        let _ = write!(
+            self.buffer,
+            r#"" fill-opacity="{:.3}" text-anchor="{}" font-weight="{}" dominant-baseline="{}""#,
+            opacity, text_anchor, font_weight, dominant_baseline
+        );
+        if font_style != "normal" {
+            let _ = write!(self.buffer, r#" font-style="{}""#, font_style);
+        }
+        if rtl {
+            let _ = self
+                .buffer
+                .write_str(r#" direction="rtl" unicode-bidi="embed""#);
+        }
+        if writing_mode == "vertical-rl" {
+            let _ = self
+                .buffer
+                .write_str(r#" writing-mode="vertical-rl" text-orientation="upright""#);
+        }
+        let _ = write!(
+            self.buffer,
+            r#" transform="rotate({} {:.3} {:.3})""#,
+            angle, x, y
+        );
+        let _ = self.buffer.write_str(">");
+        if let Some(tooltip) = &self.tooltip {
+            let _ = write!(
+                self.buffer,
+                "<title>{}</title>",
+                html_escape::encode_safe(tooltip)
+            );
+        }
+
+        // Character escaping for XML safety
+        match superscript.then(|| split_superscript(&text)).flatten() {
+            Some((base, exponent)) => {
+                self.buffer.push_str(&html_escape::encode_safe(base));
+                let _ = write!(
+                    self.buffer,
+                    r#"<tspan baseline-shift="super" font-size="{:.1}">{}</tspan>"#,
+                    font_size * 0.7,
+                    exponent
+                );
+            }
+            None => self.buffer.push_str(&html_escape::encode_safe(&text)),
+        }
+
+        let _ = self.buffer.write_str("</text>\n");
+    }
 }
 
 impl<'a> RenderBackend for SvgBackend<'a> {
@@ -51,7 +166,11 @@ impl<'a> RenderBackend for SvgBackend<'a> {
     // =========================================================================
 
     fn begin_clip_scope(&mut self, rect: &crate::coordinate::Rect) {
-        let id = "plot-clip-area";
+        let id = match self.clip_count {
+            0 => "plot-clip-area".to_string(),
+            n => format!("plot-clip-area-{}", n),
+        };
+        self.clip_count += 1;
         // Define the clipPath inside a structural defs block
         let _ = writeln!(
             self.buffer,
@@ -63,10 +182,30 @@ impl<'a> RenderBackend for SvgBackend<'a> {
     }
 
     fn end_clip_scope(&mut self) {
+        // A link left open would otherwise straddle the group boundary.
+        self.set_href(None);
         // Terminate the isolated grouping context
         let _ = self.buffer.write_str("</g>\n");
     }
 
+    fn set_tooltip(&mut self, tooltip: Option<String>) {
+        self.tooltip = tooltip;
+    }
+
+    fn set_href(&mut self, href: Option<String>) {
+        if self.href.is_some() {
+            let _ = self.buffer.write_str("</a>\n");
+        }
+        if let Some(url) = &href {
+            let _ = writeln!(
+                self.buffer,
+                r#"<a href="{}" target="_blank" style="cursor: pointer">"#,
+                html_escape::encode_double_quoted_attribute(url)
+            );
+        }
+        self.href = href;
+    }
+
     // =========================================================================
     // 🎨 SHAPE DRAWING METHODS
     // =========================================================================
@@ -95,10 +234,10 @@ impl<'a> RenderBackend for SvgBackend<'a> {
         self.write_color(&stroke);
         let _ = write!(
             self.buffer,
-            r#"" stroke-width="{:.3}" fill-opacity="{:.3}" stroke-opacity="{:.3}" />"#,
+            r#"" stroke-width="{:.3}" fill-opacity="{:.3}" stroke-opacity="{:.3}""#,
             stroke_width, opacity, opacity
         );
-        let _ = self.buffer.write_str("\n");
+        self.close_element("circle");
     }
 
     fn draw_rect(&mut self, config: RectConfig) {
@@ -126,10 +265,10 @@ impl<'a> RenderBackend for SvgBackend<'a> {
         self.write_color(&stroke);
         let _ = write!(
             self.buffer,
-            r#"" stroke-width="{:.3}" fill-opacity="{:.3}" stroke-opacity="{:.3}" />"#,
+            r#"" stroke-width="{:.3}" fill-opacity="{:.3}" stroke-opacity="{:.3}""#,
             stroke_width, opacity, 1.0
         );
-        let _ = self.buffer.write_str("\n");
+        self.close_element("rect");
     }
 
     fn draw_path(&mut self, config: PathConfig) {
@@ -191,8 +330,7 @@ impl<'a> RenderBackend for SvgBackend<'a> {
             let _ = write!(self.buffer, r#" stroke-dasharray="{}""#, dash_str.join(","));
         }
 
-        let _ = self.buffer.write_str(r#" />"#);
-        let _ = self.buffer.write_str("\n");
+        self.close_element("path");
     }
 
     fn draw_polygon(&mut self, config: PolygonConfig) {
@@ -224,49 +362,18 @@ impl<'a> RenderBackend for SvgBackend<'a> {
         self.write_color(&stroke);
         let _ = write!(
             self.buffer,
-            r#"" stroke-width="{:.3}" fill-opacity="{:.3}" />"#,
+            r#"" stroke-width="{:.3}" fill-opacity="{:.3}""#,
             stroke_width, opacity
         );
-        let _ = self.buffer.write_str("\n");
+        self.close_element("polygon");
     }
 
     fn draw_text(&mut self, config: TextConfig) {
-        let TextConfig {
-            x,
-            y,
-            text,
-            font_size,
-            font_family,
-            color,
-            text_anchor,
-            font_weight,
-            dominant_baseline,
-            opacity,
-            angle,
-        } = config;
-
-        let _ = write!(
-            self.buffer,
-            r#"<text x="{:.3}" y="{:.3}" font-size="{:.1}" font-family="{}" fill=""#,
-            x, y, font_size, font_family
-        );
-        self.write_color(&color);
-        let _ = write!(
-            self.buffer,
-            r#"" fill-opacity="{:.3}" text-anchor="{}" font-weight="{}" dominant-baseline="{}""#,
-            opacity, text_anchor, font_weight, dominant_baseline
-        );
-        let _ = write!(
-            self.buffer,
-            r#" transform="rotate({} {:.3} {:.3})""#,
-            angle, x, y
-        );
-        let _ = self.buffer.write_str(">");
-
-        // Character escaping for XML safety
-        self.buffer.push_str(&html_escape::encode_safe(&text));
+        self.write_text(config, false);
+    }
 
-        let _ = self.buffer.write_str("</text>\n");
+    fn draw_superscript_text(&mut self, config: TextConfig) {
+        self.write_text(config, true);
     }
 
     fn draw_line(&mut self, config: LineConfig) {
@@ -298,7 +405,7 @@ impl<'a> RenderBackend for SvgBackend<'a> {
             let _ = write!(self.buffer, r#" stroke-dasharray="{}""#, dash_str.join(","));
         }
 
-        let _ = self.buffer.write_str(" />\n");
+        self.close_element("line");
     }
 
     fn draw_gradient_rect(&mut self, config: GradientRectConfig) {
@@ -6,6 +6,11 @@ use crate::core::layer::{MarkRenderer, PathConfig, PathTopology, RenderBackend,
 use crate::encode::y::StackMode;
 use crate::error::ChartonError;
 use crate::mark::bar::MarkBar;
+use crate::mark::stack_label::StackLabelPart;
+use crate::mark::zero_line::ZeroLinePosition;
+use crate::render::stack_label_renderer::{StackFormat, StackSegment, render_stack_label};
+use crate::render::zero_line_renderer::render_zero_line;
+use crate::scale::Scale;
 use crate::visual::color::SingleColor;
 use ahash::AHashMap;
 
@@ -43,6 +48,12 @@ impl MarkRenderer for Chart<MarkBar> {
 
         let is_stacked = y_enc.stack != StackMode::None;
         let is_pie_mode = x_enc.field.is_empty();
+        // Dodged sub-bars hold their own stacks; `sub_idx` then indexes the dodge value.
+        let dodge_field = x_enc
+            .dodge_by
+            .as_deref()
+            .filter(|df| !is_pie_mode && *df != x_enc.field);
+        let is_dodged = dodge_field.is_some();
         let hints = context.coord.layout_hints();
         let is_polar = hints.needs_interpolation;
         let needs_nightingale_sqrt = is_polar && !is_pie_mode;
@@ -65,20 +76,63 @@ impl MarkRenderer for Chart<MarkBar> {
         for (i, val) in x_uniques.iter().enumerate() {
             x_idx_map.insert(val.as_str(), i);
         }
-        let mut stack_acc = vec![0.0; x_uniques.len()];
+        let n_slots = if is_dodged {
+            group_counts.first().map_or(1, |&n| n as usize)
+        } else {
+            1
+        };
+        let stack_slot = |idx: usize, x_pos: usize| {
+            if is_dodged {
+                x_pos * n_slots + sub_indices[idx] as usize
+            } else {
+                x_pos
+            }
+        };
+        let mut stack_acc = vec![0.0; x_uniques.len() * n_slots];
+
+        // Stack totals and the value notation, for segment labels and tooltips.
+        let label_stacks = is_stacked && !is_pie_mode && !mark_config.stack_label_parts.is_empty();
+        let tooltip_stacks = is_stacked && !is_pie_mode && self.encoding.tooltip.is_some();
+        let tooltip_parts = if mark_config.stack_label_parts.is_empty() {
+            &StackLabelPart::TOOLTIP_DEFAULT[..]
+        } else {
+            &mark_config.stack_label_parts[..]
+        };
+        let mut stack_totals = vec![0.0; x_uniques.len() * n_slots];
+        let mut stack_format = None;
+        if label_stacks || tooltip_stacks {
+            for (idx, y_val) in y_values.iter().enumerate() {
+                let x_str = ds.get(&x_enc.field, idx).to_string().unwrap_or_default();
+                if let Some(&x_pos) = x_idx_map.get(x_str.as_str()) {
+                    stack_totals[stack_slot(idx, x_pos)] += y_val;
+                }
+            }
+            let normalized = y_enc.normalize || y_enc.stack == StackMode::Normalize;
+            stack_format = Some(StackFormat::new(context, &y_enc.field, normalized));
+        }
 
         // Visual Parameters
         let eff_width = mark_config.width.unwrap_or(hints.default_bar_width);
         let eff_spacing = mark_config.spacing.unwrap_or(hints.default_bar_spacing);
         let eff_span = mark_config.span.unwrap_or(hints.default_bar_span);
-        let unit_step_norm = (x_scale.normalize(1.0) - x_scale.normalize(0.0)).abs();
+        let unit_step_norm = if matches!(x_scale.scale_type(), Scale::Temporal) {
+            temporal_step_norm(&x_norms)
+        } else {
+            (x_scale.normalize(1.0) - x_scale.normalize(0.0)).abs()
+        };
 
         // Color Mapping
-        let color_norms = context.spec.aesthetics.color.as_ref().map(|c| {
-            c.scale_impl
-                .scale_type()
-                .normalize_column(c.scale_impl.as_ref(), ds.column(&c.field).unwrap())
-        });
+        let color_norms = context
+            .spec
+            .aesthetics
+            .color
+            .as_ref()
+            .map(|c| -> Result<_, ChartonError> {
+                Ok(c.scale_impl
+                    .scale_type()
+                    .normalize_column(c.scale_impl.as_ref(), ds.column(&c.field)?))
+            })
+            .transpose()?;
 
         let global_total = if is_pie_mode {
             y_values.iter().sum::<f64>().max(1.0)
@@ -86,6 +140,17 @@ impl MarkRenderer for Chart<MarkBar> {
             1.0
         };
 
+        // The zero line only makes sense on a straight value axis.
+        let draws_zero_line = !is_pie_mode && !is_polar;
+        if draws_zero_line {
+            render_zero_line(
+                &mark_config.zero_line,
+                ZeroLinePosition::Under,
+                backend,
+                context,
+            );
+        }
+
         // --- STEP 3: Linear Rendering Loop ---
         // No more group_by! We process rows as a flat stream.
         for idx in 0..row_count {
@@ -98,11 +163,13 @@ impl MarkRenderer for Chart<MarkBar> {
             let n_groups = group_counts[idx];
 
             // A: Resolve Y-Bounds
+            let mut stack_top = (0.0, 1.0);
             let (y_low_n, y_high_n) = if is_stacked {
-                let x_pos = *x_idx_map.get(x_str.as_str()).unwrap_or(&0);
-                let start = stack_acc[x_pos];
+                let slot = stack_slot(idx, *x_idx_map.get(x_str.as_str()).unwrap_or(&0));
+                let start = stack_acc[slot];
                 let end = start + y_val;
-                stack_acc[x_pos] = end;
+                stack_acc[slot] = end;
+                stack_top = (end, stack_totals[slot]);
 
                 if needs_nightingale_sqrt {
                     (
@@ -125,7 +192,8 @@ impl MarkRenderer for Chart<MarkBar> {
             };
 
             // B: Resolve X-Position using Helper Columns
-            let bar_width_data = if is_stacked || n_groups <= 1.0 {
+            let side_by_side = !is_stacked || is_dodged;
+            let bar_width_data = if !side_by_side || n_groups <= 1.0 {
                 eff_width.min(eff_span)
             } else {
                 eff_span / (n_groups + (n_groups - 1.0) * eff_spacing)
@@ -136,7 +204,7 @@ impl MarkRenderer for Chart<MarkBar> {
 
             let (offset_norm, final_bar_width_norm) = if is_polar && !is_pie_mode && !is_stacked {
                 (0.0, eff_span * unit_step_norm) // Rose overlay mode
-            } else if !is_stacked && n_groups > 1.0 {
+            } else if side_by_side && n_groups > 1.0 {
                 // Simplified Dodge Calculation using sub_idx helper
                 let offset = (sub_idx - (n_groups - 1.0) / 2.0) * (bar_width_norm + spacing_norm);
                 (offset, bar_width_norm)
@@ -187,6 +255,22 @@ impl MarkRenderer for Chart<MarkBar> {
             let color_val = color_norms.as_ref().and_then(|cn| cn[idx]);
             let final_color = self.resolve_color_from_value(color_val, context, &mark_config.color);
 
+            let (cumulative, total) = stack_top;
+            let segment = StackSegment {
+                value: y_val,
+                cumulative,
+                total,
+            };
+            let mut tooltip = self.tooltip_text(idx, context.spec.formats);
+            if tooltip_stacks && let Some(format) = &stack_format {
+                let stack_lines = format.tooltip(tooltip_parts, segment);
+                tooltip = Some(match tooltip {
+                    Some(text) => format!("{}\n{}", text, stack_lines),
+                    None => stack_lines,
+                });
+            }
+            backend.set_tooltip(tooltip);
+            backend.set_href(self.href_url(idx));
             backend.draw_path(PathConfig {
                 points: pixel_points,
                 fill: final_color,
@@ -199,8 +283,39 @@ impl MarkRenderer for Chart<MarkBar> {
                 dash: vec![],                    // Sold line by default
                 topology: PathTopology::Complex, // Use Stencil-and-cover algorithm when using WGPU
             });
+            backend.set_tooltip(None);
+            backend.set_href(None);
+
+            // E: Labels for stacked segments
+            if label_stacks && let Some(format) = &stack_format {
+                render_stack_label(
+                    format.label(&mark_config.stack_label_parts, segment),
+                    (x_center_n, y_low_n, y_high_n),
+                    context,
+                    backend,
+                    mark_config.opacity as Precision,
+                );
+            }
+
+            // F: Dodge labels above each completed sub-bar
+            if let Some(df) = dodge_field
+                && !is_polar
+                && (idx + 1 == row_count
+                    || sub_indices[idx + 1] != sub_idx
+                    || ds.get(&x_enc.field, idx + 1).to_string().as_ref() != Some(&x_str))
+            {
+                let base_n = y_scale.normalize(0.0);
+                let label = ds.get(df, idx).to_string().unwrap_or_default();
+                self.render_dodge_label(
+                    label,
+                    (x_center_n, base_n, y_high_n),
+                    context,
+                    backend,
+                    mark_config.opacity as Precision,
+                );
+            }
 
-            // E: Labels for Pie
+            // G: Labels for Pie
             if is_pie_mode {
                 self.render_pie_label(
                     y_val,
@@ -217,6 +332,15 @@ impl MarkRenderer for Chart<MarkBar> {
             }
         }
 
+        if draws_zero_line {
+            render_zero_line(
+                &mark_config.zero_line,
+                ZeroLinePosition::Over,
+                backend,
+                context,
+            );
+        }
+
         Ok(())
     }
 }
@@ -240,6 +364,53 @@ impl Chart<MarkBar> {
         }
     }
 
+    /// Names a dodged sub-bar just beyond the end of its stack.
+    fn render_dodge_label(
+        &self,
+        text: String,
+        (x_center_n, base_n, top_n): (f64, f64, f64),
+        context: &PanelContext,
+        backend: &mut dyn RenderBackend,
+        opacity: Precision,
+    ) {
+        let theme = &context.spec.theme;
+        let font_size = theme.tick_label_size - 1.0;
+
+        // Step outward along the bar direction, whichever way the stack grows.
+        let (bx, by) = context.coord.transform(x_center_n, base_n, &context.panel);
+        let (tx, ty) = context.coord.transform(x_center_n, top_n, &context.panel);
+        let len = (tx - bx).hypot(ty - by);
+        let (dx, dy) = if len > 0.0 {
+            ((tx - bx) / len, (ty - by) / len)
+        } else if context.coord.is_flipped() {
+            (1.0, 0.0)
+        } else {
+            (0.0, -1.0)
+        };
+        let gap = if dx.abs() > dy.abs() {
+            crate::core::utils::estimate_text_width(&text, font_size) / 2.0 + 4.0
+        } else {
+            font_size * 0.8
+        };
+
+        backend.draw_text(TextConfig {
+            x: (tx + dx * gap) as Precision,
+            y: (ty + dy * gap) as Precision,
+            text,
+            font_size: font_size as Precision,
+            font_family: theme.tick_label_family.clone(),
+            color: theme.tick_label_color,
+            text_anchor: "middle".into(),
+            dominant_baseline: "middle".into(),
+            font_weight: theme.tick_label_font_weight.to_string(),
+            font_style: theme.tick_label_font_style.to_string(),
+            opacity,
+            angle: 0.0,
+            direction: theme.text_direction.as_str().to_string(),
+            writing_mode: "horizontal-tb".to_string(),
+        });
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn render_pie_label(
         &self,
@@ -279,9 +450,30 @@ impl Chart<MarkBar> {
                 text_anchor: "middle".into(),
                 dominant_baseline: "middle".into(),
                 font_weight: "bold".into(),
+                font_style: "normal".into(),
                 opacity: opacity as Precision,
                 angle: 0.0,
+                direction: theme.text_direction.as_str().to_string(),
+                writing_mode: "horizontal-tb".to_string(),
             });
         }
     }
 }
+
+/// The normalized width of one step on a temporal axis.
+///
+/// One unit of a temporal scale is a nanosecond, so bars are sized by the
+/// smallest gap between distinct timestamps instead: daily data gets day-wide
+/// steps and resampled periods sit side by side. A single timestamp falls back
+/// to a tenth of the axis.
+fn temporal_step_norm(x_norms: &[Option<f64>]) -> f64 {
+    let mut positions: Vec<f64> = x_norms.iter().flatten().copied().collect();
+    positions.sort_unstable_by(f64::total_cmp);
+    positions.dedup();
+    positions
+        .windows(2)
+        .map(|w| w[1] - w[0])
+        .filter(|gap| *gap > 0.0)
+        .reduce(f64::min)
+        .unwrap_or(0.1)
+}
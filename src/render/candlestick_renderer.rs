@@ -0,0 +1,147 @@
+use crate::Precision;
+use crate::chart::Chart;
+use crate::core::context::PanelContext;
+use crate::core::layer::{LineConfig, MarkRenderer, RectConfig, RenderBackend};
+use crate::core::utils::IntoParallelizable;
+use crate::error::ChartonError;
+use crate::mark::candlestick::MarkCandlestick;
+use crate::scale::Scale;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+impl MarkRenderer for Chart<MarkCandlestick> {
+    /// Renders one wick (low to high) and one body (open to close) per row.
+    fn render_marks(
+        &self,
+        backend: &mut dyn RenderBackend,
+        context: &PanelContext,
+    ) -> Result<(), ChartonError> {
+        let df_source = &self.data;
+        let row_count = df_source.height();
+        if row_count == 0 {
+            return Ok(());
+        }
+
+        // --- STEP 1: INITIALIZATION & VALIDATION ---
+        let mark_config = self
+            .mark
+            .as_ref()
+            .ok_or_else(|| ChartonError::Mark("Candlestick config missing".into()))?;
+        let x_enc = self
+            .encoding
+            .x
+            .as_ref()
+            .ok_or_else(|| ChartonError::Encoding("X encoding missing".into()))?;
+        let ohlc_field = |field: Option<&String>, name: &str| {
+            field
+                .cloned()
+                .ok_or_else(|| ChartonError::Encoding(format!("{} encoding missing", name)))
+        };
+        let open_field = ohlc_field(self.encoding.open.as_ref().map(|e| &e.field), "Open")?;
+        let high_field = ohlc_field(self.encoding.high.as_ref().map(|e| &e.field), "High")?;
+        let low_field = ohlc_field(self.encoding.low.as_ref().map(|e| &e.field), "Low")?;
+        let close_field = ohlc_field(self.encoding.close.as_ref().map(|e| &e.field), "Close")?;
+
+        let x_scale = context.coord.get_x_scale();
+        let y_scale = context.coord.get_y_scale();
+
+        // --- STEP 2: DATA NORMALIZATION ---
+        let x_norms = x_scale
+            .scale_type()
+            .normalize_column(x_scale, df_source.column(&x_enc.field)?);
+        let normalize_y = |field: &str| -> Result<Vec<Option<f64>>, ChartonError> {
+            Ok(y_scale
+                .scale_type()
+                .normalize_column(y_scale, df_source.column(field)?))
+        };
+        let open_norms = normalize_y(&open_field)?;
+        let high_norms = normalize_y(&high_field)?;
+        let low_norms = normalize_y(&low_field)?;
+        let close_norms = normalize_y(&close_field)?;
+
+        // Bodies fill a fraction of the distance between neighbouring candles:
+        // one category step on a discrete axis, the closest pair otherwise.
+        let step_norm = if x_scale.scale_type() == Scale::Discrete {
+            (x_scale.normalize(1.0) - x_scale.normalize(0.0)).abs()
+        } else {
+            let mut positions: Vec<f64> = x_norms.iter().flatten().copied().collect();
+            positions.sort_by(|a, b| a.total_cmp(b));
+            positions
+                .windows(2)
+                .map(|w| w[1] - w[0])
+                .filter(|d| *d > 1e-12)
+                .fold(f64::INFINITY, f64::min)
+        };
+        let half_width_n = if step_norm.is_finite() {
+            step_norm * mark_config.width / 2.0
+        } else {
+            0.05 * mark_config.width
+        };
+        let stroke_width = mark_config.stroke_width as Precision;
+
+        // --- STEP 3: PARALLEL GEOMETRY COMPUTATION ---
+        let candles: Vec<(usize, LineConfig, RectConfig)> = (0..row_count)
+            .maybe_into_par_iter()
+            .filter_map(|i| {
+                let x_n = x_norms[i]?;
+                let (open_n, high_n) = (open_norms[i]?, high_norms[i]?);
+                let (low_n, close_n) = (low_norms[i]?, close_norms[i]?);
+
+                // Normalization is monotonic, so comparing normalized prices is enough.
+                let color = if close_n >= open_n {
+                    mark_config.bull_color
+                } else {
+                    mark_config.bear_color
+                };
+
+                let project = |xn: f64, yn: f64| {
+                    let (px, py) = context.coord.transform(xn, yn, &context.panel);
+                    (px as Precision, py as Precision)
+                };
+
+                let (wx1, wy1) = project(x_n, low_n);
+                let (wx2, wy2) = project(x_n, high_n);
+                let wick = LineConfig {
+                    x1: wx1,
+                    y1: wy1,
+                    x2: wx2,
+                    y2: wy2,
+                    color,
+                    width: stroke_width,
+                    opacity: mark_config.opacity as Precision,
+                    dash: vec![],
+                };
+
+                let (bx1, by1) = project(x_n - half_width_n, open_n);
+                let (bx2, by2) = project(x_n + half_width_n, close_n);
+                // A flat candle (open == close) still shows as a thin bar.
+                let body = RectConfig {
+                    x: bx1.min(bx2),
+                    y: by1.min(by2),
+                    width: (bx1 - bx2).abs().max(stroke_width),
+                    height: (by1 - by2).abs().max(stroke_width),
+                    fill: color,
+                    stroke: color,
+                    stroke_width,
+                    opacity: mark_config.opacity as Precision,
+                };
+
+                Some((i, wick, body))
+            })
+            .collect();
+
+        // --- STEP 4: SEQUENTIAL RENDERING ---
+        // Wicks go first so that bodies cover their middle section.
+        for (i, wick, body) in candles {
+            backend.set_tooltip(self.tooltip_text(i, context.spec.formats));
+            backend.set_href(self.href_url(i));
+            backend.draw_line(wick);
+            backend.draw_rect(body);
+        }
+        backend.set_tooltip(None);
+        backend.set_href(None);
+
+        Ok(())
+    }
+}
@@ -1,9 +1,10 @@
 use crate::Precision;
-use crate::coordinate::{CoordinateTrait, Rect, cartesian::Cartesian2D};
+use crate::coordinate::{CoordinateTrait, GridLines, Rect, cartesian::Cartesian2D};
 use crate::core::layer::{LineConfig, PathConfig, PathTopology, RenderBackend, TextConfig};
 use crate::error::ChartonError;
 use crate::scale::ExplicitTick;
-use crate::theme::Theme;
+use crate::scale::nice_ticks::axis_ticks;
+use crate::theme::{TextOrientation, Theme};
 
 /// Orchestrates the visual rendering of both horizontal and vertical axes for a panel.
 ///
@@ -53,6 +54,119 @@ pub fn render_cartesian_axes(
     Ok(())
 }
 
+/// Renders a secondary Y-axis along the right edge of the panel.
+///
+/// Ticks are generated from the y scale of `coord` alone, so they are
+/// independent of the primary axis. Tick marks and labels point outwards and
+/// the title is placed beyond the widest label.
+pub fn render_secondary_y_axis(
+    backend: &mut dyn RenderBackend,
+    theme: &Theme,
+    panel: &Rect,
+    coord: &dyn CoordinateTrait,
+    label: &str,
+) -> Result<(), ChartonError> {
+    let scale = coord.get_y_scale();
+    let x = panel.x + panel.width;
+    let tick_len = 6.0;
+    let title_gap = 5.0;
+
+    // 1. Spine
+    backend.draw_path(PathConfig {
+        points: vec![
+            (x as Precision, panel.y as Precision),
+            (x as Precision, (panel.y + panel.height) as Precision),
+        ],
+        fill: "none".into(),
+        stroke: theme.axes_color,
+        stroke_width: theme.axis_width as Precision,
+        opacity: 1.0,
+        dash: vec![],
+        topology: PathTopology::Simple,
+    });
+
+    // 2. Ticks and labels
+    let ticks = axis_ticks(
+        scale,
+        theme.y_tick_count,
+        theme.suggest_tick_count(panel.height),
+    );
+    let angle_rad = theme.y_tick_label_angle.to_radians();
+    let mut max_tick_width: f64 = 0.0;
+
+    for tick in ticks {
+        let py = panel.y + (1.0 - scale.normalize(tick.value)) * panel.height;
+
+        backend.draw_path(PathConfig {
+            points: vec![
+                (x as Precision, py as Precision),
+                ((x + tick_len) as Precision, py as Precision),
+            ],
+            fill: "none".into(),
+            stroke: theme.tick_color,
+            stroke_width: theme.tick_width as Precision,
+            opacity: 1.0,
+            dash: vec![],
+            topology: PathTopology::Simple,
+        });
+
+        let w = crate::core::utils::estimate_text_width(&tick.label, theme.tick_label_size);
+        let h = theme.tick_label_size;
+        max_tick_width = max_tick_width.max(w * angle_rad.cos().abs() + h * angle_rad.sin().abs());
+
+        backend.draw_superscript_text(TextConfig {
+            text: tick.label,
+            x: (x + tick_len + theme.tick_label_padding + 1.0) as Precision,
+            y: py as Precision,
+            font_size: theme.tick_label_size as Precision,
+            font_family: theme.tick_label_family.clone(),
+            color: theme.tick_label_color,
+            text_anchor: "start".to_string(),
+            dominant_baseline: "central".to_string(),
+            font_weight: theme.tick_label_font_weight.to_string(),
+            font_style: theme.tick_label_font_style.to_string(),
+            opacity: 1.0,
+            angle: theme.y_tick_label_angle as Precision,
+            direction: theme.text_direction.as_str().to_string(),
+            writing_mode: "horizontal-tb".to_string(),
+        });
+    }
+
+    // 3. Title, reading top to bottom on the right-hand side
+    if label.is_empty() {
+        return Ok(());
+    }
+    let h_offset = tick_len
+        + theme.tick_label_padding
+        + max_tick_width
+        + theme.label_padding
+        + title_gap
+        + (theme.label_size / 2.0);
+    let (angle, baseline, writing_mode) = match theme.y_label_orientation {
+        TextOrientation::Rotated => (90.0, "middle", "horizontal-tb"),
+        TextOrientation::UprightVertical => (0.0, "central", "vertical-rl"),
+    };
+
+    backend.draw_text(TextConfig {
+        x: (x + h_offset) as Precision,
+        y: (panel.y + panel.height / 2.0) as Precision,
+        text: label.to_string(),
+        font_size: theme.label_size as Precision,
+        font_family: theme.label_family.clone(),
+        color: theme.label_color,
+        text_anchor: "middle".to_string(),
+        dominant_baseline: baseline.to_string(),
+        font_weight: theme.label_font_weight.to_string(),
+        font_style: theme.label_font_style.to_string(),
+        opacity: 1.0,
+        angle,
+        direction: theme.text_direction.as_str().to_string(),
+        writing_mode: writing_mode.to_string(),
+    });
+
+    Ok(())
+}
+
 /// Renders the straight line (spine) of the axis.
 fn draw_axis_line(
     backend: &mut dyn RenderBackend,
@@ -102,29 +216,60 @@ fn draw_ticks_and_labels(
     let is_flipped = coord.is_flipped();
 
     // 1. Select logical scale based on coordinate orientation
-    let target_scale = if is_flipped {
+    let (target_scale, tick_count) = if is_flipped {
         if is_bottom {
-            coord.get_y_scale()
+            (coord.get_y_scale(), theme.y_tick_count)
         } else {
-            coord.get_x_scale()
+            (coord.get_x_scale(), theme.x_tick_count)
         }
     } else if is_bottom {
-        coord.get_x_scale()
+        (coord.get_x_scale(), theme.x_tick_count)
     } else {
-        coord.get_y_scale()
+        (coord.get_y_scale(), theme.y_tick_count)
     };
 
-    // 2. Generate ticks based on available pixel space
+    // 2. Generate ticks based on the requested count or the available pixel space
     let ticks = match explicit_ticks {
         Some(explicit) => target_scale.create_explicit_ticks(explicit),
         None => {
             let available_space = if is_bottom { panel.width } else { panel.height };
-            target_scale.suggest_ticks(theme.suggest_tick_count(available_space))
+            axis_ticks(
+                target_scale,
+                tick_count,
+                theme.suggest_tick_count(available_space),
+            )
         }
     };
 
     let tick_len = 6.0;
 
+    // --- MINOR TICKS (half length, unlabeled) ---
+    if theme.minor_ticks && explicit_ticks.is_none() && tick_count != Some(0) {
+        for value in target_scale.minor_ticks() {
+            let norm_pos = target_scale.normalize(value);
+            let (px, py, x2, y2) = if is_bottom {
+                let px = panel.x + norm_pos * panel.width;
+                let py = panel.y + panel.height;
+                (px, py, px, py + tick_len / 2.0)
+            } else {
+                let py = panel.y + (1.0 - norm_pos) * panel.height;
+                (panel.x, py, panel.x - tick_len / 2.0, py)
+            };
+            backend.draw_path(PathConfig {
+                points: vec![
+                    (px as Precision, py as Precision),
+                    (x2 as Precision, y2 as Precision),
+                ],
+                fill: "none".into(),
+                stroke: theme.tick_color,
+                stroke_width: theme.tick_width as Precision,
+                opacity: 1.0,
+                dash: vec![],
+                topology: PathTopology::Simple,
+            });
+        }
+    }
+
     // 3. Resolve rotation angle for tick labels
     let angle = if is_bottom {
         if is_flipped {
@@ -185,7 +330,7 @@ fn draw_ticks_and_labels(
             )
         };
 
-        backend.draw_text(TextConfig {
+        backend.draw_superscript_text(TextConfig {
             text: tick.label.clone(),
             x: (px + dx) as Precision,
             y: (py + dy) as Precision,
@@ -194,9 +339,12 @@ fn draw_ticks_and_labels(
             color: theme.tick_label_color,
             text_anchor: anchor.to_string(),
             dominant_baseline: baseline.to_string(),
-            font_weight: "normal".to_string(), // Ticks usually use normal weight
+            font_weight: theme.tick_label_font_weight.to_string(),
+            font_style: theme.tick_label_font_style.to_string(),
             opacity: 1.0,
             angle: angle as Precision,
+            direction: theme.text_direction.as_str().to_string(),
+            writing_mode: "horizontal-tb".to_string(),
         });
     }
     Ok(())
@@ -220,21 +368,37 @@ fn draw_axis_title(
     let title_gap = 5.0;
 
     // Resolve which angle and scale are mapped to this physical axis.
-    let (angle_rad, target_scale) = if is_flipped {
+    let (angle_rad, target_scale, tick_count) = if is_flipped {
         if is_bottom {
-            (theme.y_tick_label_angle.to_radians(), coord.get_y_scale())
+            (
+                theme.y_tick_label_angle.to_radians(),
+                coord.get_y_scale(),
+                theme.y_tick_count,
+            )
         } else {
-            (theme.x_tick_label_angle.to_radians(), coord.get_x_scale())
+            (
+                theme.x_tick_label_angle.to_radians(),
+                coord.get_x_scale(),
+                theme.x_tick_count,
+            )
         }
     } else if is_bottom {
-        (theme.x_tick_label_angle.to_radians(), coord.get_x_scale())
+        (
+            theme.x_tick_label_angle.to_radians(),
+            coord.get_x_scale(),
+            theme.x_tick_count,
+        )
     } else {
-        (theme.y_tick_label_angle.to_radians(), coord.get_y_scale())
+        (
+            theme.y_tick_label_angle.to_radians(),
+            coord.get_y_scale(),
+            theme.y_tick_count,
+        )
     };
 
     let available_space = if is_bottom { panel.width } else { panel.height };
     let final_count = theme.suggest_tick_count(available_space);
-    let ticks = target_scale.suggest_ticks(final_count);
+    let ticks = axis_ticks(target_scale, tick_count, final_count);
 
     if is_bottom {
         let x = panel.x + panel.width / 2.0;
@@ -262,9 +426,12 @@ fn draw_axis_title(
             color: theme.label_color,
             text_anchor: "middle".to_string(),
             dominant_baseline: "hanging".to_string(),
-            font_weight: "bold".to_string(),
+            font_weight: theme.label_font_weight.to_string(),
+            font_style: theme.label_font_style.to_string(),
             opacity: 1.0,
             angle: 0.0,
+            direction: theme.text_direction.as_str().to_string(),
+            writing_mode: "horizontal-tb".to_string(),
         });
     } else {
         let y = panel.y + panel.height / 2.0;
@@ -288,6 +455,13 @@ fn draw_axis_title(
             + 3.0;
         let x = panel.x - h_offset;
 
+        // Upright vertical titles occupy one em across, just like rotated ones,
+        // so the same offset applies to both orientations.
+        let (angle, baseline, writing_mode) = match theme.y_label_orientation {
+            TextOrientation::Rotated => (-90.0, "middle", "horizontal-tb"), // Rotate CCW
+            TextOrientation::UprightVertical => (0.0, "central", "vertical-rl"),
+        };
+
         backend.draw_text(TextConfig {
             x: x as Precision,
             y: y as Precision,
@@ -296,10 +470,13 @@ fn draw_axis_title(
             font_family: theme.label_family.clone(),
             color: theme.label_color,
             text_anchor: "middle".to_string(),
-            dominant_baseline: "middle".to_string(),
-            font_weight: "bold".to_string(),
+            dominant_baseline: baseline.to_string(),
+            font_weight: theme.label_font_weight.to_string(),
+            font_style: theme.label_font_style.to_string(),
             opacity: 1.0,
-            angle: -90.0, // Rotate Counter-Clockwise(CCW) for vertical alignment
+            angle,
+            direction: theme.text_direction.as_str().to_string(),
+            writing_mode: writing_mode.to_string(),
         });
     }
 
@@ -309,16 +486,25 @@ fn draw_axis_title(
 /// Renders the underlying grid lines for a 2D Cartesian coordinate system.
 ///
 /// This must be called before `layer.render_marks` to keep grid lines in the background.
+/// Lines sit exactly on the ticks of the axis they belong to; after `coord_flip()`
+/// the x grid runs horizontally.
 #[allow(clippy::too_many_arguments)]
 pub fn render_cartesian_grid(
     backend: &mut dyn RenderBackend,
     theme: &Theme,
     panel: &Rect,
     coord: &Cartesian2D,
+    lines: GridLines,
     x_explicit: Option<&[ExplicitTick]>,
     y_explicit: Option<&[ExplicitTick]>,
 ) -> Result<(), ChartonError> {
     let is_flipped = coord.is_flipped();
+    let (show_bottom, show_left) = if is_flipped {
+        (lines.y, lines.x)
+    } else {
+        (lines.x, lines.y)
+    };
+    let dash: Vec<Precision> = theme.grid_dash.iter().map(|&d| d as Precision).collect();
 
     // ------------------------------------------------------------------------
     // 1. Render vertical grid lines (Bottom axis ticks)
@@ -331,8 +517,17 @@ pub fn render_cartesian_grid(
     let bottom_explicit = if is_flipped { y_explicit } else { x_explicit };
 
     let x_ticks = match bottom_explicit {
+        _ if !show_bottom => vec![],
         Some(explicit) => bottom_scale.create_explicit_ticks(explicit),
-        None => bottom_scale.suggest_ticks(theme.suggest_tick_count(panel.width)),
+        None => axis_ticks(
+            bottom_scale,
+            if is_flipped {
+                theme.y_tick_count
+            } else {
+                theme.x_tick_count
+            },
+            theme.suggest_tick_count(panel.width),
+        ),
     };
 
     for tick in x_ticks {
@@ -348,8 +543,8 @@ pub fn render_cartesian_grid(
             y2: (panel.y + panel.height) as Precision,
             color: theme.grid_color,
             width: theme.grid_width as Precision,
-            opacity: 0.5,
-            dash: vec![], // Can be replaced with theme.grid_dash.clone() if customized
+            opacity: theme.grid_opacity.unwrap_or(0.5) as Precision,
+            dash: dash.clone(),
         });
     }
 
@@ -364,8 +559,17 @@ pub fn render_cartesian_grid(
     let left_explicit = if is_flipped { x_explicit } else { y_explicit };
 
     let y_ticks = match left_explicit {
+        _ if !show_left => vec![],
         Some(explicit) => left_scale.create_explicit_ticks(explicit),
-        None => left_scale.suggest_ticks(theme.suggest_tick_count(panel.height)),
+        None => axis_ticks(
+            left_scale,
+            if is_flipped {
+                theme.x_tick_count
+            } else {
+                theme.y_tick_count
+            },
+            theme.suggest_tick_count(panel.height),
+        ),
     };
 
     for tick in y_ticks {
@@ -380,8 +584,8 @@ pub fn render_cartesian_grid(
             y2: canvas_y as Precision,
             color: theme.grid_color,
             width: theme.grid_width as Precision,
-            opacity: 0.5,
-            dash: vec![],
+            opacity: theme.grid_opacity.unwrap_or(0.5) as Precision,
+            dash: dash.clone(),
         });
     }
 
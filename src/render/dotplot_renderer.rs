@@ -0,0 +1,177 @@
+use crate::Precision;
+use crate::chart::Chart;
+use crate::core::context::PanelContext;
+use crate::core::layer::{CircleConfig, LineConfig, MarkRenderer, RenderBackend};
+use crate::error::ChartonError;
+use crate::mark::dotplot::MarkDotPlot;
+use crate::visual::color::SingleColor;
+
+// ============================================================================
+// MARK RENDERING (Cleveland Dot Plot Implementation)
+// ============================================================================
+
+impl MarkRenderer for Chart<MarkDotPlot> {
+    /// Renders a Cleveland dot plot in two passes:
+    ///
+    /// 1. **Guide lines**: Without `y2`, one line per category runs from the zero
+    ///    baseline (clamped to the panel) to the farthest dot of that category, so
+    ///    multi-series plots share a single guide. With `y2`, every row gets its own
+    ///    line spanning `y2` to `y` (range dots).
+    /// 2. **Dots**: Drawn on top of the guides, colored by the color aesthetic if mapped.
+    fn render_marks(
+        &self,
+        backend: &mut dyn RenderBackend,
+        context: &PanelContext,
+    ) -> Result<(), ChartonError> {
+        let df_source = &self.data;
+        let row_count = df_source.height();
+        if row_count == 0 {
+            return Ok(());
+        }
+
+        // --- STEP 1: SPECIFICATION VALIDATION ---
+        let x_enc = self
+            .encoding
+            .x
+            .as_ref()
+            .ok_or_else(|| ChartonError::Encoding("X encoding is missing".into()))?;
+        let y_enc = self
+            .encoding
+            .y
+            .as_ref()
+            .ok_or_else(|| ChartonError::Encoding("Y encoding is missing".into()))?;
+        let mark_config = self
+            .mark
+            .as_ref()
+            .ok_or_else(|| ChartonError::Mark("MarkDotPlot configuration is missing".into()))?;
+
+        // --- STEP 2: POSITION & AESTHETIC NORMALIZATION ---
+        let x_scale = context.coord.get_x_scale();
+        let y_scale = context.coord.get_y_scale();
+
+        let x_norms = x_scale
+            .scale_type()
+            .normalize_column(x_scale, df_source.column(&x_enc.field)?);
+        let y_norms = y_scale
+            .scale_type()
+            .normalize_column(y_scale, df_source.column(&y_enc.field)?);
+        let y2_norms = match self.encoding.y2.as_ref() {
+            Some(e) => Some(
+                y_scale
+                    .scale_type()
+                    .normalize_column(y_scale, df_source.column(&e.field)?),
+            ),
+            None => None,
+        };
+
+        let color_norms = context.spec.aesthetics.color.as_ref().and_then(|m| {
+            let s = m.scale_impl.as_ref();
+            let col = df_source.column(&m.field).ok()?;
+            Some(s.scale_type().normalize_column(s, col))
+        });
+
+        // --- STEP 3: GUIDE LINES ---
+        // Each entry is (x_norm, y_start_norm, y_end_norm).
+        let mut guides: Vec<(f64, f64, f64)> = Vec::new();
+        match &y2_norms {
+            Some(y2s) => {
+                for i in 0..row_count {
+                    if let (Some(x_n), Some(y_n), Some(y2_n)) = (x_norms[i], y_norms[i], y2s[i]) {
+                        guides.push((x_n, y2_n, y_n));
+                    }
+                }
+            }
+            None => {
+                // Lines grow from the zero baseline; if zero lies outside the domain
+                // they start at the panel edge instead.
+                let base_n = y_scale.normalize(0.0).clamp(0.0, 1.0);
+                for i in 0..row_count {
+                    let (Some(x_n), Some(y_n)) = (x_norms[i], y_norms[i]) else {
+                        continue;
+                    };
+                    match guides.iter_mut().find(|g| (g.0 - x_n).abs() < 1e-9) {
+                        Some(g) => {
+                            g.1 = g.1.min(y_n);
+                            g.2 = g.2.max(y_n);
+                        }
+                        None => guides.push((x_n, base_n.min(y_n), base_n.max(y_n))),
+                    }
+                }
+            }
+        }
+
+        if !mark_config.line_color.is_none() {
+            for (x_n, y_a, y_b) in guides {
+                let (x1, y1) = context.coord.transform(x_n, y_a, &context.panel);
+                let (x2, y2) = context.coord.transform(x_n, y_b, &context.panel);
+                backend.draw_line(LineConfig {
+                    x1: x1 as Precision,
+                    y1: y1 as Precision,
+                    x2: x2 as Precision,
+                    y2: y2 as Precision,
+                    color: mark_config.line_color,
+                    width: mark_config.line_width as Precision,
+                    opacity: 1.0,
+                    dash: mark_config
+                        .line_dash
+                        .iter()
+                        .map(|&d| d as Precision)
+                        .collect(),
+                });
+            }
+        }
+
+        // --- STEP 4: DOTS ---
+        for i in 0..row_count {
+            let Some(x_n) = x_norms[i] else {
+                continue;
+            };
+            let fill = self.resolve_color_from_value(
+                color_norms.as_ref().and_then(|n| n[i]),
+                context,
+                &mark_config.color,
+            );
+
+            backend.set_tooltip(self.tooltip_text(i, context.spec.formats));
+            backend.set_href(self.href_url(i));
+            let ends = [y2_norms.as_ref().and_then(|ns| ns[i]), y_norms[i]];
+            for y_n in ends.into_iter().flatten() {
+                let (px, py) = context.coord.transform(x_n, y_n, &context.panel);
+                backend.draw_circle(CircleConfig {
+                    x: px as Precision,
+                    y: py as Precision,
+                    radius: mark_config.size as Precision,
+                    fill,
+                    stroke: mark_config.stroke,
+                    stroke_width: mark_config.stroke_width as Precision,
+                    opacity: mark_config.opacity as Precision,
+                });
+            }
+        }
+        backend.set_tooltip(None);
+        backend.set_href(None);
+
+        Ok(())
+    }
+}
+
+impl Chart<MarkDotPlot> {
+    /// Reusable aesthetic color resolver.
+    fn resolve_color_from_value(
+        &self,
+        val: Option<f64>,
+        context: &PanelContext,
+        fallback: &SingleColor,
+    ) -> SingleColor {
+        if let (Some(v), Some(mapping)) = (val, &context.spec.aesthetics.color) {
+            let s_trait = mapping.scale_impl.as_ref();
+            s_trait
+                .mapper()
+                .as_ref()
+                .map(|m| m.map_to_color(v, s_trait.logical_max()))
+                .unwrap_or(*fallback)
+        } else {
+            *fallback
+        }
+    }
+}
@@ -0,0 +1,170 @@
+use crate::Precision;
+use crate::TEMP_SUFFIX;
+use crate::chart::Chart;
+use crate::core::context::PanelContext;
+use crate::core::layer::{CircleConfig, MarkRenderer, RenderBackend};
+use crate::error::ChartonError;
+use crate::mark::dots::MarkDots;
+use crate::visual::color::SingleColor;
+
+// ============================================================================
+// MARK RENDERING (Dot Histogram Implementation)
+// ============================================================================
+
+impl MarkRenderer for Chart<MarkDots> {
+    /// Renders one circle per observation.
+    ///
+    /// Each row carries its bin midpoint (X) and its 1-based stack position (Y);
+    /// the dot is centered half a unit below that position so that the first dot
+    /// rests on the baseline and the tallest stack ends exactly at its count.
+    fn render_marks(
+        &self,
+        backend: &mut dyn RenderBackend,
+        context: &PanelContext,
+    ) -> Result<(), ChartonError> {
+        let ds = &self.data;
+        let row_count = ds.height();
+        if row_count == 0 {
+            return Ok(());
+        }
+
+        let mark_config = self
+            .mark
+            .as_ref()
+            .ok_or_else(|| ChartonError::Mark("MarkDots configuration is missing".into()))?;
+        let x_enc = self
+            .encoding
+            .x
+            .as_ref()
+            .ok_or_else(|| ChartonError::Encoding("X missing".into()))?;
+        let y_enc = self
+            .encoding
+            .y
+            .as_ref()
+            .ok_or_else(|| ChartonError::Encoding("Y missing".into()))?;
+
+        // --- STEP 1: SCALES & NORMALIZATION ---
+        let x_scale = context.coord.get_x_scale();
+        let y_scale = context.coord.get_y_scale();
+        let is_flipped = context.coord.is_flipped();
+
+        let x_norms = x_scale
+            .scale_type()
+            .normalize_column(x_scale, ds.column(&x_enc.field)?);
+        let y_col = ds.column(&y_enc.field)?;
+
+        let color_norms = context.spec.aesthetics.color.as_ref().and_then(|m| {
+            let s = m.scale_impl.as_ref();
+            let col = ds.column(&m.field).ok()?;
+            Some(s.scale_type().normalize_column(s, col))
+        });
+
+        let sub_idx_col = ds.column(&format!("{}_sub_idx", TEMP_SUFFIX)).ok();
+        let groups_count_col = ds.column(&format!("{}_groups_count", TEMP_SUFFIX)).ok();
+
+        // --- STEP 2: DOT GEOMETRY ---
+        let bin_norm = self.calculate_bin_step_norm(context)?;
+        let n_groups = groups_count_col
+            .and_then(|c| c.get(0).to_f64())
+            .unwrap_or(1.0)
+            .max(1.0);
+        let lane_norm = bin_norm / n_groups;
+
+        // Pixel size of one stack unit and of one lane along the binned axis.
+        let (along, across) = if is_flipped {
+            (context.panel.height, context.panel.width)
+        } else {
+            (context.panel.width, context.panel.height)
+        };
+        let unit_px = (y_scale.normalize(1.0) - y_scale.normalize(0.0)).abs() * across;
+        let lane_px = lane_norm * along;
+
+        let radius = mark_config
+            .size
+            .unwrap_or_else(|| (unit_px.min(lane_px) / 2.0) * 0.95);
+
+        // --- STEP 3: EMISSION ---
+        for i in 0..row_count {
+            let (Some(x_n), Some(level)) = (x_norms[i], y_col.get(i).to_f64()) else {
+                continue;
+            };
+
+            let mut x_final_n = x_n;
+            if let Some(sub_col) = sub_idx_col {
+                let sub_idx = sub_col.get(i).to_f64().unwrap_or(0.0);
+                x_final_n += (sub_idx - (n_groups - 1.0) / 2.0) * lane_norm;
+            }
+
+            let y_n = y_scale.normalize(level - 0.5);
+            let (px, py) = context.coord.transform(x_final_n, y_n, &context.panel);
+
+            let fill = self.resolve_color_from_value(
+                color_norms.as_ref().and_then(|n| n[i]),
+                context,
+                &mark_config.color,
+            );
+
+            backend.set_tooltip(self.tooltip_text(i, context.spec.formats));
+            backend.set_href(self.href_url(i));
+            backend.draw_circle(CircleConfig {
+                x: px as Precision,
+                y: py as Precision,
+                radius: radius as Precision,
+                fill,
+                stroke: mark_config.stroke,
+                stroke_width: mark_config.stroke_width as Precision,
+                opacity: mark_config.opacity as Precision,
+            });
+        }
+        backend.set_tooltip(None);
+        backend.set_href(None);
+
+        Ok(())
+    }
+}
+
+// --- HELPER METHODS ---
+
+impl Chart<MarkDots> {
+    /// Measures the distance between adjacent bin centers in normalized space.
+    ///
+    /// The transform records the bin width alongside the dots, so empty outer
+    /// bins (e.g. with a pinned bin range) do not skew it.
+    fn calculate_bin_step_norm(&self, context: &PanelContext) -> Result<f64, ChartonError> {
+        let x_enc = self
+            .encoding
+            .x
+            .as_ref()
+            .ok_or_else(|| ChartonError::Encoding("X missing".into()))?;
+        let width = self
+            .data
+            .column(&format!("{}_bin_width", TEMP_SUFFIX))?
+            .get(0)
+            .to_f64()
+            .unwrap_or(1.0);
+
+        let x_scale = context.coord.get_x_scale();
+        let (v_min, _) = self.data.column(&x_enc.field)?.min_max();
+
+        Ok((x_scale.normalize(v_min + width) - x_scale.normalize(v_min)).abs())
+    }
+
+    /// Resolves a SingleColor from a normalized aesthetic value.
+    fn resolve_color_from_value(
+        &self,
+        val: Option<f64>,
+        context: &PanelContext,
+        fallback: &SingleColor,
+    ) -> SingleColor {
+        if let (Some(v), Some(mapping)) = (val, &context.spec.aesthetics.color) {
+            let s_trait = mapping.scale_impl.as_ref();
+            s_trait
+                .mapper()
+                .as_ref()
+                .map(|m| m.map_to_color(v, s_trait.logical_max()))
+                .unwrap_or(*fallback)
+        } else {
+            *fallback
+        }
+    }
+}
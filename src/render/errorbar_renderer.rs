@@ -186,6 +186,8 @@ impl Chart<MarkErrorBar> {
         };
 
         // --- 2. Draw Main Whisker and Caps ---
+        backend.set_tooltip(self.tooltip_text(idx, context.spec.formats));
+        backend.set_href(self.href_url(idx));
         if let (Some(yn1), Some(yn2)) = (y_min_norms[idx], y_max_norms[idx]) {
             // Transform both endpoints to pixel coordinates
             let (x_pix1, y_pix1) = context.coord.transform(x_final_n, yn1, &context.panel);
@@ -251,6 +253,8 @@ impl Chart<MarkErrorBar> {
                 opacity: mark_config.opacity as Precision,
             });
         }
+        backend.set_tooltip(None);
+        backend.set_href(None);
     }
 
     /// Maps a normalized aesthetic value to a concrete color using the scale's palette.
@@ -1,10 +1,10 @@
 use crate::Precision;
-use crate::coordinate::CoordinateTrait;
 use crate::coordinate::geo::Geo;
+use crate::coordinate::{CoordinateTrait, GridLines};
 use crate::core::layer::{LineConfig, RenderBackend, TextConfig};
 use crate::error::ChartonError;
 use crate::scale::ExplicitTick;
-use crate::theme::Theme;
+use crate::theme::{TextOrientation, Theme};
 
 /// Renders geographic axes (longitude/latitude grid lines) for the Geo coordinate system.
 ///
@@ -70,9 +70,12 @@ pub(crate) fn render_geo_axes(
             color: text_color,
             text_anchor: "middle".to_string(),
             dominant_baseline: "hanging".to_string(),
-            font_weight: "normal".to_string(),
+            font_weight: theme.tick_label_font_weight.to_string(),
+            font_style: theme.tick_label_font_style.to_string(),
             opacity: 1.0,
             angle: 0.0,
+            direction: theme.text_direction.as_str().to_string(),
+            writing_mode: "horizontal-tb".to_string(),
         });
     }
 
@@ -98,9 +101,12 @@ pub(crate) fn render_geo_axes(
             color: label_color,
             text_anchor: "middle".to_string(),
             dominant_baseline: "hanging".to_string(),
-            font_weight: "normal".to_string(),
+            font_weight: theme.label_font_weight.to_string(),
+            font_style: theme.label_font_style.to_string(),
             opacity: 1.0,
             angle: 0.0,
+            direction: theme.text_direction.as_str().to_string(),
+            writing_mode: "horizontal-tb".to_string(),
         });
     }
 
@@ -143,9 +149,12 @@ pub(crate) fn render_geo_axes(
             color: text_color,
             text_anchor: "end".to_string(),
             dominant_baseline: "central".to_string(),
-            font_weight: "normal".to_string(),
+            font_weight: theme.tick_label_font_weight.to_string(),
+            font_style: theme.tick_label_font_style.to_string(),
             opacity: 1.0,
             angle: 0.0,
+            direction: theme.text_direction.as_str().to_string(),
+            writing_mode: "horizontal-tb".to_string(),
         });
     }
 
@@ -164,6 +173,10 @@ pub(crate) fn render_geo_axes(
             - (label_size / 2.0)
             - 3.0;
         let label_y = panel.y + panel.height / 2.0;
+        let (angle, baseline, writing_mode) = match theme.y_label_orientation {
+            TextOrientation::Rotated => (-90.0, "middle", "horizontal-tb"),
+            TextOrientation::UprightVertical => (0.0, "central", "vertical-rl"),
+        };
 
         backend.draw_text(TextConfig {
             x: label_x as Precision,
@@ -173,10 +186,13 @@ pub(crate) fn render_geo_axes(
             font_family: label_family.clone(),
             color: label_color,
             text_anchor: "middle".to_string(),
-            dominant_baseline: "middle".to_string(),
-            font_weight: "normal".to_string(),
+            dominant_baseline: baseline.to_string(),
+            font_weight: theme.label_font_weight.to_string(),
+            font_style: theme.label_font_style.to_string(),
             opacity: 1.0,
-            angle: -90.0,
+            angle,
+            direction: theme.text_direction.as_str().to_string(),
+            writing_mode: writing_mode.to_string(),
         });
     }
 
@@ -189,14 +205,24 @@ pub(crate) fn render_geo_grid(
     theme: &Theme,
     panel: &crate::coordinate::Rect,
     geo: &Geo,
+    lines: GridLines,
     x_explicit: Option<&[ExplicitTick]>,
     y_explicit: Option<&[ExplicitTick]>,
 ) -> Result<(), ChartonError> {
     let grid_color = theme.grid_color;
     let grid_width = theme.grid_width;
+    let dash: Vec<Precision> = theme.grid_dash.iter().map(|&d| d as Precision).collect();
 
-    let x_ticks = generate_ticks(geo, true, x_explicit);
-    let y_ticks = generate_ticks(geo, false, y_explicit);
+    let x_ticks = if lines.x {
+        generate_ticks(geo, true, x_explicit)
+    } else {
+        vec![]
+    };
+    let y_ticks = if lines.y {
+        generate_ticks(geo, false, y_explicit)
+    } else {
+        vec![]
+    };
 
     // Vertical grid lines (longitude)
     for tick in &x_ticks {
@@ -208,8 +234,8 @@ pub(crate) fn render_geo_grid(
             y2: (panel.y + panel.height) as Precision,
             color: grid_color,
             width: grid_width as Precision,
-            opacity: 1.0,
-            dash: vec![],
+            opacity: theme.grid_opacity.unwrap_or(1.0) as Precision,
+            dash: dash.clone(),
         });
     }
 
@@ -223,8 +249,8 @@ pub(crate) fn render_geo_grid(
             y2: ty as Precision,
             color: grid_color,
             width: grid_width as Precision,
-            opacity: 1.0,
-            dash: vec![],
+            opacity: theme.grid_opacity.unwrap_or(1.0) as Precision,
+            dash: dash.clone(),
         });
     }
 
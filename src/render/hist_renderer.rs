@@ -70,7 +70,7 @@ impl MarkRenderer for Chart<MarkHist> {
 
         // --- STEP 4: PARALLEL PROCESSING PER GROUP ---
         // We calculate all rects for all groups in parallel while maintaining the group structure
-        let groups_render_data: Vec<Vec<RectConfig>> = grouped_indices
+        let groups_render_data: Vec<Vec<(usize, RectConfig)>> = grouped_indices
             .groups
             .maybe_par_iter()
             .map(|(_group_key, row_indices)| {
@@ -93,7 +93,7 @@ impl MarkRenderer for Chart<MarkHist> {
                                 .coord
                                 .transform(x_n, y_baseline_norm, &context.panel);
 
-                        Some(if !is_flipped {
+                        let rect = if !is_flipped {
                             let h = (py_base - py).abs();
                             RectConfig {
                                 x: (px - bar_thickness / 2.0) as Precision,
@@ -117,7 +117,8 @@ impl MarkRenderer for Chart<MarkHist> {
                                 stroke_width: mark_config.stroke_width as Precision,
                                 opacity: mark_config.opacity as Precision,
                             }
-                        })
+                        };
+                        Some((idx, rect))
                     })
                     .collect()
             })
@@ -126,10 +127,14 @@ impl MarkRenderer for Chart<MarkHist> {
         // --- STEP 5: SEQUENTIAL EMISSION ---
         // Iterate through groups in their original order to ensure correct Z-order layering
         for rects in groups_render_data {
-            for config in rects {
+            for (idx, config) in rects {
+                backend.set_tooltip(self.tooltip_text(idx, context.spec.formats));
+                backend.set_href(self.href_url(idx));
                 backend.draw_rect(config);
             }
         }
+        backend.set_tooltip(None);
+        backend.set_href(None);
 
         Ok(())
     }
@@ -141,7 +146,11 @@ impl Chart<MarkHist> {
     /// Determines the pixel thickness of bars by measuring the distance
     /// between adjacent bin centers in the current coordinate system.
     fn calculate_hist_bar_size(&self, context: &PanelContext) -> Result<f64, ChartonError> {
-        let x_enc = self.encoding.x.as_ref().unwrap();
+        let x_enc = self
+            .encoding
+            .x
+            .as_ref()
+            .ok_or_else(|| ChartonError::Encoding("X missing".into()))?;
         let n_bins = x_enc
             .bins
             .ok_or_else(|| ChartonError::Encoding("Bin count not resolved".into()))?
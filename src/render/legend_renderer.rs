@@ -1,6 +1,9 @@
 use crate::Precision;
 use crate::core::context::PanelContext;
-use crate::core::guide::{GuideKind, GuideSize, GuideSpec, LegendPosition};
+use crate::core::guide::{
+    COLORBAR_LENGTH, COLORBAR_THICKNESS, GuideKind, GuideSize, GuideSpec,
+    HORIZONTAL_LEGEND_MAX_HEIGHT, LegendGlyph, LegendPosition,
+};
 use crate::core::layer::{
     CircleConfig, GradientRectConfig, LineConfig, PolygonConfig, RectConfig, RenderBackend,
     TextConfig,
@@ -21,18 +24,24 @@ impl LegendRenderer {
     /// The primary entry point for rendering all legends and colorbars.
     ///
     /// It coordinates the layout flow (wrapping blocks) based on the available space
-    /// around the provided PanelContext.
+    /// around the provided PanelContext, starting at `origin`, the top-left corner
+    /// resolved by the layout engine. Returns the extent of the drawn blocks,
+    /// measured from `origin`.
     pub fn render_legend<B: RenderBackend>(
         backend: &mut B,
         specs: &[GuideSpec],
         theme: &Theme,
         ctx: &PanelContext,
-    ) {
+        origin: (f64, f64),
+    ) -> GuideSize {
         // Resolve the legend position from the theme.
         let position = theme.legend_position;
 
         if specs.is_empty() || matches!(position, LegendPosition::None) {
-            return;
+            return GuideSize {
+                width: 0.0,
+                height: 0.0,
+            };
         }
 
         let font_size = theme.legend_label_size;
@@ -41,13 +50,13 @@ impl LegendRenderer {
         // Layout orientation: Top/Bottom positions are horizontal; Left/Right are vertical.
         let is_horizontal = matches!(position, LegendPosition::Top | LegendPosition::Bottom);
 
-        // Determine starting coordinates relative to the panel's bounding box.
-        let (start_x, start_y) = Self::calculate_initial_anchor(ctx, theme, is_horizontal);
+        let (start_x, start_y) = origin;
 
         let mut current_x = start_x;
         let mut current_y = start_y;
         let mut max_dim_in_row_col = 0.0;
         let block_gap = theme.legend_block_gap;
+        let (mut end_x, mut end_y) = origin;
 
         for spec in specs {
             // Estimate size for wrapping calculations.
@@ -55,7 +64,7 @@ impl LegendRenderer {
             let block_size = spec.estimate_size(
                 theme,
                 if is_horizontal {
-                    150.0
+                    HORIZONTAL_LEGEND_MAX_HEIGHT
                 } else {
                     ctx.panel.height
                 },
@@ -92,9 +101,12 @@ impl LegendRenderer {
                 color: theme.legend_title_color,
                 text_anchor: "start".to_string(),
                 dominant_baseline: "central".into(),
-                font_weight: "bold".to_string(),
+                font_weight: theme.legend_title_font_weight.to_string(),
+                font_style: theme.legend_title_font_style.to_string(),
                 opacity: 1.0,
                 angle: 0.0,
+                direction: theme.text_direction.as_str().to_string(),
+                writing_mode: "horizontal-tb".to_string(),
             };
             backend.draw_text(text_config);
 
@@ -102,9 +114,15 @@ impl LegendRenderer {
 
             // 2. Render content based on GuideKind (Continuous Gradient vs. Discrete Symbols)
             let actual_block_size = match spec.kind {
-                GuideKind::ColorBar => {
-                    Self::draw_colorbar(backend, spec, ctx, current_x, content_y_offset, theme)
-                }
+                GuideKind::ColorBar => Self::draw_colorbar(
+                    backend,
+                    spec,
+                    ctx,
+                    current_x,
+                    content_y_offset,
+                    theme,
+                    is_horizontal,
+                ),
                 GuideKind::Legend => {
                     let (labels, colors, shapes, sizes) = Self::resolve_mappings(spec, ctx);
                     Self::draw_spec_group(
@@ -119,7 +137,7 @@ impl LegendRenderer {
                         font_size,
                         theme,
                         if is_horizontal {
-                            150.0
+                            HORIZONTAL_LEGEND_MAX_HEIGHT
                         } else {
                             ctx.panel.height
                         },
@@ -127,6 +145,13 @@ impl LegendRenderer {
                 }
             };
 
+            end_x = end_x
+                .max(current_x + block_size.width)
+                .max(current_x + actual_block_size.width);
+            end_y = end_y
+                .max(current_y + block_size.height)
+                .max(content_y_offset + actual_block_size.height);
+
             // 3. Advance the cursor
             if !is_horizontal {
                 current_y += actual_block_size.height + block_gap;
@@ -134,9 +159,17 @@ impl LegendRenderer {
                 current_x += actual_block_size.width + block_gap;
             }
         }
+
+        GuideSize {
+            width: end_x - start_x,
+            height: end_y - start_y,
+        }
     }
 
     /// Renders a continuous color gradient bar (ColorBar).
+    ///
+    /// The bar is vertical with labels on its right, or horizontal with labels
+    /// below it when `is_horizontal`; higher values sit at the top or right.
     fn draw_colorbar<B: RenderBackend>(
         backend: &mut B,
         spec: &GuideSpec,
@@ -144,12 +177,25 @@ impl LegendRenderer {
         x: f64,
         y: f64,
         theme: &Theme,
+        is_horizontal: bool,
     ) -> GuideSize {
-        let bar_w = 15.0;
-        let bar_h = 150.0;
         let font_size = theme.legend_label_size;
         let font_family = &theme.legend_label_family;
 
+        // End labels of a horizontal bar are centered on their ticks, so the
+        // strip starts far enough right to keep the first one inside the block.
+        let (lead, trail) = if is_horizontal {
+            spec.colorbar_label_overhang(font_size)
+        } else {
+            (0.0, 0.0)
+        };
+        let x = x + lead;
+        let (bar_w, bar_h) = if is_horizontal {
+            (COLORBAR_LENGTH, COLORBAR_THICKNESS)
+        } else {
+            (COLORBAR_THICKNESS, COLORBAR_LENGTH)
+        };
+
         let mut stops = Vec::new();
 
         // Access the color aesthetics from the central spec
@@ -161,8 +207,10 @@ impl LegendRenderer {
 
             for i in 0..=n_samples {
                 let ratio = i as f64 / n_samples as f64;
-                // Reverse sampling so higher values appear at the top.
-                let color = mapper.map_to_color(1.0 - ratio, l_max);
+                // A vertical gradient runs top to bottom, so it is sampled in
+                // reverse to put higher values at the top.
+                let norm = if is_horizontal { ratio } else { 1.0 - ratio };
+                let color = mapper.map_to_color(norm, l_max);
                 stops.push((ratio as Precision, color));
             }
         }
@@ -173,7 +221,7 @@ impl LegendRenderer {
             width: bar_w as Precision,
             height: bar_h as Precision,
             stops,
-            is_vertical: true,
+            is_vertical: !is_horizontal,
             id_suffix: spec.field.clone(),
         };
         backend.draw_gradient_rect(gradient_rect_config);
@@ -192,47 +240,62 @@ impl LegendRenderer {
 
         let mut max_label_w = 0.0;
         if let Some(mapping) = spec.mappings.first() {
-            let ticks = mapping.scale_impl.suggest_ticks(5);
-            for tick in ticks {
+            for tick in spec.colorbar_ticks() {
                 let norm = mapping.scale_impl.normalize(tick.value);
-                let tick_y = y + (bar_h * (1.0 - norm));
-
-                let line_config = LineConfig {
-                    x1: x as Precision,
-                    y1: tick_y as Precision,
-                    x2: (x + 3.0) as Precision,
-                    y2: tick_y as Precision,
-                    color: "#FFFFFF".into(),
-                    width: 1.0,
-                    opacity: 1.0,
-                    dash: vec![],
-                };
-                backend.draw_line(line_config);
-
-                let line_config = LineConfig {
-                    x1: (x + bar_w - 3.0) as Precision,
-                    y1: tick_y as Precision,
-                    x2: (x + bar_w) as Precision,
-                    y2: tick_y as Precision,
-                    color: "#FFFFFF".into(),
-                    width: 1.0,
-                    opacity: 1.0,
-                    dash: vec![],
+
+                // Short white marks cut into both long edges of the strip.
+                let (marks, label_pos, anchor, baseline) = if is_horizontal {
+                    let tick_x = x + bar_w * norm;
+                    (
+                        [
+                            (tick_x, y, tick_x, y + 3.0),
+                            (tick_x, y + bar_h - 3.0, tick_x, y + bar_h),
+                        ],
+                        (tick_x, y + bar_h + theme.legend_marker_text_gap),
+                        "middle",
+                        "hanging",
+                    )
+                } else {
+                    let tick_y = y + (bar_h * (1.0 - norm));
+                    (
+                        [
+                            (x, tick_y, x + 3.0, tick_y),
+                            (x + bar_w - 3.0, tick_y, x + bar_w, tick_y),
+                        ],
+                        (x + bar_w + theme.legend_marker_text_gap, tick_y),
+                        "start",
+                        "central",
+                    )
                 };
-                backend.draw_line(line_config);
+
+                for (x1, y1, x2, y2) in marks {
+                    backend.draw_line(LineConfig {
+                        x1: x1 as Precision,
+                        y1: y1 as Precision,
+                        x2: x2 as Precision,
+                        y2: y2 as Precision,
+                        color: "#FFFFFF".into(),
+                        width: 1.0,
+                        opacity: 1.0,
+                        dash: vec![],
+                    });
+                }
 
                 let text_config = TextConfig {
                     text: tick.label.clone(),
-                    x: (x + bar_w + theme.legend_marker_text_gap) as Precision,
-                    y: tick_y as Precision,
+                    x: label_pos.0 as Precision,
+                    y: label_pos.1 as Precision,
                     font_size: font_size as Precision,
                     font_family: font_family.clone(),
                     color: theme.legend_label_color,
-                    text_anchor: "start".to_string(),
-                    dominant_baseline: "central".into(),
-                    font_weight: "normal".to_string(),
+                    text_anchor: anchor.to_string(),
+                    dominant_baseline: baseline.into(),
+                    font_weight: theme.legend_label_font_weight.to_string(),
+                    font_style: theme.legend_label_font_style.to_string(),
                     opacity: 1.0,
                     angle: 0.0,
+                    direction: theme.text_direction.as_str().to_string(),
+                    writing_mode: "horizontal-tb".to_string(),
                 };
                 backend.draw_text(text_config);
 
@@ -241,9 +304,16 @@ impl LegendRenderer {
             }
         }
 
-        GuideSize {
-            width: bar_w + theme.legend_marker_text_gap + max_label_w,
-            height: bar_h,
+        if is_horizontal {
+            GuideSize {
+                width: lead + bar_w + trail,
+                height: bar_h + theme.legend_marker_text_gap + font_size,
+            }
+        } else {
+            GuideSize {
+                width: bar_w + theme.legend_marker_text_gap + max_label_w,
+                height: bar_h,
+            }
         }
     }
 
@@ -251,7 +321,7 @@ impl LegendRenderer {
     #[allow(clippy::too_many_arguments)]
     fn draw_spec_group(
         backend: &mut dyn RenderBackend,
-        _spec: &GuideSpec,
+        spec: &GuideSpec,
         labels: &[String],
         colors: &[SingleColor],
         shapes: Option<&[PointShape]>,
@@ -287,8 +357,9 @@ impl LegendRenderer {
 
             let shape = shapes.and_then(|s| s.get(i)).unwrap_or(&PointShape::Circle);
 
-            Self::draw_symbol(
+            Self::draw_glyph(
                 backend,
+                &spec.glyphs,
                 shape,
                 col_x + (fixed_container_size / 2.0),
                 item_y + (row_h / 2.0),
@@ -305,9 +376,12 @@ impl LegendRenderer {
                 color: theme.legend_label_color,
                 text_anchor: "start".to_string(),
                 dominant_baseline: "central".into(),
-                font_weight: "normal".to_string(),
+                font_weight: theme.legend_label_font_weight.to_string(),
+                font_style: theme.legend_label_font_style.to_string(),
                 opacity: 1.0,
                 angle: 0.0,
+                direction: theme.text_direction.as_str().to_string(),
+                writing_mode: "horizontal-tb".to_string(),
             };
             backend.draw_text(text_config);
 
@@ -331,7 +405,7 @@ impl LegendRenderer {
         Option<Vec<PointShape>>,
         Option<Vec<f64>>,
     ) {
-        let (labels, values_f64): (Vec<String>, Vec<f64>) = match &spec.domain {
+        let (mut labels, values_f64): (Vec<String>, Vec<f64>) = match &spec.domain {
             ScaleDomain::Discrete(values) => (values.clone(), Vec::new()),
             _ => {
                 let ticks = spec.get_sampling_ticks();
@@ -350,7 +424,9 @@ impl LegendRenderer {
             m.scale_impl.mapper().is_some_and(|v| {
                 matches!(
                     v,
-                    VisualMapper::DiscreteColor { .. } | VisualMapper::ContinuousColor { .. }
+                    VisualMapper::DiscreteColor { .. }
+                        | VisualMapper::OrdinalColor { .. }
+                        | VisualMapper::ContinuousColor { .. }
                 )
             })
         });
@@ -364,6 +440,11 @@ impl LegendRenderer {
                 .mapper()
                 .is_some_and(|v| matches!(v, VisualMapper::Size { .. }))
         });
+        let has_opacity = spec.mappings.iter().any(|m| {
+            m.scale_impl
+                .mapper()
+                .is_some_and(|v| matches!(v, VisualMapper::Opacity { .. }))
+        });
 
         for (i, label_str) in labels.iter().enumerate() {
             let val_f64 = values_f64.get(i).cloned();
@@ -421,6 +502,29 @@ impl LegendRenderer {
             } else {
                 sizes.push(5.0);
             }
+
+            // Resolve Opacity: the key fades like the marks it stands for.
+            if has_opacity
+                && let Some(ref mapping) = ctx.spec.aesthetics.opacity
+                && let Some(color) = colors.last_mut()
+            {
+                let norm = val_f64
+                    .map(|v| mapping.scale_impl.normalize(v))
+                    .unwrap_or_else(|| mapping.scale_impl.normalize_string(label_str));
+                let alpha = mapping
+                    .scale_impl
+                    .mapper()
+                    .map_or(1.0, |m| m.map_to_opacity(norm));
+                let [r, g, b, a] = color.rgba();
+                *color = SingleColor::from_rgba(r as f64, g as f64, b as f64, a as f64 * alpha);
+            }
+        }
+
+        // The smallest symbol stands for every value at or below it once floored.
+        if spec.floors_smallest_entry()
+            && let Some(first) = labels.first_mut()
+        {
+            *first = format!("≤ {}", first);
         }
 
         (
@@ -431,6 +535,54 @@ impl LegendRenderer {
         )
     }
 
+    /// Renders the key of one legend entry.
+    ///
+    /// Glyphs of all contributing layers are overlaid: the filled square first,
+    /// then line samples, then the symbol on top. Without glyphs a symbol is drawn.
+    fn draw_glyph(
+        backend: &mut dyn RenderBackend,
+        glyphs: &[LegendGlyph],
+        shape: &PointShape,
+        cx: f64,
+        cy: f64,
+        r: f64,
+        color: &SingleColor,
+    ) {
+        if glyphs.contains(&LegendGlyph::Swatch) {
+            let half = 6.0;
+            backend.draw_rect(RectConfig {
+                x: (cx - half) as Precision,
+                y: (cy - half) as Precision,
+                width: (half * 2.0) as Precision,
+                height: (half * 2.0) as Precision,
+                fill: *color,
+                stroke: SingleColor::new("none"),
+                stroke_width: 0.0,
+                opacity: 1.0,
+            });
+        }
+
+        for glyph in glyphs {
+            if let LegendGlyph::Line { dash } = glyph {
+                // The sample spans the whole 18px marker area.
+                backend.draw_line(LineConfig {
+                    x1: (cx - 9.0) as Precision,
+                    y1: cy as Precision,
+                    x2: (cx + 9.0) as Precision,
+                    y2: cy as Precision,
+                    color: *color,
+                    width: 2.0,
+                    opacity: 1.0,
+                    dash: dash.iter().map(|&d| d as Precision).collect(),
+                });
+            }
+        }
+
+        if glyphs.is_empty() || glyphs.contains(&LegendGlyph::Symbol) {
+            Self::draw_symbol(backend, shape, cx, cy, r, color);
+        }
+    }
+
     /// Renders a single geometric symbol based on the PointShape variant.
     fn draw_symbol(
         backend: &mut dyn RenderBackend,
@@ -556,21 +708,4 @@ impl LegendRenderer {
         }
         pts
     }
-
-    /// Calculates the initial (x, y) anchor for the legend block based on the panel position.
-    fn calculate_initial_anchor(ctx: &PanelContext, theme: &Theme, _: bool) -> (f64, f64) {
-        let mut x = ctx.panel.x;
-        let mut y = ctx.panel.y;
-        let margin = theme.legend_margin;
-        let axis_buffer = theme.axis_reserve_buffer;
-
-        match theme.legend_position {
-            LegendPosition::Right => x = ctx.panel.x + ctx.panel.width + margin,
-            LegendPosition::Left => x = (ctx.panel.x - margin - axis_buffer).max(10.0),
-            LegendPosition::Top => y = (ctx.panel.y - margin - (axis_buffer * 0.8)).max(10.0),
-            LegendPosition::Bottom => y = ctx.panel.y + ctx.panel.height + margin,
-            _ => {}
-        }
-        (x, y)
-    }
 }
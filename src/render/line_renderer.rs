@@ -105,8 +105,12 @@ impl MarkRenderer for Chart<MarkLine> {
 
         // --- STEP 2: GROUPING (Determining Path Separation) ---
         // Groups are sorted by "First Appearance" to ensure deterministic Z-indexing.
+        // A detail field splits each color group into further, identically styled paths.
         let group_field = context.spec.aesthetics.color.as_ref().map(|c| &c.field);
-        let grouped_indices = ds.group_by(group_field.map(|s| s.as_str()));
+        let mut grouped_indices = ds.group_by(group_field.map(|s| s.as_str()));
+        if let Some(detail) = &self.encoding.detail {
+            grouped_indices = grouped_indices.split_by(ds, &detail.field)?;
+        }
 
         // --- STEP 3: PARALLEL PATH CALCULATION ---
         let line_render_data: Vec<_> = grouped_indices
@@ -164,13 +168,18 @@ impl MarkRenderer for Chart<MarkLine> {
                     &mark_config.color,
                 );
 
-                Some((expanded, final_color))
+                // A mapped opacity is resolved from the first point as well.
+                let final_opacity = self
+                    .mapped_opacity(first_idx, context)
+                    .unwrap_or(mark_config.opacity);
+
+                Some((expanded, final_color, final_opacity))
             })
             .collect();
 
         // --- STEP 4: SEQUENTIAL DRAW DISPATCH ---
         // Lines are drawn in sequence to respect the Z-order established by grouping.
-        for (points, color) in line_render_data {
+        for (points, color, opacity) in line_render_data {
             if points.is_empty() {
                 continue;
             }
@@ -183,7 +192,7 @@ impl MarkRenderer for Chart<MarkLine> {
                 fill: "none".into(),
                 stroke: color,
                 stroke_width: mark_config.stroke_width as Precision,
-                opacity: mark_config.opacity as Precision,
+                opacity: opacity as Precision,
                 dash: mark_config.dash.iter().map(|&d| d as Precision).collect(),
                 topology: PathTopology::Simple,
             });
@@ -207,7 +216,7 @@ impl Chart<MarkLine> {
             expanded.push((x1, y1));
             expanded.push((x2, y1));
         }
-        expanded.push(*points.last().unwrap());
+        expanded.push(points[points.len() - 1]);
         expanded
     }
 
@@ -222,7 +231,7 @@ impl Chart<MarkLine> {
             expanded.push((x1, y1));
             expanded.push((x1, y2));
         }
-        expanded.push(*points.last().unwrap());
+        expanded.push(points[points.len() - 1]);
         expanded
     }
 
@@ -8,12 +8,42 @@ use crate::core::layer::{
 use crate::core::utils::IntoParallelizable;
 use crate::error::ChartonError;
 use crate::mark::point::{MarkPoint, PointLayout};
+use crate::scale::Scale;
 use crate::visual::color::SingleColor;
 use crate::visual::shape::PointShape;
+use ahash::AHashMap;
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
+/// Returns the offset of lane `sub_idx` of `total_groups` from the category
+/// center and the width of a lane, both in normalized units.
+///
+/// Lanes are `dodge_width` apart when it is set; otherwise they share the
+/// mark's span like the boxes of a grouped box plot. Either way the middle
+/// of the lanes sits on the category center, hence `(n - 1) / 2` rather
+/// than `n / 2`.
+fn dodge_lane(
+    mark_config: &MarkPoint,
+    sub_idx: f64,
+    total_groups: f64,
+    unit_step_norm: f64,
+) -> (f64, f64) {
+    let (step, width) = match mark_config.dodge_width {
+        Some(dodge_width) => (dodge_width, dodge_width),
+        None => {
+            let box_width = mark_config.width.min(
+                mark_config.span / (total_groups + (total_groups - 1.0) * mark_config.spacing),
+            );
+            (box_width * (1.0 + mark_config.spacing), box_width)
+        }
+    };
+    (
+        (sub_idx - (total_groups - 1.0) / 2.0) * step * unit_step_norm,
+        width * unit_step_norm,
+    )
+}
+
 // ============================================================================
 // MARK RENDERING (High-Performance Parallel Implementation)
 // ============================================================================
@@ -52,14 +82,34 @@ impl MarkRenderer for Chart<MarkPoint> {
         let is_flipped = context.coord.is_flipped();
 
         let unit_step_norm = (x_scale.normalize(1.0) - x_scale.normalize(0.0)).abs();
+        let x_is_discrete = x_scale.scale_type() == Scale::Discrete;
 
         let x_norms = x_scale
             .scale_type()
             .normalize_column(x_scale, df_source.column(&x_enc.field)?);
-        let y_norms = y_scale
+        let mut y_norms = y_scale
             .scale_type()
             .normalize_column(y_scale, df_source.column(&y_enc.field)?);
 
+        // Vertical dodge lanes shift the Y position itself, before any layout runs.
+        if let (Ok(sub_col), Ok(cnt_col)) = (
+            df_source.column(&format!("{}_y_sub_idx", TEMP_SUFFIX)),
+            df_source.column(&format!("{}_y_groups_count", TEMP_SUFFIX)),
+        ) {
+            let y_step_norm = (y_scale.normalize(1.0) - y_scale.normalize(0.0)).abs();
+            for (i, y_n) in y_norms.iter_mut().enumerate() {
+                if let Some(y_n) = y_n {
+                    let (offset, _) = dodge_lane(
+                        mark_config,
+                        sub_col.get(i).to_f64().unwrap_or(0.0),
+                        cnt_col.get(i).to_f64().unwrap_or(1.0),
+                        y_step_norm,
+                    );
+                    *y_n += offset;
+                }
+            }
+        }
+
         let sub_idx_col = df_source.column(&format!("{}_sub_idx", TEMP_SUFFIX)).ok();
         let groups_count_col = df_source
             .column(&format!("{}_groups_count", TEMP_SUFFIX))
@@ -110,23 +160,22 @@ impl MarkRenderer for Chart<MarkPoint> {
                         let y_n = y_norms[i]?;
 
                         let mut x_final_n = x_n;
-                        let mut lane_width_norm = 0.0;
+                        // Without dodge lanes, jitter spreads across the category band.
+                        let mut lane_width_norm = if x_is_discrete {
+                            mark_config.jitter_width.unwrap_or(mark_config.width) * unit_step_norm
+                        } else {
+                            0.0
+                        };
 
                         // Apply BoxPlot-style Dodge Logic to calculate categorical center
                         if let (Some(sub_col), Some(cnt_col)) = (sub_idx_col, groups_count_col) {
                             let total_groups = cnt_col.get(i).to_f64().unwrap_or(1.0);
                             let sub_idx = sub_col.get(i).to_f64().unwrap_or(0.0);
 
-                            let box_width_data = mark_config.width.min(
-                                mark_config.span
-                                    / (total_groups + (total_groups - 1.0) * mark_config.spacing),
-                            );
-                            let box_width_norm = box_width_data * unit_step_norm;
-                            let spacing_norm = box_width_norm * mark_config.spacing;
-
-                            x_final_n += (sub_idx - (total_groups - 1.0) / 2.0)
-                                * (box_width_norm + spacing_norm);
-                            lane_width_norm = box_width_norm;
+                            let (offset, lane_width) =
+                                dodge_lane(mark_config, sub_idx, total_groups, unit_step_norm);
+                            x_final_n += offset;
+                            lane_width_norm = lane_width * mark_config.jitter_width.unwrap_or(1.0);
                         }
 
                         // Project logic coordinates to screen pixels
@@ -167,6 +216,16 @@ impl MarkRenderer for Chart<MarkPoint> {
             }
         };
 
+        // Density-aware transparency scales every point's own opacity.
+        let mut render_configs = render_configs;
+        if mark_config.auto_alpha {
+            let alpha = Self::resolve_auto_alpha(&render_configs, mark_config);
+            for (_, config) in render_configs.iter_mut() {
+                config.opacity *= alpha;
+                config.stroke_opacity *= alpha;
+            }
+        }
+
         // --- STEP 4: GROUPING & EMISSION ---
         // Determine the field to group by for deterministic Z-indexing and WGPU batching.
         // We prioritize Color, then Shape. If neither is mapped, group_by(None) will
@@ -191,6 +250,8 @@ impl MarkRenderer for Chart<MarkPoint> {
             config_lookup[i] = Some(config);
         }
 
+        let auto_tooltip = self.mark.as_ref().is_some_and(|m| m.tooltip);
+
         // Emit draw calls sequentially by group.
         // This ensures identical shapes/colors are drawn contiguously, massively reducing
         // pipeline state changes (Draw Calls) in the WGPU backend via interleaved batching.
@@ -198,10 +259,20 @@ impl MarkRenderer for Chart<MarkPoint> {
             for &idx in &row_indices {
                 // take() moves the value out, leaving None, which is perfectly safe and fast.
                 if let Some(config) = config_lookup[idx].take() {
+                    backend.set_tooltip(match &self.encoding.tooltip {
+                        Some(_) => self.tooltip_text(idx, context.spec.formats),
+                        None if auto_tooltip => {
+                            self.encoded_tooltip_text(idx, context.spec.formats)
+                        }
+                        None => None,
+                    });
+                    backend.set_href(self.href_url(idx));
                     self.emit_draw_call(backend, config);
                 }
             }
         }
+        backend.set_tooltip(None);
+        backend.set_href(None);
 
         Ok(())
     }
@@ -256,14 +327,9 @@ impl Chart<MarkPoint> {
                 let sub_idx = sub_col.get(i).to_f64().unwrap_or(0.0);
                 lane_id = sub_idx as usize;
 
-                let box_width_data = mark_config.width.min(
-                    mark_config.span / (total_groups + (total_groups - 1.0) * mark_config.spacing),
-                );
-                let box_width_norm = box_width_data * unit_step_norm;
-                let spacing_norm = box_width_norm * mark_config.spacing;
-
-                x_final_n +=
-                    (sub_idx - (total_groups - 1.0) / 2.0) * (box_width_norm + spacing_norm);
+                let (offset, box_width_norm) =
+                    dodge_lane(mark_config, sub_idx, total_groups, unit_step_norm);
+                x_final_n += offset;
 
                 lane_px_width = if is_flipped {
                     box_width_norm * context.panel.height
@@ -362,6 +428,47 @@ impl Chart<MarkPoint> {
         configs
     }
 
+    /// Chooses an opacity from the expected overdraw of the laid-out points.
+    ///
+    /// Points are binned into square cells one point diameter wide. The overdraw
+    /// is the occupancy of the cell a typical point falls in (sum of squared
+    /// counts over the number of points), and the opacity is its inverse, so a
+    /// region where `k` points pile up builds up to roughly full ink.
+    fn resolve_auto_alpha(configs: &[(usize, PointElementConfig)], mark_config: &MarkPoint) -> f64 {
+        let (min, max) = mark_config.auto_alpha_range;
+        if configs.is_empty() {
+            return max;
+        }
+
+        let n = configs.len() as f64;
+        let mean_size = configs.iter().map(|(_, c)| c.size).sum::<f64>() / n;
+        let cell = (mean_size * 2.0).max(1.0);
+
+        let mut occupancy: AHashMap<(i64, i64), usize> = AHashMap::new();
+        for (_, c) in configs {
+            let key = ((c.x / cell).floor() as i64, (c.y / cell).floor() as i64);
+            *occupancy.entry(key).or_default() += 1;
+        }
+
+        let overdraw = occupancy
+            .values()
+            .map(|&count| (count * count) as f64)
+            .sum::<f64>()
+            / n;
+        let alpha = (1.0 / overdraw).clamp(min, max);
+
+        eprintln!(
+            "MarkPoint: Auto alpha {:.3} for {} points (expected overdraw {:.1}, range {}..{}).",
+            alpha,
+            configs.len(),
+            overdraw,
+            min,
+            max
+        );
+
+        alpha
+    }
+
     /// Helper to build the visual configuration for a single point element.
     #[allow(clippy::too_many_arguments)]
     fn build_element_config(
@@ -375,6 +482,7 @@ impl Chart<MarkPoint> {
         context: &PanelContext,
         mark_config: &MarkPoint,
     ) -> PointElementConfig {
+        let (opacity, stroke_opacity) = self.row_opacities(i, mark_config.opacity, context);
         PointElementConfig {
             x,
             y,
@@ -395,7 +503,9 @@ impl Chart<MarkPoint> {
             ),
             stroke: mark_config.stroke,
             stroke_width: mark_config.stroke_width,
-            opacity: mark_config.opacity,
+            opacity,
+            stroke_opacity,
+            angle: self.angle_degrees(i),
         }
     }
 }
@@ -474,7 +584,22 @@ impl Chart<MarkPoint> {
             stroke,
             stroke_width,
             opacity,
+            stroke_opacity,
+            angle,
         } = config;
+        // The backends share one opacity between fill and stroke; when the two
+        // differ, each is folded into the alpha of its own color instead.
+        let (fill, stroke, opacity) = if opacity == stroke_opacity {
+            (fill, stroke, opacity)
+        } else {
+            (
+                scale_alpha(fill, opacity),
+                scale_alpha(stroke, stroke_opacity),
+                1.0,
+            )
+        };
+        // Screen y points down, so a positive angle turns the shape clockwise.
+        let turn = angle.to_radians();
 
         match shape {
             PointShape::Circle => {
@@ -488,7 +613,7 @@ impl Chart<MarkPoint> {
                     opacity: opacity as Precision,
                 });
             }
-            PointShape::Square => {
+            PointShape::Square if turn == 0.0 => {
                 // Scale factor = sqrt(pi / 4) ≈ 0.886 to equalize square area with baseline circle
                 let adj_size = size * 0.88623;
                 backend.draw_rect(RectConfig {
@@ -506,6 +631,9 @@ impl Chart<MarkPoint> {
                 // scale_adj is calculated to equalize the physical pixel area of each shape
                 // against a baseline Circle of the same size (radius).
                 let (sides, rotation, scale_adj) = match shape {
+                    // Square: corners at 45 deg, circumradius sqrt(2) * 0.886 ≈ 1.253
+                    PointShape::Square => (4, std::f64::consts::FRAC_PI_4, 1.253),
+
                     // Diamond (Square rotated 45 deg): Area = 2 * r^2. Circle Area = pi * r^2.
                     // Scale factor = sqrt(pi / 2) ≈ 1.253
                     PointShape::Diamond => (4, 0.0, 1.253),
@@ -532,9 +660,9 @@ impl Chart<MarkPoint> {
                 let points = if shape == PointShape::Star {
                     // For Star, we adjust the outer radius to roughly match circle area
                     // A 5-point star with inner_r = 0.382 * outer_r needs ~1.6 scale to match circle area
-                    self.calculate_star(x, y, size * 1.6, size * 0.6, 5)
+                    self.calculate_star(x, y, size * 1.6, size * 0.6, 5, turn)
                 } else {
-                    self.calculate_polygon(x, y, size * scale_adj, sides, rotation)
+                    self.calculate_polygon(x, y, size * scale_adj, sides, rotation + turn)
                 };
 
                 backend.draw_polygon(PolygonConfig {
@@ -574,14 +702,21 @@ impl Chart<MarkPoint> {
         out_r: f64,
         in_r: f64,
         pts: usize,
+        rot: f64,
     ) -> Vec<(f64, f64)> {
         (0..(pts * 2))
             .map(|i| {
-                let angle =
-                    -std::f64::consts::FRAC_PI_2 + std::f64::consts::PI * (i as f64) / (pts as f64);
+                let angle = rot - std::f64::consts::FRAC_PI_2
+                    + std::f64::consts::PI * (i as f64) / (pts as f64);
                 let r = if i % 2 == 0 { out_r } else { in_r };
                 (cx + r * angle.cos(), cy + r * angle.sin())
             })
             .collect()
     }
 }
+
+/// Multiplies the alpha channel of a color by `factor`.
+fn scale_alpha(color: SingleColor, factor: f64) -> SingleColor {
+    let [r, g, b, a] = color.rgba().map(|c| c as f64);
+    SingleColor::from_rgba(r, g, b, a * factor)
+}
@@ -1,14 +1,63 @@
 use crate::Precision;
-use crate::coordinate::{CoordinateTrait, Rect, polar::Polar};
+use crate::coordinate::{CoordinateTrait, GridLines, Rect, polar::Polar};
 use crate::core::layer::{CircleConfig, LineConfig, RenderBackend, TextConfig};
 use crate::error::ChartonError;
-use crate::scale::ExplicitTick;
+use crate::scale::{ExplicitTick, Tick};
 use crate::theme::Theme;
 
+/// Resolves the angular (X) ticks, preferring user-supplied values.
+fn angular_ticks(
+    theme: &Theme,
+    coord: &Polar,
+    max_r: f64,
+    explicit: Option<&[ExplicitTick]>,
+) -> Vec<Tick> {
+    let x_scale = coord.get_x_scale();
+    match explicit {
+        Some(explicit) => x_scale.create_explicit_ticks(explicit),
+        None => x_scale.suggest_ticks(theme.suggest_tick_count(2.0 * std::f64::consts::PI * max_r)),
+    }
+}
+
+/// Resolves the radial (Y) ticks together with their ring radius in pixels.
+///
+/// Rings follow the square-root radius used by rose sectors, so every ring
+/// lines up with the outer edge of a sector of the same value. Pie and donut
+/// charts (no angular field) have no meaningful radial scale and get no rings.
+fn radial_rings(
+    theme: &Theme,
+    coord: &Polar,
+    max_r: f64,
+    explicit: Option<&[ExplicitTick]>,
+) -> Vec<(Tick, f64)> {
+    if coord.x_field.is_empty() {
+        return vec![];
+    }
+
+    let y_scale = coord.get_y_scale();
+    let ticks = match explicit {
+        Some(explicit) => y_scale.create_explicit_ticks(explicit),
+        None => y_scale.suggest_ticks(theme.suggest_tick_count(2.0 * max_r)),
+    };
+
+    ticks
+        .into_iter()
+        .filter_map(|tick| {
+            let y_n = y_scale.normalize(tick.value);
+            // The centre needs no ring, and ticks outside the domain would leave the panel.
+            if !(1e-9..=1.0 + 1e-9).contains(&y_n) {
+                return None;
+            }
+            let r_norm = coord.inner_radius + y_n.sqrt() * (1.0 - coord.inner_radius);
+            Some((tick, r_norm * max_r))
+        })
+        .collect()
+}
+
 /// Renders the polar coordinate system axes (Radial and Angular).
 ///
 /// Refinements included:
-/// 1. Radial Axis (Y): Only displays the maximum domain value to keep the center clean.
+/// 1. Radial Axis (Y): Tick labels run along the starting spoke, one per grid ring.
 /// 2. Smart Positioning: Labels use quadrant-aware anchoring to "grow" away from lines.
 /// 3. Padding: Added theme-based padding to prevent text from touching the outer ring.
 #[allow(clippy::too_many_arguments)]
@@ -18,9 +67,9 @@ pub fn render_polar_axes(
     panel: &Rect,
     coord: &Polar,
     x_label: &str,
-    _x_explicit: Option<&[ExplicitTick]>,
+    x_explicit: Option<&[ExplicitTick]>,
     _y_label: &str,
-    _y_explicit: Option<&[ExplicitTick]>,
+    y_explicit: Option<&[ExplicitTick]>,
 ) -> Result<(), ChartonError> {
     let x_scale = coord.get_x_scale();
 
@@ -45,7 +94,7 @@ pub fn render_polar_axes(
     // For Pie and Donut charts, we skip tick generation to avoid cluttering the visual display.
     let is_pie_or_donut = x_label.is_empty();
     let x_ticks = if !is_pie_or_donut {
-        x_scale.suggest_ticks(theme.suggest_tick_count(2.0 * std::f64::consts::PI * max_r))
+        angular_ticks(theme, coord, max_r, x_explicit)
     } else {
         vec![]
     };
@@ -88,9 +137,35 @@ pub fn render_polar_axes(
             color: theme.tick_label_color,
             text_anchor: anchor.to_string(),
             dominant_baseline: baseline.to_string(),
-            font_weight: "normal".to_string(),
+            font_weight: theme.tick_label_font_weight.to_string(),
+            font_style: theme.tick_label_font_style.to_string(),
+            opacity: 1.0,
+            angle: 0.0,
+            direction: theme.text_direction.as_str().to_string(),
+            writing_mode: "horizontal-tb".to_string(),
+        });
+    }
+
+    // Radial labels sit just beside the starting spoke, offset perpendicular to it
+    // so they do not collide with the spoke itself.
+    let (cos_s, sin_s) = (coord.start_angle.cos(), coord.start_angle.sin());
+    let offset = theme.tick_label_padding + 2.0;
+    for (tick, r) in radial_rings(theme, coord, max_r, y_explicit) {
+        backend.draw_text(TextConfig {
+            x: (center_x + r * cos_s - offset * sin_s) as Precision,
+            y: (center_y + r * sin_s + offset * cos_s) as Precision,
+            text: tick.label,
+            font_size: theme.tick_label_size as Precision,
+            font_family: theme.tick_label_family.clone(),
+            color: theme.tick_label_color,
+            text_anchor: "start".to_string(),
+            dominant_baseline: "middle".to_string(),
+            font_weight: theme.tick_label_font_weight.to_string(),
+            font_style: theme.tick_label_font_style.to_string(),
             opacity: 1.0,
             angle: 0.0,
+            direction: theme.text_direction.as_str().to_string(),
+            writing_mode: "horizontal-tb".to_string(),
         });
     }
 
@@ -99,19 +174,21 @@ pub fn render_polar_axes(
 
 /// Renders the underlying grid system for polar coordinates, including
 /// concentric circular boundaries and angular spokes.
+///
+/// The x grid is the set of spokes at the angular ticks, the y grid the rings
+/// at the radial ticks.
 pub fn render_polar_grid(
     backend: &mut dyn RenderBackend,
     theme: &Theme,
     panel: &Rect,
     coord: &Polar,
+    lines: GridLines,
     x_explicit: Option<&[ExplicitTick]>,
     y_explicit: Option<&[ExplicitTick]>,
 ) -> Result<(), ChartonError> {
     let center_x = panel.x + panel.width / 2.0;
     let center_y = panel.y + panel.height / 2.0;
     let max_r = panel.width.min(panel.height) / 2.0;
-    let _ = x_explicit;
-    let _ = y_explicit;
 
     // 1. Draw the concentric background circles (text labels removed)
     backend.draw_circle(CircleConfig {
@@ -124,12 +201,31 @@ pub fn render_polar_grid(
         opacity: 0.7,
     });
 
+    let rings = if lines.y {
+        radial_rings(theme, coord, max_r, y_explicit)
+    } else {
+        vec![]
+    };
+    for (_, r) in rings {
+        backend.draw_circle(CircleConfig {
+            x: center_x as Precision,
+            y: center_y as Precision,
+            radius: r as Precision,
+            fill: "none".into(),
+            stroke: theme.grid_color,
+            stroke_width: theme.grid_width as Precision,
+            opacity: theme.grid_opacity.unwrap_or(0.5) as Precision,
+        });
+    }
+
     // 2. Draw the angular grid lines / spokes (text labels removed)
     let x_scale = coord.get_x_scale();
-    let x_ticks =
-        x_scale.suggest_ticks(theme.suggest_tick_count(2.0 * std::f64::consts::PI * max_r));
-
-    for tick in x_ticks {
+    let spokes = if lines.x {
+        angular_ticks(theme, coord, max_r, x_explicit)
+    } else {
+        vec![]
+    };
+    for tick in spokes {
         let x_n = x_scale.normalize(tick.value);
         let theta = coord.start_angle + x_n * (coord.end_angle - coord.start_angle);
 
@@ -145,8 +241,8 @@ pub fn render_polar_grid(
             y2: y2 as Precision,
             color: theme.grid_color,
             width: theme.grid_width as Precision,
-            opacity: 0.5, // Grid lines can be slightly fainter than axis lines
-            dash: vec![],
+            opacity: theme.grid_opacity.unwrap_or(0.5) as Precision,
+            dash: theme.grid_dash.iter().map(|&d| d as Precision).collect(),
         });
     }
 
@@ -5,6 +5,7 @@ use crate::core::layer::{MarkRenderer, RectConfig, RenderBackend};
 use crate::core::utils::IntoParallelizable;
 use crate::error::ChartonError;
 use crate::mark::rect::MarkRect;
+use crate::scale::{Scale, ScaleTrait};
 use crate::visual::color::SingleColor;
 
 #[cfg(feature = "parallel")]
@@ -73,10 +74,11 @@ impl MarkRenderer for Chart<MarkRect> {
         // --- STEP 3: SIZE CALCULATION ---
         // Determine the fixed pixel dimensions of a single tile based on bin configuration
         let (rect_width, rect_height) = self.calculate_rect_size(context);
+        let border = mark_config.cell_border;
 
         // --- STEP 4: PARALLEL GEOMETRY GENERATION ---
         // Map normalized data to screen-space RectConfig objects
-        let render_configs: Vec<RectConfig> = (0..row_count)
+        let render_configs: Vec<(usize, RectConfig)> = (0..row_count)
             .maybe_into_par_iter()
             .filter_map(|i| {
                 let x_n = x_norms[i]?;
@@ -93,39 +95,64 @@ impl MarkRenderer for Chart<MarkRect> {
                 );
 
                 // 3. Rect Boundary Calculation: Offset from center to top-left corner
-                Some(RectConfig {
-                    x: (px - rect_width / 2.0) as Precision,
-                    y: (py - rect_height / 2.0) as Precision,
-                    width: rect_width as Precision,
-                    height: rect_height as Precision,
-                    fill,
-                    stroke: mark_config.stroke,
-                    stroke_width: mark_config.stroke_width as Precision,
-                    opacity: mark_config.opacity as Precision,
-                })
+                //    A cell border insets the cell by half the border on every side.
+                let width = (rect_width - border).max(0.0);
+                let height = (rect_height - border).max(0.0);
+                Some((
+                    i,
+                    RectConfig {
+                        x: (px - width / 2.0) as Precision,
+                        y: (py - height / 2.0) as Precision,
+                        width: width as Precision,
+                        height: height as Precision,
+                        fill,
+                        stroke: mark_config.stroke,
+                        stroke_width: mark_config.stroke_width as Precision,
+                        opacity: mark_config.opacity as Precision,
+                    },
+                ))
             })
             .collect();
 
         // --- STEP 5: SEQUENTIAL DRAW DISPATCH ---
         // Final rendering pass to the backend. Sequential execution ensures
         // that the drawing order matches the data order (stable Z-indexing).
-        for config in render_configs {
+        for (i, config) in render_configs {
+            backend.set_tooltip(self.tooltip_text(i, context.spec.formats));
+            backend.set_href(self.href_url(i));
             backend.draw_rect(config);
         }
+        backend.set_tooltip(None);
+        backend.set_href(None);
 
         Ok(())
     }
 }
 
 impl Chart<MarkRect> {
-    /// Calculates the pixel dimensions for a single rectangle tile based on bin counts.
+    /// Calculates the pixel dimensions for a single rectangle tile.
+    ///
+    /// Discrete axes use the band width of one category, so cells fill their
+    /// slot even when the domain lists categories absent from the data.
+    /// Continuous axes divide the range by the bin count.
     fn calculate_rect_size(&self, context: &PanelContext) -> (f64, f64) {
-        let x_bins = self.encoding.x.as_ref().and_then(|e| e.bins).unwrap_or(1);
-        let y_bins = self.encoding.y.as_ref().and_then(|e| e.bins).unwrap_or(1);
+        let step = |scale: &dyn ScaleTrait, bins: Option<usize>| {
+            if scale.scale_type() == Scale::Discrete {
+                (scale.normalize(1.0) - scale.normalize(0.0)).abs()
+            } else {
+                1.0 / (bins.unwrap_or(1) as f64)
+            }
+        };
 
         // Logical step in normalized [0.0, 1.0] space
-        let x_step = 1.0 / (x_bins as f64);
-        let y_step = 1.0 / (y_bins as f64);
+        let x_step = step(
+            context.coord.get_x_scale(),
+            self.encoding.x.as_ref().and_then(|e| e.bins),
+        );
+        let y_step = step(
+            context.coord.get_y_scale(),
+            self.encoding.y.as_ref().and_then(|e| e.bins),
+        );
 
         // Transform logical delta into pixel delta
         let (p0_x, p0_y) = context.coord.transform(0.0, 0.0, &context.panel);
@@ -62,11 +62,16 @@ impl MarkRenderer for Chart<MarkRule> {
             .normalize_column(y_scale, df_source.column(&y_enc.field)?);
 
         // Normalize Y2 if provided; defaults to 1.0 (top of the scale) for vertical rules
-        let y2_norms = self.encoding.y2.as_ref().map(|e| {
-            y_scale
-                .scale_type()
-                .normalize_column(y_scale, df_source.column(&e.field).unwrap())
-        });
+        let y2_norms = self
+            .encoding
+            .y2
+            .as_ref()
+            .map(|e| -> Result<_, ChartonError> {
+                Ok(y_scale
+                    .scale_type()
+                    .normalize_column(y_scale, df_source.column(&e.field)?))
+            })
+            .transpose()?;
 
         // Pre-normalize color aesthetics for data-driven mapping
         let color_norms = context.spec.aesthetics.color.as_ref().and_then(|m| {
@@ -78,7 +83,7 @@ impl MarkRenderer for Chart<MarkRule> {
         let is_flipped = context.coord.is_flipped();
 
         // --- STEP 3: PARALLEL GEOMETRY PROJECTION ---
-        let render_configs: Vec<LineConfig> = (0..row_count)
+        let render_configs: Vec<(usize, LineConfig)> = (0..row_count)
             .maybe_into_par_iter()
             .filter_map(|i| {
                 let x_n = x_norms[i]?;
@@ -105,24 +110,31 @@ impl MarkRenderer for Chart<MarkRule> {
                     &mark_config.color,
                 );
 
-                Some(LineConfig {
-                    x1: x1 as Precision,
-                    y1: y1 as Precision,
-                    x2: x2 as Precision,
-                    y2: y2 as Precision,
-                    color: final_color,
-                    width: mark_config.stroke_width as Precision,
-                    opacity: mark_config.opacity as Precision,
-                    dash: vec![],
-                })
+                Some((
+                    i,
+                    LineConfig {
+                        x1: x1 as Precision,
+                        y1: y1 as Precision,
+                        x2: x2 as Precision,
+                        y2: y2 as Precision,
+                        color: final_color,
+                        width: mark_config.stroke_width as Precision,
+                        opacity: mark_config.opacity as Precision,
+                        dash: vec![],
+                    },
+                ))
             })
             .collect();
 
         // --- STEP 4: SEQUENTIAL DRAW DISPATCH ---
         // Lines are drawn in original data order to maintain deterministic Z-indexing.
-        for config in render_configs {
+        for (i, config) in render_configs {
+            backend.set_tooltip(self.tooltip_text(i, context.spec.formats));
+            backend.set_href(self.href_url(i));
             backend.draw_line(config);
         }
+        backend.set_tooltip(None);
+        backend.set_href(None);
 
         Ok(())
     }
@@ -0,0 +1,167 @@
+use crate::Precision;
+use crate::core::context::PanelContext;
+use crate::core::layer::{RenderBackend, TextConfig};
+use crate::encode::Channel;
+use crate::mark::stack_label::StackLabelPart;
+use crate::scale::Tick;
+use crate::scale::inverse::TickFormatter;
+use crate::theme::TickFormat;
+use crate::visual::color::SingleColor;
+
+/// The values describing one segment of a stack.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct StackSegment {
+    pub(crate) value: f64,
+    /// The running total at the top of the segment.
+    pub(crate) cumulative: f64,
+    /// The total of the whole stack.
+    pub(crate) total: f64,
+}
+
+/// How the values of stack labels and tooltips are written.
+///
+/// The value axis formatter (or the value field's format) wins; otherwise the
+/// values follow the notation of the value axis ticks, which read as
+/// percentages for normalized stacks.
+pub(crate) struct StackFormat {
+    formatter: Option<TickFormatter>,
+    fallback: TickFormat,
+}
+
+impl StackFormat {
+    pub(crate) fn new(context: &PanelContext, field: &str, normalized: bool) -> Self {
+        let formats = context.spec.formats;
+        // A field format describes the raw values, not the shares of a normalized stack.
+        let formatter = if normalized {
+            formats.channel(Channel::Y)
+        } else {
+            formats.resolve(Channel::Y, field)
+        };
+
+        let fallback = if normalized {
+            TickFormat::Percent
+        } else {
+            let axis_len = if context.coord.is_flipped() {
+                context.panel.width
+            } else {
+                context.panel.height
+            };
+            let y_scale = context.coord.get_y_scale();
+            tick_label_format(
+                &y_scale.suggest_ticks(context.spec.theme.suggest_tick_count(axis_len)),
+            )
+        };
+
+        Self {
+            formatter: formatter.cloned(),
+            fallback,
+        }
+    }
+
+    fn format(&self, value: f64) -> String {
+        self.formatter
+            .as_ref()
+            .and_then(|f| f.format_value(value))
+            .or_else(|| self.fallback.format_value(value))
+            .unwrap_or_else(|| value.to_string())
+    }
+
+    fn format_part(&self, part: StackLabelPart, segment: StackSegment) -> String {
+        match part {
+            StackLabelPart::Segment => self.format(segment.value),
+            StackLabelPart::Cumulative => self.format(segment.cumulative),
+            StackLabelPart::Total => self.format(segment.total),
+            StackLabelPart::Percent => {
+                let share = if segment.total != 0.0 {
+                    segment.value / segment.total
+                } else {
+                    0.0
+                };
+                TickFormat::Percent.format_value(share).unwrap_or_default()
+            }
+        }
+    }
+
+    /// Writes the parts on one line, e.g. "12 · Σ30 · of 50 · 24%".
+    pub(crate) fn label(&self, parts: &[StackLabelPart], segment: StackSegment) -> String {
+        parts
+            .iter()
+            .map(|&part| {
+                let value = self.format_part(part, segment);
+                match part {
+                    StackLabelPart::Cumulative => format!("Σ{}", value),
+                    StackLabelPart::Total => format!("of {}", value),
+                    StackLabelPart::Segment | StackLabelPart::Percent => value,
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" · ")
+    }
+
+    /// Writes the parts as named tooltip lines, e.g. "Total: 50".
+    pub(crate) fn tooltip(&self, parts: &[StackLabelPart], segment: StackSegment) -> String {
+        parts
+            .iter()
+            .map(|&part| format!("{}: {}", part.name(), self.format_part(part, segment)))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Derives the notation of the value axis tick labels: their largest number of
+/// decimals, in scientific notation if any label uses it.
+fn tick_label_format(ticks: &[Tick]) -> TickFormat {
+    let (decimals, sci) = ticks
+        .iter()
+        .map(|t| {
+            let sci = t.label.contains(['E', 'e']);
+            let mantissa = t.label.split(['E', 'e']).next().unwrap_or_default();
+            let decimals = mantissa.split('.').nth(1).map_or(0, str::len);
+            (decimals, sci)
+        })
+        .fold((0, false), |(d, s), (d2, s2)| (d.max(d2), s || s2));
+    if sci {
+        TickFormat::Scientific
+    } else {
+        TickFormat::Precision(decimals)
+    }
+}
+
+/// Writes a label in the middle of a stack segment, spanning from `y_low_n` to
+/// `y_high_n` at `x_center_n` (all normalized). Segments too thin to hold a
+/// line of text are left unlabeled.
+pub(crate) fn render_stack_label(
+    text: String,
+    (x_center_n, y_low_n, y_high_n): (f64, f64, f64),
+    context: &PanelContext,
+    backend: &mut dyn RenderBackend,
+    opacity: Precision,
+) {
+    let theme = &context.spec.theme;
+    let font_size = theme.tick_label_size - 1.0;
+
+    let (x0, y0) = context.coord.transform(x_center_n, y_low_n, &context.panel);
+    let (x1, y1) = context
+        .coord
+        .transform(x_center_n, y_high_n, &context.panel);
+    if (x1 - x0).hypot(y1 - y0) < font_size * 1.2 {
+        return;
+    }
+
+    backend.draw_text(TextConfig {
+        x: ((x0 + x1) / 2.0) as Precision,
+        y: ((y0 + y1) / 2.0) as Precision,
+        text,
+        font_size: font_size as Precision,
+        font_family: theme.tick_label_family.clone(),
+        color: SingleColor::new("white"),
+        text_anchor: "middle".into(),
+        dominant_baseline: "middle".into(),
+        font_weight: "bold".into(),
+        font_style: "normal".into(),
+        opacity,
+        angle: 0.0,
+        direction: theme.text_direction.as_str().to_string(),
+        writing_mode: "horizontal-tb".to_string(),
+    });
+}
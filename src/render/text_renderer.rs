@@ -1,6 +1,7 @@
 use crate::Precision;
 use crate::chart::Chart;
 use crate::core::context::PanelContext;
+use crate::core::data::AnyValue;
 use crate::core::layer::{MarkRenderer, RenderBackend, TextConfig};
 use crate::core::utils::IntoParallelizable;
 use crate::error::ChartonError;
@@ -66,7 +67,7 @@ impl MarkRenderer for Chart<MarkText> {
 
         // --- STEP 3: PARALLEL PROCESSING ---
         // We process rows independently to handle high-density text labels efficiently.
-        let render_configs: Vec<TextConfig> = (0..row_count)
+        let render_configs: Vec<(usize, TextConfig)> = (0..row_count)
             .maybe_into_par_iter()
             .filter_map(|i| {
                 // Extract normalized coordinates
@@ -85,39 +86,55 @@ impl MarkRenderer for Chart<MarkText> {
 
                 // 3. Text Content Resolution:
                 // Prioritize data field from encoding, fallback to static mark text.
+                // Numbers follow the field's format when one is set.
                 let content = if let Some(ref text_enc) = self.encoding.text {
-                    df_source
-                        .column(&text_enc.field)
-                        .ok()?
-                        .get(i)
-                        .to_string()
-                        .unwrap_or_default()
-                        .to_string()
+                    let value = df_source.column(&text_enc.field).ok()?.get(i);
+                    let formatted = match value {
+                        AnyValue::Boolean(_)
+                        | AnyValue::Date(_)
+                        | AnyValue::Datetime(..)
+                        | AnyValue::Duration(_)
+                        | AnyValue::Time(_) => None,
+                        _ => value
+                            .to_f64()
+                            .and_then(|v| context.spec.formats.format(&text_enc.field, v)),
+                    };
+                    formatted.or_else(|| value.to_string()).unwrap_or_default()
                 } else {
                     mark_config.text.clone()
                 };
 
-                Some(TextConfig {
-                    x: px as Precision,
-                    y: py as Precision,
-                    text: content,
-                    font_size: mark_config.font_size as Precision,
-                    font_family: mark_config.font_family.clone(),
-                    color: fill,
-                    text_anchor: mark_config.text_anchor.to_string(),
-                    dominant_baseline: "middle".to_string(), // Common default for data point labels
-                    font_weight: mark_config.font_weight.to_string(),
-                    opacity: mark_config.opacity as Precision,
-                    angle: 0.0, // Data labels are horizontal by default
-                })
+                Some((
+                    i,
+                    TextConfig {
+                        x: px as Precision,
+                        y: py as Precision,
+                        text: content,
+                        font_size: mark_config.font_size as Precision,
+                        font_family: mark_config.font_family.clone(),
+                        color: fill,
+                        text_anchor: mark_config.text_anchor.to_string(),
+                        dominant_baseline: "middle".to_string(), // Common default for data point labels
+                        font_weight: mark_config.font_weight.to_string(),
+                        font_style: "normal".to_string(),
+                        opacity: mark_config.opacity as Precision,
+                        angle: self.angle_degrees(i) as Precision, // About the anchor point
+                        direction: context.spec.theme.text_direction.as_str().to_string(),
+                        writing_mode: "horizontal-tb".to_string(),
+                    },
+                ))
             })
             .collect();
 
         // --- STEP 4: SEQUENTIAL DRAW DISPATCH ---
         // Dispatch draw calls to the backend in deterministic data order.
-        for config in render_configs {
+        for (i, config) in render_configs {
+            backend.set_tooltip(self.tooltip_text(i, context.spec.formats));
+            backend.set_href(self.href_url(i));
             backend.draw_text(config);
         }
+        backend.set_tooltip(None);
+        backend.set_href(None);
 
         Ok(())
     }
@@ -68,7 +68,7 @@ impl MarkRenderer for Chart<MarkTick> {
 
         // --- STEP 3: PARALLEL GEOMETRY PROJECTION ---
         // Ticks are independent rectangles; we calculate their bounds in parallel.
-        let render_configs: Vec<RectConfig> = (0..row_count)
+        let render_configs: Vec<(usize, RectConfig)> = (0..row_count)
             .maybe_into_par_iter()
             .filter_map(|i| {
                 let x_n = x_norms[i]?;
@@ -107,24 +107,31 @@ impl MarkRenderer for Chart<MarkTick> {
                     )
                 };
 
-                Some(RectConfig {
-                    x: rx as Precision,
-                    y: ry as Precision,
-                    width: rw as Precision,
-                    height: rh as Precision,
-                    fill,
-                    stroke: fill, // Ticks usually use fill for the "border" visual
-                    stroke_width: 0.0,
-                    opacity: mark_config.opacity as Precision,
-                })
+                Some((
+                    i,
+                    RectConfig {
+                        x: rx as Precision,
+                        y: ry as Precision,
+                        width: rw as Precision,
+                        height: rh as Precision,
+                        fill,
+                        stroke: fill, // Ticks usually use fill for the "border" visual
+                        stroke_width: 0.0,
+                        opacity: mark_config.opacity as Precision,
+                    },
+                ))
             })
             .collect();
 
         // --- STEP 4: SEQUENTIAL DRAW DISPATCH ---
         // Ticks are dispatched to the backend in deterministic data order.
-        for config in render_configs {
+        for (i, config) in render_configs {
+            backend.set_tooltip(self.tooltip_text(i, context.spec.formats));
+            backend.set_href(self.href_url(i));
             backend.draw_rect(config);
         }
+        backend.set_tooltip(None);
+        backend.set_href(None);
 
         Ok(())
     }
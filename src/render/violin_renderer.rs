@@ -0,0 +1,249 @@
+use crate::chart::Chart;
+use crate::core::context::PanelContext;
+use crate::core::layer::{LineConfig, MarkRenderer, PolygonConfig, RectConfig, RenderBackend};
+use crate::core::utils::IntoParallelizable;
+use crate::error::ChartonError;
+use crate::mark::violin::MarkViolin;
+use crate::visual::color::SingleColor;
+use crate::{Precision, TEMP_SUFFIX};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+impl MarkRenderer for Chart<MarkViolin> {
+    /// Renders violins as mirrored density polygons around each category position.
+    fn render_marks(
+        &self,
+        backend: &mut dyn RenderBackend,
+        context: &PanelContext,
+    ) -> Result<(), ChartonError> {
+        let df_source = &self.data;
+        let row_count = df_source.height();
+        if row_count == 0 {
+            return Ok(());
+        }
+
+        // --- STEP 1: INITIALIZATION & VALIDATION ---
+        let mark_config = self
+            .mark
+            .as_ref()
+            .ok_or_else(|| ChartonError::Mark("Violin config missing".into()))?;
+        let x_enc = self
+            .encoding
+            .x
+            .as_ref()
+            .ok_or_else(|| ChartonError::Encoding("X encoding missing".into()))?;
+
+        let x_scale = context.coord.get_x_scale();
+        let y_scale = context.coord.get_y_scale();
+
+        // --- STEP 2: DATA NORMALIZATION ---
+        let x_norms = x_scale
+            .scale_type()
+            .normalize_column(x_scale, df_source.column(&x_enc.field)?);
+        let median_col = df_source.column(&format!("{}_median", TEMP_SUFFIX))?;
+        let min_col = df_source.column(&format!("{}_min", TEMP_SUFFIX))?;
+        let max_col = df_source.column(&format!("{}_max", TEMP_SUFFIX))?;
+        let q1_col = df_source.column(&format!("{}_q1", TEMP_SUFFIX))?;
+        let q3_col = df_source.column(&format!("{}_q3", TEMP_SUFFIX))?;
+        let density_col = df_source.column(&format!("{}_density", TEMP_SUFFIX))?;
+        let groups_count_col = df_source.column(&format!("{}_groups_count", TEMP_SUFFIX))?;
+        let sub_idx_col = df_source.column(&format!("{}_sub_idx", TEMP_SUFFIX))?;
+
+        let color_norms = context.spec.aesthetics.color.as_ref().and_then(|m| {
+            let s = m.scale_impl.as_ref();
+            let col = df_source.column(&m.field).ok()?;
+            Some(s.scale_type().normalize_column(s, col))
+        });
+
+        let unit_step_norm = (x_scale.normalize(1.0) - x_scale.normalize(0.0)).abs();
+        let base_fill = mark_config
+            .color
+            .unwrap_or_else(|| SingleColor::new("steelblue"));
+
+        // --- STEP 3: PARALLEL GEOMETRY COMPUTATION ---
+        let violins: Vec<ViolinElement> = (0..row_count)
+            .maybe_into_par_iter()
+            .filter_map(|i| {
+                // Gap and boundary rows carry no statistics.
+                let median = median_col.get(i).to_f64()?;
+                let lo = min_col.get(i).to_f64()?;
+                let hi = max_col.get(i).to_f64()?;
+
+                let total_groups = groups_count_col.get(i).to_f64().unwrap_or(1.0);
+                let sub_idx = sub_idx_col.get(i).to_f64().unwrap_or(0.0);
+
+                // --- DODGE LOGIC (shared with boxplot) ---
+                let violin_width_data = mark_config.width.min(
+                    mark_config.span / (total_groups + (total_groups - 1.0) * mark_config.spacing),
+                );
+                let violin_width_norm = violin_width_data * unit_step_norm;
+                let spacing_norm = violin_width_norm * mark_config.spacing;
+                let offset_norm =
+                    (sub_idx - (total_groups - 1.0) / 2.0) * (violin_width_norm + spacing_norm);
+                let x_center_n = x_norms[i]? + offset_norm;
+                let half_width_n = violin_width_norm / 2.0;
+
+                let fill = match color_norms {
+                    Some(ref norms) => self.resolve_color_from_value(norms[i], context, &base_fill),
+                    None => base_fill,
+                };
+
+                // --- DENSITY PROFILE ---
+                let profile: Vec<f64> = density_col
+                    .get(i)
+                    .to_string()
+                    .map(|raw| {
+                        raw.trim_matches(|c| c == '[' || c == ']')
+                            .split(',')
+                            .filter_map(|v| v.trim().parse::<f64>().ok())
+                            .collect()
+                    })
+                    .unwrap_or_default();
+
+                let step = if profile.len() > 1 {
+                    (hi - lo) / (profile.len() - 1) as f64
+                } else {
+                    0.0
+                };
+                let project = |offset_n: f64, value: f64| {
+                    let (px, py) = context.coord.transform(
+                        x_center_n + offset_n,
+                        y_scale.normalize(value),
+                        &context.panel,
+                    );
+                    (px as Precision, py as Precision)
+                };
+
+                // Outline: one side from min to max, the mirrored side back down.
+                let mut points = Vec::with_capacity(profile.len() * 2);
+                for (k, d) in profile.iter().enumerate() {
+                    points.push(project(d * half_width_n, lo + k as f64 * step));
+                }
+                for (k, d) in profile.iter().enumerate().rev() {
+                    points.push(project(-d * half_width_n, lo + k as f64 * step));
+                }
+
+                // The median line spans the violin's width at the median.
+                let median_half = if profile.len() > 1 && step > 0.0 {
+                    let pos = ((median - lo) / step).clamp(0.0, (profile.len() - 1) as f64);
+                    let k = (pos.floor() as usize).min(profile.len() - 2);
+                    let t = pos - k as f64;
+                    (profile[k] * (1.0 - t) + profile[k + 1] * t) * half_width_n
+                } else {
+                    half_width_n
+                };
+                let median_line = mark_config
+                    .show_median
+                    .then(|| (project(-median_half, median), project(median_half, median)));
+
+                // --- INNER BOX (quartiles and range) ---
+                let inner_box = if mark_config.inner_box {
+                    let q1 = q1_col.get(i).to_f64().unwrap_or(median);
+                    let q3 = q3_col.get(i).to_f64().unwrap_or(median);
+                    let box_half = half_width_n * 0.1;
+                    let (bx1, by1) = project(-box_half, q1);
+                    let (bx2, by2) = project(box_half, q3);
+                    Some(InnerBox {
+                        rect: RectConfig {
+                            x: bx1.min(bx2),
+                            y: by1.min(by2),
+                            width: (bx1 - bx2).abs(),
+                            height: (by1 - by2).abs(),
+                            fill: mark_config.stroke,
+                            stroke: mark_config.stroke,
+                            stroke_width: mark_config.stroke_width as Precision,
+                            opacity: 1.0,
+                        },
+                        whisker: (project(0.0, lo), project(0.0, hi)),
+                    })
+                } else {
+                    None
+                };
+
+                Some(ViolinElement {
+                    body: (points.len() > 2).then_some(PolygonConfig {
+                        points,
+                        fill,
+                        stroke: mark_config.stroke,
+                        stroke_width: mark_config.stroke_width as Precision,
+                        opacity: mark_config.opacity as Precision,
+                    }),
+                    median_line,
+                    inner_box,
+                })
+            })
+            .collect();
+
+        // --- STEP 4: SEQUENTIAL RENDERING ---
+        for el in violins {
+            if let Some(body) = el.body {
+                backend.draw_polygon(body);
+            }
+
+            if let Some(inner) = el.inner_box {
+                let ((x1, y1), (x2, y2)) = inner.whisker;
+                backend.draw_line(LineConfig {
+                    x1,
+                    y1,
+                    x2,
+                    y2,
+                    color: mark_config.stroke,
+                    width: mark_config.stroke_width as Precision,
+                    opacity: 1.0,
+                    dash: vec![],
+                });
+                backend.draw_rect(inner.rect);
+            }
+
+            if let Some(((x1, y1), (x2, y2))) = el.median_line {
+                backend.draw_line(LineConfig {
+                    x1,
+                    y1,
+                    x2,
+                    y2,
+                    color: mark_config.stroke,
+                    width: (mark_config.stroke_width * 2.0) as Precision,
+                    opacity: 1.0,
+                    dash: vec![],
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Pre-calculated screen geometry for a single violin.
+struct ViolinElement {
+    body: Option<PolygonConfig>,
+    median_line: Option<((Precision, Precision), (Precision, Precision))>,
+    inner_box: Option<InnerBox>,
+}
+
+/// The slim box plot drawn inside a violin.
+struct InnerBox {
+    rect: RectConfig,
+    whisker: ((Precision, Precision), (Precision, Precision)),
+}
+
+impl Chart<MarkViolin> {
+    /// Resolves aesthetic color based on normalized values and scale mapping.
+    fn resolve_color_from_value(
+        &self,
+        val: Option<f64>,
+        context: &PanelContext,
+        fallback: &SingleColor,
+    ) -> SingleColor {
+        if let (Some(v), Some(mapping)) = (val, &context.spec.aesthetics.color) {
+            let s_trait = mapping.scale_impl.as_ref();
+            s_trait
+                .mapper()
+                .as_ref()
+                .map(|m| m.map_to_color(v, s_trait.logical_max()))
+                .unwrap_or(*fallback)
+        } else {
+            *fallback
+        }
+    }
+}
@@ -1,4 +1,4 @@
-#![cfg(all(feature = "wgpu", feature = "png"))]
+#![cfg(all(feature = "wgpu", feature = "raster"))]
 
 use crate::core::composite::LayeredChart;
 use crate::core::conversion::IntoLayered;
@@ -40,7 +40,18 @@ pub struct WgpuRenderer {
 
 impl WgpuRenderer {
     /// Creates a new renderer, automatically initializing the WGPU instance, adapter, and device.
+    ///
+    /// # Panics
+    /// Panics if no GPU adapter or device is available; use [`WgpuRenderer::try_new`]
+    /// to handle that case.
+    #[allow(clippy::expect_used)]
     pub fn new() -> Self {
+        Self::try_new().expect("charton: failed to initialize wgpu")
+    }
+
+    /// Fallible variant of [`WgpuRenderer::new`], reporting a missing GPU adapter or
+    /// device as a `ChartonError::Render`.
+    pub fn try_new() -> Result<Self, ChartonError> {
         let instance = wgpu::Instance::new(wgpu::InstanceDescriptor {
             backends: wgpu::Backends::all(),
             flags: wgpu::InstanceFlags::default(),
@@ -55,7 +66,7 @@ impl WgpuRenderer {
             compatible_surface: None,
             force_fallback_adapter: false,
         }))
-        .expect("charton: no suitable GPU adapter found");
+        .map_err(|e| ChartonError::Render(format!("No suitable GPU adapter found: {}", e)))?;
 
         // Request the logical device and command queue
         let (device, queue) = pollster::block_on(adapter.request_device(&wgpu::DeviceDescriptor {
@@ -64,9 +75,9 @@ impl WgpuRenderer {
             required_limits: wgpu::Limits::default(),
             ..Default::default()
         }))
-        .expect("charton: failed to create wgpu device");
+        .map_err(|e| ChartonError::Render(format!("Failed to create wgpu device: {}", e)))?;
 
-        Self { device, queue }
+        Ok(Self { device, queue })
     }
 
     /// Renders the chart and returns the raw RGBA pixel data.
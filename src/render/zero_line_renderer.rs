@@ -0,0 +1,43 @@
+use crate::Precision;
+use crate::core::context::PanelContext;
+use crate::core::layer::{PathConfig, PathTopology, RenderBackend};
+use crate::mark::zero_line::{ZeroLine, ZeroLinePosition};
+use crate::visual::color::SingleColor;
+
+/// Draws the zero reference line across the panel if it is visible, drawn at
+/// `position` and zero lies within the value domain.
+pub(crate) fn render_zero_line(
+    zero_line: &ZeroLine,
+    position: ZeroLinePosition,
+    backend: &mut dyn RenderBackend,
+    context: &PanelContext,
+) {
+    if !zero_line.visible || zero_line.position != position {
+        return;
+    }
+
+    let y_scale = context.coord.get_y_scale();
+    let (y_min, y_max) = y_scale.domain();
+    if y_min > 0.0 || y_max < 0.0 {
+        return;
+    }
+
+    // Normalized endpoints go through the coordinate system, so flipped charts
+    // get a vertical line.
+    let b_norm = y_scale.normalize(0.0);
+    let (px1, py1) = context.coord.transform(0.0, b_norm, &context.panel);
+    let (px2, py2) = context.coord.transform(1.0, b_norm, &context.panel);
+
+    backend.draw_path(PathConfig {
+        points: vec![
+            (px1 as Precision, py1 as Precision),
+            (px2 as Precision, py2 as Precision),
+        ],
+        fill: SingleColor::none(),
+        stroke: zero_line.color.unwrap_or(context.spec.theme.axes_color),
+        stroke_width: zero_line.width as Precision,
+        opacity: 1.0,
+        dash: zero_line.dash.iter().map(|&d| d as Precision).collect(),
+        topology: PathTopology::Simple,
+    });
+}
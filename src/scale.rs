@@ -1,7 +1,10 @@
 pub mod discrete;
+pub mod inverse;
 pub mod linear;
 pub mod log;
 pub mod mapper;
+pub(crate) mod nice_ticks;
+pub mod reversed;
 pub mod temporal;
 
 use self::discrete::DiscreteScale;
@@ -11,6 +14,7 @@ use self::mapper::VisualMapper;
 use self::temporal::TemporalScale;
 use crate::core::utils::IntoParallelizable;
 use crate::error::ChartonError;
+use crate::theme::TickFormat;
 use std::sync::{Arc, RwLock};
 use time::OffsetDateTime;
 
@@ -23,11 +27,13 @@ use rayon::prelude::*;
 /// and an additive constant. This prevents data marks from clipping at the
 /// edges of the coordinate system.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Expansion {
     /// Multiplicative factors (lower_mult, upper_mult).
     /// e.g., (0.05, 0.05) adds 5% padding relative to the data range.
     pub mult: (f64, f64),
     /// Additive constants in data units (lower_add, upper_add).
+    /// On log scales both factors apply in log space, so `add` counts powers of the base.
     pub add: (f64, f64),
 }
 
@@ -98,7 +104,8 @@ impl<const N: usize> IntoExplicitTicks for [f64; N] {
 }
 
 /// Implementation for string slices, commonly used for categorical labels.
-/// Maps to `ExplicitTick::Discrete`.
+/// Maps to `ExplicitTick::Discrete`. On temporal axes the strings are parsed
+/// as dates (e.g. "2024-03-01"), and rendering fails on one that is not.
 impl IntoExplicitTicks for Vec<&str> {
     fn into_explicit_ticks(self) -> Vec<ExplicitTick> {
         self.into_iter()
@@ -107,6 +114,15 @@ impl IntoExplicitTicks for Vec<&str> {
     }
 }
 
+/// Array support for string slices.
+impl<const N: usize> IntoExplicitTicks for [&str; N] {
+    fn into_explicit_ticks(self) -> Vec<ExplicitTick> {
+        self.into_iter()
+            .map(|s| ExplicitTick::Discrete(s.to_string()))
+            .collect()
+    }
+}
+
 /// Implementation for raw integers.
 /// In the context of Charton, these are treated as high-precision nanosecond timestamps.
 /// Maps to `ExplicitTick::Timestamp`.
@@ -133,6 +149,7 @@ impl IntoExplicitTicks for Vec<OffsetDateTime> {
 
 /// The mathematical strategy for mapping data to a [0, 1] normalized space.
 #[derive(Clone, Debug, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Scale {
     Linear,
     Log,
@@ -177,6 +194,7 @@ impl Scale {
 
 /// A type-safe container for data boundaries.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ScaleDomain {
     Continuous(f64, f64),
     Discrete(Vec<String>),
@@ -213,6 +231,14 @@ pub trait ScaleTrait: std::fmt::Debug + Send + Sync {
     /// Generates suggested tick marks for axes or legends.
     fn suggest_ticks(&self, count: usize) -> Vec<Tick>;
 
+    /// Generates about `count` ticks for a user-requested tick count.
+    ///
+    /// Continuous linear scales space them by a "nice" step; other scales fall
+    /// back to `suggest_ticks`.
+    fn ticks_for_count(&self, count: usize) -> Vec<Tick> {
+        self.suggest_ticks(count)
+    }
+
     /// Generates user-requested ticks.
     fn create_explicit_ticks(&self, explicit: &[ExplicitTick]) -> Vec<Tick>;
 
@@ -221,16 +247,41 @@ pub trait ScaleTrait: std::fmt::Debug + Send + Sync {
 
     /// Equidistant sampling of the domain.
     fn sample_n(&self, n: usize) -> Vec<Tick>;
+
+    /// The ordered category labels of discrete scales; `None` for every other scale.
+    fn categories(&self) -> Option<&[String]> {
+        None
+    }
+
+    /// The logarithm base of log scales; `None` for every other scale.
+    fn log_base(&self) -> Option<f64> {
+        None
+    }
+
+    /// Positions of unlabeled minor ticks between the major ticks, in data units.
+    ///
+    /// Only scales with a natural subdivision (e.g. log scales) provide them.
+    fn minor_ticks(&self) -> Vec<f64> {
+        Vec::new()
+    }
+
+    /// Whether tick labels come from a user formatter and must be drawn as they are.
+    fn has_custom_labels(&self) -> bool {
+        false
+    }
 }
 
 /// Factory function to create a fully initialized scale.
 ///
 /// It resolves the domain expansion and encapsulates the concrete implementation
-/// inside an `Arc` for efficient sharing between chart layers.
+/// inside an `Arc` for efficient sharing between chart layers. `log_base` is only
+/// used by `Scale::Log`.
 pub fn create_scale(
     scale_type: &Scale,
     domain_data: ScaleDomain,
     expansion: Expansion,
+    log_base: f64,
+    time_format: Option<&str>,
     mapper: Option<VisualMapper>, // Added: Associate visual mapping logic at creation
 ) -> Result<Arc<dyn ScaleTrait>, ChartonError> {
     let scale: Box<dyn ScaleTrait> = match scale_type {
@@ -251,12 +302,33 @@ pub fn create_scale(
         }
         Scale::Log => {
             if let ScaleDomain::Continuous(min, max) = domain_data {
-                let log_min = min.ln();
-                let log_max = max.ln();
+                if !(min > 0.0 && min.is_finite() && max.is_finite()) {
+                    return Err(ChartonError::Scale(format!(
+                        "Log scale requires strictly positive data, but the domain starts at {}; \
+                         filter out values <= 0 or use a linear scale",
+                        min
+                    )));
+                }
+                if log_base <= 1.0 || !log_base.is_finite() {
+                    return Err(ChartonError::Scale(format!(
+                        "Log scale base must be greater than 1, got {}",
+                        log_base
+                    )));
+                }
+                // Expansion happens in log space: `mult` is relative to the number of
+                // decades covered and `add` is measured in powers of the base.
+                let log_min = min.log(log_base);
+                let log_max = max.log(log_base);
                 let log_range = log_max - log_min;
-                let expanded_min = (log_min - log_range * expansion.mult.0).exp();
-                let expanded_max = (log_max + log_range * expansion.mult.1).exp();
-                Box::new(LogScale::new((expanded_min, expanded_max), 10.0, mapper)?)
+                let expanded_min =
+                    log_base.powf(log_min - log_range * expansion.mult.0 - expansion.add.0);
+                let expanded_max =
+                    log_base.powf(log_max + log_range * expansion.mult.1 + expansion.add.1);
+                Box::new(LogScale::new(
+                    (expanded_min, expanded_max),
+                    log_base,
+                    mapper,
+                )?)
             } else {
                 return Err(ChartonError::Scale(
                     "Log scale requires Continuous domain".into(),
@@ -283,10 +355,20 @@ pub fn create_scale(
                 let upper_pad_ns = (diff_ns * expansion.mult.1 + expansion.add.1 * 1e9) as i64;
 
                 // 3. Pass the raw i64 nanoseconds to the constructor
-                Box::new(TemporalScale::new(
-                    (min_ns - lower_pad_ns, max_ns + upper_pad_ns),
-                    mapper,
-                ))
+                let scale =
+                    TemporalScale::new((min_ns - lower_pad_ns, max_ns + upper_pad_ns), mapper);
+
+                // 4. Apply the strftime-style label format, if any
+                match time_format {
+                    Some(fmt) => {
+                        let items =
+                            time::format_description::parse_strftime_owned(fmt).map_err(|e| {
+                                ChartonError::Scale(format!("Invalid time format '{}': {}", fmt, e))
+                            })?;
+                        Box::new(scale.with_format(items))
+                    }
+                    None => Box::new(scale),
+                }
             } else {
                 return Err(ChartonError::Scale(
                     "Time scale requires Temporal domain".into(),
@@ -341,6 +423,86 @@ pub fn get_normalized_value(
     }
 }
 
+/// Relabels log-axis ticks that fall on `m × base^k` in the power notation of
+/// `format`: "10^3", "10³" or "1e3".
+///
+/// On base-10 axes the 2× and 5× multiples of the 1-2-5 sequence are written
+/// "2×10^3", "2×10³" or "2e3"; `TickFormat::Exponent` only applies to base 10.
+/// Other ticks (e.g. on narrow log domains) keep their automatic labels.
+pub(crate) fn format_log_power_ticks(ticks: Vec<Tick>, base: f64, format: TickFormat) -> Vec<Tick> {
+    let base_label = if (base - std::f64::consts::E).abs() < 1e-12 {
+        "e".to_string()
+    } else if base.fract() == 0.0 {
+        format!("{}", base)
+    } else {
+        format!("{:.2}", base)
+    };
+    let multiples: &[u32] = if base == 10.0 { &[1, 2, 5] } else { &[1] };
+
+    ticks
+        .into_iter()
+        .map(|tick| {
+            let Some((m, exp)) = multiples.iter().find_map(|&m| {
+                let exp = (tick.value / m as f64).log(base);
+                (tick.value > 0.0 && (exp - exp.round()).abs() < 1e-9)
+                    .then(|| (m, exp.round() as i32))
+            }) else {
+                return tick;
+            };
+            let prefix = if m == 1 {
+                String::new()
+            } else {
+                format!("{}×", m)
+            };
+            let label = match format {
+                TickFormat::LogBase => format!("{}{}^{}", prefix, base_label, exp),
+                TickFormat::Superscript => {
+                    format!("{}{}{}", prefix, base_label, to_superscript(exp))
+                }
+                TickFormat::Exponent if base == 10.0 => format!("{}e{}", m, exp),
+                _ => return tick,
+            };
+            Tick {
+                value: tick.value,
+                label,
+            }
+        })
+        .collect()
+}
+
+const SUPERSCRIPT_DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
+/// Writes an integer with Unicode superscript characters, e.g. -3 as "⁻³".
+fn to_superscript(value: i32) -> String {
+    value
+        .to_string()
+        .chars()
+        .map(|c| match c.to_digit(10) {
+            Some(d) => SUPERSCRIPT_DIGITS[d as usize],
+            None => '⁻',
+        })
+        .collect()
+}
+
+/// Splits the trailing Unicode superscript characters off a label, returning
+/// the base text and the exponent in plain digits: "10⁻³" gives ("10", "-3").
+pub(crate) fn split_superscript(label: &str) -> Option<(&str, String)> {
+    let start = label
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| *c == '⁻' || SUPERSCRIPT_DIGITS.contains(c))
+        .last()
+        .map(|(i, _)| i)?;
+    let exponent = label[start..]
+        .chars()
+        .map(|c| match SUPERSCRIPT_DIGITS.iter().position(|&d| d == c) {
+            Some(d) => char::from_digit(d as u32, 10).unwrap_or('0'),
+            None => '-',
+        })
+        .collect();
+    Some((&label[..start], exponent))
+}
+
 /// A universal tick formatter following data visualization best practices.
 /// Suitable for linear, power, and log scales.
 pub(crate) fn format_ticks(values: &[f64]) -> Vec<Tick> {
@@ -382,6 +544,14 @@ pub(crate) fn format_ticks(values: &[f64]) -> Vec<Tick> {
     } else {
         0
     };
+    // Steps such as 2.5 or 0.25 need one more digit than their magnitude suggests.
+    if !use_sci && step > 0.0 && precision < 6 {
+        let is_whole = |v: f64| (v - v.round()).abs() < 1e-6 * v.abs().max(1.0);
+        let scaled = step * 10f64.powi(precision as i32);
+        if !is_whole(scaled) && is_whole(scaled * 10.0) {
+            precision += 1;
+        }
+    }
 
     // 4. Initial Formatting Pass
     let mut labels: Vec<String> = values
@@ -188,6 +188,10 @@ impl ScaleTrait for DiscreteScale {
         ScaleDomain::Discrete(self.domain.clone())
     }
 
+    fn categories(&self) -> Option<&[String]> {
+        Some(&self.domain)
+    }
+
     /// Provides a sample of categories when the total count is too large for a legend.
     ///
     /// If the domain is small, it returns all categories. If it exceeds `n`,
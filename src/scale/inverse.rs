@@ -0,0 +1,346 @@
+use super::mapper::VisualMapper;
+use super::{ExplicitTick, Scale, ScaleDomain, ScaleTrait, Tick};
+use crate::theme::TickFormat;
+use std::sync::Arc;
+
+/// Maps pre-transformed data back to its original units for tick labels.
+///
+/// Unlike a log scale, the axis stays linear in the transformed values: tick
+/// positions, domains and data are untouched, and only the label text shows
+/// the original value. A `log2(cpm)` column with `Exp2` keeps evenly spaced
+/// ticks at 8, 10, 12 but labels them 256, 1,024 and 4,096, whereas a log
+/// scale on raw `cpm` would place ticks at powers of the base.
+#[derive(Clone)]
+pub enum InverseTransform {
+    /// `2^v`, undoing `log2`.
+    Exp2,
+    /// `10^v`, undoing `log10`.
+    Exp10,
+    /// `mean + v * sd`, undoing a z-score standardization.
+    ZScore { mean: f64, sd: f64 },
+    /// Any other inverse function.
+    Custom(Arc<dyn Fn(f64) -> f64 + Send + Sync>),
+}
+
+impl InverseTransform {
+    /// Applies the inverse to a transformed value.
+    pub fn apply(&self, value: f64) -> f64 {
+        match self {
+            InverseTransform::Exp2 => value.exp2(),
+            InverseTransform::Exp10 => 10f64.powf(value),
+            InverseTransform::ZScore { mean, sd } => mean + value * sd,
+            InverseTransform::Custom(f) => f(value),
+        }
+    }
+}
+
+impl std::fmt::Debug for InverseTransform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InverseTransform::Exp2 => write!(f, "Exp2"),
+            InverseTransform::Exp10 => write!(f, "Exp10"),
+            InverseTransform::ZScore { mean, sd } => {
+                write!(f, "ZScore {{ mean: {}, sd: {} }}", mean, sd)
+            }
+            InverseTransform::Custom(_) => write!(f, "Custom(..)"),
+        }
+    }
+}
+
+impl<F> From<F> for InverseTransform
+where
+    F: Fn(f64) -> f64 + Send + Sync + 'static,
+{
+    fn from(f: F) -> Self {
+        InverseTransform::Custom(Arc::new(f))
+    }
+}
+
+/// Writes tick labels for continuous scales.
+///
+/// Accepts a built-in [`TickFormat`] or any `Fn(f64) -> String`. When the
+/// axis also has an [`InverseTransform`], the formatter receives the value in
+/// original units.
+#[derive(Clone)]
+pub enum TickFormatter {
+    /// A built-in notation, e.g. `TickFormat::Percent`.
+    Format(TickFormat),
+    /// Any other formatting function.
+    Custom(Arc<dyn Fn(f64) -> String + Send + Sync>),
+}
+
+impl TickFormatter {
+    /// Writes a single value; `None` for the log notations, which depend on the scale.
+    pub(crate) fn format_value(&self, value: f64) -> Option<String> {
+        match self {
+            TickFormatter::Format(format) => format.format_value(value),
+            TickFormatter::Custom(f) => Some(f(value)),
+        }
+    }
+}
+
+impl std::fmt::Debug for TickFormatter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TickFormatter::Format(format) => write!(f, "Format({:?})", format),
+            TickFormatter::Custom(_) => write!(f, "Custom(..)"),
+        }
+    }
+}
+
+impl From<TickFormat> for TickFormatter {
+    fn from(format: TickFormat) -> Self {
+        TickFormatter::Format(format)
+    }
+}
+
+impl<F> From<F> for TickFormatter
+where
+    F: Fn(f64) -> String + Send + Sync + 'static,
+{
+    fn from(f: F) -> Self {
+        TickFormatter::Custom(Arc::new(f))
+    }
+}
+
+/// Writes a value in original units: whole numbers with thousands separators
+/// from 100 upwards, three significant digits below.
+pub(crate) fn format_original_units(value: f64) -> String {
+    if !value.is_finite() {
+        return value.to_string();
+    }
+    if value.abs() >= 100.0 {
+        let sign = if value < 0.0 { "-" } else { "" };
+        return format!(
+            "{}{}",
+            sign,
+            group_thousands(&format!("{:.0}", value.abs()))
+        );
+    }
+    if value == 0.0 {
+        return "0".to_string();
+    }
+    let decimals = (2 - value.abs().log10().floor() as i32).max(0) as usize;
+    let label = format!("{:.*}", decimals, value);
+    if label.contains('.') {
+        label
+            .trim_end_matches('0')
+            .trim_end_matches('.')
+            .to_string()
+    } else {
+        label
+    }
+}
+
+/// Inserts a comma between every three digits of an unsigned integer string.
+pub(crate) fn group_thousands(digits: &str) -> String {
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(c);
+    }
+    grouped
+}
+
+/// Writes a fraction as a percentage with at most two decimals, e.g. 0.125 as "12.5%".
+pub(crate) fn format_percent(value: f64) -> String {
+    let label = format!("{:.2}", value * 100.0);
+    let label = label.trim_end_matches('0').trim_end_matches('.');
+    // Rounding can leave "-0" for tiny negative values.
+    let label = if label == "-0" { "0" } else { label };
+    format!("{}%", label)
+}
+
+/// Writes a value with an SI prefix and at most two decimals, e.g. 1500000 as "1.5M".
+pub(crate) fn format_si(value: f64) -> String {
+    const PREFIXES: [(f64, &str); 8] = [
+        (1e12, "T"),
+        (1e9, "G"),
+        (1e6, "M"),
+        (1e3, "k"),
+        (1.0, ""),
+        (1e-3, "m"),
+        (1e-6, "µ"),
+        (1e-9, "n"),
+    ];
+    if !value.is_finite() || value == 0.0 {
+        return if value == 0.0 {
+            "0".to_string()
+        } else {
+            value.to_string()
+        };
+    }
+    let (factor, prefix) = PREFIXES
+        .iter()
+        .find(|(factor, _)| value.abs() >= *factor * 0.9995)
+        .unwrap_or(&PREFIXES[PREFIXES.len() - 1]);
+    let label = format!("{:.2}", value / factor);
+    let label = label.trim_end_matches('0').trim_end_matches('.');
+    format!("{}{}", label, prefix)
+}
+
+/// Writes a value in scientific notation with at most two decimals in the
+/// mantissa, e.g. 1000000000 as "1.0e9" and 0.0025 as "2.5e-3".
+pub(crate) fn format_scientific(value: f64) -> String {
+    if !value.is_finite() || value == 0.0 {
+        return if value == 0.0 {
+            "0".to_string()
+        } else {
+            value.to_string()
+        };
+    }
+    let label = format!("{:.2e}", value);
+    let (mantissa, exponent) = label.split_once('e').unwrap_or((&label, "0"));
+    // Keep one decimal so the label reads as a mantissa: "1.0e9", not "1e9".
+    let mantissa = mantissa.trim_end_matches('0');
+    let mantissa = match mantissa.strip_suffix('.') {
+        Some(whole) => format!("{}.0", whole),
+        None => mantissa.to_string(),
+    };
+    format!("{}e{}", mantissa, exponent)
+}
+
+/// How an [`InverseLabelScale`] writes its tick labels.
+#[derive(Debug)]
+enum TickLabels {
+    /// The inverse-transformed value in original units.
+    Inverse(InverseTransform),
+    /// The value as a percentage of 1, for normalized stacks.
+    Percent,
+    /// The value, inverse-transformed first if set, written by a formatter.
+    Formatted(Option<InverseTransform>, TickFormatter),
+}
+
+/// A position scale whose tick labels show the inverse-transformed values.
+///
+/// Every other method delegates to the wrapped scale, so marks, domains and
+/// tick positions are the same as without the wrapper.
+#[derive(Debug)]
+pub(crate) struct InverseLabelScale {
+    inner: Arc<dyn ScaleTrait>,
+    labels: TickLabels,
+}
+
+impl InverseLabelScale {
+    pub(crate) fn wrap(
+        inner: Arc<dyn ScaleTrait>,
+        inverse: InverseTransform,
+    ) -> Arc<dyn ScaleTrait> {
+        Arc::new(Self {
+            inner,
+            labels: TickLabels::Inverse(inverse),
+        })
+    }
+
+    /// Wraps a scale over fractions so its ticks read "0%" to "100%".
+    pub(crate) fn wrap_percent(inner: Arc<dyn ScaleTrait>) -> Arc<dyn ScaleTrait> {
+        Arc::new(Self {
+            inner,
+            labels: TickLabels::Percent,
+        })
+    }
+
+    /// Wraps a continuous scale so its tick labels are written by `formatter`.
+    pub(crate) fn wrap_formatter(
+        inner: Arc<dyn ScaleTrait>,
+        inverse: Option<InverseTransform>,
+        formatter: TickFormatter,
+    ) -> Arc<dyn ScaleTrait> {
+        Arc::new(Self {
+            inner,
+            labels: TickLabels::Formatted(inverse, formatter),
+        })
+    }
+
+    fn relabel(&self, ticks: Vec<Tick>) -> Vec<Tick> {
+        // Log notations depend on the scale, so built-in formats relabel the whole set.
+        if let TickLabels::Formatted(None, TickFormatter::Format(format)) = &self.labels {
+            return format.apply(self.inner.as_ref(), ticks);
+        }
+        ticks
+            .into_iter()
+            .map(|tick| Tick {
+                label: match &self.labels {
+                    TickLabels::Inverse(inverse) => {
+                        format_original_units(inverse.apply(tick.value))
+                    }
+                    TickLabels::Percent => format_percent(tick.value),
+                    TickLabels::Formatted(inverse, formatter) => {
+                        let value = inverse.as_ref().map_or(tick.value, |i| i.apply(tick.value));
+                        match formatter {
+                            TickFormatter::Custom(f) => f(value),
+                            TickFormatter::Format(format) => format
+                                .format_value(value)
+                                .unwrap_or_else(|| format_original_units(value)),
+                        }
+                    }
+                },
+                value: tick.value,
+            })
+            .collect()
+    }
+}
+
+impl ScaleTrait for InverseLabelScale {
+    fn scale_type(&self) -> Scale {
+        self.inner.scale_type()
+    }
+
+    fn normalize(&self, value: f64) -> f64 {
+        self.inner.normalize(value)
+    }
+
+    fn normalize_string(&self, value: &str) -> f64 {
+        self.inner.normalize_string(value)
+    }
+
+    fn domain(&self) -> (f64, f64) {
+        self.inner.domain()
+    }
+
+    fn logical_max(&self) -> f64 {
+        self.inner.logical_max()
+    }
+
+    fn mapper(&self) -> Option<&VisualMapper> {
+        self.inner.mapper()
+    }
+
+    fn suggest_ticks(&self, count: usize) -> Vec<Tick> {
+        self.relabel(self.inner.suggest_ticks(count))
+    }
+
+    fn ticks_for_count(&self, count: usize) -> Vec<Tick> {
+        self.relabel(self.inner.ticks_for_count(count))
+    }
+
+    fn create_explicit_ticks(&self, explicit: &[ExplicitTick]) -> Vec<Tick> {
+        self.relabel(self.inner.create_explicit_ticks(explicit))
+    }
+
+    fn get_domain_enum(&self) -> ScaleDomain {
+        self.inner.get_domain_enum()
+    }
+
+    fn sample_n(&self, n: usize) -> Vec<Tick> {
+        self.relabel(self.inner.sample_n(n))
+    }
+
+    fn categories(&self) -> Option<&[String]> {
+        self.inner.categories()
+    }
+
+    fn log_base(&self) -> Option<f64> {
+        self.inner.log_base()
+    }
+
+    fn minor_ticks(&self) -> Vec<f64> {
+        self.inner.minor_ticks()
+    }
+
+    fn has_custom_labels(&self) -> bool {
+        matches!(self.labels, TickLabels::Formatted(..))
+    }
+}
@@ -32,36 +32,17 @@ impl LinearScale {
     /// Calculates a "nice" step size for axis ticks (e.g., 0.1, 0.2, 0.5, 1.0).
     ///
     /// This ensures that the intervals between ticks are intuitive for human readers.
-    /// It uses the range of the domain and a target tick count to find the
-    /// optimal power-of-ten interval.
+    /// The rough step `range / count` is rounded by [`super::nice_ticks::nice_round`].
     fn calculate_nice_step(&self, count: usize) -> f64 {
         let (min, max) = self.domain;
         let range = max - min;
 
         // Safety check for single-point domains or identical boundaries.
-        if range.abs() < 1e-12 {
+        if range.abs() < f64::MIN_POSITIVE {
             return 1.0;
         }
 
-        let rough_step = range / (count.max(2) as f64);
-
-        // Magnitude (power of 10) of the rough step.
-        let exp = 10f64.powf(rough_step.log10().floor());
-
-        // Normalize the step to the [1, 10] range to pick the best "nice" factor.
-        let f = rough_step / exp;
-
-        let nice = if f < 1.5 {
-            1.0
-        } else if f < 3.0 {
-            2.0
-        } else if f < 7.0 {
-            5.0
-        } else {
-            10.0
-        };
-
-        nice * exp
+        super::nice_ticks::nice_round(range / (count.max(2) as f64))
     }
 }
 
@@ -79,7 +60,7 @@ impl ScaleTrait for LinearScale {
         let (d_min, d_max) = self.domain;
         let diff = d_max - d_min;
 
-        if diff.abs() < f64::EPSILON {
+        if diff.abs() < f64::MIN_POSITIVE {
             return 0.5; // Default to center for zero-width domains.
         }
 
@@ -116,26 +97,20 @@ impl ScaleTrait for LinearScale {
     /// based on the magnitude of the calculated nice step.
     fn suggest_ticks(&self, count: usize) -> Vec<Tick> {
         let (min, max) = self.domain;
+        let count = count.min(super::nice_ticks::MAX_TICK_COUNT);
         let step = self.calculate_nice_step(count);
-        let tolerance = step * 1e-9;
-
-        let start = (min / step).ceil() * step;
-        let mut values = Vec::new();
-        let mut curr = start;
-
-        let mut iterations = 0;
-        while curr <= max + tolerance && iterations < count * 2 {
-            let clean_val = if curr.abs() < 1e-12 { 0.0 } else { curr };
-            values.push(clean_val);
-
-            curr += step;
-            iterations += 1;
-        }
+        let values = super::nice_ticks::step_tick_values(min, max, step, count * 2);
 
         // Ensure consistent axis-wide formatting (automatic scientific notation)
         super::format_ticks(&values)
     }
 
+    /// Places ticks a `nice_ceil(range / count)` step apart.
+    fn ticks_for_count(&self, count: usize) -> Vec<Tick> {
+        let (min, max) = self.domain;
+        super::format_ticks(&super::nice_ticks::nice_tick_values(min, max, count))
+    }
+
     /// Transforms user-defined explicit ticks into renderable Tick objects.
     ///
     /// This implementation performs three steps:
@@ -146,7 +121,7 @@ impl ScaleTrait for LinearScale {
         let (min, max) = self.domain;
         // Pre-calculate tolerance once to avoid repeating in the loop
         let range = (max - min).abs();
-        let tolerance = if range < f64::EPSILON {
+        let tolerance = if range < f64::MIN_POSITIVE {
             1e-10
         } else {
             range * 1e-10
@@ -163,7 +138,7 @@ impl ScaleTrait for LinearScale {
                         // Logic: Only allow values within [min, max] (with float tolerance)
                         if *val >= min - tolerance && *val <= max + tolerance {
                             // Clean up near-zero values for cleaner labels
-                            Some(if val.abs() < 1e-12 { 0.0 } else { *val })
+                            Some(if val.abs() < tolerance { 0.0 } else { *val })
                         } else {
                             out_of_domain += 1;
                             None
@@ -221,7 +196,11 @@ impl ScaleTrait for LinearScale {
                 } else {
                     min + i as f64 * step
                 };
-                if val.abs() < 1e-12 { 0.0 } else { val }
+                if val.abs() < step.abs() * 1e-9 {
+                    0.0
+                } else {
+                    val
+                }
             })
             .collect();
 
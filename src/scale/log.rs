@@ -1,3 +1,4 @@
+use super::linear::LinearScale;
 use super::{ExplicitTick, Scale, ScaleDomain, ScaleTrait, Tick, mapper::VisualMapper};
 use crate::error::ChartonError;
 
@@ -115,42 +116,56 @@ impl ScaleTrait for LogScale {
 
     /// Generates logarithmic tick marks.
     ///
-    /// This version focuses exclusively on "Major Ticks" (integer powers of the base).
-    /// It includes a safety fallback to ensure at least two ticks are returned
-    /// even if the data range is smaller than a single decade.
-    fn suggest_ticks(&self, _count: usize) -> Vec<Tick> {
+    /// Major ticks sit at integer powers of the base. On base-10 axes with room
+    /// for more than the powers, the 2× and 5× multiples of each power are added
+    /// (the 1-2-5 sequence); with too little room, only every second, third, …
+    /// power is kept. Domains spanning less than one power of the base have no
+    /// meaningful powers to show and fall back to linear-style ticks.
+    fn suggest_ticks(&self, count: usize) -> Vec<Tick> {
         let (min, max) = self.domain;
-        let mut tick_values = Vec::new();
+        let count = count.max(2);
 
-        // Logarithmic calculations to find the range of exponents
-        let log_base = self.base.ln();
-        let log_min = min.ln() / log_base;
-        let log_max = max.ln() / log_base;
-
-        let start_exp = log_min.floor() as i32;
-        let end_exp = log_max.ceil() as i32;
+        let log_min = min.log(self.base);
+        let log_max = max.log(self.base);
+        if log_max - log_min < 1.0 {
+            return LinearScale::new(self.domain, None).suggest_ticks(count);
+        }
 
-        // 1. Generate Major Ticks (powers of the base)
-        // We use a small epsilon (0.99/1.01) to account for floating point inaccuracies
-        // ensuring that boundary values are captured.
-        for exp in start_exp..=end_exp {
-            let val = self.base.powi(exp);
-            if val >= min * 0.99 && val <= max * 1.01 {
-                tick_values.push(val);
+        // A relative tolerance keeps powers that sit exactly on a boundary.
+        let in_domain = |v: f64| v >= min * (1.0 - 1e-9) && v <= max * (1.0 + 1e-9);
+        let exponents = log_min.floor() as i32..=log_max.ceil() as i32;
+
+        // 1. The 1-2-5 sequence, when it fits
+        if self.base == 10.0 {
+            let values: Vec<f64> = exponents
+                .clone()
+                .flat_map(|exp| [1.0, 2.0, 5.0].map(|m| m * 10f64.powi(exp)))
+                .filter(|&v| in_domain(v))
+                .collect();
+            if values.len() <= count {
+                return super::format_ticks(&values);
             }
         }
 
-        // 2. Fallback Logic
-        // If the domain is very narrow (e.g., between 120 and 150), no integer power
-        // of 10 exists within it. In such cases, we provide the min and max
-        // as ticks so the axis isn't blank.
+        // 2. Powers of the base, thinned to every n-th exponent if too dense
+        let powers: Vec<i32> = exponents
+            .filter(|&exp| in_domain(self.base.powi(exp)))
+            .collect();
+        let stride = powers.len().div_ceil(count) as i32;
+        let mut tick_values: Vec<f64> = powers
+            .into_iter()
+            .filter(|exp| exp.rem_euclid(stride) == 0)
+            .map(|exp| self.base.powi(exp))
+            .collect();
+
+        // 3. Fallback Logic
+        // If thinning leaves a single power, the min and max are shown instead
+        // so the axis isn't blank.
         if tick_values.len() < 2 {
-            tick_values.clear();
-            tick_values.push(min);
-            tick_values.push(max);
+            tick_values = vec![min, max];
         }
 
-        // 3. Formatting
+        // 4. Formatting
         // Delegate to the shared formatter which handles scientific notation
         // and converts raw f64 values into Tick objects.
         super::format_ticks(&tick_values)
@@ -214,6 +229,34 @@ impl ScaleTrait for LogScale {
         ScaleDomain::Continuous(min, max)
     }
 
+    fn log_base(&self) -> Option<f64> {
+        Some(self.base)
+    }
+
+    /// Returns the integer multiples of each power of the base (2, 3, … base - 1)
+    /// that fall inside the domain. Non-integer bases have no minor ticks.
+    fn minor_ticks(&self) -> Vec<f64> {
+        let (min, max) = self.domain;
+        let steps = self.base.round();
+        if (self.base - steps).abs() > 1e-9 || steps < 3.0 {
+            return Vec::new();
+        }
+
+        let start_exp = min.log(self.base).floor() as i32;
+        let end_exp = max.log(self.base).ceil() as i32;
+        let mut values = Vec::new();
+        for exp in start_exp..=end_exp {
+            let major = self.base.powi(exp);
+            for k in 2..steps as usize {
+                let v = major * k as f64;
+                if v >= min && v <= max {
+                    values.push(v);
+                }
+            }
+        }
+        values
+    }
+
     /// Force-samples the domain into N points equidistant in log-space.
     ///
     /// This is crucial for creating accurate legends for log scales. If we
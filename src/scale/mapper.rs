@@ -1,6 +1,6 @@
 use crate::scale::Scale;
 use crate::theme::Theme;
-use crate::visual::color::{ColorMap, ColorPalette, SingleColor};
+use crate::visual::color::{ColorGradient, ColorMap, ColorPalette, PaletteOverflow, SingleColor};
 use crate::visual::shape::PointShape;
 
 /// Defines how normalized data values [0.0, 1.0] are mapped to physical visual properties.
@@ -8,11 +8,18 @@ use crate::visual::shape::PointShape;
 /// This enum acts as the final stage of the scale pipeline, converting abstract
 /// mathematical ratios into concrete types like `SingleColor`, `PointShape`, or `f64`.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum VisualMapper {
     /// Continuous color mapping for numerical data (Gradients).
-    ContinuousColor { map: ColorMap },
+    ContinuousColor { map: ColorGradient },
     /// Discrete color mapping for categorical data (Palettes).
-    DiscreteColor { palette: ColorPalette },
+    DiscreteColor {
+        palette: ColorPalette,
+        /// How categories beyond the end of the palette are colored.
+        overflow: PaletteOverflow,
+    },
+    /// Ordered categories colored by evenly spaced samples of a gradient.
+    OrdinalColor { map: ColorMap },
     /// Geometric shape mapping for categorical data.
     Shape {
         /// Optional list of shapes. If None, defaults to `PointShape::LEGEND_SHAPES`.
@@ -22,6 +29,12 @@ pub enum VisualMapper {
     Size {
         /// Tuple representing (min_size, max_size) in physical units (pixels/points).
         range: (f64, f64),
+        /// Optional lower bound applied after interpolation.
+        floor: Option<f64>,
+    },
+    Opacity {
+        /// Tuple representing (min_opacity, max_opacity), each within [0, 1].
+        range: (f64, f64),
     },
 }
 
@@ -33,16 +46,50 @@ impl VisualMapper {
         match scale_type {
             Scale::Discrete => VisualMapper::DiscreteColor {
                 palette: theme.palette.clone(),
+                overflow: theme.palette.default_overflow(),
             },
             _ => VisualMapper::ContinuousColor {
-                map: theme.color_map,
+                map: theme.color_map.clone(),
             },
         }
     }
 
     /// Creates a default size mapper with a specified physical range.
     pub const fn new_size_default(min: f64, max: f64) -> Self {
-        VisualMapper::Size { range: (min, max) }
+        VisualMapper::Size {
+            range: (min, max),
+            floor: None,
+        }
+    }
+
+    /// Creates an opacity mapper interpolating between `min` and `max`.
+    pub const fn new_opacity_default(min: f64, max: f64) -> Self {
+        VisualMapper::Opacity { range: (min, max) }
+    }
+
+    /// Overrides the overflow policy of a discrete color mapper. Has no effect on
+    /// other mappers, and `None` keeps the palette's default.
+    pub const fn with_palette_overflow(mut self, policy: Option<PaletteOverflow>) -> Self {
+        if let (VisualMapper::DiscreteColor { overflow, .. }, Some(p)) = (&mut self, policy) {
+            *overflow = p;
+        }
+        self
+    }
+
+    /// Sets the lower bound of a size mapper. Has no effect on other mappers.
+    pub const fn with_size_floor(mut self, px: Option<f64>) -> Self {
+        if let VisualMapper::Size { ref mut floor, .. } = self {
+            *floor = px;
+        }
+        self
+    }
+
+    /// Returns the lower bound of a size mapper, if any.
+    pub const fn size_floor(&self) -> Option<f64> {
+        match self {
+            VisualMapper::Size { floor, .. } => *floor,
+            _ => None,
+        }
     }
 
     /// Creates a default shape mapper using the standard geometric shapes.
@@ -66,10 +113,17 @@ impl VisualMapper {
                 // Interpolates within the continuous gradient
                 map.get_color(norm)
             }
-            VisualMapper::DiscreteColor { palette } => {
+            VisualMapper::DiscreteColor { palette, overflow } => {
                 // Maps the 0-1 norm back to a specific palette index
                 let index = (norm * logical_max).round() as usize;
-                palette.get_color(index)
+                let count = logical_max.round() as usize + 1;
+                palette.get_color_with_overflow(index, count, *overflow)
+            }
+            VisualMapper::OrdinalColor { map } => {
+                // Samples the center of each category's slice of the gradient, so the
+                // lightest level stays visible against the background.
+                let index = (norm * logical_max).round();
+                map.get_color((index + 0.5) / (logical_max.round() + 1.0))
             }
             // Fallback: Returns Opaque Black if color mapping is called on a non-color mapper
             _ => SingleColor::default(),
@@ -104,12 +158,27 @@ impl VisualMapper {
 
     /// Maps a normalized value to a physical size (radius or width).
     ///
-    /// Performs linear interpolation: size = min + norm * (max - min).
+    /// Performs linear interpolation: size = min + norm * (max - min), raised to
+    /// the floor when one is set.
     pub fn map_to_size(&self, norm: f64) -> f64 {
         match self {
-            VisualMapper::Size { range } => range.0 + norm * (range.1 - range.0),
+            VisualMapper::Size { range, floor } => {
+                let size = range.0 + norm * (range.1 - range.0);
+                floor.map_or(size, |f| size.max(f))
+            }
             // Default size if no size mapping is specified
             _ => 5.0,
         }
     }
+
+    /// Maps a normalized value to an opacity in [0, 1].
+    pub fn map_to_opacity(&self, norm: f64) -> f64 {
+        match self {
+            VisualMapper::Opacity { range } => {
+                (range.0 + norm * (range.1 - range.0)).clamp(0.0, 1.0)
+            }
+            // Fully opaque if no opacity mapping is specified
+            _ => 1.0,
+        }
+    }
 }
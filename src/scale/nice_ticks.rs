@@ -0,0 +1,100 @@
+use super::{ScaleTrait, Tick};
+
+/// Rounds a positive `x` up to the nearest 1, 2, 2.5, 5 or 10 times a power of ten.
+///
+/// 2.5 keeps the rounded step within 25% of `x` between 2 and 5, so a
+/// requested tick count is not roughly halved.
+///
+/// Non-positive and non-finite inputs are returned unchanged.
+pub(crate) fn nice_ceil(x: f64) -> f64 {
+    if !(x.is_finite() && x > 0.0) {
+        return x;
+    }
+    let exp = 10f64.powf(x.log10().floor());
+    let f = x / exp;
+    // A small tolerance keeps exact powers (e.g. 0.3 / 0.1 = 2.9999…) on their factor.
+    let nice = [1.0, 2.0, 2.5, 5.0]
+        .into_iter()
+        .find(|&n| f <= n * (1.0 + 1e-9))
+        .unwrap_or(10.0);
+    nice * exp
+}
+
+/// Upper bound on the ticks requested for one axis; more could never be told apart.
+pub(crate) const MAX_TICK_COUNT: usize = 1000;
+
+/// Rounds a positive `x` to the nearest 1, 2, 5 or 10 times a power of ten.
+///
+/// Used for the automatic ticks, which only need to be about as many as the
+/// space suggests. Non-positive and non-finite inputs are returned unchanged.
+pub(crate) fn nice_round(x: f64) -> f64 {
+    if !(x.is_finite() && x > 0.0) {
+        return x;
+    }
+    let exp = 10f64.powf(x.log10().floor());
+    let f = x / exp;
+    let nice = if f < 1.5 {
+        1.0
+    } else if f < 3.0 {
+        2.0
+    } else if f < 7.0 {
+        5.0
+    } else {
+        10.0
+    };
+    nice * exp
+}
+
+/// Tick values inside `[min, max]` spaced by `nice_ceil((max - min) / count)`.
+///
+/// The ticks start at the first multiple of the step, so roughly `count`
+/// values are produced (fewer when the step rounds up a lot). A zero-width
+/// domain yields its single value. Counts above 1000 are treated as 1000.
+pub(crate) fn nice_tick_values(min: f64, max: f64, count: usize) -> Vec<f64> {
+    if count == 0 || !(min.is_finite() && max.is_finite()) {
+        return Vec::new();
+    }
+    let range = max - min;
+    if range.abs() < f64::MIN_POSITIVE {
+        return vec![min];
+    }
+
+    let count = count.min(MAX_TICK_COUNT);
+    step_tick_values(min, max, nice_ceil(range / count as f64), count * 2)
+}
+
+/// The multiples of `step` inside `[min, max]`, at most `limit` of them.
+///
+/// Values within a rounding error of zero are written as exactly zero.
+pub(crate) fn step_tick_values(min: f64, max: f64, step: f64, limit: usize) -> Vec<f64> {
+    if !(step.is_finite() && step > 0.0) {
+        return Vec::new();
+    }
+    let tolerance = step * 1e-9;
+    let first = (min / step).ceil() as i64;
+    let last = (max / step + 1e-9).floor() as i64;
+    (first..=last)
+        .take(limit)
+        .map(|k| {
+            let v = k as f64 * step;
+            if v.abs() < tolerance { 0.0 } else { v }
+        })
+        .collect()
+}
+
+/// Ticks for a positional axis.
+///
+/// With a requested `count`, a count of 0 draws no ticks at all and any other
+/// count asks the scale for about that many via `ticks_for_count`. Without
+/// one, the scale suggests `fallback` ticks as usual.
+pub(crate) fn axis_ticks(
+    scale: &dyn ScaleTrait,
+    count: Option<usize>,
+    fallback: usize,
+) -> Vec<Tick> {
+    match count {
+        Some(0) => Vec::new(),
+        Some(n) => scale.ticks_for_count(n),
+        None => scale.suggest_ticks(fallback),
+    }
+}
@@ -0,0 +1,82 @@
+use super::mapper::VisualMapper;
+use super::{ExplicitTick, Scale, ScaleDomain, ScaleTrait, Tick};
+use std::sync::Arc;
+
+/// A position scale running from its maximum to its minimum.
+///
+/// Normalized positions are mirrored (`1 - t`), so larger values sit left on
+/// the X axis and low on the Y axis; marks, bar baselines, ticks and grid
+/// lines all follow, since they are placed through `normalize`. Every other
+/// method delegates to the wrapped scale.
+#[derive(Debug)]
+pub(crate) struct ReversedScale {
+    inner: Arc<dyn ScaleTrait>,
+}
+
+impl ReversedScale {
+    pub(crate) fn wrap(inner: Arc<dyn ScaleTrait>) -> Arc<dyn ScaleTrait> {
+        Arc::new(Self { inner })
+    }
+}
+
+impl ScaleTrait for ReversedScale {
+    fn scale_type(&self) -> Scale {
+        self.inner.scale_type()
+    }
+
+    fn normalize(&self, value: f64) -> f64 {
+        1.0 - self.inner.normalize(value)
+    }
+
+    fn normalize_string(&self, value: &str) -> f64 {
+        1.0 - self.inner.normalize_string(value)
+    }
+
+    fn domain(&self) -> (f64, f64) {
+        self.inner.domain()
+    }
+
+    fn logical_max(&self) -> f64 {
+        self.inner.logical_max()
+    }
+
+    fn mapper(&self) -> Option<&VisualMapper> {
+        self.inner.mapper()
+    }
+
+    fn suggest_ticks(&self, count: usize) -> Vec<Tick> {
+        self.inner.suggest_ticks(count)
+    }
+
+    fn ticks_for_count(&self, count: usize) -> Vec<Tick> {
+        self.inner.ticks_for_count(count)
+    }
+
+    fn create_explicit_ticks(&self, explicit: &[ExplicitTick]) -> Vec<Tick> {
+        self.inner.create_explicit_ticks(explicit)
+    }
+
+    fn get_domain_enum(&self) -> ScaleDomain {
+        self.inner.get_domain_enum()
+    }
+
+    fn sample_n(&self, n: usize) -> Vec<Tick> {
+        self.inner.sample_n(n)
+    }
+
+    fn categories(&self) -> Option<&[String]> {
+        self.inner.categories()
+    }
+
+    fn log_base(&self) -> Option<f64> {
+        self.inner.log_base()
+    }
+
+    fn minor_ticks(&self) -> Vec<f64> {
+        self.inner.minor_ticks()
+    }
+
+    fn has_custom_labels(&self) -> bool {
+        self.inner.has_custom_labels()
+    }
+}
@@ -1,4 +1,6 @@
 use super::{ExplicitTick, Scale, ScaleDomain, ScaleTrait, Tick, mapper::VisualMapper};
+use crate::error::ChartonError;
+use time::format_description::OwnedFormatItem;
 use time::{Duration, OffsetDateTime};
 
 /// A high-precision temporal scale mapping nanosecond timestamps to a [0, 1] visual range.
@@ -11,6 +13,8 @@ pub struct TemporalScale {
     domain: (i64, i64),
     /// Optional visual mapper for aesthetic encodings (color, size, etc.).
     mapper: Option<VisualMapper>,
+    /// User-defined tick label format. When `None`, the format follows the tick interval.
+    format: Option<OwnedFormatItem>,
 }
 
 /// Human-friendly time intervals for axis ticks.
@@ -56,7 +60,17 @@ const TICK_LADDER: &[(Duration, &str)] = &[
 
 impl TemporalScale {
     pub const fn new(domain: (i64, i64), mapper: Option<VisualMapper>) -> Self {
-        Self { domain, mapper }
+        Self {
+            domain,
+            mapper,
+            format: None,
+        }
+    }
+
+    /// Formats every tick label with `format` instead of the interval-based default.
+    pub fn with_format(mut self, format: OwnedFormatItem) -> Self {
+        self.format = Some(format);
+        self
     }
 
     /// Selects the best visual format based on the calculated density.
@@ -126,9 +140,35 @@ impl TemporalScale {
         }
     }
 
+    /// Ranks how precisely a timestamp must be written: 0 for a year start, 1 for a
+    /// month start, 2 for midnight, 3 for a whole minute, 4 for a whole second, 5 otherwise.
+    fn precision_rank(ns: i64) -> u8 {
+        let Ok(dt) = OffsetDateTime::from_unix_timestamp_nanos(ns as i128) else {
+            return 2;
+        };
+        if dt.nanosecond() != 0 {
+            5
+        } else if dt.second() != 0 {
+            4
+        } else if dt.hour() != 0 || dt.minute() != 0 {
+            3
+        } else if dt.day() != 1 {
+            2
+        } else if dt.month() != time::Month::January {
+            1
+        } else {
+            0
+        }
+    }
+
     /// Formats nanoseconds into strings, with fallback for astronomical time.
     fn format_ns(&self, ns: i64, format_key: &str) -> String {
         match OffsetDateTime::from_unix_timestamp_nanos(ns as i128) {
+            Ok(dt) if self.format.is_some() => self
+                .format
+                .as_ref()
+                .and_then(|f| dt.format(f).ok())
+                .unwrap_or_else(|| "Time Error".to_string()),
             Ok(dt) => match format_key {
                 "year" => dt.format(&time::macros::format_description!("[year]")),
                 "month" => dt.format(&time::macros::format_description!("[year]-[month]")),
@@ -165,6 +205,58 @@ impl TemporalScale {
     }
 }
 
+/// Parses a date or date-time string into Unix nanoseconds (UTC).
+///
+/// Accepts RFC 3339 ("2024-03-01T09:30:00Z") and the plain forms "2024-03-01 09:30:00",
+/// "2024-03-01T09:30:00", "2024-03-01 09:30", "2024-03-01" and "2024-03".
+fn parse_datetime_ns(text: &str) -> Option<i64> {
+    use time::format_description::well_known::Rfc3339;
+    use time::macros::format_description;
+    use time::{Date, PrimitiveDateTime};
+
+    let text = text.trim();
+    if let Ok(dt) = OffsetDateTime::parse(text, &Rfc3339) {
+        return dt.unix_timestamp_nanos().try_into().ok();
+    }
+
+    let date_time = [
+        format_description!("[year]-[month]-[day] [hour]:[minute]:[second]"),
+        format_description!("[year]-[month]-[day]T[hour]:[minute]:[second]"),
+        format_description!("[year]-[month]-[day] [hour]:[minute]"),
+    ]
+    .iter()
+    .find_map(|fmt| PrimitiveDateTime::parse(text, fmt).ok())
+    .or_else(|| {
+        let date_fmt = format_description!("[year]-[month]-[day]");
+        Date::parse(text, date_fmt)
+            .or_else(|_| Date::parse(&format!("{}-01", text), date_fmt))
+            .ok()
+            .map(|d| d.midnight())
+    })?;
+
+    date_time
+        .assume_utc()
+        .unix_timestamp_nanos()
+        .try_into()
+        .ok()
+}
+
+/// Checks that every string among explicit temporal ticks is a date `parse_datetime_ns`
+/// understands, so a typo fails the chart instead of silently losing its tick.
+pub(crate) fn check_date_ticks(explicit: &[ExplicitTick], axis: &str) -> Result<(), ChartonError> {
+    for tick in explicit {
+        if let ExplicitTick::Discrete(text) = tick
+            && parse_datetime_ns(text).is_none()
+        {
+            return Err(ChartonError::Data(format!(
+                "{} tick '{}' is not a date; expected e.g. \"2024-03-01\" or \"2024-03-01T09:30:00Z\"",
+                axis, text
+            )));
+        }
+    }
+    Ok(())
+}
+
 impl ScaleTrait for TemporalScale {
     fn scale_type(&self) -> Scale {
         Scale::Temporal
@@ -200,8 +292,22 @@ impl ScaleTrait for TemporalScale {
 
         // Epsilon to prevent missing the last tick due to float precision
         let end_with_epsilon = end + 1;
-        let seconds_per_tick = (end - start).abs() as f64 / (1e9 * count.max(1) as f64);
-        let (interval, format_key) = Self::pick_format_and_interval(seconds_per_tick);
+        let span_seconds = (end - start).abs() as f64 / 1e9;
+        let mut target = count.max(1);
+        let (mut interval, mut format_key) =
+            Self::pick_format_and_interval(span_seconds / target as f64);
+
+        // The tick budget assumes labels about five characters wide. Longer labels
+        // (e.g. "2024-01-15") thin the ticks out, which may move to a coarser unit.
+        for _ in 0..3 {
+            let label_len = self.format_ns(start, format_key).chars().count().max(1);
+            let fit = (count * 5 / label_len).max(2);
+            if fit >= target {
+                break;
+            }
+            target = fit;
+            (interval, format_key) = Self::pick_format_and_interval(span_seconds / target as f64);
+        }
         let interval_ns = interval.whole_nanoseconds() as i64;
 
         let mut ticks = Vec::new();
@@ -259,27 +365,39 @@ impl ScaleTrait for TemporalScale {
     }
 
     fn create_explicit_ticks(&self, explicit: &[ExplicitTick]) -> Vec<Tick> {
-        let total_sec = (self.domain.1 - self.domain.0).abs() as f64 / 1e9;
-        let (_, format_key) = Self::pick_format_and_interval(total_sec / 5.0);
+        let values: Vec<i64> = explicit
+            .iter()
+            .filter_map(|tick| match tick {
+                ExplicitTick::Timestamp(ns) => Some(*ns),
+                ExplicitTick::Temporal(dt) => Some(dt.unix_timestamp_nanos() as i64),
+                ExplicitTick::Continuous(f) => Some(*f as i64),
+                ExplicitTick::Discrete(text) => parse_datetime_ns(text),
+            })
+            .filter(|ns| *ns >= self.domain.0 && *ns <= self.domain.1)
+            .collect();
 
-        explicit
+        // User-chosen ticks are labeled at the coarsest unit that still shows them
+        // exactly, e.g. "2024-03" for month starts and "2024-03-15" for other days.
+        let span_days = (self.domain.1 - self.domain.0).abs() as f64 / 86_400e9;
+        let format_key = values
             .iter()
-            .filter_map(|tick| {
-                let val_ns = match tick {
-                    ExplicitTick::Timestamp(ns) => *ns,
-                    ExplicitTick::Temporal(dt) => dt.unix_timestamp_nanos() as i64,
-                    ExplicitTick::Continuous(f) => *f as i64,
-                    _ => return None,
-                };
-
-                if val_ns >= self.domain.0 && val_ns <= self.domain.1 {
-                    Some(Tick {
-                        value: val_ns as f64,
-                        label: self.format_ns(val_ns, format_key),
-                    })
-                } else {
-                    None
-                }
+            .map(|&ns| Self::precision_rank(ns))
+            .max()
+            .map_or("day", |rank| match rank {
+                0 => "year",
+                1 => "month",
+                2 => "day",
+                3 if span_days >= 1.0 => "hour",
+                3 => "minute",
+                4 => "second",
+                _ => "millisecond",
+            });
+
+        values
+            .into_iter()
+            .map(|ns| Tick {
+                value: ns as f64,
+                label: self.format_ns(ns, format_key),
             })
             .collect()
     }
@@ -12,68 +12,168 @@
 /// # Returns
 /// A tuple containing (original_x, smoothed_y).
 pub(crate) fn loess(x: &[f64], y: &[f64], bandwidth: f64) -> (Vec<f64>, Vec<f64>) {
-    let n = x.len();
     // LOESS requires at least 2 points for a linear fit.
-    if n < 2 {
+    if x.len() < 2 {
         return (x.to_vec(), y.to_vec());
     }
 
+    let smoothed_y = fit_at(x, y, bandwidth, 0, x, Window::Inner)
+        .into_iter()
+        .zip(y)
+        // Fallback to the original value if the regression fails.
+        .map(|(fit, &orig)| fit.unwrap_or(orig))
+        .collect();
+
+    (x.to_vec(), smoothed_y)
+}
+
+/// Evaluates a robust LOESS fit of `(x, y)` at every value of `targets`.
+///
+/// Each target gets a weighted linear regression over its nearest
+/// `bandwidth * n` points, weighted by the tricube of their distance. Each of
+/// the `iterations` robustness passes refits the data points and down-weights
+/// those with large residuals (bisquare of the residual over six times the
+/// median absolute residual), so outliers pull less on the curve.
+///
+/// Returns `None` for targets whose neighbours all carry zero weight.
+pub(crate) fn loess_at(
+    x: &[f64],
+    y: &[f64],
+    bandwidth: f64,
+    iterations: usize,
+    targets: &[f64],
+) -> Vec<Option<f64>> {
+    fit_at(x, y, bandwidth, iterations, targets, Window::Full)
+}
+
+/// Which neighbours make up the local window of a fit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Window {
+    /// The `k - 1` nearest points, spanning to the farthest of them. A window
+    /// of zero width yields no fit. This is the weighting of `loess`.
+    Inner,
+    /// The `k` nearest points, spanning to the farthest of them. Points that
+    /// all sit at the target are weighted equally.
+    Full,
+}
+
+fn fit_at(
+    x: &[f64],
+    y: &[f64],
+    bandwidth: f64,
+    iterations: usize,
+    targets: &[f64],
+    window: Window,
+) -> Vec<Option<f64>> {
+    let n = x.len();
+    if n == 0 {
+        return vec![None; targets.len()];
+    }
+
     // k is the number of neighbors included in the local window.
     let k = (n as f64 * bandwidth).max(2.0).min(n as f64) as usize;
 
-    let mut smoothed_y = Vec::with_capacity(n);
-
     // PERF: Reuse a single distance buffer to avoid O(N) allocations.
     let mut distances: Vec<(usize, f64)> = Vec::with_capacity(n);
-
-    for i in 0..n {
-        let target_x = x[i];
-
-        // 1. Calculate absolute distances from target_x to all other points.
-        distances.clear();
-        for (j, &val) in x.iter().enumerate() {
-            distances.push((j, (val - target_x).abs()));
+    let mut robustness = vec![1.0; n];
+
+    for _ in 0..iterations {
+        // Points whose neighbourhood carries no weight have no residual.
+        let residuals: Vec<Option<f64>> = x
+            .iter()
+            .zip(y)
+            .map(|(&xi, &yi)| {
+                local_fit(x, y, &robustness, k, window, xi, &mut distances).map(|f| (yi - f).abs())
+            })
+            .collect();
+
+        let mut sorted: Vec<f64> = residuals.iter().flatten().copied().collect();
+        if sorted.is_empty() {
+            break;
         }
-
-        // 2. PERF(Performance): Partial sort using Quickselect (select_nth_unstable_by).
-        // This finds the k-nearest neighbors in O(N) average time,
-        // significantly faster than a full O(N log N) sort.
-        // We use partial_cmp().unwrap_or because distances are non-NaN f64s.
-        let (neighbors, _, _) = distances.select_nth_unstable_by(k - 1, |a, b| {
-            a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal)
-        });
-
-        // 3. Determine the maximum distance in the neighborhood for weighting.
-        let max_dist = neighbors.iter().map(|d| d.1).fold(0.0, f64::max);
-
-        // If all neighbors are at the same X, local regression is vertical (invalid).
-        if max_dist == 0.0 {
-            smoothed_y.push(y[i]);
-            continue;
+        sorted.sort_unstable_by(|a, b| a.total_cmp(b));
+        let cmad = 6.0 * sorted[sorted.len() / 2];
+        let mean = sorted.iter().sum::<f64>() / sorted.len() as f64;
+        // A (near) perfect fit leaves nothing to down-weight.
+        if cmad <= 1e-7 * mean || mean <= f64::EPSILON {
+            break;
         }
 
-        // 4. Perform weighted linear regression on the neighborhood.
-        if let Some(pred) =
-            weighted_linear_regression_optimized(x, y, neighbors, max_dist, target_x)
-        {
-            smoothed_y.push(pred);
-        } else {
-            // Fallback to the original value if the regression fails (e.g., singular matrix).
-            smoothed_y.push(y[i]);
+        // Cleveland's cut-offs: tiny residuals keep full weight, large ones none.
+        for (w, r) in robustness.iter_mut().zip(&residuals) {
+            let Some(r) = *r else { continue };
+            *w = if r <= 0.001 * cmad {
+                1.0
+            } else if r > 0.999 * cmad {
+                0.0
+            } else {
+                (1.0 - (r / cmad).powi(2)).powi(2)
+            };
         }
     }
 
-    (x.to_vec(), smoothed_y)
+    targets
+        .iter()
+        .map(|&t| local_fit(x, y, &robustness, k, window, t, &mut distances))
+        .collect()
+}
+
+/// Fits the weighted local line around `target_x` and returns its value there.
+fn local_fit(
+    x: &[f64],
+    y: &[f64],
+    robustness: &[f64],
+    k: usize,
+    window: Window,
+    target_x: f64,
+    distances: &mut Vec<(usize, f64)>,
+) -> Option<f64> {
+    let size = match window {
+        Window::Inner => k - 1,
+        Window::Full => k,
+    };
+
+    // 1. Calculate absolute distances from target_x to all other points.
+    distances.clear();
+    for (j, &val) in x.iter().enumerate() {
+        distances.push((j, (val - target_x).abs()));
+    }
+
+    // 2. PERF(Performance): Partial sort using Quickselect (select_nth_unstable_by).
+    // This finds the k-nearest neighbors in O(N) average time,
+    // significantly faster than a full O(N log N) sort.
+    // We use partial_cmp().unwrap_or because distances are non-NaN f64s.
+    let (neighbors, kth, _) = distances.select_nth_unstable_by(size - 1, |a, b| {
+        a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal)
+    });
+    let kth = *kth;
+
+    // 3. Determine the maximum distance in the neighborhood for weighting.
+    let max_dist = neighbors.iter().map(|d| d.1).fold(kth.1, f64::max);
+    if window == Window::Inner && max_dist == 0.0 {
+        return None;
+    }
+
+    // 4. Perform weighted linear regression on the neighborhood.
+    weighted_linear_regression_optimized(
+        x,
+        y,
+        robustness,
+        neighbors.iter().chain(std::iter::once(&kth)),
+        max_dist,
+        target_x,
+    )
 }
 
 /// A memory-efficient weighted linear regression implementation.
 ///
 /// Instead of creating new vectors for the local subset, this indices into the
 /// original data using the indices found during neighbor selection.
-fn weighted_linear_regression_optimized(
+fn weighted_linear_regression_optimized<'a>(
     original_x: &[f64],
     original_y: &[f64],
-    neighbors: &[(usize, f64)],
+    robustness: &[f64],
+    neighbors: impl Iterator<Item = &'a (usize, f64)>,
     max_dist: f64,
     target_x: f64,
 ) -> Option<f64> {
@@ -83,10 +183,16 @@ fn weighted_linear_regression_optimized(
     let mut sum_wxx = 0.0;
     let mut sum_wxy = 0.0;
 
-    // Use the Tricube Weighting Function: W(u) = (1 - |u|^3)^3 for |u| < 1
+    // Use the Tricube Weighting Function: W(u) = (1 - |u|^3)^3 for |u| < 1.
+    // If all neighbors sit at target_x, they are weighted equally.
     for &(idx, dist) in neighbors {
-        let u = dist / max_dist;
-        let w = (1.0 - u.powi(3)).powi(3).max(0.0);
+        let tricube = if max_dist > 0.0 {
+            let u = dist / max_dist;
+            (1.0 - u.powi(3)).powi(3).max(0.0)
+        } else {
+            1.0
+        };
+        let w = tricube * robustness[idx];
 
         let xi = original_x[idx];
         let yi = original_y[idx];
@@ -1,6 +1,114 @@
 use crate::core::guide::LegendPosition;
+use crate::mark::text::{FontStyle, FontWeight};
 use crate::prelude::SingleColor;
-use crate::visual::color::{ColorMap, ColorPalette};
+use crate::scale::{Scale, ScaleTrait, Tick};
+use crate::visual::color::{ColorGradient, ColorMap, ColorPalette};
+
+/// Inline base direction of chart text (titles, axis labels, ticks and legends).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum TextDirection {
+    /// Left-to-right scripts (Latin, CJK, ...).
+    #[default]
+    Ltr,
+    /// Right-to-left scripts (Hebrew, Arabic, ...). Characters are reordered
+    /// for display while every label keeps its anchor position.
+    Rtl,
+}
+
+impl TextDirection {
+    /// The SVG `direction` attribute value.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            TextDirection::Ltr => "ltr",
+            TextDirection::Rtl => "rtl",
+        }
+    }
+}
+
+/// How the vertical axis title is laid out.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum TextOrientation {
+    /// Horizontal text rotated 90° counter-clockwise.
+    #[default]
+    Rotated,
+    /// Glyphs stacked top to bottom and kept upright, as is customary for CJK text
+    /// (`writing-mode="vertical-rl"` with `text-orientation="upright"`).
+    UprightVertical,
+}
+
+/// How axis tick labels are written.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum TickFormat {
+    /// Plain or scientific notation chosen from the tick values (e.g. "1E3").
+    #[default]
+    Auto,
+    /// Powers of the base on logarithmic axes are written as "10^3"; other
+    /// scales keep the automatic labels.
+    LogBase,
+    /// Powers of the base on logarithmic axes are written with a raised
+    /// exponent, "10³" (an SVG `tspan`, Unicode superscripts elsewhere).
+    Superscript,
+    /// Powers of ten on base-10 logarithmic axes are written as "1e3".
+    Exponent,
+    /// Fractions are written as percentages, 0.25 as "25%".
+    Percent,
+    /// Large and small values are written with an SI prefix, 1500000 as "1.5M".
+    SiSuffix,
+    /// Values are written with a fixed number of decimals.
+    Precision(usize),
+    /// Values are written in scientific notation, 1000000000 as "1.0e9".
+    Scientific,
+}
+
+impl TickFormat {
+    /// Rewrites the labels of ticks generated by `scale` in this notation.
+    ///
+    /// Scales whose labels come from a tick formatter keep them unchanged.
+    pub(crate) fn apply(self, scale: &dyn ScaleTrait, ticks: Vec<Tick>) -> Vec<Tick> {
+        if scale.has_custom_labels() {
+            return ticks;
+        }
+        match self {
+            TickFormat::Auto => ticks,
+            TickFormat::LogBase | TickFormat::Superscript | TickFormat::Exponent => {
+                match scale.log_base() {
+                    Some(base) => crate::scale::format_log_power_ticks(ticks, base, self),
+                    None => ticks,
+                }
+            }
+            TickFormat::Percent
+            | TickFormat::SiSuffix
+            | TickFormat::Precision(_)
+            | TickFormat::Scientific => {
+                if matches!(scale.scale_type(), Scale::Discrete | Scale::Temporal) {
+                    return ticks;
+                }
+                ticks
+                    .into_iter()
+                    .map(|tick| Tick {
+                        label: self.format_value(tick.value).unwrap_or(tick.label),
+                        value: tick.value,
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    /// Writes a single value in this notation; `None` for the formats that
+    /// depend on the scale rather than on the value alone.
+    pub(crate) fn format_value(self, value: f64) -> Option<String> {
+        match self {
+            TickFormat::Percent => Some(crate::scale::inverse::format_percent(value)),
+            TickFormat::SiSuffix => Some(crate::scale::inverse::format_si(value)),
+            TickFormat::Precision(decimals) => Some(format!("{:.*}", decimals, value)),
+            TickFormat::Scientific => Some(crate::scale::inverse::format_scientific(value)),
+            TickFormat::Auto
+            | TickFormat::LogBase
+            | TickFormat::Superscript
+            | TickFormat::Exponent => None,
+        }
+    }
+}
 
 /// A `Theme` defines the visual "look and feel" of a chart.
 ///
@@ -25,6 +133,11 @@ pub struct Theme {
     pub(crate) show_grid: bool,
     pub(crate) grid_color: SingleColor,
     pub(crate) grid_width: f64,
+    /// Dash pattern of grid lines (e.g. `[4.0, 2.0]`); empty draws solid lines.
+    pub(crate) grid_dash: Vec<f64>,
+    /// Opacity of grid lines, from 0.0 (hidden) to 1.0. If `None`, cartesian and
+    /// polar grids are drawn at 0.5 and geographic graticules fully opaque.
+    pub(crate) grid_opacity: Option<f64>,
 
     // --- Main Title Styling ---
     /// Font size for the main chart title.
@@ -33,6 +146,10 @@ pub struct Theme {
     pub(crate) title_family: String,
     /// Text color for the main chart title.
     pub(crate) title_color: SingleColor,
+    /// Font weight for the main chart title.
+    pub(crate) title_font_weight: FontWeight,
+    /// Font style for the main chart title.
+    pub(crate) title_font_style: FontStyle,
 
     // --- Axis Title (Label) Styling ---
     /// Font size for axis titles (e.g., "Price").
@@ -41,8 +158,14 @@ pub struct Theme {
     pub(crate) label_family: String,
     /// Text color for axis titles.
     pub(crate) label_color: SingleColor,
+    /// Font weight for axis titles.
+    pub(crate) label_font_weight: FontWeight,
+    /// Font style for axis titles.
+    pub(crate) label_font_style: FontStyle,
     /// Spacing between the axis title and the tick labels.
     pub(crate) label_padding: f64,
+    /// Layout of the vertical axis title.
+    pub(crate) y_label_orientation: TextOrientation,
 
     // --- Tick Label Styling (The numbers/categories on axes) ---
     /// Font size for the text next to axis ticks.
@@ -51,6 +174,10 @@ pub struct Theme {
     pub(crate) tick_label_family: String,
     /// Text color for tick labels.
     pub(crate) tick_label_color: SingleColor,
+    /// Font weight for tick labels.
+    pub(crate) tick_label_font_weight: FontWeight,
+    /// Font style for tick labels.
+    pub(crate) tick_label_font_style: FontStyle,
     /// Distance between the tick mark and the tick text.
     pub(crate) tick_label_padding: f64,
     /// Default rotation angle in degrees for X-axis tick labels.
@@ -58,6 +185,9 @@ pub struct Theme {
     /// Default rotation angle in degrees for Y-axis tick labels.
     pub(crate) y_tick_label_angle: f64,
 
+    /// Base direction of all text drawn by the theme.
+    pub(crate) text_direction: TextDirection,
+
     // --- Geometry & Stroke Properties ---
     /// Width of the main axis lines.
     pub(crate) axis_width: f64,
@@ -69,18 +199,31 @@ pub struct Theme {
     pub(crate) tick_length: f64,
     /// Minimum pixel spacing between ticks to ensure visual density.
     pub(crate) tick_min_spacing: f64,
+    /// Draws short, unlabeled minor ticks on scales that provide them (log axes).
+    pub(crate) minor_ticks: bool,
+    /// Requested number of ticks on the X and Y axes; `None` derives it from the space.
+    pub(crate) x_tick_count: Option<usize>,
+    pub(crate) y_tick_count: Option<usize>,
 
     // --- Legend Styling ---
     pub(crate) show_legend: bool,
     /// Font size for the legend's title.
     pub(crate) legend_title_size: f64,
     pub(crate) legend_title_color: SingleColor,
+    /// Font weight for the legend's title.
+    pub(crate) legend_title_font_weight: FontWeight,
+    /// Font style for the legend's title.
+    pub(crate) legend_title_font_style: FontStyle,
     /// Font size for legend item labels.
     pub(crate) legend_label_size: f64,
     /// Font family for all legend text.
     pub(crate) legend_label_family: String,
     /// Text color for all legend text.
     pub(crate) legend_label_color: SingleColor,
+    /// Font weight for legend item labels.
+    pub(crate) legend_label_font_weight: FontWeight,
+    /// Font style for legend item labels.
+    pub(crate) legend_label_font_style: FontStyle,
     /// Default position of the legend relative to the plot.
     pub(crate) legend_position: LegendPosition,
     /// Spacing between the plot area and the legend.
@@ -106,7 +249,7 @@ pub struct Theme {
 
     // --- Aesthetic Defaults ---
     /// Default color map for continuous data mapping.
-    pub(crate) color_map: ColorMap,
+    pub(crate) color_map: ColorGradient,
     /// Default categorical palette for discrete data mapping.
     pub(crate) palette: ColorPalette,
 
@@ -132,6 +275,52 @@ pub struct Theme {
 }
 
 impl Theme {
+    /// A preset for dark slides and dark-mode pages.
+    ///
+    /// Uses a near-black background with light text, axes and ticks, a muted
+    /// grid and facet strips, the soft `Set2` palette and the `Cool` color map,
+    /// whose colors all stay visible on a dark background. Light text reads
+    /// heavier on a dark canvas, so only the chart and legend titles are bold.
+    /// Layout and font families are those of `Theme::default()`. The
+    /// background is part of the theme, so SVG and PNG output both show it.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// chart.with_theme(Theme::dark()).save("slide.png")?;
+    /// ```
+    pub fn dark() -> Self {
+        let text: SingleColor = "#e0e0e0".into();
+        let line: SingleColor = "#b0b0b0".into();
+
+        Self {
+            background_color: "#1e1e1e".into(),
+            grid_color: "#3d3d3d".into(),
+            grid_opacity: Some(1.0),
+            title_color: text,
+            title_font_weight: FontWeight::Bold,
+            title_font_style: FontStyle::Normal,
+            label_color: text,
+            label_font_weight: FontWeight::Normal,
+            label_font_style: FontStyle::Normal,
+            tick_label_color: text,
+            tick_label_font_weight: FontWeight::Normal,
+            tick_label_font_style: FontStyle::Normal,
+            axes_color: line,
+            tick_color: line,
+            legend_title_color: text,
+            legend_title_font_weight: FontWeight::Bold,
+            legend_title_font_style: FontStyle::Normal,
+            legend_label_color: text,
+            legend_label_font_weight: FontWeight::Normal,
+            legend_label_font_style: FontStyle::Normal,
+            color_map: ColorGradient::Map(ColorMap::Cool),
+            palette: ColorPalette::Set2,
+            facet_label_color: text,
+            facet_strip_fill: "#383838".into(),
+            ..Self::default()
+        }
+    }
+
     // --- Global Configuration ---
 
     pub fn with_background_color(mut self, color: impl Into<SingleColor>) -> Self {
@@ -179,6 +368,18 @@ impl Theme {
         self
     }
 
+    /// Sets the dash pattern of grid lines as alternating dash and gap lengths.
+    pub fn with_grid_dash(mut self, dash: impl Into<Vec<f64>>) -> Self {
+        self.grid_dash = dash.into();
+        self
+    }
+
+    /// Sets the opacity of grid lines, clamped to `[0.0, 1.0]`.
+    pub const fn with_grid_opacity(mut self, opacity: f64) -> Self {
+        self.grid_opacity = Some(opacity.clamp(0.0, 1.0));
+        self
+    }
+
     // --- Title ---
 
     pub const fn with_title_size(mut self, size: f64) -> Self {
@@ -196,6 +397,16 @@ impl Theme {
         self
     }
 
+    pub const fn with_title_font_weight(mut self, weight: FontWeight) -> Self {
+        self.title_font_weight = weight;
+        self
+    }
+
+    pub const fn with_title_font_style(mut self, style: FontStyle) -> Self {
+        self.title_font_style = style;
+        self
+    }
+
     // --- Axis Label ---
 
     pub const fn with_label_size(mut self, size: f64) -> Self {
@@ -213,11 +424,27 @@ impl Theme {
         self
     }
 
+    pub const fn with_label_font_weight(mut self, weight: FontWeight) -> Self {
+        self.label_font_weight = weight;
+        self
+    }
+
+    pub const fn with_label_font_style(mut self, style: FontStyle) -> Self {
+        self.label_font_style = style;
+        self
+    }
+
     pub const fn with_label_padding(mut self, padding: f64) -> Self {
         self.label_padding = padding;
         self
     }
 
+    /// Draws the vertical axis title rotated (default) or with upright stacked glyphs.
+    pub const fn with_y_label_orientation(mut self, orientation: TextOrientation) -> Self {
+        self.y_label_orientation = orientation;
+        self
+    }
+
     // --- Tick Label ---
 
     pub const fn with_tick_label_size(mut self, size: f64) -> Self {
@@ -235,6 +462,16 @@ impl Theme {
         self
     }
 
+    pub const fn with_tick_label_font_weight(mut self, weight: FontWeight) -> Self {
+        self.tick_label_font_weight = weight;
+        self
+    }
+
+    pub const fn with_tick_label_font_style(mut self, style: FontStyle) -> Self {
+        self.tick_label_font_style = style;
+        self
+    }
+
     pub const fn with_tick_label_padding(mut self, padding: f64) -> Self {
         self.tick_label_padding = padding;
         self
@@ -250,6 +487,12 @@ impl Theme {
         self
     }
 
+    /// Sets the base direction of titles, axis labels, tick labels and legend text.
+    pub const fn with_text_direction(mut self, direction: TextDirection) -> Self {
+        self.text_direction = direction;
+        self
+    }
+
     // --- Geometry Strokes ---
 
     pub const fn with_axis_width(mut self, width: f64) -> Self {
@@ -282,6 +525,24 @@ impl Theme {
         self
     }
 
+    /// Enables minor ticks between the major ticks of log axes (2, 3, … 9 × 10ⁿ).
+    pub const fn with_minor_ticks(mut self, show: bool) -> Self {
+        self.minor_ticks = show;
+        self
+    }
+
+    /// Asks for about `count` ticks on the X-axis; 0 hides them.
+    pub const fn with_x_tick_count(mut self, count: usize) -> Self {
+        self.x_tick_count = Some(count);
+        self
+    }
+
+    /// Asks for about `count` ticks on the Y-axis; 0 hides them.
+    pub const fn with_y_tick_count(mut self, count: usize) -> Self {
+        self.y_tick_count = Some(count);
+        self
+    }
+
     // --- Legend Styling ---
 
     pub const fn with_show_legend(mut self, show: bool) -> Self {
@@ -299,6 +560,16 @@ impl Theme {
         self
     }
 
+    pub const fn with_legend_title_font_weight(mut self, weight: FontWeight) -> Self {
+        self.legend_title_font_weight = weight;
+        self
+    }
+
+    pub const fn with_legend_title_font_style(mut self, style: FontStyle) -> Self {
+        self.legend_title_font_style = style;
+        self
+    }
+
     pub const fn with_legend_label_size(mut self, size: f64) -> Self {
         self.legend_label_size = size;
         self
@@ -314,6 +585,16 @@ impl Theme {
         self
     }
 
+    pub const fn with_legend_label_font_weight(mut self, weight: FontWeight) -> Self {
+        self.legend_label_font_weight = weight;
+        self
+    }
+
+    pub const fn with_legend_label_font_style(mut self, style: FontStyle) -> Self {
+        self.legend_label_font_style = style;
+        self
+    }
+
     pub const fn with_legend_block_gap(mut self, gap: f64) -> Self {
         self.legend_block_gap = gap;
         self
@@ -370,8 +651,8 @@ impl Theme {
 
     // --- Color & Palette Defaults ---
 
-    pub const fn with_color_map(mut self, map: ColorMap) -> Self {
-        self.color_map = map;
+    pub fn with_color_map<M: Into<ColorGradient>>(mut self, map: M) -> Self {
+        self.color_map = map.into();
         self
     }
 
@@ -434,23 +715,33 @@ impl Default for Theme {
             show_grid: false,
             grid_color: " #BDBDBD".into(),
             grid_width: 1.0,
+            grid_dash: Vec::new(),
+            grid_opacity: None,
 
             title_size: 18.0,
             title_family: font_stack.clone(),
             title_color: "#333".into(),
+            title_font_weight: FontWeight::Bold,
+            title_font_style: FontStyle::Normal,
 
             label_size: 15.0,
             label_family: font_stack.clone(),
             label_color: "#333".into(),
+            label_font_weight: FontWeight::Bold,
+            label_font_style: FontStyle::Normal,
             label_padding: 5.0,
+            y_label_orientation: TextOrientation::Rotated,
 
             tick_label_size: 13.0,
             tick_label_family: font_stack.clone(),
             tick_label_color: "#333".into(),
+            tick_label_font_weight: FontWeight::Normal,
+            tick_label_font_style: FontStyle::Normal,
             tick_label_padding: 3.0,
 
             x_tick_label_angle: 0.0,
             y_tick_label_angle: 0.0,
+            text_direction: TextDirection::Ltr,
 
             axis_width: 1.0,
             axes_color: "black".into(),
@@ -458,13 +749,20 @@ impl Default for Theme {
             tick_color: "black".into(),
             tick_length: 6.0,
             tick_min_spacing: 50.0,
+            minor_ticks: false,
+            x_tick_count: None,
+            y_tick_count: None,
 
             show_legend: true,
             legend_title_color: "#333".into(),
+            legend_title_font_weight: FontWeight::Bold,
+            legend_title_font_style: FontStyle::Normal,
             legend_title_size: 14.0,
             legend_label_size: 12.0,
             legend_label_family: font_stack,
             legend_label_color: "#333".into(),
+            legend_label_font_weight: FontWeight::Normal,
+            legend_label_font_style: FontStyle::Normal,
             legend_block_gap: 35.0,
             legend_item_v_gap: 3.0,
             legend_col_h_gap: 15.0,
@@ -478,7 +776,7 @@ impl Default for Theme {
             panel_defense_ratio: 0.2,
             axis_reserve_buffer: 60.0,
 
-            color_map: ColorMap::Viridis,
+            color_map: ColorGradient::Map(ColorMap::Viridis),
             palette: ColorPalette::Tab10,
 
             facet_label_size: 11.0,
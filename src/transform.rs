@@ -1,10 +1,26 @@
+#![deny(clippy::unwrap_used, clippy::expect_used)]
+
+pub(crate) mod aggregate_transform;
 pub(crate) mod area_transform;
 pub(crate) mod bar_transform;
+pub(crate) mod bin_transform;
 pub(crate) mod box_tranform;
 pub(crate) mod calculate_transform;
 pub(crate) mod density_transform;
+pub(crate) mod dotplot_transform;
+pub(crate) mod dots_transform;
 pub(crate) mod errorbar_transform;
+pub(crate) mod filter_transform;
+pub(crate) mod fold_transform;
 pub(crate) mod hist_transform;
+pub(crate) mod index_transform;
+pub(crate) mod loess_transform;
+pub(crate) mod lookup_transform;
 pub(crate) mod point_transform;
+pub(crate) mod quantile_transform;
 pub(crate) mod rect_transform;
+pub(crate) mod regression_transform;
+pub(crate) mod resample_transform;
+pub(crate) mod sample_transform;
+pub(crate) mod violin_transform;
 pub(crate) mod window_transform;
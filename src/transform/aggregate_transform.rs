@@ -0,0 +1,166 @@
+use crate::chart::Chart;
+use crate::core::data::{AggregateOp, ColumnVector, Dataset};
+use crate::error::ChartonError;
+use crate::mark::Mark;
+use ahash::AHashMap;
+
+/// Definition of a single aggregated output column.
+///
+/// This struct specifies which field to summarize, which statistic to compute,
+/// and what to name the resulting column.
+#[derive(Debug, Clone)]
+pub struct AggregateFieldDef {
+    /// The data field to aggregate
+    pub field: String,
+    /// The aggregation to apply
+    pub op: AggregateOp,
+    /// The output name for the aggregated value
+    pub as_: String,
+}
+
+impl AggregateFieldDef {
+    /// Creates a new `AggregateFieldDef` instance
+    ///
+    /// # Parameters
+    /// * `field` - The name of the data field to aggregate
+    /// * `op` - The aggregation to apply (an `AggregateOp` or a name such as "mean")
+    /// * `as_` - The name for the output column
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let mean_price = AggregateFieldDef::new("price", AggregateOp::Mean, "mean_price");
+    /// ```
+    pub fn new(field: &str, op: impl Into<AggregateOp>, as_: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            op: op.into(),
+            as_: as_.into(),
+        }
+    }
+}
+
+/// Configuration parameters for aggregation
+///
+/// Collapses the data to one row per distinct combination of the `groupby`
+/// fields, holding the group keys followed by one column per aggregate.
+/// With no `groupby` fields the whole dataset is summarized into a single row.
+#[derive(Debug, Clone, Default)]
+pub struct AggregateTransform {
+    /// The aggregated output columns
+    pub aggregate: Vec<AggregateFieldDef>,
+    /// The data fields whose combined values define the groups
+    pub groupby: Vec<String>,
+}
+
+impl AggregateTransform {
+    /// Create a new AggregateTransform with the given aggregate fields and no grouping
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let aggregate = AggregateTransform::new(vec![
+    ///     AggregateFieldDef::new("price", AggregateOp::Mean, "mean_price"),
+    ///     AggregateFieldDef::new("price", AggregateOp::Count, "n"),
+    /// ])
+    /// .with_groupby(["category"]);
+    /// ```
+    pub const fn new(aggregate: Vec<AggregateFieldDef>) -> Self {
+        Self {
+            aggregate,
+            groupby: Vec::new(),
+        }
+    }
+
+    /// Set the groupby fields
+    ///
+    /// # Parameters
+    /// * `groupby` - The names of the columns to group by. Each distinct combination
+    ///   of their values becomes one output row.
+    pub fn with_groupby<S: Into<String>>(mut self, groupby: impl IntoIterator<Item = S>) -> Self {
+        self.groupby = groupby.into_iter().map(Into::into).collect();
+        self
+    }
+}
+
+impl<T: Mark> Chart<T> {
+    /// Aggregates the chart data per group, replacing it with the summary table.
+    ///
+    /// Groups keep the order in which they first appear in the data, and null
+    /// keys form a group of their own. Encodings and later transforms refer to
+    /// the group fields and the `as_` names of the aggregates.
+    pub fn transform_aggregate(mut self, params: AggregateTransform) -> Result<Self, ChartonError> {
+        if params.aggregate.is_empty() {
+            return Err(ChartonError::Data(
+                "AggregateTransform requires at least one aggregate field".into(),
+            ));
+        }
+
+        // Output names must be unique, or later columns would silently overwrite earlier ones.
+        let mut names: Vec<&str> = params.groupby.iter().map(String::as_str).collect();
+        for def in &params.aggregate {
+            if names.contains(&def.as_.as_str()) {
+                return Err(ChartonError::Data(format!(
+                    "AggregateTransform: duplicate output column '{}'",
+                    def.as_
+                )));
+            }
+            names.push(&def.as_);
+        }
+
+        let n = self.data.height();
+
+        // --- PHASE 1: GROUPING (first appearance order) ---
+        let key_cols = params
+            .groupby
+            .iter()
+            .map(|g| self.data.column(g))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let groups: Vec<Vec<usize>> = if key_cols.is_empty() {
+            vec![(0..n).collect()]
+        } else {
+            let mut order: Vec<Vec<usize>> = Vec::new();
+            let mut lookup: AHashMap<Vec<Option<String>>, usize> = AHashMap::new();
+            for i in 0..n {
+                let key: Vec<Option<String>> =
+                    key_cols.iter().map(|col| col.get(i).to_string()).collect();
+                let slot = *lookup.entry(key).or_insert_with(|| {
+                    order.push(Vec::new());
+                    order.len() - 1
+                });
+                order[slot].push(i);
+            }
+            order
+        };
+
+        // --- PHASE 2: BUILD THE SUMMARY TABLE ---
+        let mut result = Dataset::new();
+
+        // Group keys keep their original column type (e.g. dates stay temporal).
+        let first_rows: Vec<usize> = groups.iter().filter_map(|g| g.first().copied()).collect();
+        for (name, col) in params.groupby.iter().zip(&key_cols) {
+            result.add_column(name.as_str(), col.take(&first_rows))?;
+        }
+
+        for def in &params.aggregate {
+            let col = self.data.column(&def.field)?;
+            let data: Vec<f64> = groups
+                .iter()
+                .map(|indices| match def.op {
+                    // An empty dataset still has one (empty) global group.
+                    AggregateOp::Count if indices.is_empty() => 0.0,
+                    op => op.aggregate_by_index(col, indices),
+                })
+                .collect();
+            result.add_column(
+                def.as_.as_str(),
+                ColumnVector::Float64 {
+                    data,
+                    validity: None,
+                },
+            )?;
+        }
+
+        self.data = result;
+        Ok(self)
+    }
+}
@@ -1,6 +1,6 @@
 use crate::TEMP_SUFFIX;
 use crate::chart::Chart;
-use crate::core::data::{ColumnVector, Dataset};
+use crate::core::data::{ColumnVector, Dataset, NANOS_PER_DAY};
 use crate::core::utils::IntoParallelizable;
 use crate::encode::y::StackMode;
 use crate::error::ChartonError;
@@ -49,13 +49,13 @@ impl<T: Mark> Chart<T> {
         let x_prototype = x_col.clone();
 
         let x_ticks_str = if !is_continuous {
-            x_col.unique_values()
+            x_col.category_values()
         } else {
             Vec::new()
         };
 
         let color_series = if let Some(cf) = color_field {
-            self.data.column(cf)?.unique_values()
+            self.data.column(cf)?.category_values()
         } else {
             vec![format!("{}_default", TEMP_SUFFIX)]
         };
@@ -126,7 +126,11 @@ impl<T: Mark> Chart<T> {
                     (hasher.finish(), None, Some(s.clone()))
                 };
 
-                let series_values = grid.get(&x_key).unwrap();
+                // Every tick was collected from the grid itself, but a missing entry
+                // simply means no series has a value there.
+                let Some(series_values) = grid.get(&x_key) else {
+                    return tick_data;
+                };
                 let total: f64 = color_series
                     .iter()
                     .map(|c| series_values.get(c).copied().unwrap_or(0.0))
@@ -202,8 +206,12 @@ impl<T: Mark> Chart<T> {
                     validity: None,
                     timezone,
                 },
+                // Temporal values are read as nanoseconds; Date columns store days.
                 ColumnVector::Date { .. } => ColumnVector::Date {
-                    data: final_x_f.into_iter().map(|v| v.round() as i32).collect(),
+                    data: final_x_f
+                        .into_iter()
+                        .map(|v| (v / NANOS_PER_DAY).round() as i32)
+                        .collect(),
                     validity: None,
                 },
                 ColumnVector::Duration { .. } => ColumnVector::Duration {
@@ -4,6 +4,7 @@ use crate::core::data::{ColumnVector, Dataset};
 use crate::encode::y::StackMode;
 use crate::error::ChartonError;
 use crate::mark::Mark;
+use crate::mark::bar::MarkBar;
 use ahash::AHashMap;
 
 impl<T: Mark> Chart<T> {
@@ -14,7 +15,7 @@ impl<T: Mark> Chart<T> {
             .y
             .as_mut()
             .ok_or_else(|| ChartonError::Encoding("Y encoding missing".into()))?;
-        let agg_op = y_enc.aggregate;
+        let mut agg_op = y_enc.aggregate;
         let x_enc = self
             .encoding
             .x
@@ -27,17 +28,38 @@ impl<T: Mark> Chart<T> {
 
         // Check for Pie mode (empty X field)
         let is_pie = x_field.is_empty();
+        let mut per_row_slices = false;
         if is_pie {
             y_enc.stack = StackMode::Stacked;
             x_field = format!("{}_virtual_root__", TEMP_SUFFIX);
+
+            let arc_aggregate = self
+                .mark
+                .as_ref()
+                .and_then(|m| (m as &dyn std::any::Any).downcast_ref::<MarkBar>())
+                .and_then(|b| b.arc_aggregate);
+            match arc_aggregate {
+                Some(Some(op)) => agg_op = op,
+                Some(None) => per_row_slices = true,
+                None => {}
+            }
         }
 
+        // An explicit dodge field splits every category into side-by-side sub-bars.
+        let dodge_field = x_enc
+            .dodge_by
+            .clone()
+            .filter(|df| !is_pie && df != &x_field);
+
         let color_field = color_enc_opt.map(|ce| &ce.field);
-        let has_grouping_color = if let Some(cf) = color_field {
-            cf != &x_field
-        } else {
-            false
-        };
+        // Color only splits the bars when it encodes a field other than X.
+        let grouping_field = color_field.filter(|cf| *cf != &x_field);
+        let has_grouping_color = grouping_field.is_some();
+
+        // Colors inside a dodged sub-bar are always stacked.
+        if dodge_field.is_some() && has_grouping_color && y_enc.stack == StackMode::None {
+            y_enc.stack = StackMode::Stacked;
+        }
 
         // Capture prototypes for categorical restoration
         let x_col_proto = if !is_pie {
@@ -45,14 +67,18 @@ impl<T: Mark> Chart<T> {
         } else {
             None
         };
-        let c_col_proto = if has_grouping_color {
-            Some(self.data.column(color_field.unwrap())?.clone())
-        } else {
-            None
+        let c_col_proto = match grouping_field {
+            Some(cf) => Some(self.data.column(cf)?.clone()),
+            None => None,
+        };
+        let d_col_proto = match &dodge_field {
+            Some(df) => Some(self.data.column(df)?.clone()),
+            None => None,
         };
 
         // --- STEP 2: Aggregate Data ---
-        let mut group_map: AHashMap<(String, Option<String>), Vec<usize>> = AHashMap::new();
+        type GroupKey = (String, Option<String>, Option<String>);
+        let mut group_map: AHashMap<GroupKey, Vec<usize>> = AHashMap::new();
         let row_count = self.data.height();
 
         for i in 0..row_count {
@@ -74,23 +100,95 @@ impl<T: Mark> Chart<T> {
             } else {
                 None
             };
-            group_map.entry((x_val, c_val)).or_default().push(i);
+            let d_val = dodge_field.as_ref().map(|df| {
+                self.data
+                    .get(df, i)
+                    .to_string()
+                    .unwrap_or_else(|| "null".to_string())
+            });
+            group_map.entry((x_val, d_val, c_val)).or_default().push(i);
         }
 
         let y_col = self.data.column(&y_field)?;
-        let mut lookup: AHashMap<(String, Option<String>), f64> = group_map
+
+        // A link column is carried through with the URL of the first row of each bar.
+        let href_field = self
+            .encoding
+            .href
+            .as_ref()
+            .map(|h| h.field.clone())
+            .filter(|hf| {
+                *hf != x_field
+                    && *hf != y_field
+                    && Some(hf) != grouping_field
+                    && Some(hf) != dodge_field.as_ref()
+            });
+        let first_rows: AHashMap<GroupKey, usize> = match &href_field {
+            Some(_) => group_map
+                .iter()
+                .map(|(key, indices)| (key.clone(), indices[0]))
+                .collect(),
+            None => AHashMap::new(),
+        };
+
+        if is_pie {
+            // Slice angles are proportional to the values, so negatives have no meaning.
+            let negative_rows: Vec<usize> = (0..row_count)
+                .filter(|&i| y_col.get(i).to_f64().is_some_and(|v| v < 0.0))
+                .collect();
+            if !negative_rows.is_empty() {
+                let shown: Vec<String> = negative_rows
+                    .iter()
+                    .take(10)
+                    .map(|i| i.to_string())
+                    .collect();
+                let more = if negative_rows.len() > 10 {
+                    ", ..."
+                } else {
+                    ""
+                };
+                return Err(ChartonError::Data(format!(
+                    "Pie chart values in '{}' must be non-negative; found {} negative value(s) at rows [{}{}]",
+                    y_field,
+                    negative_rows.len(),
+                    shown.join(", "),
+                    more
+                )));
+            }
+
+            if per_row_slices {
+                let normalize = y_enc.normalize || y_enc.stack == StackMode::Normalize;
+                let color_field = grouping_field.cloned();
+                return self.per_row_pie(color_field, href_field, normalize);
+            }
+
+            // Repeated categories are merged into a single slice rather than drawn as
+            // separate wedges of the same color; report it so the merge is not silent.
+            if group_map.len() < row_count {
+                eprintln!(
+                    "Pie: Aggregated {} rows of '{}' into {} slices ({:?}).",
+                    row_count,
+                    y_field,
+                    group_map.len(),
+                    agg_op
+                );
+            }
+        }
+
+        let mut lookup: AHashMap<GroupKey, f64> = group_map
             .into_iter()
             .map(|(key, indices)| (key, agg_op.aggregate_by_index(y_col, &indices)))
             .collect();
 
         // --- STEP 3: Normalization ---
+        // Each bar (or dodged sub-bar) sums to 1.
         if y_enc.normalize || y_enc.stack == StackMode::Normalize {
-            let mut x_sums: AHashMap<String, f64> = AHashMap::new();
-            for ((x, _), val) in &lookup {
-                *x_sums.entry(x.clone()).or_insert(0.0) += val;
+            let mut x_sums: AHashMap<(String, Option<String>), f64> = AHashMap::new();
+            for ((x, d, _), val) in &lookup {
+                *x_sums.entry((x.clone(), d.clone())).or_insert(0.0) += val;
             }
-            for ((x, _), val) in lookup.iter_mut() {
-                let sum = x_sums.get(x).cloned().unwrap_or(0.0);
+            for ((x, d, _), val) in lookup.iter_mut() {
+                let sum = x_sums.get(&(x.clone(), d.clone())).cloned().unwrap_or(0.0);
                 *val = if sum != 0.0 { *val / sum } else { 0.0 };
             }
         }
@@ -99,34 +197,55 @@ impl<T: Mark> Chart<T> {
         let x_uniques = if is_pie {
             vec!["all".to_string()]
         } else {
-            self.data.column(&x_field)?.unique_values()
+            self.data.column(&x_field)?.category_values()
         };
 
-        let c_uniques = if has_grouping_color {
-            self.data.column(color_field.unwrap())?.unique_values()
-        } else {
-            vec![]
+        let mut c_uniques = match grouping_field {
+            Some(cf) => self.data.column(cf)?.category_values(),
+            None => vec![],
+        };
+        // Ordinal colors stack (and dodge) in their declared order.
+        if let Some(order) = self.encoding.color.as_ref().and_then(|c| c.order.as_ref()) {
+            c_uniques.sort_by_key(|c| order.iter().position(|o| o == c).unwrap_or(order.len()));
+        }
+
+        let d_uniques: Vec<Option<String>> = match &dodge_field {
+            Some(df) => self
+                .data
+                .column(df)?
+                .category_values()
+                .into_iter()
+                .map(Some)
+                .collect(),
+            None => vec![None],
         };
 
         let mut final_x = Vec::new();
         let mut final_y = Vec::new();
         let mut final_color = Vec::new();
+        let mut final_dodge = Vec::new();
+        let mut final_href_rows = Vec::new();
 
         for x in &x_uniques {
-            if has_grouping_color {
-                for c in &c_uniques {
-                    let val = lookup
-                        .get(&(x.clone(), Some(c.clone())))
-                        .cloned()
-                        .unwrap_or(0.0);
+            for d in &d_uniques {
+                if has_grouping_color {
+                    for c in &c_uniques {
+                        let key = (x.clone(), d.clone(), Some(c.clone()));
+                        let val = lookup.get(&key).cloned().unwrap_or(0.0);
+                        final_href_rows.push(first_rows.get(&key).copied());
+                        final_x.push(x.clone());
+                        final_dodge.extend(d.clone());
+                        final_color.push(c.clone());
+                        final_y.push(val);
+                    }
+                } else {
+                    let key = (x.clone(), d.clone(), None);
+                    let val = lookup.get(&key).cloned().unwrap_or(0.0);
+                    final_href_rows.push(first_rows.get(&key).copied());
                     final_x.push(x.clone());
-                    final_color.push(c.clone());
+                    final_dodge.extend(d.clone());
                     final_y.push(val);
                 }
-            } else {
-                let val = lookup.get(&(x.clone(), None)).cloned().unwrap_or(0.0);
-                final_x.push(x.clone());
-                final_y.push(val);
             }
         }
 
@@ -149,56 +268,17 @@ impl<T: Mark> Chart<T> {
                 },
             )?;
         } else {
-            let x_cv = match x_col_proto {
-                Some(ColumnVector::Categorical { values, .. }) => {
-                    let val_map: AHashMap<&str, u32> = values
-                        .iter()
-                        .enumerate()
-                        .map(|(idx, s)| (s.as_str(), idx as u32))
-                        .collect();
-                    let keys = final_x
-                        .iter()
-                        .map(|s| *val_map.get(s.as_str()).unwrap_or(&0))
-                        .collect();
-                    ColumnVector::Categorical {
-                        keys,
-                        values,
-                        validity: None,
-                    }
-                }
-                _ => ColumnVector::String {
-                    data: final_x,
-                    validity: None,
-                },
-            };
-            new_ds.add_column(&x_field, x_cv)?;
-        }
-
-        // 2. Restore Color Axis (Categorical support)
-        if has_grouping_color {
-            let c_cv = match c_col_proto {
-                Some(ColumnVector::Categorical { values, .. }) => {
-                    let val_map: AHashMap<&str, u32> = values
-                        .iter()
-                        .enumerate()
-                        .map(|(idx, s)| (s.as_str(), idx as u32))
-                        .collect();
-                    let keys = final_color
-                        .iter()
-                        .map(|s| *val_map.get(s.as_str()).unwrap_or(&0))
-                        .collect();
-                    ColumnVector::Categorical {
-                        keys,
-                        values,
-                        validity: None,
-                    }
-                }
-                _ => ColumnVector::String {
-                    data: final_color,
-                    validity: None,
-                },
-            };
-            new_ds.add_column(color_field.unwrap(), c_cv)?;
+            new_ds.add_column(&x_field, restore_column(x_col_proto, final_x))?;
+        }
+
+        // 2. Restore Color and Dodge Axes (Categorical support)
+        if let Some(cf) = grouping_field {
+            new_ds.add_column(cf, restore_column(c_col_proto, final_color))?;
+        }
+        if let Some(df) = &dodge_field
+            && Some(df) != grouping_field
+        {
+            new_ds.add_column(df, restore_column(d_col_proto, final_dodge))?;
         }
 
         // 3. Measures (Y is always F64 after aggregation)
@@ -210,14 +290,29 @@ impl<T: Mark> Chart<T> {
             },
         )?;
 
+        // Gap-filled bars have no source row and get no link.
+        if let Some(hf) = &href_field {
+            let href_col = self.data.column(hf)?.take_or_null(&final_href_rows);
+            new_ds.add_column(hf.as_str(), href_col)?;
+        }
+
         // 4. Layout Helpers (consistent with new Float64 variant)
         let mut f_groups_count = Vec::with_capacity(total_rows);
         let mut f_sub_idx = Vec::with_capacity(total_rows);
 
+        // Dodged sub-bars take the side-by-side slots; colors then stack inside them.
+        let total_d = d_uniques.len();
         for _ in &x_uniques {
-            for j in 0..total_c {
-                f_groups_count.push(total_c as f64);
-                f_sub_idx.push(j as f64);
+            for d in 0..total_d {
+                for c in 0..total_c {
+                    let (count, idx) = if dodge_field.is_some() {
+                        (total_d, d)
+                    } else {
+                        (total_c, c)
+                    };
+                    f_groups_count.push(count as f64);
+                    f_sub_idx.push(idx as f64);
+                }
             }
         }
 
@@ -241,3 +336,114 @@ impl<T: Mark> Chart<T> {
         Ok(self)
     }
 }
+
+impl<T: Mark> Chart<T> {
+    /// Lays out a pie with one slice per row, in data order, for charts that
+    /// opted out of merging repeated categories (`with_arc_aggregate(None)`).
+    fn per_row_pie(
+        mut self,
+        color_field: Option<String>,
+        href_field: Option<String>,
+        normalize: bool,
+    ) -> Result<Self, ChartonError> {
+        let y_field = self
+            .encoding
+            .y
+            .as_ref()
+            .map(|y| y.field.clone())
+            .unwrap_or_default();
+        let row_count = self.data.height();
+
+        let mut values: Vec<f64> = (0..row_count)
+            .map(|i| {
+                self.data
+                    .column(&y_field)
+                    .map(|c| c.get(i).to_f64().unwrap_or(0.0))
+            })
+            .collect::<Result<_, _>>()?;
+        if normalize {
+            let sum: f64 = values.iter().sum();
+            for v in values.iter_mut() {
+                *v = if sum != 0.0 { *v / sum } else { 0.0 };
+            }
+        }
+
+        let rows: Vec<usize> = (0..row_count).collect();
+        let mut new_ds = Dataset::new();
+        new_ds.add_column(
+            "",
+            ColumnVector::String {
+                data: vec!["all".to_string(); row_count],
+                validity: None,
+            },
+        )?;
+        if let Some(cf) = &color_field {
+            new_ds.add_column(cf.as_str(), self.data.column(cf)?.take(&rows))?;
+        }
+        new_ds.add_column(
+            &y_field,
+            ColumnVector::Float64 {
+                data: values,
+                validity: None,
+            },
+        )?;
+        if let Some(hf) = &href_field {
+            new_ds.add_column(hf.as_str(), self.data.column(hf)?.take(&rows))?;
+        }
+        new_ds.add_column(
+            format!("{}_groups_count", TEMP_SUFFIX),
+            ColumnVector::Float64 {
+                data: vec![1.0; row_count],
+                validity: None,
+            },
+        )?;
+        new_ds.add_column(
+            format!("{}_sub_idx", TEMP_SUFFIX),
+            ColumnVector::Float64 {
+                data: vec![0.0; row_count],
+                validity: None,
+            },
+        )?;
+
+        self.data = new_ds;
+        Ok(self)
+    }
+}
+
+/// Rebuilds a grouping column from its string values, keeping the categorical
+/// dictionary of the source column so that declared category orders survive.
+/// Dates and datetimes are parsed back from their raw values so that bars on a
+/// time axis keep their temporal scale.
+fn restore_column(proto: Option<ColumnVector>, data: Vec<String>) -> ColumnVector {
+    match proto {
+        Some(ColumnVector::Categorical { values, .. }) => {
+            let val_map: AHashMap<&str, u32> = values
+                .iter()
+                .enumerate()
+                .map(|(idx, s)| (s.as_str(), idx as u32))
+                .collect();
+            let keys = data
+                .iter()
+                .map(|s| *val_map.get(s.as_str()).unwrap_or(&0))
+                .collect();
+            ColumnVector::Categorical {
+                keys,
+                values,
+                validity: None,
+            }
+        }
+        Some(ColumnVector::Date { .. }) => ColumnVector::Date {
+            data: data.iter().map(|s| s.parse().unwrap_or_default()).collect(),
+            validity: None,
+        },
+        Some(ColumnVector::Datetime { timezone, .. }) => ColumnVector::Datetime {
+            data: data.iter().map(|s| s.parse().unwrap_or_default()).collect(),
+            validity: None,
+            timezone,
+        },
+        _ => ColumnVector::String {
+            data,
+            validity: None,
+        },
+    }
+}
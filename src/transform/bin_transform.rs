@@ -0,0 +1,243 @@
+use crate::chart::Chart;
+use crate::core::data::{ColumnVector, SemanticType};
+use crate::error::ChartonError;
+use crate::mark::Mark;
+use crate::transform::hist_transform::{MAX_BINS, cap_bin_count};
+
+/// Configuration parameters for the bin transformation
+///
+/// Assigns every value of `field` to an interval and records the interval
+/// bounds in two new columns. Unlike `mark_hist`, nothing is counted: the rows
+/// are kept, so the bins can be aggregated, colored and dodged like any other
+/// category, e.g. with `mark_bar` and a `count` aggregate.
+///
+/// Bins are defined by an explicit `step`, or else by the nicest step (1, 2 or
+/// 5 times a power of ten) that covers the extent in at most `maxbins` bins.
+#[derive(Debug, Clone)]
+pub struct BinTransform {
+    // The numeric field to bin
+    pub(crate) field: String,
+    // The name of the column holding the lower bound of each bin
+    pub(crate) as_start: String,
+    // The name of the column holding the upper bound of each bin
+    pub(crate) as_end: String,
+    // The maximal number of bins when no step is given
+    pub(crate) maxbins: Option<usize>,
+    // The width of each bin; takes precedence over `maxbins`
+    pub(crate) step: Option<f64>,
+    // The range to bin; defaults to the range of the data
+    pub(crate) extent: Option<(f64, f64)>,
+}
+
+impl BinTransform {
+    /// Creates a new `BinTransform` instance with default parameters
+    ///
+    /// # Parameters
+    /// * `field` - The name of the numeric column to bin
+    ///
+    /// # Returns
+    /// A new `BinTransform` instance with the following defaults:
+    /// - Output field names: `{field}_start` and `{field}_end`
+    /// - At most 10 bins with a nice step
+    /// - Extent: the range of the data, widened to multiples of the step
+    pub fn new(field: impl Into<String>) -> Self {
+        let field = field.into();
+        Self {
+            as_start: format!("{}_start", field),
+            as_end: format!("{}_end", field),
+            field,
+            maxbins: None,
+            step: None,
+            extent: None,
+        }
+    }
+
+    /// Sets the output column names for the bin bounds
+    pub fn with_as(mut self, start: impl Into<String>, end: impl Into<String>) -> Self {
+        self.as_start = start.into();
+        self.as_end = end.into();
+        self
+    }
+
+    /// Sets the maximal number of bins (default 10, at most 10,000); ignored when a step is set
+    pub const fn with_maxbins(mut self, maxbins: usize) -> Self {
+        self.maxbins = Some(maxbins);
+        self
+    }
+
+    /// Sets the exact width of the bins, widened if the extent would need over 10,000
+    pub const fn with_step(mut self, step: f64) -> Self {
+        self.step = Some(step);
+        self
+    }
+
+    /// Sets the range to bin
+    ///
+    /// The first bin starts exactly at `min`; values outside the range get
+    /// null bounds.
+    pub const fn with_extent(mut self, min: f64, max: f64) -> Self {
+        self.extent = Some((min, max));
+        self
+    }
+}
+
+impl<T: Mark> Chart<T> {
+    /// Bins a numeric field, adding the lower and upper bound of each value's bin.
+    ///
+    /// Bins are closed on the left, except the last one which also includes its
+    /// upper bound. Null values get null bounds.
+    ///
+    /// # Errors
+    /// Returns `ChartonError::Data` if the field is missing or not numeric, if
+    /// `maxbins` is 0, if the step or extent is invalid, or if an output column
+    /// already exists.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// chart
+    ///     .transform_bin(BinTransform::new("body_mass_g").with_step(250.0))?
+    ///     .mark_bar()?
+    ///     .encode((
+    ///         alt::x("body_mass_g_start").with_scale(Scale::Discrete),
+    ///         alt::y("body_mass_g").with_aggregate("count"),
+    ///         alt::color("species"),
+    ///     ))?
+    /// ```
+    pub fn transform_bin(mut self, params: BinTransform) -> Result<Self, ChartonError> {
+        if params.as_start == params.as_end {
+            return Err(ChartonError::Data(format!(
+                "transform_bin: output fields are both named '{}'",
+                params.as_start
+            )));
+        }
+        for name in [&params.as_start, &params.as_end] {
+            if self.data.schema.contains_key(name) {
+                return Err(ChartonError::Data(format!(
+                    "transform_bin: column '{}' already exists",
+                    name
+                )));
+            }
+        }
+
+        let col = self.data.column(&params.field)?;
+        if col.semantic_type() != SemanticType::Continuous {
+            return Err(ChartonError::Data(format!(
+                "transform_bin: field '{}' is not numeric",
+                params.field
+            )));
+        }
+        let values = col.to_f64_options();
+
+        // --- STEP 1: Extent ---
+        let (lo, hi) = match params.extent {
+            Some((lo, hi)) if lo.is_finite() && hi.is_finite() && lo < hi => (lo, hi),
+            Some((lo, hi)) => {
+                return Err(ChartonError::Data(format!(
+                    "transform_bin: invalid extent [{}, {}]",
+                    lo, hi
+                )));
+            }
+            None => {
+                let finite = values.iter().flatten().filter(|v| v.is_finite());
+                let (lo, hi) = finite.fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), &v| {
+                    (lo.min(v), hi.max(v))
+                });
+                // A single distinct value still gets a bin of its own.
+                if lo > hi {
+                    (0.0, 1.0)
+                } else if lo == hi {
+                    (lo, lo + 1.0)
+                } else {
+                    (lo, hi)
+                }
+            }
+        };
+
+        // --- STEP 2: Step and first edge ---
+        let step = match (params.step, params.maxbins) {
+            (Some(step), _) if step.is_finite() && step > 0.0 => step,
+            (Some(step), _) => {
+                return Err(ChartonError::Data(format!(
+                    "transform_bin: step must be positive, got {}",
+                    step
+                )));
+            }
+            (None, Some(0)) => {
+                return Err(ChartonError::Data(
+                    "transform_bin: maxbins must be at least 1".into(),
+                ));
+            }
+            (None, maxbins) => nice_step(
+                lo,
+                hi,
+                cap_bin_count(maxbins.unwrap_or(10)),
+                params.extent.is_some(),
+            ),
+        };
+        // A step too small for the extent is widened to the nicest step that fits.
+        let step = if ((hi - lo) / step).ceil() >= MAX_BINS as f64 {
+            let widened = nice_step(lo, hi, MAX_BINS, params.extent.is_some());
+            if widened > step {
+                eprintln!(
+                    "transform_bin: a step of {} needs more than {} bins; widened to {}.",
+                    step, MAX_BINS, widened
+                );
+            }
+            step.max(widened)
+        } else {
+            step
+        };
+        // An explicit extent anchors the bins; otherwise they sit on multiples of the step.
+        let start = if params.extent.is_some() {
+            lo
+        } else {
+            (lo / step).floor() * step
+        };
+        let n_bins = (((hi - start) / step).ceil() as usize).max(1);
+
+        // --- STEP 3: Assign bins ---
+        let (bin_start, bin_end): (Vec<Option<f64>>, Vec<Option<f64>>) = values
+            .iter()
+            .map(|v| match v {
+                Some(v) if v.is_finite() && (lo..=hi).contains(v) => {
+                    // The tolerance keeps values on an edge (e.g. 0.3 with a 0.1 step)
+                    // from falling into the previous bin through rounding.
+                    let idx = (((v - start) / step + 1e-9).floor() as usize).min(n_bins - 1);
+                    let edge = start + idx as f64 * step;
+                    (Some(edge), Some(edge + step))
+                }
+                _ => (None, None),
+            })
+            .unzip();
+
+        self.data
+            .add_column(params.as_start.as_str(), ColumnVector::from(bin_start))?;
+        self.data
+            .add_column(params.as_end.as_str(), ColumnVector::from(bin_end))?;
+        Ok(self)
+    }
+}
+
+/// The smallest step of the form 1, 2 or 5 times a power of ten that covers
+/// `[lo, hi]` in at most `maxbins` bins.
+///
+/// Unless `anchored` (bins starting exactly at `lo`), the first bin starts at a
+/// multiple of the step, which may take one more bin than the range alone.
+fn nice_step(lo: f64, hi: f64, maxbins: usize, anchored: bool) -> f64 {
+    let raw = (hi - lo) / maxbins as f64;
+    let mut exp = 10f64.powf(raw.log10().floor());
+    loop {
+        for factor in [1.0, 2.0, 5.0] {
+            let step = factor * exp;
+            let start = if anchored {
+                lo
+            } else {
+                (lo / step).floor() * step
+            };
+            if ((hi - start) / step).ceil() <= maxbins as f64 {
+                return step;
+            }
+        }
+        exp *= 10.0;
+    }
+}
@@ -11,8 +11,18 @@ impl<T: Mark> Chart<T> {
     /// This version ensures that gaps in categorical combinations are filled
     /// to maintain visual alignment (Dodge) and injects boundary points for scaling.
     pub(crate) fn transform_boxplot_data(mut self) -> Result<Self, ChartonError> {
-        let x_name = &self.encoding.x.as_ref().unwrap().field;
-        let y_name = &self.encoding.y.as_ref().unwrap().field;
+        let x_name = &self
+            .encoding
+            .x
+            .as_ref()
+            .ok_or_else(|| ChartonError::Encoding("X encoding missing for boxplot".into()))?
+            .field;
+        let y_name = &self
+            .encoding
+            .y
+            .as_ref()
+            .ok_or_else(|| ChartonError::Encoding("Y encoding missing for boxplot".into()))?
+            .field;
 
         // --- STEP 1: Capture raw columns and calculate global Y-axis boundaries ---
         let x_col = self.data.column(x_name)?;
@@ -37,14 +47,14 @@ impl<T: Mark> Chart<T> {
         }
 
         // --- STEP 2: Establish Deterministic Order for X and Color ---
-        let x_order = x_col.unique_values();
+        let x_order = x_col.category_values();
         let mut color_field_name: Option<String> = None;
         let mut color_order = Vec::new();
 
         if let Some(color_enc) = &self.encoding.color {
             let cf = color_enc.field.clone();
             let c_col = self.data.column(&cf)?;
-            color_order = c_col.unique_values();
+            color_order = c_col.category_values();
             color_col_proto = Some(c_col.clone());
             color_field_name = Some(cf);
         }
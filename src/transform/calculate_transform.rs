@@ -14,10 +14,24 @@ impl<T: Mark> Chart<T> {
     /// * `as_name`: The name of the new column.
     /// * `f`: A closure mapping a row to an optional value.
     ///   Returning `None` will be treated as a Null value (validity = 0).
+    ///
+    /// Calls chain, and each new column is visible to the closures of later
+    /// calculations, to other transforms and to `encode()`.
+    ///
+    /// # Errors
+    /// Returns `ChartonError::Data` if `as_name` is already a column, instead of
+    /// silently overwriting it.
     pub fn transform_calculate<F>(mut self, as_name: &str, f: F) -> Result<Self, ChartonError>
     where
         F: Fn(RowAccessor) -> Option<f64> + Sync + Send,
     {
+        if self.data.schema.contains_key(as_name) {
+            return Err(ChartonError::Data(format!(
+                "transform_calculate: column '{}' already exists",
+                as_name
+            )));
+        }
+
         let row_count = self.data.height();
         if row_count == 0 {
             return Ok(self);
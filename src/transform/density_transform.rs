@@ -1,8 +1,9 @@
 use crate::chart::Chart;
-use crate::core::data::{ColumnVector, Dataset};
+use crate::core::data::{ColumnVector, Dataset, NANOS_PER_DAY};
 use crate::error::ChartonError;
 use crate::mark::Mark;
 use ahash::AHashMap;
+#[cfg(feature = "kde")]
 use kernel_density_estimation::prelude::*;
 
 /// Kernel functions used in kernel density estimation
@@ -240,7 +241,7 @@ impl DensityTransform {
 
 impl<T: Mark> Chart<T> {
     /// Transform data by performing kernel density estimation (KDE).
-    /// Uses ColumnVector::unique_values() to ensure deterministic group ordering
+    /// Uses ColumnVector::category_values() to ensure deterministic group ordering
     /// and consistent null-filtering behavior.
     pub fn transform_density(mut self, params: DensityTransform) -> Result<Self, ChartonError> {
         let density_field = &params.density;
@@ -274,12 +275,12 @@ impl<T: Mark> Chart<T> {
         let x_axis_values: Vec<f64> = eval_points.iter().map(|&v| v as f64).collect();
 
         // --- STEP 2: Establish Deterministic Order ---
-        // We use unique_values() to ensure the order of density curves matches
-        // the legend and other transforms (First Appearance).
+        // We use category_values() to ensure the order of density curves matches
+        // the legend and other transforms.
         let group_order: Vec<Option<String>> = if let Some(ref g_field) = params.groupby {
             self.data
                 .column(g_field)?
-                .unique_values()
+                .category_values()
                 .into_iter()
                 .map(Some)
                 .collect()
@@ -324,101 +325,13 @@ impl<T: Mark> Chart<T> {
 
             let group_label = key.as_deref().unwrap_or("all").to_string();
 
-            // KDE calculation dispatch (Logic preserved from original version)
-            let density_values: Vec<f64> = match (&params.bandwidth, &params.kernel) {
-                (BandwidthType::Scott, KernelType::Normal) => {
-                    let kde = KernelDensityEstimator::new(observations.clone(), Scott, Normal);
-                    if params.cumulative {
-                        kde.cdf(&eval_points)
-                    } else {
-                        kde.pdf(&eval_points)
-                    }
-                }
-                (BandwidthType::Scott, KernelType::Epanechnikov) => {
-                    let kde =
-                        KernelDensityEstimator::new(observations.clone(), Scott, Epanechnikov);
-                    if params.cumulative {
-                        kde.cdf(&eval_points)
-                    } else {
-                        kde.pdf(&eval_points)
-                    }
-                }
-                (BandwidthType::Scott, KernelType::Uniform) => {
-                    let kde = KernelDensityEstimator::new(observations.clone(), Scott, Uniform);
-                    if params.cumulative {
-                        kde.cdf(&eval_points)
-                    } else {
-                        kde.pdf(&eval_points)
-                    }
-                }
-                (BandwidthType::Silverman, KernelType::Normal) => {
-                    let kde = KernelDensityEstimator::new(observations.clone(), Silverman, Normal);
-                    if params.cumulative {
-                        kde.cdf(&eval_points)
-                    } else {
-                        kde.pdf(&eval_points)
-                    }
-                }
-                (BandwidthType::Silverman, KernelType::Epanechnikov) => {
-                    let kde =
-                        KernelDensityEstimator::new(observations.clone(), Silverman, Epanechnikov);
-                    if params.cumulative {
-                        kde.cdf(&eval_points)
-                    } else {
-                        kde.pdf(&eval_points)
-                    }
-                }
-                (BandwidthType::Silverman, KernelType::Uniform) => {
-                    let kde = KernelDensityEstimator::new(observations.clone(), Silverman, Uniform);
-                    if params.cumulative {
-                        kde.cdf(&eval_points)
-                    } else {
-                        kde.pdf(&eval_points)
-                    }
-                }
-                (BandwidthType::Fixed(bw), KernelType::Normal) => {
-                    let h = *bw as f32;
-                    let kde = KernelDensityEstimator::new(
-                        observations.clone(),
-                        move |_: &[f32]| h,
-                        Normal,
-                    );
-                    if params.cumulative {
-                        kde.cdf(&eval_points)
-                    } else {
-                        kde.pdf(&eval_points)
-                    }
-                }
-                (BandwidthType::Fixed(bw), KernelType::Epanechnikov) => {
-                    let h = *bw as f32;
-                    let kde = KernelDensityEstimator::new(
-                        observations.clone(),
-                        move |_: &[f32]| h,
-                        Epanechnikov,
-                    );
-                    if params.cumulative {
-                        kde.cdf(&eval_points)
-                    } else {
-                        kde.pdf(&eval_points)
-                    }
-                }
-                (BandwidthType::Fixed(bw), KernelType::Uniform) => {
-                    let h = *bw as f32;
-                    let kde = KernelDensityEstimator::new(
-                        observations.clone(),
-                        move |_: &[f32]| h,
-                        Uniform,
-                    );
-                    if params.cumulative {
-                        kde.cdf(&eval_points)
-                    } else {
-                        kde.pdf(&eval_points)
-                    }
-                }
-            }
-            .into_iter()
-            .map(|v| v as f64)
-            .collect();
+            let density_values = estimate_density(
+                observations,
+                &eval_points,
+                &params.bandwidth,
+                &params.kernel,
+                params.cumulative,
+            )?;
 
             let obs_count = observations.len() as f64;
             let processed_y = if params.counts {
@@ -447,8 +360,12 @@ impl<T: Mark> Chart<T> {
                 validity: None,
                 timezone,
             },
+            // Temporal values are read as nanoseconds; Date columns store days.
             ColumnVector::Date { .. } => ColumnVector::Date {
-                data: final_x.into_iter().map(|v| v.round() as i32).collect(),
+                data: final_x
+                    .into_iter()
+                    .map(|v| (v / NANOS_PER_DAY).round() as i32)
+                    .collect(),
                 validity: None,
             },
             ColumnVector::Duration { .. } => ColumnVector::Duration {
@@ -491,3 +408,110 @@ impl<T: Mark> Chart<T> {
         Ok(self)
     }
 }
+
+/// Evaluates the kernel density estimate (or its CDF) of `observations` at `eval_points`.
+///
+/// Shared by `transform_density` and the violin transform.
+#[cfg(feature = "kde")]
+pub(crate) fn estimate_density(
+    observations: &[f32],
+    eval_points: &[f32],
+    bandwidth: &BandwidthType,
+    kernel: &KernelType,
+    cumulative: bool,
+) -> Result<Vec<f64>, ChartonError> {
+    let observations = observations.to_vec();
+    let values: Vec<f32> = match (bandwidth, kernel) {
+        (BandwidthType::Scott, KernelType::Normal) => {
+            let kde = KernelDensityEstimator::new(observations, Scott, Normal);
+            if cumulative {
+                kde.cdf(eval_points)
+            } else {
+                kde.pdf(eval_points)
+            }
+        }
+        (BandwidthType::Scott, KernelType::Epanechnikov) => {
+            let kde = KernelDensityEstimator::new(observations, Scott, Epanechnikov);
+            if cumulative {
+                kde.cdf(eval_points)
+            } else {
+                kde.pdf(eval_points)
+            }
+        }
+        (BandwidthType::Scott, KernelType::Uniform) => {
+            let kde = KernelDensityEstimator::new(observations, Scott, Uniform);
+            if cumulative {
+                kde.cdf(eval_points)
+            } else {
+                kde.pdf(eval_points)
+            }
+        }
+        (BandwidthType::Silverman, KernelType::Normal) => {
+            let kde = KernelDensityEstimator::new(observations, Silverman, Normal);
+            if cumulative {
+                kde.cdf(eval_points)
+            } else {
+                kde.pdf(eval_points)
+            }
+        }
+        (BandwidthType::Silverman, KernelType::Epanechnikov) => {
+            let kde = KernelDensityEstimator::new(observations, Silverman, Epanechnikov);
+            if cumulative {
+                kde.cdf(eval_points)
+            } else {
+                kde.pdf(eval_points)
+            }
+        }
+        (BandwidthType::Silverman, KernelType::Uniform) => {
+            let kde = KernelDensityEstimator::new(observations, Silverman, Uniform);
+            if cumulative {
+                kde.cdf(eval_points)
+            } else {
+                kde.pdf(eval_points)
+            }
+        }
+        (BandwidthType::Fixed(bw), KernelType::Normal) => {
+            let h = *bw as f32;
+            let kde = KernelDensityEstimator::new(observations, move |_: &[f32]| h, Normal);
+            if cumulative {
+                kde.cdf(eval_points)
+            } else {
+                kde.pdf(eval_points)
+            }
+        }
+        (BandwidthType::Fixed(bw), KernelType::Epanechnikov) => {
+            let h = *bw as f32;
+            let kde = KernelDensityEstimator::new(observations, move |_: &[f32]| h, Epanechnikov);
+            if cumulative {
+                kde.cdf(eval_points)
+            } else {
+                kde.pdf(eval_points)
+            }
+        }
+        (BandwidthType::Fixed(bw), KernelType::Uniform) => {
+            let h = *bw as f32;
+            let kde = KernelDensityEstimator::new(observations, move |_: &[f32]| h, Uniform);
+            if cumulative {
+                kde.cdf(eval_points)
+            } else {
+                kde.pdf(eval_points)
+            }
+        }
+    };
+
+    Ok(values.into_iter().map(|v| v as f64).collect())
+}
+
+/// Fallback used when the `kde` feature is disabled.
+#[cfg(not(feature = "kde"))]
+pub(crate) fn estimate_density(
+    _observations: &[f32],
+    _eval_points: &[f32],
+    _bandwidth: &BandwidthType,
+    _kernel: &KernelType,
+    _cumulative: bool,
+) -> Result<Vec<f64>, ChartonError> {
+    Err(ChartonError::Unimplemented(
+        "Kernel density estimation is disabled. Please enable the 'kde' feature".to_string(),
+    ))
+}
@@ -0,0 +1,101 @@
+use crate::chart::Chart;
+use crate::core::data::ColumnVector;
+use crate::error::ChartonError;
+use crate::mark::Mark;
+use crate::mark::dotplot::{MarkDotPlot, SortOrder};
+use ahash::AHashMap;
+
+impl<T: Mark> Chart<T> {
+    /// Reorders the rows of a dot plot so that categories follow their values.
+    ///
+    /// Discrete scales list categories in order of first appearance, so sorting is
+    /// achieved by reordering rows: each category is ranked by its largest `y`
+    /// value (the tip of its guide line), and rows are stably regrouped by rank.
+    /// Categorical columns are re-encoded so their dictionary follows the new order.
+    pub(crate) fn transform_dotplot_data(mut self) -> Result<Self, ChartonError> {
+        let sort = self
+            .mark
+            .as_ref()
+            .and_then(|m| (m as &dyn std::any::Any).downcast_ref::<MarkDotPlot>())
+            .map(|m| m.sort)
+            .unwrap_or_default();
+        if sort == SortOrder::None {
+            return Ok(self);
+        }
+
+        let x_field = match &self.encoding.x {
+            Some(x) => x.field.clone(),
+            None => return Ok(self),
+        };
+        let y_field = match &self.encoding.y {
+            Some(y) => y.field.clone(),
+            None => return Ok(self),
+        };
+
+        let x_col = self.data.column(&x_field)?;
+        let y_col = self.data.column(&y_field)?;
+        let row_count = self.data.height();
+
+        // --- STEP 1: Rank each category by its maximum value ---
+        let mut order: Vec<String> = Vec::new();
+        let mut keys: AHashMap<String, f64> = AHashMap::new();
+        for i in 0..row_count {
+            let Some(cat) = x_col.get(i).to_string() else {
+                continue;
+            };
+            let val = y_col.get(i).to_f64().unwrap_or(f64::NAN);
+            match keys.get_mut(&cat) {
+                Some(k) => {
+                    if !val.is_nan() && (k.is_nan() || val > *k) {
+                        *k = val;
+                    }
+                }
+                None => {
+                    order.push(cat.clone());
+                    keys.insert(cat, val);
+                }
+            }
+        }
+
+        // Stable sort keeps ties (and NaN keys, pushed last) in appearance order.
+        order.sort_by(|a, b| {
+            let (ka, kb) = (keys[a], keys[b]);
+            match (ka.is_nan(), kb.is_nan()) {
+                (true, true) => std::cmp::Ordering::Equal,
+                (true, false) => std::cmp::Ordering::Greater,
+                (false, true) => std::cmp::Ordering::Less,
+                _ if sort == SortOrder::Descending => kb.total_cmp(&ka),
+                _ => ka.total_cmp(&kb),
+            }
+        });
+
+        let rank: AHashMap<&str, usize> = order
+            .iter()
+            .enumerate()
+            .map(|(i, c)| (c.as_str(), i))
+            .collect();
+
+        // --- STEP 2: Regroup rows by category rank ---
+        let mut indices: Vec<usize> = (0..row_count).collect();
+        indices.sort_by_key(|&i| {
+            x_col
+                .get(i)
+                .to_string()
+                .and_then(|c| rank.get(c.as_str()).copied())
+                .unwrap_or(usize::MAX)
+        });
+
+        let is_categorical = matches!(x_col, ColumnVector::Categorical { .. });
+        self.data = self.data.take_rows(&indices)?;
+
+        // Categorical dictionaries carry their own order; rebuild it from the new row order.
+        if is_categorical {
+            let x_col = self.data.column(&x_field)?;
+            let rebuilt =
+                ColumnVector::from_str_as_cat_opt((0..row_count).map(|i| x_col.get(i).to_string()));
+            self.data.add_column(x_field, rebuilt)?;
+        }
+
+        Ok(self)
+    }
+}
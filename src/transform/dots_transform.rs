@@ -0,0 +1,170 @@
+use crate::TEMP_SUFFIX;
+use crate::chart::Chart;
+use crate::core::data::{ColumnVector, Dataset};
+use crate::error::ChartonError;
+use crate::mark::Mark;
+use crate::mark::dots::{DotsLayout, MarkDots};
+use crate::transform::hist_transform::HistBins;
+use ahash::AHashMap;
+
+impl<T: Mark> Chart<T> {
+    /// Bins the data for a dot histogram, emitting one row per observation.
+    ///
+    /// Binning matches `transform_histogram_data`, but instead of collapsing each bin
+    /// into a count, every valid observation keeps its own row:
+    /// * **X**: the midpoint of the bin the observation falls into.
+    /// * **Y**: the running count of the stack, i.e. the dot's 1-based position.
+    ///
+    /// With a color encoding, groups are stacked one after another within the bin
+    /// (`Stack`), or get an independent stack each (`Dodge`), in which case the
+    /// `sub_idx`/`groups_count` helper columns drive the side-by-side offsets.
+    pub(crate) fn transform_dots_data(mut self) -> Result<Self, ChartonError> {
+        // --- STEP 1: Extract Encodings ---
+        let x_enc = self
+            .encoding
+            .x
+            .as_ref()
+            .ok_or_else(|| ChartonError::Encoding("X missing".into()))?;
+        let y_enc = self
+            .encoding
+            .y
+            .as_ref()
+            .ok_or_else(|| ChartonError::Encoding("Y missing".into()))?;
+        let color_enc = self.encoding.color.as_ref();
+
+        let dots = self
+            .mark
+            .as_ref()
+            .and_then(|m| (m as &dyn std::any::Any).downcast_ref::<MarkDots>());
+        let layout = dots.map(|m| m.layout).unwrap_or_default();
+        let bin_range = dots.and_then(|m| m.bin_range);
+
+        let bin_field = &x_enc.field;
+        let count_field = &y_enc.field;
+
+        // --- STEP 2: Calculate Binning Parameters ---
+        let x_col = self.data.column(bin_field)?;
+        let bins = HistBins::resolve(x_col, x_enc.bins, dots.and_then(|m| m.binning), bin_range)?;
+
+        // --- STEP 3: Establish Deterministic Order for Color ---
+        let color_list: Vec<String> = match color_enc {
+            Some(c_enc) => self.data.column(&c_enc.field)?.category_values(),
+            None => Vec::new(),
+        };
+        let color_rank: AHashMap<&str, usize> = color_list
+            .iter()
+            .enumerate()
+            .map(|(i, c)| (c.as_str(), i))
+            .collect();
+
+        // --- STEP 4: Assign every observation to (bin, group) ---
+        let mut members: Vec<(usize, usize, Option<String>, usize)> = Vec::new();
+        for i in 0..self.data.height() {
+            let Some(bin_idx) = x_col
+                .get(i)
+                .to_f64()
+                .and_then(|v| bins.index_of(v, bin_range.is_some()))
+            else {
+                continue;
+            };
+
+            let color_label = color_enc.and_then(|c| self.data.get(&c.field, i).to_string());
+            let rank = color_label
+                .as_deref()
+                .and_then(|c| color_rank.get(c).copied())
+                .unwrap_or(color_list.len());
+
+            members.push((bin_idx, rank, color_label, i));
+        }
+
+        // Groups are laid out in their order of appearance within each bin.
+        members.sort_by_key(|&(bin, rank, _, _)| (bin, rank));
+
+        // --- STEP 5: Stack Positions ---
+        let is_dodge = layout == DotsLayout::Dodge && color_enc.is_some();
+        let mut heights: AHashMap<(usize, usize), f64> = AHashMap::new();
+
+        let mut final_x = Vec::with_capacity(members.len());
+        let mut final_y = Vec::with_capacity(members.len());
+        let mut final_color = Vec::with_capacity(members.len());
+        let mut final_sub_idx = Vec::with_capacity(members.len());
+        let mut source_rows = Vec::with_capacity(members.len());
+
+        for (bin_idx, rank, color_label, row) in members {
+            // Dodged groups grow their own stack; stacked groups share the bin's stack.
+            let key = if is_dodge {
+                (bin_idx, rank)
+            } else {
+                (bin_idx, 0)
+            };
+            let level = heights.entry(key).or_insert(0.0);
+            *level += 1.0;
+
+            final_x.push(bins.middle(bin_idx));
+            final_y.push(*level);
+            final_color.push(color_label);
+            final_sub_idx.push(rank as f64);
+            source_rows.push(row);
+        }
+
+        // --- STEP 6: Rebuild Dataset ---
+        let row_count = final_x.len();
+        let mut new_ds = Dataset::new();
+        new_ds.add_column(
+            bin_field,
+            ColumnVector::Float64 {
+                data: final_x,
+                validity: None,
+            },
+        )?;
+        new_ds.add_column(
+            count_field,
+            ColumnVector::Float64 {
+                data: final_y,
+                validity: None,
+            },
+        )?;
+
+        if let Some(c_enc) = color_enc {
+            new_ds.add_column(&c_enc.field, final_color)?;
+        }
+
+        // The renderer sizes the dots from the bin width, as outer bins may be empty.
+        new_ds.add_column(
+            format!("{}_bin_width", TEMP_SUFFIX),
+            ColumnVector::Float64 {
+                data: vec![bins.width; row_count],
+                validity: None,
+            },
+        )?;
+
+        if is_dodge {
+            new_ds.add_column(
+                format!("{}_sub_idx", TEMP_SUFFIX),
+                ColumnVector::Float64 {
+                    data: final_sub_idx,
+                    validity: None,
+                },
+            )?;
+            new_ds.add_column(
+                format!("{}_groups_count", TEMP_SUFFIX),
+                ColumnVector::Float64 {
+                    data: vec![color_list.len() as f64; row_count],
+                    validity: None,
+                },
+            )?;
+        }
+
+        // Every dot is one observation, so tooltip and href fields follow it from its source row.
+        let tooltip_fields = self.encoding.tooltip.iter().flat_map(|t| t.fields.iter());
+        let href_field = self.encoding.href.iter().map(|h| &h.field);
+        for field in tooltip_fields.chain(href_field) {
+            if !new_ds.schema.contains_key(field) {
+                new_ds.add_column(field, self.data.column(field)?.take(&source_rows))?;
+            }
+        }
+
+        self.data = new_ds;
+        Ok(self)
+    }
+}
@@ -13,8 +13,18 @@ impl<T: Mark> Chart<T> {
     #[allow(clippy::type_complexity)]
     pub(crate) fn transform_errorbar_data(mut self) -> Result<Self, ChartonError> {
         // --- STEP 1: Extract Encoding Context ---
-        let x_field = &self.encoding.x.as_ref().unwrap().field;
-        let y_field = &self.encoding.y.as_ref().unwrap().field;
+        let x_field = &self
+            .encoding
+            .x
+            .as_ref()
+            .ok_or_else(|| ChartonError::Encoding("X encoding missing for errorbar".into()))?
+            .field;
+        let y_field = &self
+            .encoding
+            .y
+            .as_ref()
+            .ok_or_else(|| ChartonError::Encoding("Y encoding missing for errorbar".into()))?
+            .field;
         let color_enc_opt = self.encoding.color.as_ref();
 
         // Standardized temporary column names for the error boundaries
@@ -22,12 +32,9 @@ impl<T: Mark> Chart<T> {
         let y_max_col = format!("{}_{}_max", TEMP_SUFFIX, y_field);
 
         // Determine if we are grouping by Color (Aesthetics)
-        let group_by_color = if let Some(ce) = color_enc_opt {
-            &ce.field != x_field
-        } else {
-            false
-        };
         let color_field = color_enc_opt.map(|ce| &ce.field);
+        let grouping_field = color_field.filter(|cf| *cf != x_field);
+        let group_by_color = grouping_field.is_some();
 
         // --- STEP 2: Unified Grouping ---
         let mut group_map: AHashMap<(String, Option<String>), Vec<usize>> = AHashMap::new();
@@ -94,11 +101,10 @@ impl<T: Mark> Chart<T> {
         let lookup: AHashMap<(String, Option<String>), (f64, f64, f64)> =
             aggregated_results.into_iter().collect();
 
-        let x_uniques = self.data.column(x_field)?.unique_values();
-        let c_uniques = if group_by_color {
-            self.data.column(color_field.unwrap())?.unique_values()
-        } else {
-            vec![]
+        let x_uniques = self.data.column(x_field)?.category_values();
+        let c_uniques = match grouping_field {
+            Some(cf) => self.data.column(cf)?.category_values(),
+            None => vec![],
         };
 
         let mut final_x = Vec::new();
@@ -182,9 +188,9 @@ impl<T: Mark> Chart<T> {
         )?;
 
         // 3. Add Color aesthetic column
-        if group_by_color {
+        if let Some(cf) = grouping_field {
             new_ds.add_column(
-                color_field.unwrap(),
+                cf,
                 ColumnVector::String {
                     data: final_color,
                     validity: None,
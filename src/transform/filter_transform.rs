@@ -0,0 +1,190 @@
+use crate::chart::Chart;
+use crate::core::data::RowAccessor;
+use crate::core::utils::IntoParallelizable;
+use crate::error::ChartonError;
+use crate::mark::Mark;
+use std::cmp::Ordering;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// A comparison operator of a filter expression.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CompareOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl CompareOp {
+    /// Two-character operators come first so that `>=` is not read as `>`.
+    const TOKENS: [(&'static str, CompareOp); 6] = [
+        (">=", CompareOp::Ge),
+        ("<=", CompareOp::Le),
+        ("==", CompareOp::Eq),
+        ("!=", CompareOp::Ne),
+        (">", CompareOp::Gt),
+        ("<", CompareOp::Lt),
+    ];
+
+    fn holds(self, ord: Ordering) -> bool {
+        match self {
+            CompareOp::Eq => ord == Ordering::Equal,
+            CompareOp::Ne => ord != Ordering::Equal,
+            CompareOp::Lt => ord == Ordering::Less,
+            CompareOp::Le => ord != Ordering::Greater,
+            CompareOp::Gt => ord == Ordering::Greater,
+            CompareOp::Ge => ord != Ordering::Less,
+        }
+    }
+}
+
+/// The right-hand side of a comparison.
+#[derive(Debug, Clone)]
+enum Literal {
+    Number(f64),
+    Text(String),
+}
+
+/// A single `field <op> literal` comparison.
+#[derive(Debug, Clone)]
+struct Comparison {
+    field: String,
+    op: CompareOp,
+    value: Literal,
+}
+
+impl Comparison {
+    fn parse(clause: &str) -> Result<Self, ChartonError> {
+        let (pos, token, op) = CompareOp::TOKENS
+            .iter()
+            .filter_map(|&(token, op)| clause.find(token).map(|pos| (pos, token, op)))
+            .min_by_key(|&(pos, token, _)| (pos, std::cmp::Reverse(token.len())))
+            .ok_or_else(|| {
+                ChartonError::Data(format!(
+                    "transform_filter_str: no comparison operator in '{}'",
+                    clause.trim()
+                ))
+            })?;
+
+        let field = clause[..pos].trim();
+        let raw = clause[pos + token.len()..].trim();
+        if field.is_empty() || raw.is_empty() {
+            return Err(ChartonError::Data(format!(
+                "transform_filter_str: '{}' must have the form `field {} value`",
+                clause.trim(),
+                token
+            )));
+        }
+
+        let quoted = |q: char| raw.len() >= 2 && raw.starts_with(q) && raw.ends_with(q);
+        let value = if quoted('\'') || quoted('"') {
+            Literal::Text(raw[1..raw.len() - 1].to_string())
+        } else {
+            raw.parse::<f64>().map(Literal::Number).map_err(|_| {
+                ChartonError::Data(format!(
+                    "transform_filter_str: '{}' is neither a number nor a quoted string",
+                    raw
+                ))
+            })?
+        };
+
+        Ok(Self {
+            field: field.to_string(),
+            op,
+            value,
+        })
+    }
+
+    /// Null values never match.
+    fn matches(&self, row: &RowAccessor) -> bool {
+        let ord = match &self.value {
+            Literal::Number(v) => row.val(&self.field).and_then(|x| x.partial_cmp(v)),
+            Literal::Text(s) => row.str(&self.field).map(|x| x.as_str().cmp(s.as_str())),
+        };
+        ord.is_some_and(|ord| self.op.holds(ord))
+    }
+}
+
+impl<T: Mark> Chart<T> {
+    /// Keeps only the rows for which `predicate` returns `true`.
+    ///
+    /// Filters can be chained; each one works on the rows left by the previous one.
+    ///
+    /// # Errors
+    /// Returns `ChartonError::Data` if no row passes the filter, rather than
+    /// silently producing an empty chart.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// chart.transform_filter(|row| row.val("price").is_some_and(|p| p > 100.0))?
+    /// ```
+    pub fn transform_filter<F>(mut self, predicate: F) -> Result<Self, ChartonError>
+    where
+        F: Fn(RowAccessor) -> bool + Sync + Send,
+    {
+        let row_count = self.data.height();
+        if row_count == 0 {
+            return Ok(self);
+        }
+
+        let ds_ref = &self.data;
+        let keep: Vec<usize> = (0..row_count)
+            .maybe_into_par_iter()
+            .filter(|&i| predicate(RowAccessor::new(ds_ref, i)))
+            .collect();
+
+        if keep.is_empty() {
+            return Err(ChartonError::Data(format!(
+                "transform_filter removed all {} rows; the chart would be empty",
+                row_count
+            )));
+        }
+
+        if keep.len() < row_count {
+            self.data = self.data.take_rows(&keep)?;
+        }
+        Ok(self)
+    }
+
+    /// Keeps only the rows matching a textual filter expression.
+    ///
+    /// The expression is one or more comparisons `field <op> value`, where `op`
+    /// is one of `==`, `!=`, `<`, `<=`, `>`, `>=` and `value` is a number or a
+    /// quoted string. Comparisons can be joined with `&&` and `||`, with `&&`
+    /// binding tighter. Rows whose field is null never match.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// chart.transform_filter_str("price > 100 && category != 'misc'")?
+    /// ```
+    pub fn transform_filter_str(self, expr: &str) -> Result<Self, ChartonError> {
+        // Disjunction of conjunctions: `a && b || c` keeps rows matching (a and b) or c.
+        let clauses = expr
+            .split("||")
+            .map(|any| {
+                any.split("&&")
+                    .map(Comparison::parse)
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for cmp in clauses.iter().flatten() {
+            if !self.data.schema.contains_key(&cmp.field) {
+                return Err(ChartonError::Data(format!(
+                    "transform_filter_str: column '{}' not found",
+                    cmp.field
+                )));
+            }
+        }
+
+        self.transform_filter(|row| {
+            clauses
+                .iter()
+                .any(|all| all.iter().all(|cmp| cmp.matches(&row)))
+        })
+    }
+}
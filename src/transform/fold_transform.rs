@@ -0,0 +1,106 @@
+use crate::chart::Chart;
+use crate::core::data::{ColumnVector, Dataset, SemanticType};
+use crate::error::ChartonError;
+use crate::mark::Mark;
+
+impl<T: Mark> Chart<T> {
+    /// Folds several columns into key/value pairs (wide to long).
+    ///
+    /// Every row becomes one row per folded field: `key_field` holds the name of
+    /// the field and `value_field` its value. All other columns are repeated, so
+    /// they can still be encoded, e.g. as color. Rows are ordered field by field,
+    /// which keeps the fields in the given order on a discrete axis.
+    ///
+    /// # Errors
+    /// Returns `ChartonError::Data` if a field is missing or not numeric, is
+    /// listed twice, or if an output name clashes with a kept column.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// chart
+    ///     .transform_fold(vec!["jan", "feb", "mar"], "sales", "month")?
+    ///     .mark_line()?
+    ///     .encode((alt::x("month"), alt::y("sales"), alt::color("category")))?
+    /// ```
+    pub fn transform_fold(
+        mut self,
+        fields: Vec<&str>,
+        value_field: impl Into<String>,
+        key_field: impl Into<String>,
+    ) -> Result<Self, ChartonError> {
+        let value_field = value_field.into();
+        let key_field = key_field.into();
+
+        if fields.is_empty() {
+            return Err(ChartonError::Data(
+                "transform_fold requires at least one field to fold".into(),
+            ));
+        }
+        if value_field == key_field {
+            return Err(ChartonError::Data(format!(
+                "transform_fold: key and value fields are both named '{}'",
+                key_field
+            )));
+        }
+
+        let mut folded: Vec<&ColumnVector> = Vec::with_capacity(fields.len());
+        for (i, field) in fields.iter().enumerate() {
+            if fields[..i].contains(field) {
+                return Err(ChartonError::Data(format!(
+                    "transform_fold: field '{}' is listed more than once",
+                    field
+                )));
+            }
+            let col = self.data.column(field)?;
+            if col.semantic_type() != SemanticType::Continuous {
+                return Err(ChartonError::Data(format!(
+                    "transform_fold: field '{}' is not numeric",
+                    field
+                )));
+            }
+            folded.push(col);
+        }
+
+        let kept: Vec<String> = self
+            .data
+            .get_column_names()
+            .into_iter()
+            .filter(|name| !fields.contains(&name.as_str()))
+            .collect();
+        for name in [&key_field, &value_field] {
+            if kept.contains(name) {
+                return Err(ChartonError::Data(format!(
+                    "transform_fold: output column '{}' already exists",
+                    name
+                )));
+            }
+        }
+
+        // Kept columns repeat the original rows once per folded field.
+        let n = self.data.height();
+        let indices: Vec<usize> = (0..fields.len()).flat_map(|_| 0..n).collect();
+
+        let mut result = Dataset::new();
+        for name in &kept {
+            result.add_column(name.as_str(), self.data.column(name)?.take(&indices))?;
+        }
+
+        let keys: Vec<String> = fields
+            .iter()
+            .flat_map(|field| std::iter::repeat_n(field.to_string(), n))
+            .collect();
+        result.add_column(
+            key_field,
+            ColumnVector::String {
+                data: keys,
+                validity: None,
+            },
+        )?;
+
+        let values: Vec<Option<f64>> = folded.iter().flat_map(|col| col.to_f64_options()).collect();
+        result.add_column(value_field, ColumnVector::from(values))?;
+
+        self.data = result;
+        Ok(self)
+    }
+}
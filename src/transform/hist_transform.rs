@@ -3,11 +3,12 @@ use crate::chart::Chart;
 use crate::core::data::{ColumnVector, Dataset};
 use crate::error::ChartonError;
 use crate::mark::Mark;
+use crate::mark::histogram::{CumulativeDirection, HistBinning, MarkHist};
 use ahash::AHashMap;
 
 impl<T: Mark> Chart<T> {
     /// Handle grouping and aggregation of data for histogram chart.
-    /// Uses explicit unique_values() for color to maintain consistent appearance order.
+    /// Uses explicit category_values() for color to keep the group order in line with the legend.
     pub(crate) fn transform_histogram_data(mut self) -> Result<Self, ChartonError> {
         // --- STEP 1: Extract Encodings ---
         let x_enc = self
@@ -27,23 +28,23 @@ impl<T: Mark> Chart<T> {
 
         // --- STEP 2: Calculate Binning Parameters ---
         let x_col = self.data.column(bin_field)?;
-        let (min_val, max_val) = x_col.min_max();
-        let n_bins = x_enc.bins.unwrap_or(10);
+        let hist = self
+            .mark
+            .as_ref()
+            .and_then(|m| (m as &dyn std::any::Any).downcast_ref::<MarkHist>());
+        let binning = hist.and_then(|h| h.binning);
+        let bin_range = hist.and_then(|h| h.bin_range);
+        let cumulative = hist.and_then(|h| h.cumulative);
 
-        let bin_width = if n_bins > 1 {
-            (max_val - min_val) / (n_bins as f64)
-        } else {
-            1.0
-        };
+        let bins = HistBins::resolve(x_col, x_enc.bins, binning, bin_range)?;
+        let n_bins = bins.count;
 
         // Pre-calculate bin midpoints (Natural numeric order for X-axis)
-        let bin_middles: Vec<f64> = (0..n_bins)
-            .map(|i| min_val + (i as f64 + 0.5) * bin_width)
-            .collect();
+        let bin_middles: Vec<f64> = (0..n_bins).map(|i| bins.middle(i)).collect();
 
         // --- STEP 3: Establish Deterministic Order for Color ---
         let color_list: Vec<String> = if let Some(c_enc) = color_enc {
-            self.data.column(&c_enc.field)?.unique_values()
+            self.data.column(&c_enc.field)?.category_values()
         } else {
             vec![format!("{}_default", TEMP_SUFFIX)]
         };
@@ -53,8 +54,11 @@ impl<T: Mark> Chart<T> {
         let row_count = self.data.height();
 
         for i in 0..row_count {
-            let val = x_col.get(i).to_f64().unwrap_or(min_val);
-            let bin_idx = (((val - min_val) / bin_width).floor() as usize).min(n_bins - 1);
+            let val = x_col.get(i).to_f64().unwrap_or(bins.min);
+            // Only a pinned range can leave values outside the bins.
+            let Some(bin_idx) = bins.index_of(val, bin_range.is_some()) else {
+                continue;
+            };
 
             let color_label = if let Some(c_enc) = color_enc {
                 self.data
@@ -91,6 +95,27 @@ impl<T: Mark> Chart<T> {
             }
         }
 
+        // --- STEP 5b: Accumulate Counts (Optional) ---
+        if let Some(direction) = cumulative {
+            let bin_order: Vec<usize> = match direction {
+                CumulativeDirection::Asc => (0..n_bins).collect(),
+                CumulativeDirection::Desc => (0..n_bins).rev().collect(),
+            };
+            for color in &color_list {
+                let mut running = 0.0;
+                for &bin_idx in &bin_order {
+                    let count = lookup.entry((bin_idx, color.clone())).or_insert(0.0);
+                    running += *count;
+                    // Normalized sums can overshoot 1 by rounding error.
+                    *count = if y_enc.normalize {
+                        running.min(1.0)
+                    } else {
+                        running
+                    };
+                }
+            }
+        }
+
         // --- STEP 6: Cartesian Product & Gap Filling ---
         let mut final_x = Vec::new();
         let mut final_y = Vec::new();
@@ -143,6 +168,124 @@ impl<T: Mark> Chart<T> {
         }
 
         self.data = new_ds;
+        // The axis domain and bar width are derived from the final bin count.
+        if let Some(x_enc) = self.encoding.x.as_mut() {
+            x_enc.bins = Some(n_bins);
+        }
         Ok(self)
     }
 }
+
+/// Upper bound on the bins of one binning; more could never be told apart.
+pub(crate) const MAX_BINS: usize = 10_000;
+
+/// The bins of a histogram-like transform: `count` bins of `width` from `min` to `max`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct HistBins {
+    pub(crate) min: f64,
+    pub(crate) max: f64,
+    pub(crate) count: usize,
+    pub(crate) width: f64,
+}
+
+impl HistBins {
+    /// Resolves the bins of `x_col` from the explicit mark options, falling back
+    /// to `default_count` (the x encoding's `with_bins`, else 10) over the data range.
+    ///
+    /// Options asking for more than [`MAX_BINS`] bins are capped at that many.
+    pub(crate) fn resolve(
+        x_col: &ColumnVector,
+        default_count: Option<usize>,
+        binning: Option<HistBinning>,
+        bin_range: Option<(f64, f64)>,
+    ) -> Result<Self, ChartonError> {
+        let (min, mut max) = match bin_range {
+            Some((lo, hi)) if lo.is_finite() && hi.is_finite() && lo < hi => (lo, hi),
+            Some((lo, hi)) => {
+                return Err(ChartonError::Mark(format!(
+                    "Histogram bin range [{}, {}] must be finite with min < max",
+                    lo, hi
+                )));
+            }
+            None => x_col.min_max(),
+        };
+
+        let (count, width) = match binning {
+            Some(HistBinning::Count(0)) => {
+                return Err(ChartonError::Mark(
+                    "Histogram bin count must be at least 1".into(),
+                ));
+            }
+            Some(HistBinning::Count(n)) => {
+                let n = cap_bin_count(n);
+                let width = if max > min {
+                    (max - min) / (n as f64)
+                } else {
+                    1.0
+                };
+                (n, width)
+            }
+            Some(HistBinning::Width(w)) if !(w.is_finite() && w > 0.0) => {
+                return Err(ChartonError::Mark(format!(
+                    "Histogram bin width must be positive, got {}",
+                    w
+                )));
+            }
+            Some(HistBinning::Width(w)) => {
+                // Round up so the last bin still covers the maximum, then move
+                // the upper edge onto the last bin boundary.
+                let needed = ((max - min) / w).ceil();
+                let (n, w) = if needed > MAX_BINS as f64 {
+                    let widened = (max - min) / MAX_BINS as f64;
+                    eprintln!(
+                        "Binning: a bin width of {} needs more than {} bins; widened to {}.",
+                        w, MAX_BINS, widened
+                    );
+                    (MAX_BINS, widened)
+                } else {
+                    ((needed as usize).max(1), w)
+                };
+                max = min + n as f64 * w;
+                (n, w)
+            }
+            None => {
+                let n = cap_bin_count(default_count.unwrap_or(10).max(1));
+                let width = if max > min {
+                    (max - min) / (n as f64)
+                } else {
+                    1.0
+                };
+                (n, width)
+            }
+        };
+
+        Ok(Self {
+            min,
+            max,
+            count,
+            width,
+        })
+    }
+
+    /// The bin holding `value`, or `None` for NaN and values outside a pinned range.
+    pub(crate) fn index_of(&self, value: f64, pinned: bool) -> Option<usize> {
+        if value.is_nan() || (pinned && !(self.min..=self.max).contains(&value)) {
+            return None;
+        }
+        let idx = ((value - self.min) / self.width).floor().max(0.0) as usize;
+        Some(idx.min(self.count - 1))
+    }
+
+    /// The midpoint of bin `idx`.
+    pub(crate) fn middle(&self, idx: usize) -> f64 {
+        self.min + (idx as f64 + 0.5) * self.width
+    }
+}
+
+/// Caps a requested number of bins at [`MAX_BINS`], reporting the adjustment.
+pub(crate) fn cap_bin_count(n: usize) -> usize {
+    if n > MAX_BINS {
+        eprintln!("Binning: {} bins requested; capped at {}.", n, MAX_BINS);
+    }
+    n.min(MAX_BINS)
+}
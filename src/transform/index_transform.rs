@@ -0,0 +1,185 @@
+use crate::chart::Chart;
+use crate::core::data::ColumnVector;
+use crate::error::ChartonError;
+use crate::mark::Mark;
+use ahash::AHashMap;
+
+/// Reference value each series is divided by in an [`IndexTransform`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum IndexBase {
+    /// The value at the smallest key (or the first row when no key is set).
+    First,
+    /// The value at the row whose key equals the given value (numbers or temporal keys).
+    AtKey(f64),
+    /// The mean of the series.
+    Mean,
+}
+
+/// Configuration for rebasing series to 100 (an "index" or percent-of-base view).
+///
+/// Every value becomes `value / base * 100`, where the base is taken per group
+/// according to [`IndexBase`]. This puts series of very different magnitudes
+/// (e.g. stock prices) on a common scale that starts at 100.
+#[derive(Debug, Clone)]
+pub struct IndexTransform {
+    /// The data field to rebase.
+    pub field: String,
+    /// The output column name. Defaults to "{field}_index".
+    pub as_: String,
+    /// The field ordering each series (e.g. a date), used to find the base row.
+    pub key: Option<String>,
+    /// The field splitting the data into independently rebased series.
+    pub groupby: Option<String>,
+    /// How the base value of each series is chosen.
+    pub base: IndexBase,
+}
+
+impl IndexTransform {
+    /// Creates a new `IndexTransform` rebasing `field` at its first value.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let index = IndexTransform::new("price")
+    ///     .with_key("date")
+    ///     .with_groupby("ticker")
+    ///     .with_base(IndexBase::First);
+    /// ```
+    pub fn new(field: &str) -> Self {
+        Self {
+            field: field.into(),
+            as_: format!("{}_index", field),
+            key: None,
+            groupby: None,
+            base: IndexBase::First,
+        }
+    }
+
+    /// Sets the name of the output column.
+    pub fn with_as(mut self, as_: impl Into<String>) -> Self {
+        self.as_ = as_.into();
+        self
+    }
+
+    /// Sets the field ordering each series, used to locate the base row.
+    pub fn with_key(mut self, key: &str) -> Self {
+        self.key = Some(key.into());
+        self
+    }
+
+    /// Sets the field whose values identify separate series.
+    pub fn with_groupby(mut self, groupby: &str) -> Self {
+        self.groupby = Some(groupby.into());
+        self
+    }
+
+    /// Sets how the base value of each series is chosen.
+    pub const fn with_base(mut self, base: IndexBase) -> Self {
+        self.base = base;
+        self
+    }
+}
+
+impl<T: Mark> Chart<T> {
+    /// Rebases a field to 100 per series and stores the result in a new column.
+    ///
+    /// Series whose base is zero or missing cannot be indexed; their rows are set
+    /// to null and the affected series are reported once on stderr.
+    pub fn transform_index(mut self, params: IndexTransform) -> Result<Self, ChartonError> {
+        let n = self.data.height();
+        if n == 0 {
+            return Ok(self);
+        }
+
+        if matches!(params.base, IndexBase::AtKey(_)) && params.key.is_none() {
+            return Err(ChartonError::Data(
+                "IndexTransform: IndexBase::AtKey requires a key field (with_key)".into(),
+            ));
+        }
+
+        let values = self.data.column(&params.field)?.to_f64_options();
+        let keys = match &params.key {
+            Some(k) => Some(self.data.column(k)?.to_f64_options()),
+            None => None,
+        };
+
+        // --- Grouping (first appearance order) ---
+        let mut order: Vec<Option<String>> = Vec::new();
+        let mut groups: AHashMap<Option<String>, Vec<usize>> = AHashMap::new();
+        if let Some(ref group_field) = params.groupby {
+            let group_col = self.data.column(group_field)?;
+            for i in 0..n {
+                let g = group_col.get(i).to_string();
+                if !groups.contains_key(&g) {
+                    order.push(g.clone());
+                }
+                groups.entry(g).or_default().push(i);
+            }
+        } else {
+            order.push(None);
+            groups.insert(None, (0..n).collect());
+        }
+
+        // --- Base resolution and rebasing ---
+        // Unindexed rows stay NaN, which Float64 columns treat as null.
+        let mut data = vec![f64::NAN; n];
+        let mut failed: Vec<String> = Vec::new();
+
+        for g in order {
+            let Some(indices) = groups.get(&g) else {
+                continue;
+            };
+
+            let base = match (&params.base, &keys) {
+                (IndexBase::First, Some(keys)) => indices
+                    .iter()
+                    .filter_map(|&i| keys[i].map(|k| (k, i)))
+                    .min_by(|a, b| a.0.total_cmp(&b.0))
+                    .and_then(|(_, i)| values[i]),
+                (IndexBase::First, None) => indices.first().and_then(|&i| values[i]),
+                (IndexBase::AtKey(at), Some(keys)) => indices
+                    .iter()
+                    .find(|&&i| keys[i] == Some(*at))
+                    .and_then(|&i| values[i]),
+                (IndexBase::AtKey(_), None) => None,
+                (IndexBase::Mean, _) => {
+                    let valid: Vec<f64> = indices.iter().filter_map(|&i| values[i]).collect();
+                    if valid.is_empty() {
+                        None
+                    } else {
+                        Some(valid.iter().sum::<f64>() / valid.len() as f64)
+                    }
+                }
+            };
+
+            match base.filter(|b| *b != 0.0 && b.is_finite()) {
+                Some(b) => {
+                    for &i in indices {
+                        if let Some(v) = values[i] {
+                            data[i] = v / b * 100.0;
+                        }
+                    }
+                }
+                None => failed.push(g.unwrap_or_else(|| "all".into())),
+            }
+        }
+
+        if !failed.is_empty() {
+            eprintln!(
+                "IndexTransform: '{}' has a zero or missing base in {} series ({}); their index is null.",
+                params.field,
+                failed.len(),
+                failed.join(", ")
+            );
+        }
+
+        self.data.add_column(
+            &params.as_,
+            ColumnVector::Float64 {
+                data,
+                validity: None,
+            },
+        )?;
+
+        Ok(self)
+    }
+}
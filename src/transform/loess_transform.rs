@@ -0,0 +1,280 @@
+use crate::chart::Chart;
+use crate::core::data::{ColumnVector, Dataset, SemanticType};
+use crate::error::ChartonError;
+use crate::mark::Mark;
+use crate::stats::stat_loess::loess_at;
+use ahash::AHashMap;
+
+/// Configuration parameters for the LOESS transformation
+///
+/// LOESS fits a weighted straight line around every point of an evaluation
+/// grid, using only the nearest fraction (`bandwidth`) of the data. It follows
+/// non-linear trends that a single regression line cannot.
+#[derive(Debug, Clone)]
+pub struct LoessTransform {
+    // The name of the independent (x) column
+    pub(crate) on: String,
+    // The name of the dependent (y) column to smooth
+    pub(crate) loess: String,
+    // The fraction of the data used for each local fit, in (0, 1]
+    pub(crate) bandwidth: f64,
+    // The number of robustness passes that down-weight outliers
+    pub(crate) iterations: usize,
+    // The names of the two output columns: [x_column_name, smoothed_y_column_name]
+    pub(crate) as_: [String; 2],
+    // The number of evaluation points between the smallest and largest x
+    pub(crate) steps: usize,
+    // The data field to group by, with one curve per group
+    pub(crate) groupby: Option<String>,
+}
+
+impl LoessTransform {
+    /// Creates a new `LoessTransform` instance with default parameters
+    ///
+    /// # Parameters
+    /// * `on` - The name of the column holding the independent (x) values
+    /// * `loess` - The name of the column holding the values to smooth
+    ///
+    /// # Returns
+    /// A new `LoessTransform` instance with the following defaults:
+    /// - Bandwidth: 0.3
+    /// - Robustness iterations: 2
+    /// - Output field names: the input field names
+    /// - Steps: 200
+    /// - No grouping
+    pub fn new(on: impl Into<String>, loess: impl Into<String>) -> Self {
+        let on = on.into();
+        let loess = loess.into();
+        Self {
+            as_: [on.clone(), loess.clone()],
+            on,
+            loess,
+            bandwidth: 0.3,
+            iterations: 2,
+            steps: 200,
+            groupby: None,
+        }
+    }
+
+    /// Sets the fraction of the data used for each local fit
+    ///
+    /// Values near 1.0 give a smooth, almost global fit; small values such as
+    /// 0.1 follow local detail (and noise) closely.
+    pub const fn with_bandwidth(mut self, bandwidth: f64) -> Self {
+        self.bandwidth = bandwidth;
+        self
+    }
+
+    /// Sets the number of robustness iterations
+    ///
+    /// Each iteration refits with outliers down-weighted; 0 gives a plain
+    /// (non-robust) LOESS.
+    pub const fn with_iterations(mut self, iterations: usize) -> Self {
+        self.iterations = iterations;
+        self
+    }
+
+    /// Sets the output column names for the x and smoothed y values
+    pub fn with_as(mut self, x_field: impl Into<String>, y_field: impl Into<String>) -> Self {
+        self.as_ = [x_field.into(), y_field.into()];
+        self
+    }
+
+    /// Sets the number of evaluation points of the smoothed curve
+    pub const fn with_steps(mut self, steps: usize) -> Self {
+        self.steps = steps;
+        self
+    }
+
+    /// Sets the field to group by for separate smoothed curves
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let transform = LoessTransform::new("date", "price")
+    ///     .with_groupby("symbol"); // One curve per stock
+    /// ```
+    pub fn with_groupby(mut self, groupby: &str) -> Self {
+        self.groupby = Some(groupby.into());
+        self
+    }
+}
+
+impl<T: Mark> Chart<T> {
+    /// Smooths one field against another with LOESS and replaces the data with the curve.
+    ///
+    /// Rows where either field is null are ignored. The output has the same two
+    /// columns as `transform_regression`, `as_[0]` (x) and `as_[1]` (smoothed y),
+    /// so it composes with `mark_line` the same way. A temporal x field keeps
+    /// its type, so a smoothed time series shares the axis of the raw one.
+    ///
+    /// With a group field, every category gets its own curve over its own x
+    /// range, in the order of the categories, and the output keeps the group
+    /// column for `color` or `detail`.
+    ///
+    /// # Errors
+    /// Returns `ChartonError::Data` if a field is missing or not numeric, if the
+    /// bandwidth is outside (0, 1], if fewer than 2 steps are requested, or if
+    /// the data (or a group) has fewer than 3 points or the x values do not
+    /// span a range.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// chart
+    ///     .transform_loess(LoessTransform::new("x", "y").with_bandwidth(0.5))?
+    ///     .mark_line()?
+    ///     .encode((alt::x("x"), alt::y("y")))?
+    /// ```
+    pub fn transform_loess(mut self, params: LoessTransform) -> Result<Self, ChartonError> {
+        if !(params.bandwidth > 0.0 && params.bandwidth <= 1.0) {
+            return Err(ChartonError::Data(format!(
+                "transform_loess: bandwidth must be in (0, 1], got {}",
+                params.bandwidth
+            )));
+        }
+        if params.steps < 2 {
+            return Err(ChartonError::Data(format!(
+                "transform_loess: at least 2 steps are required, got {}",
+                params.steps
+            )));
+        }
+        let mut outputs = vec![params.as_[0].as_str(), params.as_[1].as_str()];
+        outputs.extend(params.groupby.as_deref());
+        for (i, name) in outputs.iter().enumerate() {
+            if outputs[..i].contains(name) {
+                return Err(ChartonError::Data(format!(
+                    "transform_loess: output fields are both named '{}'",
+                    name
+                )));
+            }
+        }
+
+        let mut columns = Vec::with_capacity(2);
+        for field in [&params.on, &params.loess] {
+            let col = self.data.column(field)?;
+            let temporal_x = field == &params.on && col.semantic_type() == SemanticType::Temporal;
+            if col.semantic_type() != SemanticType::Continuous && !temporal_x {
+                return Err(ChartonError::Data(format!(
+                    "transform_loess: field '{}' is not numeric",
+                    field
+                )));
+            }
+            columns.push(col.to_f64_options());
+        }
+
+        // Groups are smoothed in the order of their categories, like the legend.
+        let groups: Vec<(Option<String>, Vec<usize>)> = match &params.groupby {
+            Some(g_field) => {
+                let group_col = self.data.column(g_field)?;
+                let mut rows: AHashMap<String, Vec<usize>> = AHashMap::new();
+                for i in 0..self.data.height() {
+                    if let Some(key) = group_col.get(i).to_string() {
+                        rows.entry(key).or_default().push(i);
+                    }
+                }
+                group_col
+                    .category_values()
+                    .into_iter()
+                    .filter_map(|key| rows.remove(&key).map(|r| (Some(key), r)))
+                    .collect()
+            }
+            None => vec![(None, (0..self.data.height()).collect())],
+        };
+
+        let mut fit_x = Vec::new();
+        let mut fit_y = Vec::new();
+        let mut fit_group = Vec::new();
+
+        for (key, rows) in groups {
+            let scope = key
+                .as_ref()
+                .map(|k| format!(" in group '{}'", k))
+                .unwrap_or_default();
+            let (xs, ys): (Vec<f64>, Vec<f64>) = rows
+                .iter()
+                .filter_map(|&i| Some((columns[0][i]?, columns[1][i]?)))
+                .filter(|(x, y)| x.is_finite() && y.is_finite())
+                .unzip();
+
+            if xs.len() < 3 {
+                return Err(ChartonError::Data(format!(
+                    "transform_loess: at least 3 points are required{}, found {}",
+                    scope,
+                    xs.len()
+                )));
+            }
+            let min = xs.iter().copied().fold(f64::INFINITY, f64::min);
+            let max = xs.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+            if min >= max {
+                return Err(ChartonError::Data(format!(
+                    "transform_loess: field '{}' needs at least 2 distinct values{}",
+                    params.on, scope
+                )));
+            }
+
+            let step = (max - min) / (params.steps - 1) as f64;
+            let grid: Vec<f64> = (0..params.steps).map(|i| min + i as f64 * step).collect();
+            let fitted = loess_at(&xs, &ys, params.bandwidth, params.iterations, &grid);
+
+            // Grid points without any weighted neighbour are left out of the curve.
+            let before = fit_x.len();
+            for (x, y) in grid.into_iter().zip(fitted) {
+                if let Some(y) = y {
+                    fit_x.push(x);
+                    fit_y.push(y);
+                }
+            }
+            if let Some(key) = key {
+                fit_group.extend(std::iter::repeat_n(key, fit_x.len() - before));
+            }
+        }
+
+        // Temporal x values are restored to their original type. Dates become
+        // datetimes, since the evaluation grid falls between days.
+        let restored_x = match self.data.column(&params.on)? {
+            ColumnVector::Datetime { timezone, .. } => ColumnVector::Datetime {
+                data: fit_x.into_iter().map(|v| v.round() as i64).collect(),
+                validity: None,
+                timezone: timezone.clone(),
+            },
+            ColumnVector::Date { .. } => ColumnVector::Datetime {
+                data: fit_x.into_iter().map(|v| v.round() as i64).collect(),
+                validity: None,
+                timezone: None,
+            },
+            ColumnVector::Duration { .. } => ColumnVector::Duration {
+                data: fit_x.into_iter().map(|v| v.round() as i64).collect(),
+                validity: None,
+            },
+            ColumnVector::Time { .. } => ColumnVector::Time {
+                data: fit_x.into_iter().map(|v| v.round() as i64).collect(),
+                validity: None,
+            },
+            _ => ColumnVector::Float64 {
+                data: fit_x,
+                validity: None,
+            },
+        };
+
+        let mut new_ds = Dataset::new();
+        new_ds.add_column(&params.as_[0], restored_x)?;
+        new_ds.add_column(
+            &params.as_[1],
+            ColumnVector::Float64 {
+                data: fit_y,
+                validity: None,
+            },
+        )?;
+        if let Some(ref g_field) = params.groupby {
+            new_ds.add_column(
+                g_field,
+                ColumnVector::String {
+                    data: fit_group,
+                    validity: None,
+                },
+            )?;
+        }
+
+        self.data = new_ds;
+        Ok(self)
+    }
+}
@@ -0,0 +1,126 @@
+use crate::chart::Chart;
+use crate::core::data::Dataset;
+use crate::error::ChartonError;
+use crate::mark::Mark;
+use ahash::AHashMap;
+
+/// Configuration parameters for the lookup transformation
+///
+/// Joins columns of a secondary table onto the chart data, e.g. to show
+/// readable names for the codes stored in the chart data. Every chart row is
+/// matched on `lookup_key` against `from_key` in the `from` table.
+#[derive(Debug, Clone)]
+pub struct LookupTransform {
+    // The secondary table holding the looked-up values
+    pub(crate) from: Dataset,
+    // The key column in the chart data
+    pub(crate) lookup_key: String,
+    // The key column in the secondary table
+    pub(crate) from_key: String,
+    // The columns copied from the secondary table
+    pub(crate) values: Vec<String>,
+}
+
+impl LookupTransform {
+    /// Creates a new `LookupTransform` instance
+    ///
+    /// # Parameters
+    /// * `from` - The secondary table
+    /// * `lookup_key` - The key column in the chart data
+    /// * `from_key` - The key column in `from`
+    ///
+    /// # Returns
+    /// A new `LookupTransform` that copies every column of `from` except
+    /// `from_key`, unless narrowed with [`LookupTransform::with_values`].
+    pub fn new(from: Dataset, lookup_key: impl Into<String>, from_key: impl Into<String>) -> Self {
+        Self {
+            from,
+            lookup_key: lookup_key.into(),
+            from_key: from_key.into(),
+            values: Vec::new(),
+        }
+    }
+
+    /// Sets the columns of `from` to copy into the chart data
+    pub fn with_values(mut self, values: Vec<impl Into<String>>) -> Self {
+        self.values = values.into_iter().map(Into::into).collect();
+        self
+    }
+}
+
+impl<T: Mark> Chart<T> {
+    /// Joins columns of a secondary table onto the chart data (a left join).
+    ///
+    /// Keys are compared by their text, so an integer code matches the same
+    /// code stored as a string. If `from` holds a key more than once, the first
+    /// row wins, so the chart keeps its row count. Chart rows without a match,
+    /// including null keys, get nulls in the joined columns and are dropped by
+    /// `encode()` only if those columns are encoded.
+    ///
+    /// # Errors
+    /// Returns `ChartonError::Data` if a key or value column is missing, if
+    /// `from` is empty, or if a value column already exists in the chart data.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// chart
+    ///     .transform_lookup(
+    ///         LookupTransform::new(names, "code", "code").with_values(vec!["name"]),
+    ///     )?
+    ///     .mark_bar()?
+    ///     .encode((alt::x("name"), alt::y("count")))?
+    /// ```
+    pub fn transform_lookup(mut self, params: LookupTransform) -> Result<Self, ChartonError> {
+        let from = &params.from;
+        let values = if params.values.is_empty() {
+            from.get_column_names()
+                .into_iter()
+                .filter(|name| *name != params.from_key)
+                .collect()
+        } else {
+            params.values.clone()
+        };
+
+        let lookup_col = self.data.column(&params.lookup_key)?;
+        let from_col = from.column(&params.from_key)?;
+        if from.height() == 0 {
+            return Err(ChartonError::Data(
+                "transform_lookup: the lookup table has no rows".into(),
+            ));
+        }
+        for name in &values {
+            from.column(name)?;
+            if self.data.schema.contains_key(name) {
+                return Err(ChartonError::Data(format!(
+                    "transform_lookup: column '{}' already exists",
+                    name
+                )));
+            }
+        }
+
+        // --- STEP 1: Index the secondary table, keeping the first row per key ---
+        let mut index: AHashMap<String, usize> = AHashMap::with_capacity(from.height());
+        for row in 0..from.height() {
+            if let Some(key) = from_col.get(row).to_string() {
+                index.entry(key).or_insert(row);
+            }
+        }
+
+        // --- STEP 2: Match every chart row ---
+        let matches: Vec<Option<usize>> = (0..self.data.height())
+            .map(|row| {
+                lookup_col
+                    .get(row)
+                    .to_string()
+                    .and_then(|key| index.get(&key).copied())
+            })
+            .collect();
+
+        // --- STEP 3: Copy the value columns ---
+        for name in &values {
+            let joined = from.column(name)?.take_or_null(&matches);
+            self.data.add_column(name.as_str(), joined)?;
+        }
+        Ok(self)
+    }
+}
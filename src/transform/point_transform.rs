@@ -10,11 +10,13 @@ impl<T: Mark> Chart<T> {
     /// Transforms the point data to support categorical layouts such as Dodging and Beeswarm.
     ///
     /// ### Logic Overview:
-    /// 1. **Early Exit for Continuous Scales**: If the X-axis is a numerical or temporal scale
-    ///    (Linear, Log, Temporal), the raw coordinates are preserved to maintain mathematical precision.
-    /// 2. **Aesthetic Grouping**: If a `color` encoding is present (and differs from the X field),
-    ///    the points are assigned to discrete "slots" (lanes) within each X-category.
-    /// 3. **Layout Helper Injections**:
+    /// 1. **Vertical Lanes**: A `y_offset` encoding assigns every point the lane of its group
+    ///    around the Y position (`y_sub_idx` and `y_groups_count`).
+    /// 2. **Early Exit for Continuous Scales**: Without an `x_offset` encoding, numerical or
+    ///    temporal X-axes (Linear, Log, Temporal) keep the raw coordinates.
+    /// 3. **Aesthetic Grouping**: The points are assigned to discrete "slots" (lanes) within each
+    ///    X-category, by the `x_offset` field or else by a `color` field that differs from X.
+    /// 4. **Layout Helper Injections**:
     ///     * `sub_idx`: The zero-indexed slot position for the point's group.
     ///     * `groups_count`: The total number of groups at that X-position (used for width normalization).
     ///     * `swarm_local_idx`: A sequential counter for points sharing the same logic-space,
@@ -25,7 +27,7 @@ impl<T: Mark> Chart<T> {
             ChartonError::Encoding("X encoding is required for transformation".into())
         })?;
 
-        let x_field = &x_enc.field;
+        let x_field = x_enc.field.clone();
 
         // Transformation logic requires resolved scales to distinguish between Discrete and Continuous axes.
         let x_scale_type = x_enc.scale_type.as_ref().ok_or_else(|| {
@@ -34,29 +36,44 @@ impl<T: Mark> Chart<T> {
             )
         })?;
 
-        // Early Exit: Continuous axes do not support dodging as they rely on exact coordinate mapping.
-        if matches!(x_scale_type, Scale::Linear | Scale::Log | Scale::Temporal) {
-            return Ok(self);
+        let is_continuous = matches!(x_scale_type, Scale::Linear | Scale::Log | Scale::Temporal);
+
+        // Vertical lanes are independent of the horizontal ones.
+        if let Some(y_offset) = &self.encoding.y_offset {
+            let (y_sub_idx, y_groups_count) = self.lane_indices(&y_offset.field.clone())?;
+            self.data.add_column(
+                format!("{}_y_sub_idx", TEMP_SUFFIX),
+                ColumnVector::Float64 {
+                    data: y_sub_idx,
+                    validity: None,
+                },
+            )?;
+            self.data.add_column(
+                format!("{}_y_groups_count", TEMP_SUFFIX),
+                ColumnVector::Float64 {
+                    data: y_groups_count,
+                    validity: None,
+                },
+            )?;
         }
 
         // --- STEP 2: Grouping Context Identification ---
-        // Grouping/Dodging is typically triggered by a secondary aesthetic (usually Color).
-        let color_field = match &self.encoding.color {
-            Some(c) => &c.field,
-            None => return Ok(self), // No grouping aesthetic; use default single-column layout.
+        // An explicit x_offset always dodges. Otherwise grouping is triggered by Color, and
+        // only on discrete axes: continuous ones rely on exact coordinate mapping.
+        let color_field = match (&self.encoding.x_offset, &self.encoding.color) {
+            (Some(offset), _) => offset.field.clone(),
+            (None, _) if is_continuous => return Ok(self),
+            (None, Some(c)) if c.field != x_field => c.field.clone(),
+            // No grouping aesthetic, or a 1-to-1 mapping with X: default single-column layout.
+            (None, _) => return Ok(self),
         };
 
-        // If the color field is identical to the X field, it's a 1-to-1 mapping (no dodging needed).
-        if color_field == x_field {
-            return Ok(self);
-        }
-
         // --- STEP 3: Categorical Indexing ---
-        let x_col = self.data.column(x_field)?;
-        let color_col = self.data.column(color_field)?;
+        let x_col = self.data.column(&x_field)?;
+        let color_col = self.data.column(&color_field)?;
 
         // Determine unique groups to establish deterministic slot ordering.
-        let color_uniques = color_col.unique_values();
+        let color_uniques = color_col.category_values();
         let color_map: AHashMap<String, usize> = color_uniques
             .iter()
             .enumerate()
@@ -125,4 +142,22 @@ impl<T: Mark> Chart<T> {
 
         Ok(self)
     }
+    /// Returns the lane of every row in the category order of `field`, with the lane count.
+    fn lane_indices(&self, field: &str) -> Result<(Vec<f64>, Vec<f64>), ChartonError> {
+        let col = self.data.column(field)?;
+        let uniques = col.category_values();
+        let lanes: AHashMap<String, usize> = uniques
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (v.clone(), i))
+            .collect();
+
+        let sub_idx = (0..self.data.height())
+            .map(|i| {
+                let value = col.get(i).to_string().unwrap_or_else(|| "null".to_string());
+                *lanes.get(&value).unwrap_or(&0) as f64
+            })
+            .collect();
+        Ok((sub_idx, vec![uniques.len() as f64; self.data.height()]))
+    }
 }
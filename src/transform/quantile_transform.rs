@@ -0,0 +1,189 @@
+use crate::chart::Chart;
+use crate::core::data::{ColumnVector, SemanticType, get_quantile};
+use crate::error::ChartonError;
+use crate::mark::Mark;
+use crate::scale::inverse::format_percent;
+
+/// Configuration parameters for the quantile transformation
+///
+/// Splits the values of `field` at the given quantiles and stores the group
+/// of every row in a new categorical column, e.g. to color a scatter plot by
+/// quartile. Groups are labeled with their percentile range ("0-25%",
+/// "25-50%", ...) and ordered from lowest to highest.
+///
+/// The breaks are an explicit list of probabilities, or else every multiple
+/// of a fixed `step` below 1.
+#[derive(Debug, Clone)]
+pub struct QuantileTransform {
+    // The numeric field to split
+    pub(crate) field: String,
+    // The probabilities at which the field is split, each in (0, 1)
+    pub(crate) probs: Vec<f64>,
+    // The spacing of the probabilities; takes precedence over `probs`
+    pub(crate) step: Option<f64>,
+    // The name of the new category column
+    pub(crate) as_: String,
+}
+
+impl QuantileTransform {
+    /// Creates a new `QuantileTransform` instance with default parameters
+    ///
+    /// # Parameters
+    /// * `field` - The name of the numeric column to split
+    ///
+    /// # Returns
+    /// A new `QuantileTransform` instance with the following defaults:
+    /// - Quartile breaks: `[0.25, 0.5, 0.75]`
+    /// - Output field name: `{field}_quantile`
+    pub fn new(field: impl Into<String>) -> Self {
+        let field = field.into();
+        Self {
+            as_: format!("{}_quantile", field),
+            field,
+            probs: vec![0.25, 0.5, 0.75],
+            step: None,
+        }
+    }
+
+    /// Sets the probabilities at which the data is split
+    ///
+    /// They must be strictly increasing and lie strictly between 0 and 1.
+    pub fn with_probs(mut self, probs: Vec<f64>) -> Self {
+        self.probs = probs;
+        self
+    }
+
+    /// Splits at every multiple of `step`, e.g. 0.1 for deciles
+    pub const fn with_step(mut self, step: f64) -> Self {
+        self.step = Some(step);
+        self
+    }
+
+    /// Sets the output column name
+    pub fn with_as(mut self, as_: impl Into<String>) -> Self {
+        self.as_ = as_.into();
+        self
+    }
+
+    /// Resolves the probabilities from either the step or the explicit list.
+    fn resolve_probs(&self) -> Result<Vec<f64>, ChartonError> {
+        let probs = match self.step {
+            Some(step) if step.is_finite() && step > 0.0 && step < 1.0 => {
+                // The tolerance drops a last break that only misses 1 through rounding.
+                let n = ((1.0 - 1e-9) / step).floor() as usize;
+                (1..=n).map(|i| i as f64 * step).collect()
+            }
+            Some(step) => {
+                return Err(ChartonError::Data(format!(
+                    "transform_quantile: step must lie in (0, 1), got {}",
+                    step
+                )));
+            }
+            None => self.probs.clone(),
+        };
+
+        if probs.is_empty() {
+            return Err(ChartonError::Data(
+                "transform_quantile: at least one probability is required".into(),
+            ));
+        }
+        if let Some(p) = probs.iter().find(|p| !(**p > 0.0 && **p < 1.0)) {
+            return Err(ChartonError::Data(format!(
+                "transform_quantile: probability {} is outside (0, 1)",
+                p
+            )));
+        }
+        if probs.windows(2).any(|w| w[0] >= w[1]) {
+            return Err(ChartonError::Data(format!(
+                "transform_quantile: probabilities {:?} are not strictly increasing",
+                probs
+            )));
+        }
+        Ok(probs)
+    }
+}
+
+impl<T: Mark> Chart<T> {
+    /// Assigns every row to a quantile group of a numeric field.
+    ///
+    /// Quantiles are interpolated linearly between the sorted values, like the
+    /// boxplot statistics. Groups are closed on the right: a value equal to a
+    /// break belongs to the lower group. Null and NaN values get a null group.
+    ///
+    /// # Errors
+    /// Returns `ChartonError::Data` if the field is missing or not numeric, if
+    /// the probabilities or step are invalid, or if the output column already
+    /// exists.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// chart
+    ///     .transform_quantile(QuantileTransform::new("hp").with_as("hp_quartile"))?
+    ///     .mark_point()?
+    ///     .encode((alt::x("wt"), alt::y("mpg"), alt::color("hp_quartile")))?
+    /// ```
+    pub fn transform_quantile(mut self, params: QuantileTransform) -> Result<Self, ChartonError> {
+        let probs = params.resolve_probs()?;
+        if self.data.schema.contains_key(&params.as_) {
+            return Err(ChartonError::Data(format!(
+                "transform_quantile: column '{}' already exists",
+                params.as_
+            )));
+        }
+
+        let col = self.data.column(&params.field)?;
+        if col.semantic_type() != SemanticType::Continuous {
+            return Err(ChartonError::Data(format!(
+                "transform_quantile: field '{}' is not numeric",
+                params.field
+            )));
+        }
+        let values = col.to_f64_options();
+
+        // --- STEP 1: Breaks ---
+        let mut sorted: Vec<f64> = values
+            .iter()
+            .flatten()
+            .copied()
+            .filter(|v| !v.is_nan())
+            .collect();
+        sorted.sort_unstable_by(|a, b| a.total_cmp(b));
+        let breaks: Vec<f64> = probs.iter().map(|&p| get_quantile(&sorted, p)).collect();
+
+        // --- STEP 2: Labels, one per group and in ascending order ---
+        let edges: Vec<f64> = std::iter::once(0.0)
+            .chain(probs.iter().copied())
+            .chain(std::iter::once(1.0))
+            .collect();
+        let labels: Vec<String> = edges
+            .windows(2)
+            .map(|w| {
+                // The percent sign is written once for the whole range.
+                let low = format_percent(w[0]);
+                format!("{}-{}", low.trim_end_matches('%'), format_percent(w[1]))
+            })
+            .collect();
+
+        // --- STEP 3: Assign groups ---
+        let mut keys = Vec::with_capacity(values.len());
+        let mut validity = vec![0u8; values.len().div_ceil(8)];
+        let mut has_null = false;
+        for (i, v) in values.iter().enumerate() {
+            match v {
+                Some(v) if !v.is_nan() => {
+                    let group = breaks.iter().take_while(|b| v > *b).count();
+                    keys.push(group as u32);
+                    validity[i / 8] |= 1 << (i % 8);
+                }
+                _ => {
+                    keys.push(0);
+                    has_null = true;
+                }
+            }
+        }
+
+        let column = ColumnVector::from_categorical(keys, labels, has_null.then_some(validity));
+        self.data.add_column(params.as_.as_str(), column)?;
+        Ok(self)
+    }
+}
@@ -38,8 +38,8 @@ impl<T: Mark> Chart<T> {
         let color_col = self.data.column(&color_enc.field)?;
 
         // Determine if axes are discrete to decide between Categorical grouping or Binning
-        let x_is_discrete = matches!(x_enc.scale_type.as_ref().unwrap(), Scale::Discrete);
-        let y_is_discrete = matches!(y_enc.scale_type.as_ref().unwrap(), Scale::Discrete);
+        let x_is_discrete = matches!(x_enc.scale_type, Some(Scale::Discrete));
+        let y_is_discrete = matches!(y_enc.scale_type, Some(Scale::Discrete));
 
         // --- STEP 3: Calculate Binning Parameters (Only for Continuous axes) ---
         let x_bin_params = if !x_is_discrete {
@@ -0,0 +1,528 @@
+use crate::chart::Chart;
+use crate::core::data::{ColumnVector, Dataset, SemanticType};
+use crate::error::ChartonError;
+use crate::mark::Mark;
+use ahash::AHashMap;
+
+/// Number of points at which a polynomial fit is evaluated.
+const REGRESSION_STEPS: usize = 100;
+
+/// Name of the output column holding the coefficient of determination (R²) of each fit.
+pub const R_SQUARED_FIELD: &str = "r_squared";
+
+/// The 97.5th percentile of the standard normal distribution, for two-sided 95% intervals.
+const Z_975: f64 = 1.959963984540054;
+
+/// The model fitted by `transform_regression`.
+///
+/// Variants:
+/// - `Linear`: Ordinary least squares line `y = a + b * x`
+/// - `Polynomial { degree }`: Least squares polynomial of the given degree (at least 1)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RegressionMethod {
+    Linear,
+    Polynomial { degree: usize },
+}
+
+impl RegressionMethod {
+    const fn degree(&self) -> usize {
+        match self {
+            RegressionMethod::Linear => 1,
+            RegressionMethod::Polynomial { degree } => *degree,
+        }
+    }
+}
+
+/// Configuration parameters for the regression transformation
+///
+/// The transform fits a least squares model of `regression` against `on` and
+/// replaces the chart data with the fitted curve, ready to be drawn with
+/// `mark_line` on top of a scatter layer.
+#[derive(Debug, Clone)]
+pub struct RegressionTransform {
+    // The name of the independent (x) column
+    pub(crate) on: String,
+    // The name of the dependent (y) column
+    pub(crate) regression: String,
+    // The fitted model
+    pub(crate) method: RegressionMethod,
+    // The names of the two output columns: [x_column_name, predicted_y_column_name]
+    pub(crate) as_: [String; 2],
+    // The names of the confidence interval columns: [lower_column_name, upper_column_name]
+    pub(crate) ci_as: [String; 2],
+    // The x range over which the fit is evaluated; defaults to the data range
+    pub(crate) extent: Option<(f64, f64)>,
+    // The data field to group by; each group gets its own fit
+    pub(crate) groupby: Option<String>,
+}
+
+impl RegressionTransform {
+    /// Creates a new `RegressionTransform` instance with default parameters
+    ///
+    /// # Parameters
+    /// * `on` - The name of the column holding the independent (x) values
+    /// * `regression` - The name of the column holding the dependent (y) values
+    ///
+    /// # Returns
+    /// A new `RegressionTransform` instance with the following defaults:
+    /// - Method: linear
+    /// - Output field names: the input field names, so the fit can reuse the same encodings
+    /// - Confidence interval field names: `<regression>_lower` and `<regression>_upper`
+    /// - Extent: the range of the x values
+    /// - No grouping
+    pub fn new(on: impl Into<String>, regression: impl Into<String>) -> Self {
+        let on = on.into();
+        let regression = regression.into();
+        Self {
+            as_: [on.clone(), regression.clone()],
+            ci_as: [
+                format!("{}_lower", regression),
+                format!("{}_upper", regression),
+            ],
+            on,
+            regression,
+            method: RegressionMethod::Linear,
+            extent: None,
+            groupby: None,
+        }
+    }
+
+    /// Sets the regression model
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let transform = RegressionTransform::new("x", "y")
+    ///     .with_method(RegressionMethod::Polynomial { degree: 2 });
+    /// ```
+    pub const fn with_method(mut self, method: RegressionMethod) -> Self {
+        self.method = method;
+        self
+    }
+
+    /// Fits a polynomial of the given degree; a degree of 1 is a straight line
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let transform = RegressionTransform::new("x", "y").with_degree(3);
+    /// ```
+    pub const fn with_degree(mut self, degree: usize) -> Self {
+        self.method = RegressionMethod::Polynomial { degree };
+        self
+    }
+
+    /// Alias for [`with_degree`](Self::with_degree), following the Vega-Lite `order` name
+    pub const fn with_order(self, order: usize) -> Self {
+        self.with_degree(order)
+    }
+
+    /// Sets the field to group by, fitting a separate curve for each group
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let transform = RegressionTransform::new("x", "y")
+    ///     .with_groupby("species"); // One trend line per species
+    /// ```
+    pub fn with_groupby(mut self, groupby: &str) -> Self {
+        self.groupby = Some(groupby.into());
+        self
+    }
+
+    /// Sets the output column names for the fitted x and predicted y values
+    pub fn with_as(mut self, x_field: impl Into<String>, y_field: impl Into<String>) -> Self {
+        self.as_ = [x_field.into(), y_field.into()];
+        self
+    }
+
+    /// Sets the output column names for the lower and upper bounds of the 95% confidence interval
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let transform = RegressionTransform::new("x", "y").with_ci_as("lo", "hi");
+    /// ```
+    pub fn with_ci_as(mut self, lower: impl Into<String>, upper: impl Into<String>) -> Self {
+        self.ci_as = [lower.into(), upper.into()];
+        self
+    }
+
+    /// Sets the x range over which the fitted curve is evaluated
+    ///
+    /// A range wider than the data extrapolates the model.
+    pub const fn with_extent(mut self, min: f64, max: f64) -> Self {
+        self.extent = Some((min, max));
+        self
+    }
+}
+
+impl<T: Mark> Chart<T> {
+    /// Fits a linear or polynomial regression and replaces the data with the fitted curve.
+    ///
+    /// Rows where either field is null are ignored. The output has the columns
+    /// `as_[0]` (x) and `as_[1]` (predicted y), sampled over the extent, the
+    /// `ci_as` bounds of the 95% confidence interval of the mean response, and
+    /// [`R_SQUARED_FIELD`] with the R² of the fit on every row. With a groupby
+    /// field, each group is fitted over its own x range and the output keeps
+    /// the group column.
+    ///
+    /// The interval uses Student's t distribution with `n - degree - 1`
+    /// degrees of freedom; a fit through exactly `degree + 1` points has none
+    /// left, so its interval collapses onto the curve.
+    ///
+    /// # Errors
+    /// Returns `ChartonError::Data` if a field is missing or not numeric, if the
+    /// polynomial degree is 0, if a group has fewer distinct x values than the
+    /// model has coefficients, if the extent is invalid, or if an output name
+    /// is used twice.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let points = chart!(df)?.mark_point()?.encode((alt::x("x"), alt::y("y")))?;
+    /// let fit = chart!(df)?.transform_regression(RegressionTransform::new("x", "y"))?;
+    /// let band = fit
+    ///     .clone()
+    ///     .mark_area()?
+    ///     .encode((alt::x("x"), alt::y("y_upper"), alt::y2("y_lower")))?;
+    /// let trend = fit.mark_line()?.encode((alt::x("x"), alt::y("y")))?;
+    /// band.and(points).and(trend).save("trend.svg")?;
+    /// ```
+    pub fn transform_regression(
+        mut self,
+        params: RegressionTransform,
+    ) -> Result<Self, ChartonError> {
+        let degree = params.method.degree();
+        if degree == 0 {
+            return Err(ChartonError::Data(
+                "transform_regression: polynomial degree must be at least 1".into(),
+            ));
+        }
+        let mut outputs = vec![
+            params.as_[0].as_str(),
+            params.as_[1].as_str(),
+            params.ci_as[0].as_str(),
+            params.ci_as[1].as_str(),
+            R_SQUARED_FIELD,
+        ];
+        outputs.extend(params.groupby.as_deref());
+        for (i, name) in outputs.iter().enumerate() {
+            if outputs[..i].contains(name) {
+                return Err(ChartonError::Data(format!(
+                    "transform_regression: output fields are both named '{}'",
+                    name
+                )));
+            }
+        }
+        if let Some((min, max)) = params.extent
+            && !(min.is_finite() && max.is_finite() && min < max)
+        {
+            return Err(ChartonError::Data(format!(
+                "transform_regression: invalid extent [{}, {}]",
+                min, max
+            )));
+        }
+
+        let mut columns = Vec::with_capacity(2);
+        for field in [&params.on, &params.regression] {
+            let col = self.data.column(field)?;
+            if col.semantic_type() != SemanticType::Continuous {
+                return Err(ChartonError::Data(format!(
+                    "transform_regression: field '{}' is not numeric",
+                    field
+                )));
+            }
+            columns.push(col.to_f64_options());
+        }
+
+        // Groups are fitted in the order of their categories, like the legend.
+        let groups: Vec<(Option<String>, Vec<usize>)> = match &params.groupby {
+            Some(g_field) => {
+                let group_col = self.data.column(g_field)?;
+                let mut rows: AHashMap<String, Vec<usize>> = AHashMap::new();
+                for i in 0..self.data.height() {
+                    if let Some(key) = group_col.get(i).to_string() {
+                        rows.entry(key).or_default().push(i);
+                    }
+                }
+                group_col
+                    .category_values()
+                    .into_iter()
+                    .filter_map(|key| rows.remove(&key).map(|r| (Some(key), r)))
+                    .collect()
+            }
+            None => vec![(None, (0..self.data.height()).collect())],
+        };
+
+        let mut fit_x = Vec::new();
+        let mut fit_y = Vec::new();
+        let mut fit_lower = Vec::new();
+        let mut fit_upper = Vec::new();
+        let mut r_squared = Vec::new();
+        let mut fit_group = Vec::new();
+
+        for (key, rows) in groups {
+            let (xs, ys): (Vec<f64>, Vec<f64>) = rows
+                .iter()
+                .filter_map(|&i| Some((columns[0][i]?, columns[1][i]?)))
+                .filter(|(x, y)| x.is_finite() && y.is_finite())
+                .unzip();
+
+            let mut distinct = xs.clone();
+            distinct.sort_unstable_by(|a, b| a.total_cmp(b));
+            distinct.dedup();
+            if distinct.len() <= degree {
+                let scope = key
+                    .as_ref()
+                    .map(|k| format!(" in group '{}'", k))
+                    .unwrap_or_default();
+                return Err(ChartonError::Data(format!(
+                    "transform_regression: a degree {} fit needs at least {} distinct '{}' values{}, found {}",
+                    degree,
+                    degree + 1,
+                    params.on,
+                    scope,
+                    distinct.len()
+                )));
+            }
+
+            let (min, max) = params
+                .extent
+                .unwrap_or((distinct[0], distinct[distinct.len() - 1]));
+            let fit = PolynomialFit::new(&xs, &ys, degree)?;
+            let r2 = fit.r_squared(&xs, &ys);
+            let t = t_quantile_975(xs.len() - degree - 1);
+            let sigma = fit.residual_std_error(&xs, &ys);
+
+            // The confidence band bends away from the fit, so even straight
+            // lines are sampled densely.
+            let step = (max - min) / (REGRESSION_STEPS - 1) as f64;
+            for i in 0..REGRESSION_STEPS {
+                let x = min + i as f64 * step;
+                let y = fit.predict(x);
+                let half_width = t * sigma * fit.leverage(x).sqrt();
+                fit_x.push(x);
+                fit_y.push(y);
+                fit_lower.push(y - half_width);
+                fit_upper.push(y + half_width);
+            }
+            r_squared.extend(std::iter::repeat_n(r2, REGRESSION_STEPS));
+            if let Some(key) = key {
+                fit_group.extend(std::iter::repeat_n(key, REGRESSION_STEPS));
+            }
+        }
+
+        let mut new_ds = Dataset::new();
+        new_ds.add_column(
+            &params.as_[0],
+            ColumnVector::Float64 {
+                data: fit_x,
+                validity: None,
+            },
+        )?;
+        new_ds.add_column(
+            &params.as_[1],
+            ColumnVector::Float64 {
+                data: fit_y,
+                validity: None,
+            },
+        )?;
+        new_ds.add_column(
+            &params.ci_as[0],
+            ColumnVector::Float64 {
+                data: fit_lower,
+                validity: None,
+            },
+        )?;
+        new_ds.add_column(
+            &params.ci_as[1],
+            ColumnVector::Float64 {
+                data: fit_upper,
+                validity: None,
+            },
+        )?;
+        new_ds.add_column(
+            R_SQUARED_FIELD,
+            ColumnVector::Float64 {
+                data: r_squared,
+                validity: None,
+            },
+        )?;
+        if let Some(ref g_field) = params.groupby {
+            new_ds.add_column(
+                g_field,
+                ColumnVector::String {
+                    data: fit_group,
+                    validity: None,
+                },
+            )?;
+        }
+
+        self.data = new_ds;
+        Ok(self)
+    }
+}
+
+/// A least squares polynomial, fitted on standardized x values for numerical stability.
+struct PolynomialFit {
+    mean: f64,
+    scale: f64,
+    // Coefficients in increasing powers of the standardized x
+    coefficients: Vec<f64>,
+    // (VᵀV)⁻¹, the coefficient covariance up to the residual variance
+    inverse: Vec<Vec<f64>>,
+}
+
+impl PolynomialFit {
+    fn new(xs: &[f64], ys: &[f64], degree: usize) -> Result<Self, ChartonError> {
+        let n = xs.len() as f64;
+        let mean = xs.iter().sum::<f64>() / n;
+        let variance = xs.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+        let scale = variance.sqrt();
+
+        // Normal equations: (VᵀV) c = Vᵀy, with V the Vandermonde matrix.
+        let size = degree + 1;
+        let mut power_sums = vec![0.0; 2 * degree + 1];
+        let mut rhs = vec![0.0; size];
+        for (&x, &y) in xs.iter().zip(ys) {
+            let t = (x - mean) / scale;
+            let mut p = 1.0;
+            for (k, sum) in power_sums.iter_mut().enumerate() {
+                *sum += p;
+                if k < size {
+                    rhs[k] += p * y;
+                }
+                p *= t;
+            }
+        }
+        let matrix: Vec<Vec<f64>> = (0..size)
+            .map(|row| power_sums[row..row + size].to_vec())
+            .collect();
+
+        let singular =
+            || ChartonError::Data("transform_regression: the fit is numerically singular".into());
+        let coefficients = solve_linear_system(matrix.clone(), rhs).ok_or_else(singular)?;
+        // Column k of the inverse solves (VᵀV) x = e_k.
+        let mut inverse = vec![vec![0.0; size]; size];
+        for k in 0..size {
+            let mut unit = vec![0.0; size];
+            unit[k] = 1.0;
+            let column = solve_linear_system(matrix.clone(), unit).ok_or_else(singular)?;
+            for (row, value) in column.into_iter().enumerate() {
+                inverse[row][k] = value;
+            }
+        }
+
+        Ok(Self {
+            mean,
+            scale,
+            coefficients,
+            inverse,
+        })
+    }
+
+    /// The residual standard error, or 0 when no degrees of freedom are left.
+    fn residual_std_error(&self, xs: &[f64], ys: &[f64]) -> f64 {
+        let dof = xs.len().saturating_sub(self.coefficients.len());
+        if dof == 0 {
+            return 0.0;
+        }
+        let ss_res: f64 = xs
+            .iter()
+            .zip(ys)
+            .map(|(&x, y)| (y - self.predict(x)).powi(2))
+            .sum();
+        (ss_res / dof as f64).sqrt()
+    }
+
+    /// The variance of the predicted mean at `x`, in units of the residual variance.
+    fn leverage(&self, x: f64) -> f64 {
+        let t = (x - self.mean) / self.scale;
+        let powers: Vec<f64> = std::iter::successors(Some(1.0), |p| Some(p * t))
+            .take(self.coefficients.len())
+            .collect();
+        let quadratic: f64 = self
+            .inverse
+            .iter()
+            .zip(&powers)
+            .map(|(row, pi)| pi * row.iter().zip(&powers).map(|(a, pj)| a * pj).sum::<f64>())
+            .sum();
+        quadratic.max(0.0)
+    }
+
+    /// The share of the variance of `ys` explained by the fit; 1 for constant data.
+    fn r_squared(&self, xs: &[f64], ys: &[f64]) -> f64 {
+        let mean = ys.iter().sum::<f64>() / ys.len() as f64;
+        let ss_tot: f64 = ys.iter().map(|y| (y - mean).powi(2)).sum();
+        if ss_tot == 0.0 {
+            return 1.0;
+        }
+        let ss_res: f64 = xs
+            .iter()
+            .zip(ys)
+            .map(|(&x, y)| (y - self.predict(x)).powi(2))
+            .sum();
+        1.0 - ss_res / ss_tot
+    }
+
+    fn predict(&self, x: f64) -> f64 {
+        let t = (x - self.mean) / self.scale;
+        // Horner's scheme
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(0.0, |acc, c| acc * t + c)
+    }
+}
+
+/// The 97.5th percentile of Student's t distribution with `dof` degrees of freedom.
+///
+/// One and two degrees of freedom have closed forms; otherwise the Cornish-Fisher
+/// expansion around the normal quantile (Abramowitz & Stegun 26.7.5) is used,
+/// which is within 0.2% from three degrees of freedom on.
+fn t_quantile_975(dof: usize) -> f64 {
+    match dof {
+        // With no residual degrees of freedom the band has zero width anyway.
+        0 => 0.0,
+        1 => (std::f64::consts::PI * 0.475).tan(),
+        2 => 0.95 / (2.0 * 0.975 * 0.025_f64).sqrt(),
+        _ => {
+            let z = Z_975;
+            let n = dof as f64;
+            let z2 = z * z;
+            let g1 = (z2 + 1.0) * z / 4.0;
+            let g2 = ((5.0 * z2 + 16.0) * z2 + 3.0) * z / 96.0;
+            let g3 = (((3.0 * z2 + 19.0) * z2 + 17.0) * z2 - 15.0) * z / 384.0;
+            let g4 =
+                ((((79.0 * z2 + 776.0) * z2 + 1482.0) * z2 - 1920.0) * z2 - 945.0) * z / 92160.0;
+            z + g1 / n + g2 / n.powi(2) + g3 / n.powi(3) + g4 / n.powi(4)
+        }
+    }
+}
+
+/// Solves `a · x = b` by Gaussian elimination with partial pivoting.
+///
+/// Returns `None` if the matrix is singular.
+fn solve_linear_system(mut a: Vec<Vec<f64>>, mut b: Vec<f64>) -> Option<Vec<f64>> {
+    let n = b.len();
+    for col in 0..n {
+        let pivot = (col..n).max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs()))?;
+        if a[pivot][col].abs() < 1e-12 {
+            return None;
+        }
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+
+        for row in col + 1..n {
+            let factor = a[row][col] / a[col][col];
+            let (upper, lower) = a.split_at_mut(row);
+            for (target, &p) in lower[0][col..].iter_mut().zip(&upper[col][col..]) {
+                *target -= factor * p;
+            }
+            b[row] -= factor * b[col];
+        }
+    }
+
+    let mut x = vec![0.0; n];
+    for row in (0..n).rev() {
+        let tail: f64 = (row + 1..n).map(|k| a[row][k] * x[k]).sum();
+        x[row] = (b[row] - tail) / a[row][row];
+    }
+    Some(x)
+}
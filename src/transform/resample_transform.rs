@@ -0,0 +1,325 @@
+use crate::chart::Chart;
+use crate::core::data::{AggregateOp, ColumnVector, Dataset, NANOS_PER_DAY};
+use crate::error::ChartonError;
+use crate::mark::Mark;
+use crate::transform::aggregate_transform::AggregateFieldDef;
+use ahash::AHashMap;
+use time::{Date, Month, OffsetDateTime, Weekday};
+
+/// Calendar period used by `transform_resample`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Period {
+    Day,
+    /// Seven days starting on the transform's week start (Monday by default).
+    Week,
+    Month,
+    /// Three months starting in January, April, July or October.
+    Quarter,
+    Year,
+}
+
+impl Period {
+    /// Returns the first day of the period containing `date` and of the next period.
+    fn bounds(self, date: Date, week_start: Weekday) -> Option<(Date, Date)> {
+        let month_start = |year: i32, month: u8| {
+            Date::from_calendar_date(year, Month::try_from(month).ok()?, 1).ok()
+        };
+        let add_months = |start: Date, months: u8| {
+            let index = start.month() as u8 - 1 + months;
+            month_start(start.year() + i32::from(index / 12), index % 12 + 1)
+        };
+
+        let start = match self {
+            Period::Day => date,
+            Period::Week => {
+                let back = (date.weekday().number_days_from_monday() + 7
+                    - week_start.number_days_from_monday())
+                    % 7;
+                date.checked_sub(time::Duration::days(i64::from(back)))?
+            }
+            Period::Month => month_start(date.year(), date.month() as u8)?,
+            Period::Quarter => month_start(date.year(), (date.month() as u8 - 1) / 3 * 3 + 1)?,
+            Period::Year => month_start(date.year(), 1)?,
+        };
+        let end = match self {
+            Period::Day => start.next_day()?,
+            Period::Week => start.checked_add(time::Duration::days(7))?,
+            Period::Month => add_months(start, 1)?,
+            Period::Quarter => add_months(start, 3)?,
+            Period::Year => add_months(start, 12)?,
+        };
+        Some((start, end))
+    }
+}
+
+/// Configuration parameters for the resample transformation
+///
+/// Buckets a date or datetime column into calendar periods and aggregates the
+/// values of each period, optionally per series. The period column keeps its
+/// name and type and holds the start of each period, so it can be encoded on a
+/// temporal axis directly.
+#[derive(Debug, Clone)]
+pub struct ResampleTransform {
+    // The date or datetime column to bucket
+    pub(crate) on: String,
+    // The calendar period of each bucket
+    pub(crate) period: Period,
+    // The aggregated output columns
+    pub(crate) aggregate: Vec<AggregateFieldDef>,
+    // The fields whose combined values define separate series
+    pub(crate) groupby: Vec<String>,
+    // The first day of a week for `Period::Week`
+    pub(crate) week_start: Weekday,
+    // Whether to drop the last period of a series when the data stops before its end
+    pub(crate) drop_incomplete: bool,
+}
+
+impl ResampleTransform {
+    /// Creates a new `ResampleTransform` instance with default parameters
+    ///
+    /// # Parameters
+    /// * `on` - The name of the date or datetime column
+    /// * `period` - The calendar period of each bucket
+    ///
+    /// # Returns
+    /// A new `ResampleTransform` instance with the following defaults:
+    /// - No aggregates (add them with `agg`)
+    /// - No grouping
+    /// - Weeks starting on Monday
+    /// - Incomplete trailing periods kept
+    pub fn new(on: impl Into<String>, period: Period) -> Self {
+        Self {
+            on: on.into(),
+            period,
+            aggregate: Vec::new(),
+            groupby: Vec::new(),
+            week_start: Weekday::Monday,
+            drop_incomplete: false,
+        }
+    }
+
+    /// Adds aggregates as (field, operation) pairs; each output column keeps the field name
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let monthly = ResampleTransform::new("date", Period::Month)
+    ///     .agg(&[("value", AggregateOp::Mean), ("volume", AggregateOp::Sum)]);
+    /// ```
+    pub fn agg(mut self, aggregates: &[(&str, AggregateOp)]) -> Self {
+        self.aggregate.extend(
+            aggregates
+                .iter()
+                .map(|&(field, op)| AggregateFieldDef::new(field, op, field)),
+        );
+        self
+    }
+
+    /// Set the groupby fields
+    ///
+    /// # Parameters
+    /// * `groupby` - The names of the columns whose combined values define a series.
+    ///   Each series is resampled separately.
+    pub fn with_groupby<S: Into<String>>(mut self, groupby: impl IntoIterator<Item = S>) -> Self {
+        self.groupby = groupby.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the first day of the week used by `Period::Week`
+    pub const fn with_week_start(mut self, week_start: Weekday) -> Self {
+        self.week_start = week_start;
+        self
+    }
+
+    /// Drops the last period of each series when the data stops before the period ends
+    ///
+    /// The data is considered to reach the end of a period when its last
+    /// timestamp is within one sampling interval (the smallest gap between
+    /// distinct timestamps) of the next period.
+    pub const fn with_drop_incomplete(mut self, drop: bool) -> Self {
+        self.drop_incomplete = drop;
+        self
+    }
+}
+
+impl<T: Mark> Chart<T> {
+    /// Aggregates the data per calendar period, replacing it with one row per period and series.
+    ///
+    /// Rows with a null timestamp are ignored. Only periods containing data are
+    /// produced. Rows are ordered by series (in order of first appearance) and
+    /// then by period. Calendar boundaries are computed in UTC.
+    ///
+    /// # Errors
+    /// Returns `ChartonError::Data` if there are no aggregates, if `on` is not a
+    /// date or datetime column, or if output column names clash.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// chart
+    ///     .transform_resample(
+    ///         ResampleTransform::new("date", Period::Month)
+    ///             .agg(&[("value", AggregateOp::Mean)])
+    ///             .with_groupby(["series"]),
+    ///     )?
+    ///     .mark_bar()?
+    ///     .encode((alt::x("date"), alt::y("value"), alt::color("series")))?
+    /// ```
+    pub fn transform_resample(mut self, params: ResampleTransform) -> Result<Self, ChartonError> {
+        if params.aggregate.is_empty() {
+            return Err(ChartonError::Data(
+                "ResampleTransform requires at least one aggregate field".into(),
+            ));
+        }
+
+        let mut names: Vec<&str> = params.groupby.iter().map(String::as_str).collect();
+        names.push(&params.on);
+        for (i, name) in names.iter().enumerate() {
+            if names[..i].contains(name) {
+                return Err(ChartonError::Data(format!(
+                    "ResampleTransform: field '{}' is listed more than once",
+                    name
+                )));
+            }
+        }
+        for def in &params.aggregate {
+            if names.contains(&def.as_.as_str()) {
+                return Err(ChartonError::Data(format!(
+                    "ResampleTransform: duplicate output column '{}'",
+                    def.as_
+                )));
+            }
+            names.push(&def.as_);
+        }
+
+        // --- PHASE 1: TIMESTAMPS (nanoseconds since the epoch) ---
+        let on_col = self.data.column(&params.on)?;
+        let timestamps: Vec<Option<i64>> = match on_col {
+            ColumnVector::Date { data, validity } => (0..data.len())
+                .map(|i| {
+                    ColumnVector::is_valid_in_mask(validity, i)
+                        .then(|| (f64::from(data[i]) * NANOS_PER_DAY) as i64)
+                })
+                .collect(),
+            ColumnVector::Datetime { data, validity, .. } => (0..data.len())
+                .map(|i| ColumnVector::is_valid_in_mask(validity, i).then_some(data[i]))
+                .collect(),
+            _ => {
+                return Err(ChartonError::Data(format!(
+                    "ResampleTransform: field '{}' is not a date or datetime column",
+                    params.on
+                )));
+            }
+        };
+
+        let period_of = |ts: i64| -> Result<(i64, i64), ChartonError> {
+            let out_of_range = || {
+                ChartonError::Data(format!(
+                    "ResampleTransform: timestamp {} of '{}' is out of range",
+                    ts, params.on
+                ))
+            };
+            let date = OffsetDateTime::from_unix_timestamp_nanos(i128::from(ts))
+                .map_err(|_| out_of_range())?
+                .date();
+            let (start, end) = params
+                .period
+                .bounds(date, params.week_start)
+                .ok_or_else(out_of_range)?;
+            let to_nanos = |d: Date| (d.midnight().assume_utc().unix_timestamp_nanos()) as i64;
+            Ok((to_nanos(start), to_nanos(end)))
+        };
+
+        // --- PHASE 2: BUCKETING (series in first appearance order, then period) ---
+        let key_cols = params
+            .groupby
+            .iter()
+            .map(|g| self.data.column(g))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut series_lookup: AHashMap<Vec<Option<String>>, usize> = AHashMap::new();
+        let mut series_last: Vec<i64> = Vec::new();
+        let mut bucket_lookup: AHashMap<(usize, i64), usize> = AHashMap::new();
+        // (series, period start, period end, row indices)
+        let mut buckets: Vec<(usize, i64, i64, Vec<usize>)> = Vec::new();
+
+        for (i, ts) in timestamps.iter().enumerate() {
+            let Some(ts) = *ts else { continue };
+            let key: Vec<Option<String>> =
+                key_cols.iter().map(|col| col.get(i).to_string()).collect();
+            let series = *series_lookup.entry(key).or_insert_with(|| {
+                series_last.push(ts);
+                series_last.len() - 1
+            });
+            series_last[series] = series_last[series].max(ts);
+
+            let (start, end) = period_of(ts)?;
+            let slot = *bucket_lookup.entry((series, start)).or_insert_with(|| {
+                buckets.push((series, start, end, Vec::new()));
+                buckets.len() - 1
+            });
+            buckets[slot].3.push(i);
+        }
+        buckets.sort_by_key(|&(series, start, ..)| (series, start));
+
+        if params.drop_incomplete {
+            let mut distinct: Vec<i64> = timestamps.iter().flatten().copied().collect();
+            distinct.sort_unstable();
+            distinct.dedup();
+            let interval = distinct.windows(2).map(|w| w[1] - w[0]).min().unwrap_or(0);
+
+            // The buckets of a series are contiguous and sorted, so its last bucket
+            // is the one followed by another series (or by nothing).
+            let mut keep = vec![true; buckets.len()];
+            for (i, &(series, _, end, _)) in buckets.iter().enumerate() {
+                let is_last = buckets.get(i + 1).is_none_or(|next| next.0 != series);
+                if is_last && series_last[series].saturating_add(interval) < end {
+                    keep[i] = false;
+                }
+            }
+            let mut flags = keep.into_iter();
+            buckets.retain(|_| flags.next().unwrap_or(true));
+        }
+
+        // --- PHASE 3: BUILD THE RESAMPLED TABLE ---
+        let mut result = Dataset::new();
+
+        let first_rows: Vec<usize> = buckets.iter().map(|b| b.3[0]).collect();
+        for (name, col) in params.groupby.iter().zip(&key_cols) {
+            result.add_column(name.as_str(), col.take(&first_rows))?;
+        }
+
+        // Period starts keep the type of the input column.
+        let starts = buckets.iter().map(|b| b.1);
+        let period_col = match on_col {
+            ColumnVector::Datetime { timezone, .. } => ColumnVector::Datetime {
+                data: starts.collect(),
+                validity: None,
+                timezone: timezone.clone(),
+            },
+            _ => ColumnVector::Date {
+                data: starts
+                    .map(|s| (s as f64 / NANOS_PER_DAY).round() as i32)
+                    .collect(),
+                validity: None,
+            },
+        };
+        result.add_column(params.on.as_str(), period_col)?;
+
+        for def in &params.aggregate {
+            let col = self.data.column(&def.field)?;
+            let data: Vec<f64> = buckets
+                .iter()
+                .map(|b| def.op.aggregate_by_index(col, &b.3))
+                .collect();
+            result.add_column(
+                def.as_.as_str(),
+                ColumnVector::Float64 {
+                    data,
+                    validity: None,
+                },
+            )?;
+        }
+
+        self.data = result;
+        Ok(self)
+    }
+}
@@ -0,0 +1,153 @@
+use crate::chart::Chart;
+use crate::error::ChartonError;
+use crate::mark::Mark;
+use std::hash::{BuildHasher, RandomState};
+
+/// A small SplitMix64 generator; good enough to pick rows and fully
+/// reproducible for a given seed on every platform.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    const fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a value in `0..bound`.
+    const fn below(&mut self, bound: usize) -> usize {
+        ((self.next_u64() as u128 * bound as u128) >> 64) as usize
+    }
+}
+
+/// Draws a seed from the process-wide hasher keys, which works on wasm too.
+fn random_seed() -> u64 {
+    RandomState::new().hash_one(0u8)
+}
+
+/// Picks `n` distinct entries of `pool` with a partial Fisher-Yates shuffle.
+fn pick(mut pool: Vec<usize>, n: usize, rng: &mut SplitMix64) -> Vec<usize> {
+    let n = n.min(pool.len());
+    for i in 0..n {
+        let j = i + rng.below(pool.len() - i);
+        pool.swap(i, j);
+    }
+    pool.truncate(n);
+    pool
+}
+
+impl<T: Mark> Chart<T> {
+    /// Keeps `n` randomly chosen rows, e.g. to draw a readable scatter plot of
+    /// a very large dataset.
+    ///
+    /// The rows keep their original order. Every call draws a new sample; use
+    /// [`Chart::transform_sample_with_seed`] for reproducible output. If the
+    /// data has no more than `n` rows, it is left untouched.
+    ///
+    /// # Errors
+    /// Returns `ChartonError::Data` if `n` is zero.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// chart!(df)?.transform_sample(5_000)?.mark_point()?
+    /// ```
+    pub fn transform_sample(self, n: usize) -> Result<Self, ChartonError> {
+        self.transform_sample_with_seed(n, random_seed())
+    }
+
+    /// Keeps `n` randomly chosen rows, drawn from a generator seeded with `seed`.
+    ///
+    /// The same data, `n` and `seed` always select the same rows.
+    pub fn transform_sample_with_seed(mut self, n: usize, seed: u64) -> Result<Self, ChartonError> {
+        if n == 0 {
+            return Err(ChartonError::Data(
+                "transform_sample: the sample size must be at least 1".into(),
+            ));
+        }
+
+        let row_count = self.data.height();
+        if n >= row_count {
+            eprintln!(
+                "SampleTransform: requested {} rows but the data has only {}; all rows are kept.",
+                n, row_count
+            );
+            return Ok(self);
+        }
+
+        let mut keep = pick((0..row_count).collect(), n, &mut SplitMix64(seed));
+        keep.sort_unstable();
+        self.data = self.data.take_rows(&keep)?;
+        Ok(self)
+    }
+
+    /// Keeps about `n` random rows, split evenly over the categories of `group_by`.
+    ///
+    /// Each category contributes `n / categories` rows, so small classes are
+    /// not crowded out of the sample; a category with fewer rows keeps all of
+    /// them. Nulls form their own category. The rows keep their original order.
+    /// Every call draws a new sample; use
+    /// [`Chart::transform_stratified_sample_with_seed`] for reproducible output.
+    ///
+    /// # Errors
+    /// Returns `ChartonError::Data` if `group_by` is not a column, or if `n` is
+    /// smaller than the number of categories.
+    pub fn transform_stratified_sample(
+        self,
+        n: usize,
+        group_by: &str,
+    ) -> Result<Self, ChartonError> {
+        self.transform_stratified_sample_with_seed(n, group_by, random_seed())
+    }
+
+    /// Keeps about `n` random rows split evenly over the categories of
+    /// `group_by`, drawn from a generator seeded with `seed`.
+    ///
+    /// The same data, `n`, `group_by` and `seed` always select the same rows.
+    pub fn transform_stratified_sample_with_seed(
+        mut self,
+        n: usize,
+        group_by: &str,
+        seed: u64,
+    ) -> Result<Self, ChartonError> {
+        if !self.data.schema.contains_key(group_by) {
+            return Err(ChartonError::Data(format!(
+                "transform_stratified_sample: column '{}' not found",
+                group_by
+            )));
+        }
+
+        let row_count = self.data.height();
+        if n >= row_count {
+            eprintln!(
+                "SampleTransform: requested {} rows but the data has only {}; all rows are kept.",
+                n, row_count
+            );
+            return Ok(self);
+        }
+
+        let groups = self.data.group_by(Some(group_by)).groups;
+        let per_group = n / groups.len();
+        if per_group == 0 {
+            return Err(ChartonError::Data(format!(
+                "transform_stratified_sample: cannot take {} rows from {} categories of '{}'",
+                n,
+                groups.len(),
+                group_by
+            )));
+        }
+
+        let mut rng = SplitMix64(seed);
+        let mut keep: Vec<usize> = groups
+            .into_iter()
+            .flat_map(|(_, rows)| pick(rows, per_group, &mut rng))
+            .collect();
+        keep.sort_unstable();
+
+        if keep.len() < row_count {
+            self.data = self.data.take_rows(&keep)?;
+        }
+        Ok(self)
+    }
+}
@@ -0,0 +1,277 @@
+use crate::TEMP_SUFFIX;
+use crate::chart::Chart;
+use crate::core::data::{ColumnVector, Dataset, get_quantile};
+use crate::error::ChartonError;
+use crate::mark::Mark;
+use crate::mark::violin::MarkViolin;
+use crate::transform::density_transform::{BandwidthType, estimate_density};
+use ahash::AHashMap;
+
+/// Number of points at which each violin's density profile is evaluated.
+pub(crate) const VIOLIN_STEPS: usize = 100;
+
+impl<T: Mark> Chart<T> {
+    /// Computes one density profile per (x, color) group for violin plots.
+    ///
+    /// Mirrors the boxplot aggregation: the Cartesian product of categories is kept
+    /// (so dodged violins stay aligned), and invisible boundary rows make the Y scale
+    /// cover the full data range. Each group's profile is evaluated at `VIOLIN_STEPS`
+    /// points between its minimum and maximum and normalized by the largest density
+    /// of all groups, so violin areas stay comparable.
+    pub(crate) fn transform_violin_data(mut self) -> Result<Self, ChartonError> {
+        let x_name = &self
+            .encoding
+            .x
+            .as_ref()
+            .ok_or_else(|| ChartonError::Encoding("X encoding missing for violin".into()))?
+            .field;
+        let y_name = &self
+            .encoding
+            .y
+            .as_ref()
+            .ok_or_else(|| ChartonError::Encoding("Y encoding missing for violin".into()))?
+            .field;
+        let (bandwidth, kernel) = self
+            .mark
+            .as_ref()
+            .and_then(|m| (m as &dyn std::any::Any).downcast_ref::<MarkViolin>())
+            .map(|m| (m.bandwidth.clone(), m.kernel.clone()))
+            .ok_or_else(|| ChartonError::Mark("MarkViolin config missing".into()))?;
+        if let BandwidthType::Fixed(bw) = bandwidth
+            && !(bw > 0.0 && bw.is_finite())
+        {
+            return Err(ChartonError::Mark(format!(
+                "Violin bandwidth must be positive, got {}",
+                bw
+            )));
+        }
+
+        let x_col = self.data.column(x_name)?;
+        let y_col = self.data.column(y_name)?;
+        let row_count = self.data.height();
+
+        let (global_min, global_max) = y_col.min_max();
+
+        // --- STEP 1: Deterministic order for X and Color ---
+        let x_order = x_col.category_values();
+        let mut color_field_name: Option<String> = None;
+        let mut color_order = Vec::new();
+        let mut color_col_proto: Option<ColumnVector> = None;
+
+        if let Some(color_enc) = &self.encoding.color {
+            let c_col = self.data.column(&color_enc.field)?;
+            color_order = c_col.category_values();
+            color_col_proto = Some(c_col.clone());
+            color_field_name = Some(color_enc.field.clone());
+        }
+
+        // Coloring by the x field itself gives one violin per slot, so nothing is dodged.
+        let color_is_x = color_field_name.as_deref() == Some(x_name.as_str());
+        let groups_count = if color_order.is_empty() || color_is_x {
+            1.0
+        } else {
+            color_order.len() as f64
+        };
+
+        // --- STEP 2: Grouping ---
+        let mut group_map: AHashMap<(String, Option<String>), Vec<f64>> = AHashMap::new();
+        for i in 0..row_count {
+            let Some(v) = y_col.get(i).to_f64() else {
+                continue;
+            };
+            let x_val = x_col
+                .get(i)
+                .to_string()
+                .unwrap_or_else(|| "null".to_string());
+            let c_val = color_field_name.as_ref().map(|f| {
+                self.data
+                    .get(f, i)
+                    .to_string()
+                    .unwrap_or_else(|| "null".to_string())
+            });
+            group_map.entry((x_val, c_val)).or_default().push(v);
+        }
+
+        // --- STEP 3: Cartesian product & density estimation ---
+        let mut final_x = Vec::new();
+        let mut final_c = Vec::new();
+        let mut f_median = Vec::new();
+        let mut f_q1 = Vec::new();
+        let mut f_q3 = Vec::new();
+        let mut f_min = Vec::new();
+        let mut f_max = Vec::new();
+        let mut f_sub_idx = Vec::new();
+        let mut profiles: Vec<Vec<f64>> = Vec::new();
+
+        for x_val in &x_order {
+            let sub_tasks: Vec<(f64, Option<String>)> = if color_order.is_empty() {
+                vec![(0.0, None)]
+            } else if color_is_x {
+                vec![(0.0, Some(x_val.clone()))]
+            } else {
+                color_order
+                    .iter()
+                    .enumerate()
+                    .map(|(i, c)| (i as f64, Some(c.clone())))
+                    .collect()
+            };
+
+            for (c_idx, c_val) in sub_tasks {
+                final_x.push(x_val.clone());
+                final_c.push(
+                    c_val
+                        .clone()
+                        .unwrap_or_else(|| format!("{}_default", TEMP_SUFFIX)),
+                );
+                f_sub_idx.push(c_idx);
+
+                let mut vals = group_map
+                    .remove(&(x_val.clone(), c_val))
+                    .unwrap_or_default();
+                if vals.is_empty() {
+                    // Gap filling: keeps dodge positions aligned for missing groups.
+                    f_median.push(f64::NAN);
+                    f_q1.push(f64::NAN);
+                    f_q3.push(f64::NAN);
+                    f_min.push(f64::NAN);
+                    f_max.push(f64::NAN);
+                    profiles.push(Vec::new());
+                    continue;
+                }
+                vals.sort_unstable_by(|a, b| a.total_cmp(b));
+
+                let lo = vals[0];
+                let hi = vals[vals.len() - 1];
+                f_median.push(get_quantile(&vals, 0.5));
+                f_q1.push(get_quantile(&vals, 0.25));
+                f_q3.push(get_quantile(&vals, 0.75));
+                f_min.push(lo);
+                f_max.push(hi);
+
+                // A single distinct value has no spread to estimate a density from;
+                // such groups are drawn as a median line only.
+                if hi - lo <= 0.0 {
+                    profiles.push(Vec::new());
+                    continue;
+                }
+
+                let step = (hi - lo) / (VIOLIN_STEPS - 1) as f64;
+                let eval_points: Vec<f32> = (0..VIOLIN_STEPS)
+                    .map(|i| (lo + i as f64 * step) as f32)
+                    .collect();
+                let observations: Vec<f32> = vals.iter().map(|&v| v as f32).collect();
+                profiles.push(estimate_density(
+                    &observations,
+                    &eval_points,
+                    &bandwidth,
+                    &kernel,
+                    false,
+                )?);
+            }
+        }
+
+        // Normalize all profiles by the largest density so the widest violin spans
+        // the full configured width.
+        let peak = profiles
+            .iter()
+            .flatten()
+            .copied()
+            .filter(|d| d.is_finite())
+            .fold(0.0, f64::max);
+        let f_density: Vec<String> = profiles
+            .into_iter()
+            .map(|p| {
+                let scaled: Vec<f64> = p
+                    .into_iter()
+                    .map(|d| {
+                        if peak > 0.0 && d.is_finite() {
+                            d / peak
+                        } else {
+                            0.0
+                        }
+                    })
+                    .collect();
+                format!("{:?}", scaled)
+            })
+            .collect();
+
+        // --- STEP 4: Boundary injection (invisible rows for the Y scale) ---
+        let mut final_y = f_median.clone();
+        let mut f_density = f_density;
+        if global_min.is_finite() && global_max.is_finite() {
+            for bound in [global_min, global_max] {
+                final_x.push(format!("{}_boundary", TEMP_SUFFIX));
+                final_c.push(format!("{}_default", TEMP_SUFFIX));
+                final_y.push(bound);
+                f_median.push(f64::NAN);
+                f_q1.push(f64::NAN);
+                f_q3.push(f64::NAN);
+                f_min.push(f64::NAN);
+                f_max.push(f64::NAN);
+                f_sub_idx.push(0.0);
+                f_density.push("[]".to_string());
+            }
+        }
+
+        // --- STEP 5: Dataset assembly with categorical restoration ---
+        let result_len = final_x.len();
+        let mut new_ds = Dataset::new();
+        new_ds.add_column(x_name, restore_categories(x_col.clone(), final_x))?;
+
+        let float = |data: Vec<f64>| ColumnVector::Float64 {
+            data,
+            validity: None,
+        };
+        new_ds.add_column(y_name, float(final_y))?;
+        new_ds.add_column(format!("{}_median", TEMP_SUFFIX), float(f_median))?;
+        new_ds.add_column(format!("{}_q1", TEMP_SUFFIX), float(f_q1))?;
+        new_ds.add_column(format!("{}_q3", TEMP_SUFFIX), float(f_q3))?;
+        new_ds.add_column(format!("{}_min", TEMP_SUFFIX), float(f_min))?;
+        new_ds.add_column(format!("{}_max", TEMP_SUFFIX), float(f_max))?;
+        new_ds.add_column(format!("{}_sub_idx", TEMP_SUFFIX), float(f_sub_idx))?;
+        new_ds.add_column(
+            format!("{}_groups_count", TEMP_SUFFIX),
+            float(vec![groups_count; result_len]),
+        )?;
+        new_ds.add_column(
+            format!("{}_density", TEMP_SUFFIX),
+            ColumnVector::String {
+                data: f_density,
+                validity: None,
+            },
+        )?;
+
+        if let (Some(f), Some(proto)) = (&color_field_name, color_col_proto) {
+            new_ds.add_column(f, restore_categories(proto, final_c))?;
+        }
+
+        self.data = new_ds;
+        Ok(self)
+    }
+}
+
+/// Rebuilds a grouping column, keeping the categorical dictionary of the source column.
+fn restore_categories(proto: ColumnVector, labels: Vec<String>) -> ColumnVector {
+    match proto {
+        ColumnVector::Categorical { values, .. } => {
+            let val_map: AHashMap<&str, u32> = values
+                .iter()
+                .enumerate()
+                .map(|(idx, s)| (s.as_str(), idx as u32))
+                .collect();
+            let keys = labels
+                .iter()
+                .map(|s| *val_map.get(s.as_str()).unwrap_or(&0))
+                .collect();
+            ColumnVector::Categorical {
+                keys,
+                values,
+                validity: None,
+            }
+        }
+        _ => ColumnVector::String {
+            data: labels,
+            validity: None,
+        },
+    }
+}
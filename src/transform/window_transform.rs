@@ -378,24 +378,20 @@ impl<T: Mark> Chart<T> {
 
         for key in group_order {
             if let Some(indices) = groups.remove(&key) {
-                let mut valid_indices: Vec<usize> = indices
+                // NaN has no place on a cumulative axis, so it is dropped like a null.
+                let mut valid_values: Vec<f64> = indices
                     .into_iter()
-                    .filter(|&idx| target_col.get(idx).to_f64().is_some())
+                    .filter_map(|idx| target_col.get(idx).to_f64())
+                    .filter(|v| !v.is_nan())
                     .collect();
 
-                if valid_indices.is_empty() {
+                if valid_values.is_empty() {
                     continue;
                 }
 
-                valid_indices.sort_by(|&a, &b| {
-                    target_col
-                        .get(a)
-                        .to_f64()
-                        .partial_cmp(&target_col.get(b).to_f64())
-                        .unwrap()
-                });
+                valid_values.sort_by(|a, b| a.total_cmp(b));
 
-                let group_size = valid_indices.len() as f64;
+                let group_size = valid_values.len() as f64;
                 let group_label = key.as_deref().unwrap_or("all").to_string();
 
                 // A. Start Padding
@@ -406,8 +402,7 @@ impl<T: Mark> Chart<T> {
                 }
 
                 // B. Actual Cumulative Points
-                for (i, &idx) in valid_indices.iter().enumerate() {
-                    let x_val = target_col.get(idx).to_f64().unwrap();
+                for (i, &x_val) in valid_values.iter().enumerate() {
                     let count = (i + 1) as f64;
                     let y_val = if params.normalize {
                         count / group_size
@@ -1,2 +1,2 @@
-pub(crate) mod color;
+pub mod color;
 pub(crate) mod shape;
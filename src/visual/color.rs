@@ -1,7 +1,12 @@
 // Allow approx_constant because these values are raw color data, not mathematical constants.
 #![allow(clippy::approx_constant)]
 
+mod cvd;
+
+pub use cvd::{CvdKind, check_distinguishable, simulate_cvd};
+
 use crate::Precision;
+use crate::error::ChartonError;
 use csscolorparser::Color;
 
 // Continuous color mapping schemes (colormaps) for numerical data visualization.
@@ -11,6 +16,7 @@ use csscolorparser::Color;
 /// Continuous color mapping schemes (colormaps) for numerical data visualization.
 /// Optimized for direct SingleColor (f64) output to support high-performance rendering.
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ColorMap {
     // Perceptually Uniform (Best for accurate data representation)
     Viridis,
@@ -49,6 +55,17 @@ pub enum ColorMap {
 }
 
 impl ColorMap {
+    /// Returns the same map run backwards, so high values get the colors of low
+    /// ones (`Viridis_r` in matplotlib).
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// Theme::default().with_color_map(ColorMap::Viridis.reversed())
+    /// ```
+    pub fn reversed(self) -> ColorGradient {
+        ColorGradient::Map(self).reversed()
+    }
+
     /// Returns a SingleColor based on a normalized value between 0.0 and 1.0.
     /// Following standard convention, the alpha channel is set to 1.0 (opaque).
     pub(crate) fn get_color(&self, value: f64) -> SingleColor {
@@ -503,9 +520,110 @@ impl ColorMap {
     }
 }
 
+/// A continuous color mapping: a built-in [`ColorMap`], a user-defined
+/// gradient, or either of them run backwards.
+///
+/// Kept apart from `ColorMap` so the presets stay `Copy`. Every API taking a
+/// gradient also accepts a plain `ColorMap`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ColorGradient {
+    /// One of the built-in color maps.
+    Map(ColorMap),
+
+    /// User-defined gradient as `(position, r, g, b)` stops with channels in [0, 1].
+    /// Build it with [`ColorGradient::custom`], which parses and validates the stops.
+    Custom(Vec<(f64, f64, f64, f64)>),
+
+    /// Another gradient run backwards, e.g. `Viridis_r`. Build it with
+    /// [`ColorGradient::reversed`] or [`ColorMap::reversed`].
+    Reversed(Box<ColorGradient>),
+}
+
+impl ColorGradient {
+    /// Creates a custom gradient from `(position, color)` stops.
+    ///
+    /// Colors accept any CSS color string ("#1f77b4", "steelblue", ...). Positions
+    /// must be strictly increasing, start at 0.0 and end at 1.0.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let brand = ColorGradient::custom(vec![(0.0, "#0b1d3a"), (0.5, "#2a9d8f"), (1.0, "#e9c46a")])?;
+    /// ```
+    pub fn custom<S: AsRef<str>>(stops: Vec<(f64, S)>) -> Result<Self, ChartonError> {
+        if stops.len() < 2 {
+            return Err(ChartonError::Scale(
+                "A custom color map needs at least two stops".into(),
+            ));
+        }
+        if stops
+            .windows(2)
+            .any(|w| w[0].0.partial_cmp(&w[1].0) != Some(std::cmp::Ordering::Less))
+        {
+            return Err(ChartonError::Scale(
+                "Custom color map positions must be strictly increasing".into(),
+            ));
+        }
+        if stops[0].0 != 0.0 || stops[stops.len() - 1].0 != 1.0 {
+            return Err(ChartonError::Scale(
+                "Custom color map positions must span [0, 1]".into(),
+            ));
+        }
+
+        stops
+            .iter()
+            .map(|(pos, color)| {
+                let parsed = color.as_ref().parse::<Color>().map_err(|_| {
+                    ChartonError::Scale(format!(
+                        "Invalid color '{}' in custom color map",
+                        color.as_ref()
+                    ))
+                })?;
+                Ok((*pos, parsed.r as f64, parsed.g as f64, parsed.b as f64))
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(ColorGradient::Custom)
+    }
+
+    /// Returns the same gradient run backwards, so high values get the colors
+    /// of low ones (`Viridis_r` in matplotlib). Reversing twice gives back the
+    /// original map.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let brand_r = ColorGradient::custom(vec![(0.0, "#0b1d3a"), (1.0, "#e9c46a")])?.reversed();
+    /// ```
+    pub fn reversed(self) -> Self {
+        match self {
+            ColorGradient::Reversed(inner) => *inner,
+            map => ColorGradient::Reversed(Box::new(map)),
+        }
+    }
+
+    /// Returns a SingleColor based on a normalized value between 0.0 and 1.0.
+    pub(crate) fn get_color(&self, value: f64) -> SingleColor {
+        let t = value.clamp(0.0, 1.0);
+        match self {
+            ColorGradient::Map(map) => map.get_color(t),
+            ColorGradient::Custom(stops) if !stops.is_empty() => {
+                ColorMap::interpolate_stops(stops, t)
+            }
+            ColorGradient::Custom(_) => SingleColor::from_rgba(0.0, 0.0, 0.0, 1.0),
+            ColorGradient::Reversed(inner) => inner.get_color(1.0 - t),
+        }
+    }
+}
+
+impl From<ColorMap> for ColorGradient {
+    fn from(map: ColorMap) -> Self {
+        ColorGradient::Map(map)
+    }
+}
+
 /// Discrete color palettes for categorical data visualization.
 /// Optimized to return `SingleColor` with pre-calculated f64 RGBA values.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ColorPalette {
     Tab10,
     Tab20,
@@ -516,6 +634,18 @@ pub enum ColorPalette {
     Pastel2,
     Dark2,
     Accent,
+
+    // Colorblind-safe qualitative palettes
+    /// Okabe & Ito's eight colors, distinguishable under all common color
+    /// vision deficiencies.
+    OkabeIto,
+    /// Paul Tol's bright scheme (7 colors).
+    TolBright,
+    /// Paul Tol's muted scheme (9 colors), for text and lines.
+    TolMuted,
+    /// Paul Tol's light scheme (9 colors), for filled areas with labels.
+    TolLight,
+
     Custom(Vec<SingleColor>), // Custom palette
 }
 
@@ -549,6 +679,46 @@ impl<const N: usize> From<[&str; N]> for ColorPalette {
 }
 
 impl ColorPalette {
+    /// Creates a custom categorical palette, checking every color up front.
+    ///
+    /// Unlike the `From` conversions, which silently fall back to black for
+    /// strings they cannot read, this reports the first invalid color. Colors
+    /// accept any CSS color string ("#1f77b4", "steelblue", "none", ...) and are
+    /// assigned to categories in order, wrapping like the built-in palettes.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let brand = ColorPalette::custom(["#0b1d3a", "#2a9d8f", "#e9c46a"])?;
+    /// chart.with_color_palette(brand)
+    /// ```
+    pub fn custom<S: AsRef<str>>(
+        colors: impl IntoIterator<Item = S>,
+    ) -> Result<Self, ChartonError> {
+        let colors = colors
+            .into_iter()
+            .map(|color| {
+                let color = color.as_ref();
+                let is_none =
+                    color.eq_ignore_ascii_case("none") || color.eq_ignore_ascii_case("transparent");
+                if is_none || color.parse::<Color>().is_ok() {
+                    Ok(SingleColor::new(color))
+                } else {
+                    Err(ChartonError::Scale(format!(
+                        "Invalid color '{}' in custom palette",
+                        color
+                    )))
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if colors.is_empty() {
+            return Err(ChartonError::Scale(
+                "A custom palette needs at least one color".into(),
+            ));
+        }
+        Ok(ColorPalette::Custom(colors))
+    }
+
     /// Returns a specific color from the palette by index (with automatic wrapping).
     /// Bypasses hex parsing by using pre-calculated RGBA components.
     pub(crate) fn get_color(&self, index: usize) -> SingleColor {
@@ -682,9 +852,117 @@ impl ColorPalette {
                 (0.749, 0.357, 0.090),
                 (0.400, 0.400, 0.400),
             ],
+            ColorPalette::OkabeIto => &[
+                (0.902, 0.624, 0.000),
+                (0.337, 0.706, 0.914),
+                (0.000, 0.620, 0.451),
+                (0.941, 0.894, 0.259),
+                (0.000, 0.447, 0.698),
+                (0.835, 0.369, 0.000),
+                (0.800, 0.475, 0.655),
+                (0.000, 0.000, 0.000),
+            ],
+            ColorPalette::TolBright => &[
+                (0.267, 0.467, 0.667),
+                (0.933, 0.400, 0.467),
+                (0.133, 0.533, 0.200),
+                (0.800, 0.733, 0.267),
+                (0.400, 0.800, 0.933),
+                (0.667, 0.200, 0.467),
+                (0.733, 0.733, 0.733),
+            ],
+            ColorPalette::TolMuted => &[
+                (0.800, 0.400, 0.467),
+                (0.200, 0.133, 0.533),
+                (0.867, 0.800, 0.467),
+                (0.067, 0.467, 0.200),
+                (0.533, 0.800, 0.933),
+                (0.533, 0.133, 0.333),
+                (0.267, 0.667, 0.600),
+                (0.600, 0.600, 0.200),
+                (0.667, 0.267, 0.600),
+            ],
+            ColorPalette::TolLight => &[
+                (0.467, 0.667, 0.867),
+                (0.933, 0.533, 0.400),
+                (0.933, 0.867, 0.533),
+                (1.000, 0.667, 0.733),
+                (0.600, 0.867, 1.000),
+                (0.267, 0.733, 0.600),
+                (0.733, 0.800, 0.200),
+                (0.667, 0.667, 0.000),
+                (0.867, 0.867, 0.867),
+            ],
             ColorPalette::Custom(_) => unreachable!("Use get_color for Custom variant"),
         }
     }
+
+    /// Number of distinct colors in the palette.
+    pub(crate) fn color_count(&self) -> usize {
+        match self {
+            ColorPalette::Custom(colors) => colors.len(),
+            _ => self.rgba_colors().len(),
+        }
+    }
+
+    /// All colors of the palette, in order.
+    pub(crate) fn colors(&self) -> Vec<SingleColor> {
+        (0..self.color_count()).map(|i| self.get_color(i)).collect()
+    }
+
+    /// The overflow policy used when the chart does not set one: the short
+    /// qualitative palettes interpolate, the others keep cycling.
+    pub(crate) const fn default_overflow(&self) -> PaletteOverflow {
+        match self {
+            ColorPalette::Tab10 | ColorPalette::Set1 | ColorPalette::Set2 | ColorPalette::Set3 => {
+                PaletteOverflow::Interpolate
+            }
+            _ => PaletteOverflow::Cycle,
+        }
+    }
+
+    /// Returns the color for `index` out of `count` categories, applying `overflow`
+    /// to indices beyond the end of the palette.
+    ///
+    /// Under `Interpolate`, the palette colors are kept for the first categories and
+    /// the remaining ones are spread evenly over the companion `ColorMap::Rainbow`.
+    pub(crate) fn get_color_with_overflow(
+        &self,
+        index: usize,
+        count: usize,
+        overflow: PaletteOverflow,
+    ) -> SingleColor {
+        let len = self.color_count();
+        if index < len || len == 0 || !matches!(overflow, PaletteOverflow::Interpolate) {
+            return self.get_color(index);
+        }
+        let extra = count.saturating_sub(len).max(1);
+        let t = ((index - len) as f64 + 0.5) / extra as f64;
+        ColorMap::Rainbow.get_color(t.clamp(0.0, 1.0))
+    }
+}
+
+/// Policy for discrete color scales with more categories than palette colors.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PaletteOverflow {
+    /// Reuses palette colors from the start, so category `i` gets color `i % len`.
+    Cycle,
+    /// Keeps the palette colors and samples the extra ones from a companion color map.
+    Interpolate,
+    /// Fails with a scale error instead of drawing ambiguous colors.
+    Error,
+}
+
+/// Implements conversion from string slices for a more ergonomic Fluent API.
+impl From<&str> for PaletteOverflow {
+    fn from(s: &str) -> Self {
+        match s.to_lowercase().as_str() {
+            "cycle" => PaletteOverflow::Cycle,
+            "error" => PaletteOverflow::Error,
+            _ => PaletteOverflow::Interpolate,
+        }
+    }
 }
 
 /// A lightweight, copyable color representation using normalized RGBA values.
@@ -701,6 +979,7 @@ impl ColorPalette {
 /// (like "#ff0000" or "rgba(255,0,0,0.5)") while maintaining a stack-allocated
 /// internal structure for performance.
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SingleColor {
     /// Pre-parsed RGBA normalized values [0.0 - 1.0].
     rgba: [Precision; 4],
@@ -0,0 +1,219 @@
+use super::{ColorPalette, SingleColor};
+
+/// The smallest CIEDE2000 difference at which two simulated colors count as
+/// distinguishable.
+///
+/// Around 2 is just noticeable side by side; categorical marks need more,
+/// since they are compared across the plot and against the legend. At 10 the
+/// Okabe-Ito and Tol Muted palettes pass for every kind, while Tab10 and Set1
+/// do not.
+pub const MIN_DELTA_E: f64 = 10.0;
+
+/// The kinds of color vision deficiency that can be simulated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CvdKind {
+    /// No functioning long-wavelength (red) cones.
+    Protanopia,
+    /// No functioning medium-wavelength (green) cones; the most common kind.
+    Deuteranopia,
+    /// No functioning short-wavelength (blue) cones.
+    Tritanopia,
+}
+
+impl CvdKind {
+    /// The full-severity simulation matrix of Machado, Oliveira & Fernandes
+    /// (2009), applied to linear RGB.
+    const fn matrix(self) -> [[f64; 3]; 3] {
+        match self {
+            CvdKind::Protanopia => [
+                [0.152286, 1.052583, -0.204868],
+                [0.114503, 0.786281, 0.099216],
+                [-0.003882, -0.048116, 1.051998],
+            ],
+            CvdKind::Deuteranopia => [
+                [0.367322, 0.860646, -0.227968],
+                [0.280085, 0.672501, 0.047413],
+                [-0.011820, 0.042940, 0.968881],
+            ],
+            CvdKind::Tritanopia => [
+                [1.255528, -0.076749, -0.178779],
+                [-0.078411, 0.930809, 0.147602],
+                [0.004733, 0.691367, 0.303900],
+            ],
+        }
+    }
+}
+
+/// Simulates how colors appear to a viewer with a color vision deficiency.
+///
+/// Accepts a built-in palette or anything convertible into a custom one,
+/// such as a list of CSS color strings. Returns one CSS `rgba()` string per
+/// color, in order; transparency is kept.
+///
+/// # Example
+/// ```rust,ignore
+/// let seen = simulate_cvd(ColorPalette::Tab10, CvdKind::Deuteranopia);
+/// let seen = simulate_cvd(["#e41a1c", "#4daf4a"], CvdKind::Protanopia);
+/// ```
+pub fn simulate_cvd(colors: impl Into<ColorPalette>, kind: CvdKind) -> Vec<String> {
+    colors
+        .into()
+        .colors()
+        .iter()
+        .map(|c| simulate(c, kind).to_css_string())
+        .collect()
+}
+
+/// Finds the pairs of colors that become hard to tell apart under a color
+/// vision deficiency.
+///
+/// Every pair is simulated with [`simulate_cvd`] and compared by its
+/// CIEDE2000 difference; pairs below [`MIN_DELTA_E`] are returned as
+/// `(i, j, delta_e)` with `i < j`, most similar first. An empty result means
+/// all colors stay distinguishable, which makes the check easy to assert in
+/// tests.
+///
+/// # Example
+/// ```rust,ignore
+/// assert!(check_distinguishable(ColorPalette::OkabeIto, CvdKind::Deuteranopia).is_empty());
+/// ```
+pub fn check_distinguishable(
+    colors: impl Into<ColorPalette>,
+    kind: CvdKind,
+) -> Vec<(usize, usize, f64)> {
+    let lab: Vec<[f64; 3]> = colors
+        .into()
+        .colors()
+        .iter()
+        .map(|c| to_lab(&simulate(c, kind)))
+        .collect();
+
+    let mut pairs = Vec::new();
+    for i in 0..lab.len() {
+        for j in i + 1..lab.len() {
+            let delta_e = ciede2000(&lab[i], &lab[j]);
+            if delta_e < MIN_DELTA_E {
+                pairs.push((i, j, delta_e));
+            }
+        }
+    }
+    pairs.sort_by(|a, b| a.2.total_cmp(&b.2));
+    pairs
+}
+
+fn simulate(color: &SingleColor, kind: CvdKind) -> SingleColor {
+    let [r, g, b, a] = color.rgba().map(|c| c as f64);
+    let linear = [to_linear(r), to_linear(g), to_linear(b)];
+    let m = kind.matrix();
+    let out: [f64; 3] = std::array::from_fn(|i| {
+        let v = m[i][0] * linear[0] + m[i][1] * linear[1] + m[i][2] * linear[2];
+        from_linear(v.clamp(0.0, 1.0))
+    });
+    SingleColor::from_rgba(out[0], out[1], out[2], a)
+}
+
+fn to_linear(c: f64) -> f64 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn from_linear(c: f64) -> f64 {
+    if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// Converts an sRGB color to CIELAB under the D65 white point.
+fn to_lab(color: &SingleColor) -> [f64; 3] {
+    let [r, g, b, _] = color.rgba().map(|c| to_linear(c as f64));
+    let x = (0.4124564 * r + 0.3575761 * g + 0.1804375 * b) / 0.95047;
+    let y = 0.2126729 * r + 0.7151522 * g + 0.0721750 * b;
+    let z = (0.0193339 * r + 0.1191920 * g + 0.9503041 * b) / 1.08883;
+
+    let f = |t: f64| {
+        if t > 216.0 / 24389.0 {
+            t.cbrt()
+        } else {
+            (24389.0 / 27.0 * t + 16.0) / 116.0
+        }
+    };
+    let (fx, fy, fz) = (f(x), f(y), f(z));
+    [116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz)]
+}
+
+/// The CIEDE2000 color difference of two CIELAB colors.
+fn ciede2000(lab1: &[f64; 3], lab2: &[f64; 3]) -> f64 {
+    let [l1, a1, b1] = *lab1;
+    let [l2, a2, b2] = *lab2;
+
+    let c_bar = (a1.hypot(b1) + a2.hypot(b2)) / 2.0;
+    let g = 0.5 * (1.0 - (c_bar.powi(7) / (c_bar.powi(7) + 25f64.powi(7))).sqrt());
+    let (a1p, a2p) = ((1.0 + g) * a1, (1.0 + g) * a2);
+    let (c1p, c2p) = (a1p.hypot(b1), a2p.hypot(b2));
+    let hue = |b: f64, a: f64| {
+        if a == 0.0 && b == 0.0 {
+            0.0
+        } else {
+            b.atan2(a).to_degrees().rem_euclid(360.0)
+        }
+    };
+    let (h1p, h2p) = (hue(b1, a1p), hue(b2, a2p));
+
+    let dl = l2 - l1;
+    let dc = c2p - c1p;
+    let dh = if c1p * c2p == 0.0 {
+        0.0
+    } else if (h2p - h1p).abs() <= 180.0 {
+        h2p - h1p
+    } else if h2p <= h1p {
+        h2p - h1p + 360.0
+    } else {
+        h2p - h1p - 360.0
+    };
+    let dh_big = 2.0 * (c1p * c2p).sqrt() * (dh / 2.0).to_radians().sin();
+
+    let l_bar = (l1 + l2) / 2.0;
+    let c_bar_p = (c1p + c2p) / 2.0;
+    let h_bar = if c1p * c2p == 0.0 {
+        h1p + h2p
+    } else if (h1p - h2p).abs() <= 180.0 {
+        (h1p + h2p) / 2.0
+    } else if h1p + h2p < 360.0 {
+        (h1p + h2p + 360.0) / 2.0
+    } else {
+        (h1p + h2p - 360.0) / 2.0
+    };
+
+    let t = 1.0 - 0.17 * (h_bar - 30.0).to_radians().cos()
+        + 0.24 * (2.0 * h_bar).to_radians().cos()
+        + 0.32 * (3.0 * h_bar + 6.0).to_radians().cos()
+        - 0.20 * (4.0 * h_bar - 63.0).to_radians().cos();
+    let d_theta = 30.0 * (-((h_bar - 275.0) / 25.0).powi(2)).exp();
+    let r_c = 2.0 * (c_bar_p.powi(7) / (c_bar_p.powi(7) + 25f64.powi(7))).sqrt();
+    let s_l = 1.0 + 0.015 * (l_bar - 50.0).powi(2) / (20.0 + (l_bar - 50.0).powi(2)).sqrt();
+    let s_c = 1.0 + 0.045 * c_bar_p;
+    let s_h = 1.0 + 0.015 * c_bar_p * t;
+    let r_t = -(2.0 * d_theta).to_radians().sin() * r_c;
+
+    let (tl, tc, th) = (dl / s_l, dc / s_c, dh_big / s_h);
+    (tl * tl + tc * tc + th * th + r_t * tc * th).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ciede2000_matches_reference_pairs() {
+        // Pairs 1 and 17 of Sharma, Wu & Dalal's CIEDE2000 test data.
+        let d1 = ciede2000(&[50.0, 2.6772, -79.7751], &[50.0, 0.0, -82.7485]);
+        assert!((d1 - 2.0425).abs() < 1e-4, "{}", d1);
+        let d17 = ciede2000(&[50.0, 2.5, 0.0], &[73.0, 25.0, -18.0]);
+        assert!((d17 - 27.1492).abs() < 1e-4, "{}", d17);
+    }
+}
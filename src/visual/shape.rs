@@ -3,6 +3,7 @@
 /// integer IDs for GPU-accelerated rendering.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)] // Added Copy/Eq for easier usage
 #[derive(Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PointShape {
     #[default]
     Circle = 0,
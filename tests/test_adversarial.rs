@@ -0,0 +1,209 @@
+use charton::encode::Channel;
+use charton::error::ChartonError;
+use charton::prelude::*;
+use charton::scale::ScaleDomain;
+use std::error::Error;
+
+/// A frame whose numeric columns hold nothing but nulls.
+fn all_null_dataset() -> Result<Dataset, ChartonError> {
+    Dataset::new()
+        .with_column("group", vec!["a", "b", "c"])?
+        .with_column("x", vec![None::<f64>, None, None])?
+        .with_column("y", vec![None::<f64>, None, None])
+}
+
+/// A frame with the right schema but no rows, so every group is empty.
+fn empty_dataset() -> Result<Dataset, ChartonError> {
+    Dataset::new()
+        .with_column("group", Vec::<&str>::new())?
+        .with_column("x", Vec::<f64>::new())?
+        .with_column("y", Vec::<f64>::new())
+}
+
+#[test]
+fn test_all_null_columns_do_not_panic() -> Result<(), Box<dyn Error>> {
+    let ds = all_null_dataset()?;
+
+    chart!(&ds)?
+        .mark_point()?
+        .encode((alt::x("x"), alt::y("y")))?
+        .to_svg()?;
+    chart!(&ds)?
+        .mark_bar()?
+        .encode((alt::x("group"), alt::y("y"), alt::color("group")))?
+        .to_svg()?;
+    chart!(&ds)?
+        .mark_area()?
+        .encode((alt::x("x"), alt::y("y"), alt::color("group")))?
+        .to_svg()?;
+    chart!(&ds)?
+        .mark_boxplot()?
+        .encode((alt::x("group"), alt::y("y")))?
+        .to_svg()?;
+    chart!(&ds)?
+        .mark_rule()?
+        .encode((alt::x("x"), alt::y("y"), alt::y2("y")))?
+        .to_svg()?;
+
+    // Binning has no range to work with: this must be reported, not panic.
+    let hist = chart!(&ds)?
+        .mark_hist()?
+        .encode((alt::x("x"), alt::y("count")))?
+        .to_svg();
+    assert!(matches!(hist, Err(ChartonError::Data(_))));
+
+    Ok(())
+}
+
+#[test]
+fn test_empty_groups_do_not_panic() -> Result<(), Box<dyn Error>> {
+    let ds = empty_dataset()?;
+
+    chart!(&ds)?
+        .mark_bar()?
+        .encode((alt::x("group"), alt::y("y"), alt::color("group")))?
+        .to_svg()?;
+    chart!(&ds)?
+        .mark_errorbar()?
+        .encode((alt::x("group"), alt::y("y")))?
+        .to_svg()?;
+    chart!(&ds)?
+        .mark_line()?
+        .configure_line(|l| l.with_interpolation("step"))
+        .encode((alt::x("x"), alt::y("y"), alt::color("group")))?
+        .to_svg()?;
+
+    chart!(&ds)?
+        .mark_hist()?
+        .encode((alt::x("x"), alt::y("count"), alt::color("group")))?
+        .to_svg()?;
+
+    Ok(())
+}
+
+#[test]
+fn test_nan_in_cumulative_window() -> Result<(), Box<dyn Error>> {
+    let ds = Dataset::new().with_column("value", vec![3.0, f64::NAN, 1.0, 2.0])?;
+
+    // NaN used to abort the sort of the cumulative distribution.
+    let svg = chart!(ds)?
+        .transform_window(WindowTransform::new(WindowFieldDef::new(
+            "value",
+            WindowOnlyOp::CumeDist,
+            "ecdf",
+        )))?
+        .mark_line()?
+        .encode((alt::x("value"), alt::y("ecdf")))?
+        .to_svg()?;
+    assert!(svg.contains("<path") || svg.contains("<polyline"));
+
+    Ok(())
+}
+
+#[test]
+fn test_missing_column_is_an_error() -> Result<(), Box<dyn Error>> {
+    let ds = all_null_dataset()?;
+
+    // This used to reach the layout stage and panic on the unresolved Y scale.
+    let result = chart!(&ds)?
+        .mark_point()?
+        .encode((alt::x("x"), alt::y("does_not_exist")));
+    assert!(matches!(result, Err(ChartonError::Data(_))));
+
+    Ok(())
+}
+
+/// The x positions of the point marks, in document order.
+fn circle_xs(svg: &str) -> Vec<f64> {
+    svg.split(r#"<circle cx=""#)
+        .skip(1)
+        .filter_map(|c| c.split('"').next()?.parse().ok())
+        .collect()
+}
+
+#[test]
+fn test_tiny_magnitude_domain() -> Result<(), Box<dyn Error>> {
+    let ds = Dataset::new()
+        .with_column("x", vec![1e-15, 1.5e-15, 2e-15])?
+        .with_column("y", vec![1.0, 2.0, 3.0])?;
+
+    let chart = chart!(ds)?
+        .mark_point()?
+        .encode((alt::x("x"), alt::y("y")))?;
+
+    // The range is real, however small: it must not be padded away.
+    let x_spec = LayeredChart::from(chart.clone())
+        .resolve_scale_spec(Channel::X)?
+        .expect("x is encoded");
+    assert!(
+        matches!(x_spec.domain, ScaleDomain::Continuous(min, max) if min == 1e-15 && max == 2e-15)
+    );
+
+    let svg = chart.to_svg()?;
+    assert!(!svg.contains("NaN"));
+    let xs = circle_xs(&svg);
+    assert_eq!(xs.len(), 3);
+    assert!(xs[1] - xs[0] > 10.0 && xs[2] - xs[1] > 10.0, "{:?}", xs);
+
+    Ok(())
+}
+
+#[test]
+fn test_identical_layer_does_not_pad_ranged_layer() -> Result<(), Box<dyn Error>> {
+    let flat = Dataset::new()
+        .with_column("x", vec![5.0, 5.0])?
+        .with_column("y", vec![1.0, 2.0])?;
+    let ranged = Dataset::new()
+        .with_column("x", vec![0.0, 10.0])?
+        .with_column("y", vec![1.0, 2.0])?;
+
+    let layered = chart!(flat)?
+        .mark_point()?
+        .encode((alt::x("x"), alt::y("y")))?
+        .and(
+            chart!(ranged)?
+                .mark_point()?
+                .encode((alt::x("x"), alt::y("y")))?,
+        );
+
+    let x_spec = layered
+        .resolve_scale_spec(Channel::X)?
+        .expect("x is encoded");
+    assert!(
+        matches!(x_spec.domain, ScaleDomain::Continuous(min, max) if min == 0.0 && max == 10.0)
+    );
+    assert!(!layered.to_svg()?.contains("NaN"));
+
+    Ok(())
+}
+
+#[test]
+fn test_identical_values_pad_by_magnitude() -> Result<(), Box<dyn Error>> {
+    let domain_of = |value: f64| -> Result<ScaleDomain, Box<dyn Error>> {
+        let ds = Dataset::new()
+            .with_column("x", vec![value, value])?
+            .with_column("y", vec![1.0, 2.0])?;
+        let chart = chart!(ds)?
+            .mark_point()?
+            .encode((alt::x("x"), alt::y("y")))?;
+        assert!(!chart.clone().to_svg()?.contains("NaN"));
+        Ok(LayeredChart::from(chart)
+            .resolve_scale_spec(Channel::X)?
+            .expect("x is encoded")
+            .domain)
+    };
+
+    // Negative values keep their sign and pad by half their size.
+    assert!(
+        matches!(domain_of(-4.0)?, ScaleDomain::Continuous(min, max) if min == -6.0 && max == -2.0)
+    );
+    assert!(
+        matches!(domain_of(3e-15)?, ScaleDomain::Continuous(min, max) if min == 1.5e-15 && max == 4.5e-15)
+    );
+    // Zero has no magnitude and falls back to a unit domain.
+    assert!(
+        matches!(domain_of(0.0)?, ScaleDomain::Continuous(min, max) if min == -0.5 && max == 0.5)
+    );
+
+    Ok(())
+}
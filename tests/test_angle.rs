@@ -0,0 +1,128 @@
+use charton::error::ChartonError;
+use charton::prelude::*;
+use std::error::Error;
+
+/// Returns the vertices of every `<polygon>` in an SVG document.
+fn polygons(svg: &str) -> Vec<Vec<(f64, f64)>> {
+    svg.split("<polygon points=\"")
+        .skip(1)
+        .map(|p| {
+            p.split('"')
+                .next()
+                .unwrap_or_default()
+                .split(' ')
+                .filter_map(|xy| {
+                    let (x, y) = xy.split_once(',')?;
+                    Some((x.parse().ok()?, y.parse().ok()?))
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn test_angle_1() -> Result<(), Box<dyn Error>> {
+    // Wind at four stations, each turned a further quarter clockwise.
+    let station = vec![1.0, 2.0, 3.0, 4.0];
+    let speed = vec![4.0, 6.0, 5.0, 7.0];
+    let direction = vec![0.0, 90.0, 180.0, 270.0];
+
+    let chart = chart!(station, speed, direction)?
+        .mark_point()?
+        .configure_point(|m| m.with_shape("triangle").with_size(10.0))
+        .encode((alt::x("station"), alt::y("speed"), alt::angle("direction")))?
+        .with_title("Wind direction");
+
+    let svg = chart.to_svg()?;
+    let triangles = polygons(&svg);
+    assert_eq!(triangles.len(), 4);
+
+    // The apex of an unrotated triangle points up; each station turns it a
+    // further quarter clockwise: up, right, down, left.
+    let expected = [(0.0, -1.0), (1.0, 0.0), (0.0, 1.0), (-1.0, 0.0)];
+    for (vertices, (ex, ey)) in triangles.iter().zip(expected) {
+        let cx = vertices.iter().map(|v| v.0).sum::<f64>() / 3.0;
+        let cy = vertices.iter().map(|v| v.1).sum::<f64>() / 3.0;
+        let (ax, ay) = vertices[0];
+        let len = (ax - cx).hypot(ay - cy);
+        assert!(((ax - cx) / len - ex).abs() < 1e-3, "{:?}", vertices);
+        assert!(((ay - cy) / len - ey).abs() < 1e-3, "{:?}", vertices);
+    }
+
+    chart.save("./tests/angle_1.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_angle_text_and_squares() -> Result<(), Box<dyn Error>> {
+    let x = vec![1.0, 2.0];
+    let y = vec![1.0, 2.0];
+    let label = vec!["tilted", "level"];
+    let tilt = vec![Some(-45.0), None];
+
+    let ds = Dataset::new()
+        .with_column("x", x)?
+        .with_column("y", y)?
+        .with_column("label", label)?
+        .with_column("tilt", tilt)?;
+
+    // Text turns about its anchor; a null angle leaves the label level.
+    let svg = chart!(&ds)?
+        .mark_text()?
+        .encode((
+            alt::x("x"),
+            alt::y("y"),
+            alt::text("label"),
+            alt::angle("tilt"),
+        ))?
+        .to_svg()?;
+    let texts: Vec<&str> = svg
+        .split("<text")
+        .skip(1)
+        .filter(|t| t.contains(">tilted<") || t.contains(">level<"))
+        .collect();
+    assert_eq!(texts.len(), 2);
+    assert!(texts[0].contains(r#"transform="rotate(-45 "#));
+    assert!(texts[1].contains(r#"transform="rotate(0 "#));
+
+    // A rotated square becomes a polygon; an unrotated one stays a rect.
+    let svg = chart!(&ds)?
+        .mark_point()?
+        .configure_point(|m| m.with_shape("square"))
+        .encode((alt::x("x"), alt::y("y"), alt::angle("tilt")))?
+        .to_svg()?;
+    assert_eq!(polygons(&svg).len(), 1);
+    assert_eq!(polygons(&svg)[0].len(), 4);
+
+    Ok(())
+}
+
+#[test]
+fn test_angle_invalid_column() -> Result<(), Box<dyn Error>> {
+    let x = vec![1.0, 2.0];
+    let y = vec![1.0, 2.0];
+    let heading = vec!["N", "E"];
+
+    let err = chart!(x, y, heading)?.mark_point()?.encode((
+        alt::x("x"),
+        alt::y("y"),
+        alt::angle("heading"),
+    ));
+    assert!(matches!(err, Err(ChartonError::Encoding(msg)) if msg.contains("heading")));
+
+    let err = chart!(x, y)?
+        .mark_point()?
+        .encode((alt::x("x"), alt::y("y"), alt::angle("missing")));
+    assert!(matches!(err, Err(ChartonError::Data(_))));
+
+    // Only point and text marks can be rotated.
+    let tilt = vec![0.0, 45.0];
+    let err =
+        chart!(x, y, tilt)?
+            .mark_bar()?
+            .encode((alt::x("x"), alt::y("y"), alt::angle("tilt")));
+    assert!(matches!(err, Err(ChartonError::Encoding(msg)) if msg.contains("bar")));
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use charton::prelude::*;
+use std::error::Error;
+
+/// Returns the value of attribute `name` on the first SVG element line containing `needle`.
+fn attr(svg: &str, needle: &str, name: &str) -> Option<f64> {
+    let line = svg.lines().find(|l| l.contains(needle))?;
+    line.split(&format!(r#" {}=""#, name))
+        .nth(1)?
+        .split('"')
+        .next()?
+        .parse()
+        .ok()
+}
+
+#[test]
+fn test_annotation_1() -> Result<(), Box<dyn Error>> {
+    let ds = load_dataset("mtcars")?;
+
+    let chart = chart!(ds)?
+        .mark_point()?
+        .encode((alt::x("wt"), alt::y("mpg")))?
+        .with_annotation(2.2, 12.0, "plain label")
+        .annotate(
+            Annotation::new(1.835, 33.9, "Toyota Corolla")
+                .with_offset(40.0, 25.0)
+                .with_anchor("start")
+                .with_arrow(true)
+                .with_color("firebrick"),
+        )
+        .with_title("Annotated scatter");
+
+    let svg = chart.to_svg()?;
+    assert!(svg.contains(">plain label</text>"));
+    assert!(svg.contains(r#"text-anchor="start""#));
+    // The arrow head is the only polygon of the chart.
+    assert_eq!(svg.matches("<polygon").count(), 1);
+
+    chart.save("./tests/annotation_1.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_annotation_follows_domain() -> Result<(), Box<dyn Error>> {
+    let x = [1.0, 2.0, 3.0];
+    let y = [1.0, 4.0, 9.0];
+
+    // Without an offset the label sits exactly on the annotated point.
+    for y_max in [10.0, 20.0] {
+        let svg = chart!(x, y)?
+            .mark_point()?
+            .encode((alt::x("x"), alt::y("y")))?
+            .with_y_domain(0.0, y_max)
+            .with_annotation(3.0, 9.0, "peak")
+            .to_svg()?;
+
+        let point = svg.lines().filter(|l| l.starts_with("<circle"));
+        let (cx, cy) = point
+            .filter_map(|l| Some((attr(l, "<circle", "cx")?, attr(l, "<circle", "cy")?)))
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .ok_or("no points")?;
+        let tx = attr(&svg, ">peak<", "x").ok_or("no label")?;
+        let ty = attr(&svg, ">peak<", "y").ok_or("no label")?;
+        assert!((cx - tx).abs() < 1e-3 && (cy - ty).abs() < 1e-3);
+    }
+
+    // A point outside the domains is not drawn.
+    let svg = chart!(x, y)?
+        .mark_point()?
+        .encode((alt::x("x"), alt::y("y")))?
+        .with_annotation(3.0, 50.0, "off the chart")
+        .to_svg()?;
+    assert!(!svg.contains("off the chart"));
+
+    Ok(())
+}
@@ -76,3 +76,128 @@ fn test_area_2() -> Result<(), Box<dyn Error>> {
 
     Ok(())
 }
+
+#[test]
+fn test_area_difference() -> Result<(), Box<dyn Error>> {
+    // Deliberately unsorted x; sorted, the series cross between x=1 and x=2
+    // and touch exactly at x=4.
+    let x = vec![3.0, 1.0, 5.0, 4.0, 2.0];
+    let revenue = vec![1.0, 3.0, 0.0, 2.0, 2.0];
+    let cost = vec![2.0, 1.0, 1.0, 2.0, 3.0];
+
+    let gap = chart!(x, revenue, cost)?
+        .mark_area()?
+        .configure_area(|a| {
+            a.with_positive_color("#00aa00")
+                .with_negative_color("#cc0000")
+                .with_opacity(0.5)
+        })
+        .encode((alt::x("x"), alt::y("revenue"), alt::y2("cost")))?;
+    let revenue_line = chart!(x, revenue)?
+        .mark_line()?
+        .encode((alt::x("x"), alt::y("revenue")))?;
+    let cost_line = chart!(x, cost)?
+        .mark_line()?
+        .encode((alt::x("x"), alt::y("cost")))?;
+
+    let chart = gap.and(revenue_line).and(cost_line);
+    let svg = chart.to_svg()?;
+
+    // One region above the cost line, two below it (split at the touching point).
+    assert_eq!(svg.matches(r#"fill="rgba(0,170,0,1.000)""#).count(), 1);
+    assert_eq!(svg.matches(r#"fill="rgba(204,0,0,1.000)""#).count(), 2);
+
+    chart.save("./tests/area_3.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_area_stack_normalize() -> Result<(), Box<dyn Error>> {
+    let year = vec![2020, 2021, 2022, 2020, 2021, 2022, 2020, 2021, 2022];
+    let share = vec![10.0, 20.0, 30.0, 30.0, 20.0, 10.0, 5.0, 5.0, 5.0];
+    let source = vec![
+        "Solar", "Solar", "Solar", "Wind", "Wind", "Wind", "Hydro", "Hydro", "Hydro",
+    ];
+
+    let chart = chart!(year, share, source)?
+        .mark_area()?
+        .encode((
+            alt::x("year"),
+            alt::y("share").stack_normalize(),
+            alt::color("source"),
+        ))?
+        .with_size(600, 400)
+        .with_title("Energy Mix");
+
+    let svg = chart.to_svg()?;
+    for label in ["0%", "20%", "100%"] {
+        assert!(
+            svg.contains(&format!(">{}</text>", label)),
+            "missing {}",
+            label
+        );
+    }
+
+    // The layers fill the panel: the top band ends at the top edge of the clip area.
+    let clip_top = svg
+        .split("<clipPath")
+        .nth(1)
+        .and_then(|s| s.split("y=\"").nth(1))
+        .and_then(|s| s.split('"').next())
+        .ok_or("missing clip path")?;
+    assert!(svg.contains(&format!(" {} L ", clip_top)));
+
+    chart.save("./tests/area_4.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_area_date_axis() -> Result<(), Box<dyn Error>> {
+    use charton::prelude::ctime::{Date, Month};
+
+    let start = Date::from_calendar_date(2024, Month::January, 1)?;
+    let day: Vec<Date> = (0..90).map(|i| start + ctime::Duration::days(i)).collect();
+    let value: Vec<f64> = (0..90).map(|i| 2.0 + (i as f64 / 10.0).sin()).collect();
+
+    // Dates keep their calendar positions through the stacking transform.
+    let svg = chart!(day, value)?
+        .mark_area()?
+        .encode((alt::x("day"), alt::y("value")))?
+        .to_svg()?;
+    assert!(svg.contains(">2024-02<"));
+    assert!(svg.contains(">2024-03<"));
+    assert!(!svg.contains(">1970"));
+
+    Ok(())
+}
+
+#[test]
+fn test_area_stack_labels() -> Result<(), Box<dyn Error>> {
+    let year = vec![2020, 2021, 2022, 2020, 2021, 2022, 2020, 2021, 2022];
+    let share = vec![10.0, 20.0, 30.0, 30.0, 20.0, 10.0, 5.0, 5.0, 5.0];
+    let source = vec![
+        "Solar", "Solar", "Solar", "Wind", "Wind", "Wind", "Hydro", "Hydro", "Hydro",
+    ];
+
+    let svg = chart!(year, share, source)?
+        .mark_area()?
+        .configure_area(|a| {
+            a.with_stack_label_parts(&[StackLabelPart::Segment, StackLabelPart::Percent])
+        })
+        .encode((
+            alt::x("year"),
+            alt::y("share").with_stack("stacked"),
+            alt::color("source"),
+        ))?
+        .with_size(600, 400)
+        .to_svg()?;
+
+    // One label per band, where it is thickest.
+    assert_eq!(svg.matches(" · ").count(), 3);
+    assert_eq!(svg.matches(">30 · 66.67%<").count(), 2);
+    assert!(svg.contains(">5 · 11.11%<"));
+
+    Ok(())
+}
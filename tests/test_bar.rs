@@ -36,3 +36,179 @@ fn test_bar_1() -> Result<(), Box<dyn Error>> {
 
     Ok(())
 }
+
+#[test]
+fn test_bar_numeric_categories() -> Result<(), Box<dyn Error>> {
+    let cluster = vec![10i64, 2, 7, 2, 0, 10, 7, 1];
+    let size = vec![3.0, 4.0, 5.0, 2.0, 1.0, 6.0, 2.5, 3.3];
+
+    // Integer ids on a discrete axis, without casting them to String first.
+    let chart = chart!(cluster, size)?.mark_bar()?.encode((
+        alt::x("cluster").with_scale(Scale::Discrete),
+        alt::y("size"),
+        alt::color("cluster").with_scale(Scale::Discrete),
+    ))?;
+
+    let svg = chart.to_svg()?;
+    // Categories follow numeric order (not "10" before "2") on both the axis and the legend.
+    let labels: Vec<&str> = svg
+        .split("</text>")
+        .filter_map(|t| t.rsplit('>').next())
+        .filter(|t| t.parse::<i64>().is_ok())
+        .collect();
+    assert_eq!(&labels[..5], &["0", "1", "2", "7", "10"]);
+    assert_eq!(&labels[labels.len() - 5..], &["0", "1", "2", "7", "10"]);
+
+    chart.save("./tests/bar_2.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_bar_stack_labels() -> Result<(), Box<dyn Error>> {
+    let quarter = ["Q1", "Q1", "Q1", "Q2", "Q2", "Q2"];
+    let sales = [10.0, 20.0, 30.0, 25.0, 25.0, 50.0];
+    let product = ["A", "B", "C", "A", "B", "C"];
+
+    let chart = chart!(quarter, sales, product)?
+        .mark_bar()?
+        .configure_bar(|b| {
+            b.with_stack_label_parts(&[
+                StackLabelPart::Segment,
+                StackLabelPart::Cumulative,
+                StackLabelPart::Total,
+                StackLabelPart::Percent,
+            ])
+        })
+        .encode((
+            alt::x("quarter"),
+            alt::y("sales").with_stack("stacked"),
+            alt::color("product"),
+        ))?
+        .with_size(600, 400)
+        .with_title("Stacked Bar Labels");
+
+    let svg = chart.to_svg()?;
+    assert!(svg.contains(">20 · Σ30 · of 60 · 33.33%<"));
+    assert!(svg.contains(">50 · Σ100 · of 100 · 50%<"));
+
+    chart.save("./tests/bar_3.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_bar_dodge_within_stack() -> Result<(), Box<dyn Error>> {
+    let month = [
+        "Jan", "Jan", "Jan", "Jan", "Jan", "Jan", "Feb", "Feb", "Feb", "Feb", "Feb", "Feb",
+    ];
+    let region = [
+        "North", "North", "North", "South", "South", "South", "North", "North", "North", "South",
+        "South", "South",
+    ];
+    let product = ["A", "B", "C", "A", "B", "C", "A", "B", "C", "A", "B", "C"];
+    let sales = [
+        10.0, 20.0, 10.0, 5.0, 10.0, 5.0, 5.0, 5.0, 5.0, 10.0, 10.0, 10.0,
+    ];
+
+    let chart = chart!(month, region, product, sales)?
+        .mark_bar()?
+        .configure_bar(|b| b.with_stack_label_parts(&[StackLabelPart::Total]))
+        .encode((
+            alt::x("month").with_dodge_by("region"),
+            alt::y("sales").with_stack("stacked"),
+            alt::color("product"),
+        ))?
+        .with_size(600, 400)
+        .with_title("Sales by Region, Stacked by Product");
+
+    let svg = chart.to_svg()?;
+
+    // Each (month, region) pair is its own stack: totals are per sub-bar, not per month.
+    for total in ["of 40", "of 20", "of 15", "of 30"] {
+        assert!(svg.contains(&format!(">{}<", total)), "missing {}", total);
+    }
+    assert!(!svg.contains(">of 60<"));
+    assert!(!svg.contains(">of 45<"));
+
+    // Every sub-bar is named once, and the products still get a legend.
+    assert_eq!(svg.matches(">North<").count(), 2);
+    assert_eq!(svg.matches(">South<").count(), 2);
+    assert!(svg.contains(">product<"));
+
+    chart.save("./tests/bar_4.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_bar_zero_line() -> Result<(), Box<dyn Error>> {
+    let quarter = ["Q1", "Q2", "Q3", "Q4"];
+    let profit = [12.0, -8.0, 5.0, -3.0];
+
+    let zero_lines = |svg: &str, dash: &str| -> Vec<String> {
+        svg.lines()
+            .filter(|l| l.contains(&format!(r#"stroke-dasharray="{}""#, dash)))
+            .map(str::to_string)
+            .collect()
+    };
+
+    // Hidden by default.
+    let plain = chart!(quarter, profit)?
+        .mark_bar()?
+        .encode((alt::x("quarter"), alt::y("profit")))?
+        .to_svg()?;
+    assert!(zero_lines(&plain, "4,4").is_empty());
+
+    // Shown in the theme's axis color, which follows a dark theme.
+    let chart = chart!(quarter, profit)?
+        .mark_bar()?
+        .configure_bar(|b| b.with_zero_line(true))
+        .encode((alt::x("quarter"), alt::y("profit")))?
+        .with_theme(Theme::dark())
+        .with_title("Quarterly Profit");
+    let svg = chart.to_svg()?;
+    let lines = zero_lines(&svg, "4,4");
+    assert_eq!(lines.len(), 1);
+    assert!(lines[0].contains(r#"stroke="rgba(176,176,176,1.000)""#));
+
+    // Drawn over the bars by default, beneath them on request.
+    let bar_at = |svg: &str| svg.find("Z\" fill=\"rgba(70,130,180").unwrap_or(0);
+    let line_at = |svg: &str| svg.find("stroke-dasharray").unwrap_or(0);
+    assert!(line_at(&svg) > bar_at(&svg));
+    let under = chart!(quarter, profit)?
+        .mark_bar()?
+        .configure_bar(|b| {
+            b.with_zero_line(true)
+                .with_zero_line_position(ZeroLinePosition::Under)
+        })
+        .encode((alt::x("quarter"), alt::y("profit")))?
+        .to_svg()?;
+    assert!(line_at(&under) < bar_at(&under));
+
+    // Custom style.
+    let styled = chart!(quarter, profit)?
+        .mark_bar()?
+        .configure_bar(|b| {
+            b.with_zero_line(true)
+                .with_zero_line_style("red", 2.0, vec![6.0, 2.0])
+        })
+        .encode((alt::x("quarter"), alt::y("profit")))?
+        .to_svg()?;
+    let lines = zero_lines(&styled, "6,2");
+    assert_eq!(lines.len(), 1);
+    assert!(lines[0].contains(r#"stroke="rgba(255,0,0,1.000)" stroke-width="2.000""#));
+
+    // The line is independent of the domain: without zero in it, nothing is drawn.
+    let revenue = [120.0, 140.0, 130.0, 150.0];
+    let no_zero = chart!(quarter, revenue)?
+        .mark_bar()?
+        .configure_bar(|b| b.with_zero_line(true))
+        .encode((alt::x("quarter"), alt::y("revenue").with_zero(false)))?
+        .to_svg()?;
+    assert!(zero_lines(&no_zero, "4,4").is_empty());
+
+    chart.save("./tests/bar_5.svg")?;
+
+    Ok(())
+}
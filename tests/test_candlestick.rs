@@ -0,0 +1,67 @@
+use charton::error::ChartonError;
+use charton::prelude::*;
+use std::error::Error;
+
+#[test]
+fn test_candlestick_1() -> Result<(), Box<dyn Error>> {
+    let day: Vec<f64> = (1..=12).map(|d| d as f64).collect();
+    let open = vec![
+        100.0, 102.5, 101.0, 104.0, 107.5, 106.0, 103.0, 104.5, 108.0, 111.0, 109.5, 112.0,
+    ];
+    let close = vec![
+        102.5, 101.0, 104.0, 107.5, 106.0, 103.0, 104.5, 108.0, 111.0, 109.5, 112.0, 115.5,
+    ];
+    let high = vec![
+        103.5, 104.0, 105.0, 108.5, 109.0, 107.0, 105.5, 109.0, 112.5, 112.0, 113.0, 116.0,
+    ];
+    let low = vec![
+        99.0, 100.5, 100.0, 103.5, 105.0, 102.0, 101.5, 104.0, 107.0, 108.5, 109.0, 111.5,
+    ];
+
+    let chart = chart!(day, open, close, high, low)?
+        .mark_candlestick()?
+        .configure_candlestick(|c| {
+            c.with_bull_color("seagreen")
+                .with_bear_color("crimson")
+                .with_candle_width(0.6)
+        })
+        .encode((
+            alt::x("day"),
+            alt::open("open"),
+            alt::high("high"),
+            alt::low("low"),
+            alt::close("close"),
+        ))?;
+
+    let svg = chart.to_svg()?;
+    // Eight rising and four falling days.
+    assert_eq!(svg.matches("fill=\"rgba(46,139,87,1.000)\"").count(), 8);
+    assert_eq!(svg.matches("fill=\"rgba(220,20,60,1.000)\"").count(), 4);
+
+    chart.save("./tests/candlestick_1.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_candlestick_requires_ohlc() {
+    let day = ["Mon", "Tue"];
+    let open = [1.0, 2.0];
+    let high = [2.5, 3.0];
+    let low = [0.5, 1.5];
+
+    let result = chart!(day, open, high, low).and_then(|c| {
+        c.mark_candlestick()?.encode((
+            alt::x("day"),
+            alt::open("open"),
+            alt::high("high"),
+            alt::low("low"),
+        ))
+    });
+
+    match result {
+        Err(ChartonError::Encoding(msg)) => assert!(msg.contains("missing: close")),
+        Err(e) => panic!("unexpected error: {e}"),
+        Ok(_) => panic!("a candlestick chart without a close encoding must be rejected"),
+    }
+}
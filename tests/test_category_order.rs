@@ -0,0 +1,139 @@
+use charton::error::ChartonError;
+use charton::prelude::*;
+use std::error::Error;
+
+/// Returns the x position of the tick label showing `label`.
+fn label_x(svg: &str, label: &str) -> Option<f64> {
+    let end = svg.find(&format!(">{}</text>", label))?;
+    let start = svg[..end].rfind("<text x=\"")? + "<text x=\"".len();
+    svg[start..].split('"').next()?.parse().ok()
+}
+
+/// Returns the horizontal centers of the filled bars, in drawing order.
+fn bar_centers(svg: &str) -> Vec<f64> {
+    svg.lines()
+        .filter(|l| l.starts_with("<path d=\"M") && l.contains(" Z\""))
+        .map(|l| {
+            let xs: Vec<f64> = l["<path d=\"".len()..]
+                .split(['M', 'L', 'Z'])
+                .filter_map(|p| p.split_whitespace().next()?.parse().ok())
+                .collect();
+            let min = xs.iter().copied().fold(f64::MAX, f64::min);
+            let max = xs.iter().copied().fold(f64::MIN, f64::max);
+            (min + max) / 2.0
+        })
+        .collect()
+}
+
+#[test]
+fn test_x_category_order() -> Result<(), Box<dyn Error>> {
+    const WEEK: [&str; 5] = ["Mon", "Tue", "Wed", "Thu", "Fri"];
+    let day = ["Wed", "Mon", "Fri", "Sat"];
+    let sales = [3.0, 1.0, 5.0, 2.0];
+
+    let chart = chart!(day, sales)?
+        .mark_bar()?
+        .encode((alt::x("day"), alt::y("sales")))?
+        .with_x_category_order(WEEK.to_vec());
+
+    let svg = chart.to_svg()?;
+    // Listed days come first, even without data; unlisted ones follow.
+    let ticks: Vec<f64> = WEEK
+        .iter()
+        .chain(&["Sat"])
+        .map(|d| label_x(&svg, d).unwrap())
+        .collect();
+    assert!(ticks.windows(2).all(|w| w[0] < w[1]), "{ticks:?}");
+
+    // Each bar sits on the tick of its own day.
+    let centers = bar_centers(&svg);
+    for (d, c) in day.iter().zip(&centers) {
+        assert!((label_x(&svg, d).unwrap() - c).abs() < 0.01, "{d}: {c}");
+    }
+
+    chart.save("./tests/category_order_1.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_category_order_aligns_layers() -> Result<(), Box<dyn Error>> {
+    let order = vec!["low", "mid", "high"];
+
+    // Each layer only has part of the categories.
+    let level = ["high", "low"];
+    let value = [4.0, 1.0];
+    let bars = chart!(level, value)?
+        .mark_bar()?
+        .encode((alt::x("level"), alt::y("value")))?;
+    let level = ["mid"];
+    let value = [2.0];
+    let points = chart!(level, value)?
+        .mark_point()?
+        .encode((alt::x("level"), alt::y("value")))?;
+
+    let svg = bars.and(points).with_x_category_order(order).to_svg()?;
+    let (low, mid, high) = (
+        label_x(&svg, "low").unwrap(),
+        label_x(&svg, "mid").unwrap(),
+        label_x(&svg, "high").unwrap(),
+    );
+    assert!(low < mid && mid < high);
+    assert_eq!(bar_centers(&svg).len(), 2);
+    assert!(bar_centers(&svg).iter().all(|c| (c - mid).abs() > 1.0));
+    assert!(svg.contains(&format!(r#"<circle cx="{:.3}""#, mid)));
+
+    Ok(())
+}
+
+#[test]
+fn test_category_order_boxplot_and_y() -> Result<(), Box<dyn Error>> {
+    let ds = load_dataset("mtcars")?;
+
+    let svg = Chart::build(ds.clone())?
+        .mark_boxplot()?
+        .encode((alt::x("cyl").with_scale(Scale::Discrete), alt::y("mpg")))?
+        .with_x_category_order(vec!["8", "6", "4"])
+        .to_svg()?;
+    let ticks: Vec<f64> = ["8", "6", "4"]
+        .iter()
+        .map(|c| label_x(&svg, c).unwrap())
+        .collect();
+    assert!(ticks.windows(2).all(|w| w[0] < w[1]), "{ticks:?}");
+
+    let svg = Chart::build(ds)?
+        .mark_point()?
+        .encode((alt::x("mpg"), alt::y("gear").with_scale(Scale::Discrete)))?
+        .with_y_category_order(vec!["5", "4", "3"])
+        .to_svg()?;
+    // The first category sits at the bottom of the axis.
+    let y_of = |label: &str| {
+        let end = svg.find(&format!(">{}</text>", label)).unwrap();
+        let tag = &svg[svg[..end].rfind("<text").unwrap()..end];
+        let y = tag
+            .split(" y=\"")
+            .nth(1)
+            .unwrap()
+            .split('"')
+            .next()
+            .unwrap();
+        y.parse::<f64>().unwrap()
+    };
+    assert!(y_of("5") > y_of("4") && y_of("4") > y_of("3"));
+
+    Ok(())
+}
+
+#[test]
+fn test_category_order_needs_discrete_scale() -> Result<(), Box<dyn Error>> {
+    let ds = load_dataset("mtcars")?;
+
+    let result = Chart::build(ds)?
+        .mark_point()?
+        .encode((alt::x("wt"), alt::y("mpg")))?
+        .with_x_category_order(vec!["a", "b"])
+        .to_svg();
+    assert!(matches!(result, Err(ChartonError::Scale(_))));
+
+    Ok(())
+}
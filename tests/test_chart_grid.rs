@@ -0,0 +1,98 @@
+use charton::error::ChartonError;
+use charton::prelude::*;
+use std::error::Error;
+
+#[test]
+fn test_chart_grid_1() -> Result<(), Box<dyn Error>> {
+    let x: Vec<f64> = (0..100).map(|i| i as f64 / 10.0).collect();
+    let y: Vec<f64> = x.iter().map(|v| v.sin()).collect();
+    let category = vec!["a", "b", "c", "d"];
+    let value = vec![3.0, 7.0, 2.0, 5.0];
+
+    let line = chart!(x, y)?
+        .mark_line()?
+        .encode((alt::x("x"), alt::y("y")))?;
+    let points = chart!(x, y)?
+        .mark_point()?
+        .encode((alt::x("x"), alt::y("y")))?;
+    let bars = chart!(category, value)?
+        .mark_bar()?
+        .encode((alt::x("category"), alt::y("value")))?;
+
+    let grid = ChartGrid::new(2, 2)
+        .place_span(line, 0, 0, 1, 2)
+        .place(points, 1, 0)
+        .place(bars.with_title("Bars"), 1, 1)
+        .with_gap(12.0)
+        .with_size(800, 600)
+        .with_title("Dashboard");
+
+    let svg = grid.to_svg()?;
+    // Every child gets its own clip region.
+    assert_eq!(svg.matches("<clipPath").count(), 3);
+    assert!(svg.contains(">Dashboard<"));
+    assert!(svg.contains(">Bars<"));
+
+    grid.save("./tests/chart_grid_1.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_chart_grid_shared_legend() -> Result<(), Box<dyn Error>> {
+    let x = vec![1.0, 2.0, 3.0, 4.0];
+    let y = vec![2.0, 4.0, 1.0, 3.0];
+    let group = vec!["g1", "g1", "g2", "g2"];
+
+    let left = chart!(x, y, group)?.mark_point()?.encode((
+        alt::x("x"),
+        alt::y("y"),
+        alt::color("group"),
+    ))?;
+    let right = chart!(x, y, group)?.mark_line()?.encode((
+        alt::x("x"),
+        alt::y("y"),
+        alt::color("group"),
+    ))?;
+
+    let separate = ChartGrid::new(1, 2)
+        .place(left.clone(), 0, 0)
+        .place(right.clone(), 0, 1)
+        .to_svg()?;
+    let shared = ChartGrid::new(1, 2)
+        .place(left, 0, 0)
+        .place(right, 0, 1)
+        .with_shared_legend(true)
+        .to_svg()?;
+
+    assert_eq!(separate.matches(">g1<").count(), 2);
+    assert_eq!(shared.matches(">g1<").count(), 1);
+
+    // The shared legend keeps the number formats of its chart.
+    let share = vec![0.1, 0.4, 0.6, 0.9];
+    let colored = chart!(x, y, share)?
+        .mark_point()?
+        .encode((alt::x("x"), alt::y("y"), alt::color("share")))?
+        .with_color_tick_formatter(TickFormat::Percent);
+    let shared = ChartGrid::new(1, 1)
+        .place(colored, 0, 0)
+        .with_shared_legend(true)
+        .to_svg()?;
+    assert!(shared.contains(">40%<") && shared.contains(">80%<"));
+
+    Ok(())
+}
+
+#[test]
+fn test_chart_grid_out_of_bounds() -> Result<(), Box<dyn Error>> {
+    let x = vec![1.0, 2.0];
+    let y = vec![1.0, 2.0];
+    let points = chart!(x, y)?
+        .mark_point()?
+        .encode((alt::x("x"), alt::y("y")))?;
+
+    let result = ChartGrid::new(2, 2).place_span(points, 1, 0, 2, 1).to_svg();
+    assert!(matches!(result, Err(ChartonError::Render(_))));
+
+    Ok(())
+}
@@ -0,0 +1,137 @@
+use charton::coordinate::CoordinateTrait;
+use charton::core::aesthetics::GlobalAesthetics;
+use charton::core::context::PanelContext;
+use charton::core::layer::{Layer, MarkRenderer, RectConfig, RenderBackend};
+use charton::encode::Channel;
+use charton::error::ChartonError;
+use charton::prelude::*;
+use charton::scale::ScaleDomain;
+use std::error::Error;
+use std::sync::{Arc, Mutex};
+
+/// A minimal custom mark: one bar per category, filling a share of its band.
+struct BandBars {
+    categories: Vec<String>,
+    values: Vec<f64>,
+    fill: f64,
+    /// Band geometry seen while rendering, recorded for the assertions below.
+    seen: Arc<Mutex<Vec<(f64, f64)>>>,
+}
+
+impl MarkRenderer for BandBars {
+    fn render_marks(
+        &self,
+        backend: &mut dyn RenderBackend,
+        context: &PanelContext,
+    ) -> Result<(), ChartonError> {
+        let band = context
+            .band_width(Channel::X)
+            .ok_or_else(|| ChartonError::Scale("x must be discrete".into()))?;
+        let edges = context
+            .band_edges(Channel::X)
+            .ok_or_else(|| ChartonError::Scale("x must be discrete".into()))?;
+        let categories = context.categories(Channel::X).unwrap_or_default();
+        let y_scale = context.coord.get_y_scale();
+
+        for (label, value) in self.categories.iter().zip(&self.values) {
+            let Some(idx) = categories.iter().position(|c| c == label) else {
+                continue;
+            };
+            let (start, end) = edges[idx];
+            let inset = (end - start) * (1.0 - self.fill) / 2.0;
+            let (_, top) = context.transform(0.0, y_scale.normalize(*value));
+            let (_, bottom) = context.transform(0.0, y_scale.normalize(0.0));
+            backend.draw_rect(RectConfig {
+                x: (context.panel.x + start + inset) as _,
+                y: top as _,
+                width: (end - start - 2.0 * inset) as _,
+                height: (bottom - top) as _,
+                fill: SingleColor::new("teal"),
+                stroke: SingleColor::new("none"),
+                stroke_width: 0.0,
+                opacity: 1.0,
+            });
+            self.seen.lock().unwrap().push((band, end - start));
+        }
+        Ok(())
+    }
+}
+
+impl Layer for BandBars {
+    fn requires_axes(&self) -> bool {
+        true
+    }
+
+    fn get_field(&self, channel: Channel) -> Option<String> {
+        match channel {
+            Channel::X => Some("category".into()),
+            Channel::Y => Some("value".into()),
+            _ => None,
+        }
+    }
+
+    fn get_scale(&self, channel: Channel) -> Option<Scale> {
+        match channel {
+            Channel::X => Some(Scale::Discrete),
+            Channel::Y => Some(Scale::Linear),
+            _ => None,
+        }
+    }
+
+    fn get_expand(&self, channel: Channel) -> Option<Expansion> {
+        (channel == Channel::X).then_some(Expansion {
+            mult: (0.0, 0.0),
+            add: (0.5, 0.5),
+        })
+    }
+
+    fn get_data_bounds(&self, channel: Channel) -> Result<ScaleDomain, ChartonError> {
+        match channel {
+            Channel::X => Ok(ScaleDomain::Discrete(self.categories.clone())),
+            _ => {
+                let max = self.values.iter().copied().fold(0.0, f64::max);
+                Ok(ScaleDomain::Continuous(0.0, max))
+            }
+        }
+    }
+
+    fn inject_resolved_scales(
+        &self,
+        _coord: Arc<dyn CoordinateTrait>,
+        _aesthetics: &GlobalAesthetics,
+    ) {
+    }
+}
+
+#[test]
+fn test_custom_layer_band_geometry() -> Result<(), Box<dyn Error>> {
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let layer = BandBars {
+        categories: vec!["a".into(), "b".into(), "c".into(), "d".into()],
+        values: vec![3.0, 5.0, 2.0, 4.0],
+        fill: 0.6,
+        seen: seen.clone(),
+    };
+
+    let chart = LayeredChart::new().with_layer(layer).with_size(500, 400);
+    let svg = chart.to_svg()?;
+    assert_eq!(
+        svg.matches("rgba(0,128,128,1.000)").count(),
+        4,
+        "one bar per category"
+    );
+
+    // Every band edge pair spans exactly one band width.
+    {
+        let seen = seen.lock().unwrap();
+        assert_eq!(seen.len(), 4);
+        for (band, span) in seen.iter() {
+            assert!(*band > 0.0);
+            assert!((band - span).abs() < 1e-9);
+        }
+    }
+
+    chart.save("./tests/custom_layer_1.svg")?;
+
+    Ok(())
+}
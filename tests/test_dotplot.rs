@@ -0,0 +1,60 @@
+use charton::prelude::*;
+use std::error::Error;
+
+#[test]
+fn test_dotplot_1() -> Result<(), Box<dyn Error>> {
+    let country = ["Norway", "Japan", "Chile", "Kenya", "Spain"];
+    let score = [7.3, 6.1, 6.4, 4.5, 6.5];
+
+    let chart = chart!(country, score)?
+        .mark_dotplot()?
+        .configure_dotplot(|m| {
+            m.with_color("steelblue")
+                .with_size(5.0)
+                .with_line_dash(vec![3.0, 2.0])
+                .with_sort("descending")
+        })
+        .encode((alt::x("country"), alt::y("score")))?
+        .coord_flip()
+        .with_title("Cleveland Dot Plot");
+
+    let svg = chart.to_svg()?;
+    // One dot and one dashed guide line per category.
+    assert_eq!(svg.matches("<circle").count(), 5);
+    assert_eq!(svg.matches("stroke-dasharray").count(), 5);
+
+    // Descending sort: the first category on the axis is the highest score.
+    let norway = svg.find(">Norway<").unwrap();
+    let spain = svg.find(">Spain<").unwrap();
+    let kenya = svg.find(">Kenya<").unwrap();
+    assert!(norway < spain && spain < kenya);
+
+    chart.save("tests/dotplot_1.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_dotplot_range() -> Result<(), Box<dyn Error>> {
+    let group = ["A", "B", "C", "A", "B", "C"];
+    let before = [3.0, 4.5, 2.0, 3.5, 5.0, 2.5];
+    let after = [5.0, 6.0, 4.0, 4.5, 7.5, 3.0];
+    let year = ["2023", "2023", "2023", "2024", "2024", "2024"];
+
+    let chart = chart!(group, before, after, year)?
+        .mark_dotplot()?
+        .encode((
+            alt::x("group"),
+            alt::y("after"),
+            alt::y2("before"),
+            alt::color("year"),
+        ))?;
+
+    let svg = chart.to_svg()?;
+    // Range dots draw a dot at both ends of every row (plus legend symbols).
+    assert!(svg.matches("<circle").count() >= 12);
+
+    chart.save("tests/dotplot_2.svg")?;
+
+    Ok(())
+}
@@ -0,0 +1,97 @@
+use charton::prelude::*;
+use std::collections::BTreeMap;
+use std::error::Error;
+
+/// Counts the circles drawn at each distinct horizontal position.
+fn dots_per_column(svg: &str) -> BTreeMap<i64, usize> {
+    let mut counts = BTreeMap::new();
+    for part in svg.split("<circle cx=\"").skip(1) {
+        let cx: f64 = part[..part.find('"').unwrap()].parse().unwrap();
+        *counts.entry((cx * 10.0).round() as i64).or_insert(0) += 1;
+    }
+    counts
+}
+
+#[test]
+fn test_dots_1() -> Result<(), Box<dyn Error>> {
+    let ds = Dataset::new().with_column("value", vec![1.0, 1.0, 2.0, 2.0, 2.0, 3.0, 5.0, 5.0])?;
+
+    let chart = chart!(ds)?
+        .mark_dots()?
+        .configure_dots(|m| m.with_color("steelblue"))
+        .encode((alt::x("value").with_bins(4), alt::y("count")))?
+        .with_title("Dot Histogram");
+
+    let svg = chart.to_svg()?;
+    // Bins of width 1 over [1, 5]: the maximum falls into the last bin.
+    let counts: Vec<usize> = dots_per_column(&svg).into_values().collect();
+    assert_eq!(counts, vec![2, 3, 1, 2]);
+
+    chart.save("tests/dots_1.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_dots_color_layout() -> Result<(), Box<dyn Error>> {
+    let value = vec![1.0, 1.0, 2.0, 2.0, 2.0, 3.0, 5.0, 5.0];
+    let group = vec!["a", "b", "a", "a", "b", "b", "a", "b"];
+
+    // Stacked segments: one column per bin.
+    let stacked = chart!(value, group)?.mark_dots()?.encode((
+        alt::x("value").with_bins(4),
+        alt::y("count"),
+        alt::color("group"),
+    ))?;
+    let svg = stacked.to_svg()?;
+    assert!(svg.matches("<circle").count() >= 8);
+
+    // Dodged groups: every bin holding both groups splits into two columns.
+    let dodged = chart!(value, group)?
+        .mark_dots()?
+        .configure_dots(|m| m.with_layout("dodge"))
+        .encode((
+            alt::x("value").with_bins(4),
+            alt::y("count"),
+            alt::color("group"),
+        ))?;
+    let svg = dodged.to_svg()?;
+    // Group "b" alone occupies the third bin; the legend symbols come last.
+    let columns: Vec<usize> = dots_per_column(&svg).into_values().take(7).collect();
+    assert_eq!(columns, vec![1, 1, 2, 1, 1, 1, 1]);
+
+    Ok(())
+}
+
+#[test]
+fn test_dots_bin_options() -> Result<(), Box<dyn Error>> {
+    let ds = Dataset::new().with_column("value", vec![1.0, 1.2, 2.0, 2.6, 3.0, 9.0])?;
+
+    // Width-based bins count from the lower end of the data: [1, 2), [2, 3), ...
+    let svg = chart!(&ds)?
+        .mark_dots()?
+        .with_bin_width(1.0)
+        .encode((alt::x("value"), alt::y("count")))?
+        .to_svg()?;
+    let counts: Vec<usize> = dots_per_column(&svg).into_values().collect();
+    assert_eq!(counts, vec![2, 2, 1, 1]);
+
+    // A pinned range drops the outlier and keeps the empty outer bin.
+    let svg = chart!(&ds)?
+        .mark_dots()?
+        .with_bin_range(0.0, 4.0)
+        .with_bin_count(4)
+        .encode((alt::x("value"), alt::y("count")))?
+        .to_svg()?;
+    assert_eq!(svg.matches("<circle").count(), 5);
+    let counts: Vec<usize> = dots_per_column(&svg).into_values().collect();
+    assert_eq!(counts, vec![2, 2, 1]);
+
+    let invalid = chart!(&ds)?
+        .mark_dots()?
+        .with_bin_count(0)
+        .encode((alt::x("value"), alt::y("count")));
+    assert!(invalid.is_err());
+
+    Ok(())
+}
@@ -0,0 +1,98 @@
+use charton::error::ChartonError;
+use charton::prelude::*;
+use std::error::Error;
+
+#[test]
+fn test_encode_duplicate_channel() -> Result<(), Box<dyn Error>> {
+    let a = vec![1.0, 2.0, 3.0];
+    let b = vec![4.0, 5.0, 6.0];
+    let c = vec![7.0, 8.0, 9.0];
+
+    let result = chart!(a, b, c)?
+        .mark_point()?
+        .encode((alt::x("a"), alt::x("b"), alt::y("c")));
+
+    match result {
+        Err(ChartonError::Encoding(msg)) => assert_eq!(
+            msg,
+            "Channel 'x' is encoded more than once: 'a' (position 0) and 'b' (position 1)"
+        ),
+        Err(e) => panic!("expected an encoding error, got {e:?}"),
+        Ok(_) => panic!("expected an encoding error"),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_encode_repeated_calls_refine() -> Result<(), Box<dyn Error>> {
+    let a = vec![1.0, 2.0, 3.0];
+    let b = vec![4.0, 5.0, 6.0];
+    let c = vec![7.0, 8.0, 9.0];
+
+    // Separate encode calls may replace a channel; only duplicates within one call fail.
+    chart!(a, b, c)?
+        .encode((alt::x("a"), alt::y("b")))?
+        .encode(alt::y("c"))?
+        .mark_point()?
+        .to_svg()?;
+
+    Ok(())
+}
+
+#[test]
+fn test_encode_numeric_strings() -> Result<(), Box<dyn Error>> {
+    // Years loaded as text: a histogram needs a quantitative x axis.
+    let year: Vec<String> = (0..40).map(|i| (1990 + i % 20).to_string()).collect();
+    let id: Vec<f64> = (0..40).map(f64::from).collect();
+
+    let svg = chart!(year, id)?
+        .mark_hist()?
+        .encode((alt::x("year"), alt::y("count")))?
+        .to_svg()?;
+    assert!(svg.contains(">2000<"));
+
+    // Without coercion the column stays categorical, which a histogram rejects.
+    let result = chart!(year, id)?
+        .with_auto_numeric_coercion(false)
+        .mark_hist()?
+        .encode((alt::x("year"), alt::y("count")));
+    assert!(matches!(result, Err(ChartonError::Encoding(_))));
+
+    Ok(())
+}
+
+#[test]
+fn test_encode_mostly_numeric_strings() -> Result<(), Box<dyn Error>> {
+    // One stray "N/A" in 200 values is cast to null and the point is dropped.
+    let mut x: Vec<String> = (0..200).map(|i| format!("{}.5", i)).collect();
+    x[7] = "N/A".to_string();
+    let y: Vec<f64> = (0..200).map(f64::from).collect();
+
+    let svg = chart!(x, y)?
+        .mark_point()?
+        .encode((alt::x("x"), alt::y("y")))?
+        .to_svg()?;
+    assert_eq!(svg.matches("<circle").count(), 199);
+    assert!(!svg.contains(">N/A<"));
+
+    // Too many non-numeric values: the column stays categorical.
+    let x: Vec<String> = (0..20)
+        .map(|i| {
+            if i % 4 == 0 {
+                "-".to_string()
+            } else {
+                i.to_string()
+            }
+        })
+        .collect();
+    let y: Vec<f64> = (0..20).map(f64::from).collect();
+    let svg = chart!(x, y)?
+        .mark_point()?
+        .encode((alt::x("x"), alt::y("y")))?
+        .to_svg()?;
+    assert_eq!(svg.matches("<circle").count(), 20);
+    assert!(svg.contains(">-<"));
+
+    Ok(())
+}
@@ -0,0 +1,222 @@
+use charton::error::ChartonError;
+use charton::prelude::*;
+use std::error::Error;
+
+/// Strip headers use the default `facet_strip_fill` ("lightgray").
+const STRIP_FILL: &str = r#"fill="rgba(211,211,211,1.000)""#;
+
+/// Returns the distinct `y` attributes of the strip rectangles, i.e. the panel rows.
+fn strip_rows(svg: &str) -> Vec<String> {
+    let mut rows: Vec<String> = svg
+        .split("<rect")
+        .skip(1)
+        .filter(|r| r.contains(STRIP_FILL))
+        .filter_map(|r| r.split(r#" y=""#).nth(1)?.split('"').next())
+        .map(str::to_string)
+        .collect();
+    rows.dedup();
+    rows
+}
+
+#[test]
+fn test_facet_1() -> Result<(), Box<dyn Error>> {
+    let ds = load_dataset("mtcars")?;
+
+    let chart = chart!(ds)?
+        .mark_point()?
+        .encode((alt::x("wt"), alt::y("mpg"), alt::color("gear")))?
+        .facet("cyl")
+        .with_size(600, 500)
+        .with_title("MPG vs Weight by Cylinders");
+
+    let svg = chart.to_svg()?;
+    assert_eq!(svg.matches(STRIP_FILL).count(), 3, "one strip per cylinder");
+    for label in [">4<", ">6<", ">8<"] {
+        assert!(svg.contains(label), "missing strip label {}", label);
+    }
+    // Three panels wrap into a near-square 2 x 2 grid.
+    assert_eq!(strip_rows(&svg).len(), 2);
+
+    chart.save("./tests/facet_1.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_facet_wraps_many_levels() -> Result<(), Box<dyn Error>> {
+    let group = ["a", "b", "c", "d", "e", "f", "g"];
+    let x = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0];
+    let y = [7.0, 6.0, 5.0, 4.0, 3.0, 2.0, 1.0];
+
+    let svg = chart!(group, x, y)?
+        .mark_point()?
+        .encode((alt::x("x"), alt::y("y")))?
+        .facet("group")
+        .with_size(600, 600)
+        .to_svg()?;
+
+    // Seven panels wrap into a 3 x 3 grid, leaving the last two cells empty.
+    assert_eq!(svg.matches(STRIP_FILL).count(), 7);
+    assert_eq!(strip_rows(&svg).len(), 3);
+
+    // Each panel draws only its own row of data.
+    assert_eq!(svg.matches("<circle").count(), 7);
+
+    Ok(())
+}
+
+#[test]
+fn test_facet_keeps_empty_categories() -> Result<(), Box<dyn Error>> {
+    // "c" is a declared category without any rows.
+    let site = ColumnVector::from_categorical(
+        vec![0, 0, 1, 1],
+        vec!["a".into(), "b".into(), "c".into()],
+        None,
+    );
+    let ds = Dataset::new()
+        .with_column("site", site)?
+        .with_column("x", vec![1.0, 2.0, 3.0, 4.0])?
+        .with_column("y", vec![10.0, 20.0, 30.0, 40.0])?;
+
+    let svg = Chart::build(ds)?
+        .mark_point()?
+        .encode((alt::x("x"), alt::y("y")))?
+        .facet("site")
+        .to_svg()?;
+
+    assert_eq!(svg.matches(STRIP_FILL).count(), 3);
+    assert!(svg.contains(">c<"));
+    assert_eq!(svg.matches("<circle").count(), 4);
+
+    // Shared scales: every panel labels the same y ticks.
+    let panels = svg.matches(">40<").count();
+    assert_eq!(panels, 3, "each panel draws the full y domain");
+
+    Ok(())
+}
+
+#[test]
+fn test_facet_unknown_field() -> Result<(), Box<dyn Error>> {
+    let x = [1.0, 2.0];
+    let y = [3.0, 4.0];
+
+    let result = chart!(x, y)?
+        .mark_point()?
+        .encode((alt::x("x"), alt::y("y")))?
+        .facet("missing")
+        .to_svg();
+    assert!(matches!(result, Err(ChartonError::Encoding(msg)) if msg.contains("missing")));
+
+    Ok(())
+}
+
+#[test]
+fn test_facet_wrap_columns() -> Result<(), Box<dyn Error>> {
+    let ds = load_dataset("mtcars")?;
+
+    let chart = chart!(&ds)?
+        .mark_point()?
+        .encode((alt::x("wt"), alt::y("mpg")))?
+        .facet_wrap("cyl", 3)
+        .with_size(800, 350)
+        .with_title("MPG vs Weight, one row per cylinder count");
+
+    // Three columns put all panels in one row.
+    let svg = chart.to_svg()?;
+    assert_eq!(svg.matches(STRIP_FILL).count(), 3);
+    assert_eq!(strip_rows(&svg).len(), 1);
+    chart.save("./tests/facet_2.svg")?;
+
+    // A single column stacks them.
+    let stacked = chart!(ds)?
+        .mark_point()?
+        .encode((alt::x("wt"), alt::y("mpg")))?
+        .facet_wrap("cyl", 1)
+        .to_svg()?;
+    assert_eq!(strip_rows(&stacked).len(), 3);
+
+    Ok(())
+}
+
+#[test]
+fn test_facet_grid() -> Result<(), Box<dyn Error>> {
+    let ds = load_dataset("mtcars")?;
+
+    let chart = chart!(&ds)?
+        .mark_point()?
+        .encode((alt::x("wt"), alt::y("mpg")))?
+        .facet_grid("vs", "cyl")
+        .with_size(700, 500)
+        .with_title("MPG vs Weight by engine shape and cylinders");
+
+    let svg = chart.to_svg()?;
+    // Three column headers on top and two row headers on the right.
+    assert_eq!(svg.matches(STRIP_FILL).count(), 5);
+    assert_eq!(strip_rows(&svg).len(), 3);
+    assert_eq!(svg.matches(r#"transform="rotate(90 "#).count(), 2);
+    for label in [">4<", ">6<", ">8<", ">0<", ">1<"] {
+        assert!(svg.contains(label), "missing strip label {}", label);
+    }
+    // Every car is drawn once; straight eights do not exist and leave a panel empty.
+    assert_eq!(svg.matches("<circle").count(), 32);
+    chart.save("./tests/facet_3.svg")?;
+
+    // A layer without the row field is repeated down its column.
+    let cyl = [4.0, 6.0, 8.0];
+    let wt = [2.3, 3.1, 4.0];
+    let mpg = [26.7, 19.7, 15.1];
+    let means = chart!(cyl, wt, mpg)?
+        .mark_point()?
+        .encode((alt::x("wt"), alt::y("mpg")))?;
+    let repeated = chart!(&ds)?
+        .mark_point()?
+        .encode((alt::x("wt"), alt::y("mpg")))?
+        .and(means)
+        .facet_grid("vs", "cyl")
+        .to_svg()?;
+    assert_eq!(repeated.matches("<circle").count(), 32 + 3 * 2);
+
+    Ok(())
+}
+
+#[test]
+fn test_facet_bar_on_unencoded_field() -> Result<(), Box<dyn Error>> {
+    let ds = load_dataset("mtcars")?;
+
+    // The bar transform aggregates `am` away; the panels are split from the raw rows.
+    let svg = chart!(ds)?
+        .mark_bar()?
+        .encode((alt::x("cyl").with_scale(Scale::Discrete), alt::y("mpg")))?
+        .facet("am")
+        .with_size(600, 300)
+        .to_svg()?;
+
+    assert_eq!(
+        svg.matches(STRIP_FILL).count(),
+        2,
+        "one strip per transmission"
+    );
+    for label in [">0<", ">1<"] {
+        assert!(svg.contains(label), "missing strip label {}", label);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_facet_bar_domain_covers_panels() -> Result<(), Box<dyn Error>> {
+    let group = ["a", "a", "b"];
+    let kind = ["k", "k", "k"];
+    let value = [2.0, 2.0, 40.0];
+
+    // Averaged over both groups the bar is only about 15 tall, but panel b's is 40.
+    let svg = chart!(group, kind, value)?
+        .mark_bar()?
+        .encode((alt::x("kind"), alt::y("value").with_aggregate("mean")))?
+        .facet("group")
+        .to_svg()?;
+
+    assert!(svg.contains(">40<"), "the y axis reaches panel b's bar");
+
+    Ok(())
+}
@@ -0,0 +1,160 @@
+use charton::prelude::*;
+use std::error::Error;
+
+/// Counts the (vertical, horizontal) grid lines, which are the only lines drawn at half opacity.
+fn count_grid_lines(svg: &str) -> (usize, usize) {
+    let attr = |line: &str, name: &str| {
+        line.split(&format!(r#" {}=""#, name))
+            .nth(1)
+            .and_then(|rest| rest.split('"').next())
+            .map(str::to_string)
+    };
+    svg.lines()
+        .filter(|l| l.starts_with("<line") && l.contains(r#"stroke-opacity="0.500""#))
+        .fold((0, 0), |(v, h), l| {
+            if attr(l, "x1") == attr(l, "x2") {
+                (v + 1, h)
+            } else {
+                (v, h + 1)
+            }
+        })
+}
+
+#[test]
+fn test_grid_lines_per_axis() -> Result<(), Box<dyn Error>> {
+    let ds = load_dataset("mtcars")?;
+
+    let chart = chart!(ds)?
+        .mark_point()?
+        .encode((alt::x("wt"), alt::y("mpg")))?
+        .with_grid(true)
+        .with_x_grid(false)
+        .with_theme(Theme::default().with_grid_dash(vec![4.0, 2.0]));
+
+    let svg = chart.to_svg()?;
+    let (vertical, horizontal) = count_grid_lines(&svg);
+    assert_eq!(vertical, 0);
+    assert!(horizontal > 0);
+    assert!(svg.contains(r#"stroke-dasharray="4.0,2.0""#));
+
+    chart.save("./tests/grid_lines_1.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_grid_lines_follow_coord_flip() -> Result<(), Box<dyn Error>> {
+    let ds = load_dataset("mtcars")?;
+
+    // The x grid belongs to the x scale, which is drawn vertically once flipped.
+    let svg = chart!(ds)?
+        .mark_point()?
+        .encode((alt::x("wt"), alt::y("mpg")))?
+        .coord_flip()
+        .with_x_grid(true)
+        .to_svg()?;
+    let (vertical, horizontal) = count_grid_lines(&svg);
+    assert_eq!(vertical, 0);
+    assert!(horizontal > 0);
+
+    Ok(())
+}
+
+#[test]
+fn test_grid_lines_default() -> Result<(), Box<dyn Error>> {
+    let ds = load_dataset("mtcars")?;
+
+    let svg = chart!(ds)?
+        .mark_point()?
+        .encode((alt::x("wt"), alt::y("mpg")))?
+        .with_grid(true)
+        .to_svg()?;
+    let (vertical, horizontal) = count_grid_lines(&svg);
+    assert!(vertical > 0 && horizontal > 0);
+    assert!(!svg.contains("stroke-dasharray"));
+
+    Ok(())
+}
+
+#[test]
+fn test_grid_line_styling() -> Result<(), Box<dyn Error>> {
+    let ds = load_dataset("mtcars")?;
+
+    let chart = chart!(ds)?
+        .mark_point()?
+        .encode((alt::x("wt"), alt::y("mpg")))?
+        .with_y_grid(true)
+        .with_grid_line_color("#ff0000")
+        .with_grid_line_width(0.5)
+        .with_grid_line_dash("3, 1")
+        .with_grid_opacity(0.25);
+
+    let svg = chart.to_svg()?;
+    let grid: Vec<&str> = svg
+        .lines()
+        .filter(|l| l.starts_with("<line") && l.contains(r#"stroke="rgba(255,0,0,1.000)""#))
+        .collect();
+    assert!(!grid.is_empty());
+    for line in &grid {
+        assert!(line.contains(r#"stroke-width="0.500""#), "{line}");
+        assert!(line.contains(r#"stroke-opacity="0.250""#), "{line}");
+        assert!(line.contains(r#"stroke-dasharray="3.0,1.0""#), "{line}");
+    }
+    // Only horizontal lines, drawn beneath the points.
+    let attr = |line: &str, name: &str| {
+        line.split(&format!(r#" {}=""#, name))
+            .nth(1)?
+            .split('"')
+            .next()
+            .map(str::to_string)
+    };
+    assert!(grid.iter().all(|l| attr(l, "y1") == attr(l, "y2")));
+    assert!(svg.find(grid[0]) < svg.find("<circle"));
+
+    chart.save("./tests/grid_lines_2.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_grid_line_dash_invalid_is_ignored() -> Result<(), Box<dyn Error>> {
+    let ds = load_dataset("mtcars")?;
+
+    let svg = chart!(ds)?
+        .mark_point()?
+        .encode((alt::x("wt"), alt::y("mpg")))?
+        .with_grid(true)
+        .with_grid_line_dash("4 -2")
+        .to_svg()?;
+    assert!(!svg.contains("stroke-dasharray"));
+
+    Ok(())
+}
+
+#[test]
+fn test_geo_graticule_opacity() -> Result<(), Box<dyn Error>> {
+    let ds = Dataset::new()
+        .with_column("lon", vec![108.6, 111.0, 110.2, 108.6])?
+        .with_column("lat", vec![18.2, 20.0, 18.0, 18.2])?
+        .with_column("region", vec!["Hainan"; 4])?;
+    let graticule = |svg: &str, opacity: &str| {
+        svg.lines()
+            .filter(|l| l.starts_with("<line") && l.contains(opacity))
+            .count()
+    };
+
+    // Graticules stay fully opaque unless the theme says otherwise.
+    let chart = chart!(&ds)?
+        .mark_geoshape()?
+        .encode((alt::x("lon"), alt::y("lat"), alt::path_group("region")))?
+        .with_coord(CoordSystem::Geo)
+        .with_grid(true);
+    let svg = chart.to_svg()?;
+    assert!(graticule(&svg, r#"stroke-opacity="1.000""#) > 0);
+    assert_eq!(graticule(&svg, r#"stroke-opacity="0.500""#), 0);
+
+    let svg = chart.with_grid_opacity(0.5).to_svg()?;
+    assert!(graticule(&svg, r#"stroke-opacity="0.500""#) > 0);
+
+    Ok(())
+}
@@ -1,3 +1,4 @@
+use charton::error::ChartonError;
 use charton::prelude::*;
 use std::error::Error;
 
@@ -48,3 +49,122 @@ fn test_histogram_1() -> Result<(), Box<dyn Error>> {
 
     Ok(())
 }
+
+/// Deterministic standard normal samples (LCG + Box-Muller).
+fn normal_samples(n: usize) -> Result<Dataset, ChartonError> {
+    let mut state: u64 = 42;
+    let mut uniform = || {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        ((state >> 11) as f64 + 0.5) / (1u64 << 53) as f64
+    };
+    let value: Vec<f64> = (0..n)
+        .map(|_| {
+            let (u1, u2) = (uniform(), uniform());
+            (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+        })
+        .collect();
+    Dataset::new().with_column("value", value)
+}
+
+/// Histogram bars are the only rects filled with this color.
+const BAR_FILL: &str = r#"fill="rgba(70,130,180,1.000)""#;
+
+#[test]
+fn test_histogram_bin_count() -> Result<(), Box<dyn Error>> {
+    let chart = Chart::build(normal_samples(1000)?)?
+        .mark_hist()?
+        .configure_hist(|h| h.with_color("steelblue"))
+        .with_bin_count(25)
+        .encode((alt::x("value"), alt::y("count")))?;
+
+    let svg = chart.to_svg()?;
+    assert_eq!(svg.matches(BAR_FILL).count(), 25);
+
+    chart
+        .with_title("25 bins")
+        .save("./tests/histogram_bin_count.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_histogram_bin_width_and_range() -> Result<(), Box<dyn Error>> {
+    // Half-unit bins over [-3, 3]; samples beyond it are not counted.
+    let svg = Chart::build(normal_samples(1000)?)?
+        .mark_hist()?
+        .configure_hist(|h| h.with_color("steelblue"))
+        .with_bin_width(0.5)
+        .with_bin_range(-3.0, 3.0)
+        .encode((alt::x("value"), alt::y("count")))?
+        .to_svg()?;
+    assert_eq!(svg.matches(BAR_FILL).count(), 12);
+    assert!(svg.contains(">-3<") && svg.contains(">3<"));
+
+    // The last of the two exclusive options wins.
+    let svg = Chart::build(normal_samples(100)?)?
+        .mark_hist()?
+        .configure_hist(|h| h.with_color("steelblue"))
+        .with_bin_width(0.1)
+        .with_bin_count(8)
+        .encode((alt::x("value"), alt::y("count")))?
+        .to_svg()?;
+    assert_eq!(svg.matches(BAR_FILL).count(), 8);
+
+    let result = Chart::build(normal_samples(10)?)?
+        .mark_hist()?
+        .with_bin_width(0.0)
+        .encode((alt::x("value"), alt::y("count")));
+    assert!(matches!(result, Err(ChartonError::Mark(_))));
+
+    // Options asking for more than 10,000 bins are capped at that many.
+    for chart in [
+        Chart::build(normal_samples(10)?)?
+            .mark_hist()?
+            .with_bin_count(usize::MAX),
+        Chart::build(normal_samples(10)?)?
+            .mark_hist()?
+            .with_bin_width(1e-12),
+    ] {
+        let svg = chart
+            .configure_hist(|h| h.with_color("steelblue"))
+            .encode((alt::x("value"), alt::y("count")))?
+            .to_svg()?;
+        assert!(svg.matches(BAR_FILL).count() <= 10_000);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_histogram_cumulative() -> Result<(), Box<dyn Error>> {
+    let ecdf = Chart::build(normal_samples(500)?)?
+        .mark_hist()?
+        .with_bin_count(20)
+        .with_cumulative(true)
+        .configure_hist(|h| h.with_color("steelblue"))
+        .encode((alt::x("value"), alt::y("count").with_normalize(true)))?
+        .with_title("Binned ECDF");
+
+    let svg = ecdf.to_svg()?;
+    // Proportions accumulate to 1, so the y axis stops there.
+    assert!(svg.contains(">1.0<"));
+    assert!(!svg.contains(">1.2<"));
+
+    ecdf.save("./tests/histogram_cumulative.svg")?;
+
+    // Ascending and descending sums both top out at the sample size.
+    for direction in [CumulativeDirection::Asc, CumulativeDirection::Desc] {
+        let svg = Chart::build(normal_samples(500)?)?
+            .mark_hist()?
+            .with_bin_count(20)
+            .with_cumulative_direction(direction)
+            .encode((alt::x("value"), alt::y("count")))?
+            .with_title("Counts")
+            .to_svg()?;
+        assert!(svg.contains(">500<"));
+    }
+
+    Ok(())
+}
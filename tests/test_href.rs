@@ -0,0 +1,92 @@
+use charton::error::ChartonError;
+use charton::prelude::*;
+use std::error::Error;
+
+#[test]
+fn test_href_1() -> Result<(), Box<dyn Error>> {
+    let urls: Vec<Option<String>> = vec![
+        Some("https://example.com/a?x=1&y=2".into()),
+        None,
+        Some("https://example.com/c".into()),
+    ];
+    let ds = Dataset::new()
+        .with_column("item", vec!["a", "b", "c"])?
+        .with_column("count", vec![3.0, 5.0, 4.0])?
+        .with_column("url", urls)?;
+
+    let chart = chart!(&ds)?
+        .mark_bar()?
+        .encode((alt::x("item"), alt::y("count"), alt::href("url")))?
+        .with_title("Linked bars");
+
+    let svg = chart.to_svg()?;
+    // The bar with a null URL is drawn without a link.
+    assert_eq!(svg.matches("<a href=").count(), 2);
+    assert_eq!(svg.matches("</a>").count(), 2);
+    assert!(svg.contains(
+        r#"<a href="https://example.com/a?x=1&amp;y=2" target="_blank" style="cursor: pointer">"#
+    ));
+    // Every link wraps exactly one bar.
+    for link in svg.split("<a href=").skip(1) {
+        let inner = link.split("</a>").next().unwrap_or_default();
+        assert_eq!(inner.matches("<path").count(), 1);
+    }
+
+    chart.save("./tests/href_1.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_href_points_with_tooltips() -> Result<(), Box<dyn Error>> {
+    let x = vec![1.0, 2.0];
+    let y = vec![3.0, 4.0];
+    let url = vec!["https://example.com/1", "https://example.com/2"];
+
+    let svg = chart!(x, y, url)?
+        .mark_point()?
+        .encode((
+            alt::x("x"),
+            alt::y("y"),
+            alt::href("url"),
+            alt::tooltip("url"),
+        ))?
+        .to_svg()?;
+    assert!(svg.contains(
+        "<a href=\"https://example.com/2\" target=\"_blank\" style=\"cursor: pointer\">\n<circle"
+    ));
+    assert_eq!(svg.matches("</title></circle>\n</a>").count(), 2);
+
+    let missing = chart!(x, y)?
+        .mark_point()?
+        .encode((alt::x("x"), alt::y("y"), alt::href("url")));
+    assert!(missing.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_href_dots_and_rejections() -> Result<(), Box<dyn Error>> {
+    let x = vec![1.0, 2.0, 4.0];
+    let y = vec![3.0, 1.0, 2.0];
+    let url = vec!["https://example.com/1", "https://example.com/2", ""];
+
+    let svg = chart!(x, y, url)?
+        .mark_dots()?
+        .encode((alt::x("x").with_bins(2), alt::y("count"), alt::href("url")))?
+        .to_svg()?;
+    // The empty URL leaves its dot unlinked.
+    assert_eq!(svg.matches("<a href=").count(), 2);
+
+    let line = chart!(x, y, url)?
+        .mark_line()?
+        .encode((alt::x("x"), alt::y("y"), alt::href("url")));
+    assert!(matches!(line, Err(ChartonError::Encoding(_))));
+
+    let numeric = chart!(x, y)?
+        .mark_point()?
+        .encode((alt::x("x"), alt::y("y"), alt::href("y")));
+    assert!(matches!(numeric, Err(ChartonError::Data(msg)) if msg.contains("string column")));
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use charton::prelude::*;
+use std::error::Error;
+
+#[test]
+fn test_inset_1() -> Result<(), Box<dyn Error>> {
+    let x: Vec<f64> = (0..200).map(|i| i as f64 / 10.0).collect();
+    let y: Vec<f64> = x.iter().map(|v| v.sin() * v).collect();
+
+    let line = chart!(x, y)?
+        .mark_line()?
+        .encode((alt::x("x"), alt::y("y")))?;
+
+    let chart = line
+        .clone()
+        .with_inset(
+            line,
+            InsetSpec::new()
+                .with_anchor("top-left")
+                .with_size(0.35, 0.35)
+                .with_x_domain(2.0, 5.0)
+                .with_y_domain(-5.0, 2.0),
+        )
+        .with_size(600, 400)
+        .with_title("Inset Example");
+
+    let svg = chart.to_svg()?;
+    // The inset gets its own clip region, distinct from the main panel's.
+    assert_eq!(svg.matches("<clipPath").count(), 2);
+    assert!(svg.contains(r#"id="plot-clip-area""#));
+    assert!(svg.contains(r#"id="plot-clip-area-1""#));
+    // Two long polylines are drawn: the main series and its zoomed copy.
+    let series = svg
+        .split("<path")
+        .filter(|p| p.split('>').next().unwrap_or("").matches(" L ").count() > 20)
+        .count();
+    assert_eq!(series, 2);
+
+    chart.save("./tests/inset_1.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_inset_without_indicator() -> Result<(), Box<dyn Error>> {
+    let a = vec!["w", "x", "y", "z"];
+    let b = vec![4.0, 3.0, 5.0, 1.0];
+
+    let main = chart!(a, b)?
+        .mark_point()?
+        .encode((alt::x("a"), alt::y("b")))?;
+    let svg_plain = main.to_svg()?;
+
+    let inner = chart!(a, b)?
+        .mark_bar()?
+        .encode((alt::x("a"), alt::y("b")))?;
+    let svg = main
+        .with_inset(
+            inner,
+            InsetSpec::new()
+                .with_anchor(InsetAnchor::BottomRight)
+                .with_indicator(false)
+                .with_axes(false),
+        )
+        .to_svg()?;
+
+    // No zoom rectangle: only the inset frame and its clip region add rectangles.
+    assert_eq!(
+        svg.matches("<rect").count(),
+        svg_plain.matches("<rect").count() + 2
+    );
+
+    Ok(())
+}
@@ -0,0 +1,145 @@
+use charton::prelude::*;
+use std::error::Error;
+
+#[test]
+fn test_legend_line_glyph() -> Result<(), Box<dyn Error>> {
+    let x = [1.0, 2.0, 3.0, 1.0, 2.0, 3.0];
+    let y = [1.0, 3.0, 2.0, 2.0, 1.0, 3.0];
+    let series = ["a", "a", "a", "b", "b", "b"];
+
+    let svg = chart!(x, y, series)?
+        .mark_line()?
+        .configure_line(|l| l.with_dash(vec![4.0, 2.0]))
+        .encode((alt::x("x"), alt::y("y"), alt::color("series")))?
+        .to_svg()?;
+    // One dashed line sample per series, and no marker.
+    let samples = svg
+        .lines()
+        .filter(|l| l.starts_with("<line") && l.contains(r#"stroke-dasharray="4.0,2.0""#))
+        .count();
+    assert_eq!(samples, 2);
+    assert!(!svg.contains("<circle"));
+
+    Ok(())
+}
+
+#[test]
+fn test_legend_combined_glyph() -> Result<(), Box<dyn Error>> {
+    let x = [1.0, 2.0, 3.0, 1.0, 2.0, 3.0];
+    let y = [1.0, 3.0, 2.0, 2.0, 1.0, 3.0];
+    let series = ["a", "a", "a", "b", "b", "b"];
+
+    let lines = chart!(x, y, series)?.mark_line()?.encode((
+        alt::x("x"),
+        alt::y("y"),
+        alt::color("series"),
+    ))?;
+    let points = chart!(x, y, series)?.mark_point()?.encode((
+        alt::x("x"),
+        alt::y("y"),
+        alt::color("series"),
+    ))?;
+    let chart = lines.and(points).with_title("Line with points");
+
+    let svg = chart.to_svg()?;
+    // Six data points plus a marker over each of the two line samples.
+    assert_eq!(svg.matches("<circle").count(), 8);
+    assert!(svg.contains(r#"stroke="rgba(31,119,180,1.000)" stroke-width="2.000""#));
+
+    chart.save("./tests/legend_1.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_legend_swatch_glyph() -> Result<(), Box<dyn Error>> {
+    let group = ["a", "b", "c"];
+    let value = [3.0, 5.0, 4.0];
+
+    let svg = chart!(group, value)?
+        .mark_bar()?
+        .encode((alt::x("group"), alt::y("value"), alt::color("group")))?
+        .to_svg()?;
+    assert_eq!(svg.matches(r#"width="12.000" height="12.000""#).count(), 3);
+    assert!(!svg.contains("<circle"));
+
+    Ok(())
+}
+
+#[test]
+fn test_legend_svg_standalone() -> Result<(), Box<dyn Error>> {
+    let x = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+    let y = [2.0, 4.0, 3.0, 5.0, 1.0, 6.0];
+    let species = [
+        "setosa",
+        "setosa",
+        "versicolor",
+        "versicolor",
+        "virginica",
+        "virginica",
+    ];
+
+    let chart = chart!(x, y, species)?
+        .mark_point()?
+        .encode((alt::x("x"), alt::y("y"), alt::color("species")))?
+        .with_legend(false);
+
+    let legend = chart.legend_svg()?;
+    std::fs::write("./tests/legend_2.svg", &legend)?;
+    assert!(legend.starts_with("<svg"));
+    assert_eq!(legend.matches("<circle").count(), 3);
+    for label in ["species", "setosa", "versicolor", "virginica"] {
+        assert!(
+            legend.contains(&format!(">{}</text>", label)),
+            "missing {}",
+            label
+        );
+    }
+
+    // The hidden legend draws nothing, yet the swatches match the marks.
+    let svg = chart.to_svg()?;
+    assert!(!svg.contains(">versicolor</text>"));
+    let circle_fills = |doc: &str| -> Vec<String> {
+        doc.split(r#"<circle cx=""#)
+            .skip(1)
+            .filter_map(|c| c.split(r#"fill=""#).nth(1))
+            .filter_map(|f| f.split('"').next())
+            .map(str::to_string)
+            .collect()
+    };
+    let swatches = circle_fills(&legend);
+    let marks = circle_fills(&svg);
+    assert_eq!(marks.len(), 6);
+    for (i, fill) in swatches.iter().enumerate() {
+        assert_eq!(fill, &marks[2 * i]);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_legend_hidden_widens_panel() -> Result<(), Box<dyn Error>> {
+    let x = [1.0, 2.0, 3.0];
+    let y = [2.0, 4.0, 3.0];
+    let group = ["a long group name", "b", "c"];
+
+    let shown = chart!(x, y, group)?
+        .mark_point()?
+        .encode((alt::x("x"), alt::y("y"), alt::color("group")))?
+        .with_legend(true);
+    let hidden = shown.clone().with_legend(false);
+
+    let (_, shown_panel, ..) = shown.resolve_scene()?;
+    let (_, hidden_panel, ..) = hidden.resolve_scene()?;
+    assert!(hidden_panel.width > shown_panel.width + 50.0);
+    assert_eq!(hidden_panel.height, shown_panel.height);
+
+    // A chart without color, shape or size encodings has no legend.
+    let plain = chart!(x, y)?
+        .mark_point()?
+        .encode((alt::x("x"), alt::y("y")))?
+        .with_legend(true);
+    assert!(plain.legend_svg().is_err());
+
+    Ok(())
+}
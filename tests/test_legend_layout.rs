@@ -0,0 +1,124 @@
+use charton::core::guide::LegendPosition;
+use charton::prelude::*;
+use std::error::Error;
+
+/// Returns the (x, y) anchor of the first text element with the given content.
+fn text_anchor(svg: &str, content: &str) -> Option<(f64, f64)> {
+    let end = svg.find(&format!(">{}</text>", content))?;
+    let start = svg[..end].rfind("<text")?;
+    let tag = &svg[start..end];
+    let attr = |name: &str| -> Option<f64> {
+        let from = tag.find(&format!(" {}=\"", name))? + name.len() + 3;
+        tag[from..].split('"').next()?.parse().ok()
+    };
+    Some((attr("x")?, attr("y")?))
+}
+
+#[test]
+fn test_legend_layout_bottom_rotated_labels() -> Result<(), Box<dyn Error>> {
+    let product: Vec<String> = (0..12)
+        .map(|i| format!("Product line number {}", i))
+        .collect();
+    let sales: Vec<f64> = (0..12)
+        .map(|i| 10.0 + (i as f64 * 1.3).sin() * 5.0)
+        .collect();
+    let region: Vec<&str> = (0..12).map(|i| ["North", "South", "East"][i % 3]).collect();
+
+    let chart = chart!(product, sales, region)?
+        .mark_point()?
+        .encode((alt::x("product"), alt::y("sales"), alt::color("region")))?
+        .with_theme(
+            Theme::default()
+                .with_x_tick_label_angle(-45.0)
+                .with_legend_position(LegendPosition::Bottom),
+        );
+
+    let svg = chart.to_svg()?;
+    let (_, axis_title_y) = text_anchor(&svg, "product").ok_or("missing axis title")?;
+    let (_, legend_title_y) = text_anchor(&svg, "region").ok_or("missing legend title")?;
+    let (_, last_entry_y) = text_anchor(&svg, "East").ok_or("missing legend entry")?;
+
+    // The legend starts below the rotated tick labels and the axis title,
+    // and still fits on the canvas.
+    assert!(legend_title_y > axis_title_y + 15.0);
+    assert!(last_entry_y < 400.0);
+
+    chart.save("./tests/legend_layout_1.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_legend_layout_right_long_labels() -> Result<(), Box<dyn Error>> {
+    let site: Vec<String> = (0..5)
+        .map(|i| format!("Northern distribution center {}", i))
+        .collect();
+    let volume = vec![12.0, 30.0, 18.0, 25.0, 7.0];
+    let carrier = vec![
+        "Rail freight",
+        "Road haulage",
+        "Sea freight",
+        "Rail freight",
+        "Air",
+    ];
+
+    let chart = chart!(site, volume, carrier)?.mark_point()?.encode((
+        alt::x("volume"),
+        alt::y("site"),
+        alt::color("carrier"),
+    ))?;
+
+    let svg = chart.to_svg()?;
+    let (label_x, _) =
+        text_anchor(&svg, "Northern distribution center 0").ok_or("missing tick label")?;
+    let (legend_x, _) = text_anchor(&svg, "carrier").ok_or("missing legend title")?;
+    let clip = svg.split("<clipPath").nth(1).ok_or("missing panel clip")?;
+    let attr = |name: &str| -> Option<f64> {
+        let from = clip.find(&format!(" {}=\"", name))? + name.len() + 3;
+        clip[from..].split('"').next()?.parse().ok()
+    };
+    let panel_right = attr("x").ok_or("missing x")? + attr("width").ok_or("missing width")?;
+
+    // The long labels push the panel right, and the legend stays beside it.
+    assert!(label_x > 150.0);
+    assert!(legend_x > panel_right);
+    assert!(legend_x < 450.0);
+
+    chart.save("./tests/legend_layout_2.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_legend_layout_horizontal_colorbar() -> Result<(), Box<dyn Error>> {
+    let x: Vec<f64> = (0..20).map(|i| i as f64).collect();
+    let y: Vec<f64> = x.iter().map(|v| (v * 0.4).sin()).collect();
+    let temperature: Vec<f64> = x.iter().map(|v| 1000.0 + v * 250.0).collect();
+
+    let base = chart!(x, y, temperature)?.mark_point()?.encode((
+        alt::x("x"),
+        alt::y("y"),
+        alt::color("temperature"),
+    ))?;
+
+    // Beside the plot the bar stands upright with labels on its right.
+    let svg = base.clone().to_svg()?;
+    assert!(svg.contains(r#"x2="0%" y2="100%""#));
+
+    let chart = base.with_theme(Theme::default().with_legend_position(LegendPosition::Bottom));
+    let svg = chart.to_svg()?;
+
+    // Below the plot it lies flat, with centered labels under the strip.
+    assert!(svg.contains(r#"x2="100%" y2="0%""#));
+    let (_, title_y) = text_anchor(&svg, "temperature").ok_or("missing legend title")?;
+    let (low_x, low_y) = text_anchor(&svg, "1000").ok_or("missing first label")?;
+    let (high_x, high_y) = text_anchor(&svg, "5000").ok_or("missing last label")?;
+    assert!(low_y > title_y && low_y == high_y);
+    assert!(low_x < high_x);
+    // The end labels stay on the canvas.
+    assert!(low_x > 10.0 && high_x < 490.0);
+
+    chart.save("./tests/legend_layout_3.svg")?;
+
+    Ok(())
+}
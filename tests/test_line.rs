@@ -1,3 +1,4 @@
+use charton::error::ChartonError;
 use charton::prelude::*;
 use std::error::Error;
 
@@ -87,3 +88,151 @@ fn test_line_4() -> Result<(), Box<dyn Error>> {
 
     Ok(())
 }
+
+#[test]
+fn test_line_date_axis() -> Result<(), Box<dyn Error>> {
+    use charton::prelude::ctime::{Date, Month};
+
+    let start = Date::from_calendar_date(2024, Month::January, 1)?;
+    let day: Vec<Date> = (0..120).map(|i| start + ctime::Duration::days(i)).collect();
+    let value: Vec<f64> = (0..120).map(|i| (i as f64 / 10.0).sin()).collect();
+
+    let chart = chart!(day, value)?
+        .mark_line()?
+        .encode((alt::x("day"), alt::y("value")))?;
+
+    // Dates are placed on the temporal axis and labeled by month.
+    let svg = chart.to_svg()?;
+    assert!(svg.contains(">2024-01<"));
+    assert!(svg.contains(">2024-03<"));
+
+    let formatted = chart.with_x_time_format("%b %Y");
+    assert!(formatted.to_svg()?.contains(">Feb 2024<"));
+    formatted.save("./tests/line_5.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_line_single_date() -> Result<(), Box<dyn Error>> {
+    use charton::prelude::ctime::{Date, Month};
+
+    let day = vec![Date::from_calendar_date(2024, Month::May, 10)?];
+    let value = vec![1.0];
+
+    // A single timestamp is padded by one day on each side.
+    let svg = chart!(day, value)?
+        .mark_point()?
+        .encode((alt::x("day"), alt::y("value")))?
+        .to_svg()?;
+    assert!(svg.contains(">2024-05-09<"));
+    assert!(svg.contains(">2024-05-11<"));
+
+    Ok(())
+}
+
+#[test]
+fn test_line_datetime_stock_prices() -> Result<(), Box<dyn Error>> {
+    use charton::prelude::ctime::{Date, Month};
+
+    let start = Date::from_calendar_date(2024, Month::January, 1)?
+        .midnight()
+        .assume_utc();
+    let timestamp: Vec<ctime::OffsetDateTime> =
+        (0..90).map(|i| start + ctime::Duration::days(i)).collect();
+    let price: Vec<f64> = (0..90)
+        .map(|i| 100.0 + 5.0 * (i as f64 / 7.0).sin() + i as f64 * 0.2)
+        .collect();
+
+    let chart = chart!(timestamp, price)?
+        .mark_line()?
+        .encode((alt::x("timestamp"), alt::y("price")))?;
+
+    // Readable date labels out of the box, never raw nanoseconds.
+    let svg = chart.to_svg()?;
+    assert!(svg.contains(">2024-02<") || svg.contains(">2024-02-01<"));
+    assert!(!svg.contains("E18<"));
+
+    // Explicit ticks may be given as date strings.
+    let svg = chart
+        .clone()
+        .with_x_ticks(["2024-01-15", "2024-02-15", "2024-03-15T00:00:00Z"])
+        .to_svg()?;
+    for label in ["2024-01-15", "2024-02-15", "2024-03-15"] {
+        assert!(svg.contains(&format!(">{}<", label)), "missing {}", label);
+    }
+
+    // A string that is not a date is reported rather than dropped.
+    let result = chart.with_x_ticks(["2024-01-15", "Feb 15"]).to_svg();
+    assert!(matches!(result, Err(ChartonError::Data(msg)) if msg.contains("Feb 15")));
+
+    Ok(())
+}
+
+#[test]
+fn test_line_detail() -> Result<(), Box<dyn Error>> {
+    let year = [
+        2020.0, 2021.0, 2022.0, 2020.0, 2021.0, 2022.0, 2020.0, 2021.0, 2022.0,
+    ];
+    let gdp = [1.0, 1.4, 1.9, 2.2, 2.0, 2.6, 0.6, 0.9, 0.8];
+    let country = ["A", "A", "A", "B", "B", "B", "C", "C", "C"];
+    let region = [
+        "north", "north", "north", "south", "south", "south", "north", "north", "north",
+    ];
+
+    // Counts the paths drawn inside the clipped panel.
+    let panel_paths = |svg: &str| -> usize {
+        svg.split("<g clip-path")
+            .nth(1)
+            .and_then(|s| s.split("</g>").next())
+            .map_or(0, |p| p.matches("<path").count())
+    };
+
+    // One path per country, without colors or a legend.
+    let chart = chart!(year, gdp, country, region)?
+        .mark_line()?
+        .encode((alt::x("year"), alt::y("gdp"), alt::detail("country")))?
+        .with_title("One line per country");
+    let svg = chart.to_svg()?;
+    assert_eq!(panel_paths(&svg), 3);
+    assert!(!svg.contains(">country<"));
+    chart.save("./tests/line_6.svg")?;
+
+    // With a color encoding, lines of one color stay separate; the legend
+    // lists the colors only.
+    let svg = chart!(year, gdp, country, region)?
+        .mark_line()?
+        .encode((
+            alt::x("year"),
+            alt::y("gdp"),
+            alt::color("region"),
+            alt::detail("country"),
+        ))?
+        .to_svg()?;
+    assert_eq!(panel_paths(&svg), 3);
+    assert!(svg.contains(">north<") && svg.contains(">south<"));
+    assert!(!svg.contains(">C<"));
+
+    // Without it, the rows of all countries are joined into one path.
+    let svg = chart!(year, gdp, country)?
+        .mark_line()?
+        .encode((alt::x("year"), alt::y("gdp")))?
+        .to_svg()?;
+    assert_eq!(panel_paths(&svg), 1);
+
+    // Only lines accept a detail field, and it must exist.
+    let point = chart!(year, gdp, country)?.mark_point()?.encode((
+        alt::x("year"),
+        alt::y("gdp"),
+        alt::detail("country"),
+    ));
+    assert!(matches!(point, Err(ChartonError::Encoding(_))));
+    let missing = chart!(year, gdp)?.mark_line()?.encode((
+        alt::x("year"),
+        alt::y("gdp"),
+        alt::detail("country"),
+    ));
+    assert!(matches!(missing, Err(ChartonError::Data(_))));
+
+    Ok(())
+}
@@ -0,0 +1,119 @@
+use charton::prelude::*;
+use std::error::Error;
+
+/// Returns the centers of all circles, in document order.
+fn circle_centers(svg: &str) -> Vec<(f64, f64)> {
+    svg.lines()
+        .filter(|l| l.trim_start().starts_with("<circle"))
+        .filter_map(|l| {
+            let attr = |name: &str| -> Option<f64> {
+                l.split(&format!(r#" {}=""#, name))
+                    .nth(1)?
+                    .split('"')
+                    .next()?
+                    .parse()
+                    .ok()
+            };
+            Some((attr("cx")?, attr("cy")?))
+        })
+        .collect()
+}
+
+#[test]
+fn test_offset_1() -> Result<(), Box<dyn Error>> {
+    let dose = ["low", "low", "low", "high", "high", "high"];
+    let trial = ["t1", "t2", "t3", "t1", "t2", "t3"];
+    let response = [2.0, 2.0, 2.0, 5.0, 5.0, 5.0];
+
+    let chart = chart!(dose, trial, response)?
+        .mark_point()?
+        .configure_point(|p| p.with_size(6.0).with_dodge_width(0.2))
+        .encode((
+            alt::x("dose"),
+            alt::y("response"),
+            alt::x_offset("trial"),
+            alt::color("trial"),
+        ))?
+        .with_title("Dodged trials");
+    let svg = chart.to_svg()?;
+
+    // Every row is drawn once; within a category the three trials sit at three
+    // evenly spaced, centered positions.
+    let centers = circle_centers(&svg);
+    let mut low: Vec<f64> = centers
+        .iter()
+        .filter(|c| centers.iter().all(|o| o.1 <= c.1))
+        .map(|c| c.0)
+        .collect();
+    low.sort_by(f64::total_cmp);
+    assert_eq!(low.len(), 3);
+    assert!((low[1] - low[0] - (low[2] - low[1])).abs() < 0.01);
+    assert!(low[1] - low[0] > 1.0);
+
+    chart.save("./tests/offset_1.svg")?;
+
+    // Without a color encoding the offset alone still dodges, in trial order.
+    let plain = chart!(dose, trial, response)?
+        .mark_point()?
+        .configure_point(|p| p.with_dodge_width(0.2))
+        .encode((alt::x("dose"), alt::y("response"), alt::x_offset("trial")))?
+        .to_svg()?;
+    let centers = circle_centers(&plain);
+    assert!(centers[0].0 < centers[1].0 && centers[1].0 < centers[2].0);
+    assert!(centers[3].0 < centers[4].0 && centers[4].0 < centers[5].0);
+
+    // An even number of lanes is centered on the category too.
+    let pair = |offset: bool| -> Result<Vec<(f64, f64)>, Box<dyn Error>> {
+        let dose = ["low", "low", "high"];
+        let trial = ["t1", "t2", "t1"];
+        let response = [2.0, 2.0, 5.0];
+        let chart = chart!(dose, trial, response)?
+            .mark_point()?
+            .configure_point(|p| p.with_dodge_width(0.2));
+        let svg = if offset {
+            chart.encode((alt::x("dose"), alt::y("response"), alt::x_offset("trial")))?
+        } else {
+            chart.encode((alt::x("dose"), alt::y("response")))?
+        }
+        .to_svg()?;
+        Ok(circle_centers(&svg))
+    };
+    let (dodged, plain) = (pair(true)?, pair(false)?);
+    assert!(((dodged[0].0 + dodged[1].0) / 2.0 - plain[0].0).abs() < 0.01);
+
+    Ok(())
+}
+
+#[test]
+fn test_offset_vertical_and_errors() -> Result<(), Box<dyn Error>> {
+    let team = ["a", "a", "b", "b"];
+    let season = ["2023", "2024", "2023", "2024"];
+    let score = [10.0, 10.0, 20.0, 20.0];
+
+    let svg = chart!(team, season, score)?
+        .mark_point()?
+        .encode((alt::x("score"), alt::y("team"), alt::y_offset("season")))?
+        .to_svg()?;
+    let centers = circle_centers(&svg);
+    assert_eq!(centers.len(), 4);
+    // Points with the same score and team are split vertically.
+    assert_ne!(centers[0].1, centers[1].1);
+    assert_eq!(centers[0].0, centers[1].0);
+
+    // Only point marks dodge by an offset encoding.
+    let err = chart!(team, season, score)?
+        .mark_bar()?
+        .encode((alt::x("team"), alt::y("score"), alt::x_offset("season")))
+        .err()
+        .expect("bars reject x_offset");
+    assert!(err.to_string().contains("x_offset"));
+
+    let err = chart!(team, season, score)?
+        .mark_point()?
+        .encode((alt::x("team"), alt::y("score"), alt::x_offset("missing")))
+        .err()
+        .expect("missing columns are reported");
+    assert!(err.to_string().contains("missing"));
+
+    Ok(())
+}
@@ -0,0 +1,195 @@
+use charton::error::ChartonError;
+use charton::prelude::*;
+use std::error::Error;
+
+/// Returns the `fill` and `stroke` attributes of all circles, in document order.
+fn circle_paints(svg: &str) -> Vec<(String, String)> {
+    svg.lines()
+        .filter(|l| l.trim_start().starts_with("<circle"))
+        .filter_map(|l| {
+            let attr = |name: &str| -> Option<String> {
+                Some(
+                    l.split(&format!(r#" {}=""#, name))
+                        .nth(1)?
+                        .split('"')
+                        .next()?
+                        .to_string(),
+                )
+            };
+            Some((attr("fill")?, attr("stroke")?))
+        })
+        .collect()
+}
+
+#[test]
+fn test_opacity_1() -> Result<(), Box<dyn Error>> {
+    let gdp = [1.0, 2.0, 3.0, 4.0];
+    let life = [60.0, 68.0, 74.0, 79.0];
+    let share = [0.2, 0.4, 0.6, 0.8];
+
+    let chart = chart!(gdp, life, share)?
+        .mark_point()?
+        .configure_point(|p| {
+            p.with_size(14.0)
+                .with_color("#1f77b4")
+                .with_stroke("black")
+                .with_stroke_width(1.5)
+        })
+        .encode((alt::x("gdp"), alt::y("life"), alt::fill_opacity("share")))?
+        .with_title("Translucent bubbles");
+    let svg = chart.to_svg()?;
+
+    // Each fill follows its row while the outlines stay solid.
+    let paints = circle_paints(&svg);
+    assert_eq!(paints.len(), 4);
+    for ((fill, stroke), alpha) in paints.iter().zip(["0.200", "0.400", "0.600", "0.800"]) {
+        assert!(fill.ends_with(&format!(",{})", alpha)), "{}", fill);
+        assert_eq!(stroke, "rgba(0,0,0,1.000)");
+    }
+    chart.save("./tests/opacity_1.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_opacity_precedence() -> Result<(), Box<dyn Error>> {
+    let x = [1.0, 2.0];
+    let y = [1.0, 2.0];
+    let shared = [0.0, 1.0];
+    let outline = [0.25, f64::NAN];
+
+    // The mapped opacity applies to both sides unless a side has its own.
+    let svg = chart!(x, y, shared, outline)?
+        .mark_point()?
+        .configure_point(|p| p.with_stroke("black"))
+        .encode((
+            alt::x("x"),
+            alt::y("y"),
+            alt::opacity("shared"),
+            alt::stroke_opacity("outline"),
+        ))?
+        .with_legend(false)
+        .to_svg()?;
+    let paints = circle_paints(&svg);
+    assert_eq!(paints.len(), 2);
+    assert!(paints[0].0.ends_with(",0.200)"), "{:?}", paints[0]);
+    assert!(paints[0].1.ends_with(",0.250)"), "{:?}", paints[0]);
+    // A null stroke opacity falls back to the mapped one.
+    assert!(svg.contains(r#"fill-opacity="1.000" stroke-opacity="1.000""#));
+
+    Ok(())
+}
+
+#[test]
+fn test_opacity_validation() -> Result<(), Box<dyn Error>> {
+    let x = ["a", "b"];
+    let y = [1.0, 2.0];
+
+    let bar = chart!(x, y)?
+        .mark_bar()?
+        .encode((alt::x("x"), alt::y("y"), alt::opacity("y")));
+    assert!(matches!(bar, Err(ChartonError::Encoding(_))));
+
+    let text_column =
+        chart!(x, y)?
+            .mark_point()?
+            .encode((alt::x("x"), alt::y("y"), alt::fill_opacity("x")));
+    assert!(matches!(text_column, Err(ChartonError::Encoding(_))));
+
+    let missing = chart!(x, y)?.mark_point()?.encode((
+        alt::x("x"),
+        alt::y("y"),
+        alt::stroke_opacity("alpha"),
+    ));
+    assert!(matches!(missing, Err(ChartonError::Data(_))));
+
+    Ok(())
+}
+
+/// Returns the effective fill alpha of every circle, in document order.
+fn circle_alphas(svg: &str) -> Vec<f64> {
+    circle_paints(svg)
+        .iter()
+        .zip(
+            svg.lines()
+                .filter(|l| l.trim_start().starts_with("<circle")),
+        )
+        .filter_map(|((fill, _), line)| {
+            let color: f64 = fill
+                .trim_end_matches(')')
+                .rsplit(',')
+                .next()?
+                .parse()
+                .ok()?;
+            let opacity: f64 = line
+                .split(r#" fill-opacity=""#)
+                .nth(1)?
+                .split('"')
+                .next()?
+                .parse()
+                .ok()?;
+            Some(color * opacity)
+        })
+        .collect()
+}
+
+#[test]
+fn test_opacity_scaled() -> Result<(), Box<dyn Error>> {
+    let ds = load_dataset("mtcars")?;
+
+    let chart = chart!(ds)?
+        .mark_point()?
+        .configure_point(|p| p.with_size(6.0).with_color("#1f77b4"))
+        .encode((alt::x("wt"), alt::y("mpg"), alt::opacity("hp")))?
+        .with_title("Opacity by horsepower");
+    chart.save("./tests/opacity_2.svg")?;
+    let svg = chart.to_svg()?;
+
+    // 32 marks plus the legend keys, which fade from the smallest value up.
+    let alphas = circle_alphas(&svg);
+    assert!(alphas.len() > 32);
+    let (marks, keys) = alphas.split_at(32);
+    let min = marks.iter().cloned().fold(f64::INFINITY, f64::min);
+    let max = marks.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    assert!((min - 0.2).abs() < 1e-3, "{min}");
+    assert!((max - 1.0).abs() < 1e-3, "{max}");
+    assert!(keys.len() >= 3);
+    assert!(keys.windows(2).all(|w| w[0] < w[1]), "{keys:?}");
+    assert!(svg.contains(">hp<"));
+
+    Ok(())
+}
+
+#[test]
+fn test_opacity_lines_and_layers() -> Result<(), Box<dyn Error>> {
+    let x = [1.0, 2.0, 1.0, 2.0];
+    let y = [1.0, 2.0, 2.0, 3.0];
+    let series = ["a", "a", "b", "b"];
+    let weight = [10.0, 10.0, 30.0, 30.0];
+
+    // Each line takes the mapped opacity of its first row.
+    let lines = chart!(x, y, series, weight)?.mark_line()?.encode((
+        alt::x("x"),
+        alt::y("y"),
+        alt::color("series"),
+        alt::opacity("weight").with_range(0.1, 0.9),
+    ))?;
+    let svg = lines.to_svg()?;
+    assert!(svg.contains(r#"stroke-opacity="0.100""#), "{svg}");
+    assert!(svg.contains(r#"stroke-opacity="0.900""#));
+
+    // The domain is shared with a point layer whose values reach further.
+    let px = [1.5];
+    let py = [2.5];
+    let pw = [50.0];
+    let points = chart!(px, py, pw)?.mark_point()?.encode((
+        alt::x("px"),
+        alt::y("py"),
+        alt::opacity("pw"),
+    ))?;
+    let svg = lines.and(points).to_svg()?;
+    // 30 now sits halfway along the 10..50 domain.
+    assert!(svg.contains(r#"stroke-opacity="0.500""#), "{svg}");
+
+    Ok(())
+}
@@ -1,4 +1,6 @@
+use charton::error::ChartonError;
 use charton::prelude::*;
+use std::collections::HashSet;
 use std::error::Error;
 
 #[test]
@@ -53,3 +55,342 @@ fn test_palette_3() -> Result<(), Box<dyn Error>> {
 
     Ok(())
 }
+
+/// Fourteen categories, more than the ten colors of the default Tab10 palette.
+fn many_categories() -> Result<Dataset, ChartonError> {
+    let labels: Vec<String> = (0..14).map(|i| format!("c{:02}", i)).collect();
+    let x: Vec<f64> = (0..14).map(|i| i as f64).collect();
+    Dataset::new()
+        .with_column("x", x.clone())?
+        .with_column("y", x)?
+        .with_column("label", labels)
+}
+
+/// Collects the distinct fill colors of every `<circle>` in an SVG document.
+fn circle_fills(svg: &str) -> HashSet<String> {
+    svg.split("<circle")
+        .skip(1)
+        .filter_map(|c| Some(c.split("fill=\"").nth(1)?.split('"').next()?.to_string()))
+        .collect()
+}
+
+#[test]
+fn test_palette_overflow() -> Result<(), Box<dyn Error>> {
+    let ds = many_categories()?;
+    let points =
+        chart!(&ds)?
+            .mark_point()?
+            .encode((alt::x("x"), alt::y("y"), alt::color("label")))?;
+
+    // Tab10 interpolates by default, so every category keeps its own color.
+    let svg = points.to_svg()?;
+    assert_eq!(circle_fills(&svg).len(), 14);
+    points.save("./tests/palette4.svg")?;
+
+    let cycled = points
+        .clone()
+        .with_palette_overflow(PaletteOverflow::Cycle)
+        .to_svg()?;
+    assert_eq!(circle_fills(&cycled).len(), 10);
+
+    let result = points.with_palette_overflow("error").to_svg();
+    assert!(matches!(result, Err(ChartonError::Scale(_))));
+
+    Ok(())
+}
+
+#[test]
+fn test_ordinal_color() -> Result<(), Box<dyn Error>> {
+    const LEVELS: [&str; 5] = [
+        "Strongly disagree",
+        "Disagree",
+        "Neutral",
+        "Agree",
+        "Strongly agree",
+    ];
+    // Data lists the responses out of order on purpose.
+    let question = ["Q1", "Q1", "Q1", "Q1", "Q1", "Q2", "Q2", "Q2", "Q2", "Q2"];
+    let response = [
+        "Agree",
+        "Neutral",
+        "Strongly agree",
+        "Disagree",
+        "Strongly disagree",
+        "Strongly agree",
+        "Agree",
+        "Neutral",
+        "Strongly disagree",
+        "Disagree",
+    ];
+    let count = [12.0, 5.0, 8.0, 3.0, 2.0, 10.0, 9.0, 4.0, 1.0, 6.0];
+
+    let chart = chart!(question, response, count)?.mark_bar()?.encode((
+        alt::x("question"),
+        alt::y("count").with_stack("stacked"),
+        alt::color("response")
+            .ordinal(&LEVELS)
+            .with_scheme(ColorMap::Blues),
+    ))?;
+
+    let svg = chart.to_svg()?;
+    // The legend follows the declared order.
+    let positions: Vec<usize> = LEVELS
+        .iter()
+        .map(|l| svg.find(&format!(">{}<", l)).unwrap_or(usize::MAX))
+        .collect();
+    assert!(positions.windows(2).all(|w| w[0] < w[1]));
+    // Colors come from the Blues gradient, not the default Tab10 palette.
+    assert!(!svg.contains("rgba(31,119,180"));
+
+    chart.save("./tests/palette5.svg")?;
+
+    // A typo in the data is reported instead of silently getting its own color.
+    let typo = ["Agree", "Agre", "Neutral"];
+    let n = [1.0, 2.0, 3.0];
+    let q = ["Q1", "Q1", "Q1"];
+    let strict = chart!(q, typo, n)?
+        .mark_bar()?
+        .encode((
+            alt::x("q"),
+            alt::y("n").with_stack("stacked"),
+            alt::color("typo").ordinal(&LEVELS),
+        ))?
+        .to_svg();
+    assert!(matches!(strict, Err(ChartonError::Encoding(msg)) if msg.contains("Agre")));
+
+    let appended = chart!(q, typo, n)?
+        .mark_bar()?
+        .encode((
+            alt::x("q"),
+            alt::y("n").with_stack("stacked"),
+            alt::color("typo")
+                .ordinal(&LEVELS)
+                .with_append_unlisted(true),
+        ))?
+        .to_svg()?;
+    assert!(appended.find(">Strongly agree<") < appended.find(">Agre<"));
+
+    Ok(())
+}
+
+#[test]
+fn test_color_domain_and_range() -> Result<(), Box<dyn Error>> {
+    const SPECIES: [&str; 3] = ["setosa", "versicolor", "virginica"];
+    let black = "rgba(0,0,0,1.000)";
+    let green = "rgba(44,160,44,1.000)";
+    let red = "rgba(255,0,0,1.000)";
+    let tab10_blue = "rgba(31,119,180,1.000)";
+
+    let species = ["virginica", "setosa", "versicolor", "setosa"];
+    let x = [1.0, 2.0, 3.0, 4.0];
+    let chart = chart!(species, x)?
+        .mark_point()?
+        .encode((alt::x("x"), alt::y("x"), alt::color("species")))?
+        .with_color_domain(SPECIES.to_vec())
+        .with_color_range(vec!["#000", "#2ca02c", "red"]);
+
+    let svg = chart.to_svg()?;
+    assert_eq!(
+        circle_fills(&svg),
+        HashSet::from([black, green, red].map(String::from))
+    );
+    // The legend follows the domain, not the order of appearance.
+    let positions: Vec<usize> = SPECIES
+        .iter()
+        .map(|s| svg.find(&format!(">{}<", s)).unwrap_or(usize::MAX))
+        .collect();
+    assert!(positions.windows(2).all(|w| w[0] < w[1]));
+    chart.save("./tests/palette_mapping.svg")?;
+
+    // Filtering the data keeps every category on its color and in the legend.
+    let species = ["virginica"];
+    let x = [1.0];
+    let filtered = chart!(species, x)?
+        .mark_point()?
+        .encode((alt::x("x"), alt::y("x"), alt::color("species")))?
+        .with_color_domain(SPECIES.to_vec())
+        .with_color_range(vec!["#000", "#2ca02c", "red"])
+        .to_svg()?;
+    assert_eq!(filtered.matches(&format!(r#"fill="{}""#, red)).count(), 2);
+    assert!(filtered.contains(">setosa<"));
+
+    // A category missing from the mapping takes the palette after the domain.
+    let species = ["hybrid", "setosa", "virginica"];
+    let x = [1.0, 2.0, 3.0];
+    let extra = chart!(species, x)?
+        .mark_point()?
+        .encode((alt::x("x"), alt::y("x"), alt::color("species")))?
+        .with_color_domain(SPECIES.to_vec())
+        .with_color_range(vec!["#000", "#2ca02c", "red"])
+        .to_svg()?;
+    assert!(circle_fills(&extra).contains(tab10_blue));
+    assert!(extra.find(">virginica<") < extra.find(">hybrid<"));
+
+    // Without a domain, the range pairs up with the categories in order of appearance.
+    let species = ["b", "a"];
+    let x = [1.0, 2.0];
+    let range_only = chart!(species, x)?
+        .mark_point()?
+        .encode((alt::x("x"), alt::y("x"), alt::color("species")))?
+        .with_color_range(vec!["red"])
+        .to_svg()?;
+    assert_eq!(
+        circle_fills(&range_only),
+        HashSet::from([red, tab10_blue].map(String::from))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_custom_color_map() -> Result<(), Box<dyn Error>> {
+    let ds = load_dataset("mtcars")?;
+    let brand = ColorGradient::custom(vec![(0.0, "#000000"), (0.5, "#ff0000"), (1.0, "#ffffff")])?;
+
+    let chart = Chart::build(ds)?
+        .mark_point()?
+        .encode((alt::x("wt"), alt::y("mpg"), alt::color("hp")))?
+        .configure_theme(|t| t.with_color_map(brand));
+
+    let svg = chart.to_svg()?;
+    // The colorbar gradient runs from black through red to white.
+    assert!(svg.contains(r#"stop-color="rgba(0,0,0,1.000)""#));
+    assert!(svg.contains(r#"stop-color="rgba(255,85,85,1.000)""#));
+    assert!(svg.contains(r#"stop-color="rgba(136,0,0,1.000)""#));
+    assert!(svg.contains(r#"stop-color="rgba(255,255,255,1.000)""#));
+
+    chart.save("./tests/color_map_custom.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_custom_color_map_invalid() {
+    // Positions must be increasing and cover [0, 1]; colors must parse.
+    assert!(ColorGradient::custom(vec![(0.0, "#000"), (0.8, "#fff")]).is_err());
+    assert!(
+        ColorGradient::custom(vec![
+            (0.0, "#000"),
+            (0.6, "#888"),
+            (0.4, "#aaa"),
+            (1.0, "#fff")
+        ])
+        .is_err()
+    );
+    assert!(ColorGradient::custom(vec![(0.0, "#000")]).is_err());
+    assert!(matches!(
+        ColorGradient::custom(vec![(0.0, "#000"), (1.0, "not-a-color")]),
+        Err(ChartonError::Scale(msg)) if msg.contains("not-a-color")
+    ));
+}
+
+#[test]
+fn test_custom_palette() -> Result<(), Box<dyn Error>> {
+    let ds = load_dataset("mtcars")?;
+    let brand = ColorPalette::custom(["#0b1d3a", "#e9c46a"])?;
+
+    // Three gears on a two-color palette: the third category wraps to the first color.
+    let chart = Chart::build(ds)?
+        .mark_point()?
+        .encode((
+            alt::x("wt"),
+            alt::y("mpg"),
+            alt::color("gear").with_scale(Scale::Discrete),
+        ))?
+        .with_color_palette(brand)
+        .with_palette_overflow(PaletteOverflow::Cycle);
+
+    let svg = chart.to_svg()?;
+    let navy = svg.matches(r#"fill="rgba(11,29,58,1.000)""#).count();
+    let sand = svg.matches(r#"fill="rgba(233,196,106,1.000)""#).count();
+    // 15 + 5 cars with 3 or 5 gears and 12 with 4 gears, plus the legend swatches.
+    assert_eq!(navy, 20 + 2);
+    assert_eq!(sand, 12 + 1);
+
+    chart.save("./tests/palette_custom.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_custom_palette_invalid() {
+    assert!(ColorPalette::custom(["#123456", "steelblue", "none"]).is_ok());
+    assert!(ColorPalette::custom(Vec::<&str>::new()).is_err());
+    assert!(matches!(
+        ColorPalette::custom(["#123456", "#12345z"]),
+        Err(ChartonError::Scale(msg)) if msg.contains("#12345z")
+    ));
+}
+
+#[test]
+fn test_colorblind_palette() -> Result<(), Box<dyn Error>> {
+    let ds = load_dataset("mtcars")?;
+
+    let chart = Chart::build(ds)?
+        .mark_point()?
+        .encode((
+            alt::x("wt"),
+            alt::y("mpg"),
+            alt::color("cyl").with_scale(Scale::Discrete),
+        ))?
+        .configure_theme(|t| t.with_palette(ColorPalette::OkabeIto));
+
+    let svg = chart.to_svg()?;
+    // Okabe-Ito starts with orange, sky blue and bluish green.
+    let fills = circle_fills(&svg);
+    for color in [
+        "rgba(230,159,0,1.000)",
+        "rgba(86,180,233,1.000)",
+        "rgba(0,158,115,1.000)",
+    ] {
+        assert!(fills.contains(color), "missing {}", color);
+    }
+
+    chart.save("./tests/palette6.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_cvd_simulation() {
+    use charton::visual::color::{CvdKind, check_distinguishable, simulate_cvd};
+
+    let kinds = [
+        CvdKind::Protanopia,
+        CvdKind::Deuteranopia,
+        CvdKind::Tritanopia,
+    ];
+
+    // Grays carry no hue information, so every deficiency leaves them alone.
+    let seen = simulate_cvd(
+        ["#000000", "#ffffff", "rgba(128,128,128,0.5)"],
+        CvdKind::Deuteranopia,
+    );
+    assert_eq!(
+        seen,
+        [
+            "rgba(0,0,0,1.000)",
+            "rgba(255,255,255,1.000)",
+            "rgba(128,128,128,0.500)"
+        ]
+    );
+    assert_eq!(
+        simulate_cvd(ColorPalette::TolBright, CvdKind::Protanopia).len(),
+        7
+    );
+
+    for kind in kinds {
+        assert!(check_distinguishable(ColorPalette::OkabeIto, kind).is_empty());
+        assert!(check_distinguishable(ColorPalette::TolMuted, kind).is_empty());
+    }
+
+    // Tab10's green and red collapse for deuteranopes.
+    let pairs = check_distinguishable(ColorPalette::Tab10, CvdKind::Deuteranopia);
+    assert!(pairs.iter().any(|&(i, j, _)| (i, j) == (2, 3)));
+    assert!(pairs.windows(2).all(|w| w[0].2 <= w[1].2));
+    assert!(pairs.iter().all(|&(i, j, _)| i < j && j < 10));
+
+    let red_green = check_distinguishable(["#e41a1c", "#4daf4a"], CvdKind::Deuteranopia);
+    assert_eq!(red_green.len(), 1);
+    assert_eq!((red_green[0].0, red_green[0].1), (0, 1));
+}
@@ -1,3 +1,4 @@
+use charton::error::ChartonError;
 use charton::prelude::*;
 use std::error::Error;
 
@@ -87,3 +88,221 @@ fn test_nightingale() -> Result<(), Box<dyn Error>> {
 
     Ok(())
 }
+
+/// Every point of every slice outline, in pixels.
+fn slice_points(svg: &str) -> Vec<(f64, f64)> {
+    svg.split("<path d=\"")
+        .skip(1)
+        .flat_map(|rest| {
+            let d = &rest[..rest.find('"').unwrap()];
+            let nums: Vec<f64> = d
+                .split(|c: char| c.is_whitespace() || c.is_ascii_alphabetic())
+                .filter_map(|t| t.parse().ok())
+                .collect();
+            nums.chunks(2).map(|p| (p[0], p[1])).collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+#[test]
+fn test_donut_ratio_and_center_text() -> Result<(), Box<dyn Error>> {
+    let category = ["A", "B", "C", "D"];
+    let value = [40.0, 30.0, 20.0, 10.0];
+
+    let svg = chart!(value, category)?
+        .mark_bar()?
+        .encode((alt::x(""), alt::y("value"), alt::color("category")))?
+        .with_coord(CoordSystem::Polar)
+        .with_inner_radius_ratio(0.6)
+        .with_center_text("Total 100")
+        .with_legend(false)
+        .to_svg()?;
+
+    assert!(svg.contains("Total 100"));
+
+    // Slices are annular sectors: no outline point comes closer to the center
+    // than 60% of the outer radius.
+    let points = slice_points(&svg);
+    let (min_x, max_x) = points.iter().fold((f64::MAX, f64::MIN), |(lo, hi), p| {
+        (lo.min(p.0), hi.max(p.0))
+    });
+    let (cx, outer) = ((min_x + max_x) / 2.0, (max_x - min_x) / 2.0);
+    let cy = points.iter().fold((f64::MAX, f64::MIN), |(lo, hi), p| {
+        (lo.min(p.1), hi.max(p.1))
+    });
+    let cy = (cy.0 + cy.1) / 2.0;
+    let nearest = points
+        .iter()
+        .map(|(x, y)| ((x - cx).powi(2) + (y - cy).powi(2)).sqrt())
+        .fold(f64::MAX, f64::min);
+    assert!((nearest / outer - 0.6).abs() < 0.01, "{nearest} / {outer}");
+
+    Ok(())
+}
+
+#[test]
+fn test_donut_ratio_out_of_range_rejected() -> Result<(), Box<dyn Error>> {
+    let category = ["A", "B"];
+    let value = [1.0, 2.0];
+
+    for ratio in [1.0, 1.5, -0.1] {
+        let result = chart!(value, category).and_then(|c| {
+            c.mark_bar()?
+                .encode((alt::x(""), alt::y("value"), alt::color("category")))?
+                .with_coord(CoordSystem::Polar)
+                .with_inner_radius_ratio(ratio)
+                .to_svg()
+        });
+        assert!(
+            matches!(result, Err(ChartonError::Encoding(_))),
+            "ratio {ratio}: {result:?}"
+        );
+    }
+
+    Ok(())
+}
+
+/// Returns the angle (in degrees) swept by each slice, in drawing order.
+fn slice_angles(svg: &str) -> Vec<f64> {
+    svg.lines()
+        .filter(|l| l.starts_with("<path"))
+        .map(|l| {
+            let d = l.split(r#"d=""#).nth(1).unwrap().split('"').next().unwrap();
+            let nums: Vec<f64> = d
+                .split_whitespace()
+                .filter_map(|t| t.parse().ok())
+                .collect();
+            let points: Vec<(f64, f64)> = nums.chunks(2).map(|p| (p[0], p[1])).collect();
+            let (cx, cy) = points[0];
+            let outer: Vec<f64> = points
+                .iter()
+                .filter(|(x, y)| (x - cx).hypot(y - cy) > 1.0)
+                .map(|(x, y)| (y - cy).atan2(x - cx))
+                .collect();
+            outer
+                .windows(2)
+                .map(|w| {
+                    let step = (w[1] - w[0]).rem_euclid(std::f64::consts::TAU);
+                    step.min(std::f64::consts::TAU - step)
+                })
+                .sum::<f64>()
+                .to_degrees()
+        })
+        .collect()
+}
+
+fn assert_angles(actual: &[f64], shares: &[f64]) {
+    let total: f64 = shares.iter().sum();
+    assert_eq!(actual.len(), shares.len(), "{actual:?}");
+    for (a, s) in actual.iter().zip(shares) {
+        assert!(
+            (a - 360.0 * s / total).abs() < 0.5,
+            "{actual:?} vs {shares:?}"
+        );
+    }
+}
+
+#[test]
+fn test_pie_repeated_categories_merge() -> Result<(), Box<dyn Error>> {
+    let category = ["A", "B", "A", "C", "B", "A"];
+    let value = [10.0, 20.0, 5.0, 15.0, 10.0, 5.0];
+
+    let pie = |op: &str, arc: Option<Option<AggregateOp>>| -> Result<Vec<f64>, Box<dyn Error>> {
+        let svg = chart!(value, category)?
+            .mark_bar()?
+            .configure_bar(|b| match arc {
+                Some(arc) => b.with_arc_aggregate(arc),
+                None => b,
+            })
+            .encode((
+                alt::x(""),
+                alt::y("value").with_aggregate(op),
+                alt::color("category"),
+            ))?
+            .with_coord(CoordSystem::Polar)
+            .to_svg()?;
+        Ok(slice_angles(&svg))
+    };
+
+    // One slice per category, merged with the y encoding's aggregate.
+    assert_angles(&pie("sum", None)?, &[20.0, 30.0, 15.0]);
+    assert_angles(&pie("mean", None)?, &[20.0 / 3.0, 15.0, 15.0]);
+
+    // An arc aggregate takes precedence over it.
+    assert_angles(
+        &pie("mean", Some(Some(AggregateOp::Max)))?,
+        &[10.0, 20.0, 15.0],
+    );
+
+    // Opting out keeps one slice per row, in data order.
+    assert_angles(&pie("sum", Some(None))?, &value);
+
+    Ok(())
+}
+
+#[test]
+fn test_pie_negative_values_rejected() -> Result<(), Box<dyn Error>> {
+    let category = ["A", "B", "C", "D"];
+    let value = [10.0, -2.0, 5.0, -1.5];
+
+    let result = chart!(value, category).and_then(|c| {
+        c.mark_bar()?
+            .encode((alt::x(""), alt::y("value"), alt::color("category")))?
+            .with_coord(CoordSystem::Polar)
+            .to_svg()
+    });
+
+    match result {
+        Err(ChartonError::Data(msg)) => assert!(msg.contains("rows [1, 3]"), "{msg}"),
+        other => panic!("expected a data error, got {other:?}"),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_wind_rose() -> Result<(), Box<dyn Error>> {
+    let ds = load_dataset("mtcars")?;
+
+    let chart = chart!(ds)?
+        .mark_bar()?
+        .encode((
+            alt::x("carb").with_scale(Scale::Discrete),
+            alt::y("hp").with_stack("stacked"),
+            alt::color("gear").with_scale(Scale::Discrete),
+        ))?
+        .with_coord_polar()
+        .with_grid(true);
+
+    let svg = chart.to_svg()?;
+    // One stacked sector per (carb, gear) combination present in the data.
+    assert!(svg.matches("<path").count() >= 6);
+
+    chart.save("./tests/wind_rose.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_radial_bar_grid() -> Result<(), Box<dyn Error>> {
+    let day = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+    let hours = [3.0, 8.0, 5.5, 9.0, 2.0, 6.0, 7.5];
+
+    let chart = chart!(day, hours)?
+        .mark_bar()?
+        .encode((alt::x("day"), alt::y("hours")))?
+        .with_coord_polar()
+        .with_grid(true)
+        .with_y_ticks([2.0, 4.0, 6.0, 8.0]);
+
+    let svg = chart.to_svg()?;
+    // The outer boundary is drawn by both the grid and the axes, plus one ring per radial tick.
+    assert_eq!(svg.matches("<circle").count(), 2 + 4);
+    for label in [">2<", ">4<", ">6<", ">8<"] {
+        assert!(svg.contains(label), "missing radial label {label}");
+    }
+
+    chart.save("./tests/radial_bar.svg")?;
+
+    Ok(())
+}
@@ -53,3 +53,67 @@ fn test_rect_3() -> Result<(), Box<dyn Error>> {
 
     Ok(())
 }
+
+/// Reads a numeric attribute of an SVG element.
+fn attr(element: &str, name: &str) -> f64 {
+    element
+        .split(&format!(r#" {}=""#, name))
+        .nth(1)
+        .and_then(|rest| rest.split('"').next())
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(f64::NAN)
+}
+
+#[test]
+fn test_rect_heatmap_cells() -> Result<(), Box<dyn Error>> {
+    let a = ["A", "B", "C", "A", "B", "C", "A", "B", "C"];
+    let b = ["X", "X", "X", "Y", "Y", "Y", "Z", "Z", "Z"];
+    let value = [1.0, -0.5, 0.2, -0.5, 1.0, 0.7, 0.2, 0.7, 1.0];
+
+    // The x axis is shared with a chart that also has a "D" column, so "D"
+    // takes a slot on the axis without any data.
+    let wide_a = ["A", "B", "C", "D"];
+    let wide_b = ["X", "X", "X", "X"];
+    let wide_value = [1.0, 1.0, 1.0, 1.0];
+    let wide: LayeredChart = chart!(wide_a, wide_b, wide_value)?
+        .mark_rect()?
+        .encode((alt::x("wide_a"), alt::y("wide_b"), alt::color("wide_value")))?
+        .into();
+    let scales = SharedScales {
+        x: SharedScales::from_chart(&wide)?.x,
+        ..Default::default()
+    };
+
+    let chart = chart!(a, b, value)?
+        .mark_rect()?
+        .configure_rect(|r| r.with_cell_border(4.0))
+        .encode((alt::x("a"), alt::y("b"), alt::color("value")))?
+        .with_size(500, 400)
+        .with_shared_scales(&scales)
+        .with_title("Correlation");
+
+    let svg = chart.to_svg()?;
+    let panel_width = svg
+        .split("<clipPath")
+        .nth(1)
+        .map(|clip| attr(clip, "width"))
+        .unwrap_or(f64::NAN);
+    let cells: Vec<&str> = svg
+        .lines()
+        .filter(|l| l.starts_with("<rect") && l.contains(r#"stroke="rgba(255,255,255,1.000)""#))
+        .collect();
+    assert_eq!(cells.len(), 9);
+    for cell in &cells {
+        // One band of the four-category axis, minus the border.
+        assert!((attr(cell, "width") + 4.0 - panel_width / 4.0).abs() < 0.01);
+    }
+    // Neighbouring cells are separated by the border.
+    let gap = attr(cells[1], "x") - attr(cells[0], "x") - attr(cells[0], "width");
+    assert!((gap - 4.0).abs() < 0.01);
+    // The continuous color channel is shown as a colorbar.
+    assert!(svg.contains("<linearGradient"));
+
+    chart.save("./tests/rect_4.svg")?;
+
+    Ok(())
+}
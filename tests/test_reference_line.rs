@@ -0,0 +1,83 @@
+use charton::prelude::*;
+use std::error::Error;
+
+/// Returns the points of the first SVG path whose line contains `needle`.
+fn path_points(svg: &str, needle: &str) -> Option<Vec<(f64, f64)>> {
+    let line = svg.lines().find(|l| l.contains(needle))?;
+    let d = line.split(r#"d=""#).nth(1)?.split('"').next()?;
+    let nums: Vec<f64> = d
+        .split(['M', 'L', ' '])
+        .filter_map(|t| t.parse().ok())
+        .collect();
+    Some(nums.chunks(2).map(|p| (p[0], p[1])).collect())
+}
+
+#[test]
+fn test_reference_line_1() -> Result<(), Box<dyn Error>> {
+    let ds = load_dataset("mtcars")?;
+
+    let chart = chart!(ds)?
+        .mark_point()?
+        .encode((alt::x("wt"), alt::y("mpg")))?
+        .with_hline(20.0)
+        .with_reference_line(
+            ReferenceLine::vertical(3.5)
+                .with_color("firebrick")
+                .with_width(2.0)
+                .with_dash(vec![6.0, 3.0]),
+        )
+        .with_title("Reference lines");
+
+    let svg = chart.to_svg()?;
+
+    // The vertical line is styled and spans the panel top to bottom at one x.
+    let v = path_points(&svg, r#"stroke-dasharray="6,3""#).ok_or("missing vline")?;
+    assert_eq!(v.len(), 2);
+    assert!((v[0].0 - v[1].0).abs() < 1e-6);
+    assert!(svg.contains(r#"stroke="rgba(178,34,34,1.000)" stroke-width="2.000""#));
+
+    // The horizontal line defaults to a solid line in the axis color.
+    let h = svg
+        .lines()
+        .filter(|l| l.starts_with("<path") && l.contains(r#"stroke="rgba(0,0,0,1.000)""#))
+        .filter_map(|l| path_points(l, "<path"))
+        .find(|p| p.len() == 2 && (p[0].1 - p[1].1).abs() < 1e-6 && p[0].0 != p[1].0);
+    assert!(h.is_some());
+
+    chart.save("./tests/reference_line_1.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_reference_line_follows_coordinates() -> Result<(), Box<dyn Error>> {
+    let x = [1.0, 2.0, 3.0];
+    let y = [0.0, 1.0, 2.0];
+
+    // The line is placed through the scales, so it moves with the panel.
+    let y_of_hline = |height: u32| -> Result<f64, Box<dyn Error>> {
+        let svg = chart!(x, y)?
+            .mark_point()?
+            .encode((alt::x("x"), alt::y("y").with_zero(false)))?
+            .with_reference_line(ReferenceLine::horizontal(1.0).with_dash(vec![2.0, 2.0]))
+            .with_size(400, height)
+            .to_svg()?;
+        let pts = path_points(&svg, r#"stroke-dasharray="2,2""#).ok_or("missing hline")?;
+        assert!((pts[0].1 - pts[1].1).abs() < 1e-6);
+        Ok(pts[0].1)
+    };
+    assert!((y_of_hline(300)? - y_of_hline(600)?).abs() > 50.0);
+
+    // Flipping the coordinates turns a y line vertical.
+    let svg = chart!(x, y)?
+        .mark_point()?
+        .encode((alt::x("x"), alt::y("y")))?
+        .with_reference_line(ReferenceLine::horizontal(1.0).with_dash(vec![2.0, 2.0]))
+        .coord_flip()
+        .to_svg()?;
+    let pts = path_points(&svg, r#"stroke-dasharray="2,2""#).ok_or("missing hline")?;
+    assert!((pts[0].0 - pts[1].0).abs() < 1e-6);
+    assert!((pts[0].1 - pts[1].1).abs() > 1.0);
+
+    Ok(())
+}
@@ -0,0 +1,95 @@
+use charton::error::ChartonError;
+use charton::prelude::*;
+use std::error::Error;
+
+#[test]
+fn test_register_dataset_1() -> Result<(), Box<dyn Error>> {
+    let csv =
+        "region,sales,note\nNorth,120,\nSouth,95.5,\"late, revised\"\nEast,,\"said \"\"ok\"\"\"\n";
+    register_dataset(
+        "regional_sales",
+        "Sales by region",
+        DatasetSource::Bytes(csv.as_bytes().to_vec(), DataFormat::Csv),
+        false,
+    )?;
+
+    let ds = load_dataset("regional_sales")?;
+    assert_eq!(ds.height(), 3);
+    assert_eq!(ds.get_column::<f64>("sales")?[1], 95.5);
+    assert!(ds.is_null("sales", 2));
+    assert!(ds.is_null("note", 0));
+    assert_eq!(
+        ds.column("note")?.get(2).to_string().as_deref(),
+        Some("said \"ok\"")
+    );
+
+    let chart = chart!(ds)?
+        .mark_bar()?
+        .encode((alt::x("region"), alt::y("sales")))?
+        .with_title("Registered dataset");
+    chart.save("./tests/register_dataset_1.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_register_dataset_sources_and_listing() -> Result<(), Box<dyn Error>> {
+    let path = std::env::temp_dir().join("charton_register_dataset.csv");
+    std::fs::write(&path, "x,y\n1,2\n3,4\n")?;
+    register_dataset(
+        "from_file",
+        "Read from disk",
+        DatasetSource::Path(path),
+        false,
+    )?;
+    assert_eq!(load_dataset("from_file")?.height(), 2);
+
+    fn small_iris() -> Result<Dataset, ChartonError> {
+        Ok(load_dataset("iris")?.head(5))
+    }
+    register_dataset(
+        "iris_head",
+        "First rows of iris",
+        DatasetSource::Loader(small_iris),
+        false,
+    )?;
+    assert_eq!(load_dataset("iris_head")?.height(), 5);
+
+    let names = list_datasets();
+    assert_eq!(names[0].0, "mtcars");
+    for name in ["iris", "from_file", "iris_head"] {
+        assert!(names.iter().any(|(n, _)| n == name), "missing {}", name);
+    }
+    assert!(
+        names
+            .iter()
+            .any(|(n, d)| n == "iris_head" && d == "First rows of iris")
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_register_dataset_name_collisions() -> Result<(), Box<dyn Error>> {
+    fn tiny() -> Result<Dataset, ChartonError> {
+        Dataset::new().with_column("value", vec![1.0, 2.0])
+    }
+
+    // Built-in names are protected unless overwrite is set.
+    let clash = register_dataset("penguins", "Mine", DatasetSource::Loader(tiny), false);
+    assert!(matches!(clash, Err(ChartonError::Data(_))));
+    assert_eq!(load_dataset("penguins")?.height(), 344);
+
+    register_dataset("tiny", "Two values", DatasetSource::Loader(tiny), false)?;
+    assert!(register_dataset("tiny", "Again", DatasetSource::Loader(tiny), false).is_err());
+    register_dataset("tiny", "Replaced", DatasetSource::Loader(tiny), true)?;
+    assert!(
+        list_datasets()
+            .iter()
+            .any(|(n, d)| n == "tiny" && d == "Replaced")
+    );
+
+    assert!(load_dataset("no_such_dataset").is_err());
+
+    Ok(())
+}
@@ -0,0 +1,142 @@
+use charton::prelude::*;
+use std::error::Error;
+
+/// Returns the `stop-color` values of the colorbar gradient, in order.
+fn stop_colors(svg: &str) -> Vec<&str> {
+    svg.split(r#"stop-color=""#)
+        .skip(1)
+        .filter_map(|s| s.split('"').next())
+        .collect()
+}
+
+/// Returns the centers of all circles, in document order.
+fn circle_centers(svg: &str) -> Vec<(f64, f64)> {
+    svg.lines()
+        .filter(|l| l.trim_start().starts_with("<circle"))
+        .filter_map(|l| {
+            let attr = |name: &str| -> Option<f64> {
+                l.split(&format!(r#" {}=""#, name))
+                    .nth(1)?
+                    .split('"')
+                    .next()?
+                    .parse()
+                    .ok()
+            };
+            Some((attr("cx")?, attr("cy")?))
+        })
+        .collect()
+}
+
+/// Returns the x position of the text element reading `label`.
+fn label_x(svg: &str, label: &str) -> Option<f64> {
+    let before = svg.split(&format!(">{}</text>", label)).next()?;
+    let tag = before.rsplit("<text").next()?;
+    tag.split(r#" x=""#).nth(1)?.split('"').next()?.parse().ok()
+}
+
+#[test]
+fn test_reversed_color_map() -> Result<(), Box<dyn Error>> {
+    let ds = load_dataset("mtcars")?;
+    let chart =
+        Chart::build(ds)?
+            .mark_point()?
+            .encode((alt::x("wt"), alt::y("mpg"), alt::color("hp")))?;
+
+    let forward = chart
+        .clone()
+        .configure_theme(|t| t.with_color_map(ColorMap::Viridis))
+        .to_svg()?;
+    let backward = chart
+        .configure_theme(|t| t.with_color_map(ColorMap::Viridis.reversed()))
+        .to_svg()?;
+
+    // The colorbar flips with the map.
+    let mut expected = stop_colors(&forward);
+    expected.reverse();
+    assert!(!expected.is_empty());
+    assert_eq!(stop_colors(&backward), expected);
+
+    assert_eq!(
+        ColorMap::Viridis.reversed().reversed(),
+        ColorGradient::from(ColorMap::Viridis)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_reverse_1() -> Result<(), Box<dyn Error>> {
+    let x = [1.0, 2.0, 3.0];
+    let y = [10.0, 20.0, 30.0];
+
+    let chart = chart!(x, y)?
+        .mark_point()?
+        .encode((alt::x("x"), alt::y("y")))?;
+    let normal = circle_centers(&chart.clone().to_svg()?);
+    let reversed = chart
+        .with_x_reversed(true)
+        .with_y_reversed(true)
+        .with_title("Reversed axes");
+    let svg = reversed.to_svg()?;
+    let flipped = circle_centers(&svg);
+
+    // Larger values move left and down.
+    assert_eq!(flipped.len(), 3);
+    assert!(flipped[0].0 > flipped[2].0);
+    assert!(flipped[0].1 < flipped[2].1);
+    assert!(normal[0].0 < normal[2].0);
+
+    // The ticks follow: the largest X label is now leftmost.
+    let (one, three) = (label_x(&svg, "1.0"), label_x(&svg, "3.0"));
+    assert!(
+        one.zip(three).is_some_and(|(a, b)| a > b),
+        "{:?}",
+        (one, three)
+    );
+    reversed.save("./tests/reverse_1.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_reversed_bars() -> Result<(), Box<dyn Error>> {
+    let category = ["a", "b", "c"];
+    let value = [1.0, 2.0, 3.0];
+
+    let svg = chart!(category, value)?
+        .mark_bar()?
+        .encode((alt::x("category"), alt::y("value")))?
+        .with_x_reversed(true)
+        .with_y_reversed(true)
+        .to_svg()?;
+
+    // Categories read right to left, and the bars hang from the zero baseline at the top.
+    let (a, c) = (label_x(&svg, "a"), label_x(&svg, "c"));
+    assert!(a.zip(c).is_some_and(|(a, c)| a > c), "{:?}", (a, c));
+    let bars: Vec<Vec<f64>> = svg
+        .lines()
+        .filter(|l| l.starts_with(r#"<path d="M "#) && l.contains(r#"fill="rgba(70,130,180"#))
+        .filter_map(|l| {
+            let d = l.split('"').nth(1)?;
+            let numbers: Vec<f64> = d.split(' ').filter_map(|t| t.parse().ok()).collect();
+            Some(numbers.chunks(2).map(|p| p[1]).collect())
+        })
+        .collect();
+    assert_eq!(bars.len(), 3);
+    let tops: Vec<f64> = bars
+        .iter()
+        .map(|ys| ys.iter().copied().fold(f64::INFINITY, f64::min))
+        .collect();
+    let bottoms: Vec<f64> = bars
+        .iter()
+        .map(|ys| ys.iter().copied().fold(f64::NEG_INFINITY, f64::max))
+        .collect();
+    assert!(
+        tops.windows(2).all(|w| (w[0] - w[1]).abs() < 1e-6),
+        "{:?}",
+        tops
+    );
+    assert!(bottoms.windows(2).all(|w| w[0] != w[1]), "{:?}", bottoms);
+
+    Ok(())
+}
@@ -0,0 +1,171 @@
+use charton::error::ChartonError;
+use charton::prelude::*;
+use std::error::Error;
+
+#[test]
+#[cfg(not(feature = "raster"))]
+fn test_save_png_requires_raster_feature() -> Result<(), Box<dyn Error>> {
+    let x = [1.0, 2.0, 3.0];
+    let y = [4.0, 5.0, 6.0];
+
+    let result = chart!(x, y)?
+        .mark_point()?
+        .encode((alt::x("x"), alt::y("y")))?
+        .save("./tests/disabled.png");
+
+    assert!(matches!(result, Err(ChartonError::Unimplemented(msg)) if msg.contains("'raster'")));
+    assert!(!std::path::Path::new("./tests/disabled.png").exists());
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "raster")]
+fn test_to_png_in_memory() -> Result<(), Box<dyn Error>> {
+    const PNG_MAGIC: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+
+    let x = [1.0, 2.0, 3.0];
+    let y = [4.0, 5.0, 6.0];
+    let chart = chart!(x, y)?
+        .mark_point()?
+        .encode((alt::x("x"), alt::y("y")))?;
+
+    let png = chart.to_png()?;
+    assert!(png.len() > PNG_MAGIC.len());
+    assert!(png.starts_with(&PNG_MAGIC));
+
+    // The IHDR chunk holds the pixel width right after the magic number and chunk header.
+    let width = |bytes: &[u8]| u32::from_be_bytes([bytes[16], bytes[17], bytes[18], bytes[19]]);
+    let small = chart.to_png_with_scale(1.0)?;
+    assert!(small.starts_with(&PNG_MAGIC));
+    assert_eq!(width(&png), 2 * width(&small));
+
+    assert!(matches!(
+        chart.to_png_with_scale(0.0),
+        Err(ChartonError::Render(_))
+    ));
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "raster")]
+fn test_png_dpi() -> Result<(), Box<dyn Error>> {
+    let x = [1.0, 2.0, 3.0];
+    let y = [4.0, 5.0, 6.0];
+    let chart = chart!(x, y)?
+        .mark_point()?
+        .encode((alt::x("x"), alt::y("y")))?
+        .with_size(400, 300)
+        .with_dpi(300.0);
+
+    let png = chart.to_png()?;
+    let be_u32 = |at: usize| u32::from_be_bytes([png[at], png[at + 1], png[at + 2], png[at + 3]]);
+    assert_eq!((be_u32(16), be_u32(20)), (1250, 937));
+
+    // The pHYs chunk follows IHDR and stores 300 DPI as pixels per metre.
+    assert_eq!(&png[37..41], b"pHYs");
+    assert_eq!((be_u32(41), be_u32(45), png[49]), (11811, 11811, 1));
+
+    // A scale far beyond the available memory fails instead of aborting.
+    assert!(matches!(
+        chart.to_png_with_scale(1.0e6),
+        Err(ChartonError::Render(_))
+    ));
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "pdf")]
+fn test_save_pdf() -> Result<(), Box<dyn Error>> {
+    let x = [1.0, 2.0, 3.0];
+    let y = [4.0, 5.0, 6.0];
+    let path = std::env::temp_dir().join("charton_save.pdf");
+    let chart = chart!(x, y)?
+        .mark_point()?
+        .encode((alt::x("x"), alt::y("y")))?
+        .with_title("Vector output")
+        .with_size(400, 300);
+    chart.save(&path)?;
+
+    let pdf = std::fs::read(&path)?;
+    assert!(pdf.starts_with(b"%PDF-"));
+    // A single page of 400 × 300 points, with the text drawn in an embedded font.
+    let text = String::from_utf8_lossy(&pdf);
+    assert!(text.contains("/Count 1"));
+    assert!(text.contains("/MediaBox [0 0 400 300]"));
+    assert!(text.contains("/FontFile2"));
+
+    // The same document can be produced in memory.
+    let in_memory = chart.to_pdf()?;
+    assert!(in_memory.starts_with(b"%PDF-"));
+    assert!(String::from_utf8_lossy(&in_memory).contains("/MediaBox [0 0 400 300]"));
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "jpeg")]
+fn test_save_jpeg() -> Result<(), Box<dyn Error>> {
+    let x = [1.0, 2.0, 3.0];
+    let y = [4.0, 5.0, 6.0];
+    let chart = chart!(x, y)?
+        .mark_point()?
+        .encode((alt::x("x"), alt::y("y")))?
+        .with_title("Thumbnail")
+        .with_size(400, 300)
+        .with_scale_factor(1.0);
+
+    let path = std::env::temp_dir().join("charton_save.jpeg");
+    chart.save(&path)?;
+    let jpeg = std::fs::read(&path)?;
+    assert!(jpeg.starts_with(&[0xFF, 0xD8, 0xFF]));
+
+    // Lower quality trades detail for size.
+    let small = chart.clone().with_jpeg_quality(20).to_jpeg()?;
+    assert!(small.len() < chart.to_jpeg()?.len());
+    assert!(matches!(
+        chart.with_jpeg_quality(0).to_jpeg(),
+        Err(ChartonError::Render(_))
+    ));
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "webp")]
+fn test_save_webp() -> Result<(), Box<dyn Error>> {
+    let x = [1.0, 2.0, 3.0];
+    let y = [4.0, 5.0, 6.0];
+    let chart = chart!(x, y)?
+        .mark_point()?
+        .encode((alt::x("x"), alt::y("y")))?
+        .with_size(400, 300);
+
+    let path = std::env::temp_dir().join("charton_save.webp");
+    chart.save(&path)?;
+    let webp = std::fs::read(&path)?;
+    assert_eq!(&webp[..4], b"RIFF");
+    assert_eq!(&webp[8..12], b"WEBP");
+    assert_eq!(chart.to_webp()?, webp);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(not(feature = "jpeg"))]
+fn test_save_jpeg_requires_jpeg_feature() -> Result<(), Box<dyn Error>> {
+    let x = [1.0, 2.0, 3.0];
+    let y = [4.0, 5.0, 6.0];
+
+    let result = chart!(x, y)?
+        .mark_point()?
+        .encode((alt::x("x"), alt::y("y")))?
+        .save("./tests/disabled.jpg");
+
+    assert!(matches!(result, Err(ChartonError::Unimplemented(msg)) if msg.contains("'jpeg'")));
+    assert!(!std::path::Path::new("./tests/disabled.jpg").exists());
+
+    Ok(())
+}
@@ -1,3 +1,4 @@
+use charton::error::ChartonError;
 use charton::prelude::*;
 use std::error::Error;
 
@@ -349,3 +350,402 @@ fn test_scatter_15() -> Result<(), Box<dyn Error>> {
 
     Ok(())
 }
+
+/// Parses the radius of every `<circle>` element in an SVG document.
+fn circle_radii(svg: &str) -> Vec<f64> {
+    svg.split("<circle")
+        .skip(1)
+        .filter_map(|c| c.split("r=\"").nth(1)?.split('"').next()?.parse().ok())
+        .collect()
+}
+
+#[test]
+fn test_scatter_size_floor() -> Result<(), Box<dyn Error>> {
+    let a = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+    let b = [3.0, 1.0, 4.0, 1.0, 5.0, 9.0];
+    let weight = [0.0, 0.5, 1.0, 40.0, 80.0, 100.0];
+
+    let svg = chart!(a, b, weight)?
+        .mark_point()?
+        .encode((
+            alt::x("a"),
+            alt::y("b"),
+            alt::size("weight").with_floor(4.0),
+        ))?
+        .with_title("Size Floor")
+        .to_svg()?;
+    std::fs::write("./tests/scatter_size_floor.svg", &svg)?;
+
+    // Marks and legend symbols are never drawn below the floor...
+    let radii = circle_radii(&svg);
+    assert!(!radii.is_empty());
+    assert!(radii.iter().all(|r| *r >= 4.0 - 1e-6), "{radii:?}");
+    // ...and the smallest legend entry says so.
+    assert!(svg.contains("≤ 0"));
+
+    Ok(())
+}
+
+#[test]
+fn test_scatter_size_drop_zero() -> Result<(), Box<dyn Error>> {
+    let a = [1.0, 2.0, 3.0, 4.0];
+    let b = [1.0, 2.0, 3.0, 4.0];
+    let weight = [0.0, 2.0, 0.0, 8.0];
+
+    let svg = chart!(a, b, weight)?
+        .mark_point()?
+        .encode((
+            alt::x("a"),
+            alt::y("b"),
+            alt::size("weight").with_drop_zero(true),
+        ))?
+        .with_theme(Theme::default().with_show_legend(false))
+        .to_svg()?;
+
+    assert_eq!(circle_radii(&svg).len(), 2);
+
+    Ok(())
+}
+
+#[test]
+fn test_scatter_negative_size_rejected() -> Result<(), Box<dyn Error>> {
+    let a = [1.0, 2.0, 3.0];
+    let b = [1.0, 2.0, 3.0];
+    let weight = [1.0, -3.0, -0.5];
+
+    let result = chart!(a, b, weight).and_then(|c| {
+        c.mark_point()?
+            .encode((alt::x("a"), alt::y("b"), alt::size("weight")))?
+            .to_svg()
+    });
+
+    match result {
+        Err(ChartonError::Data(msg)) => {
+            assert!(
+                msg.contains("rows [1, 2]") && msg.contains("min -3"),
+                "{msg}"
+            )
+        }
+        other => panic!("expected a data error, got {other:?}"),
+    }
+
+    Ok(())
+}
+
+fn circle_opacities(svg: &str) -> Vec<f64> {
+    svg.split("<circle")
+        .skip(1)
+        .filter_map(|c| {
+            c.split("fill-opacity=\"")
+                .nth(1)?
+                .split('"')
+                .next()?
+                .parse()
+                .ok()
+        })
+        .collect()
+}
+
+#[test]
+fn test_scatter_auto_alpha() -> Result<(), Box<dyn Error>> {
+    // A dense, overlapping cloud: sums of uniform noise approximate a normal blob.
+    let mut seed: u64 = 42;
+    let mut noise = || {
+        (0..4)
+            .map(|_| {
+                seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+                (seed >> 33) as f64 / (1u64 << 31) as f64
+            })
+            .sum::<f64>()
+    };
+    let x: Vec<f64> = (0..1500).map(|_| noise()).collect();
+    let y: Vec<f64> = (0..1500).map(|_| noise()).collect();
+
+    let auto = chart!(x, y)?
+        .mark_point()?
+        .configure_point(|p| p.with_auto_alpha(true))
+        .encode((alt::x("x"), alt::y("y")))?;
+    auto.clone()
+        .with_title("Auto Alpha")
+        .save("./tests/scatter_16.svg")?;
+
+    let alpha = circle_opacities(&auto.to_svg()?)[0];
+    assert!(alpha > 0.05 && alpha < 1.0, "alpha = {}", alpha);
+
+    // The automatic value multiplies an explicit opacity instead of replacing it.
+    let halved = chart!(x, y)?
+        .mark_point()?
+        .configure_point(|p| p.with_opacity(0.5).with_auto_alpha(true))
+        .encode((alt::x("x"), alt::y("y")))?
+        .to_svg()?;
+    assert!((circle_opacities(&halved)[0] - alpha * 0.5).abs() < 1e-3);
+
+    // The clamp range bounds the result.
+    let floored = chart!(x, y)?
+        .mark_point()?
+        .configure_point(|p| p.with_auto_alpha(true).with_auto_alpha_range(0.6, 1.0))
+        .encode((alt::x("x"), alt::y("y")))?
+        .to_svg()?;
+    assert!(circle_opacities(&floored).iter().all(|&o| o == 0.6));
+
+    Ok(())
+}
+
+#[test]
+fn test_scatter_log_scale() -> Result<(), Box<dyn Error>> {
+    // Gene expression counts spanning five orders of magnitude
+    let counts = [1.0, 12.0, 150.0, 2400.0, 31000.0, 99000.0];
+    let gene_len = [0.8, 1.5, 2.2, 3.1, 4.0, 5.5];
+
+    let chart = chart!(counts, gene_len)?
+        .mark_point()?
+        .encode((alt::x("counts").with_scale(Scale::Log), alt::y("gene_len")))?
+        .with_size(500, 400);
+
+    let svg = chart.to_svg()?;
+    for label in ["1E0", "1E1", "1E2", "1E3", "1E4", "1E5"] {
+        assert!(
+            svg.contains(&format!(">{}<", label)),
+            "missing tick {}",
+            label
+        );
+    }
+    chart.save("./tests/scatter_17.svg")?;
+
+    // A base-2 axis places ticks at powers of two.
+    let svg = chart!(counts, gene_len)?
+        .mark_point()?
+        .encode((alt::x("gene_len").with_log_base(2.0), alt::y("counts")))?
+        .to_svg()?;
+    assert!(svg.contains(">1<") && svg.contains(">2<") && svg.contains(">4<"));
+
+    // Non-positive values cannot be placed on a log axis.
+    let with_zero = [0.0, 12.0, 150.0, 2400.0, 31000.0, 99000.0];
+    let err = chart!(with_zero, gene_len)?
+        .mark_point()?
+        .encode((
+            alt::x("with_zero").with_scale(Scale::Log),
+            alt::y("gene_len"),
+        ))?
+        .to_svg();
+    assert!(matches!(err, Err(ChartonError::Scale(msg)) if msg.contains("strictly positive")));
+
+    Ok(())
+}
+
+#[test]
+fn test_scatter_log_minor_ticks() -> Result<(), Box<dyn Error>> {
+    let counts = [1.0, 12.0, 150.0, 2400.0, 31000.0, 99000.0];
+    let gene_len = [0.8, 1.5, 2.2, 3.1, 4.0, 5.5];
+
+    let build = |minor: bool| -> Result<String, ChartonError> {
+        chart!(counts, gene_len)?
+            .mark_point()?
+            .encode((alt::x("counts").with_scale(Scale::Log), alt::y("gene_len")))?
+            .configure_theme(|t| t.with_minor_ticks(minor))
+            .to_svg()
+    };
+
+    let plain = build(false)?;
+    let with_minor = build(true)?;
+    // 2..9 × 10ⁿ for every decade between 1 and 1E5: at least 8 per decade.
+    let extra = with_minor.matches("<path").count() - plain.matches("<path").count();
+    assert!(
+        extra >= 40,
+        "expected minor ticks, got {} extra paths",
+        extra
+    );
+    // Minor ticks carry no labels.
+    assert_eq!(
+        with_minor.matches("<text").count(),
+        plain.matches("<text").count()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_scatter_log_power_labels() -> Result<(), Box<dyn Error>> {
+    // Populations from a few hundred people to over a billion
+    let population = [450.0, 38_000.0, 5.6e6, 8.3e7, 3.3e8, 1.4e9];
+    let area = [0.4, 160.0, 42_000.0, 357_000.0, 9.8e6, 9.6e6];
+
+    let chart = chart!(population, area)?
+        .mark_point()?
+        .encode((
+            alt::x("population").with_scale(Scale::Log),
+            alt::y("area").with_scale(Scale::Log),
+        ))?
+        .with_x_tick_formatter(TickFormat::LogBase)
+        .with_y_tick_formatter(TickFormat::LogBase);
+
+    let svg = chart.to_svg()?;
+    for label in ["10^3", "10^6", "10^9"] {
+        assert!(
+            svg.contains(&format!(">{}<", label)),
+            "missing tick {}",
+            label
+        );
+    }
+    assert!(!svg.contains(">1E3<"));
+    chart.save("./tests/scatter_18.svg")?;
+
+    // A domain reaching zero is rejected on a log axis.
+    let err = chart!(population, area)?
+        .mark_point()?
+        .encode((alt::x("population").with_scale(Scale::Log), alt::y("area")))?
+        .with_x_domain(0.0, 1e10)
+        .to_svg();
+    assert!(matches!(err, Err(ChartonError::Scale(_))));
+
+    Ok(())
+}
+
+#[test]
+fn test_scatter_log_tick_generation() -> Result<(), Box<dyn Error>> {
+    use charton::scale::ScaleTrait;
+    use charton::scale::log::LogScale;
+
+    let labels = |domain: (f64, f64), count: usize| -> Result<Vec<String>, ChartonError> {
+        let scale = LogScale::new(domain, 10.0, None)?;
+        Ok(scale
+            .suggest_ticks(count)
+            .into_iter()
+            .map(|t| t.label)
+            .collect())
+    };
+
+    // Powers of ten inside the domain, and the 1-2-5 sequence when there is room.
+    assert_eq!(labels((3.0, 70000.0), 8)?, ["1E1", "1E2", "1E3", "1E4"]);
+    assert_eq!(labels((3.0, 70000.0), 20)?.len(), 13);
+    assert_eq!(labels((0.004, 2.0), 8)?, ["0.01", "0.10", "1.00"]);
+    assert_eq!(
+        labels((0.004, 2.0), 10)?,
+        [
+            "0.005", "0.010", "0.020", "0.050", "0.100", "0.200", "0.500", "1.000", "2.000"
+        ]
+    );
+    // Too many decades keep every n-th power.
+    assert_eq!(labels((1e-10, 1e10), 4)?, ["1E-6", "1E0", "1E6"]);
+    // Less than a decade falls back to linear-style ticks.
+    assert_eq!(labels((120.0, 150.0), 4)?, ["120", "130", "140", "150"]);
+
+    Ok(())
+}
+
+#[test]
+fn test_scatter_log_superscript_labels() -> Result<(), Box<dyn Error>> {
+    let x = [3.0, 120.0, 70000.0];
+    let y = [0.004, 0.3, 2.0];
+
+    let chart = chart!(x, y)?
+        .mark_point()?
+        .encode((
+            alt::x("x").with_scale(Scale::Log),
+            alt::y("y").with_scale(Scale::Log),
+        ))?
+        .with_x_tick_formatter(TickFormat::Superscript)
+        .with_y_tick_formatter(TickFormat::Exponent);
+
+    let svg = chart.to_svg()?;
+    for exp in 1..=4 {
+        let label = format!(
+            r#">10<tspan baseline-shift="super" font-size="9.1">{}</tspan>"#,
+            exp
+        );
+        assert!(svg.contains(&label), "missing tick 10^{}", exp);
+    }
+    for label in ["1e-2", "1e-1", "1e0"] {
+        assert!(
+            svg.contains(&format!(">{}<", label)),
+            "missing tick {}",
+            label
+        );
+    }
+    chart.save("./tests/scatter_19.svg")?;
+
+    Ok(())
+}
+
+/// Groups the `cx` of every `<circle>` element by its fill color.
+fn circle_cx_by_fill(svg: &str) -> Vec<(String, Vec<f64>)> {
+    let mut groups: Vec<(String, Vec<f64>)> = Vec::new();
+    for c in svg.split("<circle").skip(1) {
+        let attr = |name: &str| {
+            c.split(&format!(" {name}=\""))
+                .nth(1)
+                .and_then(|v| v.split('"').next())
+                .map(str::to_string)
+        };
+        let (Some(cx), Some(fill)) = (attr("cx"), attr("fill")) else {
+            continue;
+        };
+        let cx: f64 = cx.parse().unwrap();
+        match groups.iter_mut().find(|(f, _)| *f == fill) {
+            Some((_, xs)) => xs.push(cx),
+            None => groups.push((fill, vec![cx])),
+        }
+    }
+    groups
+}
+
+fn spread(xs: &[f64]) -> f64 {
+    let min = xs.iter().cloned().fold(f64::INFINITY, f64::min);
+    let max = xs.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    max - min
+}
+
+#[test]
+fn test_scatter_jitter_strip() -> Result<(), Box<dyn Error>> {
+    let ds = load_dataset("mtcars")?;
+
+    let strip = |width: u32| -> Result<String, ChartonError> {
+        chart!(ds.clone())?
+            .mark_point()?
+            .configure_point(|m| m.with_jitter(0.4))
+            .encode((
+                alt::x("cyl").with_scale(Scale::Discrete),
+                alt::y("mpg"),
+                alt::color("cyl").with_scale(Scale::Discrete),
+            ))?
+            .with_size(width, 400)
+            .with_legend(false)
+            .to_svg()
+    };
+
+    let narrow = strip(500)?;
+    let wide = strip(1000)?;
+
+    // Reproducible: the same chart jitters the same way.
+    assert_eq!(narrow, strip(500)?);
+
+    // Three categories: every one is spread out, but stays well inside 40% of its band.
+    let groups = circle_cx_by_fill(&narrow);
+    assert_eq!(groups.len(), 3);
+    for (_, xs) in &groups {
+        let s = spread(xs);
+        assert!(s > 5.0, "points are not jittered: {s}");
+        assert!(s < 0.4 * 500.0 / 3.0, "jitter leaves its band: {s}");
+    }
+
+    // The jitter is measured in pixels from the band, so it grows with the chart.
+    let narrow_spread = spread(&groups[0].1);
+    let wide_spread = spread(&circle_cx_by_fill(&wide)[0].1);
+    assert!(wide_spread > 1.8 * narrow_spread);
+
+    // A strip plot over a boxplot.
+    let boxes = chart!(ds.clone())?
+        .mark_boxplot()?
+        .encode((alt::x("cyl").with_scale(Scale::Discrete), alt::y("mpg")))?;
+    let points = chart!(ds)?
+        .mark_point()?
+        .configure_point(|m| m.with_jitter(0.5).with_size(2.5).with_opacity(0.7))
+        .encode((
+            alt::x("cyl").with_scale(Scale::Discrete),
+            alt::y("mpg"),
+            alt::color("cyl").with_scale(Scale::Discrete),
+        ))?;
+    boxes.and(points).save("./tests/scatter_jitter.svg")?;
+
+    Ok(())
+}
@@ -0,0 +1,147 @@
+use charton::error::ChartonError;
+use charton::prelude::*;
+use std::error::Error;
+
+/// Returns the x position of every text element whose content is `label`.
+fn text_x(svg: &str, label: &str) -> Vec<f64> {
+    let needle = format!(">{label}</text>");
+    svg.lines()
+        .filter(|l| l.starts_with("<text") && l.contains(&needle))
+        .filter_map(|l| l.split(r#" x=""#).nth(1)?.split('"').next()?.parse().ok())
+        .collect()
+}
+
+#[test]
+fn test_secondary_axis_1() -> Result<(), Box<dyn Error>> {
+    let month = ["Jan", "Feb", "Mar", "Apr", "May"];
+    let revenue = [1200.0, 1500.0, 1100.0, 1800.0, 2100.0];
+    let rate = [0.12, 0.18, 0.09, 0.22, 0.25];
+
+    let bars = chart!(month, revenue)?
+        .mark_bar()?
+        .encode((alt::x("month"), alt::y("revenue")))?;
+    let line = chart!(month, rate)?
+        .mark_line()?
+        .encode((alt::x("month"), alt::y("rate").axis_secondary()))?;
+
+    let chart = bars
+        .and(line)
+        .with_y_label("revenue")
+        .with_y2_label("conversion rate")
+        .with_y2_domain(0.0, 0.3)
+        .with_title("Dual axis");
+
+    let svg = chart.to_svg()?;
+
+    // Each axis keeps its own domain: the rate ticks are not drawn on the left.
+    let left = text_x(&svg, "2000");
+    let right = text_x(&svg, "0.30");
+    assert_eq!(left.len(), 1);
+    assert_eq!(right.len(), 1);
+    assert!(right[0] > left[0] + 200.0);
+    assert!(right[0] < 500.0);
+    assert_eq!(text_x(&svg, "conversion rate").len(), 1);
+
+    chart.save("./tests/secondary_axis_1.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_secondary_axis_reserves_margin() -> Result<(), Box<dyn Error>> {
+    let x = [1.0, 2.0, 3.0];
+    let y = [10.0, 20.0, 30.0];
+    let z = [100000.0, 200000.0, 300000.0];
+
+    let primary = chart!(x, y)?
+        .mark_point()?
+        .encode((alt::x("x"), alt::y("y")))?;
+    let plain = primary.clone().and(primary.clone()).to_svg()?;
+
+    let secondary = chart!(x, z)?
+        .mark_point()?
+        .encode((alt::x("x"), alt::y("z").axis_secondary()))?;
+    let dual = primary.and(secondary).with_y2_label("z").to_svg()?;
+
+    // The panel shrinks on the right to make room for the second axis, so the
+    // centered x title moves left.
+    let center_plain = text_x(&plain, "x")[0];
+    let center_dual = text_x(&dual, "x")[0];
+    assert!(center_dual < center_plain - 10.0);
+
+    Ok(())
+}
+
+#[test]
+fn test_secondary_axis_rejects_flip() -> Result<(), Box<dyn Error>> {
+    let x = [1.0, 2.0, 3.0];
+    let y = [1.0, 2.0, 3.0];
+
+    let a = chart!(x, y)?
+        .mark_point()?
+        .encode((alt::x("x"), alt::y("y")))?;
+    let b = chart!(x, y)?
+        .mark_line()?
+        .encode((alt::x("x"), alt::y("y").axis_secondary()))?;
+
+    assert!(a.and(b).coord_flip().to_svg().is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_secondary_axis_side() -> Result<(), Box<dyn Error>> {
+    let month = ["Jan", "Feb", "Mar", "Apr", "May", "Jun"];
+    let celsius = [-2.0, 1.0, 6.0, 12.0, 17.0, 21.0];
+    let mm = [48.0, 41.0, 52.0, 60.0, 75.0, 90.0];
+
+    let rain = chart!(month, mm)?
+        .mark_bar()?
+        .encode((alt::x("month"), alt::y("mm")))?
+        .with_y_axis_side(YAxisSide::Right);
+    let temperature = chart!(month, celsius)?
+        .mark_line()?
+        .encode((alt::x("month"), alt::y("celsius")))?
+        .with_y_axis_side(YAxisSide::Left);
+
+    let chart = rain
+        .and(temperature)
+        .with_y_label("temperature (°C)")
+        .with_secondary_y_label("precipitation (mm)");
+    let svg = chart.to_svg()?;
+
+    // The precipitation ticks sit on the right axis, the temperature ticks on the left.
+    let left = text_x(&svg, "15");
+    let right = text_x(&svg, "60");
+    assert_eq!(left.len(), 1);
+    assert_eq!(right.len(), 1);
+    assert!(right[0] > left[0] + 200.0);
+    assert_eq!(text_x(&svg, "precipitation (mm)").len(), 1);
+
+    chart.save("./tests/secondary_axis_2.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_secondary_axis_scale_conflict() -> Result<(), Box<dyn Error>> {
+    let x = [1.0, 2.0, 3.0];
+    let y = [1.0, 10.0, 100.0];
+
+    let primary = chart!(x, y)?
+        .mark_point()?
+        .encode((alt::x("x"), alt::y("y")))?;
+    let linear = chart!(x, y)?
+        .mark_point()?
+        .encode((alt::x("x"), alt::y("y")))?
+        .with_y_axis_side(YAxisSide::Right);
+    let log = chart!(x, y)?
+        .mark_line()?
+        .encode((alt::x("x"), alt::y("y").with_scale(Scale::Log)))?
+        .with_y_axis_side(YAxisSide::Right);
+
+    let result = primary.and(linear).and(log).to_svg();
+    assert!(matches!(result, Err(ChartonError::Scale(_))));
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use charton::error::ChartonError;
+use charton::prelude::*;
+use std::error::Error;
+
+#[test]
+fn test_shared_scales_1() -> Result<(), Box<dyn Error>> {
+    let ds = load_dataset("mtcars")?;
+
+    let all = chart!(ds)?
+        .mark_point()?
+        .encode((alt::x("wt"), alt::y("mpg")))?
+        .with_title("All cars");
+    let scales = SharedScales::from_chart(&all)?;
+
+    let thirsty = Chart::build(load_dataset("mtcars")?)?
+        .transform_filter_str("mpg < 20")?
+        .mark_point()?
+        .encode((alt::x("wt"), alt::y("mpg")))?
+        .with_shared_scales(&scales)
+        .with_title("Below 20 mpg");
+
+    let svg = thirsty.to_svg()?;
+    // The y axis keeps the full range of the first chart, not just its own data.
+    assert!(svg.contains(">30<"));
+    assert_eq!(svg.matches("<circle").count(), 18);
+
+    thirsty.save("./tests/shared_scales_1.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_shared_color_mapper() -> Result<(), Box<dyn Error>> {
+    let x = [1.0, 2.0, 3.0];
+    let y = [1.0, 2.0, 3.0];
+    let group = ["a", "b", "c"];
+    let first: LayeredChart = chart!(x, y, group)?
+        .mark_point()?
+        .encode((alt::x("x"), alt::y("y"), alt::color("group")))?
+        .into();
+    let scales = SharedScales::from_chart(&first)?;
+
+    // Only "c" is present, but it keeps the third palette color (Tab10 green).
+    let x = [1.0];
+    let y = [1.0];
+    let group = ["c"];
+    let svg = chart!(x, y, group)?
+        .mark_point()?
+        .encode((alt::x("x"), alt::y("y"), alt::color("group")))?
+        .with_shared_scales(&scales)
+        .to_svg()?;
+    let mark = svg.split("<circle").nth(1).unwrap_or_default();
+    assert!(mark.contains("rgba(44,160,44,1.000)"));
+    // The legend lists the whole shared domain.
+    assert!(svg.contains(">a<") && svg.contains(">b<"));
+
+    Ok(())
+}
+
+#[test]
+fn test_shared_scale_type_conflict() -> Result<(), Box<dyn Error>> {
+    let x = [1.0, 2.0];
+    let y = [3.0, 4.0];
+    let numeric: LayeredChart = chart!(x, y)?
+        .mark_point()?
+        .encode((alt::x("x"), alt::y("y")))?
+        .into();
+    let scales = SharedScales::from_chart(&numeric)?;
+
+    let x = ["a", "b"];
+    let result = chart!(x, y)?
+        .mark_bar()?
+        .encode((alt::x("x"), alt::y("y")))?
+        .with_shared_scales(&scales)
+        .to_svg();
+    assert!(matches!(result, Err(ChartonError::Scale(msg)) if msg.contains("shared scale")));
+
+    Ok(())
+}
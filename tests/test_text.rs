@@ -34,3 +34,79 @@ fn test_text_1() -> Result<(), Box<dyn Error>> {
 
     Ok(())
 }
+
+#[test]
+fn test_text_rtl_direction() -> Result<(), Box<dyn Error>> {
+    let price = [12.0, 18.0, 9.0];
+    let volume = [3.0, 5.0, 4.0];
+    let city = ["תל אביב", "חיפה", "ירושלים"];
+
+    let svg = chart!(price, volume, city)?
+        .mark_text()?
+        .configure_text(|t| t.with_anchor("start"))
+        .encode((alt::x("price"), alt::y("volume"), alt::text("city")))?
+        .with_x_label("מחיר (₪)")
+        .with_y_label("נפח")
+        .with_theme(Theme::default().with_text_direction(TextDirection::Rtl))
+        .to_svg()?;
+
+    assert!(svg.contains(r#"direction="rtl" unicode-bidi="embed""#));
+    // Left-anchored data labels keep their position: "start" is mirrored to "end".
+    let label = svg
+        .lines()
+        .find(|l| l.contains(">חיפה<"))
+        .ok_or("missing data label")?;
+    assert!(label.contains(r#"text-anchor="end""#));
+
+    std::fs::write("./tests/text_2.svg", svg)?;
+
+    Ok(())
+}
+
+#[test]
+fn test_text_upright_vertical_y_label() -> Result<(), Box<dyn Error>> {
+    let month = [1.0, 2.0, 3.0, 4.0];
+    let sales = [120.0, 150.0, 90.0, 170.0];
+
+    let svg = chart!(month, sales)?
+        .mark_point()?
+        .encode((alt::x("month"), alt::y("sales")))?
+        .with_x_label("月")
+        .with_y_label("売上高")
+        .with_theme(Theme::default().with_y_label_orientation(TextOrientation::UprightVertical))
+        .to_svg()?;
+
+    assert!(svg.contains(r#"writing-mode="vertical-rl" text-orientation="upright""#));
+    assert!(!svg.contains("rotate(-90"));
+
+    std::fs::write("./tests/text_3.svg", svg)?;
+
+    Ok(())
+}
+
+#[test]
+fn test_text_font_weight_and_style() -> Result<(), Box<dyn Error>> {
+    let month = [1.0, 2.0, 3.0, 4.0];
+    let sales = [120.0, 150.0, 90.0, 170.0];
+
+    let svg = chart!(month, sales)?
+        .mark_point()?
+        .encode((alt::x("month"), alt::y("sales")))?
+        .with_title("Quarterly Sales")
+        .with_theme(
+            Theme::default()
+                .with_title_font_style(FontStyle::Italic)
+                .with_label_font_weight(FontWeight::Normal)
+                .with_label_font_style(FontStyle::Oblique)
+                .with_tick_label_font_weight(FontWeight::Weight(300)),
+        )
+        .to_svg()?;
+
+    assert!(svg.contains(r#"font-weight="bold" dominant-baseline="middle" font-style="italic""#));
+    assert_eq!(svg.matches(r#"font-style="oblique""#).count(), 2);
+    assert!(svg.contains(r#"font-weight="300""#));
+
+    std::fs::write("./tests/text_4.svg", svg)?;
+
+    Ok(())
+}
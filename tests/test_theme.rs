@@ -0,0 +1,37 @@
+use charton::prelude::*;
+use std::error::Error;
+
+#[test]
+fn test_theme_dark() -> Result<(), Box<dyn Error>> {
+    let ds = load_dataset("mtcars")?;
+
+    let chart = chart!(ds)?
+        .mark_point()?
+        .encode((alt::x("wt"), alt::y("mpg"), alt::color("cyl")))?
+        .with_title("Dark theme")
+        .with_theme(Theme::dark());
+
+    let svg = chart.to_svg()?;
+    // Dark canvas with light text.
+    assert!(svg.contains(r#"fill="rgba(30,30,30,1.000)""#));
+    assert!(svg.contains("rgba(224,224,224,1.000)"));
+    // Only the titles are bold; the axis labels use a normal weight.
+    let weight = |label: &str| -> Option<String> {
+        let line = svg
+            .lines()
+            .find(|l| l.ends_with(&format!(">{}</text>", label)))?;
+        Some(
+            line.split(r#"font-weight=""#)
+                .nth(1)?
+                .split('"')
+                .next()?
+                .to_string(),
+        )
+    };
+    assert_eq!(weight("Dark theme").as_deref(), Some("bold"));
+    assert_eq!(weight("wt").as_deref(), Some("normal"));
+
+    chart.save("./tests/theme_dark.svg")?;
+
+    Ok(())
+}
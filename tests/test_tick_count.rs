@@ -0,0 +1,94 @@
+use charton::prelude::*;
+use std::error::Error;
+
+/// Returns the text of every end-anchored label: left-axis ticks.
+fn y_tick_labels(svg: &str) -> Vec<String> {
+    svg.lines()
+        .filter(|l| l.starts_with("<text") && l.contains(r#"text-anchor="end""#))
+        .filter_map(|l| Some(l.split('>').nth(1)?.split('<').next()?.to_string()))
+        .collect()
+}
+
+/// Returns the text of every middle-anchored label below the panel: bottom-axis
+/// ticks and the axis titles.
+fn x_tick_labels(svg: &str) -> Vec<String> {
+    svg.lines()
+        .filter(|l| l.starts_with("<text") && l.contains(r#"text-anchor="middle""#))
+        .filter_map(|l| Some(l.split('>').nth(1)?.split('<').next()?.to_string()))
+        .filter(|t| t.parse::<f64>().is_ok())
+        .collect()
+}
+
+#[test]
+fn test_tick_count_1() -> Result<(), Box<dyn Error>> {
+    let x = [0.0, 1.3, 2.9, 4.4, 6.1, 7.8];
+    let y = [3.0, 17.0, 41.0, 38.0, 66.0, 97.0];
+
+    let chart = chart!(x, y)?
+        .mark_line()?
+        .encode((alt::x("x"), alt::y("y")))?
+        .with_x_tick_count(4)
+        .with_y_tick_count(5)
+        .with_title("Four x ticks, five y ticks");
+    let svg = chart.to_svg()?;
+
+    // A step of 2.5 keeps four x ticks, labelled with the digit they need.
+    assert_eq!(x_tick_labels(&svg), ["0.0", "2.5", "5.0", "7.5"]);
+    assert_eq!(y_tick_labels(&svg), ["0", "25", "50", "75", "100"]);
+
+    chart.save("./tests/tick_count_1.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_tick_count_precedence_and_zero() -> Result<(), Box<dyn Error>> {
+    let x = [0.0, 1.3, 2.9, 4.4, 6.1, 7.8];
+    let y = [3.0, 17.0, 41.0, 38.0, 66.0, 97.0];
+
+    // Explicit tick values win over a requested count.
+    let explicit = chart!(x, y)?
+        .mark_line()?
+        .encode((alt::x("x"), alt::y("y")))?
+        .with_y_tick_count(5)
+        .with_y_ticks([10.0, 90.0])
+        .to_svg()?;
+    assert_eq!(y_tick_labels(&explicit), ["10", "90"]);
+
+    // A count of 0 hides the y ticks and labels but keeps the x axis.
+    let hidden = chart!(x, y)?
+        .mark_line()?
+        .encode((alt::x("x"), alt::y("y")))?
+        .with_y_tick_count(0)
+        .to_svg()?;
+    assert!(y_tick_labels(&hidden).is_empty());
+    assert!(!x_tick_labels(&hidden).is_empty());
+
+    // The count follows the logical axis when the coordinates are flipped.
+    let flipped = chart!(x, y)?
+        .mark_point()?
+        .encode((alt::x("x"), alt::y("y")))?
+        .with_y_tick_count(5)
+        .coord_flip()
+        .to_svg()?;
+    assert_eq!(x_tick_labels(&flipped), ["0", "25", "50", "75", "100"]);
+
+    Ok(())
+}
+
+#[test]
+fn test_tick_count_huge_is_bounded() -> Result<(), Box<dyn Error>> {
+    let x = [0.0, 1.0];
+    let y = [0.0, 1.0];
+
+    // An absurd count is capped instead of generating ticks forever.
+    let svg = chart!(x, y)?
+        .mark_point()?
+        .encode((alt::x("x"), alt::y("y")))?
+        .with_y_tick_count(usize::MAX)
+        .to_svg()?;
+    let ticks = y_tick_labels(&svg).len();
+    assert!((2..=2001).contains(&ticks), "{} ticks", ticks);
+
+    Ok(())
+}
@@ -0,0 +1,158 @@
+use charton::prelude::*;
+use std::error::Error;
+
+/// Returns the text of every end-anchored label: left-axis ticks.
+fn y_tick_labels(svg: &str) -> Vec<String> {
+    svg.lines()
+        .filter(|l| l.starts_with("<text") && l.contains(r#"text-anchor="end""#))
+        .filter_map(|l| Some(l.split('>').nth(1)?.split('<').next()?.to_string()))
+        .collect()
+}
+
+#[test]
+fn test_tick_formatter_1() -> Result<(), Box<dyn Error>> {
+    let year = [2019.0, 2020.0, 2021.0, 2022.0, 2023.0];
+    let revenue = [1.2e6, 1.8e6, 2.5e6, 3.1e6, 4.0e6];
+    let margin = [0.12, 0.18, 0.21, 0.25, 0.3];
+
+    let chart = chart!(year, revenue, margin)?
+        .mark_point()?
+        .configure_point(|p| p.with_size(8.0))
+        .encode((alt::x("year"), alt::y("revenue"), alt::color("margin")))?
+        .with_x_tick_formatter(|v: f64| format!("FY{:02}", v as i64 % 100))
+        .with_y_tick_formatter(TickFormat::SiSuffix)
+        .with_color_tick_formatter(TickFormat::Percent)
+        .with_title("Revenue and margin");
+    let svg = chart.to_svg()?;
+
+    assert!(svg.contains(">FY21<"));
+    assert!(svg.contains(">3M<"));
+    assert!(svg.contains(">2.5M<"));
+    // The colorbar labels are percentages too.
+    assert!(svg.contains(">20%<"));
+    assert!(!svg.contains(">0.20<"));
+
+    chart.save("./tests/tick_formatter_1.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_tick_formatter_builtins() -> Result<(), Box<dyn Error>> {
+    let x = [1.0, 2.0, 3.0];
+    let y = [0.0, 0.5, 1.0];
+
+    let labels = |formatter: TickFormatter| -> Result<Vec<String>, Box<dyn Error>> {
+        let svg = chart!(x, y)?
+            .mark_point()?
+            .encode((alt::x("x"), alt::y("y")))?
+            .with_y_tick_formatter(formatter)
+            .to_svg()?;
+        Ok(y_tick_labels(&svg))
+    };
+
+    let percent = labels(TickFormat::Percent.into())?;
+    assert!(percent.contains(&"40%".to_string()));
+    assert!(percent.contains(&"100%".to_string()));
+
+    let precision = labels(TickFormat::Precision(3).into())?;
+    assert!(precision.contains(&"0.400".to_string()));
+
+    let custom = labels((|v: f64| format!("<{}>", v * 10.0)).into())?;
+    assert!(custom.contains(&"&lt;4&gt;".to_string()));
+
+    // With an inverse transform the formatter sees original units.
+    let svg = chart!(x, y)?
+        .mark_point()?
+        .encode((alt::x("x"), alt::y("y")))?
+        .with_y_tick_inverse_transform(|v: f64| v * 2e4)
+        .with_y_tick_formatter(TickFormat::SiSuffix)
+        .to_svg()?;
+    assert!(y_tick_labels(&svg).contains(&"20k".to_string()));
+
+    Ok(())
+}
+
+#[test]
+fn test_tick_formatter_field_format() -> Result<(), Box<dyn Error>> {
+    let quarter = ["Q1", "Q1", "Q2", "Q2"];
+    let sales = [12000.0, 18000.0, 25000.0, 15000.0];
+    let product = ["A", "B", "A", "B"];
+
+    let bars = chart!(quarter, sales, product)?
+        .mark_bar()?
+        .configure_bar(|b| b.with_stack_label_parts(&[StackLabelPart::Segment]))
+        .encode((
+            alt::x("quarter"),
+            alt::y("sales").with_stack("stacked"),
+            alt::color("product"),
+            alt::tooltip(["product", "sales"]),
+        ))?;
+    let chart = bars
+        .clone()
+        .with_field_format("sales", TickFormat::SiSuffix)
+        .with_title("One format for every sales label");
+    let svg = chart.to_svg()?;
+
+    // Axis ticks, stack labels and tooltips all read "k".
+    assert!(y_tick_labels(&svg).contains(&"40k".to_string()));
+    assert!(svg.contains(">18k<"));
+    assert!(svg.contains("sales: 25k"));
+
+    chart.save("./tests/tick_formatter_2.svg")?;
+
+    // A channel-specific option still takes precedence.
+    let svg = bars
+        .with_field_format("sales", TickFormat::SiSuffix)
+        .with_y_tick_formatter(TickFormat::Precision(1))
+        .to_svg()?;
+    assert!(y_tick_labels(&svg).contains(&"40000.0".to_string()));
+    assert!(svg.contains(">18000.0<"));
+    assert!(svg.contains("sales: 25k"));
+
+    // Text marks format numeric fields too.
+    let x = [1.0, 2.0];
+    let share = [0.25, 0.5];
+    let svg = chart!(x, share)?
+        .mark_text()?
+        .encode((alt::x("x"), alt::y("share"), alt::text("share")))?
+        .with_field_format("share", TickFormat::Percent)
+        .to_svg()?;
+    assert!(svg.contains(">25%<"));
+    assert!(svg.contains(">50%<"));
+
+    Ok(())
+}
+
+#[test]
+fn test_tick_format_scientific() -> Result<(), Box<dyn Error>> {
+    let x = [1.0, 2.0, 3.0, 4.0];
+    let y = [0.0, 4e8, 7.5e8, 1e9];
+
+    let chart = chart!(x, y)?
+        .mark_line()?
+        .encode((alt::x("x"), alt::y("y")))?
+        .with_y_tick_formatter(TickFormat::Scientific)
+        .with_x_tick_formatter(TickFormat::Precision(1));
+    let svg = chart.to_svg()?;
+
+    let labels = y_tick_labels(&svg);
+    assert!(labels.contains(&"1.0e9".to_string()), "{labels:?}");
+    assert!(labels.contains(&"2.0e8".to_string()), "{labels:?}");
+    assert!(labels.contains(&"0".to_string()));
+    assert!(!svg.contains(">1000000000<"));
+    assert!(svg.contains(">2.0<"));
+
+    // The format is kept by the chart, so a later theme does not reset it.
+    let si = chart!(x, y)?
+        .mark_line()?
+        .encode((alt::x("x"), alt::y("y")))?
+        .with_y_tick_formatter(TickFormat::SiSuffix)
+        .with_theme(Theme::default())
+        .to_svg()?;
+    assert!(y_tick_labels(&si).contains(&"1G".to_string()));
+
+    chart.save("./tests/tick_format_scientific.svg")?;
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use charton::prelude::*;
+use std::error::Error;
+
+/// Returns the label and y position of every left-axis tick label.
+fn y_tick_labels(svg: &str) -> Vec<(String, String)> {
+    svg.lines()
+        .filter(|l| l.starts_with("<text") && l.contains(r#"text-anchor="end""#))
+        .filter_map(|l| {
+            let y = l.split(r#" y=""#).nth(1)?.split('"').next()?;
+            let label = l.split('>').nth(1)?.split('<').next()?;
+            Some((label.to_string(), y.to_string()))
+        })
+        .collect()
+}
+
+#[test]
+fn test_tick_inverse_transform_1() -> Result<(), Box<dyn Error>> {
+    let gene = ["a", "b", "c", "d", "e"];
+    let log2_cpm = [2.0, 4.5, 7.0, 10.2, 12.0];
+
+    let base = chart!(gene, log2_cpm)?
+        .mark_point()?
+        .encode((alt::x("gene"), alt::y("log2_cpm")))?;
+
+    let plain = base.clone().to_svg()?;
+    let chart = base
+        .with_y_tick_inverse_transform(InverseTransform::Exp2)
+        .with_y_label("CPM")
+        .with_title("log2 CPM in original units");
+    let svg = chart.to_svg()?;
+
+    // Same positions, new text: the tick at 10 reads 2^10.
+    let before = y_tick_labels(&plain);
+    let after = y_tick_labels(&svg);
+    assert_eq!(before.len(), after.len());
+    for ((_, y0), (_, y1)) in before.iter().zip(&after) {
+        assert_eq!(y0, y1);
+    }
+    assert!(after.iter().any(|(label, _)| label == "1,024"));
+    assert!(after.iter().any(|(label, _)| label == "4"));
+
+    chart.save("./tests/tick_inverse_transform_1.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_tick_inverse_transform_presets() -> Result<(), Box<dyn Error>> {
+    let x = [1.0, 2.0, 3.0];
+    let z = [-2.0, 0.0, 2.0];
+
+    let labels = |inverse: InverseTransform| -> Result<Vec<String>, Box<dyn Error>> {
+        let svg = chart!(x, z)?
+            .mark_point()?
+            .encode((alt::x("x"), alt::y("z")))?
+            .with_y_tick_inverse_transform(inverse)
+            .to_svg()?;
+        Ok(y_tick_labels(&svg).into_iter().map(|(l, _)| l).collect())
+    };
+
+    // z = 0 maps back to the mean and z = 2 to two standard deviations above.
+    let zscore = labels(InverseTransform::ZScore {
+        mean: 170.0,
+        sd: 10.0,
+    })?;
+    assert!(zscore.contains(&"170".to_string()));
+    assert!(zscore.contains(&"190".to_string()));
+
+    let exp10 = labels(InverseTransform::Exp10)?;
+    assert!(exp10.contains(&"0.01".to_string()));
+    assert!(exp10.contains(&"100".to_string()));
+
+    // Any closure works as a custom inverse.
+    let custom = labels((|v: f64| v * 1000.0).into())?;
+    assert!(custom.contains(&"-2,000".to_string()));
+
+    Ok(())
+}
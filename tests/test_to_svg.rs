@@ -14,3 +14,64 @@ fn test_scatter_1() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn test_svg_metadata() -> Result<(), Box<dyn std::error::Error>> {
+    let x = [1.0, 2.0, 3.0];
+    let y = [4.0, 5.0, 6.0];
+    let chart = chart!(x, y)?
+        .mark_point()?
+        .encode((alt::x("x"), alt::y("y")))?
+        .with_title("Sales")
+        .with_title_href("https://example.com/figures/12?a=1&b=2")
+        .with_metadata(&[
+            ("data-figure-id", "fig-12"),
+            ("data-source", "warehouse.sales"),
+        ])
+        .with_metadata(&[("data-figure-id", "fig-13"), ("data-note", r#"say "hi""#)]);
+    let svg = chart.to_svg()?;
+
+    // The attributes sit on the root element exactly once, next to its viewBox.
+    let root = svg.split('>').next().unwrap_or_default();
+    assert!(root.contains(r#"viewBox="0 0 "#));
+    assert!(root.contains(r#" data-figure-id="fig-13""#));
+    assert!(root.contains(r#" data-source="warehouse.sales""#));
+    assert!(root.contains(r#" data-note="say &quot;hi&quot;""#));
+    assert_eq!(svg.matches("data-figure-id").count(), 1);
+
+    // Only the title is linked.
+    assert_eq!(svg.matches("<a href=").count(), 1);
+    let link = svg
+        .split(r#"<a href="https://example.com/figures/12?a=1&amp;b=2""#)
+        .nth(1)
+        .expect("the title is linked");
+    let linked = &link[..link.find("</a>").expect("the link is closed")];
+    assert!(linked.contains(">Sales</text>"));
+
+    Ok(())
+}
+
+#[test]
+fn test_svg_metadata_validation() -> Result<(), Box<dyn std::error::Error>> {
+    let x = [1.0, 2.0, 3.0];
+    let y = [4.0, 5.0, 6.0];
+    let chart = chart!(x, y)?
+        .mark_point()?
+        .encode((alt::x("x"), alt::y("y")))?;
+
+    for name in ["1st", "data id", "xmlns", "width", "xlink:href", "on\"load"] {
+        let result = chart.clone().with_metadata(&[(name, "v")]).to_svg();
+        assert!(
+            matches!(result, Err(charton::error::ChartonError::Render(_))),
+            "{} was accepted",
+            name
+        );
+    }
+    let control = chart.with_metadata(&[("data-id", "a\u{0}b")]).to_svg();
+    assert!(matches!(
+        control,
+        Err(charton::error::ChartonError::Render(_))
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,223 @@
+use charton::error::ChartonError;
+use charton::prelude::*;
+use std::error::Error;
+
+#[test]
+fn test_tooltip_1() -> Result<(), Box<dyn Error>> {
+    let ds = load_dataset("mtcars")?;
+
+    let chart = chart!(ds)?
+        .mark_point()?
+        .encode((
+            alt::x("wt"),
+            alt::y("mpg"),
+            alt::tooltip(["model", "mpg", "hp"]),
+        ))?
+        .with_title("Hover a point");
+
+    let svg = chart.to_svg()?;
+    assert_eq!(svg.matches("<title>").count(), 32);
+    assert!(svg.contains("<title>model: Mazda RX4\nmpg: 21\nhp: 110</title></circle>"));
+
+    chart.save("./tests/tooltip_1.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_tooltip_bars_and_escaping() -> Result<(), Box<dyn Error>> {
+    let category = vec!["A & B", "<C>"];
+    let value = vec![1.5, 2.25];
+
+    let svg = chart!(category, value)?
+        .mark_bar()?
+        .encode((alt::x("category"), alt::y("value"), alt::tooltip("value")))?
+        .to_svg()?;
+
+    assert_eq!(svg.matches("<title>").count(), 2);
+    assert!(svg.contains("<title>value: 2.25</title></path>"));
+
+    let labeled = chart!(category, value)?
+        .mark_point()?
+        .encode((alt::x("value"), alt::y("value"), alt::tooltip("category")))?
+        .to_svg()?;
+    assert!(labeled.contains("<title>category: A &amp; B</title>"));
+    assert!(labeled.contains("<title>category: &lt;C&gt;</title>"));
+
+    Ok(())
+}
+
+#[test]
+fn test_tooltip_absent_leaves_output_unchanged() -> Result<(), Box<dyn Error>> {
+    let x = vec![1.0, 2.0, 3.0];
+    let y = vec![3.0, 1.0, 2.0];
+
+    let svg = chart!(x, y)?
+        .mark_point()?
+        .encode((alt::x("x"), alt::y("y")))?
+        .to_svg()?;
+    assert!(!svg.contains("<title>"));
+    assert_eq!(svg.matches("<circle").count(), 3);
+
+    Ok(())
+}
+
+#[test]
+fn test_tooltip_missing_column() -> Result<(), Box<dyn Error>> {
+    let x = vec![1.0, 2.0, 3.0];
+    let y = vec![3.0, 1.0, 2.0];
+
+    let result =
+        chart!(x, y)?
+            .mark_point()?
+            .encode((alt::x("x"), alt::y("y"), alt::tooltip(["x", "name"])));
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_tooltip_multi_with_format() -> Result<(), Box<dyn Error>> {
+    let name = vec!["a", "b"];
+    let share = vec![0.12345, 1234.5];
+    let count = vec![1500i64, 7];
+
+    let svg = chart!(name, share, count)?
+        .mark_point()?
+        .encode((
+            alt::x("share"),
+            alt::y("count"),
+            alt::tooltip_multi(vec!["name", "share", "count"])
+                .with_format(TickFormat::Precision(2)),
+        ))?
+        .to_svg()?;
+    assert!(svg.contains("<title>name: a\nshare: 0.12\ncount: 1500.00</title>"));
+    assert!(svg.contains("<title>name: b\nshare: 1234.50\ncount: 7.00</title>"));
+
+    let percent = chart!(name, share, count)?
+        .mark_point()?
+        .encode((
+            alt::x("share"),
+            alt::y("count"),
+            alt::tooltip("share").with_format(TickFormat::Percent),
+        ))?
+        .to_svg()?;
+    assert!(percent.contains("<title>share: 12.35%</title>"));
+
+    // Closures work as for the axis tick formatters.
+    let custom = chart!(name, share, count)?
+        .mark_point()?
+        .encode((
+            alt::x("share"),
+            alt::y("count"),
+            alt::tooltip("count").with_format(|v: f64| format!("{} units", v)),
+        ))?
+        .to_svg()?;
+    assert!(custom.contains("<title>count: 1500 units</title>"));
+
+    Ok(())
+}
+
+#[test]
+fn test_tooltip_from_encoded_fields() -> Result<(), Box<dyn Error>> {
+    let ds = load_dataset("mtcars")?;
+
+    let svg = chart!(ds.clone())?
+        .mark_point()?
+        .configure_point(|m| m.with_tooltip(true))
+        .encode((
+            alt::x("wt"),
+            alt::y("mpg"),
+            alt::color("cyl").with_scale(Scale::Discrete),
+        ))?
+        .to_svg()?;
+    assert_eq!(svg.matches("<title>").count(), 32);
+    assert!(svg.contains("<title>wt: 2.62\nmpg: 21\ncyl: 6</title></circle>"));
+
+    // An explicit tooltip encoding still decides the fields.
+    let svg = chart!(ds)?
+        .mark_point()?
+        .configure_point(|m| m.with_tooltip(true))
+        .encode((alt::x("wt"), alt::y("mpg"), alt::tooltip("model")))?
+        .to_svg()?;
+    assert!(svg.contains("<title>model: Mazda RX4</title></circle>"));
+    assert!(!svg.contains("<title>wt: "));
+
+    Ok(())
+}
+
+#[test]
+fn test_tooltip_on_dots() -> Result<(), Box<dyn Error>> {
+    let name = vec!["a", "b", "c"];
+    let value = vec![1.0, 2.0, 4.0];
+
+    let svg = chart!(name, value)?
+        .mark_dots()?
+        .encode((
+            alt::x("value").with_bins(2),
+            alt::y("count"),
+            alt::tooltip("name"),
+        ))?
+        .to_svg()?;
+    assert_eq!(svg.matches("<title>").count(), 3);
+    assert!(svg.contains("<title>name: c</title></circle>"));
+
+    let svg = chart!(name, value)?
+        .mark_dotplot()?
+        .encode((alt::x("name"), alt::y("value"), alt::tooltip("value")))?
+        .to_svg()?;
+    assert_eq!(svg.matches("<title>").count(), 3);
+    assert!(svg.contains("<title>value: 4</title></circle>"));
+
+    Ok(())
+}
+
+#[test]
+fn test_tooltip_rejected_on_path_marks() -> Result<(), Box<dyn Error>> {
+    let x = vec![1.0, 2.0, 3.0];
+    let y = vec![3.0, 1.0, 2.0];
+
+    let result = chart!(x, y)?
+        .mark_line()?
+        .encode((alt::x("x"), alt::y("y"), alt::tooltip("y")));
+    assert!(matches!(result, Err(ChartonError::Encoding(msg)) if msg.contains("line")));
+
+    let result = chart!(x, y)?
+        .mark_area()?
+        .encode((alt::x("x"), alt::y("y"), alt::tooltip("y")));
+    assert!(matches!(result, Err(ChartonError::Encoding(_))));
+
+    Ok(())
+}
+
+#[test]
+fn test_tooltip_stacked_bar_parts() -> Result<(), Box<dyn Error>> {
+    let quarter = ["Q1", "Q1", "Q1", "Q2", "Q2", "Q2"];
+    let sales = [10.0, 20.0, 30.0, 25.0, 25.0, 50.0];
+    let product = ["A", "B", "C", "A", "B", "C"];
+    let stacked = |parts: &[StackLabelPart]| -> Result<String, Box<dyn Error>> {
+        let parts = parts.to_vec();
+        Ok(chart!(quarter, sales, product)?
+            .mark_bar()?
+            .configure_bar(|b| b.with_stack_label_parts(&parts))
+            .encode((
+                alt::x("quarter"),
+                alt::y("sales").with_stack("stacked"),
+                alt::color("product"),
+                alt::tooltip("product"),
+            ))?
+            .to_svg()?)
+    };
+
+    // Segment, running and stack totals by default.
+    let svg = stacked(&[])?;
+    assert!(svg.contains("<title>product: B\nSegment: 20\nCumulative: 30\nTotal: 60</title>"));
+    // No label parts are set, so no segment is labeled.
+    assert!(!svg.contains("Σ"));
+
+    // The label parts, when set, are listed instead.
+    let svg = stacked(&[StackLabelPart::Percent])?;
+    assert!(svg.contains("<title>product: C\nPercent: 50%</title>"));
+
+    Ok(())
+}
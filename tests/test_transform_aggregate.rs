@@ -0,0 +1,94 @@
+use charton::error::ChartonError;
+use charton::prelude::*;
+use std::error::Error;
+
+#[test]
+fn test_transform_aggregate_1() -> Result<(), Box<dyn Error>> {
+    let ds = load_dataset("mtcars")?;
+
+    chart!(ds)?
+        .transform_aggregate(
+            AggregateTransform::new(vec![AggregateFieldDef::new(
+                "mpg",
+                AggregateOp::Mean,
+                "mean_mpg",
+            )])
+            .with_groupby(["cyl"]),
+        )?
+        .mark_bar()?
+        .encode((
+            alt::x("cyl").with_scale(Scale::Discrete),
+            alt::y("mean_mpg"),
+        ))?
+        .with_title("Mean MPG by Cylinders")
+        .save("./tests/transform_aggregate_1.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_transform_aggregate_values() -> Result<(), Box<dyn Error>> {
+    let shop = ["A", "A", "B", "B", "A", "B"];
+    let year = [2023.0, 2023.0, 2023.0, 2024.0, 2024.0, 2024.0];
+    let sales = [2.0, 4.0, 10.0, 1.0, 7.0, 5.0];
+
+    // Two group keys: (A, 2023) averages 2 and 4; (B, 2024) averages 1 and 5.
+    let svg = chart!(shop, year, sales)?
+        .transform_aggregate(
+            AggregateTransform::new(vec![
+                AggregateFieldDef::new("sales", "mean", "avg"),
+                AggregateFieldDef::new("sales", AggregateOp::Var, "var"),
+            ])
+            .with_groupby(["shop", "year"]),
+        )?
+        .mark_text()?
+        .encode((alt::x("year"), alt::y("avg"), alt::text("avg")))?
+        .to_svg()?;
+
+    // One text mark (12px) per (shop, year) combination, in first-appearance order.
+    let labels: Vec<&str> = svg
+        .split(r#"font-size="12.0""#)
+        .skip(1)
+        .filter_map(|s| s.split('>').nth(1)?.split('<').next())
+        .collect();
+    assert_eq!(labels, ["3", "10", "3", "7"]);
+
+    Ok(())
+}
+
+#[test]
+fn test_transform_aggregate_global() -> Result<(), Box<dyn Error>> {
+    let x = [1.0, 2.0, 3.0, 4.0];
+    let g = ["a", "a", "b", "b"];
+
+    // No groupby: the whole dataset collapses into a single row.
+    let svg = chart!(x, g)?
+        .transform_aggregate(AggregateTransform::new(vec![
+            AggregateFieldDef::new("x", AggregateOp::Count, "n"),
+            AggregateFieldDef::new("x", AggregateOp::Std, "sd"),
+        ]))?
+        .mark_text()?
+        .encode((alt::x("n"), alt::y("sd"), alt::text("n")))?
+        .to_svg()?;
+
+    assert_eq!(svg.matches(r#"font-size="12.0""#).count(), 1);
+    assert!(svg.contains(">4<"));
+
+    Ok(())
+}
+
+#[test]
+fn test_transform_aggregate_invalid() -> Result<(), Box<dyn Error>> {
+    let x = [1.0, 2.0];
+    let g = ["a", "b"];
+
+    let empty = chart!(x, g)?.transform_aggregate(AggregateTransform::new(vec![]));
+    assert!(matches!(empty, Err(ChartonError::Data(_))));
+
+    let duplicate = chart!(x, g)?.transform_aggregate(
+        AggregateTransform::new(vec![AggregateFieldDef::new("x", "sum", "g")]).with_groupby(["g"]),
+    );
+    assert!(matches!(duplicate, Err(ChartonError::Data(msg)) if msg.contains("duplicate")));
+
+    Ok(())
+}
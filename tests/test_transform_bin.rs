@@ -0,0 +1,123 @@
+use charton::error::ChartonError;
+use charton::prelude::*;
+use std::error::Error;
+
+#[test]
+fn test_transform_bin_1() -> Result<(), Box<dyn Error>> {
+    let ds = load_dataset("penguins")?;
+
+    let chart = chart!(ds)?
+        .transform_bin(
+            BinTransform::new("Body Mass (g)")
+                .with_step(500.0)
+                .with_as("mass_start", "mass_end"),
+        )?
+        .mark_bar()?
+        .encode((
+            alt::x("mass_start").with_scale(Scale::Discrete),
+            alt::y("Body Mass (g)").with_aggregate("count"),
+            alt::color("Species"),
+        ))?
+        .with_title("Body mass by species");
+
+    let svg = chart.to_svg()?;
+    // Masses range from 2700 g to 6300 g.
+    assert!(svg.contains(">2500<") && svg.contains(">6000<"));
+    assert!(!svg.contains(">6500<"));
+
+    chart.save("./tests/transform_bin_1.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_transform_bin_edges() -> Result<(), Box<dyn Error>> {
+    let ds = Dataset::new().with_column("value", vec![0.1, 0.3, 0.7, 1.0, 4.2, 9.9])?;
+
+    let labels = |params: BinTransform| -> Result<String, Box<dyn Error>> {
+        let ds = ds.clone();
+        let svg = chart!(ds)?
+            .transform_bin(params.with_as("start", "end"))?
+            .mark_bar()?
+            .encode((
+                alt::x("start").with_scale(Scale::Discrete),
+                alt::y("value").with_aggregate("count"),
+            ))?
+            .to_svg()?;
+        Ok(svg)
+    };
+
+    // At most 5 bins over [0.1, 9.9] gives a nice step of 2 starting at 0.
+    let nice = labels(BinTransform::new("value").with_maxbins(5))?;
+    assert!(nice.contains(">0<") && nice.contains(">4<") && nice.contains(">8<"));
+    assert!(!nice.contains(">6<"));
+
+    // An explicit step wins over maxbins.
+    let stepped = labels(BinTransform::new("value").with_maxbins(5).with_step(5.0))?;
+    assert!(stepped.contains(">0<") && stepped.contains(">5<"));
+
+    // An explicit extent anchors the first bin and leaves out values beyond it.
+    let clipped = labels(
+        BinTransform::new("value")
+            .with_extent(0.5, 4.5)
+            .with_step(1.0),
+    )?;
+    assert!(clipped.contains(">0.5<") && clipped.contains(">3.5<"));
+    assert!(!clipped.contains(">0<") && !clipped.contains(">8.5<"));
+
+    Ok(())
+}
+
+#[test]
+fn test_transform_bin_capped() -> Result<(), Box<dyn Error>> {
+    let labels = |params: BinTransform| -> Result<String, Box<dyn Error>> {
+        let ds = Dataset::new().with_column("value", vec![0.0, 5.0, 10.0])?;
+        let svg = chart!(ds)?
+            .transform_bin(params.with_as("start", "end"))?
+            .mark_bar()?
+            .encode((
+                alt::x("start").with_scale(Scale::Discrete),
+                alt::y("value").with_aggregate("count"),
+            ))?
+            .to_svg()?;
+        Ok(svg)
+    };
+
+    // A step far too small for the extent is widened to 10,000 bins of 0.001.
+    let stepped = labels(BinTransform::new("value").with_step(1e-12))?;
+    assert!(
+        stepped.contains(">5<") && stepped.contains(">9.999<"),
+        "{stepped}"
+    );
+
+    // So is an unbounded maxbins.
+    let unbounded = labels(BinTransform::new("value").with_maxbins(usize::MAX))?;
+    assert!(unbounded.contains(">9.999<"));
+
+    Ok(())
+}
+
+#[test]
+fn test_transform_bin_invalid() -> Result<(), Box<dyn Error>> {
+    let value = Dataset::new().with_column("value", vec![1.0, 2.0, 3.0])?;
+    let label = Dataset::new().with_column("label", vec!["a", "b", "c"])?;
+
+    let step = chart!(value.clone())?.transform_bin(BinTransform::new("value").with_step(0.0));
+    assert!(matches!(step, Err(ChartonError::Data(msg)) if msg.contains("step")));
+
+    let maxbins = chart!(value.clone())?.transform_bin(BinTransform::new("value").with_maxbins(0));
+    assert!(matches!(maxbins, Err(ChartonError::Data(msg)) if msg.contains("maxbins")));
+
+    let extent =
+        chart!(value.clone())?.transform_bin(BinTransform::new("value").with_extent(3.0, 1.0));
+    assert!(matches!(extent, Err(ChartonError::Data(msg)) if msg.contains("extent")));
+
+    let text = chart!(label)?.transform_bin(BinTransform::new("label"));
+    assert!(matches!(text, Err(ChartonError::Data(msg)) if msg.contains("not numeric")));
+
+    let clash =
+        chart!(value.clone())?.transform_bin(BinTransform::new("value").with_as("value", "end"));
+    assert!(matches!(clash, Err(ChartonError::Data(msg)) if msg.contains("already exists")));
+
+    Ok(())
+}
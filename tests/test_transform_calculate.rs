@@ -1,3 +1,4 @@
+use charton::error::ChartonError;
 use charton::prelude::*;
 use std::error::Error;
 
@@ -35,3 +36,26 @@ fn test_transform_calculate_1() -> Result<(), Box<dyn Error>> {
 
     Ok(())
 }
+
+#[test]
+fn test_transform_calculate_chained() -> Result<(), Box<dyn Error>> {
+    let price = [10.0, 100.0, 1000.0];
+    let units = [4.0, 5.0, 8.0];
+
+    let svg = chart!(price, units)?
+        .transform_calculate("log_price", |row| row.val("price").map(f64::log10))?
+        // A later calculation can use the column produced by an earlier one.
+        .transform_calculate("ratio", |row| {
+            Some(row.val("log_price")? / row.val("units")?)
+        })?
+        .mark_point()?
+        .encode((alt::x("log_price"), alt::y("ratio")))?
+        .to_svg()?;
+    assert_eq!(svg.matches("<circle").count(), 3);
+
+    // The alias must not shadow an existing column.
+    let result = chart!(price, units)?.transform_calculate("price", |row| row.val("units"));
+    assert!(matches!(result, Err(ChartonError::Data(msg)) if msg.contains("price")));
+
+    Ok(())
+}
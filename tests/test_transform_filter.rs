@@ -0,0 +1,78 @@
+use charton::error::ChartonError;
+use charton::prelude::*;
+use std::error::Error;
+
+#[test]
+fn test_transform_filter_1() -> Result<(), Box<dyn Error>> {
+    let ds = load_dataset("mtcars")?;
+
+    // mtcars has 14 cars with mpg above 20.
+    let chart = chart!(ds)?
+        .transform_filter(|row| row.val("mpg").is_some_and(|mpg| mpg > 20.0))?
+        .mark_point()?
+        .encode((alt::x("wt"), alt::y("mpg")))?
+        .with_title("Cars above 20 MPG");
+
+    let svg = chart.to_svg()?;
+    assert_eq!(svg.matches("<circle").count(), 14);
+
+    chart.save("./tests/transform_filter_1.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_transform_filter_chain_and_str() -> Result<(), Box<dyn Error>> {
+    let item = ["a", "b", "c", "d", "e", "f"];
+    let price = [50.0, 120.0, 150.0, 90.0, 300.0, 110.0];
+    let shop = ["x", "x", "y", "y", "y", "x"];
+
+    let count = |svg: &str| svg.matches("<circle").count();
+
+    // Chained closures: price > 100, then shop == "x" keeps b and f.
+    let chained = chart!(item, price, shop)?
+        .transform_filter(|row| row.val("price").is_some_and(|p| p > 100.0))?
+        .transform_filter(|row| row.str("shop").as_deref() == Some("x"))?
+        .mark_point()?
+        .encode((alt::x("item"), alt::y("price")))?
+        .to_svg()?;
+    assert_eq!(count(&chained), 2);
+
+    // The same filter written as an expression.
+    let parsed = chart!(item, price, shop)?
+        .transform_filter_str("price > 100 && shop == 'x'")?
+        .mark_point()?
+        .encode((alt::x("item"), alt::y("price")))?
+        .to_svg()?;
+    assert_eq!(chained, parsed);
+
+    // `&&` binds tighter than `||`: (price >= 300) or (shop == "y" and price < 100).
+    let either = chart!(item, price, shop)?
+        .transform_filter_str("price >= 300 || shop == \"y\" && price < 100")?
+        .mark_point()?
+        .encode((alt::x("item"), alt::y("price")))?
+        .to_svg()?;
+    assert_eq!(count(&either), 2);
+
+    Ok(())
+}
+
+#[test]
+fn test_transform_filter_invalid() -> Result<(), Box<dyn Error>> {
+    let x = [1.0, 2.0, 3.0];
+    let y = [4.0, 5.0, 6.0];
+
+    let empty = chart!(x, y)?.transform_filter(|row| row.val("x").is_some_and(|v| v > 10.0));
+    assert!(matches!(empty, Err(ChartonError::Data(msg)) if msg.contains("removed all 3 rows")));
+
+    let missing = chart!(x, y)?.transform_filter_str("z > 1");
+    assert!(matches!(missing, Err(ChartonError::Data(msg)) if msg.contains("'z'")));
+
+    let no_op = chart!(x, y)?.transform_filter_str("x 1");
+    assert!(matches!(no_op, Err(ChartonError::Data(_))));
+
+    let bad_value = chart!(x, y)?.transform_filter_str("x > one");
+    assert!(matches!(bad_value, Err(ChartonError::Data(_))));
+
+    Ok(())
+}
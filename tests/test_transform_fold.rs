@@ -0,0 +1,51 @@
+use charton::error::ChartonError;
+use charton::prelude::*;
+use std::error::Error;
+
+#[test]
+fn test_transform_fold_1() -> Result<(), Box<dyn Error>> {
+    let category = ["books", "games", "music"];
+    let jan = [12.0, 30.0, 7.0];
+    let feb = [15.0, 26.0, 9.0];
+    let mar = [19.0, 33.0, 8.0];
+
+    let chart = chart!(category, jan, feb, mar)?
+        .transform_fold(vec!["jan", "feb", "mar"], "sales", "month")?
+        .mark_point()?
+        .encode((alt::x("month"), alt::y("sales"), alt::color("category")))?
+        .with_title("Monthly sales");
+
+    let svg = chart.to_svg()?;
+    // One point per category and month.
+    assert_eq!(svg.matches("<circle").count(), 9 + 3);
+    // Months keep the order of the folded fields.
+    let (jan_pos, feb_pos, mar_pos) = (
+        svg.find(">jan<").ok_or("jan")?,
+        svg.find(">feb<").ok_or("feb")?,
+        svg.find(">mar<").ok_or("mar")?,
+    );
+    assert!(jan_pos < feb_pos && feb_pos < mar_pos);
+    assert!(svg.contains(">games<"));
+
+    chart.save("./tests/transform_fold_1.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_transform_fold_errors() -> Result<(), Box<dyn Error>> {
+    let category = ["a", "b"];
+    let jan = [1.0, 2.0];
+    let feb = [3.0, 4.0];
+
+    let missing = chart!(category, jan, feb)?.transform_fold(vec!["jan", "apr"], "v", "k");
+    assert!(matches!(missing, Err(ChartonError::Data(msg)) if msg.contains("apr")));
+
+    let clash = chart!(category, jan, feb)?.transform_fold(vec!["jan", "feb"], "v", "category");
+    assert!(matches!(clash, Err(ChartonError::Data(msg)) if msg.contains("category")));
+
+    let text = chart!(category, jan, feb)?.transform_fold(vec!["category"], "v", "k");
+    assert!(matches!(text, Err(ChartonError::Data(_))));
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use charton::error::ChartonError;
+use charton::prelude::*;
+use std::error::Error;
+
+#[test]
+fn test_transform_index_1() -> Result<(), Box<dyn Error>> {
+    let day = [1.0, 2.0, 3.0, 4.0, 1.0, 2.0, 3.0, 4.0];
+    let price = [200.0, 220.0, 210.0, 250.0, 20.0, 18.0, 24.0, 30.0];
+    let ticker = ["AAA", "AAA", "AAA", "AAA", "BBB", "BBB", "BBB", "BBB"];
+
+    chart!(day, price, ticker)?
+        .transform_index(
+            IndexTransform::new("price")
+                .with_key("day")
+                .with_groupby("ticker")
+                .with_base(IndexBase::First),
+        )?
+        .mark_line()?
+        .encode((alt::x("day"), alt::y("price_index"), alt::color("ticker")))?
+        .with_size(600, 400)
+        .with_title("Price Indexed to 100")
+        .save("./tests/transform_index_1.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_transform_index_values() -> Result<(), Box<dyn Error>> {
+    // Rows are deliberately out of key order: the base is the value at the smallest key.
+    let day = [3.0, 1.0, 2.0];
+    let price = [150.0, 50.0, 100.0];
+
+    let svg = chart!(day, price)?
+        .transform_index(
+            IndexTransform::new("price")
+                .with_key("day")
+                .with_as("indexed"),
+        )?
+        .mark_text()?
+        .encode((alt::x("day"), alt::y("price"), alt::text("indexed")))?
+        .to_svg()?;
+
+    for label in [">100<", ">200<", ">300<"] {
+        assert!(svg.contains(label), "missing label {}", label);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_transform_index_zero_base() -> Result<(), Box<dyn Error>> {
+    let day = [1.0, 2.0, 1.0, 2.0];
+    let price = [0.0, 5.0, 10.0, 15.0];
+    let ticker = ["AAA", "AAA", "BBB", "BBB"];
+
+    // The series with a zero base is emitted as nulls instead of infinities.
+    let svg = chart!(day, price, ticker)?
+        .transform_index(
+            IndexTransform::new("price")
+                .with_key("day")
+                .with_groupby("ticker")
+                .with_base(IndexBase::AtKey(1.0)),
+        )?
+        .mark_text()?
+        .encode((alt::x("day"), alt::y("price"), alt::text("price_index")))?
+        .to_svg()?;
+
+    assert!(svg.contains(">150<"));
+    assert!(!svg.contains("inf") && !svg.contains("NaN"));
+
+    Ok(())
+}
+
+#[test]
+fn test_transform_index_at_key_requires_key() -> Result<(), Box<dyn Error>> {
+    let day = [1.0, 2.0];
+    let price = [10.0, 20.0];
+
+    let result = chart!(day, price)?
+        .transform_index(IndexTransform::new("price").with_base(IndexBase::AtKey(1.0)));
+
+    assert!(matches!(result, Err(ChartonError::Data(_))));
+
+    Ok(())
+}
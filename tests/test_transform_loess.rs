@@ -0,0 +1,164 @@
+use charton::error::ChartonError;
+use charton::prelude::*;
+use std::error::Error;
+
+#[test]
+fn test_transform_loess_1() -> Result<(), Box<dyn Error>> {
+    let ds = load_dataset("mtcars")?;
+
+    let points = chart!(ds.clone())?
+        .mark_point()?
+        .encode((alt::x("wt"), alt::y("mpg")))?;
+    let smooth = chart!(ds)?
+        .transform_loess(LoessTransform::new("wt", "mpg").with_bandwidth(0.5))?
+        .mark_line()?
+        .encode((alt::x("wt"), alt::y("mpg")))?;
+
+    points
+        .and(smooth)
+        .with_title("Fuel economy vs. weight (LOESS)")
+        .save("./tests/transform_loess_1.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_transform_loess_robust() -> Result<(), Box<dyn Error>> {
+    // A slightly noisy line with a single wild outlier at x = 10.
+    let x: Vec<f64> = (0..=20).map(f64::from).collect();
+    let y: Vec<f64> = x
+        .iter()
+        .map(|&x| {
+            if x == 10.0 {
+                200.0
+            } else {
+                x + (1.7 * x).sin()
+            }
+        })
+        .collect();
+
+    let fit = |iterations: usize| -> Result<String, Box<dyn Error>> {
+        let (x, y) = (x.clone(), y.clone());
+        let svg = chart!(x, y)?
+            .transform_loess(
+                LoessTransform::new("x", "y")
+                    .with_bandwidth(0.5)
+                    .with_iterations(iterations)
+                    .with_steps(21)
+                    .with_as("fit_x", "fit_y"),
+            )?
+            .mark_point()?
+            .encode((alt::x("fit_x"), alt::y("fit_y")))?
+            .with_title("Robust LOESS")
+            .to_svg()?;
+        Ok(svg)
+    };
+
+    let plain = fit(0)?;
+    let robust = fit(2)?;
+    // One point per evaluation step.
+    assert_eq!(robust.matches("<circle").count(), 21);
+    // The plain fit bends towards the outlier; the robust fit stays on the line.
+    assert!(plain.contains(">40<"));
+    assert!(!robust.contains(">40<"));
+
+    Ok(())
+}
+
+#[test]
+fn test_transform_loess_invalid() -> Result<(), Box<dyn Error>> {
+    let x = [1.0, 2.0, 3.0, 4.0];
+    let y = [2.0, 4.0, 5.0, 4.0];
+    let label = ["a", "b", "c", "d"];
+
+    for bandwidth in [0.0, 1.5] {
+        let result =
+            chart!(x, y)?.transform_loess(LoessTransform::new("x", "y").with_bandwidth(bandwidth));
+        assert!(matches!(result, Err(ChartonError::Data(msg)) if msg.contains("bandwidth")));
+    }
+
+    let text = chart!(label, y)?.transform_loess(LoessTransform::new("label", "y"));
+    assert!(matches!(text, Err(ChartonError::Data(msg)) if msg.contains("not numeric")));
+
+    Ok(())
+}
+
+#[test]
+fn test_transform_loess_groupby_time_series() -> Result<(), Box<dyn Error>> {
+    use time::Duration;
+    use time::macros::date;
+
+    // Two noisy daily series over 60 days.
+    let date: Vec<time::Date> = (0..120)
+        .map(|i| date!(2025 - 01 - 01) + Duration::days(i % 60))
+        .collect();
+    let series: Vec<&str> = (0..120)
+        .map(|i| if i < 60 { "north" } else { "south" })
+        .collect();
+    let value: Vec<f64> = (0..120)
+        .map(|i| {
+            let t = (i % 60) as f64 / 10.0;
+            let noise = (7.3 * i as f64).sin();
+            if i < 60 {
+                10.0 + 3.0 * t.sin() + noise
+            } else {
+                5.0 + t + noise
+            }
+        })
+        .collect();
+    let ds = Dataset::new()
+        .with_column("date", date)?
+        .with_column("series", series)?
+        .with_column("value", value)?;
+
+    let raw = chart!(ds.clone())?.mark_point()?.encode((
+        alt::x("date"),
+        alt::y("value"),
+        alt::color("series"),
+    ))?;
+    let smooth = chart!(ds)?
+        .transform_loess(
+            LoessTransform::new("date", "value")
+                .with_bandwidth(0.4)
+                .with_steps(50)
+                .with_groupby("series"),
+        )?
+        .mark_line()?
+        .encode((alt::x("date"), alt::y("value"), alt::color("series")))?;
+
+    let svg = smooth.to_svg()?;
+    // One 50-point line per series; axis ticks are single segments.
+    let curves = svg
+        .split("<path")
+        .filter(|p| {
+            p.split('"')
+                .nth(1)
+                .is_some_and(|d| d.matches(" L ").count() == 49)
+        })
+        .count();
+    assert_eq!(curves, 2);
+
+    raw.and(smooth)
+        .with_title("Smoothed daily series")
+        .save("./tests/transform_loess_2.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_transform_loess_too_few_points() -> Result<(), Box<dyn Error>> {
+    let x = [1.0, 2.0, 3.0, 4.0];
+    let y = [2.0, 4.0, 5.0, 4.0];
+    let group = ["a", "a", "a", "b"];
+
+    let (x2, y2) = ([1.0, 2.0], [2.0, 4.0]);
+    let two = chart!(x2, y2)?.transform_loess(LoessTransform::new("x2", "y2"));
+    assert!(matches!(two, Err(ChartonError::Data(msg)) if msg.contains("at least 3 points")));
+
+    // Group "b" has a single point.
+    let grouped =
+        chart!(x, y, group)?.transform_loess(LoessTransform::new("x", "y").with_groupby("group"));
+    assert!(matches!(grouped, Err(ChartonError::Data(msg)) if msg.contains("group 'b'")));
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use charton::prelude::*;
+use std::error::Error;
+
+#[test]
+fn test_transform_lookup_1() -> Result<(), Box<dyn Error>> {
+    let code = vec![1i64, 2, 3, 1, 2];
+    let sales = vec![12.0, 7.0, 9.0, 4.0, 5.0];
+    let names = Dataset::new()
+        .with_column("id", vec!["1", "2", "3"])?
+        .with_column("name", vec!["Apples", "Bananas", "Cherries"])?;
+
+    let chart = chart!(code, sales)?
+        .transform_lookup(LookupTransform::new(names, "code", "id").with_values(vec!["name"]))?
+        .mark_bar()?
+        .encode((alt::x("name"), alt::y("sales")))?
+        .with_title("Sales by fruit");
+
+    let svg = chart.to_svg()?;
+    for name in ["Apples", "Bananas", "Cherries"] {
+        assert!(
+            svg.contains(&format!(">{}</text>", name)),
+            "missing {}",
+            name
+        );
+    }
+
+    chart.save("./tests/transform_lookup_1.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_transform_lookup_unmatched_rows_are_null() -> Result<(), Box<dyn Error>> {
+    let code = vec!["a", "b", "z"];
+    let value = vec![1.0, 2.0, 3.0];
+    // Duplicate keys resolve to the first row.
+    let labels = Dataset::new()
+        .with_column("code", vec!["a", "b", "a"])?
+        .with_column("label", vec!["Alpha", "Beta", "Other"])?;
+
+    let svg = chart!(code, value)?
+        .transform_lookup(LookupTransform::new(labels, "code", "code"))?
+        .mark_text()?
+        .encode((alt::x("value"), alt::y("value"), alt::text("label")))?
+        .to_svg()?;
+
+    assert_eq!(svg.matches(">Alpha</text>").count(), 1);
+    assert_eq!(svg.matches(">Beta</text>").count(), 1);
+    assert!(!svg.contains(">Other</text>"));
+    // The row coded "z" has no match and gets an empty label.
+    assert_eq!(svg.matches("></text>").count(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn test_transform_lookup_errors() -> Result<(), Box<dyn Error>> {
+    let code = vec!["a", "b"];
+    let value = vec![1.0, 2.0];
+    let labels = Dataset::new()
+        .with_column("code", vec!["a", "b"])?
+        .with_column("value", vec![10.0, 20.0])?;
+
+    // The joined column would overwrite a chart column.
+    let clash =
+        chart!(code, value)?.transform_lookup(LookupTransform::new(labels.clone(), "code", "code"));
+    assert!(clash.is_err());
+
+    let missing = chart!(code, value)?
+        .transform_lookup(LookupTransform::new(labels, "code", "code").with_values(vec!["label"]));
+    assert!(missing.is_err());
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use charton::prelude::*;
+use std::error::Error;
+
+#[test]
+fn test_transform_quantile_1() -> Result<(), Box<dyn Error>> {
+    let ds = load_dataset("mtcars")?;
+
+    let chart = chart!(ds)?
+        .transform_quantile(QuantileTransform::new("hp").with_as("hp_quartile"))?
+        .mark_point()?
+        .encode((alt::x("wt"), alt::y("mpg"), alt::color("hp_quartile")))?
+        .with_title("Cars colored by horsepower quartile");
+
+    let svg = chart.to_svg()?;
+    for label in ["0-25%", "25-50%", "50-75%", "75-100%"] {
+        assert!(
+            svg.contains(&format!(">{}</text>", label)),
+            "missing {}",
+            label
+        );
+    }
+
+    chart.save("./tests/transform_quantile_1.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_transform_quantile_groups() -> Result<(), Box<dyn Error>> {
+    let value: Vec<f64> = (1..=10).map(f64::from).collect();
+    let group: Vec<&str> = vec!["a"; 10];
+
+    // Medians split 1..=10 at 5.5; a value on a break joins the lower group.
+    let chart = chart!(value, group)?
+        .transform_quantile(QuantileTransform::new("value").with_probs(vec![0.5]))?
+        .mark_text()?
+        .encode((
+            alt::x("value"),
+            alt::y("group"),
+            alt::text("value_quantile"),
+        ))?;
+    let svg = chart.to_svg()?;
+    assert_eq!(svg.matches(">0-50%</text>").count(), 5);
+    assert_eq!(svg.matches(">50-100%</text>").count(), 5);
+
+    // A step of 0.1 gives deciles, one value each.
+    let deciles = chart!(value, group)?
+        .transform_quantile(QuantileTransform::new("value").with_step(0.1))?
+        .mark_text()?
+        .encode((
+            alt::x("value"),
+            alt::y("group"),
+            alt::text("value_quantile"),
+        ))?
+        .to_svg()?;
+    assert_eq!(deciles.matches(">90-100%</text>").count(), 1);
+    assert_eq!(deciles.matches(">0-10%</text>").count(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn test_transform_quantile_invalid() -> Result<(), Box<dyn Error>> {
+    let value = vec![1.0, 2.0, 3.0];
+    let name = vec!["a", "b", "c"];
+
+    let quantile = |params: QuantileTransform| chart!(value, name)?.transform_quantile(params);
+
+    assert!(quantile(QuantileTransform::new("value").with_probs(vec![0.75, 0.25])).is_err());
+    assert!(quantile(QuantileTransform::new("value").with_probs(vec![0.0, 0.5])).is_err());
+    assert!(quantile(QuantileTransform::new("value").with_probs(vec![0.5, 1.2])).is_err());
+    assert!(quantile(QuantileTransform::new("value").with_probs(vec![])).is_err());
+    assert!(quantile(QuantileTransform::new("value").with_step(1.5)).is_err());
+    assert!(quantile(QuantileTransform::new("name")).is_err());
+    assert!(quantile(QuantileTransform::new("value").with_as("name")).is_err());
+
+    Ok(())
+}
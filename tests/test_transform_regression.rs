@@ -0,0 +1,191 @@
+use charton::error::ChartonError;
+use charton::prelude::*;
+use std::error::Error;
+
+#[test]
+fn test_transform_regression_1() -> Result<(), Box<dyn Error>> {
+    let ds = load_dataset("mtcars")?;
+
+    let points = chart!(ds.clone())?
+        .mark_point()?
+        .encode((alt::x("wt"), alt::y("mpg")))?;
+    let linear = chart!(ds.clone())?
+        .transform_regression(RegressionTransform::new("wt", "mpg"))?
+        .mark_line()?
+        .encode((alt::x("wt"), alt::y("mpg")))?;
+    let quadratic = chart!(ds)?
+        .transform_regression(
+            RegressionTransform::new("wt", "mpg")
+                .with_method(RegressionMethod::Polynomial { degree: 2 }),
+        )?
+        .mark_line()?
+        .configure_line(|l| l.with_color("orange"))
+        .encode((alt::x("wt"), alt::y("mpg")))?;
+
+    points
+        .and(linear)
+        .and(quadratic)
+        .with_title("Fuel economy vs. weight")
+        .save("./tests/transform_regression_1.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_transform_regression_exact_fit() -> Result<(), Box<dyn Error>> {
+    // y = 1 - 2x + 0.5x², recovered exactly by a quadratic fit.
+    let x = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0];
+    let y: Vec<f64> = x.iter().map(|x| 1.0 - 2.0 * x + 0.5 * x * x).collect();
+
+    let chart = chart!(x, y)?
+        .transform_regression(
+            RegressionTransform::new("x", "y")
+                .with_method(RegressionMethod::Polynomial { degree: 2 })
+                .with_extent(0.0, 10.0)
+                .with_as("fit_x", "fit_y"),
+        )?
+        .mark_line()?;
+    let svg = chart
+        .encode((alt::x("fit_x"), alt::y("fit_y")))?
+        .with_title("Quadratic")
+        .to_svg()?;
+    // Extrapolated to x = 10, the curve reaches y = 31.
+    assert!(svg.contains(">30<"));
+
+    Ok(())
+}
+
+#[test]
+fn test_transform_regression_invalid() -> Result<(), Box<dyn Error>> {
+    let x = [1.0, 2.0, 3.0];
+    let y = [2.0, 4.0, 5.0];
+
+    let zero = chart!(x, y)?.transform_regression(
+        RegressionTransform::new("x", "y").with_method(RegressionMethod::Polynomial { degree: 0 }),
+    );
+    assert!(matches!(zero, Err(ChartonError::Data(msg)) if msg.contains("degree")));
+
+    // Three points cannot determine a cubic.
+    let cubic = chart!(x, y)?.transform_regression(
+        RegressionTransform::new("x", "y").with_method(RegressionMethod::Polynomial { degree: 3 }),
+    );
+    assert!(matches!(cubic, Err(ChartonError::Data(msg)) if msg.contains("distinct")));
+
+    Ok(())
+}
+
+#[test]
+fn test_transform_regression_groupby() -> Result<(), Box<dyn Error>> {
+    // Group "a" lies exactly on y = 2x; group "b" is noisy around y = -x.
+    let x = [1.0, 2.0, 3.0, 4.0, 1.0, 2.0, 3.0, 4.0];
+    let y = [2.0, 4.0, 6.0, 8.0, -1.5, -1.5, -3.5, -3.5];
+    let g = ["a", "a", "a", "a", "b", "b", "b", "b"];
+
+    let fit = chart!(x, y, g)?
+        .transform_regression(RegressionTransform::new("x", "y").with_groupby("g"))?
+        .mark_line()?
+        .encode((alt::x("x"), alt::y("y"), alt::color("g")))?;
+
+    // One straight line per group, drawn inside the clipped panel.
+    let svg = fit.to_svg()?;
+    let panel = svg
+        .split("<g clip-path")
+        .nth(1)
+        .and_then(|s| s.split("</g>").next())
+        .ok_or("missing panel")?;
+    assert_eq!(panel.matches("<path").count(), 2);
+
+    // The R² column reports a perfect fit for "a" and a poorer one for "b".
+    let fitted = chart!(x, y, g)?
+        .transform_regression(RegressionTransform::new("x", "y").with_groupby("g"))?;
+    let r2 = |group: &'static str, keep: fn(f64) -> bool| {
+        fitted.clone().transform_filter(move |row| {
+            row.str("g").as_deref() == Some(group) && row.val(R_SQUARED_FIELD).is_some_and(keep)
+        })
+    };
+    assert!(r2("a", |r| r > 0.999).is_ok());
+    assert!(r2("b", |r| r > 0.999).is_err());
+    assert!(r2("b", |r| r > 0.5 && r < 0.9).is_ok());
+
+    // with_degree is shorthand for a polynomial method; a group with too
+    // few points is reported by name.
+    let cubic = chart!(x, y, g)?.transform_regression(
+        RegressionTransform::new("x", "y")
+            .with_degree(4)
+            .with_groupby("g"),
+    );
+    assert!(matches!(cubic, Err(ChartonError::Data(msg)) if msg.contains("group 'a'")));
+
+    Ok(())
+}
+
+#[test]
+fn test_transform_regression_confidence_band() -> Result<(), Box<dyn Error>> {
+    let ds = load_dataset("mtcars")?;
+
+    let points = chart!(ds.clone())?
+        .mark_point()?
+        .encode((alt::x("wt"), alt::y("mpg")))?;
+    let fit = chart!(ds)?.transform_regression(RegressionTransform::new("wt", "mpg"))?;
+    let band = fit
+        .clone()
+        .mark_area()?
+        .configure_area(|a| a.with_positive_color("steelblue").with_opacity(0.25))
+        .encode((alt::x("wt"), alt::y("mpg_upper"), alt::y2("mpg_lower")))?;
+    let trend = fit
+        .mark_line()?
+        .configure_line(|l| l.with_color("steelblue"))
+        .encode((alt::x("wt"), alt::y("mpg")))?;
+
+    band.and(points)
+        .and(trend)
+        .with_y_label("mpg")
+        .with_title("Linear trend with 95% confidence band")
+        .save("./tests/transform_regression_2.svg")?;
+
+    // y = 2.2 + 0.6x with s² = 0.8; at x = 1 the mean response is 2.8 with a
+    // standard error of sqrt(0.8 * 0.6), and t(0.975, 3) = 3.182.
+    let x = [1.0, 2.0, 3.0, 4.0, 5.0];
+    let y = [2.0, 4.0, 5.0, 4.0, 5.0];
+    let fitted = chart!(x, y)?.transform_regression(
+        RegressionTransform::new("x", "y")
+            .with_order(1)
+            .with_ci_as("lo", "hi"),
+    )?;
+    let half_width = 3.182 * (0.8_f64 * 0.6).sqrt();
+    let first = fitted.transform_filter(move |row| {
+        row.val("x") == Some(1.0)
+            && row.val("y").is_some_and(|v| (v - 2.8).abs() < 1e-9)
+            && row
+                .val("lo")
+                .is_some_and(|v| (v - (2.8 - half_width)).abs() < 0.01)
+            && row
+                .val("hi")
+                .is_some_and(|v| (v - (2.8 + half_width)).abs() < 0.01)
+    });
+    assert!(first.is_ok());
+
+    Ok(())
+}
+
+#[test]
+fn test_transform_regression_degenerate() -> Result<(), Box<dyn Error>> {
+    // A single point, or x without variance, cannot determine a line.
+    let x = [1.0];
+    let y = [2.0];
+    let single = chart!(x, y)?.transform_regression(RegressionTransform::new("x", "y"));
+    assert!(matches!(single, Err(ChartonError::Data(msg)) if msg.contains("distinct")));
+
+    let x = [3.0, 3.0, 3.0];
+    let y = [1.0, 2.0, 3.0];
+    let flat = chart!(x, y)?.transform_regression(RegressionTransform::new("x", "y"));
+    assert!(matches!(flat, Err(ChartonError::Data(msg)) if msg.contains("distinct")));
+
+    // The confidence columns must not collide with the other outputs.
+    let x = [1.0, 2.0, 3.0];
+    let clash = chart!(x, y)?
+        .transform_regression(RegressionTransform::new("x", "y").with_ci_as("y", "hi"));
+    assert!(matches!(clash, Err(ChartonError::Data(msg)) if msg.contains("'y'")));
+
+    Ok(())
+}
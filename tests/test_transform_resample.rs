@@ -0,0 +1,128 @@
+use charton::error::ChartonError;
+use charton::prelude::*;
+use std::error::Error;
+use time::macros::{date, datetime};
+use time::{Duration, Weekday};
+
+/// Daily dates from 2025-01-01 on.
+fn days(n: i64) -> Vec<time::Date> {
+    (0..n)
+        .map(|i| date!(2025 - 01 - 01) + Duration::days(i))
+        .collect()
+}
+
+/// Counts the bars, which are the only paths filled with the default steelblue.
+fn count_bars(svg: &str) -> usize {
+    svg.matches(r#"Z" fill="rgba(70,130,180,1.000)""#).count()
+}
+
+#[test]
+fn test_transform_resample_1() -> Result<(), Box<dyn Error>> {
+    let date: Vec<time::Date> = days(120).into_iter().cycle().take(240).collect();
+    let series: Vec<&str> = (0..240)
+        .map(|i| if i < 120 { "north" } else { "south" })
+        .collect();
+    let value: Vec<f64> = (0..240)
+        .map(|i| {
+            let t = (i % 120) as f64 / 15.0;
+            if i < 120 {
+                10.0 + 4.0 * t.sin()
+            } else {
+                8.0 + 3.0 * t.cos()
+            }
+        })
+        .collect();
+    let ds = Dataset::new()
+        .with_column("date", date)?
+        .with_column("series", series)?
+        .with_column("value", value)?;
+
+    let chart = chart!(ds)?
+        .transform_resample(
+            ResampleTransform::new("date", Period::Month)
+                .agg(&[("value", AggregateOp::Mean)])
+                .with_groupby(["series"]),
+        )?
+        .mark_bar()?
+        .encode((alt::x("date"), alt::y("value"), alt::color("series")))?
+        .with_title("Monthly mean");
+
+    let svg = chart.to_svg()?;
+    assert!(svg.contains(">2025-01<") && svg.contains(">2025-04<"));
+
+    chart.save("./tests/transform_resample_1.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_transform_resample_weeks() -> Result<(), Box<dyn Error>> {
+    // 2025-01-01 is a Wednesday and 2025-01-12 a Sunday.
+    let bars = |week_start: Weekday, drop_incomplete: bool| -> Result<usize, Box<dyn Error>> {
+        let (date, value) = (days(12), vec![1.0; 12]);
+        let svg = chart!(date, value)?
+            .transform_resample(
+                ResampleTransform::new("date", Period::Week)
+                    .agg(&[("value", AggregateOp::Sum)])
+                    .with_week_start(week_start)
+                    .with_drop_incomplete(drop_incomplete),
+            )?
+            .mark_bar()?
+            .encode((alt::x("date"), alt::y("value")))?
+            .to_svg()?;
+        Ok(count_bars(&svg))
+    };
+
+    // Monday weeks: Dec 30 - Jan 5 and Jan 6 - Jan 12, the last one complete.
+    assert_eq!(bars(Weekday::Monday, false)?, 2);
+    assert_eq!(bars(Weekday::Monday, true)?, 2);
+    // Sunday weeks add Jan 12 - Jan 18, which only has one day of data.
+    assert_eq!(bars(Weekday::Sunday, false)?, 3);
+    assert_eq!(bars(Weekday::Sunday, true)?, 2);
+
+    Ok(())
+}
+
+#[test]
+fn test_transform_resample_datetime() -> Result<(), Box<dyn Error>> {
+    let time = vec![
+        datetime!(2024-11-20 08:00 UTC),
+        datetime!(2024-12-31 23:59 UTC),
+        datetime!(2025-01-01 00:00 UTC),
+        datetime!(2025-05-02 12:00 UTC),
+    ];
+    let value = [1.0, 2.0, 3.0, 4.0];
+
+    let svg = chart!(time, value)?
+        .transform_resample(
+            ResampleTransform::new("time", Period::Year).agg(&[("value", "max".into())]),
+        )?
+        .mark_bar()?
+        .encode((alt::x("time"), alt::y("value")))?
+        .to_svg()?;
+    assert_eq!(count_bars(&svg), 2);
+
+    Ok(())
+}
+
+#[test]
+fn test_transform_resample_invalid() -> Result<(), Box<dyn Error>> {
+    let (date, value) = (days(3), [1.0, 2.0, 3.0]);
+    let label = ["a", "b", "c"];
+
+    let no_agg =
+        chart!(label, value)?.transform_resample(ResampleTransform::new("label", Period::Day));
+    assert!(matches!(no_agg, Err(ChartonError::Data(msg)) if msg.contains("aggregate")));
+
+    let not_temporal = chart!(label, value)?.transform_resample(
+        ResampleTransform::new("label", Period::Day).agg(&[("value", AggregateOp::Sum)]),
+    );
+    assert!(matches!(not_temporal, Err(ChartonError::Data(msg)) if msg.contains("date")));
+
+    let clash = chart!(date, value)?.transform_resample(
+        ResampleTransform::new("date", Period::Day).agg(&[("date", AggregateOp::Count)]),
+    );
+    assert!(matches!(clash, Err(ChartonError::Data(msg)) if msg.contains("duplicate")));
+
+    Ok(())
+}
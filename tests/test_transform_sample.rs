@@ -0,0 +1,95 @@
+use charton::prelude::*;
+use std::error::Error;
+
+#[test]
+fn test_transform_sample_1() -> Result<(), Box<dyn Error>> {
+    let ds = load_dataset("mtcars")?;
+
+    let chart = chart!(ds)?
+        .transform_sample_with_seed(10, 42)?
+        .mark_point()?
+        .encode((alt::x("wt"), alt::y("mpg")))?
+        .with_title("10 random cars");
+
+    let svg = chart.to_svg()?;
+    assert_eq!(svg.matches("<circle").count(), 10);
+
+    chart.save("./tests/transform_sample_1.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_transform_sample_seed_and_size() -> Result<(), Box<dyn Error>> {
+    let x: Vec<f64> = (0..1000).map(f64::from).collect();
+    let y: Vec<f64> = x.iter().map(|v| (v * 0.1).sin()).collect();
+
+    let draw = |chart: Chart| -> Result<String, Box<dyn Error>> {
+        Ok(chart
+            .mark_point()?
+            .encode((alt::x("x"), alt::y("y")))?
+            .to_svg()?)
+    };
+
+    // The same seed picks the same rows; another seed picks different ones.
+    let a = draw(chart!(x, y)?.transform_sample_with_seed(50, 7)?)?;
+    let b = draw(chart!(x, y)?.transform_sample_with_seed(50, 7)?)?;
+    let c = draw(chart!(x, y)?.transform_sample_with_seed(50, 8)?)?;
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+    assert_eq!(a.matches("<circle").count(), 50);
+
+    // The unseeded variant keeps the requested number of rows too.
+    let random = draw(chart!(x, y)?.transform_sample(25)?)?;
+    assert_eq!(random.matches("<circle").count(), 25);
+
+    // Asking for at least as many rows as there are keeps everything.
+    let all = draw(chart!(x, y)?.transform_sample(5000)?)?;
+    assert_eq!(all.matches("<circle").count(), 1000);
+
+    assert!(chart!(x, y)?.transform_sample(0).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_transform_stratified_sample() -> Result<(), Box<dyn Error>> {
+    // A 90/10 class imbalance.
+    let class: Vec<&str> = (0..100)
+        .map(|i| if i < 90 { "big" } else { "small" })
+        .collect();
+    let value: Vec<f64> = (0..100).map(f64::from).collect();
+
+    let sampled = chart!(class, value)?.transform_stratified_sample(16, "class")?;
+    let svg = sampled
+        .mark_point()?
+        .encode((alt::x("value"), alt::y("value"), alt::color("class")))?
+        .to_svg()?;
+
+    // Each class contributes 16 / 2 rows, keeping the small class visible.
+    assert_eq!(svg.matches("<circle").count(), 16 + 2);
+
+    // The same seed picks the same rows; another seed picks different ones.
+    let seeded = |seed: u64| -> Result<String, Box<dyn Error>> {
+        Ok(chart!(class, value)?
+            .transform_stratified_sample_with_seed(16, "class", seed)?
+            .mark_point()?
+            .encode((alt::x("value"), alt::y("value"), alt::color("class")))?
+            .to_svg()?)
+    };
+    assert_eq!(seeded(3)?, seeded(3)?);
+    assert_ne!(seeded(3)?, seeded(4)?);
+
+    assert!(
+        chart!(class, value)?
+            .transform_stratified_sample(16, "missing")
+            .is_err()
+    );
+    assert!(
+        chart!(class, value)?
+            .transform_stratified_sample(1, "class")
+            .is_err()
+    );
+
+    Ok(())
+}
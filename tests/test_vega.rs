@@ -0,0 +1,130 @@
+#![cfg(feature = "vega")]
+
+use charton::error::ChartonError;
+use charton::prelude::*;
+use serde_json::Value;
+use std::error::Error;
+
+fn parse(spec: String) -> Result<Value, Box<dyn Error>> {
+    Ok(serde_json::from_str(&spec)?)
+}
+
+#[test]
+fn test_vega_point() -> Result<(), Box<dyn Error>> {
+    let ds = load_dataset("mtcars")?;
+
+    let spec = Chart::build(ds)?
+        .mark_point()?
+        .encode((
+            alt::x("wt"),
+            alt::y("mpg").with_scale(Scale::Log),
+            alt::color("gear").with_scale(Scale::Discrete),
+        ))?
+        .with_title("Mileage")
+        .with_x_label("Weight")
+        .to_vega_json()?;
+    let spec = parse(spec)?;
+
+    assert_eq!(
+        spec["$schema"],
+        "https://vega.github.io/schema/vega-lite/v5.json"
+    );
+    assert_eq!(spec["title"], "Mileage");
+    assert_eq!(spec["mark"]["type"], "circle");
+
+    let encoding = &spec["encoding"];
+    assert_eq!(encoding["x"]["field"], "wt");
+    assert_eq!(encoding["x"]["type"], "quantitative");
+    assert_eq!(encoding["x"]["title"], "Weight");
+    assert_eq!(encoding["y"]["scale"]["type"], "log");
+    assert_eq!(encoding["color"]["type"], "nominal");
+    assert_eq!(encoding["color"]["scale"]["scheme"], "tableau10");
+    assert_eq!(
+        encoding["color"]["scale"]["domain"]
+            .as_array()
+            .unwrap()
+            .len(),
+        3
+    );
+
+    let values = spec["data"]["values"].as_array().unwrap();
+    assert_eq!(values.len(), 32);
+    assert!(values[0]["mpg"].is_number());
+
+    Ok(())
+}
+
+#[test]
+fn test_vega_layered_line_and_bar() -> Result<(), Box<dyn Error>> {
+    let ds = Dataset::new()
+        .with_column("day", vec!["Mon", "Tue", "Wed", "Mon", "Tue", "Wed"])?
+        .with_column("sales", vec![3.0, 5.0, 2.0, 4.0, 1.0, 6.0])?
+        .with_column("shop", vec!["a", "a", "a", "b", "b", "b"])?;
+
+    let bars = Chart::build(ds.clone())?.mark_bar()?.encode((
+        alt::x("day"),
+        alt::y("sales").with_stack("stacked"),
+        alt::color("shop"),
+    ))?;
+    let line = Chart::build(ds)?
+        .mark_line()?
+        .configure_line(|m| m.with_color("red").with_stroke_width(2.0))
+        .encode((alt::x("day"), alt::y("sales"), alt::detail("shop")))?;
+
+    let spec = parse(
+        bars.and(line)
+            .with_x_category_order(vec!["Wed", "Tue", "Mon"])
+            .to_vega_json()?,
+    )?;
+
+    let layers = spec["layer"].as_array().unwrap();
+    assert_eq!(layers.len(), 2);
+
+    assert_eq!(layers[0]["mark"]["type"], "bar");
+    assert_eq!(layers[0]["encoding"]["y"]["stack"], "zero");
+    assert_eq!(
+        layers[0]["encoding"]["x"]["sort"],
+        serde_json::json!(["Wed", "Tue", "Mon"])
+    );
+
+    assert_eq!(layers[1]["mark"]["type"], "line");
+    assert_eq!(layers[1]["mark"]["color"], "#ff0000");
+    assert_eq!(layers[1]["mark"]["strokeWidth"], 2.0);
+    assert_eq!(layers[1]["encoding"]["x"]["type"], "nominal");
+    assert_eq!(layers[1]["encoding"]["detail"]["field"], "shop");
+
+    Ok(())
+}
+
+#[test]
+fn test_vega_continuous_color_map() -> Result<(), Box<dyn Error>> {
+    let ds = load_dataset("mtcars")?;
+
+    let spec = Chart::build(ds)?
+        .mark_point()?
+        .encode((alt::x("wt"), alt::y("mpg"), alt::color("hp")))?
+        .configure_theme(|t| t.with_color_map(ColorMap::Magma.reversed()))
+        .to_vega_json()?;
+    let spec = parse(spec)?;
+
+    let color = &spec["encoding"]["color"];
+    assert_eq!(color["type"], "quantitative");
+    assert_eq!(color["scale"]["scheme"], "magma");
+    assert_eq!(color["scale"]["reverse"], true);
+
+    Ok(())
+}
+
+#[test]
+fn test_vega_unsupported_mark() -> Result<(), Box<dyn Error>> {
+    let ds = load_dataset("mtcars")?;
+
+    let result = Chart::build(ds)?
+        .mark_boxplot()?
+        .encode((alt::x("gear").with_scale(Scale::Discrete), alt::y("mpg")))?
+        .to_vega_json();
+
+    assert!(matches!(result, Err(ChartonError::Unimplemented(_))));
+
+    Ok(())
+}
@@ -0,0 +1,107 @@
+use charton::error::ChartonError;
+use charton::prelude::*;
+use std::error::Error;
+
+#[test]
+fn test_violin_1() -> Result<(), Box<dyn Error>> {
+    let ds = load_dataset("penguins")?;
+
+    chart!(ds)?
+        .mark_violin()?
+        .configure_violin(|v| v.with_violin_width(0.8).with_opacity(0.8))
+        .encode((
+            alt::x("Sex"),
+            alt::y("Body Mass (g)"),
+            alt::color("Species"),
+        ))?
+        .coord_flip()
+        .save("./tests/violin_1.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_violin_median_line() -> Result<(), Box<dyn Error>> {
+    let group = ["a", "a", "a", "a", "a", "b", "b", "b", "b", "b"];
+    let value = [1.0, 2.0, 2.5, 3.0, 5.0, 4.0, 6.0, 6.5, 7.0, 9.0];
+
+    let with_median = chart!(group, value)?
+        .mark_violin()?
+        .configure_violin(|v| v.with_violin_color(Some(SingleColor::new("orange"))))
+        .encode((alt::x("group"), alt::y("value")))?;
+    let svg = with_median.to_svg()?;
+    assert_eq!(svg.matches("<polygon").count(), 2);
+    assert!(svg.contains("fill=\"rgba(255,165,0,1.000)\""));
+
+    let without_median = chart!(group, value)?
+        .mark_violin()?
+        .configure_violin(|v| v.with_median(false))
+        .encode((alt::x("group"), alt::y("value")))?;
+    let plain = without_median.to_svg()?;
+    assert_eq!(plain.matches("<polygon").count(), 2);
+    assert!(plain.matches("<line").count() < svg.matches("<line").count());
+
+    Ok(())
+}
+
+#[test]
+fn test_violin_inner_box_palette() -> Result<(), Box<dyn Error>> {
+    let ds = load_dataset("penguins")?;
+
+    let chart = chart!(ds)?
+        .mark_violin()?
+        .configure_violin(|v| v.with_inner_box(true).with_violin_width(0.9))
+        .encode((
+            alt::x("Species"),
+            alt::y("Flipper Length (mm)"),
+            alt::color("Species"),
+        ))?
+        .with_color_palette(ColorPalette::Set2);
+
+    let svg = chart.to_svg()?;
+    // One violin body and one inner box per species.
+    assert_eq!(svg.matches("<polygon").count(), 3);
+    assert!(svg.matches("<rect").count() >= 3);
+
+    chart.save("./tests/violin_2.svg")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_violin_bandwidth_kernel() -> Result<(), Box<dyn Error>> {
+    let ds = load_dataset("penguins")?;
+
+    let outline = |bandwidth: BandwidthType| -> Result<String, Box<dyn Error>> {
+        let svg = chart!(ds.clone())?
+            .mark_violin()?
+            .configure_violin(|v| {
+                v.with_violin_bandwidth(bandwidth)
+                    .with_violin_kernel(KernelType::Epanechnikov)
+            })
+            .encode((alt::x("Species"), alt::y("Body Mass (g)")))?
+            .to_svg()?;
+        Ok(svg.split("<polygon").nth(1).unwrap_or_default().to_string())
+    };
+    // A narrower kernel follows the data more closely and changes the outline.
+    assert_ne!(
+        outline(BandwidthType::Fixed(50.0))?,
+        outline(BandwidthType::Fixed(400.0))?
+    );
+
+    chart!(ds)?
+        .mark_violin()?
+        .configure_violin(|v| v.with_violin_bandwidth(BandwidthType::Fixed(100.0)))
+        .encode((alt::x("Species"), alt::y("Body Mass (g)")))?
+        .save("./tests/violin_3.svg")?;
+
+    let x = ["a", "a", "a"];
+    let y = [1.0, 2.0, 3.0];
+    let result = chart!(x, y)?
+        .mark_violin()?
+        .configure_violin(|v| v.with_violin_bandwidth(BandwidthType::Fixed(0.0)))
+        .encode((alt::x("x"), alt::y("y")));
+    assert!(matches!(result, Err(ChartonError::Mark(_))));
+
+    Ok(())
+}